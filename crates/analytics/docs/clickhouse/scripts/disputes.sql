@@ -52,7 +52,9 @@ CREATE TABLE dispute (
     `merchant_connector_id` Nullable(String),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `organization_id` String,
+    `metadata_map` Map(String, String),
     `sign_flag` Int8,
+    INDEX metadataValuesIndex mapValues(metadata_map) TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX disputeStatusIndex dispute_status TYPE bloom_filter GRANULARITY 1,
     INDEX disputeStageIndex dispute_stage TYPE bloom_filter GRANULARITY 1
@@ -84,6 +86,7 @@ CREATE MATERIALIZED VIEW dispute_mv TO dispute (
     `merchant_connector_id` Nullable(String),
     `organization_id` String,
     `inserted_at` DateTime64(3),
+    `metadata_map` Map(String, String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -110,6 +113,14 @@ SELECT
     merchant_connector_id,
     organization_id,
     now() AS inserted_at,
+    -- payment_intent_metadata is created along with the payment_intents table
+    CAST(
+        JSONExtractKeysAndValues(
+            dictGetOrDefault('payment_intent_metadata', 'metadata', (merchant_id, payment_id), '{}'),
+            'String'
+        ),
+        'Map(String, String)'
+    ) AS metadata_map,
     sign_flag
 FROM
     dispute_queue
@@ -104,7 +104,9 @@ CREATE TABLE payment_attempts (
     `debit_routing_savings` Nullable(UInt32),
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
+    `metadata_map` Map(String, String),
     `sign_flag` Int8,
+    INDEX metadataValuesIndex mapValues(metadata_map) TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
     INDEX authenticationTypeIndex authentication_type TYPE bloom_filter GRANULARITY 1,
@@ -164,6 +166,7 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `debit_routing_savings` Nullable(UInt32),
     `signature_network` Nullable(String),
     `is_issuer_regulated` Nullable(Bool),
+    `metadata_map` Map(String, String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -216,6 +219,14 @@ SELECT
     debit_routing_savings,
     signature_network,
     is_issuer_regulated,
+    -- payment_intent_metadata is created along with the payment_intents table
+    CAST(
+        JSONExtractKeysAndValues(
+            dictGetOrDefault('payment_intent_metadata', 'metadata', (merchant_id, payment_id), '{}'),
+            'String'
+        ),
+        'Map(String, String)'
+    ) AS metadata_map,
     sign_flag
 FROM
     payment_attempt_queue
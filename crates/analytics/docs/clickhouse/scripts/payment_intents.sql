@@ -24,6 +24,7 @@ CREATE TABLE payment_intents_queue
     `created_at` DateTime CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `organization_id` String,
+    `metadata` Nullable(String),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-intent-events',
@@ -58,7 +59,10 @@ CREATE TABLE payment_intents
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `organization_id` String,
+    `metadata` Nullable(String),
+    `metadata_map` Map(String, String),
     `sign_flag` Int8,
+    INDEX metadataValuesIndex mapValues(metadata_map) TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector_id TYPE bloom_filter GRANULARITY 1,
     INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1,
     INDEX statusIndex status TYPE bloom_filter GRANULARITY 1
@@ -96,6 +100,8 @@ CREATE MATERIALIZED VIEW payment_intents_mv TO payment_intents
     `last_synced` Nullable(DateTime64(3)),
     `inserted_at` DateTime64(3),
     `organization_id` String,
+    `metadata` Nullable(String),
+    `metadata_map` Map(String, String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -124,5 +130,33 @@ SELECT
     last_synced,
     now() AS inserted_at,
     organization_id,
+    metadata,
+    CAST(
+        JSONExtractKeysAndValues(ifNull(metadata, '{}'), 'String'),
+        'Map(String, String)'
+    ) AS metadata_map,
     sign_flag
-FROM payment_intents_queue;
\ No newline at end of file
+FROM payment_intents_queue;
+
+-- Latest metadata of each payment. The payment_intents table keeps a row for every update until
+-- its parts are collapsed, so the most recently modified live row is picked explicitly
+CREATE VIEW payment_intent_latest_metadata AS
+SELECT
+    merchant_id,
+    payment_id,
+    argMax(ifNull(metadata, '{}'), modified_at) AS metadata
+FROM payment_intents
+WHERE sign_flag = 1
+GROUP BY merchant_id, payment_id;
+
+-- Metadata of each payment, copied onto its attempts, refunds and disputes as they are ingested
+-- so that the metadata keys merchants register can be used as analytics dimensions on them
+CREATE DICTIONARY payment_intent_metadata
+(
+    `merchant_id` String,
+    `payment_id` String,
+    `metadata` String DEFAULT '{}'
+)
+PRIMARY KEY merchant_id, payment_id
+SOURCE(CLICKHOUSE(TABLE 'payment_intent_latest_metadata'))
+LAYOUT(COMPLEX_KEY_DIRECT());
\ No newline at end of file
@@ -0,0 +1,81 @@
+-- One row per payment session (payment intent) with the funnel steps it reached as 0 / 1 flags,
+-- joined from the intent, its attempts, its authentications and the SDK events of the session
+CREATE VIEW payment_sessions AS
+SELECT
+    intents.payment_id AS payment_id,
+    intents.merchant_id AS merchant_id,
+    intents.organization_id AS organization_id,
+    intents.profile_id AS profile_id,
+    intents.created_at AS created_at,
+    intents.currency AS currency,
+    intents.business_country AS country,
+    attempts.payment_method AS payment_method,
+    attempts.first_payment_method AS first_payment_method,
+    attempts.connector AS connector,
+    coalesce(sdk.platform, attempts.platform) AS platform,
+    toUInt8(
+        ifNull(sdk.payment_method_selected, 0) = 1
+        OR attempts.first_payment_method IS NOT NULL
+    ) AS payment_method_selected,
+    attempts.payment_attempted AS payment_attempted,
+    auths.authentication_started AS authentication_started,
+    auths.authentication_completed AS authentication_completed,
+    toUInt8(
+        intents.status IN (
+            'succeeded',
+            'requires_capture',
+            'partially_captured',
+            'partially_captured_and_capturable'
+        )
+    ) AS session_converted,
+    multiIf(
+        session_converted = 1, 'session_converted',
+        authentication_completed = 1, 'authentication_completed',
+        authentication_started = 1, 'authentication_started',
+        payment_attempted = 1, 'payment_attempted',
+        payment_method_selected = 1, 'payment_method_selected',
+        'session_started'
+    ) AS last_funnel_step
+FROM payment_intents AS intents FINAL
+LEFT JOIN (
+    SELECT
+        merchant_id,
+        payment_id,
+        argMaxIf(payment_method, created_at, payment_method IS NOT NULL) AS payment_method,
+        argMinIf(payment_method, created_at, payment_method IS NOT NULL) AS first_payment_method,
+        argMaxIf(connector, created_at, connector IS NOT NULL) AS connector,
+        nullIf(
+            argMinIf(
+                JSONExtractString(browser_info, 'os_type'),
+                created_at,
+                JSONExtractString(browser_info, 'os_type') != ''
+            ),
+            ''
+        ) AS platform,
+        toUInt8(max(confirm)) AS payment_attempted
+    FROM payment_attempts FINAL
+    GROUP BY merchant_id, payment_id
+) AS attempts ON attempts.merchant_id = intents.merchant_id
+    AND attempts.payment_id = intents.payment_id
+LEFT JOIN (
+    SELECT
+        merchant_id,
+        assumeNotNull(payment_id) AS payment_id,
+        toUInt8(1) AS authentication_started,
+        toUInt8(max(authentication_status = 'success')) AS authentication_completed
+    FROM authentications FINAL
+    WHERE payment_id IS NOT NULL
+    GROUP BY merchant_id, payment_id
+) AS auths ON auths.merchant_id = intents.merchant_id
+    AND auths.payment_id = intents.payment_id
+LEFT JOIN (
+    SELECT
+        merchant_id,
+        assumeNotNull(payment_id) AS payment_id,
+        any(platform) AS platform,
+        toUInt8(max(event_name = 'PaymentMethodChanged')) AS payment_method_selected
+    FROM sdk_events
+    WHERE payment_id IS NOT NULL
+    GROUP BY merchant_id, payment_id
+) AS sdk ON sdk.merchant_id = intents.merchant_id
+    AND sdk.payment_id = intents.payment_id;
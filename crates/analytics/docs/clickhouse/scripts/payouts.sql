@@ -2,6 +2,7 @@ CREATE TABLE payout_queue (
     `payout_id` String,
     `payout_attempt_id` String,
     `merchant_id` String,
+    `organization_id` String,
     `customer_id` String,
     `address_id` String,
     `profile_id` String,
@@ -39,6 +40,7 @@ CREATE TABLE payout (
     `payout_id` String,
     `payout_attempt_id` String,
     `merchant_id` String,
+    `organization_id` String,
     `customer_id` String,
     `address_id` String,
     `profile_id` String,
@@ -81,6 +83,7 @@ CREATE MATERIALIZED VIEW payout_mv TO payout (
     `payout_id` String,
     `payout_attempt_id` String,
     `merchant_id` String,
+    `organization_id` String,
     `customer_id` String,
     `address_id` String,
     `profile_id` String,
@@ -114,6 +117,7 @@ SELECT
     payout_id,
     payout_attempt_id,
     merchant_id,
+    organization_id,
     customer_id,
     address_id,
     profile_id,
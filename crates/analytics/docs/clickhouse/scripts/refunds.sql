@@ -56,7 +56,9 @@ CREATE TABLE refunds (
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `organization_id` String,
     `profile_id` String,
+    `metadata_map` Map(String, String),
     `sign_flag` Int8,
+    INDEX metadataValuesIndex mapValues(metadata_map) TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX refundTypeIndex refund_type TYPE bloom_filter GRANULARITY 1,
     INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1,
@@ -91,6 +93,7 @@ CREATE MATERIALIZED VIEW refund_mv TO refunds (
     `inserted_at` DateTime64(3),
     `organization_id` String,
     `profile_id` String,
+    `metadata_map` Map(String, String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -119,6 +122,14 @@ SELECT
     now() AS inserted_at,
     organization_id,
     profile_id,
+    -- payment_intent_metadata is created along with the payment_intents table
+    CAST(
+        JSONExtractKeysAndValues(
+            dictGetOrDefault('payment_intent_metadata', 'metadata', (merchant_id, payment_id), '{}'),
+            'String'
+        ),
+        'Map(String, String)'
+    ) AS metadata_map,
     sign_flag
 FROM
     refund_queue
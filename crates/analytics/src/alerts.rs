@@ -0,0 +1,636 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::analytics::{alerts::AlertRuleFilters, Granularity, TimeRange};
+use common_enums::{AnalyticsAlertDirection, AnalyticsAlertMetric};
+use common_utils::{errors::ReportSwitchExt, id_type::MerchantId};
+use diesel_models::enums::AttemptStatus;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, QueryResult, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsError,
+        MetricsResult,
+    },
+};
+
+/// Flow of the connector calls the latency of a connector is computed from
+const LATENCY_FLOW: &str = "Authorize";
+
+/// Minimum number of baseline windows with a value for a series to be evaluated, the standard
+/// deviation of fewer values is meaningless
+const MIN_BASELINE_WINDOWS: usize = 2;
+
+/// Minimum deviation of the success rate and decline code share, in percentage points, for a
+/// series to be reported. A steady baseline has a standard deviation close to zero, which would
+/// otherwise report every fluctuation.
+const MIN_PERCENTAGE_POINTS_DEVIATION: f64 = 2.0;
+
+/// Minimum deviation of the volume and latency, relative to their baseline mean
+const MIN_RELATIVE_DEVIATION: f64 = 0.1;
+
+pub trait AlertAnalytics: LoadRow<AlertPaymentRow> {}
+
+/// Payment attempts of a window, grouped by the dimensions the alerts are raised for
+#[derive(Debug, serde::Deserialize)]
+pub struct AlertPaymentRow {
+    pub profile_id: Option<String>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub status: Option<DBEnumWrapper<AttemptStatus>>,
+    pub error_code: Option<String>,
+    pub count: Option<i64>,
+}
+
+/// Latency of the calls made to a connector over a window
+#[derive(Debug, serde::Deserialize)]
+pub struct AlertLatencyRow {
+    pub connector_name: Option<String>,
+    pub latency_sum: Option<u64>,
+    pub latency_count: Option<u64>,
+}
+
+/// Dimensions of a series of values an alert rule is evaluated on. Dimensions which do not apply
+/// to the metric of the rule are left empty.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AlertSeriesKey {
+    pub profile_id: Option<String>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub error_code: Option<String>,
+}
+
+/// Value of a series over a window, along with the number of payment attempts, declines or
+/// connector calls it is computed from
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AlertSeriesValue {
+    pub value: f64,
+    pub sample_size: u64,
+}
+
+pub type AlertSeries = HashMap<AlertSeriesKey, AlertSeriesValue>;
+
+/// Conditions under which a series is reported as deviating from its baseline
+#[derive(Clone, Copy, Debug)]
+pub struct AnomalyThreshold {
+    /// Number of standard deviations from the baseline mean
+    pub deviation_threshold: f64,
+    pub direction: AnalyticsAlertDirection,
+    /// Minimum number of payment attempts, declines or connector calls in a window
+    pub min_volume: u64,
+}
+
+/// A series whose value in the latest window deviates from its baseline
+#[derive(Clone, Debug)]
+pub struct AlertAnomaly {
+    pub key: AlertSeriesKey,
+    pub observed: AlertSeriesValue,
+    pub baseline_mean: f64,
+    pub baseline_stddev: f64,
+}
+
+impl<T> QueryFilter<T> for AlertRuleFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause("profile_id", &self.profile_id)
+                .attach_printable("Error adding profile id filter")?;
+        }
+
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause("connector", &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.payment_method.is_empty() {
+            builder
+                .add_filter_in_range_clause("payment_method", &self.payment_method)
+                .attach_printable("Error adding payment method filter")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Computes the series of a payment attempt metric over a window, one series per profile,
+/// connector and payment method, and additionally per error code for the decline code share
+pub async fn load_payment_series<T>(
+    pool: &T,
+    metric: AnalyticsAlertMetric,
+    merchant_id: &MerchantId,
+    filters: &AlertRuleFilters,
+    time_range: &TimeRange,
+) -> MetricsResult<AlertSeries>
+where
+    T: AnalyticsDataSource + AlertAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    const DIMENSIONS: [&str; 5] = [
+        "profile_id",
+        "connector",
+        "payment_method",
+        "status",
+        "error_code",
+    ];
+
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+    for dimension in DIMENSIONS {
+        query_builder.add_select_column(dimension).switch()?;
+    }
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+
+    filters.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    for dimension in DIMENSIONS {
+        query_builder
+            .add_group_by_clause(dimension)
+            .attach_printable("Error grouping by dimensions")
+            .switch()?;
+    }
+
+    let rows = query_builder
+        .execute_query::<AlertPaymentRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)?;
+
+    Ok(aggregate_payment_rows(metric, rows))
+}
+
+#[derive(Default)]
+struct AttemptCounts {
+    total: u64,
+    terminal: u64,
+    succeeded: u64,
+    declined: u64,
+    declined_by_error_code: HashMap<Option<String>, u64>,
+}
+
+fn aggregate_payment_rows(metric: AnalyticsAlertMetric, rows: Vec<AlertPaymentRow>) -> AlertSeries {
+    let mut counts: HashMap<AlertSeriesKey, AttemptCounts> = HashMap::new();
+    for row in rows {
+        // Collapsing tables report cancelled versions of a row with a non positive count
+        let count = match row.count.and_then(|count| u64::try_from(count).ok()) {
+            Some(count) if count > 0 => count,
+            _ => continue,
+        };
+        let attempt_counts = counts
+            .entry(AlertSeriesKey {
+                profile_id: row.profile_id,
+                connector: row.connector,
+                payment_method: row.payment_method,
+                error_code: None,
+            })
+            .or_default();
+
+        attempt_counts.total += count;
+        if let Some(DBEnumWrapper(status)) = row.status {
+            let is_declined = matches!(
+                status,
+                AttemptStatus::Failure | AttemptStatus::AuthorizationFailed
+            );
+            if status.is_terminal_status() || is_declined {
+                attempt_counts.terminal += count;
+            }
+            if status.is_success() {
+                attempt_counts.succeeded += count;
+            }
+            if is_declined {
+                attempt_counts.declined += count;
+                *attempt_counts
+                    .declined_by_error_code
+                    .entry(row.error_code)
+                    .or_default() += count;
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .flat_map(|(key, attempt_counts)| match metric {
+            // Attempts still in flight are left out, so that the latest window is not penalized
+            // for attempts which have not completed yet
+            AnalyticsAlertMetric::SuccessRate => {
+                percentage(attempt_counts.succeeded, attempt_counts.terminal)
+                    .map(|value| {
+                        (
+                            key,
+                            AlertSeriesValue {
+                                value,
+                                sample_size: attempt_counts.terminal,
+                            },
+                        )
+                    })
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            }
+            AnalyticsAlertMetric::DeclineCodeShare => attempt_counts
+                .declined_by_error_code
+                .into_iter()
+                .filter_map(|(error_code, declined)| {
+                    percentage(declined, attempt_counts.declined).map(|value| {
+                        (
+                            AlertSeriesKey {
+                                error_code,
+                                ..key.clone()
+                            },
+                            AlertSeriesValue {
+                                value,
+                                sample_size: attempt_counts.declined,
+                            },
+                        )
+                    })
+                })
+                .collect(),
+            AnalyticsAlertMetric::Volume => vec![(
+                key,
+                AlertSeriesValue {
+                    value: count_to_f64(attempt_counts.total),
+                    sample_size: attempt_counts.total,
+                },
+            )],
+            AnalyticsAlertMetric::Latency => Vec::new(),
+        })
+        .collect()
+}
+
+/// Computes the average latency of the authorize calls made to each connector over a window
+pub async fn load_latency_series<T>(
+    pool: &T,
+    merchant_id: &MerchantId,
+    filters: &AlertRuleFilters,
+    time_range: &TimeRange,
+) -> MetricsResult<AlertSeries>
+where
+    T: AnalyticsDataSource + LoadRow<AlertLatencyRow>,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::ConnectorEvents);
+
+    query_builder.add_select_column("connector_name").switch()?;
+    query_builder
+        .add_select_column(Aggregate::Sum {
+            field: "latency",
+            alias: Some("latency_sum"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: Some("latency"),
+            alias: Some("latency_count"),
+        })
+        .switch()?;
+
+    // Connector events carry neither the profile nor the payment method of the payment
+    if !filters.connector.is_empty() {
+        query_builder
+            .add_filter_in_range_clause("connector_name", &filters.connector)
+            .attach_printable("Error adding connector filter")
+            .switch()?;
+    }
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+    query_builder
+        .add_filter_clause("flow", LATENCY_FLOW)
+        .switch()?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    query_builder
+        .add_group_by_clause("connector_name")
+        .attach_printable("Error grouping by connector")
+        .switch()?;
+
+    Ok(query_builder
+        .execute_query::<AlertLatencyRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)?
+        .into_iter()
+        .filter_map(|row| {
+            let latency_count = row.latency_count.filter(|count| *count > 0)?;
+            let average_latency = row.latency_sum.unwrap_or_default() / latency_count;
+            Some((
+                AlertSeriesKey {
+                    connector: row.connector_name,
+                    ..Default::default()
+                },
+                AlertSeriesValue {
+                    value: count_to_f64(average_latency),
+                    sample_size: latency_count,
+                },
+            ))
+        })
+        .collect())
+}
+
+/// Compares the series of the latest window with the same series over the baseline windows, and
+/// returns the series whose latest value is more than the threshold number of standard deviations
+/// away from their baseline mean in the watched direction
+pub fn detect_anomalies(
+    metric: AnalyticsAlertMetric,
+    latest: &AlertSeries,
+    baseline: &[AlertSeries],
+    threshold: AnomalyThreshold,
+) -> Vec<AlertAnomaly> {
+    // A series missing from a window had no attempts in the window, which is meaningful for the
+    // volume only, the other metrics are undefined in such a window
+    let keys: HashSet<&AlertSeriesKey> = match metric {
+        AnalyticsAlertMetric::Volume => latest
+            .keys()
+            .chain(baseline.iter().flat_map(HashMap::keys))
+            .collect(),
+        AnalyticsAlertMetric::SuccessRate
+        | AnalyticsAlertMetric::DeclineCodeShare
+        | AnalyticsAlertMetric::Latency => latest.keys().collect(),
+    };
+
+    keys.into_iter()
+        .filter_map(|key| {
+            let observed = latest.get(key).copied().unwrap_or_default();
+            let baseline_values: Vec<f64> = baseline
+                .iter()
+                .filter_map(|window| match metric {
+                    AnalyticsAlertMetric::Volume => {
+                        Some(window.get(key).map_or(0.0, |value| value.value))
+                    }
+                    AnalyticsAlertMetric::SuccessRate
+                    | AnalyticsAlertMetric::DeclineCodeShare
+                    | AnalyticsAlertMetric::Latency => window
+                        .get(key)
+                        .filter(|value| value.sample_size >= threshold.min_volume)
+                        .map(|value| value.value),
+                })
+                .collect();
+            let (baseline_mean, baseline_stddev) = mean_and_stddev(&baseline_values)?;
+
+            // The volume of a series which stopped receiving attempts is judged by its baseline
+            let volume = match metric {
+                AnalyticsAlertMetric::Volume => baseline_mean.max(observed.value),
+                AnalyticsAlertMetric::SuccessRate
+                | AnalyticsAlertMetric::DeclineCodeShare
+                | AnalyticsAlertMetric::Latency => count_to_f64(observed.sample_size),
+            };
+            if volume < count_to_f64(threshold.min_volume) {
+                return None;
+            }
+
+            let deviation = observed.value - baseline_mean;
+            let allowed_deviation = (threshold.deviation_threshold * baseline_stddev)
+                .max(get_min_deviation(metric, baseline_mean));
+            let is_anomalous = match threshold.direction {
+                AnalyticsAlertDirection::Drop => -deviation > allowed_deviation,
+                AnalyticsAlertDirection::Rise => deviation > allowed_deviation,
+                AnalyticsAlertDirection::Both => deviation.abs() > allowed_deviation,
+            };
+
+            is_anomalous.then(|| AlertAnomaly {
+                key: key.clone(),
+                observed,
+                baseline_mean,
+                baseline_stddev,
+            })
+        })
+        .collect()
+}
+
+/// Deviation from the baseline mean below which a series is not reported, whatever its standard
+/// deviation
+fn get_min_deviation(metric: AnalyticsAlertMetric, baseline_mean: f64) -> f64 {
+    match metric {
+        AnalyticsAlertMetric::SuccessRate | AnalyticsAlertMetric::DeclineCodeShare => {
+            MIN_PERCENTAGE_POINTS_DEVIATION
+        }
+        // A series with no baseline still needs a change of at least one attempt or millisecond
+        AnalyticsAlertMetric::Volume | AnalyticsAlertMetric::Latency => {
+            (baseline_mean.abs() * MIN_RELATIVE_DEVIATION).max(1.0)
+        }
+    }
+}
+
+/// Mean and sample standard deviation of the values, if there are enough values
+fn mean_and_stddev(values: &[f64]) -> Option<(f64, f64)> {
+    if values.len() < MIN_BASELINE_WINDOWS {
+        return None;
+    }
+    let count = f64::from(u32::try_from(values.len()).ok()?);
+    let mean = values.iter().sum::<f64>() / count;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / (count - 1.0);
+    Some((mean, variance.sqrt()))
+}
+
+fn percentage(part: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| count_to_f64(part) * 100.0 / count_to_f64(total))
+}
+
+/// Converts a count to a float, saturating counts which do not fit in a `u32`
+fn count_to_f64(count: u64) -> f64 {
+    f64::from(u32::try_from(count).unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const THRESHOLD: AnomalyThreshold = AnomalyThreshold {
+        deviation_threshold: 3.0,
+        direction: AnalyticsAlertDirection::Both,
+        min_volume: 20,
+    };
+
+    fn get_key(connector: &str) -> AlertSeriesKey {
+        AlertSeriesKey {
+            connector: Some(connector.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn get_series(values: &[(&str, f64, u64)]) -> AlertSeries {
+        values
+            .iter()
+            .map(|(connector, value, sample_size)| {
+                (
+                    get_key(connector),
+                    AlertSeriesValue {
+                        value: *value,
+                        sample_size: *sample_size,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn get_baseline(values: &[f64], sample_size: u64) -> Vec<AlertSeries> {
+        values
+            .iter()
+            .map(|value| get_series(&[("stripe", *value, sample_size)]))
+            .collect()
+    }
+
+    #[test]
+    fn test_mean_and_stddev() {
+        let (mean, stddev) = mean_and_stddev(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+
+        assert!((mean - 5.0).abs() < 1e-9);
+        assert!((stddev - (32.0_f64 / 7.0).sqrt()).abs() < 1e-9);
+        assert_eq!(mean_and_stddev(&[1.0, 1.0]), Some((1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_mean_and_stddev_requires_enough_values() {
+        assert_eq!(mean_and_stddev(&[]), None);
+        assert_eq!(mean_and_stddev(&[90.0]), None);
+    }
+
+    #[test]
+    fn test_success_rate_drop_is_reported() {
+        let latest = get_series(&[("stripe", 70.0, 100)]);
+        let baseline = get_baseline(&[90.0, 91.0, 89.0, 90.0], 100);
+
+        let anomalies = detect_anomalies(
+            AnalyticsAlertMetric::SuccessRate,
+            &latest,
+            &baseline,
+            THRESHOLD,
+        );
+
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(
+            anomalies.first().map(|anomaly| &anomaly.key),
+            Some(&get_key("stripe"))
+        );
+        assert!(anomalies
+            .first()
+            .is_some_and(|anomaly| (anomaly.baseline_mean - 90.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_small_deviations_from_a_steady_baseline_are_not_reported() {
+        let baseline = get_baseline(&[95.0, 95.0, 95.0, 95.0], 100);
+
+        // The standard deviation of the baseline is zero, only the minimum deviation applies
+        let latest = get_series(&[("stripe", 94.0, 100)]);
+        assert!(detect_anomalies(
+            AnalyticsAlertMetric::SuccessRate,
+            &latest,
+            &baseline,
+            THRESHOLD,
+        )
+        .is_empty());
+
+        let latest = get_series(&[("stripe", 92.0, 100)]);
+        assert_eq!(
+            detect_anomalies(
+                AnalyticsAlertMetric::SuccessRate,
+                &latest,
+                &baseline,
+                THRESHOLD,
+            )
+            .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_small_relative_deviations_of_volume_are_not_reported() {
+        let baseline = get_baseline(&[1000.0, 1000.0, 1000.0], 1000);
+
+        let latest = get_series(&[("stripe", 1050.0, 1050)]);
+        assert!(
+            detect_anomalies(AnalyticsAlertMetric::Volume, &latest, &baseline, THRESHOLD)
+                .is_empty()
+        );
+
+        let latest = get_series(&[("stripe", 1200.0, 1200)]);
+        assert_eq!(
+            detect_anomalies(AnalyticsAlertMetric::Volume, &latest, &baseline, THRESHOLD).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_series_below_the_minimum_volume_are_not_reported() {
+        let latest = get_series(&[("stripe", 0.0, 5)]);
+        let baseline = get_baseline(&[90.0, 91.0, 89.0], 100);
+
+        assert!(detect_anomalies(
+            AnalyticsAlertMetric::SuccessRate,
+            &latest,
+            &baseline,
+            THRESHOLD,
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_volume_of_a_series_which_stopped_is_reported() {
+        let latest = AlertSeries::new();
+        let baseline = get_baseline(&[100.0, 110.0, 90.0], 100);
+
+        let anomalies =
+            detect_anomalies(AnalyticsAlertMetric::Volume, &latest, &baseline, THRESHOLD);
+
+        assert_eq!(anomalies.len(), 1);
+        assert!(anomalies
+            .first()
+            .is_some_and(|anomaly| anomaly.observed.value == 0.0));
+    }
+
+    #[test]
+    fn test_only_the_watched_direction_is_reported() {
+        let latest = get_series(&[("stripe", 70.0, 100)]);
+        let baseline = get_baseline(&[90.0, 91.0, 89.0], 100);
+        let threshold = AnomalyThreshold {
+            direction: AnalyticsAlertDirection::Rise,
+            ..THRESHOLD
+        };
+
+        assert!(detect_anomalies(
+            AnalyticsAlertMetric::SuccessRate,
+            &latest,
+            &baseline,
+            threshold,
+        )
+        .is_empty());
+    }
+}
@@ -12,9 +12,15 @@ use super::{
     frm::{filters::FrmFilterRow, metrics::FrmMetricRow},
     health_check::HealthCheck,
     payment_intents::{filters::PaymentIntentFilterRow, metrics::PaymentIntentMetricRow},
+    payment_sessions::{filters::PaymentSessionFilterRow, metrics::PaymentSessionMetricRow},
     payments::{
         distribution::PaymentDistributionRow, filters::PaymentFilterRow, metrics::PaymentMetricRow,
     },
+    payouts::{
+        distribution::PayoutDistributionRow,
+        filters::PayoutFilterRow,
+        metrics::{PayoutMetricRow, PayoutProcessingTime},
+    },
     query::{Aggregate, ToSql, Window},
     refunds::{
         distribution::RefundDistributionRow, filters::RefundFilterRow, metrics::RefundMetricRow,
@@ -23,6 +29,7 @@ use super::{
     types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
 };
 use crate::{
+    alerts::{AlertLatencyRow, AlertPaymentRow},
     api_event::{
         events::ApiLogsResult,
         filters::ApiEventFilter,
@@ -32,6 +39,10 @@ use crate::{
     connector_events::events::ConnectorEventsResult,
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
+    reports::{
+        AuthenticationReportRow, DisputeReportRow, PaymentReportRow, PayoutReportRow,
+        RefundReportRow,
+    },
     routing_events::events::RoutingEventsResult,
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
@@ -141,9 +152,8 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::PaymentIntent
             | AnalyticsCollection::PaymentIntentSessionized
             | AnalyticsCollection::Authentications
-            | AnalyticsCollection::Dispute => {
-                TableEngine::CollapsingMergeTree { sign: "sign_flag" }
-            }
+            | AnalyticsCollection::Dispute
+            | AnalyticsCollection::Payout => TableEngine::CollapsingMergeTree { sign: "sign_flag" },
             AnalyticsCollection::DisputeSessionized => {
                 TableEngine::CollapsingMergeTree { sign: "sign_flag" }
             }
@@ -154,9 +164,43 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::RoutingEvents
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
-            | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
+            | AnalyticsCollection::ActivePaymentsAnalytics
+            | AnalyticsCollection::PaymentSessions => TableEngine::BasicTree,
         }
     }
+
+    fn get_metadata_dimension(
+        table: AnalyticsCollection,
+        key: &str,
+    ) -> error_stack::Result<String, ParsingError> {
+        match table {
+            // the metadata of the payment is copied into a map column when the event is ingested
+            AnalyticsCollection::Payment
+            | AnalyticsCollection::Refund
+            | AnalyticsCollection::Dispute
+            | AnalyticsCollection::PaymentIntent => Ok(format!("metadata_map['{key}']")),
+            // sessionized tables are populated outside of these scripts, so the metadata is
+            // looked up from the payment intent instead
+            AnalyticsCollection::PaymentSessionized
+            | AnalyticsCollection::RefundSessionized
+            | AnalyticsCollection::DisputeSessionized
+            | AnalyticsCollection::PaymentIntentSessionized => Ok(format!(
+                "JSONExtractString(dictGetOrDefault('payment_intent_metadata', 'metadata', \
+                (merchant_id, payment_id), '{{}}'), '{key}')"
+            )),
+            _ => Err(report!(ParsingError::UnknownError))
+                .attach_printable(format!("Metadata dimensions are not supported for {table}")),
+        }
+    }
+
+    fn get_metadata_dimensions_map(dimensions: &[(&str, String)]) -> String {
+        let entries = dimensions
+            .iter()
+            .map(|(key, expression)| format!("'{key}', {expression}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("map({entries})")
+    }
 }
 
 impl<T, E> LoadRow<T> for ClickhouseClient
@@ -177,6 +221,11 @@ impl super::payment_intents::metrics::PaymentIntentMetricAnalytics for Clickhous
 impl super::refunds::metrics::RefundMetricAnalytics for ClickhouseClient {}
 impl super::refunds::filters::RefundFilterAnalytics for ClickhouseClient {}
 impl super::refunds::distribution::RefundDistributionAnalytics for ClickhouseClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for ClickhouseClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for ClickhouseClient {}
+impl super::payouts::distribution::PayoutDistributionAnalytics for ClickhouseClient {}
+impl super::payment_sessions::metrics::PaymentSessionMetricAnalytics for ClickhouseClient {}
+impl super::payment_sessions::filters::PaymentSessionFilterAnalytics for ClickhouseClient {}
 impl super::frm::metrics::FrmMetricAnalytics for ClickhouseClient {}
 impl super::frm::filters::FrmFilterAnalytics for ClickhouseClient {}
 impl super::sdk_events::filters::SdkEventFilterAnalytics for ClickhouseClient {}
@@ -196,6 +245,8 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::reports::ReportAnalytics for ClickhouseClient {}
+impl super::alerts::AlertAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -209,6 +260,76 @@ struct CkhOutput<T> {
     data: Vec<T>,
 }
 
+impl TryInto<PaymentReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<RefundReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<RefundReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse RefundReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<DisputeReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<DisputeReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse DisputeReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<AuthenticationReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<AuthenticationReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse AuthenticationReportRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<AlertPaymentRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<AlertPaymentRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse AlertPaymentRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<AlertLatencyRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<AlertLatencyRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse AlertLatencyRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<ApiLogsResult> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -329,6 +450,56 @@ impl TryInto<RefundDistributionRow> for serde_json::Value {
     }
 }
 
+impl TryInto<PayoutMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutFilterRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutDistributionRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutDistributionRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutDistributionRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PaymentSessionMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentSessionMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentSessionMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PaymentSessionFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentSessionFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentSessionFilterRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<FrmMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -485,10 +656,18 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
             Self::Authentications => Ok("authentications".to_string()),
             Self::RoutingEvents => Ok("routing_events_audit".to_string()),
+            Self::Payout => Ok("payout".to_string()),
+            Self::PaymentSessions => Ok("payment_sessions".to_string()),
         }
     }
 }
 
+impl ToSql<ClickhouseClient> for PayoutProcessingTime {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok("dateDiff('second', created_at, last_modified_at)".to_string())
+    }
+}
+
 impl<T> ToSql<ClickhouseClient> for Aggregate<T>
 where
     T: ToSql<ClickhouseClient>,
@@ -46,6 +46,16 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_dispute_dimensions(),
         },
+        AnalyticsDomain::Payouts => GetInfoResponse {
+            metrics: utils::get_payout_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_payout_dimensions(),
+        },
+        AnalyticsDomain::PaymentSessions => GetInfoResponse {
+            metrics: utils::get_payment_session_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_payment_session_dimensions(),
+        },
         AnalyticsDomain::Routing => GetInfoResponse {
             metrics: utils::get_payment_metrics_info(),
             download_dimensions: None,
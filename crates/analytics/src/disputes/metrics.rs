@@ -3,7 +3,7 @@ mod sessionized_metrics;
 mod total_amount_disputed;
 mod total_dispute_lost_amount;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use api_models::analytics::{
     disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
@@ -30,6 +30,7 @@ pub struct DisputeMetricRow {
     pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
+    pub metadata_dimensions: Option<BTreeMap<String, String>>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
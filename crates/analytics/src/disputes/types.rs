@@ -30,6 +30,10 @@ where
                 .attach_printable("Error adding currency filter")?;
         }
 
+        self.metadata
+            .set_filter_clause(builder)
+            .attach_printable("Error adding metadata dimension filters")?;
+
         Ok(())
     }
 }
@@ -14,6 +14,24 @@ pub enum AnalyticsError {
     AccessForbiddenError,
     #[error("Failed to fetch currency exchange rate")]
     ForexFetchFailed,
+    #[error("Analytics report not found")]
+    ReportNotFound,
+    #[error("Report download link is invalid or has expired")]
+    InvalidReportDownloadLink,
+    #[error("Invalid report request: {0}")]
+    InvalidReportRequest(String),
+    #[error("Invalid metadata dimensions: {0}")]
+    InvalidMetadataDimensions(String),
+    #[error("Analytics alert rule not found")]
+    AlertRuleNotFound,
+    #[error("Invalid alert rule: {0}")]
+    InvalidAlertRule(String),
+    #[error("Analytics export not found")]
+    ExportNotFound,
+    #[error("Analytics export run not found")]
+    ExportRunNotFound,
+    #[error("Invalid export: {0}")]
+    InvalidExport(String),
 }
 
 impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
@@ -40,6 +58,48 @@ impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
                 "Failed to fetch currency exchange rate",
                 None,
             )),
+            Self::ReportNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "HE",
+                2,
+                "Report does not exist in our records",
+                None,
+            )),
+            Self::InvalidReportDownloadLink => ApiErrorResponse::Unauthorized(ApiError::new(
+                "IR",
+                0,
+                "Report download link is invalid or has expired",
+                None,
+            )),
+            Self::InvalidReportRequest(message) => {
+                ApiErrorResponse::BadRequest(ApiError::new("IR", 0, message, None))
+            }
+            Self::InvalidMetadataDimensions(message) => {
+                ApiErrorResponse::BadRequest(ApiError::new("IR", 0, message, None))
+            }
+            Self::AlertRuleNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "HE",
+                2,
+                "Alert rule does not exist in our records",
+                None,
+            )),
+            Self::InvalidAlertRule(message) => {
+                ApiErrorResponse::BadRequest(ApiError::new("IR", 0, message, None))
+            }
+            Self::ExportNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "HE",
+                2,
+                "Export does not exist in our records",
+                None,
+            )),
+            Self::ExportRunNotFound => ApiErrorResponse::NotFound(ApiError::new(
+                "HE",
+                2,
+                "Export run does not exist in our records",
+                None,
+            )),
+            Self::InvalidExport(message) => {
+                ApiErrorResponse::BadRequest(ApiError::new("IR", 0, message, None))
+            }
         }
     }
 }
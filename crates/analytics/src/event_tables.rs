@@ -0,0 +1,32 @@
+/// Tables into which events written to an event sink are loaded, for the sqlx analytics provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventTable {
+    ApiEvents,
+    ConnectorEvents,
+    OutgoingWebhookEvents,
+    RoutingEvents,
+}
+
+impl EventTable {
+    /// Name of the table in the sqlx analytics database. The tables only store the loaded events,
+    /// the analytics of these events are not served by the sqlx analytics provider.
+    pub fn table_name(self) -> &'static str {
+        match self {
+            Self::ApiEvents => "api_events",
+            Self::ConnectorEvents => "connector_events",
+            Self::OutgoingWebhookEvents => "outgoing_webhook_events",
+            Self::RoutingEvents => "routing_events",
+        }
+    }
+}
+
+/// An event as it is inserted into an event table. The merchant of the event is read from the
+/// `merchant_id` field of the event.
+#[derive(Debug, serde::Serialize)]
+pub struct EventRow {
+    /// Unique identifier of the event, events that were inserted earlier are skipped
+    pub event_id: String,
+    /// Time at which the event was created, in milliseconds since the unix epoch
+    pub created_at: i64,
+    pub event: serde_json::Value,
+}
@@ -0,0 +1,467 @@
+use std::{collections::HashMap, sync::Arc};
+
+use api_models::analytics::{Granularity, TimeRange};
+use common_enums::{AnalyticsExportFormat, AnalyticsReportType};
+use error_stack::{report, ResultExt};
+use parquet::{
+    basic::{LogicalType, Repetition, Type as PhysicalType},
+    data_type::{ByteArray, ByteArrayType},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::types::Type,
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    reports::{
+        self, AuthenticationReportRow, DisputeReportRow, PaymentReportRow, PayoutReportRow,
+        RefundReportRow, ReportAnalytics, ReportError, ReportResult, ReportRow, ReportSink,
+        ReportSummary,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow},
+};
+
+/// Columns of the rows of a dataset, in the order they are written to the export files
+pub fn get_dataset_columns(dataset: AnalyticsReportType) -> &'static [&'static str] {
+    match dataset {
+        AnalyticsReportType::Payments => PaymentReportRow::COLUMNS,
+        AnalyticsReportType::Refunds => RefundReportRow::COLUMNS,
+        AnalyticsReportType::Disputes => DisputeReportRow::COLUMNS,
+        AnalyticsReportType::Payouts => PayoutReportRow::COLUMNS,
+        AnalyticsReportType::Authentications => AuthenticationReportRow::COLUMNS,
+    }
+}
+
+/// Writes the rows of a dataset created within the time range to a file of the export format,
+/// which is written to the sink a page of rows at a time. Only the selected columns are written,
+/// in the order they are selected.
+#[allow(clippy::too_many_arguments)]
+pub async fn generate_export<T>(
+    pool: &T,
+    dataset: AnalyticsReportType,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    filters: &HashMap<String, Vec<String>>,
+    columns: &[String],
+    format: AnalyticsExportFormat,
+    page_size: u64,
+    sink: &mut dyn ReportSink,
+) -> ReportResult<ReportSummary>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let request = ExportRequest {
+        auth,
+        time_range,
+        filters,
+        columns,
+        format,
+        page_size,
+        sink,
+    };
+    match dataset {
+        AnalyticsReportType::Payments => write_export::<PaymentReportRow, T>(pool, request).await,
+        AnalyticsReportType::Refunds => write_export::<RefundReportRow, T>(pool, request).await,
+        AnalyticsReportType::Disputes => write_export::<DisputeReportRow, T>(pool, request).await,
+        AnalyticsReportType::Payouts => write_export::<PayoutReportRow, T>(pool, request).await,
+        AnalyticsReportType::Authentications => {
+            write_export::<AuthenticationReportRow, T>(pool, request).await
+        }
+    }
+}
+
+struct ExportRequest<'a> {
+    auth: &'a AuthInfo,
+    time_range: &'a TimeRange,
+    filters: &'a HashMap<String, Vec<String>>,
+    columns: &'a [String],
+    format: AnalyticsExportFormat,
+    page_size: u64,
+    sink: &'a mut dyn ReportSink,
+}
+
+async fn write_export<R, T>(pool: &T, request: ExportRequest<'_>) -> ReportResult<ReportSummary>
+where
+    R: ReportRow,
+    T: AnalyticsDataSource + LoadRow<R>,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    if let Some(column) = request
+        .columns
+        .iter()
+        .find(|column| !R::COLUMNS.contains(&column.as_str()))
+    {
+        return Err(report!(ReportError::QueryBuildingError))
+            .attach_printable(format!("Unknown report column {column}"));
+    }
+
+    let mut writer = ExportWriter::new(request.format, request.columns)?;
+
+    let mut row_count = 0;
+    let mut file_size = 0;
+    loop {
+        let rows = reports::load_report_page::<R, T>(
+            pool,
+            request.auth,
+            request.time_range,
+            request.filters,
+            request.page_size,
+            row_count,
+        )
+        .await?;
+        let page_row_count = u64::try_from(rows.len())
+            .change_context(ReportError::FileWriteFailure)
+            .attach_printable("Page size exceeds u64")?;
+
+        let page = rows
+            .into_iter()
+            .map(|row| select_columns(row, request.columns))
+            .collect::<ReportResult<Vec<_>>>()?;
+        let part = writer.write_page(request.columns, page)?;
+        file_size += write_part(request.sink, part).await?;
+        row_count += page_row_count;
+
+        if page_row_count < request.page_size {
+            break;
+        }
+    }
+    let part = writer.finish()?;
+    file_size += write_part(request.sink, part).await?;
+
+    Ok(ReportSummary {
+        file_size,
+        row_count,
+    })
+}
+
+/// Writes a part of the file to the sink, returning its size
+async fn write_part(sink: &mut dyn ReportSink, part: Vec<u8>) -> ReportResult<u64> {
+    if part.is_empty() {
+        return Ok(0);
+    }
+    let part_size = u64::try_from(part.len())
+        .change_context(ReportError::FileWriteFailure)
+        .attach_printable("Export part size exceeds u64")?;
+    sink.write_part(part).await?;
+    Ok(part_size)
+}
+
+/// Values of the selected columns of a row, in the order the columns are selected
+fn select_columns<R: ReportRow>(
+    row: R,
+    columns: &[String],
+) -> ReportResult<Vec<serde_json::Value>> {
+    let mut row = match serde_json::to_value(row).change_context(ReportError::FileWriteFailure)? {
+        serde_json::Value::Object(row) => row,
+        _ => Err(report!(ReportError::FileWriteFailure))
+            .attach_printable("Report row is not serialized as an object")?,
+    };
+    Ok(columns
+        .iter()
+        .map(|column| row.remove(column).unwrap_or(serde_json::Value::Null))
+        .collect())
+}
+
+/// Writes the pages of rows of an export file. Each page is returned as the part of the file it
+/// adds, so that the file is never held in memory as a whole.
+enum ExportWriter {
+    /// The header row is written along with the first page
+    Csv {
+        is_header_written: bool,
+    },
+    Jsonl,
+    Parquet(SerializedFileWriter<Vec<u8>>),
+}
+
+impl ExportWriter {
+    fn new(format: AnalyticsExportFormat, columns: &[String]) -> ReportResult<Self> {
+        match format {
+            AnalyticsExportFormat::Csv => Ok(Self::Csv {
+                is_header_written: false,
+            }),
+            AnalyticsExportFormat::Jsonl => Ok(Self::Jsonl),
+            AnalyticsExportFormat::Parquet => {
+                // Every column is written as an optional string, which keeps the file schema
+                // independent of the data source the rows are read from
+                let fields = columns
+                    .iter()
+                    .map(|column| {
+                        Type::primitive_type_builder(column, PhysicalType::BYTE_ARRAY)
+                            .with_repetition(Repetition::OPTIONAL)
+                            .with_logical_type(Some(LogicalType::String))
+                            .build()
+                            .map(Arc::new)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(parquet_error)?;
+                let schema = Type::group_type_builder("schema")
+                    .with_fields(fields)
+                    .build()
+                    .map_err(parquet_error)?;
+                SerializedFileWriter::new(
+                    Vec::new(),
+                    Arc::new(schema),
+                    Arc::new(WriterProperties::builder().build()),
+                )
+                .map(Self::Parquet)
+                .map_err(parquet_error)
+            }
+        }
+    }
+
+    fn write_page(
+        &mut self,
+        columns: &[String],
+        page: Vec<Vec<serde_json::Value>>,
+    ) -> ReportResult<Vec<u8>> {
+        match self {
+            Self::Csv { is_header_written } => {
+                let mut writer = csv::WriterBuilder::new()
+                    .has_headers(false)
+                    .from_writer(Vec::new());
+                if !*is_header_written {
+                    writer
+                        .write_record(columns)
+                        .change_context(ReportError::FileWriteFailure)?;
+                    *is_header_written = true;
+                }
+                for row in page {
+                    writer
+                        .write_record(row.iter().map(value_to_string))
+                        .change_context(ReportError::FileWriteFailure)?;
+                }
+                writer.into_inner().map_err(|error| {
+                    report!(ReportError::FileWriteFailure).attach_printable(error.to_string())
+                })
+            }
+            Self::Jsonl => {
+                let mut part = Vec::new();
+                for row in page {
+                    let object = columns
+                        .iter()
+                        .cloned()
+                        .zip(row)
+                        .collect::<serde_json::Map<_, _>>();
+                    serde_json::to_writer(&mut part, &object)
+                        .change_context(ReportError::FileWriteFailure)?;
+                    part.push(b'\n');
+                }
+                Ok(part)
+            }
+            Self::Parquet(writer) => {
+                if !page.is_empty() {
+                    write_parquet_row_group(writer, page)?;
+                }
+                // The writer keeps track of the offsets of the row groups itself, the bytes it
+                // has written so far can be taken out of its buffer
+                Ok(std::mem::take(writer.inner_mut()))
+            }
+        }
+    }
+
+    /// Completes the file, returning its remaining part
+    fn finish(self) -> ReportResult<Vec<u8>> {
+        match self {
+            Self::Csv { .. } | Self::Jsonl => Ok(Vec::new()),
+            Self::Parquet(mut writer) => {
+                writer.finish().map_err(parquet_error)?;
+                Ok(std::mem::take(writer.inner_mut()))
+            }
+        }
+    }
+}
+
+/// Writes a page of rows as a row group
+fn write_parquet_row_group(
+    writer: &mut SerializedFileWriter<Vec<u8>>,
+    page: Vec<Vec<serde_json::Value>>,
+) -> ReportResult<()> {
+    let mut row_group = writer.next_row_group().map_err(parquet_error)?;
+    let mut column_index = 0;
+    while let Some(mut column_writer) = row_group.next_column().map_err(parquet_error)? {
+        let mut values = Vec::with_capacity(page.len());
+        let mut definition_levels = Vec::with_capacity(page.len());
+        for row in &page {
+            match row.get(column_index) {
+                Some(serde_json::Value::Null) | None => definition_levels.push(0),
+                Some(value) => {
+                    values.push(ByteArray::from(value_to_string(value).into_bytes()));
+                    definition_levels.push(1);
+                }
+            }
+        }
+        column_writer
+            .typed::<ByteArrayType>()
+            .write_batch(&values, Some(&definition_levels), None)
+            .map_err(parquet_error)?;
+        column_writer.close().map_err(parquet_error)?;
+        column_index += 1;
+    }
+    row_group.close().map_err(parquet_error)?;
+    Ok(())
+}
+
+fn value_to_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn parquet_error(error: parquet::errors::ParquetError) -> error_stack::Report<ReportError> {
+    report!(ReportError::FileWriteFailure).attach_printable(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use diesel_models::enums::{Currency, RefundStatus};
+    use serde_json::json;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::types::DBEnumWrapper;
+
+    struct PartCollector(Vec<Vec<u8>>);
+
+    #[async_trait::async_trait]
+    impl ReportSink for PartCollector {
+        async fn write_part(&mut self, part: Vec<u8>) -> ReportResult<()> {
+            self.0.push(part);
+            Ok(())
+        }
+    }
+
+    fn get_columns() -> Vec<String> {
+        ["refund_id", "refund_amount", "refund_reason"]
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    fn get_pages() -> Vec<Vec<Vec<serde_json::Value>>> {
+        vec![
+            vec![
+                vec![json!("ref_1"), json!(100), serde_json::Value::Null],
+                vec![json!("ref_2"), json!(250), json!("duplicate, charge")],
+            ],
+            vec![vec![json!("ref_3"), json!(75), json!("fraudulent")]],
+        ]
+    }
+
+    /// Parts of the file written for the pages, followed by the part written on completion
+    fn write_file(format: AnalyticsExportFormat) -> Vec<Vec<u8>> {
+        let columns = get_columns();
+        let mut writer = ExportWriter::new(format, &columns).unwrap();
+        let mut parts = get_pages()
+            .into_iter()
+            .map(|page| writer.write_page(&columns, page).unwrap())
+            .collect::<Vec<_>>();
+        parts.push(writer.finish().unwrap());
+        parts
+    }
+
+    #[test]
+    fn test_columns_are_selected_in_order() {
+        let row = RefundReportRow {
+            refund_id: "ref_1".to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            profile_id: None,
+            connector: Some("stripe".to_string()),
+            refund_status: DBEnumWrapper(RefundStatus::Success),
+            refund_amount: Some(100),
+            currency: Some(DBEnumWrapper(Currency::USD)),
+            refund_reason: None,
+            refund_error_message: None,
+            created_at: datetime!(2024-01-02 10:00:00),
+            modified_at: datetime!(2024-01-02 10:05:00),
+        };
+        let columns = ["refund_amount", "refund_id", "refund_reason", "connector"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            select_columns(row, &columns).unwrap(),
+            [
+                json!(100),
+                json!("ref_1"),
+                serde_json::Value::Null,
+                json!("stripe")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv_header_is_written_with_the_first_page() {
+        let parts = write_file(AnalyticsExportFormat::Csv);
+
+        assert_eq!(
+            parts,
+            [
+                b"refund_id,refund_amount,refund_reason\nref_1,100,\nref_2,250,\"duplicate, charge\"\n"
+                    .to_vec(),
+                b"ref_3,75,fraudulent\n".to_vec(),
+                Vec::new(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jsonl_rows_are_written_as_objects_of_the_selected_columns() {
+        let file = write_file(AnalyticsExportFormat::Jsonl).concat();
+        let rows = String::from_utf8(file)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            [
+                json!({"refund_id": "ref_1", "refund_amount": 100, "refund_reason": null}),
+                json!({"refund_id": "ref_2", "refund_amount": 250, "refund_reason": "duplicate, charge"}),
+                json!({"refund_id": "ref_3", "refund_amount": 75, "refund_reason": "fraudulent"}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parquet_parts_add_up_to_the_whole_file() {
+        let columns = get_columns();
+        let mut writer = ExportWriter::new(AnalyticsExportFormat::Parquet, &columns).unwrap();
+        if let ExportWriter::Parquet(writer) = &mut writer {
+            for page in get_pages() {
+                write_parquet_row_group(writer, page).unwrap();
+            }
+        }
+        let whole_file = writer.finish().unwrap();
+
+        let file = write_file(AnalyticsExportFormat::Parquet).concat();
+
+        assert!(file.starts_with(b"PAR1"));
+        assert!(file.ends_with(b"PAR1"));
+        assert_eq!(file, whole_file);
+    }
+
+    #[tokio::test]
+    async fn test_empty_parts_are_not_written() {
+        let mut sink = PartCollector(Vec::new());
+
+        assert_eq!(write_part(&mut sink, Vec::new()).await.unwrap(), 0);
+        assert_eq!(write_part(&mut sink, b"ref_1\n".to_vec()).await.unwrap(), 6);
+        assert_eq!(sink.0, [b"ref_1\n".to_vec()]);
+    }
+}
@@ -1,4 +1,5 @@
 pub mod active_payments;
+pub mod alerts;
 pub mod api_event;
 pub mod auth_events;
 mod clickhouse;
@@ -7,15 +8,20 @@ pub mod core;
 pub mod disputes;
 pub mod enums;
 pub mod errors;
+pub mod event_tables;
+pub mod exports;
 pub mod frm;
 pub mod health_check;
 pub mod metrics;
 pub mod opensearch;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
+pub mod payment_sessions;
 pub mod payments;
+pub mod payouts;
 mod query;
 pub mod refunds;
+pub mod reports;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
@@ -35,10 +41,14 @@ pub use types::AnalyticsDomain;
 pub mod lambda_utils;
 pub mod utils;
 
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use api_models::analytics::{
     active_payments::{ActivePaymentsMetrics, ActivePaymentsMetricsBucketIdentifier},
+    alerts::AlertRuleFilters,
     api_event::{
         ApiEventDimensions, ApiEventFilters, ApiEventMetrics, ApiEventMetricsBucketIdentifier,
     },
@@ -51,22 +61,28 @@ use api_models::analytics::{
         PaymentIntentDimensions, PaymentIntentFilters, PaymentIntentMetrics,
         PaymentIntentMetricsBucketIdentifier,
     },
+    payment_sessions::{
+        PaymentSessionDimensions, PaymentSessionFilters, PaymentSessionMetrics,
+        PaymentSessionMetricsBucketIdentifier,
+    },
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
     sdk_events::{
         SdkEventDimensions, SdkEventFilters, SdkEventMetrics, SdkEventMetricsBucketIdentifier,
     },
-    Granularity, PaymentDistributionBody, RefundDistributionBody, TimeRange,
+    Granularity, PaymentDistributionBody, PayoutDistributionBody, RefundDistributionBody,
+    TimeRange,
 };
 use clickhouse::ClickhouseClient;
 pub use clickhouse::ClickhouseConfig;
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{
     logger,
     tracing::{self, instrument},
     types::FlowMetric,
 };
-use storage_impl::config::Database;
+use storage_impl::{config::Database, errors::ApplicationError};
 use strum::Display;
 
 use self::{
@@ -74,10 +90,15 @@ use self::{
     auth_events::metrics::{AuthEventMetric, AuthEventMetricRow},
     frm::metrics::{FrmMetric, FrmMetricRow},
     payment_intents::metrics::{PaymentIntentMetric, PaymentIntentMetricRow},
+    payment_sessions::metrics::{PaymentSessionMetric, PaymentSessionMetricRow},
     payments::{
         distribution::{PaymentDistribution, PaymentDistributionRow},
         metrics::{PaymentMetric, PaymentMetricRow},
     },
+    payouts::{
+        distribution::{PayoutDistribution, PayoutDistributionRow},
+        metrics::{PayoutMetric, PayoutMetricRow},
+    },
     refunds::metrics::{RefundMetric, RefundMetricRow},
     sdk_events::metrics::{SdkEventMetric, SdkEventMetricRow},
     sqlx::SqlxClient,
@@ -642,6 +663,291 @@ impl AnalyticsProvider {
         .await
     }
 
+    pub async fn get_payout_metrics(
+        &self,
+        metric: &PayoutMetrics,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        // Metrics to get the fetch time for each payout metric
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
+    pub async fn get_payout_distribution(
+        &self,
+        distribution: &PayoutDistributionBody,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<Vec<(PayoutMetricsBucketIdentifier, PayoutDistributionRow)>> {
+        // Metrics to get the fetch time for each payout distribution
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        distribution
+                            .distribution_for
+                            .load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                pool,
+                            )
+                            .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        distribution
+                            .distribution_for
+                            .load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                pool,
+                            )
+                            .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics distribution")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics distribution")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            &distribution.distribution_for,
+            self,
+        )
+        .await
+    }
+
+    pub async fn get_payment_session_metrics(
+        &self,
+        metric: &PaymentSessionMetrics,
+        dimensions: &[PaymentSessionDimensions],
+        auth: &AuthInfo,
+        filters: &PaymentSessionFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<
+        HashSet<(
+            PaymentSessionMetricsBucketIdentifier,
+            PaymentSessionMetricRow,
+        )>,
+    > {
+        // Metrics to get the fetch time for each payment session metric
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        metric
+                            .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                            .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payment sessions analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payment sessions analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
     pub async fn get_frm_metrics(
         &self,
         metric: &FrmMetrics,
@@ -969,6 +1275,108 @@ impl AnalyticsProvider {
         }
     }
 
+    /// Computes the series an alert rule is evaluated on over a window. Connector latencies are
+    /// loaded from clickhouse only, postgres stores connector events as raw documents.
+    #[instrument(skip_all)]
+    pub async fn get_alert_series(
+        &self,
+        metric: common_enums::AnalyticsAlertMetric,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &AlertRuleFilters,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<alerts::AlertSeries> {
+        match metric {
+            common_enums::AnalyticsAlertMetric::Latency => match self {
+                Self::Sqlx(_pool) => Err(report!(MetricsError::NotImplemented)),
+                Self::Clickhouse(ckh_pool)
+                | Self::CombinedCkh(_, ckh_pool)
+                | Self::CombinedSqlx(_, ckh_pool) => {
+                    alerts::load_latency_series(ckh_pool, merchant_id, filters, time_range).await
+                }
+            },
+            common_enums::AnalyticsAlertMetric::SuccessRate
+            | common_enums::AnalyticsAlertMetric::DeclineCodeShare
+            | common_enums::AnalyticsAlertMetric::Volume => match self {
+                Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) => {
+                    alerts::load_payment_series(pool, metric, merchant_id, filters, time_range)
+                        .await
+                }
+                Self::Clickhouse(pool) | Self::CombinedCkh(_, pool) => {
+                    alerts::load_payment_series(pool, metric, merchant_id, filters, time_range)
+                        .await
+                }
+            },
+        }
+    }
+
+    /// Generates the report of the domain from the primary data source of the provider, writing
+    /// it to the sink as it is generated
+    #[instrument(skip_all)]
+    pub async fn generate_report(
+        &self,
+        report_type: common_enums::AnalyticsReportType,
+        auth: &AuthInfo,
+        time_range: &TimeRange,
+        page_size: u64,
+        sink: &mut dyn reports::ReportSink,
+    ) -> types::ReportResult<reports::ReportSummary> {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) => {
+                reports::generate_report(pool, report_type, auth, time_range, page_size, sink).await
+            }
+            Self::Clickhouse(pool) | Self::CombinedCkh(_, pool) => {
+                reports::generate_report(pool, report_type, auth, time_range, page_size, sink).await
+            }
+        }
+    }
+
+    /// Generates the export file of the dataset from the primary data source of the provider,
+    /// writing it to the sink
+    #[allow(clippy::too_many_arguments)]
+    #[instrument(skip_all)]
+    pub async fn generate_export(
+        &self,
+        dataset: common_enums::AnalyticsReportType,
+        auth: &AuthInfo,
+        time_range: &TimeRange,
+        filters: &HashMap<String, Vec<String>>,
+        columns: &[String],
+        format: common_enums::AnalyticsExportFormat,
+        page_size: u64,
+        sink: &mut dyn reports::ReportSink,
+    ) -> types::ReportResult<reports::ReportSummary> {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) => {
+                exports::generate_export(
+                    pool, dataset, auth, time_range, filters, columns, format, page_size, sink,
+                )
+                .await
+            }
+            Self::Clickhouse(pool) | Self::CombinedCkh(_, pool) => {
+                exports::generate_export(
+                    pool, dataset, auth, time_range, filters, columns, format, page_size, sink,
+                )
+                .await
+            }
+        }
+    }
+
+    /// Inserts events loaded from an event sink into an event table. Returns the number of events
+    /// inserted, which excludes the events that were inserted earlier.
+    pub async fn insert_events(
+        &self,
+        table: event_tables::EventTable,
+        rows: &[event_tables::EventRow],
+    ) -> types::MetricsResult<u64> {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedSqlx(pool, _) | Self::CombinedCkh(pool, _) => pool
+                .insert_events(table, rows)
+                .await
+                .change_context(MetricsError::QueryExecutionFailure),
+            Self::Clickhouse(_) => Err(report!(MetricsError::NotImplemented)),
+        }
+    }
+
     pub async fn from_conf(config: &AnalyticsConfig, tenant: &dyn TenantConfig) -> Self {
         match config {
             AnalyticsConfig::Sqlx { sqlx, .. } => {
@@ -1112,7 +1520,8 @@ impl Default for AnalyticsConfig {
     }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, Default, serde::Serialize)]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
 pub struct ReportConfig {
     pub payment_function: String,
     pub refund_function: String,
@@ -1120,6 +1529,64 @@ pub struct ReportConfig {
     pub authentication_function: String,
     pub payout_function: String,
     pub region: String,
+    /// Engine the reports are generated by
+    pub engine: ReportEngine,
+    /// Number of rows read from the analytics data source at a time by the in-process engine
+    pub page_size: u64,
+    /// Validity of the download links of reports generated by the in-process engine
+    pub download_link_expiry_in_secs: u64,
+    /// Key the download links and the webhook notifications of reports are signed with
+    #[serde(skip_serializing)]
+    pub signing_key: masking::Secret<String>,
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            payment_function: String::default(),
+            refund_function: String::default(),
+            dispute_function: String::default(),
+            authentication_function: String::default(),
+            payout_function: String::default(),
+            region: String::default(),
+            engine: ReportEngine::default(),
+            page_size: 10_000,
+            download_link_expiry_in_secs: 60 * 60 * 24, // 24 hours
+            signing_key: masking::Secret::default(),
+        }
+    }
+}
+
+impl ReportConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        if self.engine == ReportEngine::InProcess {
+            when(self.signing_key.is_default_or_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Report signing key must not be empty".into(),
+                ))
+            })?;
+
+            when(self.page_size == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Report page size must be greater than zero".into(),
+                ))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportEngine {
+    /// Reports are generated by AWS Lambda functions, which email the report when it is ready
+    #[default]
+    Lambda,
+    /// Reports are generated by the scheduler and stored in the file storage of the application
+    InProcess,
 }
 
 /// Analytics Flow routes Enums
@@ -1132,6 +1599,8 @@ pub enum AnalyticsFlow {
     GetPaymentMetrics,
     GetPaymentIntentMetrics,
     GetRefundsMetrics,
+    GetPayoutsMetrics,
+    GetPaymentSessionsMetrics,
     GetFrmMetrics,
     GetSdkMetrics,
     GetAuthMetrics,
@@ -1140,6 +1609,10 @@ pub enum AnalyticsFlow {
     GetPaymentFilters,
     GetPaymentIntentFilters,
     GetRefundFilters,
+    GetPayoutFilters,
+    GetPaymentSessionFilters,
+    GetMetadataDimensions,
+    UpdateMetadataDimensions,
     GetFrmFilters,
     GetSdkEventFilters,
     GetApiEvents,
@@ -1149,6 +1622,8 @@ pub enum AnalyticsFlow {
     GenerateRefundReport,
     GenerateAuthenticationReport,
     GeneratePayoutReport,
+    GetReport,
+    DownloadReport,
     GetApiEventMetrics,
     GetApiEventFilters,
     GetConnectorEvents,
@@ -1159,6 +1634,19 @@ pub enum AnalyticsFlow {
     GetDisputeMetrics,
     GetSankey,
     GetRoutingEvents,
+    CreateAlertRule,
+    ListAlertRules,
+    GetAlertRule,
+    UpdateAlertRule,
+    DeleteAlertRule,
+    ListAlertHistory,
+    CreateExport,
+    ListExports,
+    GetExport,
+    UpdateExport,
+    DeleteExport,
+    ListExportRuns,
+    RetryExportRun,
 }
 
 impl FlowMetric for AnalyticsFlow {}
@@ -0,0 +1,98 @@
+use api_models::analytics::payment_sessions::PaymentSessionMetricsBucketValue;
+
+use super::metrics::PaymentSessionMetricRow;
+
+#[derive(Debug, Default)]
+pub struct PaymentSessionMetricsAccumulator {
+    pub sessions_started: CountAccumulator,
+    pub payment_method_selected: CountAccumulator,
+    pub payment_attempted: CountAccumulator,
+    pub authentication_started: CountAccumulator,
+    pub authentication_completed: CountAccumulator,
+    pub sessions_converted: CountAccumulator,
+    pub conversion_rate: ConversionRateAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct ConversionRateAccumulator {
+    pub converted: u32,
+    pub total: u32,
+}
+
+pub trait PaymentSessionMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentSessionMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+impl PaymentSessionMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PaymentSessionMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl PaymentSessionMetricAccumulator for ConversionRateAccumulator {
+    type MetricOutput = (Option<u32>, Option<u32>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentSessionMetricRow) {
+        if let Some(converted) = metrics
+            .converted_count
+            .and_then(|converted| u32::try_from(converted).ok())
+        {
+            self.converted += converted;
+        }
+        if let Some(total) = metrics.count.and_then(|total| u32::try_from(total).ok()) {
+            self.total += total;
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total == 0 {
+            (None, None, None)
+        } else {
+            let converted = Some(self.converted);
+            let total = Some(self.total);
+            let conversion_rate = match (converted, total) {
+                (Some(c), Some(t)) if t > 0 => Some(f64::from(c) * 100.0 / f64::from(t)),
+                _ => None,
+            };
+            (converted, total, conversion_rate)
+        }
+    }
+}
+
+impl PaymentSessionMetricsAccumulator {
+    pub fn collect(self) -> PaymentSessionMetricsBucketValue {
+        let (converted_sessions, total_sessions, session_conversion_rate) =
+            self.conversion_rate.collect();
+        PaymentSessionMetricsBucketValue {
+            converted_sessions,
+            total_sessions,
+            session_conversion_rate,
+            sessions_started: self.sessions_started.collect(),
+            payment_method_selected_count: self.payment_method_selected.collect(),
+            payment_attempted_count: self.payment_attempted.collect(),
+            authentication_started_count: self.authentication_started.collect(),
+            authentication_completed_count: self.authentication_completed.collect(),
+            sessions_converted: self.sessions_converted.collect(),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    payment_sessions::{
+        PaymentSessionDimensions, PaymentSessionMetrics, PaymentSessionMetricsBucketIdentifier,
+        PaymentSessionMetricsBucketResponse,
+    },
+    GetPaymentSessionFilterRequest, GetPaymentSessionMetricRequest, PaymentSessionFilterValue,
+    PaymentSessionFiltersResponse, PaymentSessionsAnalyticsMetadata,
+    PaymentSessionsMetricsResponse,
+};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::{
+    filters::{get_payment_session_filter_for_dimension, PaymentSessionFilterRow},
+    PaymentSessionMetricAccumulator, PaymentSessionMetricsAccumulator,
+};
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics, AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetPaymentSessionMetricRequest,
+) -> AnalyticsResult<PaymentSessionsMetricsResponse<PaymentSessionMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        PaymentSessionMetricsBucketIdentifier,
+        PaymentSessionMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_payment_session_query",
+            payment_session_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_payment_session_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                PaymentSessionMetrics::SessionsStarted => {
+                    metrics_builder.sessions_started.add_metrics_bucket(&value)
+                }
+                PaymentSessionMetrics::PaymentMethodSelectedCount => metrics_builder
+                    .payment_method_selected
+                    .add_metrics_bucket(&value),
+                PaymentSessionMetrics::PaymentAttemptedCount => {
+                    metrics_builder.payment_attempted.add_metrics_bucket(&value)
+                }
+                PaymentSessionMetrics::AuthenticationStartedCount => metrics_builder
+                    .authentication_started
+                    .add_metrics_bucket(&value),
+                PaymentSessionMetrics::AuthenticationCompletedCount => metrics_builder
+                    .authentication_completed
+                    .add_metrics_bucket(&value),
+                PaymentSessionMetrics::SessionsConverted => metrics_builder
+                    .sessions_converted
+                    .add_metrics_bucket(&value),
+                PaymentSessionMetrics::SessionConversionRate => {
+                    metrics_builder.conversion_rate.add_metrics_bucket(&value)
+                }
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+
+    let mut converted = 0;
+    let mut total = 0;
+    let query_data: Vec<PaymentSessionMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| {
+            let collected_values = val.collect();
+            if let Some(converted_count) = collected_values.converted_sessions {
+                converted += converted_count;
+            }
+            if let Some(total_count) = collected_values.total_sessions {
+                total += total_count;
+            }
+            PaymentSessionMetricsBucketResponse {
+                values: collected_values,
+                dimensions: id,
+            }
+        })
+        .collect();
+    let total_session_conversion_rate = match (converted, total) {
+        (c, t) if t > 0 => Some(f64::from(c) * 100.0 / f64::from(t)),
+        _ => None,
+    };
+    Ok(PaymentSessionsMetricsResponse {
+        query_data,
+        meta_data: [PaymentSessionsAnalyticsMetadata {
+            total_sessions_started: (total > 0).then_some(u64::from(total)),
+            total_sessions_converted: (total > 0).then_some(u64::from(converted)),
+            total_session_conversion_rate,
+        }],
+    })
+}
+
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetPaymentSessionFilterRequest,
+    auth: &AuthInfo,
+) -> AnalyticsResult<PaymentSessionFiltersResponse> {
+    let mut res = PaymentSessionFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+            AnalyticsProvider::Sqlx(pool) => {
+                get_payment_session_filter_for_dimension(dim, auth, &req.time_range, pool).await
+            }
+            AnalyticsProvider::Clickhouse(pool) => {
+                get_payment_session_filter_for_dimension(dim, auth, &req.time_range, pool).await
+            }
+            AnalyticsProvider::CombinedCkh(sqlx_pool, ckh_pool) => {
+                let ckh_result =
+                    get_payment_session_filter_for_dimension(dim, auth, &req.time_range, ckh_pool)
+                        .await;
+                let sqlx_result =
+                    get_payment_session_filter_for_dimension(dim, auth, &req.time_range, sqlx_pool)
+                        .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payment sessions analytics filters")
+                    }
+                    _ => {}
+                };
+                ckh_result
+            }
+            AnalyticsProvider::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                let ckh_result =
+                    get_payment_session_filter_for_dimension(dim, auth, &req.time_range, ckh_pool)
+                        .await;
+                let sqlx_result =
+                    get_payment_session_filter_for_dimension(dim, auth, &req.time_range, sqlx_pool)
+                        .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payment sessions analytics filters")
+                    }
+                    _ => {}
+                };
+                sqlx_result
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: PaymentSessionFilterRow| match dim {
+            PaymentSessionDimensions::PaymentMethod => fil.payment_method,
+            PaymentSessionDimensions::FirstPaymentMethod => fil.first_payment_method,
+            PaymentSessionDimensions::Connector => fil.connector,
+            PaymentSessionDimensions::Platform => fil.platform,
+            PaymentSessionDimensions::Country => fil.country,
+            PaymentSessionDimensions::Currency => fil.currency.map(|i| i.as_ref().to_string()),
+            PaymentSessionDimensions::ProfileId => fil.profile_id,
+            PaymentSessionDimensions::LastFunnelStep => fil.last_funnel_step,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(PaymentSessionFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
@@ -0,0 +1,60 @@
+use api_models::analytics::{payment_sessions::PaymentSessionDimensions, Granularity, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums::Currency;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
+        LoadRow,
+    },
+};
+pub trait PaymentSessionFilterAnalytics: LoadRow<PaymentSessionFilterRow> {}
+
+pub async fn get_payment_session_filter_for_dimension<T>(
+    dimension: PaymentSessionDimensions,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    pool: &T,
+) -> FiltersResult<Vec<PaymentSessionFilterRow>>
+where
+    T: AnalyticsDataSource + PaymentSessionFilterAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::PaymentSessions);
+
+    query_builder.add_select_column(dimension).switch()?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder.set_distinct();
+
+    query_builder
+        .execute_query::<PaymentSessionFilterRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct PaymentSessionFilterRow {
+    pub payment_method: Option<String>,
+    pub first_payment_method: Option<String>,
+    pub connector: Option<String>,
+    pub platform: Option<String>,
+    pub country: Option<String>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub profile_id: Option<String>,
+    pub last_funnel_step: Option<String>,
+}
@@ -0,0 +1,106 @@
+use api_models::analytics::{
+    payment_sessions::{
+        PaymentSessionDimensions, PaymentSessionFilters, PaymentSessionMetrics,
+        PaymentSessionMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+mod funnel_step_count;
+mod session_conversion_rate;
+use std::collections::HashSet;
+
+use funnel_step_count::FunnelStepCount;
+use session_conversion_rate::SessionConversionRate;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PaymentSessionMetricRow {
+    pub payment_method: Option<String>,
+    pub first_payment_method: Option<String>,
+    pub connector: Option<String>,
+    pub platform: Option<String>,
+    pub country: Option<String>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub profile_id: Option<String>,
+    pub last_funnel_step: Option<String>,
+    pub count: Option<i64>,
+    pub converted_count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PaymentSessionMetricAnalytics: LoadRow<PaymentSessionMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PaymentSessionMetric<T>
+where
+    T: AnalyticsDataSource + PaymentSessionMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PaymentSessionDimensions],
+        auth: &AuthInfo,
+        filters: &PaymentSessionFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            PaymentSessionMetricsBucketIdentifier,
+            PaymentSessionMetricRow,
+        )>,
+    >;
+}
+
+#[async_trait::async_trait]
+impl<T> PaymentSessionMetric<T> for PaymentSessionMetrics
+where
+    T: AnalyticsDataSource + PaymentSessionMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PaymentSessionDimensions],
+        auth: &AuthInfo,
+        filters: &PaymentSessionFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<
+        HashSet<(
+            PaymentSessionMetricsBucketIdentifier,
+            PaymentSessionMetricRow,
+        )>,
+    > {
+        match self.get_funnel_step() {
+            Some(step) => {
+                FunnelStepCount { step }
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            None => {
+                SessionConversionRate
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use api_models::analytics::payment_sessions::{PaymentSessionDimensions, PaymentSessionFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for PaymentSessionFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.payment_method.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentSessionDimensions::PaymentMethod,
+                    &self.payment_method,
+                )
+                .attach_printable("Error adding payment method filter")?;
+        }
+
+        if !self.first_payment_method.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentSessionDimensions::FirstPaymentMethod,
+                    &self.first_payment_method,
+                )
+                .attach_printable("Error adding first payment method filter")?;
+        }
+
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentSessionDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.platform.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentSessionDimensions::Platform, &self.platform)
+                .attach_printable("Error adding platform filter")?;
+        }
+
+        if !self.country.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentSessionDimensions::Country, &self.country)
+                .attach_printable("Error adding country filter")?;
+        }
+
+        if !self.currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentSessionDimensions::Currency, &self.currency)
+                .attach_printable("Error adding currency filter")?;
+        }
+
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentSessionDimensions::ProfileId, &self.profile_id)
+                .attach_printable("Error adding profile id filter")?;
+        }
+
+        if !self.last_funnel_step.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentSessionDimensions::LastFunnelStep,
+                    &self.last_funnel_step,
+                )
+                .attach_printable("Error adding last funnel step filter")?;
+        }
+
+        Ok(())
+    }
+}
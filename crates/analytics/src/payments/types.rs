@@ -143,6 +143,10 @@ where
                 .attach_printable("Error adding is debit routed filter")?;
         }
 
+        self.metadata
+            .set_filter_clause(builder)
+            .attach_printable("Error adding metadata dimension filters")?;
+
         Ok(())
     }
 }
@@ -0,0 +1,126 @@
+use api_models::analytics::{
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
+    Granularity, TimeRange,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+mod payout_avg_processing_time;
+mod payout_count;
+mod payout_failure_reason;
+mod payout_processed_amount;
+mod payout_success_count;
+mod payout_success_rate;
+use std::collections::HashSet;
+
+use payout_avg_processing_time::PayoutAvgProcessingTime;
+use payout_count::PayoutCount;
+use payout_failure_reason::PayoutFailureReason;
+use payout_processed_amount::PayoutProcessedAmount;
+use payout_success_count::PayoutSuccessCount;
+use payout_success_rate::PayoutSuccessRate;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+/// Seconds taken by a payout to move from creation to its last update, the expression for which
+/// differs across the analytics data sources
+#[derive(Debug, Clone, Copy)]
+pub struct PayoutProcessingTime;
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PayoutMetricRow {
+    pub destination_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub status: Option<DBEnumWrapper<storage_enums::PayoutStatus>>,
+    pub connector: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<storage_enums::PayoutType>>,
+    pub profile_id: Option<String>,
+    pub error_message: Option<String>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PayoutMetricAnalytics: LoadRow<PayoutMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PayoutMetric<T>
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Aggregate<PayoutProcessingTime>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> PayoutMetric<T> for PayoutMetrics
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Aggregate<PayoutProcessingTime>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        match self {
+            Self::PayoutSuccessRate => {
+                PayoutSuccessRate
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutCount => {
+                PayoutCount
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutSuccessCount => {
+                PayoutSuccessCount
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutProcessedAmount => {
+                PayoutProcessedAmount
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutAvgProcessingTime => {
+                PayoutAvgProcessingTime
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutFailureReason => {
+                PayoutFailureReason
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
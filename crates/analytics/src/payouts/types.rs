@@ -0,0 +1,56 @@
+use api_models::analytics::payouts::{PayoutDimensions, PayoutFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for PayoutFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.destination_currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PayoutDimensions::DestinationCurrency,
+                    &self.destination_currency,
+                )
+                .attach_printable("Error adding destination currency filter")?;
+        }
+
+        if !self.status.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::Status, &self.status)
+                .attach_printable("Error adding payout status filter")?;
+        }
+
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.payout_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::PayoutType, &self.payout_type)
+                .attach_printable("Error adding payout type filter")?;
+        }
+
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::ProfileId, &self.profile_id)
+                .attach_printable("Error adding profile id filter")?;
+        }
+
+        if !self.error_message.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::ErrorMessage, &self.error_message)
+                .attach_printable("Error adding error message filter")?;
+        }
+
+        Ok(())
+    }
+}
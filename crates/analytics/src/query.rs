@@ -8,14 +8,16 @@ use api_models::{
         disputes::DisputeDimensions,
         frm::{FrmDimensions, FrmTransactionType},
         payment_intents::PaymentIntentDimensions,
+        payment_sessions::{PaymentSessionDimensions, PaymentSessionFunnelStep},
         payments::{PaymentDimensions, PaymentDistributions},
+        payouts::{PayoutDimensions, PayoutDistributions},
         refunds::{RefundDimensions, RefundDistributions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
         Granularity,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, IntentStatus,
-        PaymentMethod, PaymentMethodType, RoutingApproach,
+        PaymentMethod, PaymentMethodType, PayoutStatus, PayoutType, RoutingApproach,
     },
     refunds::RefundStatus,
 };
@@ -84,6 +86,29 @@ where
     }
 }
 
+impl<T> QueryFilter<T> for analytics_api::MetadataDimensionFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        for (key, values) in self.values.iter() {
+            if !values.is_empty() {
+                builder
+                    .add_metadata_filter_clause(key, values)
+                    .attach_printable("Error adding metadata filter")?;
+            }
+        }
+        // Grouping rides along with the filters as they are passed to every metric query
+        if !self.group_by.is_empty() {
+            builder
+                .add_metadata_dimensions(&self.group_by)
+                .attach_printable("Error grouping by metadata dimensions")?;
+        }
+        Ok(())
+    }
+}
+
 impl GroupByClause<super::SqlxClient> for Granularity {
     fn set_group_by_clause(
         &self,
@@ -412,6 +437,8 @@ where
     order_by: Vec<String>,
     having: Option<Vec<(String, FilterTypes, String)>>,
     limit_by: Option<LimitByClause>,
+    limit: Option<u64>,
+    offset: Option<u64>,
     outer_select: Vec<String>,
     top_n: Option<TopN>,
     table: AnalyticsCollection,
@@ -529,7 +556,15 @@ impl_to_sql_for_to_string!(
     DisputeDimensions,
     DisputeStage,
     AuthEventDimensions,
-    &AuthEventDimensions
+    &AuthEventDimensions,
+    &PayoutDimensions,
+    PayoutDimensions,
+    &PayoutDistributions,
+    PayoutStatus,
+    PayoutType,
+    &PaymentSessionDimensions,
+    PaymentSessionDimensions,
+    PaymentSessionFunnelStep
 );
 
 #[derive(Debug, Clone, Copy)]
@@ -574,6 +609,8 @@ where
             order_by: Default::default(),
             having: Default::default(),
             limit_by: Default::default(),
+            limit: Default::default(),
+            offset: Default::default(),
             outer_select: Default::default(),
             top_n: Default::default(),
             table,
@@ -712,6 +749,52 @@ where
         self.add_custom_filter_clause(key, list, FilterTypes::In)
     }
 
+    fn get_metadata_dimension(&self, key: &str) -> QueryResult<String> {
+        if !analytics_api::is_valid_metadata_dimension(key) {
+            Err(QueryBuildingError::InvalidQuery(
+                "Invalid metadata dimension",
+            ))?;
+        }
+        T::get_metadata_dimension(self.table, key)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable_lazy(|| format!("Error serializing metadata dimension {key}"))
+    }
+
+    pub fn add_metadata_filter_clause(&mut self, key: &str, values: &[String]) -> QueryResult<()> {
+        // metadata values are free form, unlike the other range filters they are kept as is
+        // and values that could break out of the quotes are rejected instead
+        if values
+            .iter()
+            .any(|value| value.contains('\'') || value.contains('\\'))
+        {
+            Err(QueryBuildingError::InvalidQuery(
+                "Invalid metadata filter value",
+            ))?;
+        }
+        let lhs = self.get_metadata_dimension(key)?;
+        let list = values
+            .iter()
+            .map(|value| format!("'{value}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.add_custom_filter_clause(lhs, list, FilterTypes::In)
+    }
+
+    /// Selects the metadata `keys` as a single `metadata_dimensions` map and groups by each key
+    pub fn add_metadata_dimensions(&mut self, keys: &[String]) -> QueryResult<()> {
+        let dimensions = keys
+            .iter()
+            .map(|key| Ok((key.as_str(), self.get_metadata_dimension(key)?)))
+            .collect::<QueryResult<Vec<_>>>()?;
+        self.columns.push(format!(
+            "{} as metadata_dimensions",
+            T::get_metadata_dimensions_map(&dimensions)
+        ));
+        self.group_by
+            .extend(dimensions.into_iter().map(|(_, expression)| expression));
+        Ok(())
+    }
+
     pub fn add_group_by_clause(&mut self, column: impl ToSql<T>) -> QueryResult<()> {
         self.group_by.push(
             column
@@ -753,6 +836,14 @@ where
         Ok(())
     }
 
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = Some(limit);
+    }
+
+    pub fn set_offset(&mut self, offset: u64) {
+        self.offset = Some(offset);
+    }
+
     pub fn add_granularity_in_mins(&mut self, granularity: Granularity) -> QueryResult<()> {
         let interval = match granularity {
             Granularity::OneMin => "1",
@@ -895,6 +986,14 @@ where
             query.push_str(&format!(" {limit_by}"));
         }
 
+        if let Some(limit) = self.limit {
+            query.push_str(&format!(" LIMIT {limit}"));
+        }
+
+        if let Some(offset) = self.offset {
+            query.push_str(&format!(" OFFSET {offset}"));
+        }
+
         if !self.outer_select.is_empty() {
             query.insert_str(
                 0,
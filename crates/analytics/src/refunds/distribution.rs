@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use api_models::analytics::{
     refunds::{
         RefundDimensions, RefundDistributions, RefundFilters, RefundMetricsBucketIdentifier,
@@ -27,6 +29,7 @@ pub struct RefundDistributionRow {
     pub count: Option<i64>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
+    pub metadata_dimensions: Option<BTreeMap<String, String>>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
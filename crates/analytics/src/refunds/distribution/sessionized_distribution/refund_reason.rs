@@ -110,7 +110,21 @@ where
         query_builder
             .add_outer_select_column("end_bucket")
             .switch()?;
-        let sql_dimensions = query_builder.transform_to_sql_values(dimensions).switch()?;
+
+        // the distribution is computed within each group of metadata dimensions as well
+        let mut partition_columns = dimensions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !filters.metadata.group_by.is_empty() {
+            query_builder
+                .add_outer_select_column("metadata_dimensions")
+                .switch()?;
+            partition_columns.push("metadata_dimensions".to_string());
+        }
+        let sql_dimensions = query_builder
+            .transform_to_sql_values(&partition_columns)
+            .switch()?;
 
         query_builder
             .add_outer_select_column(Window::Sum {
@@ -123,7 +137,7 @@ where
 
         query_builder
             .add_top_n_clause(
-                dimensions,
+                &partition_columns,
                 distribution.distribution_cardinality.into(),
                 "count",
                 Order::Descending,
@@ -146,6 +160,7 @@ where
                         i.profile_id.clone(),
                         i.refund_reason.clone(),
                         i.refund_error_message.clone(),
+                        i.metadata_dimensions.clone(),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
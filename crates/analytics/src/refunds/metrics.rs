@@ -11,7 +11,7 @@ mod refund_processed_amount;
 mod refund_success_count;
 mod refund_success_rate;
 mod sessionized_metrics;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use refund_count::RefundCount;
 use refund_processed_amount::RefundProcessedAmount;
@@ -35,6 +35,7 @@ pub struct RefundMetricRow {
     pub refund_error_message: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
+    pub metadata_dimensions: Option<BTreeMap<String, String>>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
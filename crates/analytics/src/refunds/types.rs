@@ -57,6 +57,10 @@ where
                 .attach_printable("Error adding refund error message filter")?;
         }
 
+        self.metadata
+            .set_filter_clause(builder)
+            .attach_printable("Error adding metadata dimension filters")?;
+
         Ok(())
     }
 }
@@ -0,0 +1,595 @@
+use std::collections::HashMap;
+
+use api_models::{
+    analytics::{Granularity, TimeRange},
+    enums::{DisputeStage, DisputeStatus},
+};
+use common_enums::AnalyticsReportType;
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums::{AttemptStatus, Currency, PayoutStatus, PayoutType, RefundStatus};
+use error_stack::{report, ResultExt};
+use time::PrimitiveDateTime;
+
+pub use crate::types::{ReportError, ReportResult};
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow},
+};
+
+/// A row of a report, as it is written to the report file
+pub trait ReportRow: serde::Serialize {
+    /// Collection the rows of the report are read from
+    const COLLECTION: AnalyticsCollection;
+
+    /// Columns of the report, in the order of the fields of the row
+    const COLUMNS: &'static [&'static str];
+
+    /// Column which identifies a row, used along with `created_at` to page through the rows
+    const ID_COLUMN: &'static str;
+}
+
+pub trait ReportAnalytics:
+    LoadRow<PaymentReportRow>
+    + LoadRow<RefundReportRow>
+    + LoadRow<DisputeReportRow>
+    + LoadRow<PayoutReportRow>
+    + LoadRow<AuthenticationReportRow>
+{
+}
+
+/// Destination of a report file, which receives the file in parts as the pages of rows are loaded
+#[async_trait::async_trait]
+pub trait ReportSink: Send {
+    /// Appends a part to the report file
+    async fn write_part(&mut self, part: Vec<u8>) -> ReportResult<()>;
+}
+
+/// Size and row count of a report file written to a [`ReportSink`]
+#[derive(Debug)]
+pub struct ReportSummary {
+    pub file_size: u64,
+    pub row_count: u64,
+}
+
+/// A generated report file
+#[derive(Debug)]
+pub struct ReportFile {
+    pub content: Vec<u8>,
+    pub row_count: u64,
+}
+
+impl ReportFile {
+    pub const EXTENSION: &'static str = "csv";
+    pub const CONTENT_TYPE: &'static str = "text/csv";
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentReportRow {
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub status: DBEnumWrapper<AttemptStatus>,
+    pub amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub connector_transaction_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for PaymentReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Payment;
+    const COLUMNS: &'static [&'static str] = &[
+        "payment_id",
+        "attempt_id",
+        "merchant_id",
+        "profile_id",
+        "status",
+        "amount",
+        "currency",
+        "connector",
+        "payment_method",
+        "payment_method_type",
+        "connector_transaction_id",
+        "error_code",
+        "error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "attempt_id";
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RefundReportRow {
+    pub refund_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub connector: Option<String>,
+    pub refund_status: DBEnumWrapper<RefundStatus>,
+    pub refund_amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub refund_reason: Option<String>,
+    pub refund_error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for RefundReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Refund;
+    const COLUMNS: &'static [&'static str] = &[
+        "refund_id",
+        "payment_id",
+        "merchant_id",
+        "profile_id",
+        "connector",
+        "refund_status",
+        "refund_amount",
+        "currency",
+        "refund_reason",
+        "refund_error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "refund_id";
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DisputeReportRow {
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub connector: Option<String>,
+    pub dispute_stage: DBEnumWrapper<DisputeStage>,
+    pub dispute_status: DBEnumWrapper<DisputeStatus>,
+    pub dispute_amount: Option<i64>,
+    pub currency: Option<String>,
+    pub connector_reason: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for DisputeReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Dispute;
+    const COLUMNS: &'static [&'static str] = &[
+        "dispute_id",
+        "payment_id",
+        "merchant_id",
+        "profile_id",
+        "connector",
+        "dispute_stage",
+        "dispute_status",
+        "dispute_amount",
+        "currency",
+        "connector_reason",
+        "challenge_required_by",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "dispute_id";
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PayoutReportRow {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub customer_id: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<PayoutType>>,
+    pub status: DBEnumWrapper<PayoutStatus>,
+    pub amount: Option<i64>,
+    pub source_currency: Option<DBEnumWrapper<Currency>>,
+    pub destination_currency: Option<DBEnumWrapper<Currency>>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for PayoutReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Payout;
+    const COLUMNS: &'static [&'static str] = &[
+        "payout_id",
+        "merchant_id",
+        "profile_id",
+        "customer_id",
+        "payout_type",
+        "status",
+        "amount",
+        "source_currency",
+        "destination_currency",
+        "created_at",
+        "last_modified_at",
+    ];
+    const ID_COLUMN: &'static str = "payout_id";
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct AuthenticationReportRow {
+    pub authentication_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub payment_id: Option<String>,
+    pub authentication_connector: Option<String>,
+    pub authentication_status: Option<String>,
+    pub trans_status: Option<String>,
+    pub amount: Option<i64>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ReportRow for AuthenticationReportRow {
+    const COLLECTION: AnalyticsCollection = AnalyticsCollection::Authentications;
+    const COLUMNS: &'static [&'static str] = &[
+        "authentication_id",
+        "merchant_id",
+        "profile_id",
+        "payment_id",
+        "authentication_connector",
+        "authentication_status",
+        "trans_status",
+        "amount",
+        "currency",
+        "error_code",
+        "error_message",
+        "created_at",
+        "modified_at",
+    ];
+    const ID_COLUMN: &'static str = "authentication_id";
+}
+
+/// Generates the report of the domain as a CSV file. Rows are read in pages of `page_size` rows,
+/// so that large reports do not need a single long running query, and each page is written to
+/// the sink once it is loaded, so that the report is never held in memory as a whole.
+pub async fn generate_report<T>(
+    pool: &T,
+    report_type: AnalyticsReportType,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    page_size: u64,
+    sink: &mut dyn ReportSink,
+) -> ReportResult<ReportSummary>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    match report_type {
+        AnalyticsReportType::Payments => {
+            write_report::<PaymentReportRow, T>(pool, auth, time_range, page_size, sink).await
+        }
+        AnalyticsReportType::Refunds => {
+            write_report::<RefundReportRow, T>(pool, auth, time_range, page_size, sink).await
+        }
+        AnalyticsReportType::Disputes => {
+            write_report::<DisputeReportRow, T>(pool, auth, time_range, page_size, sink).await
+        }
+        AnalyticsReportType::Payouts => {
+            write_report::<PayoutReportRow, T>(pool, auth, time_range, page_size, sink).await
+        }
+        AnalyticsReportType::Authentications => {
+            write_report::<AuthenticationReportRow, T>(pool, auth, time_range, page_size, sink)
+                .await
+        }
+    }
+}
+
+async fn write_report<R, T>(
+    pool: &T,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    page_size: u64,
+    sink: &mut dyn ReportSink,
+) -> ReportResult<ReportSummary>
+where
+    R: ReportRow,
+    T: AnalyticsDataSource + LoadRow<R>,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut row_count = 0;
+    let mut file_size = 0;
+    let mut is_first_page = true;
+    loop {
+        let rows = load_report_page::<R, T>(
+            pool,
+            auth,
+            time_range,
+            &HashMap::new(),
+            page_size,
+            row_count,
+        )
+        .await?;
+        let (part, page_row_count) = write_csv_page(rows, is_first_page)?;
+        file_size += u64::try_from(part.len())
+            .change_context(ReportError::FileWriteFailure)
+            .attach_printable("Report part size exceeds u64")?;
+        sink.write_part(part).await?;
+        row_count += page_row_count;
+        is_first_page = false;
+
+        if page_row_count < page_size {
+            break;
+        }
+    }
+
+    Ok(ReportSummary {
+        file_size,
+        row_count,
+    })
+}
+
+/// Writes a page of rows as CSV, preceded by the header row for the first page of the report.
+/// Returns the CSV along with the number of rows written.
+fn write_csv_page<R: ReportRow>(rows: Vec<R>, is_first_page: bool) -> ReportResult<(Vec<u8>, u64)> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+    if is_first_page {
+        writer
+            .write_record(R::COLUMNS)
+            .change_context(ReportError::FileWriteFailure)?;
+    }
+
+    let mut row_count = 0;
+    for row in rows {
+        writer
+            .serialize(row)
+            .change_context(ReportError::FileWriteFailure)?;
+        row_count += 1;
+    }
+
+    let part = writer.into_inner().map_err(|error| {
+        report!(ReportError::FileWriteFailure).attach_printable(error.to_string())
+    })?;
+    Ok((part, row_count))
+}
+
+/// Loads a page of the rows of a report. Filters restrict report columns to a set of values.
+pub(crate) async fn load_report_page<R, T>(
+    pool: &T,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    filters: &HashMap<String, Vec<String>>,
+    limit: u64,
+    offset: u64,
+) -> ReportResult<Vec<R>>
+where
+    R: ReportRow,
+    T: AnalyticsDataSource + LoadRow<R>,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder =
+        get_report_page_query::<R, T>(auth, time_range, filters, limit, offset)?;
+
+    query_builder
+        .execute_query::<R, _>(pool)
+        .await
+        .change_context(ReportError::QueryBuildingError)?
+        .change_context(ReportError::QueryExecutionFailure)
+}
+
+/// Builds the query loading a page of the rows of a report
+fn get_report_page_query<R, T>(
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    filters: &HashMap<String, Vec<String>>,
+    limit: u64,
+    offset: u64,
+) -> ReportResult<QueryBuilder<T>>
+where
+    R: ReportRow,
+    T: AnalyticsDataSource,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(R::COLLECTION);
+
+    for column in R::COLUMNS {
+        query_builder.add_select_column(*column).switch()?;
+    }
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    for (column, values) in filters {
+        if !R::COLUMNS.contains(&column.as_str()) {
+            return Err(report!(ReportError::QueryBuildingError))
+                .attach_printable(format!("Unknown report column {column}"));
+        }
+        query_builder
+            .add_filter_in_range_clause(column.as_str(), values)
+            .attach_printable("Error adding report column filter")
+            .switch()?;
+    }
+
+    // On collapsing tables every update of a row inserts a cancel row, a copy of the previous
+    // version with a negative sign, along with the new version. Grouping by every column pairs
+    // each cancelled version with its cancel row, and the query builder only keeps the groups with
+    // `HAVING sum(sign_flag) >= 1`, so that only the latest version of each row is reported.
+    for column in R::COLUMNS {
+        query_builder
+            .add_group_by_clause(*column)
+            .attach_printable("Error grouping by report columns")
+            .switch()?;
+    }
+
+    query_builder
+        .add_order_by_clause("created_at", Order::Ascending)
+        .switch()?;
+    query_builder
+        .add_order_by_clause(R::ID_COLUMN, Order::Ascending)
+        .switch()?;
+    query_builder.set_limit(limit);
+    query_builder.set_offset(offset);
+
+    Ok(query_builder)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+    use time::macros::datetime;
+
+    use super::*;
+    use crate::{clickhouse::ClickhouseClient, sqlx::SqlxClient};
+
+    fn get_auth() -> AuthInfo {
+        AuthInfo::MerchantLevel {
+            org_id: id_type::OrganizationId::try_from_string("org_1".to_string()).unwrap(),
+            merchant_ids: vec![id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap()],
+        }
+    }
+
+    fn get_time_range() -> TimeRange {
+        TimeRange {
+            start_time: datetime!(2024-01-01 00:00:00),
+            end_time: Some(datetime!(2024-02-01 00:00:00)),
+        }
+    }
+
+    fn get_refund_row(refund_id: &str) -> RefundReportRow {
+        RefundReportRow {
+            refund_id: refund_id.to_string(),
+            payment_id: "pay_1".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            profile_id: None,
+            connector: Some("stripe".to_string()),
+            refund_status: DBEnumWrapper(RefundStatus::Success),
+            refund_amount: Some(100),
+            currency: Some(DBEnumWrapper(Currency::USD)),
+            refund_reason: None,
+            refund_error_message: None,
+            created_at: datetime!(2024-01-02 10:00:00),
+            modified_at: datetime!(2024-01-02 10:05:00),
+        }
+    }
+
+    #[test]
+    fn test_report_page_query_keeps_latest_versions_of_collapsing_tables() {
+        let query = get_report_page_query::<RefundReportRow, ClickhouseClient>(
+            &get_auth(),
+            &get_time_range(),
+            &HashMap::new(),
+            100,
+            200,
+        )
+        .unwrap()
+        .build_query()
+        .unwrap();
+
+        assert!(query.starts_with("SELECT refund_id, payment_id"));
+        assert!(query.contains(" GROUP BY refund_id, payment_id"));
+        assert!(query.contains(" HAVING sum(sign_flag) >= '1'"));
+        assert!(query.ends_with(" ORDER BY created_at asc, refund_id asc LIMIT 100 OFFSET 200"));
+    }
+
+    #[test]
+    fn test_report_page_query_does_not_collapse_plain_tables() {
+        let query = get_report_page_query::<RefundReportRow, SqlxClient>(
+            &get_auth(),
+            &get_time_range(),
+            &HashMap::new(),
+            100,
+            0,
+        )
+        .unwrap()
+        .build_query()
+        .unwrap();
+
+        assert!(query.contains(" GROUP BY "));
+        assert!(!query.contains(" HAVING "));
+    }
+
+    #[test]
+    fn test_report_page_query_rejects_unknown_filter_columns() {
+        let filters = HashMap::from([("card_number".to_string(), vec!["4242".to_string()])]);
+
+        assert!(get_report_page_query::<RefundReportRow, ClickhouseClient>(
+            &get_auth(),
+            &get_time_range(),
+            &filters,
+            100,
+            0,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_csv_header_is_only_written_with_first_page() {
+        let (first_page, row_count) = write_csv_page(vec![get_refund_row("ref_1")], true).unwrap();
+        assert_eq!(row_count, 1);
+        let first_page = String::from_utf8(first_page).unwrap();
+        let mut lines = first_page.lines();
+        assert_eq!(
+            lines.next(),
+            Some(RefundReportRow::COLUMNS.join(",").as_str())
+        );
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("ref_1,pay_1,merchant_1,,stripe,"));
+        assert_eq!(lines.next(), None);
+
+        let (next_page, row_count) = write_csv_page(
+            vec![get_refund_row("ref_2"), get_refund_row("ref_3")],
+            false,
+        )
+        .unwrap();
+        assert_eq!(row_count, 2);
+        let next_page = String::from_utf8(next_page).unwrap();
+        assert_eq!(next_page.lines().count(), 2);
+        assert!(next_page.starts_with("ref_2,"));
+    }
+
+    #[test]
+    fn test_empty_first_page_has_header_only() {
+        let (page, row_count) = write_csv_page::<RefundReportRow>(Vec::new(), true).unwrap();
+
+        assert_eq!(row_count, 0);
+        assert_eq!(
+            String::from_utf8(page).unwrap(),
+            format!("{}\n", RefundReportRow::COLUMNS.join(","))
+        );
+    }
+}
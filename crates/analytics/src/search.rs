@@ -12,6 +12,26 @@ use crate::{
     opensearch::{OpenSearchClient, OpenSearchError, OpenSearchQuery, OpenSearchQueryBuilder},
 };
 
+pub mod postgres;
+
+/// Configuration of the global search API
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct GlobalSearchConfig {
+    pub provider: SearchProvider,
+}
+
+/// Data source the global search API is served from
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchProvider {
+    /// Search the indexes of the OpenSearch cluster configured under `opensearch`
+    #[default]
+    Opensearch,
+    /// Search the application tables in Postgres, for deployments without OpenSearch
+    Postgres,
+}
+
 pub fn convert_to_value<T: Into<Value>>(items: Vec<T>) -> Vec<Value> {
     items.into_iter().map(|item| item.into()).collect()
 }
@@ -0,0 +1,819 @@
+//! Global search served from the application tables in Postgres, for deployments without
+//! OpenSearch. The identifiers the free text query is matched against are backed by trigram
+//! indexes, so that partial identifiers can be searched for.
+
+use api_models::analytics::search::{
+    GetGlobalSearchRequest, GetSearchRequestWithIndex, GetSearchResponse, SearchFilters,
+    SearchIndex, SearchStatus,
+};
+use common_utils::{errors::CustomResult, types::TimeRange};
+use error_stack::{report, ResultExt};
+use futures::future::join_all;
+use router_env::tracing;
+use sqlx::{Postgres, QueryBuilder, Row};
+
+use crate::{enums::AuthInfo, opensearch::OpenSearchError, sqlx::SqlxClient, AnalyticsProvider};
+
+/// Number of hits returned per index by the global search, the same as the default of OpenSearch
+const GLOBAL_SEARCH_HITS_PER_INDEX: i64 = 10;
+
+/// Fields of a search index which the filters of a search request apply to
+#[derive(Debug, Clone, Copy)]
+enum SearchField {
+    Status,
+    Amount,
+    Currency,
+    PaymentMethod,
+    PaymentMethodType,
+    Connector,
+    CardNetwork,
+    CardLast4,
+    PaymentId,
+    CustomerId,
+    SearchTags,
+}
+
+/// Table a search index is served from
+fn get_table(index: SearchIndex) -> &'static str {
+    match index {
+        SearchIndex::PaymentAttempts | SearchIndex::SessionizerPaymentAttempts => "payment_attempt",
+        SearchIndex::PaymentIntents | SearchIndex::SessionizerPaymentIntents => "payment_intent",
+        SearchIndex::Refunds | SearchIndex::SessionizerRefunds => "refund",
+        SearchIndex::Disputes | SearchIndex::SessionizerDisputes => "dispute",
+        SearchIndex::Payouts => "payouts",
+    }
+}
+
+/// Expressions the free text query of a search request is matched against
+fn get_searchable_columns(index: SearchIndex) -> &'static [&'static str] {
+    match index {
+        SearchIndex::PaymentAttempts | SearchIndex::SessionizerPaymentAttempts => &[
+            "payment_id",
+            "attempt_id",
+            "connector_transaction_id",
+            "(payment_method_data -> 'card' ->> 'last4')",
+        ],
+        SearchIndex::PaymentIntents | SearchIndex::SessionizerPaymentIntents => {
+            &["payment_id", "customer_id", "merchant_order_reference_id"]
+        }
+        SearchIndex::Refunds | SearchIndex::SessionizerRefunds => &[
+            "refund_id",
+            "payment_id",
+            "connector_refund_id",
+            "connector_transaction_id",
+        ],
+        SearchIndex::Disputes | SearchIndex::SessionizerDisputes => {
+            &["dispute_id", "payment_id", "connector_dispute_id"]
+        }
+        SearchIndex::Payouts => &["payout_id", "customer_id"],
+    }
+}
+
+/// Columns returned in the hits of an index. Columns are only returned when listed here, so that
+/// columns holding secrets, personal data or merchant provided data, as well as columns added to
+/// the tables later on, never end up in search results.
+fn get_returned_columns(index: SearchIndex) -> &'static [&'static str] {
+    match index {
+        SearchIndex::PaymentAttempts | SearchIndex::SessionizerPaymentAttempts => &[
+            "payment_id",
+            "attempt_id",
+            "merchant_id",
+            "profile_id",
+            "organization_id",
+            "status",
+            "amount",
+            "currency",
+            "amount_to_capture",
+            "amount_capturable",
+            "net_amount",
+            "surcharge_amount",
+            "tax_amount",
+            "connector",
+            "merchant_connector_id",
+            "connector_transaction_id",
+            "connector_response_reference_id",
+            "payment_method",
+            "payment_method_type",
+            "payment_experience",
+            "card_network",
+            "card_discovery",
+            "capture_method",
+            "authentication_type",
+            "authentication_connector",
+            "mandate_id",
+            "error_code",
+            "error_message",
+            "error_reason",
+            "unified_code",
+            "unified_message",
+            "routing_approach",
+            "client_source",
+            "client_version",
+            "created_at",
+            "modified_at",
+        ],
+        SearchIndex::PaymentIntents | SearchIndex::SessionizerPaymentIntents => &[
+            "payment_id",
+            "merchant_id",
+            "profile_id",
+            "organization_id",
+            "status",
+            "amount",
+            "currency",
+            "amount_captured",
+            "customer_id",
+            "merchant_order_reference_id",
+            "description",
+            "connector_id",
+            "active_attempt_id",
+            "attempt_count",
+            "setup_future_usage",
+            "off_session",
+            "business_country",
+            "business_label",
+            "statement_descriptor_name",
+            "statement_descriptor_suffix",
+            "payment_link_id",
+            "created_at",
+            "modified_at",
+        ],
+        SearchIndex::Refunds | SearchIndex::SessionizerRefunds => &[
+            "refund_id",
+            "payment_id",
+            "attempt_id",
+            "merchant_id",
+            "profile_id",
+            "organization_id",
+            "refund_status",
+            "refund_type",
+            "refund_amount",
+            "total_amount",
+            "currency",
+            "refund_reason",
+            "description",
+            "connector",
+            "merchant_connector_id",
+            "connector_transaction_id",
+            "connector_refund_id",
+            "external_reference_id",
+            "refund_arn",
+            "sent_to_gateway",
+            "refund_error_code",
+            "refund_error_message",
+            "unified_code",
+            "unified_message",
+            "created_at",
+            "modified_at",
+        ],
+        SearchIndex::Disputes | SearchIndex::SessionizerDisputes => &[
+            "dispute_id",
+            "payment_id",
+            "attempt_id",
+            "merchant_id",
+            "profile_id",
+            "organization_id",
+            "dispute_stage",
+            "dispute_status",
+            "amount",
+            "currency",
+            "dispute_amount",
+            "dispute_currency",
+            "connector",
+            "merchant_connector_id",
+            "connector_dispute_id",
+            "connector_status",
+            "connector_reason",
+            "connector_reason_code",
+            "challenge_required_by",
+            "connector_created_at",
+            "connector_updated_at",
+            "created_at",
+            "modified_at",
+        ],
+        SearchIndex::Payouts => &[
+            "payout_id",
+            "merchant_id",
+            "profile_id",
+            "organization_id",
+            "customer_id",
+            "status",
+            "payout_type",
+            "amount",
+            "source_currency",
+            "destination_currency",
+            "description",
+            "entity_type",
+            "priority",
+            "recurring",
+            "auto_fulfill",
+            "confirm",
+            "attempt_count",
+            "created_at",
+            "last_modified_at",
+        ],
+    }
+}
+
+/// Column a filter applies to, if the index has the field the filter is on
+fn get_column(index: SearchIndex, field: SearchField) -> Option<&'static str> {
+    match (index, field) {
+        (SearchIndex::PaymentAttempts | SearchIndex::SessionizerPaymentAttempts, field) => {
+            match field {
+                SearchField::Status => Some("status"),
+                SearchField::Amount => Some("amount"),
+                SearchField::Currency => Some("currency"),
+                SearchField::PaymentMethod => Some("payment_method"),
+                SearchField::PaymentMethodType => Some("payment_method_type"),
+                SearchField::Connector => Some("connector"),
+                SearchField::CardNetwork => Some("card_network"),
+                SearchField::CardLast4 => Some("(payment_method_data -> 'card' ->> 'last4')"),
+                SearchField::PaymentId => Some("payment_id"),
+                SearchField::CustomerId | SearchField::SearchTags => None,
+            }
+        }
+        (SearchIndex::PaymentIntents | SearchIndex::SessionizerPaymentIntents, field) => {
+            match field {
+                SearchField::Status => Some("status"),
+                SearchField::Amount => Some("amount"),
+                SearchField::Currency => Some("currency"),
+                SearchField::PaymentId => Some("payment_id"),
+                SearchField::CustomerId => Some("customer_id"),
+                SearchField::SearchTags => Some("(feature_metadata::jsonb -> 'search_tags')"),
+                SearchField::PaymentMethod
+                | SearchField::PaymentMethodType
+                | SearchField::Connector
+                | SearchField::CardNetwork
+                | SearchField::CardLast4 => None,
+            }
+        }
+        (SearchIndex::Refunds | SearchIndex::SessionizerRefunds, field) => match field {
+            SearchField::Status => Some("refund_status"),
+            SearchField::Amount => Some("refund_amount"),
+            SearchField::Currency => Some("currency"),
+            SearchField::Connector => Some("connector"),
+            SearchField::PaymentId => Some("payment_id"),
+            SearchField::PaymentMethod
+            | SearchField::PaymentMethodType
+            | SearchField::CardNetwork
+            | SearchField::CardLast4
+            | SearchField::CustomerId
+            | SearchField::SearchTags => None,
+        },
+        (SearchIndex::Disputes | SearchIndex::SessionizerDisputes, field) => match field {
+            SearchField::Status => Some("dispute_status"),
+            SearchField::Amount => Some("dispute_amount"),
+            SearchField::Currency => Some("currency"),
+            SearchField::Connector => Some("connector"),
+            SearchField::PaymentId => Some("payment_id"),
+            SearchField::PaymentMethod
+            | SearchField::PaymentMethodType
+            | SearchField::CardNetwork
+            | SearchField::CardLast4
+            | SearchField::CustomerId
+            | SearchField::SearchTags => None,
+        },
+        (SearchIndex::Payouts, field) => match field {
+            SearchField::Status => Some("status"),
+            SearchField::Amount => Some("amount"),
+            SearchField::Currency => Some("destination_currency"),
+            SearchField::CustomerId => Some("customer_id"),
+            SearchField::PaymentMethod
+            | SearchField::PaymentMethodType
+            | SearchField::Connector
+            | SearchField::CardNetwork
+            | SearchField::CardLast4
+            | SearchField::PaymentId
+            | SearchField::SearchTags => None,
+        },
+    }
+}
+
+fn get_sqlx_client(pool: &AnalyticsProvider) -> CustomResult<&SqlxClient, OpenSearchError> {
+    match pool {
+        AnalyticsProvider::Sqlx(sqlx_client)
+        | AnalyticsProvider::CombinedCkh(sqlx_client, _)
+        | AnalyticsProvider::CombinedSqlx(sqlx_client, _) => Ok(sqlx_client),
+        AnalyticsProvider::Clickhouse(_) => Err(report!(OpenSearchError::NotEnabled))
+            .attach_printable("Postgres search requires a sqlx analytics data source"),
+    }
+}
+
+/// Search criteria of a request, validated and prepared to be bound to the search queries
+#[derive(Debug, Clone)]
+struct SearchCriteria {
+    query_pattern: Option<String>,
+    filters: Vec<(SearchField, FilterValues)>,
+    time_range: Option<TimeRange>,
+    search_params: Vec<AuthInfo>,
+}
+
+#[derive(Debug, Clone)]
+enum FilterValues {
+    /// Matched ignoring case, as the term filters of OpenSearch are
+    CaseInsensitive(Vec<String>),
+    Exact(Vec<String>),
+    Amounts(Vec<i64>),
+    SearchTags(Vec<String>),
+}
+
+impl SearchCriteria {
+    fn new(
+        query: &str,
+        filters: Option<SearchFilters>,
+        time_range: Option<TimeRange>,
+        search_params: Vec<AuthInfo>,
+    ) -> CustomResult<Self, OpenSearchError> {
+        let query = query.trim();
+        if query.is_empty() && filters.as_ref().is_none_or(|filters| filters.is_all_none()) {
+            return Err(OpenSearchError::BadRequestError(
+                "Both query and filters are empty".to_string(),
+            )
+            .into());
+        }
+
+        Ok(Self {
+            query_pattern: (!query.is_empty()).then(|| format!("%{}%", escape_like(query))),
+            filters: filters
+                .map(Self::get_filters)
+                .transpose()?
+                .unwrap_or_default(),
+            time_range,
+            search_params,
+        })
+    }
+
+    fn get_filters(
+        filters: SearchFilters,
+    ) -> CustomResult<Vec<(SearchField, FilterValues)>, OpenSearchError> {
+        if filters
+            .customer_email
+            .as_ref()
+            .is_some_and(|customer_email| !customer_email.is_empty())
+        {
+            return Err(OpenSearchError::BadRequestError(
+                "Filtering by customer email is not supported by the postgres search provider, as customer details are encrypted".to_string(),
+            )
+            .into());
+        }
+
+        let amounts = filters
+            .amount
+            .unwrap_or_default()
+            .into_iter()
+            .map(i64::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| OpenSearchError::BadRequestError("Invalid amount".to_string()))?;
+        let search_tags = filters
+            .search_tags
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|search_tag| {
+                serde_json::to_value(search_tag)
+                    .ok()
+                    .and_then(|value| value.as_str().map(|value| value.to_string()))
+            })
+            .collect();
+
+        Ok([
+            (
+                SearchField::Currency,
+                FilterValues::CaseInsensitive(filters.currency.unwrap_or_default()),
+            ),
+            (
+                SearchField::Status,
+                FilterValues::CaseInsensitive(filters.status.unwrap_or_default()),
+            ),
+            (
+                SearchField::PaymentMethod,
+                FilterValues::CaseInsensitive(filters.payment_method.unwrap_or_default()),
+            ),
+            (
+                SearchField::Connector,
+                FilterValues::CaseInsensitive(filters.connector.unwrap_or_default()),
+            ),
+            (
+                SearchField::PaymentMethodType,
+                FilterValues::CaseInsensitive(filters.payment_method_type.unwrap_or_default()),
+            ),
+            (
+                SearchField::CardNetwork,
+                FilterValues::CaseInsensitive(filters.card_network.unwrap_or_default()),
+            ),
+            (
+                SearchField::CardLast4,
+                FilterValues::Exact(filters.card_last_4.unwrap_or_default()),
+            ),
+            (
+                SearchField::PaymentId,
+                FilterValues::Exact(filters.payment_id.unwrap_or_default()),
+            ),
+            (
+                SearchField::CustomerId,
+                FilterValues::Exact(filters.customer_id.unwrap_or_default()),
+            ),
+            (SearchField::Amount, FilterValues::Amounts(amounts)),
+            (
+                SearchField::SearchTags,
+                FilterValues::SearchTags(search_tags),
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, values)| !values.is_empty())
+        .collect())
+    }
+
+    /// Appends the conditions of the search to a query, for the table of the index
+    fn push_where_clause(&self, builder: &mut QueryBuilder<'_, Postgres>, index: SearchIndex) {
+        builder.push(" WHERE (");
+        self.push_auth_condition(builder);
+        builder.push(")");
+
+        if let Some(query_pattern) = &self.query_pattern {
+            builder.push(" AND (");
+            let mut separated = builder.separated(" OR ");
+            for column in get_searchable_columns(index) {
+                separated
+                    .push(format!("{column} ILIKE "))
+                    .push_bind_unseparated(query_pattern.clone());
+            }
+            // The connector identifiers of payouts are stored with the attempts of the payouts
+            if index == SearchIndex::Payouts {
+                separated
+                    .push("payout_id IN (SELECT payout_id FROM payout_attempt WHERE connector_payout_id ILIKE ")
+                    .push_bind_unseparated(query_pattern.clone())
+                    .push_unseparated(")");
+            }
+            builder.push(")");
+        }
+
+        for (field, values) in &self.filters {
+            // OpenSearch does not match documents without the field a filter is on
+            let Some(column) = get_column(index, *field) else {
+                builder.push(" AND FALSE");
+                continue;
+            };
+            match values {
+                FilterValues::CaseInsensitive(values) => {
+                    builder
+                        .push(format!(" AND lower({column}::text) = ANY("))
+                        .push_bind(
+                            values
+                                .iter()
+                                .map(|value| value.to_lowercase())
+                                .collect::<Vec<_>>(),
+                        )
+                        .push(")");
+                }
+                FilterValues::Exact(values) => {
+                    builder
+                        .push(format!(" AND {column} = ANY("))
+                        .push_bind(values.clone())
+                        .push(")");
+                }
+                FilterValues::Amounts(values) => {
+                    builder
+                        .push(format!(" AND {column} = ANY("))
+                        .push_bind(values.clone())
+                        .push(")");
+                }
+                FilterValues::SearchTags(values) => {
+                    builder
+                        .push(format!(" AND {column} ?| "))
+                        .push_bind(values.clone());
+                }
+            }
+        }
+
+        if let Some(time_range) = &self.time_range {
+            builder
+                .push(" AND created_at >= ")
+                .push_bind(time_range.start_time);
+            if let Some(end_time) = time_range.end_time {
+                builder.push(" AND created_at <= ").push_bind(end_time);
+            }
+        }
+    }
+
+    fn push_auth_condition(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        if self.search_params.is_empty() {
+            builder.push("FALSE");
+            return;
+        }
+
+        let mut separated = builder.separated(" OR ");
+        for auth in &self.search_params {
+            match auth {
+                AuthInfo::OrgLevel { org_id } => {
+                    separated
+                        .push("(organization_id = ")
+                        .push_bind_unseparated(org_id.get_string_repr().to_owned())
+                        .push_unseparated(")");
+                }
+                AuthInfo::MerchantLevel {
+                    org_id,
+                    merchant_ids,
+                } => {
+                    separated
+                        .push("(organization_id = ")
+                        .push_bind_unseparated(org_id.get_string_repr().to_owned())
+                        .push_unseparated(" AND merchant_id = ANY(")
+                        .push_bind_unseparated(
+                            merchant_ids
+                                .iter()
+                                .map(|merchant_id| merchant_id.get_string_repr().to_owned())
+                                .collect::<Vec<_>>(),
+                        )
+                        .push_unseparated("))");
+                }
+                AuthInfo::ProfileLevel {
+                    org_id,
+                    merchant_id,
+                    profile_ids,
+                } => {
+                    separated
+                        .push("(organization_id = ")
+                        .push_bind_unseparated(org_id.get_string_repr().to_owned())
+                        .push_unseparated(" AND merchant_id = ")
+                        .push_bind_unseparated(merchant_id.get_string_repr().to_owned())
+                        .push_unseparated(" AND profile_id = ANY(")
+                        .push_bind_unseparated(
+                            profile_ids
+                                .iter()
+                                .map(|profile_id| profile_id.get_string_repr().to_owned())
+                                .collect::<Vec<_>>(),
+                        )
+                        .push_unseparated("))");
+                }
+            }
+        }
+    }
+}
+
+impl FilterValues {
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::CaseInsensitive(values) | Self::Exact(values) | Self::SearchTags(values) => {
+                values.is_empty()
+            }
+            Self::Amounts(values) => values.is_empty(),
+        }
+    }
+}
+
+/// Escapes the wildcards of a `LIKE` pattern, so that the query is matched literally
+fn escape_like(query: &str) -> String {
+    query
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Builds the query fetching a page of the hits of an index, as JSON documents of the returned
+/// columns of the rows
+fn get_hits_query(
+    criteria: &SearchCriteria,
+    index: SearchIndex,
+    offset: i64,
+    count: i64,
+) -> QueryBuilder<'_, Postgres> {
+    let mut hits_query = QueryBuilder::<Postgres>::new(format!(
+        "SELECT to_jsonb(search_row)::text AS hit FROM (SELECT {} FROM {}",
+        get_returned_columns(index).join(", "),
+        get_table(index)
+    ));
+    criteria.push_where_clause(&mut hits_query, index);
+    hits_query
+        .push(" ORDER BY created_at DESC LIMIT ")
+        .push_bind(count)
+        .push(" OFFSET ")
+        .push_bind(offset)
+        .push(") AS search_row ORDER BY search_row.created_at DESC");
+    hits_query
+}
+
+async fn search_index(
+    sqlx_client: &SqlxClient,
+    criteria: &SearchCriteria,
+    index: SearchIndex,
+    offset: i64,
+    count: i64,
+) -> CustomResult<GetSearchResponse, OpenSearchError> {
+    let table = get_table(index);
+
+    let mut count_query =
+        QueryBuilder::<Postgres>::new(format!("SELECT count(*) AS count FROM {table}"));
+    criteria.push_where_clause(&mut count_query, index);
+    let total: i64 = count_query
+        .build()
+        .fetch_one(sqlx_client.get_pool())
+        .await
+        .and_then(|row| row.try_get("count"))
+        .change_context(OpenSearchError::ResponseError)
+        .attach_printable_lazy(|| format!("Failed to count search hits of {table}"))?;
+
+    let mut hits_query = get_hits_query(criteria, index, offset, count);
+    let hits = hits_query
+        .build()
+        .fetch_all(sqlx_client.get_pool())
+        .await
+        .change_context(OpenSearchError::ResponseError)
+        .attach_printable_lazy(|| format!("Failed to fetch search hits of {table}"))?
+        .into_iter()
+        .map(|row| {
+            row.try_get::<String, _>("hit")
+                .change_context(OpenSearchError::DeserialisationError)
+                .and_then(|hit| {
+                    serde_json::from_str(&hit).change_context(OpenSearchError::DeserialisationError)
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(GetSearchResponse {
+        count: u64::try_from(total).change_context(OpenSearchError::DeserialisationError)?,
+        index,
+        hits,
+        status: SearchStatus::Success,
+    })
+}
+
+pub async fn msearch_results(
+    pool: &AnalyticsProvider,
+    req: GetGlobalSearchRequest,
+    search_params: Vec<AuthInfo>,
+    indexes: Vec<SearchIndex>,
+) -> CustomResult<Vec<GetSearchResponse>, OpenSearchError> {
+    let sqlx_client = get_sqlx_client(pool)?;
+    let criteria = SearchCriteria::new(&req.query, req.filters, req.time_range, search_params)?;
+
+    Ok(join_all(indexes.into_iter().map(|index| {
+        let criteria = &criteria;
+        async move {
+            search_index(
+                sqlx_client,
+                criteria,
+                index,
+                0,
+                GLOBAL_SEARCH_HITS_PER_INDEX,
+            )
+            .await
+            .unwrap_or_else(|error| {
+                tracing::error!(index = ?index, ?error, "Search error");
+                GetSearchResponse {
+                    count: 0,
+                    index,
+                    hits: Vec::new(),
+                    status: SearchStatus::Failure,
+                }
+            })
+        }
+    }))
+    .await)
+}
+
+pub async fn search_results(
+    pool: &AnalyticsProvider,
+    req: GetSearchRequestWithIndex,
+    search_params: Vec<AuthInfo>,
+) -> CustomResult<GetSearchResponse, OpenSearchError> {
+    let sqlx_client = get_sqlx_client(pool)?;
+    let search_req = req.search_req;
+    let criteria = SearchCriteria::new(
+        &search_req.query,
+        search_req.filters,
+        search_req.time_range,
+        search_params,
+    )?;
+
+    Ok(search_index(
+        sqlx_client,
+        &criteria,
+        req.index,
+        search_req.offset,
+        search_req.count,
+    )
+    .await
+    .unwrap_or_else(|error| {
+        tracing::error!(index = ?req.index, ?error, "Search error");
+        GetSearchResponse {
+            count: 0,
+            index: req.index,
+            hits: Vec::new(),
+            status: SearchStatus::Failure,
+        }
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    /// Columns holding secrets, personal data or merchant provided data
+    const SENSITIVE_COLUMNS: &[&str] = &[
+        "client_secret",
+        "payment_token",
+        "encoded_data",
+        "browser_info",
+        "payment_method_data",
+        "payment_method_id",
+        "authentication_data",
+        "connector_metadata",
+        "connector_mandate_detail",
+        "customer_acceptance",
+        "mandate_data",
+        "mandate_details",
+        "customer_details",
+        "billing_details",
+        "shipping_details",
+        "shipping_address_id",
+        "billing_address_id",
+        "address_id",
+        "payout_method_id",
+        "metadata",
+        "frm_metadata",
+        "feature_metadata",
+        "order_details",
+        "return_url",
+        "evidence",
+        "fingerprint_id",
+        "network_transaction_id",
+    ];
+
+    fn get_criteria(query: &str) -> SearchCriteria {
+        SearchCriteria::new(
+            query,
+            None,
+            None,
+            vec![AuthInfo::MerchantLevel {
+                org_id: id_type::OrganizationId::try_from_string("org_1".to_string()).unwrap(),
+                merchant_ids: vec![id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap()],
+            }],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_sensitive_columns_are_not_returned() {
+        for index in SearchIndex::iter() {
+            for column in get_returned_columns(index) {
+                assert!(
+                    !SENSITIVE_COLUMNS.contains(column),
+                    "{column} is returned in the hits of {index:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_sensitive_columns_are_not_searched() {
+        for index in SearchIndex::iter() {
+            for column in get_searchable_columns(index) {
+                assert!(
+                    !SENSITIVE_COLUMNS.contains(column),
+                    "{column} is searched in {index:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_hits_are_ordered_by_a_returned_column() {
+        for index in SearchIndex::iter() {
+            assert!(get_returned_columns(index).contains(&"created_at"));
+        }
+    }
+
+    #[test]
+    fn test_hits_query_selects_only_returned_columns() {
+        let criteria = get_criteria("pay_1");
+        let hits_query = get_hits_query(&criteria, SearchIndex::PaymentIntents, 0, 10);
+        let sql = hits_query.sql();
+
+        assert!(sql.starts_with(&format!(
+            "SELECT to_jsonb(search_row)::text AS hit FROM (SELECT {} FROM payment_intent WHERE ",
+            get_returned_columns(SearchIndex::PaymentIntents).join(", ")
+        )));
+        assert!(sql.ends_with(") AS search_row ORDER BY search_row.created_at DESC"));
+        assert!(!sql.contains("client_secret"));
+        assert!(!sql.contains("customer_details"));
+    }
+
+    #[test]
+    fn test_empty_search_is_rejected() {
+        assert!(SearchCriteria::new("  ", None, None, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_like_wildcards_are_escaped() {
+        assert_eq!(escape_like(r"pay_1%\"), r"pay\_1\%\\");
+        assert_eq!(
+            get_criteria("pay_1").query_pattern,
+            Some(r"%pay\_1%".to_string())
+        );
+    }
+}
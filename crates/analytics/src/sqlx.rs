@@ -1,4 +1,4 @@
-use std::{fmt::Display, str::FromStr};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
 
 use api_models::{
     analytics::{frm::FrmTransactionType, refunds::RefundType},
@@ -13,7 +13,7 @@ use common_utils::{
 };
 use diesel_models::enums::{
     AttemptStatus, AuthenticationType, Currency, FraudCheckStatus, IntentStatus, PaymentMethod,
-    RefundStatus, RoutingApproach,
+    PayoutStatus, PayoutType, RefundStatus, RoutingApproach,
 };
 use error_stack::ResultExt;
 use sqlx::{
@@ -26,6 +26,7 @@ use storage_impl::config::Database;
 use time::PrimitiveDateTime;
 
 use super::{
+    event_tables::{EventRow, EventTable},
     health_check::HealthCheck,
     query::{Aggregate, ToSql, Window},
     types::{
@@ -65,6 +66,36 @@ impl SqlxClient {
             .expect("SQLX Pool Creation failed");
         Self { pool }
     }
+
+    pub(crate) fn get_pool(&self) -> &Pool<Postgres> {
+        &self.pool
+    }
+
+    pub async fn insert_events(
+        &self,
+        table: EventTable,
+        rows: &[EventRow],
+    ) -> CustomResult<u64, QueryExecutionError> {
+        let table_name = table.table_name();
+        let rows =
+            serde_json::to_string(rows).change_context(QueryExecutionError::DatabaseError)?;
+
+        // The rows are passed as a single JSON array so that a batch is inserted in one query
+        sqlx::query(&format!(
+            "INSERT INTO {table_name} (event_id, merchant_id, created_at, event) \
+             SELECT loaded_event->>'event_id', loaded_event->'event'->>'merchant_id', \
+             to_timestamp((loaded_event->>'created_at')::bigint / 1000.0) AT TIME ZONE 'UTC', \
+             loaded_event->'event' \
+             FROM jsonb_array_elements($1::jsonb) AS loaded_event \
+             ON CONFLICT (event_id) DO NOTHING"
+        ))
+        .bind(rows)
+        .execute(&self.pool)
+        .await
+        .map(|result| result.rows_affected())
+        .change_context(QueryExecutionError::DatabaseError)
+        .attach_printable_lazy(|| format!("Failed to insert events into {table_name}"))
+    }
 }
 
 pub trait DbType {
@@ -104,6 +135,8 @@ db_type!(TransactionStatus);
 db_type!(AuthenticationConnectors);
 db_type!(DecoupledAuthenticationType);
 db_type!(RoutingApproach);
+db_type!(PayoutStatus);
+db_type!(PayoutType);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -163,12 +196,19 @@ impl super::payment_intents::metrics::PaymentIntentMetricAnalytics for SqlxClien
 impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
 impl super::refunds::distribution::RefundDistributionAnalytics for SqlxClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for SqlxClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for SqlxClient {}
+impl super::payouts::distribution::PayoutDistributionAnalytics for SqlxClient {}
+impl super::payment_sessions::metrics::PaymentSessionMetricAnalytics for SqlxClient {}
+impl super::payment_sessions::filters::PaymentSessionFilterAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
 impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::reports::ReportAnalytics for SqlxClient {}
+impl super::alerts::AlertAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -188,6 +228,37 @@ impl AnalyticsDataSource for SqlxClient {
             .collect::<Result<Vec<_>, _>>()
             .change_context(QueryExecutionError::RowExtractionFailure)
     }
+
+    fn get_metadata_dimension(
+        table: AnalyticsCollection,
+        key: &str,
+    ) -> error_stack::Result<String, ParsingError> {
+        let lookup_table = match table {
+            AnalyticsCollection::PaymentIntent => {
+                return Ok(format!("coalesce(metadata ->> '{key}', '')"))
+            }
+            AnalyticsCollection::Payment => "payment_attempt",
+            AnalyticsCollection::Refund => "refund",
+            AnalyticsCollection::Dispute => "dispute",
+            _ => Err(error_stack::report!(ParsingError::UnknownError))
+                .attach_printable(format!("Metadata dimensions are not supported for {table}"))?,
+        };
+        // postgres has no event time copy of the metadata, it is read off the payment intent
+        Ok(format!(
+            "coalesce((SELECT payment_intent.metadata ->> '{key}' FROM payment_intent \
+            WHERE payment_intent.merchant_id = {lookup_table}.merchant_id \
+            AND payment_intent.payment_id = {lookup_table}.payment_id), '')"
+        ))
+    }
+
+    fn get_metadata_dimensions_map(dimensions: &[(&str, String)]) -> String {
+        let entries = dimensions
+            .iter()
+            .map(|(key, expression)| format!("'{key}', {expression}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("jsonb_build_object({entries})::text")
+    }
 }
 #[async_trait::async_trait]
 impl HealthCheck for SqlxClient {
@@ -200,6 +271,19 @@ impl HealthCheck for SqlxClient {
     }
 }
 
+/// Metadata dimensions are selected as a json object, see `get_metadata_dimensions_map`
+fn get_metadata_dimensions(row: &PgRow) -> sqlx::Result<Option<BTreeMap<String, String>>> {
+    let metadata_dimensions: Option<String> =
+        row.try_get("metadata_dimensions").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+    metadata_dimensions
+        .map(|value| serde_json::from_str(&value))
+        .transpose()
+        .map_err(|e| sqlx::Error::Decode(Box::new(e)))
+}
+
 impl<'a> FromRow<'a, PgRow> for super::auth_events::metrics::AuthEventMetricRow {
     fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
         let authentication_status: Option<DBEnumWrapper<AuthenticationStatus>> =
@@ -597,6 +681,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let metadata_dimensions = get_metadata_dimensions(row)?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -614,6 +699,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             refund_error_message,
             total,
             count,
+            metadata_dimensions,
             start_bucket,
             end_bucket,
         })
@@ -759,6 +845,7 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let metadata_dimensions = get_metadata_dimensions(row)?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -788,6 +875,7 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             is_debit_routed,
             total,
             count,
+            metadata_dimensions,
             start_bucket,
             end_bucket,
         })
@@ -895,6 +983,7 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let metadata_dimensions = get_metadata_dimensions(row)?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -925,6 +1014,7 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             signature_network,
             is_issuer_regulated,
             is_debit_routed,
+            metadata_dimensions,
             start_bucket,
             end_bucket,
         })
@@ -1216,6 +1306,285 @@ impl<'a> FromRow<'a, PgRow> for super::payment_intents::filters::PaymentIntentFi
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::payouts::metrics::PayoutMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+            error_message,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::filters::PayoutFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+            error_message,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::distribution::PayoutDistributionRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+            error_message,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payment_sessions::metrics::PaymentSessionMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let first_payment_method: Option<String> =
+            row.try_get("first_payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let platform: Option<String> = row.try_get("platform").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let country: Option<String> = row.try_get("country").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let last_funnel_step: Option<String> =
+            row.try_get("last_funnel_step").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let converted_count: Option<i64> = row.try_get("converted_count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            payment_method,
+            first_payment_method,
+            connector,
+            platform,
+            country,
+            currency,
+            profile_id,
+            last_funnel_step,
+            count,
+            converted_count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payment_sessions::filters::PaymentSessionFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let first_payment_method: Option<String> =
+            row.try_get("first_payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let platform: Option<String> = row.try_get("platform").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let country: Option<String> = row.try_get("country").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let last_funnel_step: Option<String> =
+            row.try_get("last_funnel_step").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        Ok(Self {
+            payment_method,
+            first_payment_method,
+            connector,
+            platform,
+            country,
+            currency,
+            profile_id,
+            last_funnel_step,
+        })
+    }
+}
+
 impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
     fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
         let currency: Option<DBEnumWrapper<Currency>> =
@@ -1304,6 +1673,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::distribution::RefundDistribution
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let metadata_dimensions = get_metadata_dimensions(row)?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -1321,6 +1691,7 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::distribution::RefundDistribution
             count,
             refund_reason,
             refund_error_message,
+            metadata_dimensions,
             start_bucket,
             end_bucket,
         })
@@ -1414,6 +1785,7 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let metadata_dimensions = get_metadata_dimensions(row)?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -1428,12 +1800,125 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
             currency,
             total,
             count,
+            metadata_dimensions,
             start_bucket,
             end_bucket,
         })
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::reports::PaymentReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            payment_id: row.try_get("payment_id")?,
+            attempt_id: row.try_get("attempt_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            status: row.try_get("status")?,
+            amount: row.try_get("amount")?,
+            currency: row.try_get("currency")?,
+            connector: row.try_get("connector")?,
+            payment_method: row.try_get("payment_method")?,
+            payment_method_type: row.try_get("payment_method_type")?,
+            connector_transaction_id: row.try_get("connector_transaction_id")?,
+            error_code: row.try_get("error_code")?,
+            error_message: row.try_get("error_message")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::RefundReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            refund_id: row.try_get("refund_id")?,
+            payment_id: row.try_get("payment_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            connector: row.try_get("connector")?,
+            refund_status: row.try_get("refund_status")?,
+            refund_amount: row.try_get("refund_amount")?,
+            currency: row.try_get("currency")?,
+            refund_reason: row.try_get("refund_reason")?,
+            refund_error_message: row.try_get("refund_error_message")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::DisputeReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            dispute_id: row.try_get("dispute_id")?,
+            payment_id: row.try_get("payment_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            connector: row.try_get("connector")?,
+            dispute_stage: row.try_get("dispute_stage")?,
+            dispute_status: row.try_get("dispute_status")?,
+            dispute_amount: row.try_get("dispute_amount")?,
+            currency: row.try_get("currency")?,
+            connector_reason: row.try_get("connector_reason")?,
+            challenge_required_by: row.try_get("challenge_required_by")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::PayoutReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            payout_id: row.try_get("payout_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            customer_id: row.try_get("customer_id")?,
+            payout_type: row.try_get("payout_type")?,
+            status: row.try_get("status")?,
+            amount: row.try_get("amount")?,
+            source_currency: row.try_get("source_currency")?,
+            destination_currency: row.try_get("destination_currency")?,
+            created_at: row.try_get("created_at")?,
+            last_modified_at: row.try_get("last_modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::reports::AuthenticationReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            authentication_id: row.try_get("authentication_id")?,
+            merchant_id: row.try_get("merchant_id")?,
+            profile_id: row.try_get("profile_id")?,
+            payment_id: row.try_get("payment_id")?,
+            authentication_connector: row.try_get("authentication_connector")?,
+            authentication_status: row.try_get("authentication_status")?,
+            trans_status: row.try_get("trans_status")?,
+            amount: row.try_get("amount")?,
+            currency: row.try_get("currency")?,
+            error_code: row.try_get("error_code")?,
+            error_message: row.try_get("error_message")?,
+            created_at: row.try_get("created_at")?,
+            modified_at: row.try_get("modified_at")?,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::alerts::AlertPaymentRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        Ok(Self {
+            profile_id: row.try_get("profile_id")?,
+            connector: row.try_get("connector")?,
+            payment_method: row.try_get("payment_method")?,
+            status: row.try_get("status")?,
+            error_code: row.try_get("error_code")?,
+            count: row.try_get("count")?,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -1472,14 +1957,70 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             Self::Dispute => Ok("dispute".to_string()),
             Self::DisputeSessionized => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("DisputeSessionized table is not implemented for Sqlx"))?,
-            Self::Authentications => Err(error_stack::report!(ParsingError::UnknownError)
-                .attach_printable("Authentications table is not implemented for Sqlx"))?,
+            Self::Authentications => Ok("authentication".to_string()),
             Self::RoutingEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("RoutingEvents table is not implemented for Sqlx"))?,
+            // The connector and the errors of a payout are stored with its latest attempt, which
+            // is looked up by the payout ID the attempts reference rather than by an attempt ID
+            // derived from the attempt count
+            Self::Payout => Ok(
+                "(SELECT payouts.*, latest_attempt.connector, latest_attempt.error_message, \
+                 latest_attempt.error_code FROM payouts LEFT JOIN LATERAL \
+                 (SELECT connector, error_message, error_code FROM payout_attempt \
+                 WHERE payout_attempt.merchant_id = payouts.merchant_id \
+                 AND payout_attempt.payout_id = payouts.payout_id \
+                 ORDER BY payout_attempt.created_at DESC, payout_attempt.payout_attempt_id DESC \
+                 LIMIT 1) AS latest_attempt ON TRUE) AS payouts"
+                    .to_string(),
+            ),
+            // One row per payment intent with the funnel steps it reached as 0 / 1 flags, built
+            // from its attempts and authentications. SDK events are not stored in postgres.
+            Self::PaymentSessions => Ok("(SELECT sessions.*, CASE \
+                 WHEN sessions.session_converted = 1 THEN 'session_converted' \
+                 WHEN sessions.authentication_completed = 1 THEN 'authentication_completed' \
+                 WHEN sessions.authentication_started = 1 THEN 'authentication_started' \
+                 WHEN sessions.payment_attempted = 1 THEN 'payment_attempted' \
+                 WHEN sessions.payment_method_selected = 1 THEN 'payment_method_selected' \
+                 ELSE 'session_started' END AS last_funnel_step \
+                 FROM (SELECT payment_intent.payment_id, payment_intent.merchant_id, \
+                 payment_intent.organization_id, payment_intent.profile_id, \
+                 payment_intent.created_at, payment_intent.currency, \
+                 payment_intent.business_country::text AS country, \
+                 active_attempt.payment_method, active_attempt.connector, \
+                 attempts.first_payment_method, attempts.platform, \
+                 (attempts.first_payment_method IS NOT NULL)::int AS payment_method_selected, \
+                 coalesce(attempts.attempted, false)::int AS payment_attempted, \
+                 (authentications.started > 0)::int AS authentication_started, \
+                 coalesce(authentications.completed, false)::int AS authentication_completed, \
+                 (payment_intent.status IN ('succeeded', 'requires_capture', \
+                 'partially_captured', 'partially_captured_and_capturable'))::int \
+                 AS session_converted \
+                 FROM payment_intent LEFT JOIN payment_attempt AS active_attempt \
+                 ON active_attempt.merchant_id = payment_intent.merchant_id \
+                 AND active_attempt.attempt_id = payment_intent.active_attempt_id \
+                 LEFT JOIN LATERAL (SELECT (array_agg(payment_method ORDER BY created_at) \
+                 FILTER (WHERE payment_method IS NOT NULL))[1] AS first_payment_method, \
+                 (array_agg(browser_info ->> 'os_type' ORDER BY created_at) \
+                 FILTER (WHERE browser_info ->> 'os_type' IS NOT NULL))[1] AS platform, \
+                 bool_or(confirm) AS attempted FROM payment_attempt \
+                 WHERE payment_attempt.merchant_id = payment_intent.merchant_id \
+                 AND payment_attempt.payment_id = payment_intent.payment_id) AS attempts ON TRUE \
+                 LEFT JOIN LATERAL (SELECT count(*) AS started, \
+                 bool_or(authentication_status = 'success') AS completed FROM authentication \
+                 WHERE authentication.merchant_id = payment_intent.merchant_id \
+                 AND authentication.payment_id = payment_intent.payment_id) AS authentications \
+                 ON TRUE) AS sessions) AS payment_sessions"
+                .to_string()),
         }
     }
 }
 
+impl ToSql<SqlxClient> for super::payouts::metrics::PayoutProcessingTime {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok("extract(epoch from (last_modified_at - created_at))::bigint".to_string())
+    }
+}
+
 impl<T> ToSql<SqlxClient> for Aggregate<T>
 where
     T: ToSql<SqlxClient>,
@@ -1604,3 +2145,53 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::query::QueryBuilder;
+
+    const PAYOUT_TABLE: &str = "(SELECT payouts.*, latest_attempt.connector, \
+        latest_attempt.error_message, latest_attempt.error_code FROM payouts LEFT JOIN LATERAL \
+        (SELECT connector, error_message, error_code FROM payout_attempt \
+        WHERE payout_attempt.merchant_id = payouts.merchant_id \
+        AND payout_attempt.payout_id = payouts.payout_id \
+        ORDER BY payout_attempt.created_at DESC, payout_attempt.payout_attempt_id DESC \
+        LIMIT 1) AS latest_attempt ON TRUE) AS payouts";
+
+    #[test]
+    fn test_payouts_are_joined_with_their_latest_attempt() {
+        assert_eq!(
+            AnalyticsCollection::Payout
+                .to_sql(&TableEngine::BasicTree)
+                .unwrap(),
+            PAYOUT_TABLE
+        );
+    }
+
+    #[test]
+    fn test_payout_query_groups_by_attempt_columns() {
+        let mut query_builder: QueryBuilder<SqlxClient> =
+            QueryBuilder::new(AnalyticsCollection::Payout);
+        query_builder.add_select_column("connector").unwrap();
+        query_builder
+            .add_select_column(Aggregate::Count {
+                field: None,
+                alias: Some("count"),
+            })
+            .unwrap();
+        query_builder
+            .add_filter_clause("merchant_id", "merchant_1")
+            .unwrap();
+        query_builder.add_group_by_clause("connector").unwrap();
+
+        assert_eq!(
+            query_builder.build_query().unwrap(),
+            format!(
+                "SELECT connector, count(*) as count FROM {PAYOUT_TABLE} \
+                 WHERE ( merchant_id = 'merchant_1' ) GROUP BY connector"
+            )
+        );
+    }
+}
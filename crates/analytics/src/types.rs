@@ -22,6 +22,8 @@ pub enum AnalyticsDomain {
     ApiEvents,
     Dispute,
     Routing,
+    Payouts,
+    PaymentSessions,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -44,6 +46,8 @@ pub enum AnalyticsCollection {
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
     RoutingEvents,
+    Payout,
+    PaymentSessions,
 }
 
 #[allow(dead_code)]
@@ -96,6 +100,15 @@ where
     fn get_table_engine(_table: AnalyticsCollection) -> TableEngine {
         TableEngine::BasicTree
     }
+
+    /// Expression reading the value of the payment metadata `key` for the rows of `table`
+    fn get_metadata_dimension(
+        table: AnalyticsCollection,
+        key: &str,
+    ) -> error_stack::Result<String, ParsingError>;
+
+    /// Expression collecting `(key, expression)` pairs into a single key value map
+    fn get_metadata_dimensions_map(dimensions: &[(&str, String)]) -> String;
 }
 
 pub trait LoadRow<T>
@@ -154,6 +167,32 @@ impl ErrorSwitch<FiltersError> for QueryBuildingError {
     }
 }
 
+pub type ReportResult<T> = CustomResult<T, ReportError>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReportError {
+    #[error("Error building query")]
+    QueryBuildingError,
+    #[error("Error running Query")]
+    QueryExecutionFailure,
+    #[error("Error writing report file")]
+    FileWriteFailure,
+    #[error("Error uploading report file")]
+    FileUploadFailure,
+}
+
+impl ErrorSwitch<ReportError> for QueryBuildingError {
+    fn switch(&self) -> ReportError {
+        ReportError::QueryBuildingError
+    }
+}
+
+impl ErrorSwitch<AnalyticsError> for ReportError {
+    fn switch(&self) -> AnalyticsError {
+        AnalyticsError::UnknownError
+    }
+}
+
 impl ErrorSwitch<AnalyticsError> for FiltersError {
     fn switch(&self) -> AnalyticsError {
         match self {
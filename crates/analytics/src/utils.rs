@@ -4,7 +4,9 @@ use api_models::analytics::{
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
+    payment_sessions::{PaymentSessionDimensions, PaymentSessionMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
     NameDescription,
@@ -131,3 +133,19 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_payout_metrics_info() -> Vec<NameDescription> {
+    PayoutMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_payout_dimensions() -> Vec<NameDescription> {
+    PayoutDimensions::iter().map(Into::into).collect()
+}
+
+pub fn get_payment_session_metrics_info() -> Vec<NameDescription> {
+    PaymentSessionMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_payment_session_dimensions() -> Vec<NameDescription> {
+    PaymentSessionDimensions::iter().map(Into::into).collect()
+}
@@ -729,6 +729,11 @@ pub struct WebhookDetails {
     #[cfg(feature = "payouts")]
     #[schema(value_type = Option<Vec<PayoutStatus>>, example = json!(["success", "failed"]))]
     pub payout_statuses_enabled: Option<Vec<api_enums::PayoutStatus>>,
+
+    /// The format in which webhooks are delivered to the merchant. Set to `stripe` to receive
+    /// webhooks in the Stripe event envelope format.
+    #[schema(value_type = Option<OutgoingWebhookFormat>, example = "hyperswitch")]
+    pub webhook_format: Option<api_enums::OutgoingWebhookFormat>,
 }
 
 impl WebhookDetails {
@@ -755,6 +760,7 @@ impl WebhookDetails {
             payout_statuses_enabled: other
                 .payout_statuses_enabled
                 .or(self.payout_statuses_enabled),
+            webhook_format: other.webhook_format.or(self.webhook_format),
         }
     }
 
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 pub use common_utils::types::TimeRange;
 use common_utils::{events::ApiEventMetric, pii::EmailStrategy, types::authentication::AuthInfo};
@@ -11,19 +11,25 @@ use self::{
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
+    payment_sessions::{PaymentSessionDimensions, PaymentSessionMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutDistributions, PayoutMetrics},
     refunds::{RefundDimensions, RefundDistributions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
 };
 pub mod active_payments;
+pub mod alerts;
 pub mod api_event;
 pub mod auth_events;
 pub mod connector_events;
 pub mod disputes;
+pub mod exports;
 pub mod frm;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
+pub mod payment_sessions;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
 pub mod routing_events;
 pub mod sdk_events;
@@ -74,6 +80,7 @@ pub struct AnalyticsRequest {
     pub payment_attempt: Option<GetPaymentMetricRequest>,
     pub refund: Option<GetRefundMetricRequest>,
     pub dispute: Option<GetDisputeMetricRequest>,
+    pub payout: Option<GetPayoutMetricRequest>,
 }
 
 impl AnalyticsRequest {
@@ -97,8 +104,55 @@ impl AnalyticsRequest {
                 .as_ref()
                 .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
                 .unwrap_or_default()
+            || self
+                .payout
+                .as_ref()
+                .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
+                .unwrap_or_default()
     }
 }
+/// Maximum number of metadata keys a profile can register as analytics dimensions
+pub const MAX_METADATA_DIMENSIONS_PER_PROFILE: usize = 5;
+
+/// Metadata keys end up in analytics queries, so only plain identifiers are accepted as keys
+pub fn is_valid_metadata_dimension(key: &str) -> bool {
+    (1..=64).contains(&key.len())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Filters on, and grouping by, the payment metadata keys registered as analytics dimensions
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct MetadataDimensionFilters {
+    /// Metadata values to filter on, keyed by metadata key
+    #[serde(default)]
+    pub values: BTreeMap<String, Vec<String>>,
+    /// Metadata keys to group the results by, on top of the requested dimensions
+    #[serde(default)]
+    pub group_by: Vec<String>,
+}
+
+impl MetadataDimensionFilters {
+    /// Every metadata key the filters refer to
+    pub fn get_keys(&self) -> impl Iterator<Item = &String> {
+        self.values.keys().chain(self.group_by.iter())
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataDimensionsRequest {
+    pub metadata_keys: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataDimensionsResponse {
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub metadata_keys: Vec<String>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentMetricRequest {
@@ -151,6 +205,34 @@ pub struct RefundDistributionBody {
 pub struct ReportRequest {
     pub time_range: TimeRange,
     pub emails: Option<Vec<Secret<String, EmailStrategy>>>,
+    /// HTTPS endpoint notified with the report details once the report has been generated. Only
+    /// allowed for reports of a single merchant, the webhook is signed with the webhook signing key
+    /// of the merchant.
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportResponse {
+    pub report_id: String,
+    pub report_type: common_enums::AnalyticsReportType,
+    pub status: common_enums::AnalyticsReportStatus,
+    pub time_range: TimeRange,
+    pub row_count: Option<i64>,
+    pub file_size: Option<i64>,
+    /// Time limited link to download the generated report, present once the report is completed
+    pub download_url: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ReportDownloadRequest {
+    pub expires_at: i64,
+    pub signature: String,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -191,6 +273,42 @@ pub struct GetRefundMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutDistributionBody {
+    pub distribution_for: PayoutDistributions,
+    pub distribution_cardinality: QueryLimit,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+    #[serde(default)]
+    pub filters: payouts::PayoutFilters,
+    pub metrics: HashSet<PayoutMetrics>,
+    pub distribution: Option<PayoutDistributionBody>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentSessionMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PaymentSessionDimensions>,
+    #[serde(default)]
+    pub filters: payment_sessions::PaymentSessionFilters,
+    pub metrics: HashSet<PaymentSessionMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmMetricRequest {
@@ -286,6 +404,22 @@ pub struct RefundsAnalyticsMetadata {
     pub total_refund_reason_count: Option<u64>,
     pub total_refund_error_message_count: Option<u64>,
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutsAnalyticsMetadata {
+    pub total_payout_success_rate: Option<f64>,
+    pub total_payout_processed_amount: Option<u64>,
+    pub total_payout_processed_amount_in_usd: Option<u64>,
+    pub total_payout_processed_count: Option<u64>,
+    pub total_payout_failure_reason_count: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentSessionsAnalyticsMetadata {
+    pub total_sessions_started: Option<u64>,
+    pub total_sessions_converted: Option<u64>,
+    pub total_session_conversion_rate: Option<f64>,
+}
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentFiltersRequest {
@@ -349,6 +483,48 @@ pub struct RefundFilterValue {
     pub values: Vec<String>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFiltersResponse {
+    pub query_data: Vec<PayoutFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFilterValue {
+    pub dimension: PayoutDimensions,
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentSessionFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PaymentSessionDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentSessionFiltersResponse {
+    pub query_data: Vec<PaymentSessionFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentSessionFilterValue {
+    pub dimension: PaymentSessionDimensions,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmFilterRequest {
@@ -431,6 +607,18 @@ pub struct RefundsMetricsResponse<T> {
 }
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
+pub struct PayoutsMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [PayoutsAnalyticsMetadata; 1],
+}
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentSessionsMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [PaymentSessionsAnalyticsMetadata; 1],
+}
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct DisputesMetricsResponse<T> {
     pub query_data: Vec<T>,
     pub meta_data: [DisputesAnalyticsMetadata; 1],
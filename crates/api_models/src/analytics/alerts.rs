@@ -0,0 +1,167 @@
+use common_enums::{
+    AnalyticsAlertBaseline, AnalyticsAlertDeliveryStatus, AnalyticsAlertDirection,
+    AnalyticsAlertMetric,
+};
+use common_utils::{id_type, pii::EmailStrategy};
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+use crate::enums::{Connector, PaymentMethod};
+
+/// Payment attempts an alert rule is evaluated on. Empty lists do not restrict the attempts.
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleFilters {
+    #[serde(default)]
+    pub profile_id: Vec<id_type::ProfileId>,
+    #[serde(default)]
+    pub connector: Vec<Connector>,
+    #[serde(default)]
+    pub payment_method: Vec<PaymentMethod>,
+}
+
+/// Destination the alerts of a rule are delivered to
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertChannel {
+    /// The alert is posted as JSON, signed with HMAC-SHA512 in the `X-Webhook-Signature` header
+    Webhook { url: String },
+    Email {
+        email: Secret<String, EmailStrategy>,
+    },
+    /// Slack compatible incoming webhook, the alert is posted as a `text` message
+    Slack { url: Secret<String> },
+}
+
+/// Time range during which the anomalies detected by a rule are recorded without being delivered
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertMuteWindow {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+}
+
+impl AlertMuteWindow {
+    pub fn contains(&self, time: PrimitiveDateTime) -> bool {
+        self.start_time <= time && time < self.end_time
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAlertRuleRequest {
+    pub name: String,
+    pub metric: AnalyticsAlertMetric,
+    #[serde(default)]
+    pub filters: AlertRuleFilters,
+    /// Defaults to the windows preceding the latest window
+    pub baseline: Option<AnalyticsAlertBaseline>,
+    /// Length of the windows the metric is computed over, defaults to an hour
+    pub evaluation_window_in_mins: Option<u32>,
+    /// Number of windows the baseline is computed from
+    pub baseline_periods: Option<u32>,
+    /// Number of standard deviations from the baseline mean at which an alert is raised
+    pub deviation_threshold: Option<f64>,
+    /// Defaults to drops for the success rate and to rises for the other metrics
+    pub direction: Option<AnalyticsAlertDirection>,
+    /// Minimum number of payment attempts in a window for the window to be evaluated
+    pub min_volume: Option<u32>,
+    pub channels: Vec<AlertChannel>,
+    #[serde(default)]
+    pub mute_windows: Vec<AlertMuteWindow>,
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAlertRuleRequest {
+    pub name: Option<String>,
+    pub filters: Option<AlertRuleFilters>,
+    pub baseline: Option<AnalyticsAlertBaseline>,
+    pub evaluation_window_in_mins: Option<u32>,
+    pub baseline_periods: Option<u32>,
+    pub deviation_threshold: Option<f64>,
+    pub direction: Option<AnalyticsAlertDirection>,
+    pub min_volume: Option<u32>,
+    pub channels: Option<Vec<AlertChannel>>,
+    /// Replaces the mute windows of the rule
+    pub mute_windows: Option<Vec<AlertMuteWindow>>,
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleResponse {
+    pub rule_id: String,
+    pub name: String,
+    pub metric: AnalyticsAlertMetric,
+    pub filters: AlertRuleFilters,
+    pub baseline: AnalyticsAlertBaseline,
+    pub evaluation_window_in_mins: u32,
+    pub baseline_periods: u32,
+    pub deviation_threshold: f64,
+    pub direction: AnalyticsAlertDirection,
+    pub min_volume: u32,
+    pub channels: Vec<AlertChannel>,
+    pub mute_windows: Vec<AlertMuteWindow>,
+    pub is_enabled: bool,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_evaluated_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleListResponse {
+    pub rules: Vec<AlertRuleResponse>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct AlertRuleId {
+    pub rule_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertHistoryRequest {
+    pub rule_id: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// An anomaly detected by an alert rule
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertResponse {
+    pub alert_id: String,
+    pub rule_id: String,
+    pub metric: AnalyticsAlertMetric,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    /// Error code of the declines, for alerts on the decline code share
+    pub error_code: Option<String>,
+    pub observed_value: f64,
+    pub baseline_mean: f64,
+    pub baseline_stddev: f64,
+    /// Number of payment attempts, declines or connector calls the observed value is computed from
+    pub sample_size: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub window_end: PrimitiveDateTime,
+    pub delivery_status: AnalyticsAlertDeliveryStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertHistoryResponse {
+    pub alerts: Vec<AlertResponse>,
+}
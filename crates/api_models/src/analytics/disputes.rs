@@ -1,9 +1,9 @@
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, BTreeMap},
     hash::{Hash, Hasher},
 };
 
-use super::{ForexMetric, NameDescription, TimeRange};
+use super::{ForexMetric, MetadataDimensionFilters, NameDescription, TimeRange};
 use crate::enums::{Currency, DisputeStage};
 
 #[derive(
@@ -87,6 +87,8 @@ pub struct DisputeFilters {
     pub connector: Vec<String>,
     #[serde(default)]
     pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub metadata: MetadataDimensionFilters,
 }
 
 #[derive(Debug, serde::Serialize, Eq)]
@@ -94,6 +96,8 @@ pub struct DisputeMetricsBucketIdentifier {
     pub dispute_stage: Option<DisputeStage>,
     pub connector: Option<String>,
     pub currency: Option<Currency>,
+    /// Values of the metadata keys the results were grouped by
+    pub metadata_dimensions: Option<BTreeMap<String, String>>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -106,6 +110,7 @@ impl Hash for DisputeMetricsBucketIdentifier {
         self.dispute_stage.hash(state);
         self.connector.hash(state);
         self.currency.hash(state);
+        self.metadata_dimensions.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -124,12 +129,14 @@ impl DisputeMetricsBucketIdentifier {
         dispute_stage: Option<DisputeStage>,
         connector: Option<String>,
         currency: Option<Currency>,
+        metadata_dimensions: Option<BTreeMap<String, String>>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
             dispute_stage,
             connector,
             currency,
+            metadata_dimensions,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+
+use common_enums::{
+    AnalyticsExportFormat, AnalyticsExportFrequency, AnalyticsExportRunStatus, AnalyticsReportType,
+};
+use common_utils::id_type;
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+/// Location the files of an export are delivered to
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportDestination {
+    /// The file storage backend configured for the application
+    FileStorage {
+        /// Directory the files are stored under, defaults to the export id
+        path_prefix: Option<String>,
+    },
+    Sftp {
+        host: String,
+        /// Defaults to 22
+        port: Option<u16>,
+        username: String,
+        password: Option<Secret<String>>,
+        /// PEM encoded private key, preferred over the password when both are provided
+        private_key: Option<Secret<String>>,
+        /// SHA256 fingerprint of the server host key, in the `SHA256:<base64>` format printed by
+        /// `ssh-keygen -l`
+        host_key_fingerprint: String,
+        directory: String,
+    },
+}
+
+/// Export destination without its credentials
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExportDestinationResponse {
+    FileStorage {
+        path_prefix: Option<String>,
+    },
+    Sftp {
+        host: String,
+        port: Option<u16>,
+        username: String,
+        host_key_fingerprint: String,
+        directory: String,
+    },
+}
+
+impl From<ExportDestination> for ExportDestinationResponse {
+    fn from(destination: ExportDestination) -> Self {
+        match destination {
+            ExportDestination::FileStorage { path_prefix } => Self::FileStorage { path_prefix },
+            ExportDestination::Sftp {
+                host,
+                port,
+                username,
+                password: _,
+                private_key: _,
+                host_key_fingerprint,
+                directory,
+            } => Self::Sftp {
+                host,
+                port,
+                username,
+                host_key_fingerprint,
+                directory,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateExportRequest {
+    pub name: String,
+    pub dataset: AnalyticsReportType,
+    /// Columns of the dataset included in the files, defaults to all of them
+    pub columns: Option<Vec<String>>,
+    /// Values the dataset columns are restricted to
+    #[serde(default)]
+    pub filters: HashMap<String, Vec<String>>,
+    pub format: AnalyticsExportFormat,
+    pub frequency: AnalyticsExportFrequency,
+    /// Hour of the day (UTC) after which the previous period is exported, defaults to 0
+    pub schedule_hour: Option<u8>,
+    pub destination: ExportDestination,
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateExportRequest {
+    pub name: Option<String>,
+    pub columns: Option<Vec<String>>,
+    /// Replaces the filters of the export
+    pub filters: Option<HashMap<String, Vec<String>>>,
+    pub format: Option<AnalyticsExportFormat>,
+    pub schedule_hour: Option<u8>,
+    pub destination: Option<ExportDestination>,
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResponse {
+    pub export_id: String,
+    pub name: String,
+    pub dataset: AnalyticsReportType,
+    pub columns: Vec<String>,
+    pub filters: HashMap<String, Vec<String>>,
+    pub format: AnalyticsExportFormat,
+    pub frequency: AnalyticsExportFrequency,
+    pub schedule_hour: u8,
+    pub destination: ExportDestinationResponse,
+    pub is_enabled: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportListResponse {
+    pub exports: Vec<ExportResponse>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ExportId {
+    pub export_id: String,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct ExportRunId {
+    pub export_id: String,
+    pub run_id: String,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRunListRequest {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Export of the dataset for a single period
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRunResponse {
+    pub run_id: String,
+    pub export_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    pub status: AnalyticsExportRunStatus,
+    pub file_name: Option<String>,
+    pub file_size: Option<i64>,
+    pub row_count: Option<i64>,
+    /// Hex encoded SHA256 digest of the file
+    pub checksum: Option<String>,
+    pub attempt_count: i32,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportRunListResponse {
+    pub runs: Vec<ExportRunResponse>,
+}
+
+/// Manifest delivered next to the files of an export run
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportManifest {
+    pub export_id: String,
+    pub run_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub dataset: AnalyticsReportType,
+    pub format: AnalyticsExportFormat,
+    pub columns: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub generated_at: PrimitiveDateTime,
+    pub files: Vec<ExportManifestFile>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportManifestFile {
+    pub name: String,
+    pub size: u64,
+    pub row_count: u64,
+    /// Hex encoded SHA256 digest of the file
+    pub sha256: String,
+}
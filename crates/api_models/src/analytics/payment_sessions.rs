@@ -0,0 +1,238 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_utils::id_type;
+
+use super::{NameDescription, TimeRange};
+use crate::enums::Currency;
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct PaymentSessionFilters {
+    #[serde(default)]
+    pub payment_method: Vec<String>,
+    #[serde(default)]
+    pub first_payment_method: Vec<String>,
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub platform: Vec<String>,
+    #[serde(default)]
+    pub country: Vec<String>,
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub profile_id: Vec<id_type::ProfileId>,
+    #[serde(default)]
+    pub last_funnel_step: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentSessionDimensions {
+    /// Payment method of the latest attempt in the session
+    PaymentMethod,
+    /// Payment method the customer tried first in the session
+    FirstPaymentMethod,
+    Connector,
+    /// Device platform reported by the SDK, or the OS of the customer's browser
+    Platform,
+    Country,
+    Currency,
+    ProfileId,
+    /// Furthest funnel step the session reached, see [`PaymentSessionFunnelStep`]
+    LastFunnelStep,
+}
+
+/// Steps of the payment session funnel, in the order a session goes through them
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentSessionFunnelStep {
+    SessionStarted,
+    PaymentMethodSelected,
+    PaymentAttempted,
+    AuthenticationStarted,
+    AuthenticationCompleted,
+    SessionConverted,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentSessionMetrics {
+    SessionsStarted,
+    PaymentMethodSelectedCount,
+    PaymentAttemptedCount,
+    AuthenticationStartedCount,
+    AuthenticationCompletedCount,
+    SessionsConverted,
+    SessionConversionRate,
+}
+
+impl PaymentSessionMetrics {
+    /// Funnel step counted by the metric, `None` for metrics derived from several steps
+    pub fn get_funnel_step(&self) -> Option<PaymentSessionFunnelStep> {
+        match self {
+            Self::SessionsStarted => Some(PaymentSessionFunnelStep::SessionStarted),
+            Self::PaymentMethodSelectedCount => {
+                Some(PaymentSessionFunnelStep::PaymentMethodSelected)
+            }
+            Self::PaymentAttemptedCount => Some(PaymentSessionFunnelStep::PaymentAttempted),
+            Self::AuthenticationStartedCount => {
+                Some(PaymentSessionFunnelStep::AuthenticationStarted)
+            }
+            Self::AuthenticationCompletedCount => {
+                Some(PaymentSessionFunnelStep::AuthenticationCompleted)
+            }
+            Self::SessionsConverted => Some(PaymentSessionFunnelStep::SessionConverted),
+            Self::SessionConversionRate => None,
+        }
+    }
+}
+
+impl From<PaymentSessionMetrics> for NameDescription {
+    fn from(value: PaymentSessionMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<PaymentSessionDimensions> for NameDescription {
+    fn from(value: PaymentSessionDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct PaymentSessionMetricsBucketIdentifier {
+    pub payment_method: Option<String>,
+    pub first_payment_method: Option<String>,
+    pub connector: Option<String>,
+    pub platform: Option<String>,
+    pub country: Option<String>,
+    pub currency: Option<Currency>,
+    pub profile_id: Option<String>,
+    pub last_funnel_step: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for PaymentSessionMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.payment_method.hash(state);
+        self.first_payment_method.hash(state);
+        self.connector.hash(state);
+        self.platform.hash(state);
+        self.country.hash(state);
+        self.currency.hash(state);
+        self.profile_id.hash(state);
+        self.last_funnel_step.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+impl PartialEq for PaymentSessionMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl PaymentSessionMetricsBucketIdentifier {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        payment_method: Option<String>,
+        first_payment_method: Option<String>,
+        connector: Option<String>,
+        platform: Option<String>,
+        country: Option<String>,
+        currency: Option<Currency>,
+        profile_id: Option<String>,
+        last_funnel_step: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            payment_method,
+            first_payment_method,
+            connector,
+            platform,
+            country,
+            currency,
+            profile_id,
+            last_funnel_step,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentSessionMetricsBucketValue {
+    pub converted_sessions: Option<u32>,
+    pub total_sessions: Option<u32>,
+    /// Percentage of started sessions that ended in a successful or authorized payment
+    pub session_conversion_rate: Option<f64>,
+    pub sessions_started: Option<u64>,
+    pub payment_method_selected_count: Option<u64>,
+    pub payment_attempted_count: Option<u64>,
+    pub authentication_started_count: Option<u64>,
+    pub authentication_completed_count: Option<u64>,
+    pub sessions_converted: Option<u64>,
+}
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentSessionMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: PaymentSessionMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: PaymentSessionMetricsBucketIdentifier,
+}
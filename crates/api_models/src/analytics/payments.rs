@@ -1,11 +1,11 @@
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, BTreeMap},
     hash::{Hash, Hasher},
 };
 
 use common_utils::id_type;
 
-use super::{ForexMetric, NameDescription, TimeRange};
+use super::{ForexMetric, MetadataDimensionFilters, NameDescription, TimeRange};
 use crate::enums::{
     AttemptStatus, AuthenticationType, CardNetwork, Connector, Currency, PaymentMethod,
     PaymentMethodType, RoutingApproach,
@@ -51,6 +51,8 @@ pub struct PaymentFilters {
     pub is_issuer_regulated: Vec<bool>,
     #[serde(default)]
     pub is_debit_routed: Vec<bool>,
+    #[serde(default)]
+    pub metadata: MetadataDimensionFilters,
 }
 
 #[derive(
@@ -220,6 +222,8 @@ pub struct PaymentMetricsBucketIdentifier {
     pub signature_network: Option<String>,
     pub is_issuer_regulated: Option<bool>,
     pub is_debit_routed: Option<bool>,
+    /// Values of the metadata keys the results were grouped by
+    pub metadata_dimensions: Option<BTreeMap<String, String>>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     // Coz FE sucks
@@ -249,6 +253,7 @@ impl PaymentMetricsBucketIdentifier {
         signature_network: Option<String>,
         is_issuer_regulated: Option<bool>,
         is_debit_routed: Option<bool>,
+        metadata_dimensions: Option<BTreeMap<String, String>>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -270,6 +275,7 @@ impl PaymentMetricsBucketIdentifier {
             signature_network,
             is_issuer_regulated,
             is_debit_routed,
+            metadata_dimensions,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -299,6 +305,7 @@ impl Hash for PaymentMetricsBucketIdentifier {
         self.signature_network.hash(state);
         self.is_issuer_regulated.hash(state);
         self.is_debit_routed.hash(state);
+        self.metadata_dimensions.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -0,0 +1,205 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_utils::id_type;
+
+use super::{ForexMetric, NameDescription, TimeRange};
+use crate::enums::{Currency, PayoutStatus, PayoutType};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct PayoutFilters {
+    #[serde(default)]
+    pub destination_currency: Vec<Currency>,
+    #[serde(default)]
+    pub status: Vec<PayoutStatus>,
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub payout_type: Vec<PayoutType>,
+    #[serde(default)]
+    pub profile_id: Vec<id_type::ProfileId>,
+    #[serde(default)]
+    pub error_message: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutDimensions {
+    DestinationCurrency,
+    Status,
+    Connector,
+    PayoutType,
+    ProfileId,
+    ErrorMessage,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMetrics {
+    PayoutSuccessRate,
+    PayoutCount,
+    PayoutSuccessCount,
+    PayoutProcessedAmount,
+    PayoutAvgProcessingTime,
+    PayoutFailureReason,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct ErrorMessagesResult {
+    pub error_message: String,
+    pub count: i64,
+    pub percentage: f64,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutDistributions {
+    #[strum(serialize = "error_message")]
+    PayoutErrorMessage,
+}
+
+impl ForexMetric for PayoutMetrics {
+    fn is_forex_metric(&self) -> bool {
+        matches!(self, Self::PayoutProcessedAmount)
+    }
+}
+
+impl From<PayoutMetrics> for NameDescription {
+    fn from(value: PayoutMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<PayoutDimensions> for NameDescription {
+    fn from(value: PayoutDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct PayoutMetricsBucketIdentifier {
+    pub destination_currency: Option<Currency>,
+    pub status: Option<String>,
+    pub connector: Option<String>,
+    pub payout_type: Option<String>,
+    pub profile_id: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for PayoutMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.destination_currency.hash(state);
+        self.status.hash(state);
+        self.connector.hash(state);
+        self.payout_type.hash(state);
+        self.profile_id.hash(state);
+        self.error_message.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+impl PartialEq for PayoutMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl PayoutMetricsBucketIdentifier {
+    pub fn new(
+        destination_currency: Option<Currency>,
+        status: Option<String>,
+        connector: Option<String>,
+        payout_type: Option<String>,
+        profile_id: Option<String>,
+        error_message: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+            error_message,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutMetricsBucketValue {
+    pub successful_payouts: Option<u32>,
+    pub total_payouts: Option<u32>,
+    pub payout_success_rate: Option<f64>,
+    pub payout_count: Option<u64>,
+    pub payout_success_count: Option<u64>,
+    pub payout_processed_amount: Option<u64>,
+    pub payout_processed_amount_in_usd: Option<u64>,
+    pub payout_processed_count: Option<u64>,
+    /// Average time in seconds taken by successful payouts to reach their final status
+    pub payout_avg_processing_time: Option<f64>,
+    pub payout_failure_reason_count: Option<u64>,
+    pub payout_error_message_distribution: Option<Vec<ErrorMessagesResult>>,
+}
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: PayoutMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: PayoutMetricsBucketIdentifier,
+}
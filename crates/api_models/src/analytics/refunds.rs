@@ -1,5 +1,5 @@
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, BTreeMap},
     hash::{Hash, Hasher},
 };
 
@@ -28,7 +28,7 @@ pub enum RefundType {
     RetryRefund,
 }
 
-use super::{ForexMetric, NameDescription, TimeRange};
+use super::{ForexMetric, MetadataDimensionFilters, NameDescription, TimeRange};
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
 pub struct RefundFilters {
     #[serde(default)]
@@ -45,6 +45,8 @@ pub struct RefundFilters {
     pub refund_reason: Vec<String>,
     #[serde(default)]
     pub refund_error_message: Vec<String>,
+    #[serde(default)]
+    pub metadata: MetadataDimensionFilters,
 }
 
 #[derive(
@@ -178,6 +180,8 @@ pub struct RefundMetricsBucketIdentifier {
     pub profile_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
+    /// Values of the metadata keys the results were grouped by
+    pub metadata_dimensions: Option<BTreeMap<String, String>>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -194,6 +198,7 @@ impl Hash for RefundMetricsBucketIdentifier {
         self.profile_id.hash(state);
         self.refund_reason.hash(state);
         self.refund_error_message.hash(state);
+        self.metadata_dimensions.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -217,6 +222,7 @@ impl RefundMetricsBucketIdentifier {
         profile_id: Option<String>,
         refund_reason: Option<String>,
         refund_error_message: Option<String>,
+        metadata_dimensions: Option<BTreeMap<String, String>>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -227,6 +233,7 @@ impl RefundMetricsBucketIdentifier {
             profile_id,
             refund_reason,
             refund_error_message,
+            metadata_dimensions,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
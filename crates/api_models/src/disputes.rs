@@ -251,6 +251,68 @@ pub struct DisputesAggregateResponse {
     pub status_with_count: HashMap<DisputeStatus, i64>,
 }
 
+/// The evidence template picked for a dispute, based on its reason code
+#[derive(Clone, Copy, Debug, Serialize, ToSchema, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeEvidenceTemplate {
+    Fraud,
+    ProductNotReceived,
+    ProductUnacceptable,
+    Duplicate,
+    CreditNotProcessed,
+    CancelledRecurring,
+    General,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct DisputeEvidenceBundleResponse {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier of the generated evidence bundle, which can be downloaded through the files API
+    pub file_id: String,
+    /// Name of the generated ZIP file
+    pub file_name: String,
+    /// Size of the generated ZIP file in bytes
+    pub file_size: i32,
+    /// Time limited URL to download the bundle directly from the file storage, present when the
+    /// configured file storage backend supports presigned URLs
+    pub download_url: Option<String>,
+    /// The evidence template used for the reason code of the dispute
+    pub template: DisputeEvidenceTemplate,
+    /// Evidence the merchant is expected to attach for this template, in addition to the bundle
+    #[schema(value_type = Vec<String>)]
+    pub recommended_evidence: Vec<EvidenceType>,
+}
+
+/// Merchant specific rules for handling open disputes
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+pub struct DisputeAutomationConfig {
+    /// Number of hours before `challenge_required_by` at which the merchant is reminded about an
+    /// open dispute. Reminders are disabled when this is not set.
+    #[schema(example = 48)]
+    pub reminder_hours_before_deadline: Option<u16>,
+    /// Open disputes matching any of these rules are accepted without merchant intervention. The
+    /// rules are checked whenever a dispute is created or updated, and against all open disputes
+    /// when the rules are changed.
+    #[serde(default)]
+    pub auto_accept_rules: Vec<DisputeAutoAcceptRule>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct DisputeAutoAcceptRule {
+    /// Currency of the disputed amount
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    /// Disputes for amounts up to and including this value are accepted
+    #[schema(value_type = i64, example = 1000)]
+    pub max_amount: common_utils::types::MinorUnit,
+    /// Restrict the rule to these connectors, applies to all connectors when not set
+    pub connectors: Option<Vec<String>>,
+    /// Restrict the rule to these connector reason codes, applies to all reason codes when not set
+    pub reason_codes: Option<Vec<String>>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DisputeRetrieveBody {
     /// Decider to enable or disable the connector call for dispute retrieve request
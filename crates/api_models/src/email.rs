@@ -0,0 +1,98 @@
+use common_utils::pii;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// Message delivered by Amazon SNS from the topic Amazon SES publishes bounces and complaints to
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "PascalCase")]
+pub struct SnsMessage {
+    /// The kind of SNS message
+    #[serde(rename = "Type")]
+    pub message_type: SnsMessageType,
+    /// Unique identifier of the message
+    pub message_id: String,
+    /// The topic the message was published to
+    pub topic_arn: String,
+    /// Subject of the notification, if the publisher set one
+    pub subject: Option<String>,
+    /// The SES notification for notifications, or a description of the subscription otherwise
+    pub message: String,
+    /// Time at which the message was published, in ISO 8601 format
+    pub timestamp: String,
+    /// `1` when the message is signed with SHA1withRSA, `2` when it is signed with SHA256withRSA
+    pub signature_version: String,
+    /// Base64 encoded signature of the message
+    pub signature: String,
+    /// URL of the certificate the message was signed with
+    #[serde(rename = "SigningCertURL")]
+    pub signing_cert_url: String,
+    /// URL to visit to confirm a subscription to the topic
+    #[serde(rename = "SubscribeURL")]
+    pub subscribe_url: Option<String>,
+    /// Token of a subscription confirmation
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub enum SnsMessageType {
+    Notification,
+    SubscriptionConfirmation,
+    UnsubscribeConfirmation,
+}
+
+/// Notification published by Amazon SES about an email it sent
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesNotification {
+    pub notification_type: SesNotificationType,
+    pub bounce: Option<SesBounce>,
+    pub complaint: Option<SesComplaint>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+pub enum SesNotificationType {
+    Bounce,
+    Complaint,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesBounce {
+    pub bounce_type: SesBounceType,
+    pub bounced_recipients: Vec<SesRecipient>,
+}
+
+/// Only permanent bounces mean that the mailbox cannot receive emails
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+pub enum SesBounceType {
+    Permanent,
+    Transient,
+    #[serde(other)]
+    Undetermined,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesComplaint {
+    pub complained_recipients: Vec<SesRecipient>,
+    pub complaint_feedback_type: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SesRecipient {
+    pub email_address: pii::Email,
+    pub diagnostic_code: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct EmailDeliveryEventResponse {
+    /// The outbox entry the event was recorded against
+    pub outbox_id: String,
+    /// The delivery status of the email after recording the event
+    #[schema(value_type = EmailOutboxStatus, example = "bounced")]
+    pub status: api_enums::EmailOutboxStatus,
+}
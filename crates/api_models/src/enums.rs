@@ -464,6 +464,7 @@ pub enum LockerChoice {
 #[strum(serialize_all = "snake_case")]
 pub enum PmAuthConnectors {
     Plaid,
+    Truelayer,
 }
 
 pub fn convert_pm_auth_connector(connector_name: &str) -> Option<PmAuthConnectors> {
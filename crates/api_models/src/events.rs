@@ -3,12 +3,14 @@ pub mod chat;
 pub mod connector_onboarding;
 pub mod customer;
 pub mod dispute;
+pub mod email;
 pub mod external_service_auth;
 pub mod gsm;
 mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refund;
@@ -29,14 +31,16 @@ use crate::tokenization;
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, routing_events::RoutingEventsRequest,
-        sdk_events::*, search::*, *,
+        alerts::*, api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
+        exports::*, outgoing_webhook_event::OutgoingWebhookLogsRequest,
+        routing_events::RoutingEventsRequest, sdk_events::*, search::*, *,
     },
     api_keys::*,
     cards_info::*,
     disputes::*,
     files::*,
+    kafka_events::*,
+    log_control::*,
     mandates::*,
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
@@ -123,6 +127,30 @@ impl_api_event_type!(
         PaymentFiltersResponse,
         GetRefundFilterRequest,
         RefundFiltersResponse,
+        GetPayoutMetricRequest,
+        GetPayoutFilterRequest,
+        PayoutFiltersResponse,
+        GetPaymentSessionMetricRequest,
+        GetPaymentSessionFilterRequest,
+        PaymentSessionFiltersResponse,
+        MetadataDimensionsRequest,
+        MetadataDimensionsResponse,
+        CreateAlertRuleRequest,
+        UpdateAlertRuleRequest,
+        AlertRuleResponse,
+        AlertRuleListResponse,
+        AlertRuleId,
+        AlertHistoryRequest,
+        AlertHistoryResponse,
+        CreateExportRequest,
+        UpdateExportRequest,
+        ExportResponse,
+        ExportListResponse,
+        ExportId,
+        ExportRunId,
+        ExportRunListRequest,
+        ExportRunResponse,
+        ExportRunListResponse,
         AuthEventFiltersResponse,
         GetSdkEventFiltersRequest,
         SdkEventFiltersResponse,
@@ -130,6 +158,8 @@ impl_api_event_type!(
         GetApiEventMetricRequest,
         SdkEventsRequest,
         ReportRequest,
+        ReportResponse,
+        ReportDownloadRequest,
         ConnectorEventsRequest,
         OutgoingWebhookLogsRequest,
         GetGlobalSearchRequest,
@@ -145,7 +175,14 @@ impl_api_event_type!(
         OrganizationUpdateRequest,
         OrganizationId,
         CustomerListRequest,
-        RoutingEventsRequest
+        RoutingEventsRequest,
+        KafkaEventsBackfillRequest,
+        KafkaEventsBackfillResponse,
+        LogFilterUpdateRequest,
+        DebugLoggingCreateRequest,
+        DebugLoggingResponse,
+        DebugLoggingDeleteRequest,
+        LogControlResponse
     )
 );
 
@@ -183,6 +220,18 @@ impl<T> ApiEventMetric for RefundsMetricsResponse<T> {
     }
 }
 
+impl<T> ApiEventMetric for PayoutsMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl<T> ApiEventMetric for PaymentSessionsMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl<T> ApiEventMetric for DisputesMetricsResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Miscellaneous)
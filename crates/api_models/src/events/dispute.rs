@@ -1,8 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputeRetrieveRequest, DisputesAggregateResponse, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeAutomationConfig, DisputeEvidenceBundleResponse, DisputeResponse,
+    DisputeResponsePaymentsRetrieve, DisputeRetrieveRequest, DisputesAggregateResponse,
+    SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -43,8 +44,22 @@ impl ApiEventMetric for DeleteEvidenceRequest {
     }
 }
 
+impl ApiEventMetric for DisputeEvidenceBundleResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for DisputesAggregateResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for DisputeAutomationConfig {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
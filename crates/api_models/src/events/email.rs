@@ -0,0 +1,11 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::email;
+
+impl ApiEventMetric for email::EmailDeliveryEventResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::EmailOutbox {
+            outbox_id: Some(self.outbox_id.clone()),
+        })
+    }
+}
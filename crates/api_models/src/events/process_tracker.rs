@@ -0,0 +1,43 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::process_tracker::tasks::{
+    ProcessTrackerBulkActionRequest, ProcessTrackerBulkActionResponse,
+    ProcessTrackerTaskActionRequest, ProcessTrackerTaskId, ProcessTrackerTaskListRequest,
+    ProcessTrackerTaskListResponse, ProcessTrackerTaskResponse,
+};
+
+impl ApiEventMetric for ProcessTrackerTaskListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerTaskListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerTaskId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerTaskResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerTaskActionRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerBulkActionRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
+impl ApiEventMetric for ProcessTrackerBulkActionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ProcessTracker)
+    }
+}
@@ -4,6 +4,9 @@ use utoipa::ToSchema;
 pub struct CreateFileResponse {
     /// ID of the file created
     pub file_id: String,
+    /// Malware scan status of the file, the file is only available once it is `clean` or `not_scanned`
+    #[schema(value_type = Option<FileScanStatus>, example = "clean")]
+    pub scan_status: Option<common_enums::FileScanStatus>,
 }
 
 #[derive(Debug, serde::Serialize, ToSchema, Clone)]
@@ -18,6 +21,9 @@ pub struct FileMetadataResponse {
     pub file_type: String,
     /// File availability
     pub available: bool,
+    /// Malware scan status of the file
+    #[schema(value_type = Option<FileScanStatus>, example = "clean")]
+    pub scan_status: Option<common_enums::FileScanStatus>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
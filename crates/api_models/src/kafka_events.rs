@@ -0,0 +1,35 @@
+use common_utils::{id_type, types::TimeRange};
+
+/// Request to publish the analytics events of the payments of a merchant to Kafka again
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct KafkaEventsBackfillRequest {
+    /// The merchant whose events are to be published
+    pub merchant_id: id_type::MerchantId,
+    /// The events of payments created in this time range are published, along with the events of
+    /// their attempts and refunds
+    pub time_range: TimeRange,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct KafkaEventsBackfillResponse {
+    pub merchant_id: id_type::MerchantId,
+    /// The process tracker task publishing the events. Its tracking data holds the progress of
+    /// the backfill.
+    pub task_id: String,
+}
+
+/// Tracking data of the process tracker task backfilling Kafka events, which is updated after
+/// every page of payment intents published
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct KafkaEventsBackfillTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    /// The time range of the backfill, with the end fixed when the backfill is requested
+    pub time_range: TimeRange,
+    /// Number of payment intents in the time range already published, which is where the next
+    /// page starts
+    pub payment_intents_count: u32,
+    /// Number of payment attempts whose events were published
+    pub payment_attempts_count: u64,
+    /// Number of refunds whose events were published
+    pub refunds_count: u64,
+}
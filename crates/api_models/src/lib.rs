@@ -13,6 +13,7 @@ pub mod consts;
 pub mod currency;
 pub mod customers;
 pub mod disputes;
+pub mod email;
 pub mod enums;
 pub mod ephemeral_key;
 #[cfg(feature = "errors")]
@@ -23,7 +24,9 @@ pub mod feature_matrix;
 pub mod files;
 pub mod gsm;
 pub mod health_check;
+pub mod kafka_events;
 pub mod locker_migration;
+pub mod log_control;
 pub mod mandates;
 pub mod open_router;
 pub mod organization;
@@ -0,0 +1,64 @@
+use common_utils::id_type;
+
+use crate::enums;
+
+/// Request to replace the logging filtering directive of all instances
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct LogFilterUpdateRequest {
+    /// Filtering directive, such as `warn,router=debug`
+    pub filter_directive: String,
+}
+
+/// Level of the events logged for the requests being debugged
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DebugLogLevel {
+    Info,
+    #[default]
+    Debug,
+    Trace,
+}
+
+/// Request to temporarily enable verbose logging for the requests matching all of the specified
+/// merchant, profile and connector
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct DebugLoggingCreateRequest {
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<enums::Connector>,
+    /// Level of the events logged for the requests
+    #[serde(default)]
+    pub level: DebugLogLevel,
+    /// Whether the traces of the requests are sampled irrespective of the sampling rate
+    #[serde(default)]
+    pub sample_traces: bool,
+    /// Time in seconds after which verbose logging is disabled, 15 minutes if not specified
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DebugLoggingResponse {
+    pub id: String,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub level: DebugLogLevel,
+    pub sample_traces: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
+
+/// Request to disable verbose logging enabled earlier
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DebugLoggingDeleteRequest {
+    pub id: String,
+}
+
+/// Logging state of the instance serving the request, other instances converge to the same state
+/// once updates are propagated to them
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LogControlResponse {
+    /// Filtering directive set at runtime, the configured directives are in effect if none is set
+    pub filter_directive: Option<String>,
+    pub debug_logging: Vec<DebugLoggingResponse>,
+}
@@ -907,7 +907,185 @@ pub struct PayoutListFiltersV2 {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
-#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The business profile under which all the payouts of the batch are created. If not provided, the default business profile of the merchant account is used.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The list of payout instructions to be executed as part of the batch. `payout_method_data` is not accepted for batch payouts, use `payout_method_id` or `payout_token` instead.
+    #[schema(value_type = Vec<PayoutsCreateRequest>)]
+    pub items: Vec<PayoutCreateRequest>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "settlement_date": "2024-01-31" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// A single payout instruction, as read from a row of a batch payout CSV file
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PayoutBatchCsvRecord {
+    pub merchant_order_reference_id: Option<String>,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payout_method_id: Option<String>,
+    pub payout_token: Option<String>,
+    pub connector: Option<api_enums::PayoutConnectors>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub description: Option<String>,
+    pub auto_fulfill: Option<bool>,
+}
+
+impl From<PayoutBatchCsvRecord> for PayoutCreateRequest {
+    fn from(record: PayoutBatchCsvRecord) -> Self {
+        Self {
+            merchant_order_reference_id: record.merchant_order_reference_id,
+            amount: Some(payments::Amount::from(common_utils::types::MinorUnit::new(
+                record.amount,
+            ))),
+            currency: Some(record.currency),
+            payout_type: record.payout_type,
+            customer_id: record.customer_id,
+            payout_method_id: record.payout_method_id,
+            payout_token: record.payout_token,
+            connector: record.connector.map(|connector| vec![connector]),
+            priority: record.priority,
+            description: record.description,
+            auto_fulfill: record.auto_fulfill,
+            confirm: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// Unique identifier for the payout batch
+    #[schema(example = "payout_batch_bCRpmLgsKbxamySwPc3F")]
+    pub batch_id: String,
+
+    /// The identifier for the Merchant Account
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile under which the payouts of the batch are created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// Aggregate status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// Total number of payout instructions in the batch
+    pub total_count: i32,
+
+    /// Number of payout instructions which are yet to be processed
+    pub pending_count: i32,
+
+    /// Number of payout instructions for which a payout was created
+    pub processed_count: i32,
+
+    /// Number of payout instructions which could not be processed
+    pub failed_count: i32,
+
+    /// Number of payout instructions which were cancelled before being processed
+    pub cancelled_count: i32,
+
+    /// Metadata provided while creating the batch
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Time at which the batch was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which all the items of the batch reached a terminal status
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutBatchItemResponse {
+    /// Unique identifier for the item within the batch
+    pub item_id: String,
+
+    /// Position of the payout instruction in the request, starting from 1
+    pub line_number: i32,
+
+    /// Identifier of the payout created for this item
+    #[schema(value_type = String)]
+    pub payout_id: id_type::PayoutId,
+
+    /// Status of the item
+    #[schema(value_type = PayoutBatchItemStatus, example = "processed")]
+    pub status: api_enums::PayoutBatchItemStatus,
+
+    /// Status of the payout created for this item
+    #[schema(value_type = Option<PayoutStatus>, example = "success")]
+    pub payout_status: Option<api_enums::PayoutStatus>,
+
+    /// Number of times processing of the item was retried
+    pub retry_count: i32,
+
+    /// Error code, if the item could not be processed
+    pub error_code: Option<String>,
+
+    /// Error message, if the item could not be processed
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchItemsListConstraints {
+    /// Only return items in this status
+    #[schema(value_type = Option<PayoutBatchItemStatus>)]
+    pub status: Option<api_enums::PayoutBatchItemStatus>,
+
+    /// limit on the number of objects to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of objects to skip, for pagination
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutBatchItemsResponse {
+    /// Unique identifier for the payout batch
+    pub batch_id: String,
+    /// The number of items included in the list
+    pub size: usize,
+    /// The list of items of the batch
+    pub data: Vec<PayoutBatchItemResponse>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchListConstraints {
+    /// limit on the number of objects to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payouts_list_limit")]
+    pub limit: u32,
+
+    /// The number of objects to skip, for pagination
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutBatchListResponse {
+    /// The number of batches included in the list
+    pub size: usize,
+    /// The list of payout batches, most recent first
+    pub data: Vec<PayoutBatchResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
     pub payout_link_id: String,
     #[schema(value_type = String)]
@@ -1,2 +1,3 @@
 #[cfg(feature = "v2")]
 pub mod revenue_recovery;
+pub mod tasks;
@@ -0,0 +1,118 @@
+use common_utils::{id_type, types::TimeRange};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Constraints for filtering process tracker tasks, each of which is applied only if specified
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerTaskFilter {
+    /// The runner (workflow) executing the tasks
+    #[schema(value_type = Option<String>, example = "PAYMENTS_SYNC_WORKFLOW")]
+    pub runner: Option<enums::ProcessTrackerRunner>,
+    /// The statuses of the tasks
+    #[schema(value_type = Option<Vec<ProcessTrackerStatus>>)]
+    pub status: Option<Vec<enums::ProcessTrackerStatus>>,
+    /// The merchant for whom the tasks were created
+    #[schema(value_type = Option<String>)]
+    pub merchant_id: Option<id_type::MerchantId>,
+    /// The time window within which the tasks are scheduled to be executed
+    pub time_range: Option<TimeRange>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerTaskListRequest {
+    #[serde(flatten)]
+    pub filter: ProcessTrackerTaskFilter,
+    /// The maximum number of tasks to be returned
+    pub limit: Option<u32>,
+    /// The number of tasks to skip
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerTaskId {
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProcessTrackerTaskResponse {
+    pub id: String,
+    pub name: Option<String>,
+    pub tag: Vec<String>,
+    pub runner: Option<String>,
+    /// The number of times the task has been retried
+    pub retry_count: i32,
+    /// The time at which the task is scheduled to be executed
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    pub rule: String,
+    /// The data required by the runner to execute the task
+    #[schema(value_type = Object)]
+    pub tracking_data: serde_json::Value,
+    pub business_status: String,
+    #[schema(value_type = ProcessTrackerStatus, example = "new")]
+    pub status: enums::ProcessTrackerStatus,
+    pub event: Vec<String>,
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProcessTrackerTaskListResponse {
+    /// The number of tasks included in the list
+    pub count: usize,
+    pub data: Vec<ProcessTrackerTaskResponse>,
+}
+
+/// Action to be performed on process tracker tasks
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ProcessTrackerTaskAction {
+    /// Schedule the task to be executed at the specified time
+    Reschedule {
+        #[schema(example = "2022-09-10T10:11:12Z")]
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        schedule_time: PrimitiveDateTime,
+    },
+    /// Schedule the task to be executed immediately
+    RetryNow,
+    /// Stop the task from being executed until it is rescheduled or retried
+    Pause,
+    /// Stop the task from being executed any further
+    Cancel,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerTaskActionRequest {
+    #[serde(skip)]
+    pub task_id: String,
+    #[serde(flatten)]
+    pub action: ProcessTrackerTaskAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerBulkActionRequest {
+    /// The tasks on which the action is to be performed
+    pub filter: ProcessTrackerTaskFilter,
+    #[serde(flatten)]
+    pub action: ProcessTrackerTaskAction,
+    /// The maximum number of tasks on which the action is to be performed
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProcessTrackerBulkActionResponse {
+    /// The number of tasks matching the filter
+    pub matched_count: usize,
+    /// The IDs of the tasks on which the action was performed
+    pub updated_task_ids: Vec<String>,
+    /// The IDs of the tasks which were skipped as the action is not applicable to their status
+    pub skipped_task_ids: Vec<String>,
+}
@@ -1,5 +1,6 @@
-use common_utils::{id_type, pii};
+use common_utils::{id_type, pii, types::MinorUnit};
 use masking::Secret;
+use time::PrimitiveDateTime;
 
 use crate::enums;
 
@@ -26,3 +27,83 @@ pub struct VerifyTokenResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub acl: Option<String>,
 }
+
+/// Ingests a connector settlement report and reconciles it against the payments and refunds on
+/// record. The report is read either from a file uploaded through the files API or from a file
+/// dropped into file storage, such as an SFTP drop synced into the bucket.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SettlementReportIngestRequest {
+    /// Connector which generated the settlement report
+    pub connector: enums::Connector,
+    /// Identifier of a file uploaded through the files API
+    pub file_id: Option<String>,
+    /// Key of a file present in file storage
+    pub file_key: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SettlementReportResponse {
+    pub report_id: String,
+    pub connector: String,
+    pub file_id: Option<String>,
+    pub file_key: Option<String>,
+    /// Number of settlement lines reconciled
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct SettlementReportListQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SettlementReportListResponse {
+    pub count: usize,
+    pub data: Vec<SettlementReportResponse>,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ReconResultListQuery {
+    /// Restricts the results to those of a single settlement report
+    pub report_id: Option<String>,
+    pub status: Option<enums::ReconResultStatus>,
+    /// Ignored when exporting results
+    pub limit: Option<i64>,
+    /// Ignored when exporting results
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconResultResponse {
+    pub id: String,
+    pub report_id: String,
+    /// Line of the settlement report the result was derived from, starting at 1
+    pub line_number: i32,
+    pub connector_transaction_id: String,
+    pub transaction_type: enums::SettlementTransactionType,
+    pub status: enums::ReconResultStatus,
+    pub payment_id: Option<id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    /// Amount on record for the transaction
+    pub expected_amount: Option<MinorUnit>,
+    pub expected_currency: Option<enums::Currency>,
+    /// Amount reported as settled by the connector
+    pub settled_amount: MinorUnit,
+    pub settled_currency: enums::Currency,
+    /// Fee charged by the connector for the transaction
+    pub fee_amount: Option<MinorUnit>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ReconResultListResponse {
+    pub count: usize,
+    pub data: Vec<ReconResultResponse>,
+}
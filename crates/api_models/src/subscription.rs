@@ -151,7 +151,91 @@ pub struct SubscriptionPlanPrices {
     pub trial_period_unit: Option<PeriodUnit>,
 }
 
-#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+/// Request payload for adding a plan to the catalog of a profile billed natively, that is a
+/// profile without a billing processor.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CreateSubscriptionPlanRequest {
+    /// Name of the plan.
+    pub name: String,
+
+    /// Description of the plan.
+    pub description: Option<String>,
+
+    /// Prices at which the plan can be subscribed to.
+    pub prices: Vec<CreateSubscriptionPlanPriceRequest>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct CreateSubscriptionPlanPriceRequest {
+    /// Amount charged every billing period, in the lowest denomination of the currency.
+    pub amount: MinorUnit,
+
+    pub currency: Currency,
+
+    /// Unit of the billing period.
+    pub interval: PeriodUnit,
+
+    /// Number of interval units making up a billing period.
+    #[serde(default = "default_interval_count")]
+    pub interval_count: i64,
+
+    /// Length of the free trial period, if any.
+    pub trial_period: Option<i64>,
+
+    /// Unit of the free trial period. Defaults to days.
+    pub trial_period_unit: Option<PeriodUnit>,
+}
+
+fn default_interval_count() -> i64 {
+    1
+}
+
+impl CreateSubscriptionPlanRequest {
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        fp_utils::when(self.prices.is_empty(), || {
+            Err(ValidationError::MissingRequiredField {
+                field_name: String::from("prices"),
+            }
+            .into())
+        })?;
+
+        self.prices.iter().try_for_each(|price| {
+            fp_utils::when(price.amount.get_amount_as_i64() < 0, || {
+                Err(ValidationError::InvalidValue {
+                    message: String::from("amount must not be negative"),
+                }
+                .into())
+            })?;
+            fp_utils::when(price.interval_count <= 0, || {
+                Err(ValidationError::InvalidValue {
+                    message: String::from("interval_count must be greater than zero"),
+                }
+                .into())
+            })?;
+            fp_utils::when(price.trial_period.is_some_and(|period| period < 0), || {
+                Err(ValidationError::InvalidValue {
+                    message: String::from("trial_period must not be negative"),
+                }
+                .into())
+            })
+        })
+    }
+}
+
+impl ApiEventMetric for CreateSubscriptionPlanRequest {}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
 pub enum PeriodUnit {
     Day,
     Week,
@@ -364,6 +448,9 @@ impl PaymentResponseData {
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct CreateMitPaymentRequestData {
+    /// Payment id to create the payment with, so that retried requests do not charge twice
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_id: Option<PaymentId>,
     pub amount: MinorUnit,
     pub currency: Currency,
     pub confirm: bool,
@@ -506,7 +593,8 @@ pub struct Invoice {
     pub profile_id: ProfileId,
 
     /// Identifier for the merchant connector account.
-    pub merchant_connector_id: MerchantConnectorAccountId,
+    /// Absent for invoices generated by the native billing engine.
+    pub merchant_connector_id: Option<MerchantConnectorAccountId>,
 
     /// Identifier for the Payment.
     pub payment_intent_id: Option<PaymentId>,
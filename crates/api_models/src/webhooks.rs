@@ -454,6 +454,9 @@ pub enum OutgoingWebhookContent {
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
     #[schema(value_type = ConfirmSubscriptionResponse, title = "ConfirmSubscriptionResponse")]
     SubscriptionDetails(Box<subscription::ConfirmSubscriptionResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutBatchResponse, title = "PayoutBatchResponse")]
+    PayoutBatchDetails(Box<payouts::PayoutBatchResponse>),
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
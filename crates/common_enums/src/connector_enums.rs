@@ -362,6 +362,7 @@ pub enum Connector {
     Tokenio,
     Trustpay,
     Trustpayments,
+    Truelayer,
     Tsys,
     // UnifiedAuthenticationService,
     Vgs,
@@ -560,6 +561,7 @@ impl Connector {
             // | Self::Thunes
             | Self::Trustpay
             | Self::Trustpayments
+            | Self::Truelayer
             // | Self::Tokenio
             | Self::Tsys
             // | Self::UnifiedAuthenticationService
@@ -921,6 +923,7 @@ impl TryFrom<Connector> for RoutableConnectors {
             | Connector::Netcetera
             | Connector::Taxjar
             | Connector::Threedsecureio
+            | Connector::Truelayer
             | Connector::Vgs
             | Connector::CtpVisa
             | Connector::Cardinal
@@ -1542,6 +1542,7 @@ pub enum EventObjectType {
     MandateDetails,
     PayoutDetails,
     SubscriptionDetails,
+    PayoutBatchDetails,
 }
 
 #[derive(
@@ -1594,6 +1595,7 @@ impl EventClass {
                 EventType::DisputeChallenged,
                 EventType::DisputeWon,
                 EventType::DisputeLost,
+                EventType::DisputeDeadlineApproaching,
             ]),
             Self::Mandates => HashSet::from([EventType::MandateActive, EventType::MandateRevoked]),
             #[cfg(feature = "payouts")]
@@ -1605,6 +1607,7 @@ impl EventClass {
                 EventType::PayoutCancelled,
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
+                EventType::PayoutBatchCompleted,
             ]),
             Self::Subscriptions => HashSet::from([EventType::InvoicePaid]),
         }
@@ -1650,6 +1653,7 @@ pub enum EventType {
     DisputeChallenged,
     DisputeWon,
     DisputeLost,
+    DisputeDeadlineApproaching,
     MandateActive,
     MandateRevoked,
     #[cfg(feature = "payouts")]
@@ -1667,6 +1671,8 @@ pub enum EventType {
     #[cfg(feature = "payouts")]
     PayoutReversed,
     InvoicePaid,
+    #[cfg(feature = "payouts")]
+    PayoutBatchCompleted,
 }
 
 #[derive(
@@ -1690,6 +1696,33 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The format in which outgoing webhooks are delivered to the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookFormat {
+    /// The default webhook payload format
+    #[default]
+    Hyperswitch,
+    /// Stripe event envelope, for merchants using Stripe SDKs against the compatibility layer
+    Stripe,
+    /// Adyen standard notification, for merchants migrating from Adyen. Notifications are signed
+    /// with the payment response hash key, which has to be an Adyen hex encoded HMAC key
+    Adyen,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -3560,6 +3593,383 @@ pub enum FileUploadProvider {
     Worldpayvantiv,
 }
 
+/// Outcome of scanning an uploaded file for malware
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FileScanStatus {
+    /// File is quarantined until the scanner has checked it
+    Pending,
+    /// Scanner did not find any threat in the file
+    Clean,
+    /// Scanner found a threat in the file, the file was discarded
+    Infected,
+    /// No scanner is configured, the file was stored without being scanned
+    NotScanned,
+}
+
+/// Delivery status of an email queued in the email outbox
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EmailOutboxStatus {
+    /// Email is waiting to be sent, or is waiting for a retry
+    Pending,
+    /// Email was accepted by the email provider
+    Sent,
+    /// Email could not be sent even after exhausting retries
+    Failed,
+    /// Email provider reported that the email bounced
+    Bounced,
+    /// Recipient marked the email as spam
+    Complained,
+    /// Email was not sent as an earlier email to the recipient bounced or was complained about
+    Suppressed,
+}
+
+impl EmailOutboxStatus {
+    /// Whether the recipient should not be sent any further emails
+    pub fn is_undeliverable(&self) -> bool {
+        matches!(self, Self::Bounced | Self::Complained)
+    }
+}
+
+/// Kind of email queued in the email outbox
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum EmailOutboxType {
+    InviteUser,
+    ResetPassword,
+    ApiKeyExpiryReminder,
+    AnalyticsReportReady,
+    AnalyticsAlert,
+}
+
+/// Domain an analytics report is generated for
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsReportType {
+    Payments,
+    Refunds,
+    Disputes,
+    Payouts,
+    Authentications,
+}
+
+/// Status of an analytics report generated by the in-process report engine
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsReportStatus {
+    /// The report is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// The report is being generated
+    Processing,
+    /// The report file is available for download
+    Completed,
+    /// The report could not be generated
+    Failed,
+}
+
+/// Metric an analytics alert rule watches
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsAlertMetric {
+    /// Percentage of payment attempts which succeeded
+    SuccessRate,
+    /// Percentage of the declined payment attempts which were declined with an error code
+    DeclineCodeShare,
+    /// Average latency of the authorize calls made to the connector, in milliseconds
+    Latency,
+    /// Number of payment attempts
+    Volume,
+}
+
+/// Windows an analytics alert rule compares the latest window with
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsAlertBaseline {
+    /// The windows immediately preceding the latest window
+    Rolling,
+    /// The same window on the preceding days
+    DailySeasonal,
+    /// The same window on the preceding weeks
+    WeeklySeasonal,
+}
+
+/// Deviations from the baseline which raise an analytics alert
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsAlertDirection {
+    Drop,
+    Rise,
+    Both,
+}
+
+/// Outcome of the delivery of an analytics alert
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsAlertDeliveryStatus {
+    /// The alert was delivered to all the channels of the rule
+    Sent,
+    /// The alert could not be delivered to some of the channels of the rule
+    Failed,
+    /// The alert was raised during a mute window of the rule, and was not delivered
+    Muted,
+}
+
+/// File format of the files of an analytics export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsExportFormat {
+    Csv,
+    /// One JSON object per line
+    Jsonl,
+    Parquet,
+}
+
+impl AnalyticsExportFormat {
+    pub fn get_file_extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Jsonl => "jsonl",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Period of data covered by each run of an analytics export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsExportFrequency {
+    Daily,
+    /// Weeks starting on Monday
+    Weekly,
+    Monthly,
+}
+
+/// Status of a run of an analytics export
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsExportRunStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// Kind of transaction settled by a line of a connector settlement report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementTransactionType {
+    Payment,
+    Refund,
+}
+
+/// Outcome of reconciling a settlement report line against the payments and refunds on record
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconResultStatus {
+    /// The settled amount and currency agree with the transaction on record
+    Matched,
+    /// No charged payment attempt of the settling connector exists for the connector transaction
+    /// id of the line
+    Missing,
+    /// The settled amount differs from the amount on record
+    AmountMismatch,
+    /// The settled currency differs from the currency on record
+    CurrencyMismatch,
+    /// The connector settled a refund for which no refund exists on record
+    UnexpectedRefund,
+}
+
+impl ReconResultStatus {
+    pub fn is_mismatch(&self) -> bool {
+        !matches!(self, Self::Matched)
+    }
+}
+
 #[derive(
     Debug, Clone, PartialEq, Eq, Serialize, Deserialize, strum::Display, strum::EnumString,
 )]
@@ -8177,6 +8587,81 @@ impl PayoutStatus {
     }
 }
 
+/// Aggregate status of a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// Batch has been accepted and is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// Items of the batch are being processed
+    Processing,
+    /// Every item of the batch was processed successfully
+    Completed,
+    /// Some items of the batch were processed successfully while others failed or were cancelled
+    PartiallyCompleted,
+    /// None of the items of the batch could be processed successfully
+    Failed,
+    /// Batch was cancelled before any of its items could be processed
+    Cancelled,
+}
+
+impl PayoutBatchStatus {
+    pub fn is_terminal_status(&self) -> bool {
+        matches!(
+            self,
+            Self::Completed | Self::PartiallyCompleted | Self::Failed | Self::Cancelled
+        )
+    }
+}
+
+/// Status of an individual payout instruction within a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchItemStatus {
+    /// Item is yet to be processed, or is waiting for a retry
+    #[default]
+    Pending,
+    /// A payout was created for the item
+    Processed,
+    /// Item could not be processed even after exhausting retries
+    Failed,
+    /// Item was cancelled before it could be processed
+    Cancelled,
+}
+
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
 #[derive(
     Clone,
@@ -9830,6 +10315,15 @@ pub enum ProcessTrackerRunner {
     ProcessDisputeWorkflow,
     DisputeListWorkflow,
     InvoiceSyncflow,
+    PayoutBatchWorkflow,
+    DisputeDeadlineWorkflow,
+    FileScanWorkflow,
+    EmailOutboxWorkflow,
+    AnalyticsReportWorkflow,
+    SubscriptionBillingWorkflow,
+    AnalyticsAlertWorkflow,
+    AnalyticsExportWorkflow,
+    KafkaEventsBackfillWorkflow,
 }
 
 #[derive(Debug)]
@@ -14,6 +14,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: id_type::PayoutId,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
     #[cfg(feature = "v1")]
     Payment {
         payment_id: id_type::PaymentId,
@@ -109,6 +112,9 @@ pub enum ApiEventsType {
     Dispute {
         dispute_id: String,
     },
+    EmailOutbox {
+        outbox_id: Option<String>,
+    },
     Events {
         merchant_id: id_type::MerchantId,
     },
@@ -234,4 +234,20 @@ impl MerchantId {
             self.get_string_repr()
         )
     }
+
+    /// Get dispute automation config key, holding the deadline reminder and auto accept rules
+    pub fn get_dispute_automation_config_key(&self) -> String {
+        format!("dispute_automation_config_{}", self.get_string_repr())
+    }
+
+    /// Get allowed file types config key, holding the content types the merchant accepts for
+    /// uploaded files in addition to the limits of the deployment
+    pub fn get_allowed_file_types_config_key(&self) -> String {
+        format!("allowed_file_types_{}", self.get_string_repr())
+    }
+
+    /// Get native fraud engine config key, holding the signal weights and decision thresholds
+    pub fn get_native_frm_config_key(&self) -> String {
+        format!("native_frm_config_{}", self.get_string_repr())
+    }
 }
@@ -31,6 +31,18 @@ impl FromStr for ProfileId {
     }
 }
 
+impl ProfileId {
+    /// Get the key under which the dunning config of native subscription billing is stored
+    pub fn get_native_billing_dunning_config_key(&self) -> String {
+        format!("native_billing_dunning_config_{}", self.get_string_repr())
+    }
+
+    /// Get the key under which the metadata keys registered as analytics dimensions are stored
+    pub fn get_analytics_metadata_dimensions_key(&self) -> String {
+        format!("analytics_metadata_dimensions_{}", self.get_string_repr())
+    }
+}
+
 // This is implemented so that we can use profile id directly as attribute in metrics
 #[cfg(feature = "metrics")]
 impl From<ProfileId> for router_env::opentelemetry::Value {
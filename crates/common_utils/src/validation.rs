@@ -2,7 +2,11 @@
 
 #![deny(clippy::invalid_regex)]
 
-use std::{collections::HashSet, sync::LazyLock};
+use std::{
+    collections::HashSet,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    sync::LazyLock,
+};
 
 use error_stack::report;
 use globset::Glob;
@@ -156,6 +160,98 @@ pub fn contains_potential_xss_or_sqli(input: &str) -> bool {
     false
 }
 
+/// Checks whether an IP address is publicly routable. Loopback, private, link local, shared,
+/// documentation, multicast and unspecified addresses are not, so that requests to addresses
+/// supplied by users cannot reach internal services.
+pub fn is_public_ip_address(ip_address: &IpAddr) -> bool {
+    match ip_address {
+        IpAddr::V4(ip_address) => is_public_ipv4_address(ip_address),
+        IpAddr::V6(ip_address) => is_public_ipv6_address(ip_address),
+    }
+}
+
+fn is_public_ipv4_address(ip_address: &Ipv4Addr) -> bool {
+    let [first_octet, second_octet, ..] = ip_address.octets();
+    !(ip_address.is_private()
+        || ip_address.is_loopback()
+        || ip_address.is_link_local()
+        || ip_address.is_unspecified()
+        || ip_address.is_broadcast()
+        || ip_address.is_documentation()
+        || ip_address.is_multicast()
+        // "This network", 0.0.0.0/8
+        || first_octet == 0
+        // Shared address space of carrier grade NATs, 100.64.0.0/10
+        || (first_octet == 100 && (second_octet & 0xc0) == 64)
+        // Reserved for future use, 240.0.0.0/4
+        || first_octet >= 240)
+}
+
+fn is_public_ipv6_address(ip_address: &Ipv6Addr) -> bool {
+    /// IPv4/IPv6 translation prefix of NAT64 gateways, 64:ff9b::/96
+    const NAT64_PREFIX: [u16; 6] = [0x64, 0xff9b, 0, 0, 0, 0];
+
+    let segments = ip_address.segments();
+    let [first_segment, second_segment, ..] = segments;
+    // IPv4 addresses mapped or translated to IPv6 are checked as the IPv4 address they embed
+    if let Some(ipv4_address) = ip_address.to_ipv4_mapped() {
+        return is_public_ipv4_address(&ipv4_address);
+    }
+    if segments.starts_with(&NAT64_PREFIX) {
+        let [.., first_octet, second_octet, third_octet, fourth_octet] = ip_address.octets();
+        return is_public_ipv4_address(&Ipv4Addr::new(
+            first_octet,
+            second_octet,
+            third_octet,
+            fourth_octet,
+        ));
+    }
+
+    !(ip_address.is_loopback()
+        || ip_address.is_unspecified()
+        || ip_address.is_multicast()
+        // Unique local addresses, fc00::/7
+        || (first_segment & 0xfe00) == 0xfc00
+        // Link local addresses, fe80::/10
+        || (first_segment & 0xffc0) == 0xfe80
+        // Documentation addresses, 2001:db8::/32
+        || (first_segment == 0x2001 && second_segment == 0x0db8))
+}
+
+/// Validates a URL that requests are sent to on behalf of users, such as a webhook URL. The URL
+/// has to use HTTPS, and a host given as an IP address has to be public. Host names are not
+/// resolved here, the addresses they resolve to have to be checked with [`is_public_ip_address`].
+pub fn validate_public_https_url(url: &str) -> CustomResult<url::Url, ValidationError> {
+    let url = url::Url::parse(url).map_err(|error| {
+        report!(ValidationError::InvalidValue {
+            message: format!("Invalid URL: {error}"),
+        })
+    })?;
+
+    if url.scheme() != "https" {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "URL must use https".to_string(),
+        }));
+    }
+
+    let is_public_host = match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(url::Host::Ipv4(ip_address)) => is_public_ipv4_address(&ip_address),
+        Some(url::Host::Ipv6(ip_address)) => is_public_ipv6_address(&ip_address),
+        None => false,
+    };
+    if !is_public_host {
+        return Err(report!(ValidationError::InvalidValue {
+            message: "URL must point to a public host".to_string(),
+        }));
+    }
+
+    Ok(url)
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{faker::internet::en::SafeEmail, Fake};
@@ -323,4 +419,46 @@ mod tests {
     fn allows_percent_char_without_encoding() {
         assert!(!contains_potential_xss_or_sqli("Get 50% off today"));
     }
+
+    #[test_case("8.8.8.8" ; "public ipv4 address")]
+    #[test_case("2606:4700:4700::1111" ; "public ipv6 address")]
+    #[test_case("::ffff:8.8.8.8" ; "ipv4 mapped public address")]
+    fn test_public_ip_address(ip_address: &str) {
+        assert!(is_public_ip_address(&ip_address.parse().unwrap()));
+    }
+
+    #[test_case("127.0.0.1" ; "loopback ipv4 address")]
+    #[test_case("10.1.2.3" ; "private ipv4 address")]
+    #[test_case("172.16.0.1" ; "private ipv4 address in 172.16.0.0/12")]
+    #[test_case("192.168.1.1" ; "private ipv4 address in 192.168.0.0/16")]
+    #[test_case("169.254.169.254" ; "link local ipv4 address")]
+    #[test_case("100.64.0.1" ; "shared ipv4 address")]
+    #[test_case("0.0.0.0" ; "unspecified ipv4 address")]
+    #[test_case("255.255.255.255" ; "broadcast ipv4 address")]
+    #[test_case("::1" ; "loopback ipv6 address")]
+    #[test_case("fd00::1" ; "unique local ipv6 address")]
+    #[test_case("fe80::1" ; "link local ipv6 address")]
+    #[test_case("::ffff:127.0.0.1" ; "ipv4 mapped loopback address")]
+    #[test_case("64:ff9b::a9fe:a9fe" ; "nat64 translated link local address")]
+    fn test_non_public_ip_address(ip_address: &str) {
+        assert!(!is_public_ip_address(&ip_address.parse().unwrap()));
+    }
+
+    #[test_case("https://example.com/webhooks" ; "domain")]
+    #[test_case("https://8.8.8.8:8443/webhooks" ; "public ip address")]
+    fn test_valid_public_https_url(url: &str) {
+        assert!(validate_public_https_url(url).is_ok());
+    }
+
+    #[test_case("http://example.com/webhooks" ; "plain http")]
+    #[test_case("https://localhost/webhooks" ; "localhost")]
+    #[test_case("https://api.localhost./webhooks" ; "localhost subdomain")]
+    #[test_case("https://127.0.0.1/webhooks" ; "loopback address")]
+    #[test_case("https://[::1]/webhooks" ; "loopback ipv6 address")]
+    #[test_case("https://169.254.169.254/latest/meta-data" ; "link local address")]
+    #[test_case("https://0x7f000001/webhooks" ; "hex encoded loopback address")]
+    #[test_case("not a url" ; "invalid url")]
+    fn test_invalid_public_https_url(url: &str) {
+        assert!(validate_public_https_url(url).is_err());
+    }
 }
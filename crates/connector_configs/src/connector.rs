@@ -353,6 +353,7 @@ pub struct ConnectorConfig {
     pub tokenex: Option<ConnectorTomlConfig>,
     pub tokenio: Option<ConnectorTomlConfig>,
     pub trustpay: Option<ConnectorTomlConfig>,
+    pub truelayer: Option<ConnectorTomlConfig>,
     pub trustpayments: Option<ConnectorTomlConfig>,
     pub threedsecureio: Option<ConnectorTomlConfig>,
     pub netcetera: Option<ConnectorTomlConfig>,
@@ -471,6 +472,7 @@ impl ConnectorConfig {
         let connector_data = Self::new()?;
         match connector {
             PmAuthConnectors::Plaid => Ok(connector_data.plaid),
+            PmAuthConnectors::Truelayer => Ok(connector_data.truelayer),
         }
     }
 
@@ -587,6 +589,7 @@ impl ConnectorConfig {
             Connector::Tokenex => Ok(connector_data.tokenex),
             Connector::Tokenio => Ok(connector_data.tokenio),
             Connector::Trustpay => Ok(connector_data.trustpay),
+            Connector::Truelayer => Ok(connector_data.truelayer),
             Connector::Trustpayments => Ok(connector_data.trustpayments),
             Connector::Threedsecureio => Ok(connector_data.threedsecureio),
             Connector::Taxjar => Ok(connector_data.taxjar),
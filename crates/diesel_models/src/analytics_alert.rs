@@ -0,0 +1,170 @@
+use common_utils::{id_type, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums as storage_enums,
+    schema::{analytics_alert_history, analytics_alert_rule},
+};
+
+#[derive(Clone, Debug, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_alert_rule, check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsAlertRuleNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub name: String,
+    pub metric: storage_enums::AnalyticsAlertMetric,
+    pub filters: serde_json::Value,
+    pub baseline: storage_enums::AnalyticsAlertBaseline,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_periods: i32,
+    pub deviation_threshold: f64,
+    pub direction: storage_enums::AnalyticsAlertDirection,
+    pub min_volume: i32,
+    pub channels: pii::SecretSerdeValue,
+    pub mute_windows: serde_json::Value,
+    pub is_enabled: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_alert_rule, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsAlertRule {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub name: String,
+    pub metric: storage_enums::AnalyticsAlertMetric,
+    pub filters: serde_json::Value,
+    pub baseline: storage_enums::AnalyticsAlertBaseline,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_periods: i32,
+    pub deviation_threshold: f64,
+    pub direction: storage_enums::AnalyticsAlertDirection,
+    pub min_volume: i32,
+    pub channels: pii::SecretSerdeValue,
+    pub mute_windows: serde_json::Value,
+    pub is_enabled: bool,
+    pub last_evaluated_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsAlertRuleUpdate {
+    Update {
+        name: Option<String>,
+        filters: Option<serde_json::Value>,
+        baseline: Option<storage_enums::AnalyticsAlertBaseline>,
+        evaluation_window_in_mins: Option<i32>,
+        baseline_periods: Option<i32>,
+        deviation_threshold: Option<f64>,
+        direction: Option<storage_enums::AnalyticsAlertDirection>,
+        min_volume: Option<i32>,
+        channels: Option<pii::SecretSerdeValue>,
+        mute_windows: Option<serde_json::Value>,
+        is_enabled: Option<bool>,
+    },
+    Evaluated {
+        last_evaluated_at: time::PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_alert_rule)]
+pub struct AnalyticsAlertRuleUpdateInternal {
+    pub name: Option<String>,
+    pub filters: Option<serde_json::Value>,
+    pub baseline: Option<storage_enums::AnalyticsAlertBaseline>,
+    pub evaluation_window_in_mins: Option<i32>,
+    pub baseline_periods: Option<i32>,
+    pub deviation_threshold: Option<f64>,
+    pub direction: Option<storage_enums::AnalyticsAlertDirection>,
+    pub min_volume: Option<i32>,
+    pub channels: Option<pii::SecretSerdeValue>,
+    pub mute_windows: Option<serde_json::Value>,
+    pub is_enabled: Option<bool>,
+    pub last_evaluated_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: Option<time::PrimitiveDateTime>,
+}
+
+impl From<AnalyticsAlertRuleUpdate> for AnalyticsAlertRuleUpdateInternal {
+    fn from(analytics_alert_rule_update: AnalyticsAlertRuleUpdate) -> Self {
+        match analytics_alert_rule_update {
+            AnalyticsAlertRuleUpdate::Update {
+                name,
+                filters,
+                baseline,
+                evaluation_window_in_mins,
+                baseline_periods,
+                deviation_threshold,
+                direction,
+                min_volume,
+                channels,
+                mute_windows,
+                is_enabled,
+            } => Self {
+                name,
+                filters,
+                baseline,
+                evaluation_window_in_mins,
+                baseline_periods,
+                deviation_threshold,
+                direction,
+                min_volume,
+                channels,
+                mute_windows,
+                is_enabled,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            // Evaluations are not changes made by the merchant, and leave `modified_at` untouched
+            AnalyticsAlertRuleUpdate::Evaluated { last_evaluated_at } => Self {
+                last_evaluated_at: Some(last_evaluated_at),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_alert_history, check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsAlertHistoryNew {
+    pub id: String,
+    pub rule_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub metric: storage_enums::AnalyticsAlertMetric,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub error_code: Option<String>,
+    pub observed_value: f64,
+    pub baseline_mean: f64,
+    pub baseline_stddev: f64,
+    pub sample_size: i64,
+    pub window_start: time::PrimitiveDateTime,
+    pub window_end: time::PrimitiveDateTime,
+    pub delivery_status: storage_enums::AnalyticsAlertDeliveryStatus,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_alert_history, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsAlertHistory {
+    pub id: String,
+    pub rule_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub metric: storage_enums::AnalyticsAlertMetric,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub error_code: Option<String>,
+    pub observed_value: f64,
+    pub baseline_mean: f64,
+    pub baseline_stddev: f64,
+    pub sample_size: i64,
+    pub window_start: time::PrimitiveDateTime,
+    pub window_end: time::PrimitiveDateTime,
+    pub delivery_status: storage_enums::AnalyticsAlertDeliveryStatus,
+    pub created_at: time::PrimitiveDateTime,
+}
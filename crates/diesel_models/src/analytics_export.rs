@@ -0,0 +1,225 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums as storage_enums,
+    schema::{analytics_export, analytics_export_run},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_export, check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsExportNew {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub organization_id: id_type::OrganizationId,
+    pub name: String,
+    pub dataset: storage_enums::AnalyticsReportType,
+    pub columns: serde_json::Value,
+    pub filters: serde_json::Value,
+    pub format: storage_enums::AnalyticsExportFormat,
+    pub frequency: storage_enums::AnalyticsExportFrequency,
+    pub schedule_hour: i32,
+    pub destination: Encryption,
+    pub is_enabled: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = analytics_export, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsExport {
+    pub id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub organization_id: id_type::OrganizationId,
+    pub name: String,
+    pub dataset: storage_enums::AnalyticsReportType,
+    pub columns: serde_json::Value,
+    pub filters: serde_json::Value,
+    pub format: storage_enums::AnalyticsExportFormat,
+    pub frequency: storage_enums::AnalyticsExportFrequency,
+    pub schedule_hour: i32,
+    pub destination: Encryption,
+    pub is_enabled: bool,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsExportUpdate {
+    Update {
+        name: Option<String>,
+        columns: Option<serde_json::Value>,
+        filters: Option<serde_json::Value>,
+        format: Option<storage_enums::AnalyticsExportFormat>,
+        schedule_hour: Option<i32>,
+        destination: Option<Encryption>,
+        is_enabled: Option<bool>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_export)]
+pub struct AnalyticsExportUpdateInternal {
+    pub name: Option<String>,
+    pub columns: Option<serde_json::Value>,
+    pub filters: Option<serde_json::Value>,
+    pub format: Option<storage_enums::AnalyticsExportFormat>,
+    pub schedule_hour: Option<i32>,
+    pub destination: Option<Encryption>,
+    pub is_enabled: Option<bool>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl From<AnalyticsExportUpdate> for AnalyticsExportUpdateInternal {
+    fn from(analytics_export_update: AnalyticsExportUpdate) -> Self {
+        match analytics_export_update {
+            AnalyticsExportUpdate::Update {
+                name,
+                columns,
+                filters,
+                format,
+                schedule_hour,
+                destination,
+                is_enabled,
+            } => Self {
+                name,
+                columns,
+                filters,
+                format,
+                schedule_hour,
+                destination,
+                is_enabled,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_export_run, check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsExportRunNew {
+    pub id: String,
+    pub export_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub period_start: time::PrimitiveDateTime,
+    pub period_end: time::PrimitiveDateTime,
+    pub status: storage_enums::AnalyticsExportRunStatus,
+    pub attempt_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = analytics_export_run, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsExportRun {
+    pub id: String,
+    pub export_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub period_start: time::PrimitiveDateTime,
+    pub period_end: time::PrimitiveDateTime,
+    pub status: storage_enums::AnalyticsExportRunStatus,
+    pub file_name: Option<String>,
+    pub file_size: Option<i64>,
+    pub row_count: Option<i64>,
+    pub checksum: Option<String>,
+    pub attempt_count: i32,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsExportRunUpdate {
+    /// The run is queued to be retried
+    Pending,
+    Running {
+        attempt_count: i32,
+    },
+    Succeeded {
+        file_name: String,
+        file_size: i64,
+        row_count: i64,
+        checksum: String,
+    },
+    Failed {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_export_run)]
+pub struct AnalyticsExportRunUpdateInternal {
+    pub status: Option<storage_enums::AnalyticsExportRunStatus>,
+    pub file_name: Option<String>,
+    pub file_size: Option<i64>,
+    pub row_count: Option<i64>,
+    pub checksum: Option<String>,
+    pub attempt_count: Option<i32>,
+    pub error_message: Option<Option<String>>,
+    pub modified_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+impl From<AnalyticsExportRunUpdate> for AnalyticsExportRunUpdateInternal {
+    fn from(analytics_export_run_update: AnalyticsExportRunUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match analytics_export_run_update {
+            AnalyticsExportRunUpdate::Pending => Self {
+                status: Some(storage_enums::AnalyticsExportRunStatus::Pending),
+                file_name: None,
+                file_size: None,
+                row_count: None,
+                checksum: None,
+                attempt_count: None,
+                error_message: None,
+                modified_at,
+                completed_at: None,
+            },
+            AnalyticsExportRunUpdate::Running { attempt_count } => Self {
+                status: Some(storage_enums::AnalyticsExportRunStatus::Running),
+                file_name: None,
+                file_size: None,
+                row_count: None,
+                checksum: None,
+                attempt_count: Some(attempt_count),
+                error_message: None,
+                modified_at,
+                completed_at: None,
+            },
+            AnalyticsExportRunUpdate::Succeeded {
+                file_name,
+                file_size,
+                row_count,
+                checksum,
+            } => Self {
+                status: Some(storage_enums::AnalyticsExportRunStatus::Succeeded),
+                file_name: Some(file_name),
+                file_size: Some(file_size),
+                row_count: Some(row_count),
+                checksum: Some(checksum),
+                attempt_count: None,
+                // The error of an earlier attempt no longer applies
+                error_message: Some(None),
+                modified_at,
+                completed_at: Some(modified_at),
+            },
+            AnalyticsExportRunUpdate::Failed { error_message } => Self {
+                status: Some(storage_enums::AnalyticsExportRunStatus::Failed),
+                file_name: None,
+                file_size: None,
+                row_count: None,
+                checksum: None,
+                attempt_count: None,
+                error_message: Some(Some(error_message)),
+                modified_at,
+                completed_at: Some(modified_at),
+            },
+        }
+    }
+}
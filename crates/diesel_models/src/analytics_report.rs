@@ -0,0 +1,110 @@
+use common_utils::{id_type, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::analytics_report};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = analytics_report, check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsReportNew {
+    pub id: String,
+    pub organization_id: id_type::OrganizationId,
+    pub report_type: storage_enums::AnalyticsReportType,
+    pub status: storage_enums::AnalyticsReportStatus,
+    pub auth_info: serde_json::Value,
+    pub start_time: time::PrimitiveDateTime,
+    pub end_time: Option<time::PrimitiveDateTime>,
+    pub requested_by: String,
+    pub notification_emails: Option<pii::SecretSerdeValue>,
+    pub webhook_url: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = analytics_report, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct AnalyticsReport {
+    pub id: String,
+    pub organization_id: id_type::OrganizationId,
+    pub report_type: storage_enums::AnalyticsReportType,
+    pub status: storage_enums::AnalyticsReportStatus,
+    pub auth_info: serde_json::Value,
+    pub start_time: time::PrimitiveDateTime,
+    pub end_time: Option<time::PrimitiveDateTime>,
+    pub requested_by: String,
+    pub notification_emails: Option<pii::SecretSerdeValue>,
+    pub webhook_url: Option<String>,
+    pub file_key: Option<String>,
+    pub file_size: Option<i64>,
+    pub row_count: Option<i64>,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsReportUpdate {
+    Processing,
+    Completed {
+        file_key: String,
+        file_size: i64,
+        row_count: i64,
+    },
+    Failed {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = analytics_report)]
+pub struct AnalyticsReportUpdateInternal {
+    pub status: Option<storage_enums::AnalyticsReportStatus>,
+    pub file_key: Option<String>,
+    pub file_size: Option<i64>,
+    pub row_count: Option<i64>,
+    pub error_message: Option<String>,
+    pub modified_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+impl From<AnalyticsReportUpdate> for AnalyticsReportUpdateInternal {
+    fn from(analytics_report_update: AnalyticsReportUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match analytics_report_update {
+            AnalyticsReportUpdate::Processing => Self {
+                status: Some(storage_enums::AnalyticsReportStatus::Processing),
+                file_key: None,
+                file_size: None,
+                row_count: None,
+                error_message: None,
+                modified_at,
+                completed_at: None,
+            },
+            AnalyticsReportUpdate::Completed {
+                file_key,
+                file_size,
+                row_count,
+            } => Self {
+                status: Some(storage_enums::AnalyticsReportStatus::Completed),
+                file_key: Some(file_key),
+                file_size: Some(file_size),
+                row_count: Some(row_count),
+                error_message: None,
+                modified_at,
+                completed_at: Some(modified_at),
+            },
+            AnalyticsReportUpdate::Failed { error_message } => Self {
+                status: Some(storage_enums::AnalyticsReportStatus::Failed),
+                file_key: None,
+                file_size: None,
+                row_count: None,
+                error_message: Some(error_message),
+                modified_at,
+                completed_at: Some(modified_at),
+            },
+        }
+    }
+}
@@ -834,6 +834,7 @@ pub struct WebhookDetails {
     pub refund_statuses_enabled: Option<Vec<common_enums::RefundStatus>>,
     pub payout_statuses_enabled: Option<Vec<common_enums::PayoutStatus>>,
     pub multiple_webhooks_list: Option<Vec<MultipleWebhookDetail>>,
+    pub webhook_format: Option<common_enums::OutgoingWebhookFormat>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
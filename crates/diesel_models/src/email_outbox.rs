@@ -0,0 +1,129 @@
+use common_utils::{encryption::Encryption, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::email_outbox};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = email_outbox, check_for_backend(diesel::pg::Pg))]
+pub struct EmailOutboxNew {
+    pub id: String,
+    pub recipient: pii::Email,
+    pub subject: String,
+    pub body: Option<Encryption>,
+    pub email_type: storage_enums::EmailOutboxType,
+    pub status: storage_enums::EmailOutboxStatus,
+    pub theme_id: Option<String>,
+    pub locale: Option<String>,
+    pub retry_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = email_outbox, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct EmailOutbox {
+    pub id: String,
+    pub recipient: pii::Email,
+    pub subject: String,
+    /// Body of the email encrypted with the master key, cleared once the email is final
+    pub body: Option<Encryption>,
+    pub email_type: storage_enums::EmailOutboxType,
+    pub status: storage_enums::EmailOutboxStatus,
+    pub theme_id: Option<String>,
+    pub locale: Option<String>,
+    pub retry_count: i32,
+    pub last_error: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    pub sent_at: Option<time::PrimitiveDateTime>,
+}
+
+/// Updates of an email outbox entry. The body of the email is cleared once the email reaches a
+/// final status, as it may contain tokens which must not outlive their use.
+#[derive(Debug)]
+pub enum EmailOutboxUpdate {
+    Sent {
+        retry_count: i32,
+    },
+    RetryScheduled {
+        retry_count: i32,
+        last_error: String,
+    },
+    Failed {
+        retry_count: i32,
+        last_error: String,
+    },
+    Suppressed,
+    DeliveryEventReceived {
+        status: storage_enums::EmailOutboxStatus,
+        last_error: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = email_outbox)]
+pub struct EmailOutboxUpdateInternal {
+    pub status: Option<storage_enums::EmailOutboxStatus>,
+    pub body: Option<Option<Encryption>>,
+    pub retry_count: Option<i32>,
+    pub last_error: Option<String>,
+    pub sent_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl From<EmailOutboxUpdate> for EmailOutboxUpdateInternal {
+    fn from(email_outbox_update: EmailOutboxUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match email_outbox_update {
+            EmailOutboxUpdate::Sent { retry_count } => Self {
+                status: Some(storage_enums::EmailOutboxStatus::Sent),
+                body: Some(None),
+                retry_count: Some(retry_count),
+                last_error: None,
+                sent_at: Some(modified_at),
+                modified_at,
+            },
+            EmailOutboxUpdate::RetryScheduled {
+                retry_count,
+                last_error,
+            } => Self {
+                status: Some(storage_enums::EmailOutboxStatus::Pending),
+                body: None,
+                retry_count: Some(retry_count),
+                last_error: Some(last_error),
+                sent_at: None,
+                modified_at,
+            },
+            EmailOutboxUpdate::Failed {
+                retry_count,
+                last_error,
+            } => Self {
+                status: Some(storage_enums::EmailOutboxStatus::Failed),
+                body: Some(None),
+                retry_count: Some(retry_count),
+                last_error: Some(last_error),
+                sent_at: None,
+                modified_at,
+            },
+            EmailOutboxUpdate::Suppressed => Self {
+                status: Some(storage_enums::EmailOutboxStatus::Suppressed),
+                body: Some(None),
+                retry_count: None,
+                last_error: None,
+                sent_at: None,
+                modified_at,
+            },
+            EmailOutboxUpdate::DeliveryEventReceived { status, last_error } => Self {
+                status: Some(status),
+                body: None,
+                retry_count: None,
+                last_error,
+                sent_at: None,
+                modified_at,
+            },
+        }
+    }
+}
@@ -107,6 +107,9 @@ pub enum EventMetadata {
         invoice_id: Option<common_utils::id_type::InvoiceId>,
         payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -19,6 +19,7 @@ pub struct FileMetadataNew {
     pub connector_label: Option<String>,
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub scan_status: Option<common_enums::FileScanStatus>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -38,6 +39,7 @@ pub struct FileMetadata {
     pub connector_label: Option<String>,
     pub profile_id: Option<common_utils::id_type::ProfileId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub scan_status: Option<common_enums::FileScanStatus>,
 }
 
 #[derive(Debug)]
@@ -48,6 +50,10 @@ pub enum FileMetadataUpdate {
         available: bool,
         profile_id: Option<common_utils::id_type::ProfileId>,
         merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+        scan_status: Option<common_enums::FileScanStatus>,
+    },
+    ScanStatusUpdate {
+        scan_status: common_enums::FileScanStatus,
     },
 }
 
@@ -56,9 +62,10 @@ pub enum FileMetadataUpdate {
 pub struct FileMetadataUpdateInternal {
     provider_file_id: Option<String>,
     file_upload_provider: Option<common_enums::FileUploadProvider>,
-    available: bool,
+    available: Option<bool>,
     profile_id: Option<common_utils::id_type::ProfileId>,
     merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    scan_status: Option<common_enums::FileScanStatus>,
 }
 
 impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
@@ -70,12 +77,18 @@ impl From<FileMetadataUpdate> for FileMetadataUpdateInternal {
                 available,
                 profile_id,
                 merchant_connector_id,
+                scan_status,
             } => Self {
                 provider_file_id,
                 file_upload_provider,
-                available,
+                available: Some(available),
                 profile_id,
                 merchant_connector_id,
+                scan_status,
+            },
+            FileMetadataUpdate::ScanStatusUpdate { scan_status } => Self {
+                scan_status: Some(scan_status),
+                ..Default::default()
             },
         }
     }
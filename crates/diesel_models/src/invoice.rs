@@ -12,14 +12,14 @@ pub struct InvoiceNew {
     pub subscription_id: common_utils::id_type::SubscriptionId,
     pub merchant_id: common_utils::id_type::MerchantId,
     pub profile_id: common_utils::id_type::ProfileId,
-    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub payment_intent_id: Option<common_utils::id_type::PaymentId>,
     pub payment_method_id: Option<String>,
     pub customer_id: common_utils::id_type::CustomerId,
     pub amount: MinorUnit,
     pub currency: String,
     pub status: InvoiceStatus,
-    pub provider_name: Connector,
+    pub provider_name: Option<Connector>,
     pub metadata: Option<SecretSerdeValue>,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
@@ -39,14 +39,14 @@ pub struct Invoice {
     pub subscription_id: common_utils::id_type::SubscriptionId,
     pub merchant_id: common_utils::id_type::MerchantId,
     pub profile_id: common_utils::id_type::ProfileId,
-    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub payment_intent_id: Option<common_utils::id_type::PaymentId>,
     pub payment_method_id: Option<String>,
     pub customer_id: common_utils::id_type::CustomerId,
     pub amount: MinorUnit,
     pub currency: String,
     pub status: InvoiceStatus,
-    pub provider_name: Connector,
+    pub provider_name: Option<Connector>,
     pub metadata: Option<SecretSerdeValue>,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
@@ -71,14 +71,14 @@ impl InvoiceNew {
         subscription_id: common_utils::id_type::SubscriptionId,
         merchant_id: common_utils::id_type::MerchantId,
         profile_id: common_utils::id_type::ProfileId,
-        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
         payment_intent_id: Option<common_utils::id_type::PaymentId>,
         payment_method_id: Option<String>,
         customer_id: common_utils::id_type::CustomerId,
         amount: MinorUnit,
         currency: String,
         status: InvoiceStatus,
-        provider_name: Connector,
+        provider_name: Option<Connector>,
         metadata: Option<SecretSerdeValue>,
         connector_invoice_id: Option<common_utils::id_type::InvoiceId>,
     ) -> Self {
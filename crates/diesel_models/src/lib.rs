@@ -1,4 +1,7 @@
 pub mod address;
+pub mod analytics_alert;
+pub mod analytics_export;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
@@ -14,6 +17,7 @@ pub mod callback_mapper;
 pub mod customers;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod email_outbox;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
@@ -38,6 +42,7 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
@@ -46,7 +51,9 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
 pub mod subscription;
+pub mod subscription_plan;
 pub mod types;
 pub mod unified_translations;
 
@@ -275,6 +275,10 @@ pub enum PaymentMethodUpdate {
         payment_method_data: Option<Encryption>,
         last_modified_by: Option<String>,
     },
+    CustomerUpdate {
+        customer_id: common_utils::id_type::CustomerId,
+        last_modified_by: Option<String>,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -416,6 +420,7 @@ impl PaymentMethodUpdateInternal {
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay, Serialize, Deserialize)]
 #[diesel(table_name = payment_methods)]
 pub struct PaymentMethodUpdateInternal {
+    customer_id: Option<common_utils::id_type::CustomerId>,
     metadata: Option<serde_json::Value>,
     payment_method_data: Option<Encryption>,
     last_used_at: Option<PrimitiveDateTime>,
@@ -439,6 +444,7 @@ pub struct PaymentMethodUpdateInternal {
 impl PaymentMethodUpdateInternal {
     pub fn apply_changeset(self, source: PaymentMethod) -> PaymentMethod {
         let Self {
+            customer_id,
             metadata,
             payment_method_data,
             last_used_at,
@@ -459,7 +465,7 @@ impl PaymentMethodUpdateInternal {
         } = self;
 
         PaymentMethod {
-            customer_id: source.customer_id,
+            customer_id: customer_id.unwrap_or(source.customer_id),
             merchant_id: source.merchant_id,
             payment_method_id: source.payment_method_id,
             accepted_currency: source.accepted_currency,
@@ -513,6 +519,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_used_at,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata,
                 payment_method_data: None,
                 last_used_at: Some(last_used_at),
@@ -535,6 +542,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_data,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data,
                 last_used_at: None,
@@ -554,6 +562,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified_by,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: Some(last_used_at),
@@ -578,6 +587,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_used_at,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data,
                 last_used_at: Some(last_used_at),
@@ -601,6 +611,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 status,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
@@ -623,6 +634,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 status,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
@@ -653,6 +665,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data,
                 last_used_at: None,
@@ -675,6 +688,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 connector_mandate_details,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
@@ -699,6 +713,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
@@ -722,6 +737,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_transaction_id,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 connector_mandate_details: connector_mandate_details
                     .map(|mandate_details| mandate_details.expose()),
                 network_transaction_id: network_transaction_id.map(|txn_id| txn_id.expose()),
@@ -741,6 +757,29 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 scheme: None,
                 last_modified_by,
             },
+            PaymentMethodUpdate::CustomerUpdate {
+                customer_id,
+                last_modified_by,
+            } => Self {
+                customer_id: Some(customer_id),
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                network_token_requestor_reference_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+                scheme: None,
+                last_modified_by,
+            },
             PaymentMethodUpdate::PaymentMethodBatchUpdate {
                 connector_mandate_details,
                 network_transaction_id,
@@ -748,6 +787,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_data,
                 last_modified_by,
             } => Self {
+                customer_id: None,
                 metadata: None,
                 last_used_at: None,
                 status,
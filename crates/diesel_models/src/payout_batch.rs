@@ -0,0 +1,216 @@
+use common_utils::{encryption::Encryption, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch, check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatchNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    pub cancelled_count: i32,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payout_batch, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    pub cancelled_count: i32,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+impl PayoutBatch {
+    /// Number of items which have not reached a terminal status yet
+    pub fn get_pending_count(&self) -> i32 {
+        self.total_count - self.processed_count - self.failed_count - self.cancelled_count
+    }
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+    CountsUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        processed_count: i32,
+        failed_count: i32,
+        cancelled_count: i32,
+        completed_at: Option<time::PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchStatus>,
+    pub processed_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub cancelled_count: Option<i32>,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(payout_batch_update: PayoutBatchUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match payout_batch_update {
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                processed_count: None,
+                failed_count: None,
+                cancelled_count: None,
+                completed_at: None,
+                modified_at,
+            },
+            PayoutBatchUpdate::CountsUpdate {
+                status,
+                processed_count,
+                failed_count,
+                cancelled_count,
+                completed_at,
+            } => Self {
+                status: Some(status),
+                processed_count: Some(processed_count),
+                failed_count: Some(failed_count),
+                cancelled_count: Some(cancelled_count),
+                completed_at,
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = payout_batch_item, check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatchItemNew {
+    pub id: String,
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub payout_id: common_utils::id_type::PayoutId,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_request: Encryption,
+    pub retry_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payout_batch_item, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatchItem {
+    pub id: String,
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub payout_id: common_utils::id_type::PayoutId,
+    pub status: storage_enums::PayoutBatchItemStatus,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    /// Encrypted payout create request of the item, cleared once the item reaches a final status
+    pub payout_request: Option<Encryption>,
+    pub retry_count: i32,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// Updates of a payout batch item. The payout request is cleared once the item reaches a final
+/// status, as it holds the customer and payout method details of the payout.
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    Processed {
+        payout_status: storage_enums::PayoutStatus,
+    },
+    RetryScheduled {
+        retry_count: i32,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+    Failed {
+        retry_count: i32,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchItemStatus>,
+    pub payout_status: Option<storage_enums::PayoutStatus>,
+    pub payout_request: Option<Option<Encryption>>,
+    pub retry_count: Option<i32>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(payout_batch_item_update: PayoutBatchItemUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match payout_batch_item_update {
+            PayoutBatchItemUpdate::Processed { payout_status } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Processed),
+                payout_status: Some(payout_status),
+                payout_request: Some(None),
+                retry_count: None,
+                error_code: None,
+                error_message: None,
+                modified_at,
+            },
+            PayoutBatchItemUpdate::RetryScheduled {
+                retry_count,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Pending),
+                payout_status: None,
+                payout_request: None,
+                retry_count: Some(retry_count),
+                error_code,
+                error_message,
+                modified_at,
+            },
+            PayoutBatchItemUpdate::Failed {
+                retry_count,
+                error_code,
+                error_message,
+            } => Self {
+                status: Some(storage_enums::PayoutBatchItemStatus::Failed),
+                payout_status: None,
+                payout_request: Some(None),
+                retry_count: Some(retry_count),
+                error_code,
+                error_message,
+                modified_at,
+            },
+        }
+    }
+}
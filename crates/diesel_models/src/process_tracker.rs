@@ -106,6 +106,17 @@ impl ProcessTrackerNew {
     }
 }
 
+/// Constraints for filtering processes, each of which is applied only if specified.
+#[derive(Clone, Debug, Default)]
+pub struct ProcessTrackerFilterConstraints {
+    pub runner: Option<ProcessTrackerRunner>,
+    pub statuses: Option<Vec<storage_enums::ProcessTrackerStatus>>,
+    /// Merchant ID in the tracking data of the process
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    /// Time window within which the process is scheduled to be executed
+    pub schedule_time_range: Option<common_utils::types::TimeRange>,
+}
+
 #[derive(Debug)]
 pub enum ProcessTrackerUpdate {
     Update {
@@ -154,6 +165,31 @@ impl Default for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        let Self {
+            name,
+            retry_count,
+            schedule_time,
+            tracking_data,
+            business_status,
+            status,
+            updated_at,
+        } = self;
+
+        ProcessTracker {
+            name: name.or(source.name),
+            retry_count: retry_count.unwrap_or(source.retry_count),
+            schedule_time: schedule_time.or(source.schedule_time),
+            tracking_data: tracking_data.unwrap_or(source.tracking_data),
+            business_status: business_status.unwrap_or(source.business_status),
+            status: status.unwrap_or(source.status),
+            updated_at: updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     fn from(process_tracker_update: ProcessTrackerUpdate) -> Self {
         match process_tracker_update {
@@ -249,6 +285,10 @@ pub mod business_status {
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 
+    /// The task was cancelled by an administrator before it could complete.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const CANCELLED: &str = "CANCELLED";
+
     /// For the PCR Workflow
     ///
     /// This status indicates the completion of a execute task
@@ -1,4 +1,7 @@
 pub mod address;
+pub mod analytics_alert;
+pub mod analytics_export;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
@@ -15,6 +18,7 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod email_outbox;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
@@ -34,6 +38,7 @@ pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
@@ -41,7 +46,9 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
 pub mod subscription;
+pub mod subscription_plan;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod unified_translations;
@@ -0,0 +1,139 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    analytics_alert::{
+        AnalyticsAlertHistory, AnalyticsAlertHistoryNew, AnalyticsAlertRule, AnalyticsAlertRuleNew,
+        AnalyticsAlertRuleUpdate, AnalyticsAlertRuleUpdateInternal,
+    },
+    errors,
+    schema::{analytics_alert_history::dsl as history_dsl, analytics_alert_rule::dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsAlertRuleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsAlertRule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsAlertRule {
+    pub async fn find_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        analytics_alert_rule_update: AnalyticsAlertRuleUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id.to_owned()),
+            AnalyticsAlertRuleUpdateInternal::from(analytics_alert_rule_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn delete_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl AnalyticsAlertHistoryNew {
+    pub async fn batch_insert(alert_history: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, AnalyticsAlertHistory>(conn, alert_history).await?;
+        Ok(())
+    }
+}
+
+impl AnalyticsAlertHistory {
+    /// Lists the alerts raised for the merchant, optionally restricted to a single rule, latest
+    /// alert first
+    pub async fn list_by_merchant_id_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match rule_id {
+            Some(rule_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    history_dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(history_dsl::rule_id.eq(rule_id.to_owned())),
+                    Some(limit),
+                    Some(offset),
+                    Some(history_dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    history_dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(limit),
+                    Some(offset),
+                    Some(history_dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+}
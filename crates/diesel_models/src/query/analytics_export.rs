@@ -0,0 +1,177 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    analytics_export::{
+        AnalyticsExport, AnalyticsExportNew, AnalyticsExportRun, AnalyticsExportRunNew,
+        AnalyticsExportRunUpdate, AnalyticsExportRunUpdateInternal, AnalyticsExportUpdate,
+        AnalyticsExportUpdateInternal,
+    },
+    errors,
+    schema::{analytics_export::dsl, analytics_export_run::dsl as run_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsExportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsExport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsExport {
+    pub async fn find_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        analytics_export_update: AnalyticsExportUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id.to_owned()),
+            AnalyticsExportUpdateInternal::from(analytics_export_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn delete_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl AnalyticsExportRunNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsExportRun> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsExportRun {
+    pub async fn find_by_merchant_id_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            run_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(run_dsl::id.eq(id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_export_id_period_start(
+        conn: &PgPooledConn,
+        export_id: &str,
+        period_start: time::PrimitiveDateTime,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            run_dsl::export_id
+                .eq(export_id.to_owned())
+                .and(run_dsl::period_start.eq(period_start)),
+        )
+        .await
+    }
+
+    /// Lists the runs of an export, latest period first
+    pub async fn list_by_merchant_id_export_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            run_dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(run_dsl::export_id.eq(export_id.to_owned())),
+            Some(limit),
+            Some(offset),
+            Some(run_dsl::period_start.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        analytics_export_run_update: AnalyticsExportRunUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            run_dsl::id.eq(self.id.to_owned()),
+            AnalyticsExportRunUpdateInternal::from(analytics_export_run_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
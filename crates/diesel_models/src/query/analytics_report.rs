@@ -0,0 +1,52 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    analytics_report::{
+        AnalyticsReport, AnalyticsReportNew, AnalyticsReportUpdate, AnalyticsReportUpdateInternal,
+    },
+    errors,
+    schema::analytics_report::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsReportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsReport {
+    pub async fn find_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        analytics_report_update: AnalyticsReportUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id.to_owned()),
+            AnalyticsReportUpdateInternal::from(analytics_report_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use common_utils::pii;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    email_outbox::{EmailOutbox, EmailOutboxNew, EmailOutboxUpdate, EmailOutboxUpdateInternal},
+    enums as storage_enums, errors,
+    schema::email_outbox::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl EmailOutboxNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<EmailOutbox> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl EmailOutbox {
+    pub async fn find_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::id.eq(id.to_owned()),
+        )
+        .await
+    }
+
+    /// Lists the emails sent to the recipient which are in any of the given statuses, latest first
+    pub async fn list_by_recipient_and_statuses(
+        conn: &PgPooledConn,
+        recipient: &pii::Email,
+        statuses: Vec<storage_enums::EmailOutboxStatus>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::recipient
+                .eq(recipient.to_owned())
+                .and(dsl::status.eq_any(statuses)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(conn, dsl::id.eq(id.to_owned()))
+            .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        email_outbox_update: EmailOutboxUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id.to_owned()),
+            EmailOutboxUpdateInternal::from(email_outbox_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    schema::{payout_batch::dsl, payout_batch_item::dsl as item_dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id.to_owned()),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn batch_insert(
+        payout_batch_items: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PayoutBatchItem>(conn, payout_batch_items).await?;
+        Ok(())
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn list_by_batch_id(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: Option<storage_enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::batch_id
+                        .eq(batch_id.to_owned())
+                        .and(item_dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(item_dsl::line_number.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    item_dsl::batch_id.eq(batch_id.to_owned()),
+                    limit,
+                    offset,
+                    Some(item_dsl::line_number.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    pub async fn count_by_batch_id_status(
+        conn: &PgPooledConn,
+        batch_id: &str,
+        status: storage_enums::PayoutBatchItemStatus,
+    ) -> StorageResult<usize> {
+        generics::generic_count::<<Self as HasTable>::Table, _>(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(status)),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            item_dsl::id.eq(self.id.to_owned()),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    /// Marks all the items of the batch which are still pending as cancelled, clearing their payout
+    /// requests
+    pub async fn cancel_pending_by_batch_id(
+        conn: &PgPooledConn,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            item_dsl::batch_id
+                .eq(batch_id.to_owned())
+                .and(item_dsl::status.eq(storage_enums::PayoutBatchItemStatus::Pending)),
+            PayoutBatchItemUpdateInternal {
+                status: Some(storage_enums::PayoutBatchItemStatus::Cancelled),
+                payout_status: None,
+                payout_request: Some(None),
+                retry_count: None,
+                error_code: None,
+                error_message: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+    }
+}
@@ -1,12 +1,18 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    PgAnyJsonExpressionMethods, QueryDsl, Table,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
     enums, errors,
     process_tracker::{
-        ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
+        ProcessTracker, ProcessTrackerFilterConstraints, ProcessTrackerNew, ProcessTrackerUpdate,
+        ProcessTrackerUpdateInternal,
     },
     schema::process_tracker::dsl,
     PgPooledConn, StorageResult,
@@ -55,6 +61,76 @@ impl ProcessTracker {
         .await
     }
 
+    /// Updates the processes with the given IDs which are still in one of the given statuses, and
+    /// returns the updated processes.
+    #[instrument(skip(conn))]
+    pub async fn update_processes_by_ids_and_statuses(
+        conn: &PgPooledConn,
+        task_ids: Vec<String>,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        task_update: ProcessTrackerUpdate,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id.eq_any(task_ids).and(dsl::status.eq_any(statuses)),
+            ProcessTrackerUpdateInternal::from(task_update),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn filter_processes_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &ProcessTrackerFilterConstraints,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .order(dsl::schedule_time.desc())
+            .into_boxed();
+
+        if let Some(runner) = constraints.runner {
+            query = query.filter(dsl::runner.eq(runner.to_string()));
+        }
+
+        if let Some(statuses) = &constraints.statuses {
+            query = query.filter(dsl::status.eq_any(statuses.clone()));
+        }
+
+        if let Some(merchant_id) = &constraints.merchant_id {
+            query = query.filter(
+                dsl::tracking_data
+                    .retrieve_as_text("merchant_id")
+                    .eq(merchant_id.get_string_repr().to_owned()),
+            );
+        }
+
+        if let Some(time_range) = constraints.schedule_time_range {
+            query = query.filter(dsl::schedule_time.ge(time_range.start_time));
+            if let Some(end_time) = time_range.end_time {
+                query = query.filter(dsl::schedule_time.le(end_time));
+            }
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+        .attach_printable("Error filtering processes by constraints")
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_process_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Option<Self>> {
         generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
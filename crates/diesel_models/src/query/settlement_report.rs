@@ -0,0 +1,104 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::{recon_result::dsl as result_dsl, settlement_report::dsl},
+    settlement_report::{ReconResult, ReconResultNew, SettlementReport, SettlementReportNew},
+    PgPooledConn, StorageResult,
+};
+
+impl SettlementReportNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementReport {
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
+
+impl ReconResultNew {
+    pub async fn batch_insert(recon_results: Vec<Self>, conn: &PgPooledConn) -> StorageResult<()> {
+        generics::generic_insert::<_, _, ReconResult>(conn, recon_results).await?;
+        Ok(())
+    }
+}
+
+impl ReconResult {
+    /// Lists the results of the merchant, optionally restricted to a single report and status,
+    /// latest report first and in file order within a report
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<&str>,
+        status: Option<storage_enums::ReconResultStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(result_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order((
+                result_dsl::created_at.desc(),
+                result_dsl::report_id.asc(),
+                result_dsl::line_number.asc(),
+            ))
+            .into_boxed();
+
+        if let Some(report_id) = report_id {
+            query = query.filter(result_dsl::report_id.eq(report_id.to_owned()));
+        }
+        if let Some(status) = status {
+            query = query.filter(result_dsl::status.eq(status));
+        }
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering recon results by constraints")
+    }
+}
@@ -0,0 +1,90 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::{subscription_plan::dsl, subscription_plan_price::dsl as price_dsl},
+    subscription_plan::{
+        SubscriptionPlan, SubscriptionPlanNew, SubscriptionPlanPrice, SubscriptionPlanPriceNew,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl SubscriptionPlanNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlan> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlanPriceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SubscriptionPlanPrice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SubscriptionPlan {
+    pub async fn find_by_profile_id_plan_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::id.eq(plan_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_profile_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::profile_id.eq(profile_id.to_owned())),
+            limit,
+            offset,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
+
+impl SubscriptionPlanPrice {
+    pub async fn list_by_profile_id_plan_ids(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            price_dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(price_dsl::plan_id.eq_any(plan_ids)),
+            None,
+            None,
+            Some(price_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_profile_id_price_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        price_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            price_dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(price_dsl::id.eq(price_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -103,6 +103,16 @@ impl_get_primary_key!(
     schema::process_tracker::table,
     schema::invoice::table,
     schema::subscription::table,
+    schema::payout_batch::table,
+    schema::payout_batch_item::table,
+    schema::email_outbox::table,
+    schema::analytics_report::table,
+    schema::settlement_report::table,
+    schema::recon_result::table,
+    schema::analytics_alert_rule::table,
+    schema::analytics_alert_history::table,
+    schema::analytics_export::table,
+    schema::analytics_export_run::table,
     // v2 tables
     schema_v2::dashboard_metadata::table,
     schema_v2::merchant_connector_account::table,
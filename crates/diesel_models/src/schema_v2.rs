@@ -35,6 +35,172 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_alert_history (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        rule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        metric -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method -> Nullable<Varchar>,
+        #[max_length = 255]
+        error_code -> Nullable<Varchar>,
+        observed_value -> Float8,
+        baseline_mean -> Float8,
+        baseline_stddev -> Float8,
+        sample_size -> Int8,
+        window_start -> Timestamp,
+        window_end -> Timestamp,
+        #[max_length = 32]
+        delivery_status -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_alert_rule (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 32]
+        metric -> Varchar,
+        filters -> Jsonb,
+        #[max_length = 32]
+        baseline -> Varchar,
+        evaluation_window_in_mins -> Int4,
+        baseline_periods -> Int4,
+        deviation_threshold -> Float8,
+        #[max_length = 32]
+        direction -> Varchar,
+        min_volume -> Int4,
+        channels -> Jsonb,
+        mute_windows -> Jsonb,
+        is_enabled -> Bool,
+        last_evaluated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_export (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 32]
+        dataset -> Varchar,
+        columns -> Jsonb,
+        filters -> Jsonb,
+        #[max_length = 32]
+        format -> Varchar,
+        #[max_length = 32]
+        frequency -> Varchar,
+        schedule_hour -> Int4,
+        destination -> Bytea,
+        is_enabled -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_export_run (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        export_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        period_start -> Timestamp,
+        period_end -> Timestamp,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 255]
+        file_name -> Nullable<Varchar>,
+        file_size -> Nullable<Int8>,
+        row_count -> Nullable<Int8>,
+        #[max_length = 64]
+        checksum -> Nullable<Varchar>,
+        attempt_count -> Int4,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_report (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 32]
+        organization_id -> Varchar,
+        #[max_length = 32]
+        report_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        auth_info -> Jsonb,
+        start_time -> Timestamp,
+        end_time -> Nullable<Timestamp>,
+        #[max_length = 64]
+        requested_by -> Varchar,
+        notification_emails -> Nullable<Jsonb>,
+        webhook_url -> Nullable<Text>,
+        file_key -> Nullable<Text>,
+        file_size -> Nullable<Int8>,
+        row_count -> Nullable<Int8>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    api_events (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        event -> Jsonb,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -364,6 +530,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_events (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        event -> Jsonb,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -504,6 +684,33 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    email_outbox (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 255]
+        recipient -> Varchar,
+        subject -> Text,
+        body -> Nullable<Bytea>,
+        #[max_length = 64]
+        email_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        theme_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        locale -> Nullable<Varchar>,
+        retry_count -> Int4,
+        last_error -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        sent_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -561,6 +768,8 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        scan_status -> Nullable<Varchar>,
     }
 }
 
@@ -751,7 +960,7 @@ diesel::table! {
         #[max_length = 64]
         profile_id -> Varchar,
         #[max_length = 128]
-        merchant_connector_id -> Varchar,
+        merchant_connector_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_intent_id -> Nullable<Varchar>,
         #[max_length = 64]
@@ -764,7 +973,7 @@ diesel::table! {
         #[max_length = 64]
         status -> Varchar,
         #[max_length = 128]
-        provider_name -> Varchar,
+        provider_name -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         created_at -> Timestamp,
         modified_at -> Timestamp,
@@ -950,6 +1159,20 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    outgoing_webhook_events (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        event -> Jsonb,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1299,6 +1522,57 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        total_count -> Int4,
+        processed_count -> Int4,
+        failed_count -> Int4,
+        cancelled_count -> Int4,
+        metadata -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 64]
+        payout_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        payout_status -> Nullable<PayoutStatus>,
+        payout_request -> Nullable<Bytea>,
+        retry_count -> Int4,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1372,6 +1646,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_result (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 128]
+        connector_transaction_id -> Varchar,
+        #[max_length = 32]
+        transaction_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        expected_amount -> Nullable<Int8>,
+        expected_currency -> Nullable<Currency>,
+        settled_amount -> Int8,
+        settled_currency -> Currency,
+        fee_amount -> Nullable<Int8>,
+        settled_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1533,6 +1841,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_events (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        event -> Jsonb,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_report (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        #[max_length = 255]
+        file_key -> Nullable<Varchar>,
+        total_count -> Int4,
+        matched_count -> Int4,
+        mismatched_count -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1567,6 +1911,54 @@ diesel::table! {
         plan_id -> Nullable<Varchar>,
         #[max_length = 128]
         item_price_id -> Nullable<Varchar>,
+        current_period_start -> Nullable<Timestamp>,
+        current_period_end -> Nullable<Timestamp>,
+        trial_end -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        name -> Varchar,
+        description -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    subscription_plan_price (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        plan_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 16]
+        interval -> Varchar,
+        interval_count -> Int8,
+        trial_period -> Nullable<Int8>,
+        #[max_length = 16]
+        trial_period_unit -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1737,6 +2129,12 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    analytics_alert_history,
+    analytics_alert_rule,
+    analytics_export,
+    analytics_export_run,
+    analytics_report,
+    api_events,
     api_keys,
     authentication,
     blocklist,
@@ -1747,10 +2145,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    connector_events,
     customers,
     dashboard_metadata,
     dispute,
     dynamic_routing_stats,
+    email_outbox,
     events,
     file_metadata,
     fraud_check,
@@ -1766,19 +2166,27 @@ diesel::allow_tables_to_appear_in_same_query!(
     merchant_connector_account,
     merchant_key_store,
     organization,
+    outgoing_webhook_events,
     payment_attempt,
     payment_intent,
     payment_link,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
+    recon_result,
     refund,
     relay,
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_events,
+    settlement_report,
     subscription,
+    subscription_plan,
+    subscription_plan_price,
     themes,
     tokenization,
     unified_translations,
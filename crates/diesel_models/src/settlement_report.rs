@@ -0,0 +1,84 @@
+use common_utils::types::MinorUnit;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    enums as storage_enums,
+    schema::{recon_result, settlement_report},
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = settlement_report, check_for_backend(diesel::pg::Pg))]
+pub struct SettlementReportNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub file_id: Option<String>,
+    pub file_key: Option<String>,
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = settlement_report, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct SettlementReport {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    pub file_id: Option<String>,
+    pub file_key: Option<String>,
+    pub total_count: i32,
+    pub matched_count: i32,
+    pub mismatched_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = recon_result, check_for_backend(diesel::pg::Pg))]
+pub struct ReconResultNew {
+    pub id: String,
+    pub report_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub connector_transaction_id: String,
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    pub status: storage_enums::ReconResultStatus,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub expected_amount: Option<MinorUnit>,
+    pub expected_currency: Option<storage_enums::Currency>,
+    pub settled_amount: MinorUnit,
+    pub settled_currency: storage_enums::Currency,
+    pub fee_amount: Option<MinorUnit>,
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = recon_result, primary_key(id), check_for_backend(diesel::pg::Pg))]
+pub struct ReconResult {
+    pub id: String,
+    pub report_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub line_number: i32,
+    pub connector_transaction_id: String,
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    pub status: storage_enums::ReconResultStatus,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub expected_amount: Option<MinorUnit>,
+    pub expected_currency: Option<storage_enums::Currency>,
+    pub settled_amount: MinorUnit,
+    pub settled_currency: storage_enums::Currency,
+    pub fee_amount: Option<MinorUnit>,
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+}
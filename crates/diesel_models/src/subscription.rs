@@ -24,6 +24,9 @@ pub struct SubscriptionNew {
     merchant_reference_id: Option<String>,
     plan_id: Option<String>,
     item_price_id: Option<String>,
+    current_period_start: Option<time::PrimitiveDateTime>,
+    current_period_end: Option<time::PrimitiveDateTime>,
+    trial_end: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(
@@ -47,6 +50,9 @@ pub struct Subscription {
     pub merchant_reference_id: Option<String>,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub current_period_start: Option<time::PrimitiveDateTime>,
+    pub current_period_end: Option<time::PrimitiveDateTime>,
+    pub trial_end: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, router_derive::DebugAsDisplay, Deserialize)]
@@ -58,6 +64,9 @@ pub struct SubscriptionUpdate {
     pub modified_at: time::PrimitiveDateTime,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub current_period_start: Option<time::PrimitiveDateTime>,
+    pub current_period_end: Option<time::PrimitiveDateTime>,
+    pub trial_end: Option<time::PrimitiveDateTime>,
 }
 
 impl SubscriptionNew {
@@ -96,6 +105,9 @@ impl SubscriptionNew {
             merchant_reference_id,
             plan_id,
             item_price_id,
+            current_period_start: None,
+            current_period_end: None,
+            trial_end: None,
         }
     }
 
@@ -122,6 +134,9 @@ impl SubscriptionUpdate {
             modified_at: common_utils::date_time::now(),
             plan_id,
             item_price_id,
+            current_period_start: None,
+            current_period_end: None,
+            trial_end: None,
         }
     }
 }
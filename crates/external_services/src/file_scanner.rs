@@ -0,0 +1,106 @@
+//! Module for scanning uploaded files for malware, with support for multiple scanner backends.
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use common_utils::errors::CustomResult;
+
+/// Includes functionality for scanning files with a ClamAV daemon.
+mod clamav;
+
+mod no_scanner;
+
+/// Enum representing different file scanner configurations.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(tag = "file_scanner_backend")]
+#[serde(rename_all = "snake_case")]
+pub enum FileScannerConfig {
+    /// ClamAV daemon reached over a TCP socket.
+    Clamav {
+        /// Configuration for the ClamAV daemon.
+        clamav: clamav::ClamavConfig,
+    },
+    /// Files are not scanned.
+    #[default]
+    NoScanner,
+}
+
+impl FileScannerConfig {
+    /// Validates the file scanner configuration.
+    pub fn validate(&self) -> Result<(), InvalidFileScannerConfig> {
+        match self {
+            Self::Clamav { clamav } => clamav.validate(),
+            Self::NoScanner => Ok(()),
+        }
+    }
+
+    /// Retrieves the appropriate file scanner client based on the file scanner configuration.
+    pub fn get_file_scanner_client(&self) -> Arc<dyn FileScannerInterface> {
+        match self {
+            Self::Clamav { clamav } => Arc::new(clamav::ClamavClient::new(clamav.clone())),
+            Self::NoScanner => Arc::new(no_scanner::NoScanner),
+        }
+    }
+
+    /// Whether uploaded files have to be scanned before they are made available.
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, Self::NoScanner)
+    }
+}
+
+/// Result of scanning a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileScanResult {
+    /// No threat was found in the file.
+    Clean,
+    /// A threat was found in the file.
+    Infected {
+        /// Name of the signature that matched the file.
+        signature: String,
+    },
+    /// The file was not scanned, as no scanner is configured.
+    NotScanned,
+}
+
+/// Trait for file scanning operations
+#[async_trait::async_trait]
+pub trait FileScannerInterface: dyn_clone::DynClone + Sync + Send {
+    /// Scans the provided file content for malware.
+    async fn scan_file(&self, file: &[u8]) -> CustomResult<FileScanResult, FileScannerError>;
+}
+
+dyn_clone::clone_trait_object!(FileScannerInterface);
+
+/// Error thrown when the file scanner config is invalid
+#[derive(Debug, Clone)]
+pub struct InvalidFileScannerConfig(&'static str);
+
+impl std::error::Error for InvalidFileScannerConfig {}
+
+impl Display for InvalidFileScannerConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "file_scanner: {}", self.0)
+    }
+}
+
+/// Represents errors that can occur during file scanning operations.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum FileScannerError {
+    /// Indicates that the scanner could not be reached.
+    #[error("Failed to connect to the file scanner")]
+    ConnectionFailed,
+
+    /// Indicates that the scanner did not respond in time.
+    #[error("File scanner timed out")]
+    Timeout,
+
+    /// Indicates that the scanner could not scan the file.
+    #[error("File scan failed")]
+    ScanFailed,
+
+    /// Indicates that the scanner response could not be understood.
+    #[error("Unexpected response from the file scanner")]
+    UnexpectedResponse,
+}
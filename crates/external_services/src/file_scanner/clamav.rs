@@ -0,0 +1,198 @@
+//! Module for scanning files with a ClamAV daemon, using the `INSTREAM` command of its socket
+//! protocol
+
+use std::time::Duration;
+
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
+use error_stack::{report, ResultExt};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::file_scanner::{
+    FileScanResult, FileScannerError, FileScannerInterface, InvalidFileScannerConfig,
+};
+
+/// Configuration for the ClamAV daemon.
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct ClamavConfig {
+    /// Host the ClamAV daemon listens on
+    host: String,
+    /// TCP port the ClamAV daemon listens on
+    port: u16,
+    /// Time allowed for connecting, streaming the file and receiving the verdict
+    timeout_in_secs: u64,
+    /// Size of the chunks the file is streamed in, must not exceed `StreamMaxLength` of the daemon
+    chunk_size_in_bytes: usize,
+}
+
+impl Default for ClamavConfig {
+    fn default() -> Self {
+        Self {
+            host: "localhost".to_string(),
+            port: 3310,
+            timeout_in_secs: 30,
+            chunk_size_in_bytes: 64 * 1024,
+        }
+    }
+}
+
+impl ClamavConfig {
+    /// Validates the ClamAV configuration.
+    pub(super) fn validate(&self) -> Result<(), InvalidFileScannerConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.host.is_default_or_empty(), || {
+            Err(InvalidFileScannerConfig("clamav host must not be empty"))
+        })?;
+
+        when(self.timeout_in_secs == 0, || {
+            Err(InvalidFileScannerConfig(
+                "clamav timeout must be greater than zero",
+            ))
+        })?;
+
+        when(self.chunk_size_in_bytes == 0, || {
+            Err(InvalidFileScannerConfig(
+                "clamav chunk size must be greater than zero",
+            ))
+        })
+    }
+}
+
+/// ClamAV file scanner client.
+#[derive(Debug, Clone)]
+pub(super) struct ClamavClient {
+    config: ClamavConfig,
+}
+
+impl ClamavClient {
+    /// Creates a new ClamAV client, connections are opened per scan.
+    pub(super) fn new(config: ClamavConfig) -> Self {
+        Self { config }
+    }
+
+    /// Streams the file to the daemon and returns its raw verdict.
+    async fn stream_file(&self, file: &[u8]) -> CustomResult<Vec<u8>, ClamavError> {
+        let mut stream = TcpStream::connect((self.config.host.as_str(), self.config.port))
+            .await
+            .change_context(ClamavError::ConnectionFailed)?;
+
+        // The `z` prefix makes the daemon expect a null terminated command and reply likewise
+        stream
+            .write_all(b"zINSTREAM\0")
+            .await
+            .change_context(ClamavError::WriteFailed)?;
+        for chunk in file.chunks(self.config.chunk_size_in_bytes) {
+            let chunk_length =
+                u32::try_from(chunk.len()).change_context(ClamavError::WriteFailed)?;
+            stream
+                .write_all(&chunk_length.to_be_bytes())
+                .await
+                .change_context(ClamavError::WriteFailed)?;
+            stream
+                .write_all(chunk)
+                .await
+                .change_context(ClamavError::WriteFailed)?;
+        }
+        // A zero length chunk marks the end of the stream
+        stream
+            .write_all(&0u32.to_be_bytes())
+            .await
+            .change_context(ClamavError::WriteFailed)?;
+
+        let mut response = Vec::new();
+        stream
+            .read_to_end(&mut response)
+            .await
+            .change_context(ClamavError::ReadFailed)?;
+        Ok(response)
+    }
+}
+
+/// Parses the verdict of the daemon, which looks like `stream: OK`,
+/// `stream: <signature> FOUND` or `<reason> ERROR`
+fn parse_scan_response(response: &[u8]) -> CustomResult<FileScanResult, FileScannerError> {
+    let response = String::from_utf8_lossy(response);
+    let response = response.trim_end_matches(['\0', '\n', '\r', ' ']);
+
+    if let Some(signature) = response
+        .strip_prefix("stream: ")
+        .and_then(|verdict| verdict.strip_suffix(" FOUND"))
+    {
+        Ok(FileScanResult::Infected {
+            signature: signature.to_string(),
+        })
+    } else if response == "stream: OK" {
+        Ok(FileScanResult::Clean)
+    } else if response.ends_with("ERROR") {
+        Err(report!(FileScannerError::ScanFailed))
+            .attach_printable(format!("ClamAV could not scan the file: {response}"))
+    } else {
+        Err(report!(FileScannerError::UnexpectedResponse))
+            .attach_printable(format!("Unexpected ClamAV response: {response}"))
+    }
+}
+
+#[async_trait::async_trait]
+impl FileScannerInterface for ClamavClient {
+    async fn scan_file(&self, file: &[u8]) -> CustomResult<FileScanResult, FileScannerError> {
+        let response = tokio::time::timeout(
+            Duration::from_secs(self.config.timeout_in_secs),
+            self.stream_file(file),
+        )
+        .await
+        .map_err(|_| report!(FileScannerError::Timeout))?
+        .change_context(FileScannerError::ConnectionFailed)?;
+
+        parse_scan_response(&response)
+    }
+}
+
+/// Represents an error that can occur while talking to the ClamAV daemon.
+#[derive(Debug, thiserror::Error)]
+enum ClamavError {
+    /// Error indicating the daemon could not be reached.
+    #[error("Failed to connect to the ClamAV daemon")]
+    ConnectionFailed,
+
+    /// Error indicating the file could not be streamed to the daemon.
+    #[error("Failed to stream the file to the ClamAV daemon")]
+    WriteFailed,
+
+    /// Error indicating the verdict could not be read.
+    #[error("Failed to read the response of the ClamAV daemon")]
+    ReadFailed,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_clean_response() {
+        assert_eq!(
+            parse_scan_response(b"stream: OK\0").unwrap(),
+            FileScanResult::Clean
+        );
+    }
+
+    #[test]
+    fn test_parse_infected_response() {
+        assert_eq!(
+            parse_scan_response(b"stream: Eicar-Test-Signature FOUND\0").unwrap(),
+            FileScanResult::Infected {
+                signature: "Eicar-Test-Signature".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_error_response() {
+        let error = parse_scan_response(b"INSTREAM size limit exceeded. ERROR\0").unwrap_err();
+        assert_eq!(error.current_context(), &FileScannerError::ScanFailed);
+    }
+}
@@ -0,0 +1,16 @@
+//! Module for deployments without a file scanner
+
+use common_utils::errors::CustomResult;
+
+use crate::file_scanner::{FileScanResult, FileScannerError, FileScannerInterface};
+
+/// File scanner that lets every file through without scanning it.
+#[derive(Debug, Clone)]
+pub(super) struct NoScanner;
+
+#[async_trait::async_trait]
+impl FileScannerInterface for NoScanner {
+    async fn scan_file(&self, _file: &[u8]) -> CustomResult<FileScanResult, FileScannerError> {
+        Ok(FileScanResult::NotScanned)
+    }
+}
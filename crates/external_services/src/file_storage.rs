@@ -3,14 +3,32 @@
 use std::{
     fmt::{Display, Formatter},
     sync::Arc,
+    time::Duration,
 };
 
 use common_utils::errors::CustomResult;
+use hyperswitch_interfaces::secrets_interface::{
+    secret_handler::SecretsHandler,
+    secret_state::{RawSecret, SecretStateContainer, SecuredSecret},
+    SecretManagementInterface, SecretsManagementError,
+};
 
 /// Includes functionality for AWS S3 storage operations.
 #[cfg(feature = "aws_s3")]
 mod aws_s3;
 
+/// Includes functionality for S3 compatible storage operations, such as MinIO or Ceph.
+#[cfg(feature = "aws_s3")]
+mod s3_compatible;
+
+/// Includes functionality for Google Cloud Storage operations.
+#[cfg(feature = "gcs")]
+mod gcs;
+
+/// Includes functionality for Azure Blob Storage operations.
+#[cfg(feature = "azure_blob")]
+mod azure_blob;
+
 mod file_system;
 
 /// Enum representing different file storage configurations, allowing for multiple storage schemes.
@@ -24,6 +42,24 @@ pub enum FileStorageConfig {
         /// Configuration for AWS S3 file storage.
         aws_s3: aws_s3::AwsFileStorageConfig,
     },
+    /// S3 compatible storage configuration, for object stores with a custom endpoint.
+    #[cfg(feature = "aws_s3")]
+    S3Compatible {
+        /// Configuration for S3 compatible file storage.
+        s3_compatible: s3_compatible::S3CompatibleFileStorageConfig,
+    },
+    /// Google Cloud Storage configuration.
+    #[cfg(feature = "gcs")]
+    Gcs {
+        /// Configuration for Google Cloud Storage.
+        gcs: gcs::GcsFileStorageConfig,
+    },
+    /// Azure Blob Storage configuration.
+    #[cfg(feature = "azure_blob")]
+    AzureBlob {
+        /// Configuration for Azure Blob Storage.
+        azure_blob: azure_blob::AzureBlobFileStorageConfig,
+    },
     /// Local file system storage configuration.
     #[default]
     FileSystem,
@@ -35,6 +71,12 @@ impl FileStorageConfig {
         match self {
             #[cfg(feature = "aws_s3")]
             Self::AwsS3 { aws_s3 } => aws_s3.validate(),
+            #[cfg(feature = "aws_s3")]
+            Self::S3Compatible { s3_compatible } => s3_compatible.validate(),
+            #[cfg(feature = "gcs")]
+            Self::Gcs { gcs } => gcs.validate(),
+            #[cfg(feature = "azure_blob")]
+            Self::AzureBlob { azure_blob } => azure_blob.validate(),
             Self::FileSystem => Ok(()),
         }
     }
@@ -44,11 +86,50 @@ impl FileStorageConfig {
         match self {
             #[cfg(feature = "aws_s3")]
             Self::AwsS3 { aws_s3 } => Arc::new(aws_s3::AwsFileStorageClient::new(aws_s3).await),
+            #[cfg(feature = "aws_s3")]
+            Self::S3Compatible { s3_compatible } => {
+                Arc::new(s3_compatible::new_s3_compatible_client(s3_compatible))
+            }
+            #[cfg(feature = "gcs")]
+            Self::Gcs { gcs } => Arc::new(gcs::GcsFileStorageClient::new(gcs)),
+            #[cfg(feature = "azure_blob")]
+            Self::AzureBlob { azure_blob } => {
+                Arc::new(azure_blob::AzureBlobFileStorageClient::new(azure_blob))
+            }
             Self::FileSystem => Arc::new(file_system::FileSystem),
         }
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for FileStorageConfig {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let file_storage_config = match value.get_inner().clone() {
+            #[cfg(feature = "aws_s3")]
+            Self::S3Compatible { s3_compatible } => Self::S3Compatible {
+                s3_compatible: s3_compatible
+                    .decrypt_secrets(secret_management_client)
+                    .await?,
+            },
+            #[cfg(feature = "gcs")]
+            Self::Gcs { gcs } => Self::Gcs {
+                gcs: gcs.decrypt_secrets(secret_management_client).await?,
+            },
+            #[cfg(feature = "azure_blob")]
+            Self::AzureBlob { azure_blob } => Self::AzureBlob {
+                azure_blob: azure_blob.decrypt_secrets(secret_management_client).await?,
+            },
+            // AWS S3 uses the credentials of the environment, the file system has none
+            file_storage_config => file_storage_config,
+        };
+
+        Ok(value.transition_state(|_| file_storage_config))
+    }
+}
+
 /// Trait for file storage operations
 #[async_trait::async_trait]
 pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
@@ -64,10 +145,75 @@ pub trait FileStorageInterface: dyn_clone::DynClone + Sync + Send {
 
     /// Retrieves a file from the selected storage scheme.
     async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError>;
+
+    /// Starts the upload of a file which is written in parts, so that large files do not have to
+    /// be held in memory. The file is only available under the file key once the upload is
+    /// completed.
+    async fn start_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn FileUpload>, FileStorageError>;
+
+    /// Generates a URL that allows downloading the file without further authentication, until
+    /// it expires.
+    async fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, FileStorageError>;
 }
 
 dyn_clone::clone_trait_object!(FileStorageInterface);
 
+/// An upload of a file in progress, started by [`FileStorageInterface::start_upload`]
+#[async_trait::async_trait]
+pub trait FileUpload: Send {
+    /// Appends a part to the file being uploaded.
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError>;
+
+    /// Completes the upload, making the file available under its file key.
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError>;
+
+    /// Aborts the upload, discarding the parts written so far.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError>;
+}
+
+/// Size of the parts sent to object stores. It is above the 5 MiB minimum part size of S3 and a
+/// multiple of the 256 KiB chunk size of Google Cloud Storage resumable uploads.
+#[cfg(any(feature = "aws_s3", feature = "gcs", feature = "azure_blob"))]
+const UPLOAD_PART_SIZE: usize = 8 * 1024 * 1024;
+
+/// Collects the parts written to an upload, until they add up to parts of the size sent to the
+/// object store.
+#[cfg(any(feature = "aws_s3", feature = "gcs", feature = "azure_blob"))]
+#[derive(Debug, Default)]
+struct PartBuffer {
+    buffer: Vec<u8>,
+}
+
+#[cfg(any(feature = "aws_s3", feature = "gcs", feature = "azure_blob"))]
+impl PartBuffer {
+    /// Appends a written part to the buffer.
+    fn push(&mut self, part: Vec<u8>) {
+        self.buffer.extend(part);
+    }
+
+    /// Takes the largest multiple of the upload part size off the buffer, if it holds a full
+    /// part.
+    fn take_full_part(&mut self) -> Option<Vec<u8>> {
+        let part_length = self.buffer.len() / UPLOAD_PART_SIZE * UPLOAD_PART_SIZE;
+        (part_length > 0).then(|| {
+            let remaining = self.buffer.split_off(part_length);
+            std::mem::replace(&mut self.buffer, remaining)
+        })
+    }
+
+    /// Takes the rest of the buffer, which is sent as the last part of the upload.
+    fn take_last_part(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.buffer)
+    }
+}
+
 /// Error thrown when the file storage config is invalid
 #[derive(Debug, Clone)]
 pub struct InvalidFileStorageConfig(&'static str);
@@ -94,4 +240,91 @@ pub enum FileStorageError {
     /// Indicates that the file deletion operation failed.
     #[error("Failed to delete file")]
     DeleteFailed,
+
+    /// Indicates that completing or aborting an upload of a file in parts failed.
+    #[error("Failed to finish the upload of the file")]
+    UploadFinishFailed,
+
+    /// Indicates that generating a presigned URL failed.
+    #[error("Failed to generate presigned URL")]
+    PresignedUrlFailed,
+
+    /// Indicates that the storage scheme does not support presigned URLs.
+    #[error("Presigned URLs are not supported by the file storage backend")]
+    PresignedUrlNotSupported,
+}
+
+/// Percent encodes a value as required by the request signing schemes of the object stores,
+/// which only leave the unreserved characters of RFC 3986 as is.
+#[cfg(any(feature = "gcs", feature = "azure_blob"))]
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    value
+        .bytes()
+        .fold(String::with_capacity(value.len()), |mut encoded, byte| {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    encoded.push(char::from(byte))
+                }
+                b'/' if !encode_slash => encoded.push('/'),
+                _ => encoded.push_str(&format!("%{byte:02X}")),
+            }
+            encoded
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use hyperswitch_interfaces::secrets_interface::{
+        secret_handler::SecretsHandler, secret_state::SecretStateContainer,
+        SecretManagementInterface, SecretsManagementError,
+    };
+    use masking::{PeekInterface, Secret};
+
+    use super::{CustomResult, FileStorageConfig};
+
+    /// Secrets manager that "decrypts" secrets by prefixing them, so that tests can tell
+    /// decrypted values apart from the configured ones.
+    pub(super) struct PrefixSecretManager;
+
+    #[async_trait::async_trait]
+    impl SecretManagementInterface for PrefixSecretManager {
+        async fn get_secret(
+            &self,
+            input: Secret<String>,
+        ) -> CustomResult<Secret<String>, SecretsManagementError> {
+            Ok(Secret::new(format!("decrypted_{}", input.peek())))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_file_system_config_has_no_secrets() {
+        let config = FileStorageConfig::convert_to_raw_secret(
+            SecretStateContainer::default(),
+            &PrefixSecretManager,
+        )
+        .await
+        .unwrap();
+
+        assert!(matches!(config.get_inner(), FileStorageConfig::FileSystem));
+    }
+
+    #[cfg(any(feature = "aws_s3", feature = "gcs", feature = "azure_blob"))]
+    #[test]
+    fn test_part_buffer_sends_multiples_of_part_size() {
+        use super::{PartBuffer, UPLOAD_PART_SIZE};
+
+        let mut buffer = PartBuffer::default();
+        buffer.push(vec![0; UPLOAD_PART_SIZE - 1]);
+        assert!(buffer.take_full_part().is_none());
+
+        buffer.push(vec![1; UPLOAD_PART_SIZE + 2]);
+        let part = buffer.take_full_part().unwrap();
+        assert_eq!(part.len(), 2 * UPLOAD_PART_SIZE);
+        assert_eq!(part.last(), Some(&1));
+        assert!(buffer.take_full_part().is_none());
+
+        assert_eq!(buffer.take_last_part(), vec![1]);
+        assert!(buffer.take_last_part().is_empty());
+    }
 }
@@ -1,16 +1,24 @@
+use std::time::Duration;
+
 use aws_config::meta::region::RegionProviderChain;
 use aws_sdk_s3::{
     operation::{
-        delete_object::DeleteObjectError, get_object::GetObjectError, put_object::PutObjectError,
+        abort_multipart_upload::AbortMultipartUploadError,
+        complete_multipart_upload::CompleteMultipartUploadError,
+        create_multipart_upload::CreateMultipartUploadError, delete_object::DeleteObjectError,
+        get_object::GetObjectError, put_object::PutObjectError, upload_part::UploadPartError,
     },
+    presigning::{PresigningConfig, PresigningConfigError},
+    types::{CompletedMultipartUpload, CompletedPart},
     Client,
 };
 use aws_sdk_sts::config::Region;
 use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 
 use super::InvalidFileStorageConfig;
-use crate::file_storage::{FileStorageError, FileStorageInterface};
+use super::PartBuffer;
+use crate::file_storage::{FileStorageError, FileStorageInterface, FileUpload};
 
 /// Configuration for AWS S3 file storage.
 #[derive(Debug, serde::Deserialize, Clone, Default)]
@@ -53,9 +61,14 @@ impl AwsFileStorageClient {
     pub(super) async fn new(config: &AwsFileStorageConfig) -> Self {
         let region_provider = RegionProviderChain::first_try(Region::new(config.region.clone()));
         let sdk_config = aws_config::from_env().region(region_provider).load().await;
+        Self::from_client(Client::new(&sdk_config), config.bucket_name.clone())
+    }
+
+    /// Creates a new file storage client from an already configured S3 client.
+    pub(super) fn from_client(inner_client: Client, bucket_name: String) -> Self {
         Self {
-            inner_client: Client::new(&sdk_config),
-            bucket_name: config.bucket_name.clone(),
+            inner_client,
+            bucket_name,
         }
     }
 
@@ -104,6 +117,157 @@ impl AwsFileStorageClient {
             .map_err(AwsS3StorageError::UnknownError)?
             .to_vec())
     }
+
+    /// Starts a multipart upload of a file to AWS S3.
+    async fn start_upload(&self, file_key: &str) -> CustomResult<AwsS3Upload, AwsS3StorageError> {
+        let upload_id = self
+            .inner_client
+            .create_multipart_upload()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CreateMultipartUploadFailure)?
+            .upload_id
+            .ok_or(AwsS3StorageError::MissingUploadId)?;
+
+        Ok(AwsS3Upload {
+            client: self.clone(),
+            file_key: file_key.to_string(),
+            upload_id,
+            completed_parts: Vec::new(),
+            buffer: PartBuffer::default(),
+        })
+    }
+
+    /// Generates a presigned URL for downloading a file from AWS S3.
+    async fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, AwsS3StorageError> {
+        let presigning_config = PresigningConfig::expires_in(expires_in)
+            .map_err(AwsS3StorageError::PresigningConfigFailure)?;
+        Ok(self
+            .inner_client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(file_key)
+            .presigned(presigning_config)
+            .await
+            .map_err(AwsS3StorageError::PresignFailure)?
+            .uri()
+            .to_string())
+    }
+}
+
+/// Multipart upload of a file to AWS S3
+#[derive(Debug)]
+pub(super) struct AwsS3Upload {
+    client: AwsFileStorageClient,
+    file_key: String,
+    upload_id: String,
+    completed_parts: Vec<CompletedPart>,
+    buffer: PartBuffer,
+}
+
+impl AwsS3Upload {
+    /// Uploads the next part of the file.
+    async fn upload_part(&mut self, part: Vec<u8>) -> CustomResult<(), AwsS3StorageError> {
+        let part_number = i32::try_from(self.completed_parts.len() + 1)
+            .map_err(|_| report!(AwsS3StorageError::TooManyParts))?;
+        let e_tag = self
+            .client
+            .inner_client
+            .upload_part()
+            .bucket(&self.client.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .part_number(part_number)
+            .body(part.into())
+            .send()
+            .await
+            .map_err(AwsS3StorageError::UploadPartFailure)?
+            .e_tag;
+
+        self.completed_parts.push(
+            CompletedPart::builder()
+                .set_e_tag(e_tag)
+                .part_number(part_number)
+                .build(),
+        );
+        Ok(())
+    }
+
+    /// Uploads the remaining part of the file and completes the multipart upload. The last part
+    /// may be smaller than the minimum part size, and is uploaded even when empty since a
+    /// multipart upload needs at least one part.
+    async fn complete(mut self) -> CustomResult<(), AwsS3StorageError> {
+        let last_part = self.buffer.take_last_part();
+        if !last_part.is_empty() || self.completed_parts.is_empty() {
+            self.upload_part(last_part).await?;
+        }
+
+        self.client
+            .inner_client
+            .complete_multipart_upload()
+            .bucket(&self.client.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .multipart_upload(
+                CompletedMultipartUpload::builder()
+                    .set_parts(Some(self.completed_parts))
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(AwsS3StorageError::CompleteMultipartUploadFailure)?;
+        Ok(())
+    }
+
+    /// Aborts the multipart upload, discarding the uploaded parts.
+    async fn abort(self) -> CustomResult<(), AwsS3StorageError> {
+        self.client
+            .inner_client
+            .abort_multipart_upload()
+            .bucket(&self.client.bucket_name)
+            .key(&self.file_key)
+            .upload_id(&self.upload_id)
+            .send()
+            .await
+            .map_err(AwsS3StorageError::AbortMultipartUploadFailure)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FileUpload for AwsS3Upload {
+    /// Uploads a part to AWS S3 whenever enough data has been written for one.
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.buffer.push(part);
+        if let Some(part) = self.buffer.take_full_part() {
+            self.upload_part(part)
+                .await
+                .change_context(FileStorageError::UploadFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Completes the multipart upload to AWS S3.
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        Ok((*self)
+            .complete()
+            .await
+            .change_context(FileStorageError::UploadFinishFailed)?)
+    }
+
+    /// Aborts the multipart upload to AWS S3.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        Ok((*self)
+            .abort()
+            .await
+            .change_context(FileStorageError::UploadFinishFailed)?)
+    }
 }
 
 #[async_trait::async_trait]
@@ -135,6 +299,30 @@ impl FileStorageInterface for AwsFileStorageClient {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Starts a multipart upload of a file to AWS S3.
+    async fn start_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn FileUpload>, FileStorageError> {
+        Ok(Box::new(
+            self.start_upload(file_key)
+                .await
+                .change_context(FileStorageError::UploadFailed)?,
+        ))
+    }
+
+    /// Generates a presigned URL for downloading a file from AWS S3.
+    async fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, FileStorageError> {
+        Ok(self
+            .generate_presigned_url(file_key, expires_in)
+            .await
+            .change_context(FileStorageError::PresignedUrlFailed)?)
+    }
 }
 
 /// Enum representing errors that can occur during AWS S3 file storage operations.
@@ -152,6 +340,38 @@ enum AwsS3StorageError {
     #[error("File delete from S3 failed: {0:?}")]
     DeleteFailure(aws_sdk_s3::error::SdkError<DeleteObjectError>),
 
+    /// Error indicating that starting a multipart upload to S3 failed.
+    #[error("Starting multipart upload to S3 failed: {0:?}")]
+    CreateMultipartUploadFailure(aws_sdk_s3::error::SdkError<CreateMultipartUploadError>),
+
+    /// Error indicating that S3 did not return the ID of a started multipart upload.
+    #[error("S3 did not return an upload ID for the multipart upload")]
+    MissingUploadId,
+
+    /// Error indicating that a multipart upload exceeded the number of parts S3 accepts.
+    #[error("Multipart upload to S3 has too many parts")]
+    TooManyParts,
+
+    /// Error indicating that uploading a part to S3 failed.
+    #[error("Uploading part to S3 failed: {0:?}")]
+    UploadPartFailure(aws_sdk_s3::error::SdkError<UploadPartError>),
+
+    /// Error indicating that completing a multipart upload to S3 failed.
+    #[error("Completing multipart upload to S3 failed: {0:?}")]
+    CompleteMultipartUploadFailure(aws_sdk_s3::error::SdkError<CompleteMultipartUploadError>),
+
+    /// Error indicating that aborting a multipart upload to S3 failed.
+    #[error("Aborting multipart upload to S3 failed: {0:?}")]
+    AbortMultipartUploadFailure(aws_sdk_s3::error::SdkError<AbortMultipartUploadError>),
+
+    /// Error indicating that the presigning configuration is invalid.
+    #[error("Invalid presigning configuration: {0:?}")]
+    PresigningConfigFailure(PresigningConfigError),
+
+    /// Error indicating that generating a presigned URL failed.
+    #[error("Presigning S3 request failed: {0:?}")]
+    PresignFailure(aws_sdk_s3::error::SdkError<GetObjectError>),
+
     /// Unknown error occurred.
     #[error("Unknown error occurred: {0:?}")]
     UnknownError(aws_sdk_s3::primitives::ByteStreamError),
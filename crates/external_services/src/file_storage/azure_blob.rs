@@ -0,0 +1,565 @@
+//! Module for Azure Blob Storage operations, using the REST API with shared key authorization
+
+use std::time::Duration;
+
+use base64::Engine;
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    errors::CustomResult,
+    ext_traits::ConfigExt,
+};
+use error_stack::ResultExt;
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
+use masking::{PeekInterface, Secret};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use super::{uri_encode, InvalidFileStorageConfig, PartBuffer};
+use crate::{
+    consts::BASE64_ENGINE,
+    file_storage::{FileStorageError, FileStorageInterface, FileUpload},
+};
+
+/// Version of the Blob Storage REST API the requests and shared access signatures are made for
+const AZURE_STORAGE_API_VERSION: &str = "2021-08-06";
+
+/// Configuration for Azure Blob Storage.
+#[derive(Debug, serde::Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AzureBlobFileStorageConfig {
+    /// The storage account holding the container
+    account_name: String,
+    /// Base64 encoded access key of the storage account
+    account_key: Secret<String>,
+    /// The container to send file uploads
+    container_name: String,
+    /// Endpoint of the blob service, defaults to `https://<account_name>.blob.core.windows.net`.
+    /// Set this for sovereign clouds or a local Azurite emulator.
+    endpoint: Option<String>,
+}
+
+impl AzureBlobFileStorageConfig {
+    /// Validates the Azure Blob Storage configuration.
+    pub(super) fn validate(&self) -> Result<(), InvalidFileStorageConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.account_name.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "azure blob account name must not be empty",
+            ))
+        })?;
+
+        when(self.container_name.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "azure blob container name must not be empty",
+            ))
+        })?;
+
+        when(
+            BASE64_ENGINE.decode(self.account_key.peek()).is_err(),
+            || {
+                Err(InvalidFileStorageConfig(
+                    "azure blob account key must be a valid base64 string",
+                ))
+            },
+        )
+    }
+
+    /// Decrypts the storage account key using the secrets manager.
+    pub(super) async fn decrypt_secrets(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let account_key = secret_management_client
+            .get_secret(self.account_key.clone())
+            .await?;
+
+        Ok(Self {
+            account_key,
+            ..self
+        })
+    }
+}
+
+/// Azure Blob Storage file storage client.
+#[derive(Debug, Clone)]
+pub(super) struct AzureBlobFileStorageClient {
+    http_client: reqwest::Client,
+    account_name: String,
+    account_key: Secret<Vec<u8>>,
+    container_name: String,
+    endpoint: String,
+}
+
+impl AzureBlobFileStorageClient {
+    /// Creates a new Azure Blob Storage client.
+    pub(super) fn new(config: &AzureBlobFileStorageConfig) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            account_name: config.account_name.clone(),
+            // The key has been checked to be valid base64 while validating the configuration
+            account_key: Secret::new(
+                BASE64_ENGINE
+                    .decode(config.account_key.peek())
+                    .unwrap_or_default(),
+            ),
+            container_name: config.container_name.clone(),
+            endpoint: config.endpoint.clone().unwrap_or_else(|| {
+                format!("https://{}.blob.core.windows.net", config.account_name)
+            }),
+        }
+    }
+
+    /// Path of the blob relative to the account, as it appears in request URLs
+    fn get_blob_path(&self, file_key: &str) -> String {
+        format!("{}/{}", self.container_name, uri_encode(file_key, false))
+    }
+
+    fn sign(&self, string_to_sign: &str) -> CustomResult<String, AzureBlobStorageError> {
+        let signature = HmacSha256
+            .sign_message(self.account_key.peek(), string_to_sign.as_bytes())
+            .change_context(AzureBlobStorageError::SigningFailure)?;
+        Ok(BASE64_ENGINE.encode(signature))
+    }
+
+    /// Builds a request carrying a shared key `Authorization` header. Query parameters are
+    /// part of the signed resource, requests without any upload the whole blob.
+    fn build_request(
+        &self,
+        method: reqwest::Method,
+        file_key: &str,
+        query: &[(&str, String)],
+        body: Option<Vec<u8>>,
+    ) -> CustomResult<reqwest::RequestBuilder, AzureBlobStorageError> {
+        let blob_path = self.get_blob_path(file_key);
+        let date = common_utils::date_time::now_rfc7231_http_date()
+            .change_context(AzureBlobStorageError::SigningFailure)?;
+
+        let content_length = body
+            .as_ref()
+            .filter(|body| !body.is_empty())
+            .map(|body| body.len().to_string())
+            .unwrap_or_default();
+        let (content_type, blob_type_header) = match (&body, query.is_empty()) {
+            (Some(_), true) => ("application/octet-stream", "x-ms-blob-type:BlockBlob\n"),
+            (Some(_), false) => ("application/octet-stream", ""),
+            (None, _) => ("", ""),
+        };
+        // Query parameters are appended to the signed resource sorted by name, with their values
+        // as is
+        let mut sorted_query = query.to_vec();
+        sorted_query.sort_by(|(name, _), (other_name, _)| name.cmp(other_name));
+        let canonicalized_query = sorted_query
+            .iter()
+            .map(|(name, value)| format!("\n{name}:{value}"))
+            .collect::<String>();
+        // Headers that are not used by these requests are left empty in the string to sign
+        let string_to_sign = format!(
+            "{method}\n\n\n{content_length}\n\n{content_type}\n\n\n\n\n\n\n\
+            {blob_type_header}x-ms-date:{date}\nx-ms-version:{AZURE_STORAGE_API_VERSION}\n\
+            /{}/{blob_path}{canonicalized_query}",
+            self.account_name
+        );
+        let signature = self.sign(&string_to_sign)?;
+
+        let query_string = query
+            .iter()
+            .map(|(name, value)| format!("{name}={}", uri_encode(value, true)))
+            .collect::<Vec<_>>()
+            .join("&");
+        let url = if query_string.is_empty() {
+            format!("{}/{blob_path}", self.endpoint)
+        } else {
+            format!("{}/{blob_path}?{query_string}", self.endpoint)
+        };
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("x-ms-date", date)
+            .header("x-ms-version", AZURE_STORAGE_API_VERSION)
+            .header(
+                reqwest::header::AUTHORIZATION,
+                format!("SharedKey {}:{signature}", self.account_name),
+            );
+        if let Some(body) = body {
+            if !blob_type_header.is_empty() {
+                request = request.header("x-ms-blob-type", "BlockBlob");
+            }
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, content_type)
+                .body(body);
+        }
+        Ok(request)
+    }
+
+    /// Uploads a file to Azure Blob Storage.
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), AzureBlobStorageError> {
+        self.build_request(reqwest::Method::PUT, file_key, &[], Some(file))?
+            .send()
+            .await
+            .change_context(AzureBlobStorageError::UploadFailure)?
+            .error_for_status()
+            .change_context(AzureBlobStorageError::UploadFailure)?;
+        Ok(())
+    }
+
+    /// Deletes a file from Azure Blob Storage.
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), AzureBlobStorageError> {
+        self.build_request(reqwest::Method::DELETE, file_key, &[], None)?
+            .send()
+            .await
+            .change_context(AzureBlobStorageError::DeleteFailure)?
+            .error_for_status()
+            .change_context(AzureBlobStorageError::DeleteFailure)?;
+        Ok(())
+    }
+
+    /// Retrieves a file from Azure Blob Storage.
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, AzureBlobStorageError> {
+        Ok(self
+            .build_request(reqwest::Method::GET, file_key, &[], None)?
+            .send()
+            .await
+            .change_context(AzureBlobStorageError::RetrieveFailure)?
+            .error_for_status()
+            .change_context(AzureBlobStorageError::RetrieveFailure)?
+            .bytes()
+            .await
+            .change_context(AzureBlobStorageError::RetrieveFailure)?
+            .to_vec())
+    }
+
+    /// Uploads a block of a blob, which becomes part of the blob once the block list is committed.
+    async fn put_block(
+        &self,
+        file_key: &str,
+        block_id: &str,
+        block: Vec<u8>,
+    ) -> CustomResult<(), AzureBlobStorageError> {
+        let query = [
+            ("comp", "block".to_string()),
+            ("blockid", block_id.to_string()),
+        ];
+        self.build_request(reqwest::Method::PUT, file_key, &query, Some(block))?
+            .send()
+            .await
+            .change_context(AzureBlobStorageError::UploadFailure)?
+            .error_for_status()
+            .change_context(AzureBlobStorageError::UploadFailure)?;
+        Ok(())
+    }
+
+    /// Commits the uploaded blocks, in order, as the content of the blob.
+    async fn put_block_list(
+        &self,
+        file_key: &str,
+        block_ids: &[String],
+    ) -> CustomResult<(), AzureBlobStorageError> {
+        let query = [("comp", "blocklist".to_string())];
+        self.build_request(
+            reqwest::Method::PUT,
+            file_key,
+            &query,
+            Some(get_block_list_body(block_ids).into_bytes()),
+        )?
+        .send()
+        .await
+        .change_context(AzureBlobStorageError::UploadFailure)?
+        .error_for_status()
+        .change_context(AzureBlobStorageError::UploadFailure)?;
+        Ok(())
+    }
+
+    /// Generates a URL carrying a read only service shared access signature for the blob.
+    fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, AzureBlobStorageError> {
+        let expires_in = time::Duration::try_from(expires_in)
+            .change_context(AzureBlobStorageError::SigningFailure)?;
+        let expiry = (OffsetDateTime::now_utc() + expires_in)
+            .replace_nanosecond(0)
+            .change_context(AzureBlobStorageError::SigningFailure)?
+            .format(&Rfc3339)
+            .change_context(AzureBlobStorageError::SigningFailure)?;
+        let blob_path = self.get_blob_path(file_key);
+        // Plain HTTP is only allowed for endpoints that do not support HTTPS, such as Azurite
+        let protocol = if self.endpoint.starts_with("https://") {
+            "https"
+        } else {
+            "https,http"
+        };
+
+        // Fields of the string to sign, in order: permissions, start, expiry, resource,
+        // identifier, IP range, protocol, version, resource type, snapshot time, encryption scope
+        // and the five response header overrides
+        let string_to_sign = format!(
+            "r\n\n{expiry}\n/blob/{}/{blob_path}\n\n\n{protocol}\n{AZURE_STORAGE_API_VERSION}\nb\n\n\n\n\n\n\n",
+            self.account_name
+        );
+        let signature = self.sign(&string_to_sign)?;
+
+        Ok(format!(
+            "{}/{blob_path}?sp=r&se={}&spr={}&sv={AZURE_STORAGE_API_VERSION}&sr=b&sig={}",
+            self.endpoint,
+            uri_encode(&expiry, true),
+            uri_encode(protocol, true),
+            uri_encode(&signature, true)
+        ))
+    }
+}
+
+/// ID of the block at the index of a block blob. IDs are base64 encoded and must have the same
+/// length for all blocks of a blob.
+fn get_block_id(block_index: usize) -> String {
+    BASE64_ENGINE.encode(format!("{block_index:010}"))
+}
+
+/// Body of a Put Block List request committing the latest uploaded version of each block
+fn get_block_list_body(block_ids: &[String]) -> String {
+    let blocks = block_ids
+        .iter()
+        .map(|block_id| format!("<Latest>{block_id}</Latest>"))
+        .collect::<String>();
+    format!(r#"<?xml version="1.0" encoding="utf-8"?><BlockList>{blocks}</BlockList>"#)
+}
+
+/// Upload of a block blob to Azure Blob Storage, one block at a time
+#[derive(Debug)]
+pub(super) struct AzureBlobUpload {
+    client: AzureBlobFileStorageClient,
+    file_key: String,
+    block_ids: Vec<String>,
+    buffer: PartBuffer,
+}
+
+impl AzureBlobUpload {
+    async fn put_block(&mut self, block: Vec<u8>) -> CustomResult<(), AzureBlobStorageError> {
+        let block_id = get_block_id(self.block_ids.len());
+        self.client
+            .put_block(&self.file_key, &block_id, block)
+            .await?;
+        self.block_ids.push(block_id);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FileUpload for AzureBlobUpload {
+    /// Uploads a block whenever enough data has been written for one.
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.buffer.push(part);
+        if let Some(block) = self.buffer.take_full_part() {
+            self.put_block(block)
+                .await
+                .change_context(FileStorageError::UploadFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Uploads the remaining block and commits the block list.
+    async fn complete(mut self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        let last_block = self.buffer.take_last_part();
+        if !last_block.is_empty() {
+            self.put_block(last_block)
+                .await
+                .change_context(FileStorageError::UploadFinishFailed)?;
+        }
+        self.client
+            .put_block_list(&self.file_key, &self.block_ids)
+            .await
+            .change_context(FileStorageError::UploadFinishFailed)?;
+        Ok(())
+    }
+
+    /// Uncommitted blocks are discarded by Azure Blob Storage after a week, so there is nothing
+    /// to clean up.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for AzureBlobFileStorageClient {
+    /// Uploads a file to Azure Blob Storage.
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError> {
+        self.upload_file(file_key, file)
+            .await
+            .change_context(FileStorageError::UploadFailed)?;
+        Ok(())
+    }
+
+    /// Deletes a file from Azure Blob Storage.
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        self.delete_file(file_key)
+            .await
+            .change_context(FileStorageError::DeleteFailed)?;
+        Ok(())
+    }
+
+    /// Retrieves a file from Azure Blob Storage.
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+        Ok(self
+            .retrieve_file(file_key)
+            .await
+            .change_context(FileStorageError::RetrieveFailed)?)
+    }
+
+    /// Starts an upload of a block blob to Azure Blob Storage.
+    async fn start_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn FileUpload>, FileStorageError> {
+        Ok(Box::new(AzureBlobUpload {
+            client: self.clone(),
+            file_key: file_key.to_string(),
+            block_ids: Vec::new(),
+            buffer: PartBuffer::default(),
+        }))
+    }
+
+    /// Generates a URL carrying a read only service shared access signature for the blob.
+    async fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, FileStorageError> {
+        Ok(self
+            .generate_presigned_url(file_key, expires_in)
+            .change_context(FileStorageError::PresignedUrlFailed)?)
+    }
+}
+
+/// Enum representing errors that can occur during Azure Blob Storage operations.
+#[derive(Debug, thiserror::Error)]
+enum AzureBlobStorageError {
+    /// Error indicating that a request or shared access signature could not be signed.
+    #[error("Failed to sign Azure Blob Storage request")]
+    SigningFailure,
+
+    /// Error indicating that file upload to Azure Blob Storage failed.
+    #[error("File upload to Azure Blob Storage failed")]
+    UploadFailure,
+
+    /// Error indicating that file retrieval from Azure Blob Storage failed.
+    #[error("File retrieve from Azure Blob Storage failed")]
+    RetrieveFailure,
+
+    /// Error indicating that file deletion from Azure Blob Storage failed.
+    #[error("File delete from Azure Blob Storage failed")]
+    DeleteFailure,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::file_storage::tests::PrefixSecretManager;
+
+    fn get_config() -> AzureBlobFileStorageConfig {
+        AzureBlobFileStorageConfig {
+            account_name: "account".to_string(),
+            account_key: Secret::new(BASE64_ENGINE.encode("account_key")),
+            container_name: "container".to_string(),
+            endpoint: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(get_config().validate().is_ok());
+
+        let config = AzureBlobFileStorageConfig {
+            account_key: Secret::new("not base64!".to_string()),
+            ..get_config()
+        };
+        assert!(config.validate().is_err());
+
+        let config = AzureBlobFileStorageConfig {
+            container_name: String::new(),
+            ..get_config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_secrets() {
+        let config = AzureBlobFileStorageConfig {
+            account_key: Secret::new("account_key".to_string()),
+            ..get_config()
+        }
+        .decrypt_secrets(&PrefixSecretManager)
+        .await
+        .unwrap();
+
+        assert_eq!(config.account_key.peek(), "decrypted_account_key");
+        assert_eq!(config.account_name, "account");
+        assert_eq!(config.container_name, "container");
+    }
+
+    #[test]
+    fn test_client_uses_decoded_key_and_default_endpoint() {
+        let client = AzureBlobFileStorageClient::new(&get_config());
+
+        assert_eq!(client.account_key.peek(), b"account_key");
+        assert_eq!(client.endpoint, "https://account.blob.core.windows.net");
+        assert_eq!(
+            client.get_blob_path("merchant/file 1"),
+            "container/merchant/file%201"
+        );
+    }
+
+    #[test]
+    fn test_block_ids_have_equal_length() {
+        assert_eq!(get_block_id(0), BASE64_ENGINE.encode("0000000000"));
+        assert_eq!(get_block_id(0).len(), get_block_id(49_999).len());
+    }
+
+    #[test]
+    fn test_block_list_body() {
+        let block_ids = vec![get_block_id(0), get_block_id(1)];
+
+        assert_eq!(
+            get_block_list_body(&block_ids),
+            format!(
+                r#"<?xml version="1.0" encoding="utf-8"?><BlockList><Latest>{}</Latest><Latest>{}</Latest></BlockList>"#,
+                block_ids[0], block_ids[1]
+            )
+        );
+    }
+
+    #[test]
+    fn test_block_request_signs_query_parameters() {
+        let client = AzureBlobFileStorageClient::new(&get_config());
+        let request = client
+            .build_request(
+                reqwest::Method::PUT,
+                "report.csv",
+                &[
+                    ("comp", "block".to_string()),
+                    ("blockid", "MDA=".to_string()),
+                ],
+                Some(vec![1, 2, 3]),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            request.url().as_str(),
+            "https://account.blob.core.windows.net/container/report.csv?comp=block&blockid=MDA%3D"
+        );
+        assert!(request.headers().get("x-ms-blob-type").is_none());
+    }
+}
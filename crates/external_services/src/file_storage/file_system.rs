@@ -1,15 +1,16 @@
 //! Module for local file system storage operations
 
 use std::{
-    fs::{remove_file, File},
+    fs::{remove_file, rename, File},
     io::{Read, Write},
     path::PathBuf,
+    time::Duration,
 };
 
 use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 
-use crate::file_storage::{FileStorageError, FileStorageInterface};
+use crate::file_storage::{FileStorageError, FileStorageInterface, FileUpload};
 
 /// Constructs the file path for a given file key within the file system.
 /// The file path is generated based on the workspace path and the provided file key.
@@ -22,6 +23,17 @@ fn get_file_path(file_key: impl AsRef<str>) -> PathBuf {
     file_path
 }
 
+/// Creates the directories in the file path which do not exist yet.
+fn create_parent_dirs(file_path: &std::path::Path) -> CustomResult<(), FileSystemStorageError> {
+    std::fs::create_dir_all(
+        file_path
+            .parent()
+            .ok_or(FileSystemStorageError::CreateDirFailed)
+            .attach_printable("Failed to obtain parent directory")?,
+    )
+    .change_context(FileSystemStorageError::CreateDirFailed)
+}
+
 /// Represents a file system for storing and managing files locally.
 #[derive(Debug, Clone)]
 pub(super) struct FileSystem;
@@ -36,13 +48,7 @@ impl FileSystem {
         let file_path = get_file_path(file_key);
 
         // Ignore the file name and create directories in the `file_path` if not exists
-        std::fs::create_dir_all(
-            file_path
-                .parent()
-                .ok_or(FileSystemStorageError::CreateDirFailed)
-                .attach_printable("Failed to obtain parent directory")?,
-        )
-        .change_context(FileSystemStorageError::CreateDirFailed)?;
+        create_parent_dirs(&file_path)?;
 
         let mut file_handler =
             File::create(file_path).change_context(FileSystemStorageError::CreateFailure)?;
@@ -69,6 +75,65 @@ impl FileSystem {
             .change_context(FileSystemStorageError::ReadFailure)?;
         Ok(received_data)
     }
+
+    /// Starts writing a file in parts. The parts are written to a temporary file, which is moved
+    /// to the path of the file key once the upload completes.
+    fn start_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<FileSystemUpload, FileSystemStorageError> {
+        let file_path = get_file_path(file_key);
+        create_parent_dirs(&file_path)?;
+
+        let mut temporary_path = file_path.clone().into_os_string();
+        temporary_path.push(".part");
+        let temporary_path = PathBuf::from(temporary_path);
+        let file =
+            File::create(&temporary_path).change_context(FileSystemStorageError::CreateFailure)?;
+
+        Ok(FileSystemUpload {
+            file,
+            file_path,
+            temporary_path,
+        })
+    }
+}
+
+/// Upload of a file in parts to the file system
+#[derive(Debug)]
+pub(super) struct FileSystemUpload {
+    file: File,
+    file_path: PathBuf,
+    temporary_path: PathBuf,
+}
+
+#[async_trait::async_trait]
+impl FileUpload for FileSystemUpload {
+    /// Appends the part to the temporary file.
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.file
+            .write_all(&part)
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFailed)
+    }
+
+    /// Moves the temporary file to the path of the file key.
+    async fn complete(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        self.file
+            .sync_all()
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFinishFailed)?;
+        rename(&self.temporary_path, &self.file_path)
+            .change_context(FileSystemStorageError::WriteFailure)
+            .change_context(FileStorageError::UploadFinishFailed)
+    }
+
+    /// Deletes the temporary file.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        remove_file(&self.temporary_path)
+            .change_context(FileSystemStorageError::DeleteFailure)
+            .change_context(FileStorageError::UploadFinishFailed)
+    }
 }
 
 #[async_trait::async_trait]
@@ -100,6 +165,26 @@ impl FileStorageInterface for FileSystem {
             .await
             .change_context(FileStorageError::RetrieveFailed)?)
     }
+
+    /// Starts writing a file in parts to the file system.
+    async fn start_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn FileUpload>, FileStorageError> {
+        Ok(Box::new(
+            self.start_upload(file_key)
+                .change_context(FileStorageError::UploadFailed)?,
+        ))
+    }
+
+    /// Files on the local file system are only served through the application.
+    async fn generate_presigned_url(
+        &self,
+        _file_key: &str,
+        _expires_in: Duration,
+    ) -> CustomResult<String, FileStorageError> {
+        Err(report!(FileStorageError::PresignedUrlNotSupported))
+    }
 }
 
 /// Represents an error that can occur during local file system storage operations.
@@ -129,3 +214,42 @@ enum FileSystemStorageError {
     #[error("Failed while creating a directory")]
     CreateDirFailed,
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn test_upload_in_parts() {
+        let file_key = "test_upload_in_parts/report.csv";
+        let mut upload = FileStorageInterface::start_upload(&FileSystem, file_key)
+            .await
+            .unwrap();
+        upload.write_part(b"id,amount\n".to_vec()).await.unwrap();
+        upload.write_part(b"pay_1,100\n".to_vec()).await.unwrap();
+
+        // The file is only available once the upload completes
+        assert!(FileSystem.retrieve_file(file_key).await.is_err());
+        upload.complete().await.unwrap();
+
+        assert_eq!(
+            FileSystem.retrieve_file(file_key).await.unwrap(),
+            b"id,amount\npay_1,100\n"
+        );
+        FileSystem.delete_file(file_key).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_aborted_upload_leaves_no_file() {
+        let file_key = "test_aborted_upload_leaves_no_file/report.csv";
+        let mut upload = FileStorageInterface::start_upload(&FileSystem, file_key)
+            .await
+            .unwrap();
+        upload.write_part(b"id,amount\n".to_vec()).await.unwrap();
+        upload.abort().await.unwrap();
+
+        assert!(FileSystem.retrieve_file(file_key).await.is_err());
+        assert!(!get_file_path(format!("{file_key}.part")).exists());
+    }
+}
@@ -0,0 +1,624 @@
+//! Module for Google Cloud Storage operations, using the JSON API with service account
+//! credentials
+
+use std::{sync::Arc, time::Duration};
+
+use base64::Engine;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    errors::CustomResult,
+    ext_traits::ConfigExt,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
+use masking::{PeekInterface, Secret};
+use time::OffsetDateTime;
+use tokio::sync::RwLock;
+
+use super::{uri_encode, InvalidFileStorageConfig, PartBuffer};
+use crate::file_storage::{FileStorageError, FileStorageInterface, FileUpload};
+
+/// OAuth scope granting read and write access to objects
+const GCS_READ_WRITE_SCOPE: &str = "https://www.googleapis.com/auth/devstorage.read_write";
+
+/// Access tokens are refreshed this long before they expire
+const ACCESS_TOKEN_EXPIRY_BUFFER: time::Duration = time::Duration::minutes(1);
+
+/// Configuration for Google Cloud Storage.
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct GcsFileStorageConfig {
+    /// The bucket to send file uploads
+    bucket_name: String,
+    /// Email of the service account that accesses the bucket
+    service_account_email: String,
+    /// PEM encoded private key of the service account
+    private_key: Secret<String>,
+    /// Endpoint to exchange the signed service account assertion for an access token
+    token_uri: String,
+    /// Endpoint of the Cloud Storage API
+    endpoint: String,
+}
+
+impl Default for GcsFileStorageConfig {
+    fn default() -> Self {
+        Self {
+            bucket_name: String::default(),
+            service_account_email: String::default(),
+            private_key: Secret::default(),
+            token_uri: "https://oauth2.googleapis.com/token".to_string(),
+            endpoint: "https://storage.googleapis.com".to_string(),
+        }
+    }
+}
+
+impl GcsFileStorageConfig {
+    /// Validates the Google Cloud Storage configuration.
+    pub(super) fn validate(&self) -> Result<(), InvalidFileStorageConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.bucket_name.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "gcs bucket name must not be empty",
+            ))
+        })?;
+
+        when(self.service_account_email.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "gcs service account email must not be empty",
+            ))
+        })?;
+
+        when(self.private_key.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "gcs service account private key must not be empty",
+            ))
+        })?;
+
+        when(
+            self.token_uri.is_default_or_empty() || self.endpoint.is_default_or_empty(),
+            || {
+                Err(InvalidFileStorageConfig(
+                    "gcs token uri and endpoint must not be empty",
+                ))
+            },
+        )
+    }
+
+    /// Decrypts the service account private key using the secrets manager.
+    pub(super) async fn decrypt_secrets(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let private_key = secret_management_client
+            .get_secret(self.private_key.clone())
+            .await?;
+
+        Ok(Self {
+            private_key,
+            ..self
+        })
+    }
+}
+
+/// Access token obtained for the service account, along with its expiry.
+#[derive(Debug, Clone)]
+struct AccessToken {
+    token: Secret<String>,
+    expires_at: OffsetDateTime,
+}
+
+/// Claims of the assertion exchanged for an access token.
+#[derive(Debug, serde::Serialize)]
+struct ServiceAccountClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: i64,
+    exp: i64,
+}
+
+/// Response of the token endpoint.
+#[derive(Debug, serde::Deserialize)]
+struct AccessTokenResponse {
+    access_token: Secret<String>,
+    expires_in: i64,
+}
+
+/// Google Cloud Storage file storage client.
+#[derive(Debug, Clone)]
+pub(super) struct GcsFileStorageClient {
+    http_client: reqwest::Client,
+    config: GcsFileStorageConfig,
+    /// Access token shared by all clones of the client
+    access_token: Arc<RwLock<Option<AccessToken>>>,
+}
+
+impl GcsFileStorageClient {
+    /// Creates a new Google Cloud Storage client, access tokens are obtained on first use.
+    pub(super) fn new(config: &GcsFileStorageConfig) -> Self {
+        Self {
+            http_client: reqwest::Client::new(),
+            config: config.clone(),
+            access_token: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Returns a valid access token, requesting a new one when the cached token is about to expire.
+    async fn get_access_token(&self) -> CustomResult<Secret<String>, GcsStorageError> {
+        let now = OffsetDateTime::now_utc();
+        if let Some(access_token) = self.access_token.read().await.as_ref() {
+            if access_token.expires_at - ACCESS_TOKEN_EXPIRY_BUFFER > now {
+                return Ok(access_token.token.clone());
+            }
+        }
+
+        let mut cached_access_token = self.access_token.write().await;
+        // Another task may have refreshed the token while waiting for the lock
+        if let Some(access_token) = cached_access_token.as_ref() {
+            if access_token.expires_at - ACCESS_TOKEN_EXPIRY_BUFFER > now {
+                return Ok(access_token.token.clone());
+            }
+        }
+
+        let claims = ServiceAccountClaims {
+            iss: &self.config.service_account_email,
+            scope: GCS_READ_WRITE_SCOPE,
+            aud: &self.config.token_uri,
+            iat: now.unix_timestamp(),
+            exp: (now + time::Duration::hours(1)).unix_timestamp(),
+        };
+        let assertion = jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256),
+            &claims,
+            &self.get_encoding_key()?,
+        )
+        .change_context(GcsStorageError::AuthenticationFailed)
+        .attach_printable("Failed to sign service account assertion")?;
+
+        let response = self
+            .http_client
+            .post(&self.config.token_uri)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", assertion.as_str()),
+            ])
+            .send()
+            .await
+            .change_context(GcsStorageError::AuthenticationFailed)?
+            .error_for_status()
+            .change_context(GcsStorageError::AuthenticationFailed)?
+            .bytes()
+            .await
+            .change_context(GcsStorageError::AuthenticationFailed)?;
+        let response: AccessTokenResponse = serde_json::from_slice(&response)
+            .change_context(GcsStorageError::AuthenticationFailed)
+            .attach_printable("Failed to parse access token response")?;
+
+        let access_token = AccessToken {
+            token: response.access_token,
+            expires_at: now + time::Duration::seconds(response.expires_in),
+        };
+        let token = access_token.token.clone();
+        *cached_access_token = Some(access_token);
+        Ok(token)
+    }
+
+    fn get_encoding_key(&self) -> CustomResult<jsonwebtoken::EncodingKey, GcsStorageError> {
+        jsonwebtoken::EncodingKey::from_rsa_pem(self.config.private_key.peek().as_bytes())
+            .change_context(GcsStorageError::AuthenticationFailed)
+            .attach_printable("Invalid service account private key")
+    }
+
+    fn get_object_url(&self, file_key: &str) -> String {
+        format!(
+            "{}/storage/v1/b/{}/o/{}",
+            self.config.endpoint,
+            self.config.bucket_name,
+            uri_encode(file_key, true)
+        )
+    }
+
+    /// Uploads a file to Google Cloud Storage.
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), GcsStorageError> {
+        let access_token = self.get_access_token().await?;
+        self.http_client
+            .post(format!(
+                "{}/upload/storage/v1/b/{}/o",
+                self.config.endpoint, self.config.bucket_name
+            ))
+            .query(&[("uploadType", "media"), ("name", file_key)])
+            .bearer_auth(access_token.peek())
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(file)
+            .send()
+            .await
+            .change_context(GcsStorageError::UploadFailure)?
+            .error_for_status()
+            .change_context(GcsStorageError::UploadFailure)?;
+        Ok(())
+    }
+
+    /// Starts a resumable upload of a file to Google Cloud Storage.
+    async fn start_upload(&self, file_key: &str) -> CustomResult<GcsUpload, GcsStorageError> {
+        let access_token = self.get_access_token().await?;
+        let session_uri = self
+            .http_client
+            .post(format!(
+                "{}/upload/storage/v1/b/{}/o",
+                self.config.endpoint, self.config.bucket_name
+            ))
+            .query(&[("uploadType", "resumable"), ("name", file_key)])
+            .bearer_auth(access_token.peek())
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .await
+            .change_context(GcsStorageError::UploadFailure)?
+            .error_for_status()
+            .change_context(GcsStorageError::UploadFailure)?
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .map(ToString::to_string)
+            .ok_or(GcsStorageError::UploadFailure)
+            .attach_printable("gcs did not return the session uri of the resumable upload")?;
+
+        Ok(GcsUpload {
+            http_client: self.http_client.clone(),
+            session_uri,
+            uploaded_size: 0,
+            buffer: PartBuffer::default(),
+        })
+    }
+
+    /// Deletes a file from Google Cloud Storage.
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), GcsStorageError> {
+        let access_token = self.get_access_token().await?;
+        self.http_client
+            .delete(self.get_object_url(file_key))
+            .bearer_auth(access_token.peek())
+            .send()
+            .await
+            .change_context(GcsStorageError::DeleteFailure)?
+            .error_for_status()
+            .change_context(GcsStorageError::DeleteFailure)?;
+        Ok(())
+    }
+
+    /// Retrieves a file from Google Cloud Storage.
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, GcsStorageError> {
+        let access_token = self.get_access_token().await?;
+        Ok(self
+            .http_client
+            .get(self.get_object_url(file_key))
+            .query(&[("alt", "media")])
+            .bearer_auth(access_token.peek())
+            .send()
+            .await
+            .change_context(GcsStorageError::RetrieveFailure)?
+            .error_for_status()
+            .change_context(GcsStorageError::RetrieveFailure)?
+            .bytes()
+            .await
+            .change_context(GcsStorageError::RetrieveFailure)?
+            .to_vec())
+    }
+
+    /// Generates a V4 signed URL for downloading a file from Google Cloud Storage.
+    fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, GcsStorageError> {
+        let host = url::Url::parse(&self.config.endpoint)
+            .change_context(GcsStorageError::PresignFailure)
+            .attach_printable("Invalid gcs endpoint")?
+            .host_str()
+            .map(ToString::to_string)
+            .ok_or(GcsStorageError::PresignFailure)
+            .attach_printable("gcs endpoint does not contain a host")?;
+
+        let now = OffsetDateTime::now_utc();
+        let timestamp = format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+            now.year(),
+            u8::from(now.month()),
+            now.day(),
+            now.hour(),
+            now.minute(),
+            now.second()
+        );
+        let credential_scope = format!(
+            "{}/auto/storage/goog4_request",
+            timestamp.get(..8).unwrap_or_default()
+        );
+        let credential = format!("{}/{credential_scope}", self.config.service_account_email);
+        let path = format!(
+            "/{}/{}",
+            self.config.bucket_name,
+            uri_encode(file_key, false)
+        );
+
+        // Query parameters have to be sorted by name for the canonical request
+        let canonical_query = [
+            ("X-Goog-Algorithm", "GOOG4-RSA-SHA256".to_string()),
+            ("X-Goog-Credential", credential),
+            ("X-Goog-Date", timestamp.clone()),
+            ("X-Goog-Expires", expires_in.as_secs().to_string()),
+            ("X-Goog-SignedHeaders", "host".to_string()),
+        ]
+        .iter()
+        .map(|(name, value)| format!("{name}={}", uri_encode(value, true)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+        let canonical_request =
+            format!("GET\n{path}\n{canonical_query}\nhost:{host}\n\nhost\nUNSIGNED-PAYLOAD");
+        let canonical_request_digest = Sha256
+            .generate_digest(canonical_request.as_bytes())
+            .change_context(GcsStorageError::PresignFailure)?;
+        let string_to_sign = format!(
+            "GOOG4-RSA-SHA256\n{timestamp}\n{credential_scope}\n{}",
+            hex::encode(canonical_request_digest)
+        );
+
+        let signature = jsonwebtoken::crypto::sign(
+            string_to_sign.as_bytes(),
+            &self.get_encoding_key()?,
+            jsonwebtoken::Algorithm::RS256,
+        )
+        .change_context(GcsStorageError::PresignFailure)?;
+        // The signature is returned base64url encoded, while signed URLs carry it hex encoded
+        let signature = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(signature)
+            .change_context(GcsStorageError::PresignFailure)?;
+
+        Ok(format!(
+            "{}{path}?{canonical_query}&X-Goog-Signature={}",
+            self.config.endpoint,
+            hex::encode(signature)
+        ))
+    }
+}
+
+/// `Content-Range` of a chunk of a resumable upload. The total size is only known, and sent, with
+/// the last chunk.
+fn get_chunk_content_range(offset: u64, chunk_size: u64, total_size: Option<u64>) -> String {
+    let total_size = total_size.map_or_else(|| "*".to_string(), |size| size.to_string());
+    if chunk_size == 0 {
+        format!("bytes */{total_size}")
+    } else {
+        format!("bytes {offset}-{}/{total_size}", offset + chunk_size - 1)
+    }
+}
+
+/// Resumable upload of a file to Google Cloud Storage. The session URI authorizes the upload, so
+/// chunks are sent without an access token.
+#[derive(Debug)]
+pub(super) struct GcsUpload {
+    http_client: reqwest::Client,
+    session_uri: String,
+    uploaded_size: u64,
+    buffer: PartBuffer,
+}
+
+impl GcsUpload {
+    /// Uploads the next chunk of the file, the last chunk completes the upload.
+    async fn upload_chunk(
+        &mut self,
+        chunk: Vec<u8>,
+        is_last_chunk: bool,
+    ) -> CustomResult<(), GcsStorageError> {
+        let chunk_size =
+            u64::try_from(chunk.len()).change_context(GcsStorageError::UploadFailure)?;
+        let total_size = is_last_chunk.then_some(self.uploaded_size + chunk_size);
+        // Chunks other than the last one are acknowledged with `308 Resume Incomplete`
+        self.http_client
+            .put(&self.session_uri)
+            .header(
+                reqwest::header::CONTENT_RANGE,
+                get_chunk_content_range(self.uploaded_size, chunk_size, total_size),
+            )
+            .body(chunk)
+            .send()
+            .await
+            .change_context(GcsStorageError::UploadFailure)?
+            .error_for_status()
+            .change_context(GcsStorageError::UploadFailure)?;
+        self.uploaded_size += chunk_size;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FileUpload for GcsUpload {
+    /// Uploads a chunk whenever enough data has been written for one.
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), FileStorageError> {
+        self.buffer.push(part);
+        if let Some(chunk) = self.buffer.take_full_part() {
+            self.upload_chunk(chunk, false)
+                .await
+                .change_context(FileStorageError::UploadFailed)?;
+        }
+        Ok(())
+    }
+
+    /// Uploads the last chunk, which completes the resumable upload.
+    async fn complete(mut self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        let last_chunk = self.buffer.take_last_part();
+        self.upload_chunk(last_chunk, true)
+            .await
+            .change_context(FileStorageError::UploadFinishFailed)?;
+        Ok(())
+    }
+
+    /// Cancels the resumable upload. Google Cloud Storage acknowledges the cancellation with a
+    /// `499` status, so the status of the response is not checked.
+    async fn abort(self: Box<Self>) -> CustomResult<(), FileStorageError> {
+        self.http_client
+            .delete(&self.session_uri)
+            .header(reqwest::header::CONTENT_LENGTH, 0)
+            .send()
+            .await
+            .change_context(GcsStorageError::UploadFailure)
+            .change_context(FileStorageError::UploadFinishFailed)?;
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl FileStorageInterface for GcsFileStorageClient {
+    /// Uploads a file to Google Cloud Storage.
+    async fn upload_file(
+        &self,
+        file_key: &str,
+        file: Vec<u8>,
+    ) -> CustomResult<(), FileStorageError> {
+        self.upload_file(file_key, file)
+            .await
+            .change_context(FileStorageError::UploadFailed)?;
+        Ok(())
+    }
+
+    /// Deletes a file from Google Cloud Storage.
+    async fn delete_file(&self, file_key: &str) -> CustomResult<(), FileStorageError> {
+        self.delete_file(file_key)
+            .await
+            .change_context(FileStorageError::DeleteFailed)?;
+        Ok(())
+    }
+
+    /// Retrieves a file from Google Cloud Storage.
+    async fn retrieve_file(&self, file_key: &str) -> CustomResult<Vec<u8>, FileStorageError> {
+        Ok(self
+            .retrieve_file(file_key)
+            .await
+            .change_context(FileStorageError::RetrieveFailed)?)
+    }
+
+    /// Starts a resumable upload of a file to Google Cloud Storage.
+    async fn start_upload(
+        &self,
+        file_key: &str,
+    ) -> CustomResult<Box<dyn FileUpload>, FileStorageError> {
+        Ok(Box::new(
+            self.start_upload(file_key)
+                .await
+                .change_context(FileStorageError::UploadFailed)?,
+        ))
+    }
+
+    /// Generates a V4 signed URL for downloading a file from Google Cloud Storage.
+    async fn generate_presigned_url(
+        &self,
+        file_key: &str,
+        expires_in: Duration,
+    ) -> CustomResult<String, FileStorageError> {
+        // Signed URLs are valid for at most seven days
+        if expires_in > Duration::from_secs(7 * 24 * 60 * 60) {
+            return Err(report!(FileStorageError::PresignedUrlFailed))
+                .attach_printable("gcs signed urls cannot be valid for more than seven days");
+        }
+        Ok(self
+            .generate_presigned_url(file_key, expires_in)
+            .change_context(FileStorageError::PresignedUrlFailed)?)
+    }
+}
+
+/// Enum representing errors that can occur during Google Cloud Storage operations.
+#[derive(Debug, thiserror::Error)]
+enum GcsStorageError {
+    /// Error indicating that an access token could not be obtained.
+    #[error("Failed to authenticate with Google Cloud Storage")]
+    AuthenticationFailed,
+
+    /// Error indicating that file upload to Google Cloud Storage failed.
+    #[error("File upload to Google Cloud Storage failed")]
+    UploadFailure,
+
+    /// Error indicating that file retrieval from Google Cloud Storage failed.
+    #[error("File retrieve from Google Cloud Storage failed")]
+    RetrieveFailure,
+
+    /// Error indicating that file deletion from Google Cloud Storage failed.
+    #[error("File delete from Google Cloud Storage failed")]
+    DeleteFailure,
+
+    /// Error indicating that a signed URL could not be generated.
+    #[error("Failed to generate Google Cloud Storage signed URL")]
+    PresignFailure,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::file_storage::tests::PrefixSecretManager;
+
+    fn get_config() -> GcsFileStorageConfig {
+        GcsFileStorageConfig {
+            bucket_name: "bucket".to_string(),
+            service_account_email: "uploader@project.iam.gserviceaccount.com".to_string(),
+            private_key: Secret::new("private_key".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(get_config().validate().is_ok());
+
+        let config = GcsFileStorageConfig {
+            private_key: Secret::default(),
+            ..get_config()
+        };
+        assert!(config.validate().is_err());
+
+        let config = GcsFileStorageConfig {
+            service_account_email: String::new(),
+            ..get_config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_secrets() {
+        let config = get_config()
+            .decrypt_secrets(&PrefixSecretManager)
+            .await
+            .unwrap();
+
+        assert_eq!(config.private_key.peek(), "decrypted_private_key");
+        assert_eq!(config.bucket_name, "bucket");
+        assert_eq!(config.token_uri, "https://oauth2.googleapis.com/token");
+    }
+
+    #[test]
+    fn test_object_url_encodes_file_key() {
+        let client = GcsFileStorageClient::new(&get_config());
+
+        assert_eq!(
+            client.get_object_url("merchant/file 1"),
+            "https://storage.googleapis.com/storage/v1/b/bucket/o/merchant%2Ffile%201"
+        );
+    }
+
+    #[test]
+    fn test_chunk_content_range() {
+        assert_eq!(
+            get_chunk_content_range(0, 262_144, None),
+            "bytes 0-262143/*"
+        );
+        assert_eq!(
+            get_chunk_content_range(262_144, 10, Some(262_154)),
+            "bytes 262144-262153/262154"
+        );
+        assert_eq!(get_chunk_content_range(0, 0, Some(0)), "bytes */0");
+    }
+}
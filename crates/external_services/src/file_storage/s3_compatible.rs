@@ -0,0 +1,172 @@
+//! Module for object stores that implement the S3 API, such as MinIO, Ceph or Cloudflare R2
+
+use aws_sdk_s3::{
+    config::{BehaviorVersion, Credentials, Region},
+    Client,
+};
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt};
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
+use masking::{PeekInterface, Secret};
+
+use super::{aws_s3::AwsFileStorageClient, InvalidFileStorageConfig};
+
+/// Configuration for S3 compatible file storage.
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct S3CompatibleFileStorageConfig {
+    /// The endpoint of the object store, for example `http://localhost:9000` for a local MinIO
+    endpoint_url: String,
+    /// The region requests are signed for, most S3 compatible stores accept any region
+    region: String,
+    /// The bucket to send file uploads
+    bucket_name: String,
+    /// The access key used to sign requests
+    access_key_id: Secret<String>,
+    /// The secret key used to sign requests
+    secret_access_key: Secret<String>,
+    /// Address buckets as `<endpoint>/<bucket>` instead of `<bucket>.<endpoint>`, which most
+    /// self-hosted object stores require
+    force_path_style: bool,
+}
+
+impl Default for S3CompatibleFileStorageConfig {
+    fn default() -> Self {
+        Self {
+            endpoint_url: String::default(),
+            region: "us-east-1".to_string(),
+            bucket_name: String::default(),
+            access_key_id: Secret::default(),
+            secret_access_key: Secret::default(),
+            force_path_style: true,
+        }
+    }
+}
+
+impl S3CompatibleFileStorageConfig {
+    /// Validates the S3 compatible file storage configuration.
+    pub(super) fn validate(&self) -> Result<(), InvalidFileStorageConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.endpoint_url.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "s3 compatible endpoint url must not be empty",
+            ))
+        })?;
+
+        when(self.region.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "s3 compatible region must not be empty",
+            ))
+        })?;
+
+        when(self.bucket_name.is_default_or_empty(), || {
+            Err(InvalidFileStorageConfig(
+                "s3 compatible bucket name must not be empty",
+            ))
+        })?;
+
+        when(
+            self.access_key_id.is_default_or_empty()
+                || self.secret_access_key.is_default_or_empty(),
+            || {
+                Err(InvalidFileStorageConfig(
+                    "s3 compatible access key id and secret access key must not be empty",
+                ))
+            },
+        )
+    }
+
+    /// Decrypts the access keys using the secrets manager.
+    pub(super) async fn decrypt_secrets(
+        self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let access_key_id = secret_management_client
+            .get_secret(self.access_key_id.clone())
+            .await?;
+        let secret_access_key = secret_management_client
+            .get_secret(self.secret_access_key.clone())
+            .await?;
+
+        Ok(Self {
+            access_key_id,
+            secret_access_key,
+            ..self
+        })
+    }
+}
+
+/// Creates a file storage client for an S3 compatible object store. The requests are the same as
+/// for AWS S3, only the endpoint, addressing style and credentials differ.
+pub(super) fn new_s3_compatible_client(
+    config: &S3CompatibleFileStorageConfig,
+) -> AwsFileStorageClient {
+    let credentials = Credentials::new(
+        config.access_key_id.peek(),
+        config.secret_access_key.peek(),
+        None,
+        None,
+        "s3_compatible_file_storage",
+    );
+    let s3_config = aws_sdk_s3::Config::builder()
+        .behavior_version(BehaviorVersion::latest())
+        .region(Region::new(config.region.clone()))
+        .endpoint_url(&config.endpoint_url)
+        .force_path_style(config.force_path_style)
+        .credentials_provider(credentials)
+        .build();
+
+    AwsFileStorageClient::from_client(Client::from_conf(s3_config), config.bucket_name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::file_storage::tests::PrefixSecretManager;
+
+    fn get_config() -> S3CompatibleFileStorageConfig {
+        S3CompatibleFileStorageConfig {
+            endpoint_url: "http://localhost:9000".to_string(),
+            bucket_name: "bucket".to_string(),
+            access_key_id: Secret::new("access_key_id".to_string()),
+            secret_access_key: Secret::new("secret_access_key".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_config() {
+        assert!(get_config().validate().is_ok());
+
+        let config = S3CompatibleFileStorageConfig {
+            secret_access_key: Secret::default(),
+            ..get_config()
+        };
+        assert!(config.validate().is_err());
+
+        let config = S3CompatibleFileStorageConfig {
+            endpoint_url: String::new(),
+            ..get_config()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_secrets() {
+        let config = get_config()
+            .decrypt_secrets(&PrefixSecretManager)
+            .await
+            .unwrap();
+
+        assert_eq!(config.access_key_id.peek(), "decrypted_access_key_id");
+        assert_eq!(
+            config.secret_access_key.peek(),
+            "decrypted_secret_access_key"
+        );
+        assert_eq!(config.endpoint_url, "http://localhost:9000");
+        assert_eq!(config.bucket_name, "bucket");
+    }
+}
@@ -8,6 +8,7 @@ pub mod aws_kms;
 pub mod crm;
 #[cfg(feature = "email")]
 pub mod email;
+pub mod file_scanner;
 pub mod file_storage;
 /// Building grpc clients to communicate with the server
 pub mod grpc_client;
@@ -19,6 +20,8 @@ pub mod http_client;
 pub mod hubspot_proxy;
 pub mod managers;
 pub mod no_encryption;
+#[cfg(feature = "sftp")]
+pub mod sftp;
 #[cfg(feature = "superposition")]
 pub mod superposition;
 /// deserializers module_path
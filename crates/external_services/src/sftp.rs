@@ -0,0 +1,364 @@
+//! Module for uploading files to SFTP servers.
+
+use std::{
+    io::Write,
+    net::{SocketAddr, TcpStream, ToSocketAddrs},
+    path::Path,
+    time::Duration,
+};
+
+use base64::Engine;
+use common_utils::{errors::CustomResult, validation};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use ssh2::{HashType, RenameFlags, Session};
+
+/// Default port of SSH servers.
+pub const DEFAULT_PORT: u16 = 22;
+
+/// Timeout for connecting to the server and for each blocking call on the session.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Connection details of an SFTP server.
+#[derive(Debug, Clone)]
+pub struct SftpConfig {
+    /// Host name or IP address of the server.
+    pub host: String,
+    /// Port of the server.
+    pub port: u16,
+    /// User to authenticate as.
+    pub username: String,
+    /// Password of the user.
+    pub password: Option<Secret<String>>,
+    /// PEM encoded private key of the user, preferred over the password when both are provided.
+    pub private_key: Option<Secret<String>>,
+    /// SHA256 fingerprint of the host key of the server, in the `SHA256:<base64>` format printed
+    /// by `ssh-keygen -l`.
+    pub host_key_fingerprint: String,
+}
+
+/// Errors that could occur while uploading files to an SFTP server.
+#[derive(Debug, thiserror::Error)]
+pub enum SftpError {
+    /// The server could not be reached.
+    #[error("Failed to connect to the SFTP server")]
+    ConnectionFailed,
+    /// The host key of the server does not match the configured fingerprint.
+    #[error("SFTP server host key does not match the configured fingerprint")]
+    HostKeyMismatch,
+    /// The server rejected the credentials.
+    #[error("Failed to authenticate with the SFTP server")]
+    AuthenticationFailed,
+    /// A file could not be written to the server.
+    #[error("Failed to upload file to the SFTP server")]
+    UploadFailed,
+    /// The upload was aborted before it was completed.
+    #[error("SFTP upload was aborted")]
+    UploadAborted,
+    /// The host resolves to an address which is not public.
+    #[error("SFTP server address is not allowed")]
+    AddressNotAllowed,
+}
+
+/// Size of the queue of parts waiting to be written to the server, which bounds the memory held
+/// by an upload when the server is slower than the parts are produced.
+const UPLOAD_QUEUE_SIZE: usize = 4;
+
+enum UploadMessage {
+    Part(Vec<u8>),
+    Complete,
+}
+
+/// An upload of a file in progress, started by [`start_upload`]. The file is written to a
+/// temporary name and renamed once complete, so that readers never see partial files and an
+/// existing file of the same name is replaced.
+#[derive(Debug)]
+pub struct SftpUpload {
+    sender: tokio::sync::mpsc::Sender<UploadMessage>,
+    task: tokio::task::JoinHandle<CustomResult<(), SftpError>>,
+}
+
+/// Starts uploading a file to a directory of an SFTP server. The parts written to the upload
+/// are appended to the file as they are received.
+pub fn start_upload(config: SftpConfig, directory: String, name: String) -> SftpUpload {
+    let (sender, receiver) = tokio::sync::mpsc::channel(UPLOAD_QUEUE_SIZE);
+    // `ssh2` only provides a blocking interface
+    let task = tokio::task::spawn_blocking(move || {
+        upload_file_blocking(&config, &directory, &name, receiver)
+    });
+    SftpUpload { sender, task }
+}
+
+impl SftpUpload {
+    /// Appends a part to the file being uploaded.
+    pub async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), SftpError> {
+        // The upload task only stops receiving parts once it failed, its error is returned when
+        // the upload is completed or aborted
+        self.sender
+            .send(UploadMessage::Part(part))
+            .await
+            .map_err(|_| report!(SftpError::UploadFailed))
+            .attach_printable("SFTP upload stopped before the part could be written")
+    }
+
+    /// Completes the upload, renaming the file to its final name.
+    pub async fn complete(self) -> CustomResult<(), SftpError> {
+        // A failed upload task has stopped receiving, its result is returned instead
+        let _ = self.sender.send(UploadMessage::Complete).await;
+        drop(self.sender);
+        self.task
+            .await
+            .change_context(SftpError::UploadFailed)
+            .attach_printable("SFTP upload task failed")?
+    }
+
+    /// Aborts the upload, removing the partially written file.
+    pub async fn abort(self) -> CustomResult<(), SftpError> {
+        drop(self.sender);
+        match self.task.await {
+            // The upload task reports the upload as aborted when it stops receiving parts
+            // without being completed
+            Ok(Err(error)) if matches!(error.current_context(), SftpError::UploadAborted) => Ok(()),
+            Ok(result) => result,
+            Err(error) => Err(report!(error).change_context(SftpError::UploadFailed))
+                .attach_printable("SFTP upload task failed"),
+        }
+    }
+}
+
+fn upload_file_blocking(
+    config: &SftpConfig,
+    directory: &str,
+    name: &str,
+    mut receiver: tokio::sync::mpsc::Receiver<UploadMessage>,
+) -> CustomResult<(), SftpError> {
+    let session = connect(config)?;
+    let sftp = session
+        .sftp()
+        .change_context(SftpError::ConnectionFailed)
+        .attach_printable("Failed to start the SFTP subsystem")?;
+
+    let path = Path::new(directory).join(name);
+    let temporary_path = Path::new(directory).join(format!("{name}.part"));
+
+    let mut file = sftp
+        .create(&temporary_path)
+        .change_context(SftpError::UploadFailed)
+        .attach_printable_lazy(|| format!("Failed to create {}", temporary_path.display()))?;
+    let is_complete = loop {
+        match receiver.blocking_recv() {
+            Some(UploadMessage::Part(part)) => {
+                if let Err(error) = file.write_all(&part) {
+                    drop(file);
+                    let _ = sftp.unlink(&temporary_path);
+                    return Err(report!(error))
+                        .change_context(SftpError::UploadFailed)
+                        .attach_printable_lazy(|| {
+                            format!("Failed to write {}", temporary_path.display())
+                        });
+                }
+            }
+            Some(UploadMessage::Complete) => break true,
+            None => break false,
+        }
+    };
+    drop(file);
+
+    if !is_complete {
+        let _ = sftp.unlink(&temporary_path);
+        let _ = session.disconnect(None, "Upload aborted", None);
+        return Err(report!(SftpError::UploadAborted));
+    }
+
+    let flags = RenameFlags::OVERWRITE | RenameFlags::ATOMIC | RenameFlags::NATIVE;
+    if sftp.rename(&temporary_path, &path, Some(flags)).is_err() {
+        // Servers on version 3 of the protocol do not overwrite files on rename
+        let _ = sftp.unlink(&path);
+        sftp.rename(&temporary_path, &path, Some(flags))
+            .change_context(SftpError::UploadFailed)
+            .attach_printable_lazy(|| format!("Failed to rename to {}", path.display()))?;
+    }
+
+    let _ = session.disconnect(None, "Upload complete", None);
+    Ok(())
+}
+
+fn connect(config: &SftpConfig) -> CustomResult<Session, SftpError> {
+    let address = resolve_public_address(&config.host, config.port)?;
+    let stream = TcpStream::connect_timeout(&address, TIMEOUT)
+        .change_context(SftpError::ConnectionFailed)?;
+
+    let mut session = Session::new().change_context(SftpError::ConnectionFailed)?;
+    session.set_timeout(u32::try_from(TIMEOUT.as_millis()).unwrap_or(u32::MAX));
+    session.set_tcp_stream(stream);
+    session
+        .handshake()
+        .change_context(SftpError::ConnectionFailed)
+        .attach_printable("SSH handshake failed")?;
+
+    verify_host_key(&session, &config.host_key_fingerprint)?;
+
+    let authentication = match (&config.private_key, &config.password) {
+        (Some(private_key), _) => {
+            session.userauth_pubkey_memory(&config.username, None, private_key.peek(), None)
+        }
+        (None, Some(password)) => session.userauth_password(&config.username, password.peek()),
+        (None, None) => {
+            return Err(report!(SftpError::AuthenticationFailed))
+                .attach_printable("Neither a password nor a private key is configured")
+        }
+    };
+    authentication.change_context(SftpError::AuthenticationFailed)?;
+
+    if !session.authenticated() {
+        return Err(report!(SftpError::AuthenticationFailed));
+    }
+
+    Ok(session)
+}
+
+/// Resolves the address of the server. The server is configured by merchants, so every address
+/// its host resolves to has to be public, so that exports cannot be used to reach internal
+/// services.
+fn resolve_public_address(host: &str, port: u16) -> CustomResult<SocketAddr, SftpError> {
+    let addresses = (host, port)
+        .to_socket_addrs()
+        .change_context(SftpError::ConnectionFailed)
+        .attach_printable("Failed to resolve the SFTP server address")?
+        .collect::<Vec<_>>();
+
+    if addresses
+        .iter()
+        .any(|address| !validation::is_public_ip_address(&address.ip()))
+    {
+        return Err(report!(SftpError::AddressNotAllowed));
+    }
+
+    addresses
+        .into_iter()
+        .next()
+        .ok_or(SftpError::ConnectionFailed)
+        .attach_printable("SFTP server address did not resolve")
+}
+
+fn verify_host_key(session: &Session, fingerprint: &str) -> CustomResult<(), SftpError> {
+    let host_key_hash = session
+        .host_key_hash(HashType::Sha256)
+        .ok_or(SftpError::HostKeyMismatch)
+        .attach_printable("SFTP server did not present a host key")?;
+    let expected_hash = decode_host_key_fingerprint(fingerprint)
+        .ok_or(SftpError::HostKeyMismatch)
+        .attach_printable("Invalid host key fingerprint")?;
+
+    if host_key_hash != expected_hash.as_slice() {
+        return Err(report!(SftpError::HostKeyMismatch));
+    }
+
+    Ok(())
+}
+
+/// Decodes a fingerprint in the `SHA256:<base64>` format, with or without padding
+fn decode_host_key_fingerprint(fingerprint: &str) -> Option<Vec<u8>> {
+    let encoded = fingerprint.strip_prefix("SHA256:")?.trim_end_matches('=');
+    base64::engine::general_purpose::STANDARD_NO_PAD
+        .decode(encoded)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_config(host: &str) -> SftpConfig {
+        SftpConfig {
+            host: host.to_string(),
+            port: DEFAULT_PORT,
+            username: "exports".to_string(),
+            password: Some(Secret::new("password".to_string())),
+            private_key: None,
+            host_key_fingerprint: "SHA256:nThbg6kXUpJWGl7E1IGOCspRomTxdCARLviKw6E5SY8".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_host_key_fingerprint_is_decoded_with_or_without_padding() {
+        let hash =
+            decode_host_key_fingerprint("SHA256:nThbg6kXUpJWGl7E1IGOCspRomTxdCARLviKw6E5SY8")
+                .unwrap();
+
+        assert_eq!(hash.len(), 32);
+        assert_eq!(
+            decode_host_key_fingerprint("SHA256:nThbg6kXUpJWGl7E1IGOCspRomTxdCARLviKw6E5SY8="),
+            Some(hash)
+        );
+    }
+
+    #[test]
+    fn test_invalid_host_key_fingerprints_are_rejected() {
+        assert_eq!(
+            decode_host_key_fingerprint("MD5:16:27:ac:a5:76:28:2d:36:63:1b:56:4d:eb:df:a6:48"),
+            None
+        );
+        assert_eq!(decode_host_key_fingerprint("SHA256:not base64!"), None);
+    }
+
+    #[test]
+    fn test_non_public_addresses_are_rejected() {
+        for host in [
+            "127.0.0.1",
+            "10.0.0.5",
+            "192.168.1.10",
+            "169.254.169.254",
+            "::1",
+            "fe80::1",
+        ] {
+            let error = resolve_public_address(host, DEFAULT_PORT).unwrap_err();
+
+            assert!(
+                matches!(error.current_context(), SftpError::AddressNotAllowed),
+                "{host} should not be allowed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_public_addresses_are_resolved() {
+        let address = resolve_public_address("93.184.215.14", 2222).unwrap();
+
+        assert_eq!(address, SocketAddr::from(([93, 184, 215, 14], 2222)));
+    }
+
+    #[tokio::test]
+    async fn test_upload_to_a_non_public_address_fails_without_connecting() {
+        let mut upload = start_upload(
+            get_config("127.0.0.1"),
+            "exports".to_string(),
+            "payments_2025-01-01.csv".to_string(),
+        );
+        // The upload task stops as soon as the address is rejected, the part may or may not have
+        // been queued by then
+        let _ = upload.write_part(b"payment_id\n".to_vec()).await;
+        let error = upload.complete().await.unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            SftpError::AddressNotAllowed
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_aborted_upload_reports_the_connection_error() {
+        let upload = start_upload(
+            get_config("10.0.0.5"),
+            "exports".to_string(),
+            "payments_2025-01-01.csv".to_string(),
+        );
+        let error = upload.abort().await.unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            SftpError::AddressNotAllowed
+        ));
+    }
+}
@@ -1567,6 +1567,12 @@ impl Profile {
             })
     }
 
+    pub fn get_outgoing_webhook_format(&self) -> Option<common_enums::OutgoingWebhookFormat> {
+        self.webhook_details
+            .as_ref()
+            .and_then(|details| details.webhook_format)
+    }
+
     pub fn get_billing_processor_id(
         &self,
     ) -> CustomResult<
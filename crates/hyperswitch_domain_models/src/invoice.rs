@@ -18,14 +18,14 @@ pub struct Invoice {
     pub subscription_id: common_utils::id_type::SubscriptionId,
     pub merchant_id: common_utils::id_type::MerchantId,
     pub profile_id: common_utils::id_type::ProfileId,
-    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub payment_intent_id: Option<common_utils::id_type::PaymentId>,
     pub payment_method_id: Option<String>,
     pub customer_id: common_utils::id_type::CustomerId,
     pub amount: MinorUnit,
     pub currency: String,
     pub status: common_enums::connector_enums::InvoiceStatus,
-    pub provider_name: common_enums::connector_enums::Connector,
+    pub provider_name: Option<common_enums::connector_enums::Connector>,
     pub metadata: Option<SecretSerdeValue>,
     pub connector_invoice_id: Option<common_utils::id_type::InvoiceId>,
 }
@@ -110,14 +110,14 @@ impl Invoice {
         subscription_id: common_utils::id_type::SubscriptionId,
         merchant_id: common_utils::id_type::MerchantId,
         profile_id: common_utils::id_type::ProfileId,
-        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
         payment_intent_id: Option<common_utils::id_type::PaymentId>,
         payment_method_id: Option<String>,
         customer_id: common_utils::id_type::CustomerId,
         amount: MinorUnit,
         currency: String,
         status: common_enums::connector_enums::InvoiceStatus,
-        provider_name: common_enums::connector_enums::Connector,
+        provider_name: Option<common_enums::connector_enums::Connector>,
         metadata: Option<SecretSerdeValue>,
         connector_invoice_id: Option<common_utils::id_type::InvoiceId>,
     ) -> Self {
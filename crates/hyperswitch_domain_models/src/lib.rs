@@ -41,6 +41,7 @@ pub mod router_request_types;
 pub mod router_response_types;
 pub mod routing;
 pub mod subscription;
+pub mod subscription_plan;
 #[cfg(feature = "tokenization_v2")]
 pub mod tokenization;
 pub mod transformers;
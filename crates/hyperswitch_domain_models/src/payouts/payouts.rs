@@ -39,6 +39,13 @@ pub trait PayoutsInterface {
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Option<Payouts>, Self::Error>;
 
+    async fn filter_payouts_by_merchant_id_payout_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payout_ids: &[id_type::PayoutId],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, Self::Error>;
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
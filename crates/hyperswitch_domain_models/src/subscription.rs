@@ -73,6 +73,9 @@ pub struct Subscription {
     pub merchant_reference_id: Option<String>,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub trial_end: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -139,6 +142,9 @@ impl super::behaviour::Conversion for Subscription {
             merchant_reference_id: self.merchant_reference_id,
             plan_id: self.plan_id,
             item_price_id: self.item_price_id,
+            current_period_start: self.current_period_start,
+            current_period_end: self.current_period_end,
+            trial_end: self.trial_end,
         })
     }
 
@@ -168,6 +174,9 @@ impl super::behaviour::Conversion for Subscription {
             merchant_reference_id: item.merchant_reference_id,
             plan_id: item.plan_id,
             item_price_id: item.item_price_id,
+            current_period_start: item.current_period_start,
+            current_period_end: item.current_period_end,
+            trial_end: item.trial_end,
         })
     }
 
@@ -232,6 +241,9 @@ pub struct SubscriptionUpdate {
     pub modified_at: PrimitiveDateTime,
     pub plan_id: Option<String>,
     pub item_price_id: Option<String>,
+    pub current_period_start: Option<PrimitiveDateTime>,
+    pub current_period_end: Option<PrimitiveDateTime>,
+    pub trial_end: Option<PrimitiveDateTime>,
 }
 
 impl SubscriptionUpdate {
@@ -249,12 +261,29 @@ impl SubscriptionUpdate {
             modified_at: common_utils::date_time::now(),
             plan_id,
             item_price_id,
+            current_period_start: None,
+            current_period_end: None,
+            trial_end: None,
         }
     }
 
     pub fn update_status(status: String) -> Self {
         Self::new(None, None, Some(status), None, None)
     }
+
+    pub fn update_billing_cycle(
+        status: String,
+        current_period_start: PrimitiveDateTime,
+        current_period_end: PrimitiveDateTime,
+        trial_end: Option<PrimitiveDateTime>,
+    ) -> Self {
+        Self {
+            current_period_start: Some(current_period_start),
+            current_period_end: Some(current_period_end),
+            trial_end,
+            ..Self::update_status(status)
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -270,6 +299,9 @@ impl super::behaviour::Conversion for SubscriptionUpdate {
             modified_at: self.modified_at,
             plan_id: self.plan_id,
             item_price_id: self.item_price_id,
+            current_period_start: self.current_period_start,
+            current_period_end: self.current_period_end,
+            trial_end: self.trial_end,
         })
     }
 
@@ -289,6 +321,9 @@ impl super::behaviour::Conversion for SubscriptionUpdate {
             modified_at: item.modified_at,
             plan_id: item.plan_id,
             item_price_id: item.item_price_id,
+            current_period_start: item.current_period_start,
+            current_period_end: item.current_period_end,
+            trial_end: item.trial_end,
         })
     }
 
@@ -300,6 +335,9 @@ impl super::behaviour::Conversion for SubscriptionUpdate {
             modified_at: self.modified_at,
             plan_id: self.plan_id,
             item_price_id: self.item_price_id,
+            current_period_start: self.current_period_start,
+            current_period_end: self.current_period_end,
+            trial_end: self.trial_end,
         })
     }
 }
@@ -0,0 +1,46 @@
+use common_utils::errors::CustomResult;
+use diesel_models::subscription_plan as storage;
+pub use diesel_models::subscription_plan::{
+    SubscriptionPlan, SubscriptionPlanNew, SubscriptionPlanPrice, SubscriptionPlanPriceNew,
+};
+
+/// Catalog of plans and prices of the profiles billed by the native billing engine
+#[async_trait::async_trait]
+pub trait SubscriptionPlanInterface {
+    type Error;
+    async fn insert_subscription_plan(
+        &self,
+        plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, Self::Error>;
+
+    async fn insert_subscription_plan_price(
+        &self,
+        price: storage::SubscriptionPlanPriceNew,
+    ) -> CustomResult<storage::SubscriptionPlanPrice, Self::Error>;
+
+    async fn find_subscription_plan_by_profile_id_plan_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, Self::Error>;
+
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, Self::Error>;
+
+    async fn list_subscription_plan_prices_by_profile_id_plan_ids(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlanPrice>, Self::Error>;
+
+    async fn find_subscription_plan_price_by_profile_id_price_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        price_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionPlanPrice>, Self::Error>;
+}
@@ -129,7 +129,7 @@ pub trait ApiClientWrapper: Send + Sync {
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
-#[instrument(skip_all, fields(connector_name, payment_method))]
+#[instrument(skip_all, fields(connector_name, payment_method, connector_error))]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -379,6 +379,10 @@ where
 
                                     let error = match body.status_code {
                                         500..=511 => {
+                                            // Traces of connector server errors are always kept
+                                            // by tail sampling
+                                            tracing::Span::current()
+                                                .record("connector_error", true);
                                             let error_res = connector_integration
                                                 .get_5xx_error_response(
                                                     body,
@@ -413,6 +417,7 @@ where
                             Ok(response)
                         }
                         Err(error) => {
+                            tracing::Span::current().record("connector_error", true);
                             connector_event.set_error(json!({"error": error.to_string()}));
                             state.event_handler().log_connector_event(&connector_event);
                             if error.current_context().is_upstream_timeout() {
@@ -67,7 +67,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
         (name = "Authentication", description = "Create and manage authentication"),
-        (name = "Subscriptions", description = "Subscription management and billing endpoints")
+        (name = "Subscriptions", description = "Subscription management and billing endpoints"),
+        (name = "Email", description = "Manage email delivery")
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -125,6 +126,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
 
+        // Routes for email
+        routes::email::record_email_delivery_event,
+
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
@@ -157,6 +161,9 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for disputes
         routes::disputes::retrieve_dispute,
         routes::disputes::retrieve_disputes_list,
+        routes::disputes::generate_dispute_evidence_bundle,
+        routes::disputes::retrieve_dispute_automation_config,
+        routes::disputes::update_dispute_automation_config,
 
         // Routes for routing
         routes::routing::routing_create_config,
@@ -196,6 +203,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payout_batch_create,
+        routes::payouts::payout_batch_retrieve,
+        routes::payouts::payout_batch_items_list,
+        routes::payouts::payout_batch_cancel,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -240,6 +251,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::subscriptions::get_subscription,
         routes::subscriptions::update_subscription,
         routes::subscriptions::get_subscription_plans,
+        routes::subscriptions::create_subscription_plan,
         routes::subscriptions::get_estimate,
         routes::subscriptions::pause_subscription,
         routes::subscriptions::resume_subscription,
@@ -454,12 +466,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::DisputeEvidenceBundleResponse,
+        api_models::disputes::DisputeEvidenceTemplate,
+        api_models::disputes::DisputeAutomationConfig,
+        api_models::disputes::DisputeAutoAcceptRule,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
         api_models::gsm::GsmDeleteRequest,
         api_models::gsm::GsmDeleteResponse,
         api_models::gsm::GsmResponse,
+        api_models::email::SnsMessage,
+        api_models::email::SnsMessageType,
+        api_models::email::EmailDeliveryEventResponse,
+        api_models::enums::EmailOutboxStatus,
         api_models::enums::GsmDecision,
         api_models::enums::GsmFeature,
         common_types::domain::GsmFeatureData,
@@ -726,6 +746,10 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutListFilters,
         api_models::payouts::PayoutListFilterConstraints,
         api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
+        api_models::payouts::PayoutBatchItemResponse,
+        api_models::payouts::PayoutBatchItemsResponse,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutMethodData,
         api_models::payouts::Passthrough,
@@ -737,6 +761,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchItemStatus,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -825,6 +851,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::OutgoingWebhookFormat,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::CustomerDetailsResponse,
@@ -964,6 +991,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::subscription::CreateSubscriptionPaymentDetails,
         api_models::subscription::SubscriptionLineItem,
         api_models::subscription::SubscriptionPlanPrices,
+        api_models::subscription::CreateSubscriptionPlanRequest,
+        api_models::subscription::CreateSubscriptionPlanPriceRequest,
         api_models::subscription::PaymentResponseData,
         api_models::subscription::Invoice,
         api_models::enums::SubscriptionStatus,
@@ -5,6 +5,7 @@ pub mod authentication;
 pub mod blocklist;
 pub mod customers;
 pub mod disputes;
+pub mod email;
 pub mod gsm;
 pub mod mandates;
 pub mod merchant_account;
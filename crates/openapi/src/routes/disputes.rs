@@ -70,3 +70,51 @@ pub async fn retrieve_disputes_list() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_disputes_list_profile() {}
+
+/// Disputes - Generate Evidence Bundle
+/// Generates a ZIP bundle with a PDF summary and a JSON copy of the evidence the router holds for the disputed payment, picked according to the reason code of the dispute. The bundle can be downloaded through the files API.
+#[utoipa::path(
+    post,
+    path = "/disputes/evidence/{dispute_id}/bundle",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute"),
+    ),
+    responses(
+        (status = 200, description = "The dispute evidence bundle was generated successfully", body = DisputeEvidenceBundleResponse),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Generate a Dispute Evidence Bundle",
+    security(("api_key" = []))
+)]
+pub async fn generate_dispute_evidence_bundle() {}
+
+/// Disputes - Retrieve Automation Config
+/// Retrieves the rules the router applies to open disputes of the merchant, the deadline reminder and the rules for accepting disputes automatically.
+#[utoipa::path(
+    get,
+    path = "/disputes/automation_config",
+    responses(
+        (status = 200, description = "The dispute automation config was retrieved successfully", body = DisputeAutomationConfig),
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve the Dispute Automation Config",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_dispute_automation_config() {}
+
+/// Disputes - Update Automation Config
+/// Replaces the rules the router applies to open disputes of the merchant. Open disputes matching any of the auto accept rules are accepted at the connector without merchant intervention, including disputes that are already open when the rules are updated.
+#[utoipa::path(
+    post,
+    path = "/disputes/automation_config",
+    request_body = DisputeAutomationConfig,
+    responses(
+        (status = 200, description = "The dispute automation config was updated successfully", body = DisputeAutomationConfig),
+        (status = 400, description = "Invalid dispute automation config")
+    ),
+    tag = "Disputes",
+    operation_id = "Update the Dispute Automation Config",
+    security(("api_key" = []))
+)]
+pub async fn update_dispute_automation_config() {}
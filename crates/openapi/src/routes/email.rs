@@ -0,0 +1,19 @@
+/// Email - Record Delivery Event
+///
+/// Records the bounces and complaints Amazon SES publishes to the configured SNS topic for emails sent through the email outbox. No further emails are sent to a recipient with a recorded bounce or complaint. The request is authenticated by its SNS signature rather than an API key, and subscription confirmations from the topic are confirmed.
+#[utoipa::path(
+    post,
+    path = "/email/events",
+    request_body(
+        content = SnsMessage,
+        description = "Message published by Amazon SES to the configured SNS topic, delivered by SNS as `text/plain`",
+    ),
+    responses(
+        (status = 200, description = "Delivery events recorded for the recipients of sent emails", body = Vec<EmailDeliveryEventResponse>),
+        (status = 400, description = "Invalid SNS message or SES notification"),
+        (status = 401, description = "Message is not from the configured topic or its SNS signature is invalid")
+    ),
+    tag = "Email",
+    operation_id = "Record Email Delivery Event",
+)]
+pub async fn record_email_delivery_event() {}
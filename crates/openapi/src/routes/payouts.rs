@@ -190,3 +190,72 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Missing Mandatory fields")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_create() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_retrieve() {}
+
+/// Payouts - Batch Items List
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}/items",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch"),
+        ("status" = Option<PayoutBatchItemStatus>, Query, description = "Only return items in this status"),
+        ("limit" = Option<u32>, Query, description = "limit on the number of objects to return"),
+        ("offset" = Option<u32>, Query, description = "The number of objects to skip, for pagination")
+    ),
+    responses(
+        (status = 200, description = "Payout batch items listed", body = PayoutBatchItemsResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "List the items of a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_items_list() {}
+
+/// Payouts - Batch Cancel
+#[utoipa::path(
+    post,
+    path = "/payouts/batch/{batch_id}/cancel",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the payout batch")
+    ),
+    responses(
+        (status = 200, description = "Pending items of the payout batch cancelled", body = PayoutBatchResponse),
+        (status = 400, description = "Payout batch cannot be cancelled")
+    ),
+    tag = "Payouts",
+    operation_id = "Cancel a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payout_batch_cancel() {}
@@ -290,6 +290,46 @@ pub async fn update_subscription() {}
 )]
 pub async fn get_subscription_plans() {}
 
+/// Subscription - Create Plan
+///
+/// Adds a plan to the catalog of a profile billed natively, that is a profile without a billing
+/// processor.
+#[utoipa::path(
+    post,
+    path = "/subscriptions/plans",
+    request_body(
+        content = CreateSubscriptionPlanRequest,
+        examples((
+            "Create plan" = (
+                value = json!({
+                    "name": "Standard",
+                    "description": "Standard plan",
+                    "prices": [{
+                        "amount": 1000,
+                        "currency": "USD",
+                        "interval": "Month",
+                        "interval_count": 1,
+                        "trial_period": 7,
+                        "trial_period_unit": "Day"
+                    }]
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Subscription plan created successfully", body = GetPlansResponse),
+        (status = 400, description = "Invalid plan data"),
+        (status = 412, description = "Profile has a billing processor")
+    ),
+    params(
+        ("X-Profile-Id" = String, Header, description = "Profile ID for authentication")
+    ),
+    tag = "Subscriptions",
+    operation_id = "Create Subscription Plan",
+    security(("api_key" = []))
+)]
+pub async fn create_subscription_plan() {}
+
 /// Subscription - Get Estimate
 ///
 /// Gets pricing estimate for a subscription.
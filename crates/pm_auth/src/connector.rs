@@ -1,3 +1,6 @@
+#[cfg(test)]
+pub mod mock_aggregator;
 pub mod plaid;
+pub mod truelayer;
 
-pub use self::plaid::Plaid;
+pub use self::{plaid::Plaid, truelayer::Truelayer};
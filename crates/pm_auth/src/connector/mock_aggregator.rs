@@ -0,0 +1,667 @@
+use common_utils::{
+    ext_traits::BytesExt,
+    request::{Method, Request, RequestBuilder, RequestContent},
+};
+use error_stack::ResultExt;
+use masking::{Mask, Maskable, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors,
+    types::{
+        self as auth_types,
+        api::{
+            auth_service::{
+                self, BankAccountCredentials, ExchangeToken, LinkToken, RecipientCreate,
+            },
+            ConnectorCommon, ConnectorIntegration,
+        },
+    },
+};
+
+/// Aggregator with a minimal wire format, used to exercise the payment method auth flows
+/// without depending on the shape of a real provider
+#[derive(Debug, Clone)]
+pub struct MockAggregator;
+
+impl ConnectorCommon for MockAggregator {
+    fn id(&self) -> &'static str {
+        "mock_aggregator"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str {
+        "http://localhost:8080/mock_aggregator"
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &auth_types::ConnectorAuthType,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        match auth_type {
+            auth_types::ConnectorAuthType::BodyKey { client_id, secret } => Ok(vec![
+                ("X-Client-Id".to_string(), client_id.clone().into_masked()),
+                ("X-Secret".to_string(), secret.clone().into_masked()),
+            ]),
+            auth_types::ConnectorAuthType::NoKey => {
+                Err(errors::ConnectorError::FailedToObtainAuthType.into())
+            }
+        }
+    }
+
+    fn build_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        let response: MockErrorResponse = res
+            .response
+            .parse_struct("MockErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.code,
+            message: response.message,
+            reason: None,
+        })
+    }
+}
+
+impl auth_service::AuthService for MockAggregator {}
+impl auth_service::AuthServiceLinkToken for MockAggregator {}
+impl auth_service::AuthServiceExchangeToken for MockAggregator {}
+impl auth_service::AuthServiceBankAccountCredentials for MockAggregator {}
+impl auth_service::PaymentInitiation for MockAggregator {}
+impl auth_service::PaymentInitiationRecipientCreate for MockAggregator {}
+
+fn build_mock_request<T, Req, Resp>(
+    connector: &MockAggregator,
+    req: &auth_types::PaymentAuthRouterData<T, Req, Resp>,
+    connectors: &auth_types::PaymentMethodAuthConnectors,
+) -> errors::CustomResult<Option<Request>, errors::ConnectorError>
+where
+    MockAggregator: ConnectorIntegration<T, Req, Resp>,
+{
+    let mut headers = connector.get_auth_header(&req.connector_auth_type)?;
+    headers.push((
+        "Content-Type".to_string(),
+        connector.common_get_content_type().to_string().into(),
+    ));
+    Ok(Some(
+        RequestBuilder::new()
+            .method(Method::Post)
+            .url(&ConnectorIntegration::<T, Req, Resp>::get_url(
+                connector, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(headers)
+            .set_body(ConnectorIntegration::<T, Req, Resp>::get_request_body(
+                connector, req,
+            )?)
+            .build(),
+    ))
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockLinkTokenRequest {
+    pub client_name: String,
+    pub country_codes: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockLinkTokenResponse {
+    pub link_token: String,
+}
+
+impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
+    for MockAggregator
+{
+    fn get_url(
+        &self,
+        _req: &auth_types::LinkTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/link_token", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::LinkTokenRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        Ok(RequestContent::Json(Box::new(MockLinkTokenRequest {
+            client_name: req.request.client_name.clone(),
+            country_codes: req.request.country_codes.clone(),
+        })))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::LinkTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        build_mock_request(self, req, connectors)
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::LinkTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::LinkTokenRouterData, errors::ConnectorError> {
+        let response: MockLinkTokenResponse = res
+            .response
+            .parse_struct("MockLinkTokenResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::LinkTokenRouterData {
+            response: Ok(auth_types::LinkTokenResponse {
+                link_token: response.link_token,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockExchangeTokenRequest {
+    pub public_token: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockExchangeTokenResponse {
+    pub access_token: String,
+}
+
+impl
+    ConnectorIntegration<
+        ExchangeToken,
+        auth_types::ExchangeTokenRequest,
+        auth_types::ExchangeTokenResponse,
+    > for MockAggregator
+{
+    fn get_url(
+        &self,
+        _req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/exchange_token", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        Ok(RequestContent::Json(Box::new(MockExchangeTokenRequest {
+            public_token: req.request.public_token.clone(),
+        })))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        build_mock_request(self, req, connectors)
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::ExchangeTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ExchangeTokenRouterData, errors::ConnectorError> {
+        let response: MockExchangeTokenResponse = res
+            .response
+            .parse_struct("MockExchangeTokenResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::ExchangeTokenRouterData {
+            response: Ok(auth_types::ExchangeTokenResponse {
+                access_token: response.access_token,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockAccountsRequest {
+    pub access_token: Secret<String>,
+    pub account_ids: Option<Vec<Secret<String>>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockAccountsResponse {
+    pub accounts: Vec<MockAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockAccount {
+    pub account_id: String,
+    pub name: Option<String>,
+    pub iban: Secret<String>,
+}
+
+impl
+    ConnectorIntegration<
+        BankAccountCredentials,
+        auth_types::BankAccountCredentialsRequest,
+        auth_types::BankAccountCredentialsResponse,
+    > for MockAggregator
+{
+    fn get_url(
+        &self,
+        _req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/accounts", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        Ok(RequestContent::Json(Box::new(MockAccountsRequest {
+            access_token: req.request.access_token.clone(),
+            account_ids: req
+                .request
+                .optional_ids
+                .as_ref()
+                .map(|optional_ids| optional_ids.ids.clone()),
+        })))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        build_mock_request(self, req, connectors)
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankDetailsRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankDetailsRouterData, errors::ConnectorError> {
+        let response: MockAccountsResponse = res
+            .response
+            .parse_struct("MockAccountsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let credentials = response
+            .accounts
+            .into_iter()
+            .map(|account| auth_types::BankAccountDetails {
+                account_name: account.name,
+                account_details: auth_types::PaymentMethodTypeDetails::Sepa(
+                    auth_types::BankAccountDetailsSepa {
+                        iban: account.iban,
+                        bic: None,
+                    },
+                ),
+                payment_method_type: common_enums::PaymentMethodType::Sepa,
+                payment_method: common_enums::PaymentMethod::BankDebit,
+                account_id: account.account_id.into(),
+                account_type: None,
+                balance: None,
+            })
+            .collect();
+        Ok(auth_types::BankDetailsRouterData {
+            response: Ok(auth_types::BankAccountCredentialsResponse { credentials }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockRecipientCreateRequest {
+    pub name: String,
+    pub iban: Secret<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockRecipientCreateResponse {
+    pub recipient_id: String,
+}
+
+impl
+    ConnectorIntegration<
+        RecipientCreate,
+        auth_types::RecipientCreateRequest,
+        auth_types::RecipientCreateResponse,
+    > for MockAggregator
+{
+    fn get_url(
+        &self,
+        _req: &auth_types::RecipientCreateRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/recipients", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::RecipientCreateRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let iban = match &req.request.account_data {
+            auth_types::RecipientAccountData::Iban(iban)
+            | auth_types::RecipientAccountData::Sepa(iban)
+            | auth_types::RecipientAccountData::SepaInstant(iban) => iban.clone(),
+            auth_types::RecipientAccountData::Bacs { .. }
+            | auth_types::RecipientAccountData::FasterPayments { .. }
+            | auth_types::RecipientAccountData::Elixir { .. }
+            | auth_types::RecipientAccountData::Bankgiro(_)
+            | auth_types::RecipientAccountData::Plusgiro(_) => {
+                return Err(errors::ConnectorError::InvalidConnectorConfig {
+                    config: "Invalid payment method selected. Only Iban, Sepa Supported",
+                }
+                .into())
+            }
+        };
+        Ok(RequestContent::Json(Box::new(MockRecipientCreateRequest {
+            name: req.request.name.clone(),
+            iban,
+        })))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::RecipientCreateRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        build_mock_request(self, req, connectors)
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::RecipientCreateRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::RecipientCreateRouterData, errors::ConnectorError> {
+        let response: MockRecipientCreateResponse = res
+            .response
+            .parse_struct("MockRecipientCreateResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::RecipientCreateRouterData {
+            response: Ok(auth_types::RecipientCreateResponse {
+                recipient_id: response.recipient_id,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MockErrorResponse {
+    pub code: String,
+    pub message: String,
+}
+
+mod tests {
+    #![allow(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::indexing_slicing,
+        clippy::panic
+    )]
+
+    use std::marker::PhantomData;
+
+    use masking::PeekInterface;
+
+    use super::*;
+    use crate::connector::truelayer::{transformers as truelayer, Truelayer};
+
+    fn router_data<T, Req, Resp>(
+        request: Req,
+        response: Resp,
+    ) -> auth_types::PaymentAuthRouterData<T, Req, Resp> {
+        auth_types::PaymentAuthRouterData {
+            flow: PhantomData,
+            merchant_id: None,
+            connector: Some("mock_aggregator".to_string()),
+            request,
+            response: Ok(response),
+            connector_auth_type: auth_types::ConnectorAuthType::BodyKey {
+                client_id: Secret::new("client_id".to_string()),
+                secret: Secret::new("secret".to_string()),
+            },
+            connector_http_status_code: None,
+        }
+    }
+
+    /// Mirrors the response handling of the router's connector processing step
+    fn process<T: Clone, Req: Clone, Resp: Clone>(
+        connector: &dyn ConnectorIntegration<T, Req, Resp>,
+        data: &auth_types::PaymentAuthRouterData<T, Req, Resp>,
+        status_code: u16,
+        body: serde_json::Value,
+    ) -> auth_types::PaymentAuthRouterData<T, Req, Resp> {
+        let response = auth_types::Response {
+            headers: None,
+            response: serde_json::to_vec(&body).unwrap().into(),
+            status_code,
+        };
+        match status_code {
+            200..=299 => connector.handle_response(data, response).unwrap(),
+            _ => auth_types::PaymentAuthRouterData {
+                response: Err(connector.get_error_response(response).unwrap()),
+                ..data.clone()
+            },
+        }
+    }
+
+    fn bank_details_request(ids: Option<Vec<&str>>) -> auth_types::BankDetailsRouterData {
+        router_data(
+            auth_types::BankAccountCredentialsRequest {
+                access_token: Secret::new("access_token".to_string()),
+                optional_ids: ids.map(|ids| auth_types::BankAccountOptionalIDs {
+                    ids: ids
+                        .into_iter()
+                        .map(|id| Secret::new(id.to_string()))
+                        .collect(),
+                }),
+            },
+            auth_types::BankAccountCredentialsResponse {
+                credentials: Vec::new(),
+            },
+        )
+    }
+
+    #[test]
+    fn mock_aggregator_runs_account_linking_flows() {
+        let connectors = auth_types::PaymentMethodAuthConnectors::Plaid;
+
+        let link_token_data: auth_types::LinkTokenRouterData = router_data(
+            auth_types::LinkTokenRequest {
+                client_name: "HyperSwitch".to_string(),
+                country_codes: None,
+                language: None,
+                user_info: None,
+                client_platform: None,
+                android_package_name: None,
+                redirect_uri: None,
+            },
+            auth_types::LinkTokenResponse {
+                link_token: String::new(),
+            },
+        );
+        let request = auth_types::PaymentAuthLinkTokenType::build_request(
+            &MockAggregator,
+            &link_token_data,
+            &connectors,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            request.url,
+            "http://localhost:8080/mock_aggregator/link_token"
+        );
+        let link_token = process(
+            &MockAggregator,
+            &link_token_data,
+            200,
+            serde_json::json!({ "link_token": "link-sandbox-123" }),
+        )
+        .response
+        .unwrap();
+        assert_eq!(link_token.link_token, "link-sandbox-123");
+
+        let exchange_data: auth_types::ExchangeTokenRouterData = router_data(
+            auth_types::ExchangeTokenRequest {
+                public_token: "public-sandbox-123".to_string(),
+                redirect_uri: None,
+            },
+            auth_types::ExchangeTokenResponse {
+                access_token: String::new(),
+            },
+        );
+        let exchange = process(
+            &MockAggregator,
+            &exchange_data,
+            200,
+            serde_json::json!({ "access_token": "access-sandbox-123" }),
+        )
+        .response
+        .unwrap();
+        assert_eq!(exchange.access_token, "access-sandbox-123");
+
+        let credentials = process(
+            &MockAggregator,
+            &bank_details_request(None),
+            200,
+            serde_json::json!({
+                "accounts": [{
+                    "account_id": "acc_1",
+                    "name": "Current account",
+                    "iban": "DE89370400440532013000"
+                }]
+            }),
+        )
+        .response
+        .unwrap()
+        .credentials;
+        assert_eq!(credentials.len(), 1);
+        assert_eq!(
+            credentials[0].payment_method_type,
+            common_enums::PaymentMethodType::Sepa
+        );
+    }
+
+    #[test]
+    fn mock_aggregator_maps_error_responses() {
+        let recipient_data: auth_types::RecipientCreateRouterData = router_data(
+            auth_types::RecipientCreateRequest {
+                name: "merchant".to_string(),
+                account_data: auth_types::RecipientAccountData::Iban(Secret::new(
+                    "DE89370400440532013000".to_string(),
+                )),
+                address: None,
+            },
+            auth_types::RecipientCreateResponse {
+                recipient_id: String::new(),
+            },
+        );
+        let error = process(
+            &MockAggregator,
+            &recipient_data,
+            400,
+            serde_json::json!({ "code": "invalid_iban", "message": "IBAN is invalid" }),
+        )
+        .response
+        .unwrap_err();
+        assert_eq!(error.code, "invalid_iban");
+        assert_eq!(error.status_code, 400);
+    }
+
+    #[test]
+    fn truelayer_splits_accounts_into_bacs_and_sepa_details() {
+        let accounts = serde_json::json!({
+            "results": [
+                {
+                    "account_id": "acc_gb",
+                    "account_type": "TRANSACTION",
+                    "display_name": "Club Lloyds",
+                    "currency": "GBP",
+                    "account_number": {
+                        "iban": "GB35LOYD30963812345678",
+                        "number": "12345678",
+                        "sort_code": "30-96-38",
+                        "swift_bic": "LOYDGB21"
+                    }
+                },
+                {
+                    "account_id": "acc_other",
+                    "account_type": "SAVINGS",
+                    "account_number": { "iban": "DE89370400440532013000" }
+                }
+            ],
+            "status": "Succeeded"
+        });
+
+        let credentials = process(
+            &Truelayer,
+            &bank_details_request(Some(vec!["acc_gb"])),
+            200,
+            accounts,
+        )
+        .response
+        .unwrap()
+        .credentials;
+
+        assert_eq!(credentials.len(), 2);
+        match &credentials[0].account_details {
+            auth_types::PaymentMethodTypeDetails::Bacs(bacs) => {
+                assert_eq!(bacs.sort_code.peek(), "309638");
+            }
+            other => panic!("expected bacs details, got {other:?}"),
+        }
+        assert_eq!(credentials[0].account_type.as_deref(), Some("checking"));
+        assert_eq!(
+            credentials[1].payment_method_type,
+            common_enums::PaymentMethodType::Sepa
+        );
+    }
+
+    #[test]
+    fn truelayer_requires_redirect_uri_for_code_exchange() {
+        let exchange_data: auth_types::ExchangeTokenRouterData = router_data(
+            auth_types::ExchangeTokenRequest {
+                public_token: "code".to_string(),
+                redirect_uri: None,
+            },
+            auth_types::ExchangeTokenResponse {
+                access_token: String::new(),
+            },
+        );
+        let error = truelayer::TruelayerExchangeTokenRequest::try_from(&exchange_data)
+            .expect_err("redirect_uri is mandatory for TrueLayer");
+        assert_eq!(
+            error.current_context(),
+            &errors::ConnectorError::MissingRequiredField {
+                field_name: "redirect_uri"
+            }
+        );
+    }
+}
@@ -448,7 +448,7 @@ impl<F, T>
             let account_details =
                 types::PaymentMethodTypeDetails::Sepa(types::BankAccountDetailsSepa {
                     iban: Secret::new(sepa.iban),
-                    bic: Secret::new(sepa.bic),
+                    bic: Some(Secret::new(sepa.bic)),
                 });
 
             let bank_details_new = types::BankAccountDetails {
@@ -0,0 +1,304 @@
+use common_enums::{BankType, PaymentMethod, PaymentMethodType};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types};
+
+/// Data API scopes needed to read the account holder and account numbers
+const TRUELAYER_DATA_SCOPES: [&str; 3] = ["info", "accounts", "offline_access"];
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct TruelayerAuthLinkRequest {
+    response_type: &'static str,
+    client_id: Secret<String>,
+    redirect_uri: String,
+    scope: Vec<&'static str>,
+    providers: Vec<String>,
+}
+
+impl TryFrom<&types::LinkTokenRouterData> for TruelayerAuthLinkRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::LinkTokenRouterData) -> Result<Self, Self::Error> {
+        let auth = TruelayerAuthType::try_from(&item.connector_auth_type)?;
+        let providers = match item.request.country_codes.as_deref() {
+            Some(country_codes) if !country_codes.is_empty() => country_codes
+                .iter()
+                .flat_map(|country_code| get_truelayer_providers(country_code))
+                .collect(),
+            _ => get_truelayer_providers("GB"),
+        };
+
+        Ok(Self {
+            response_type: "code",
+            client_id: auth.client_id,
+            redirect_uri: item.request.redirect_uri.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "redirect_uri",
+                },
+            )?,
+            scope: TRUELAYER_DATA_SCOPES.to_vec(),
+            providers,
+        })
+    }
+}
+
+/// TrueLayer groups banks by country and access scheme, UK and Irish banks are reached through
+/// open banking APIs while the rest of Europe goes through PSD2 (XS2A) APIs
+fn get_truelayer_providers(country_code: &str) -> Vec<String> {
+    match country_code.to_uppercase().as_str() {
+        "GB" => vec!["uk-ob-all".to_string(), "uk-oauth-all".to_string()],
+        "IE" => vec!["ie-ob-all".to_string()],
+        other => vec![format!("{}-xs2a-all", other.to_lowercase())],
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+pub struct TruelayerAuthLinkResponse {
+    result: String,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, TruelayerAuthLinkResponse, T, types::LinkTokenResponse>>
+    for types::PaymentAuthRouterData<F, T, types::LinkTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, TruelayerAuthLinkResponse, T, types::LinkTokenResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::LinkTokenResponse {
+                link_token: item.response.result,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct TruelayerExchangeTokenRequest {
+    grant_type: &'static str,
+    client_id: Secret<String>,
+    client_secret: Secret<String>,
+    redirect_uri: String,
+    code: Secret<String>,
+}
+
+impl TryFrom<&types::ExchangeTokenRouterData> for TruelayerExchangeTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ExchangeTokenRouterData) -> Result<Self, Self::Error> {
+        let auth = TruelayerAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            grant_type: "authorization_code",
+            client_id: auth.client_id,
+            client_secret: auth.client_secret,
+            redirect_uri: item.request.redirect_uri.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "redirect_uri",
+                },
+            )?,
+            code: Secret::new(item.request.public_token.clone()),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct TruelayerExchangeTokenResponse {
+    pub access_token: Secret<String>,
+    pub expires_in: Option<i64>,
+    pub refresh_token: Option<Secret<String>>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            TruelayerExchangeTokenResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::ExchangeTokenResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            TruelayerExchangeTokenResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::ExchangeTokenResponse {
+                access_token: item.response.access_token.expose(),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct TruelayerAccountsResponse {
+    pub results: Vec<TruelayerAccount>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct TruelayerAccount {
+    pub account_id: String,
+    pub account_type: Option<TruelayerAccountType>,
+    pub display_name: Option<String>,
+    pub currency: Option<String>,
+    pub account_number: TruelayerAccountNumber,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TruelayerAccountType {
+    Transaction,
+    Savings,
+    BusinessTransaction,
+    BusinessSavings,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TruelayerAccountType {
+    fn get_bank_type(&self) -> Option<BankType> {
+        match self {
+            Self::Transaction | Self::BusinessTransaction => Some(BankType::Checking),
+            Self::Savings | Self::BusinessSavings => Some(BankType::Savings),
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct TruelayerAccountNumber {
+    pub iban: Option<Secret<String>>,
+    pub number: Option<Secret<String>>,
+    pub sort_code: Option<Secret<String>>,
+    pub swift_bic: Option<Secret<String>>,
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            TruelayerAccountsResponse,
+            types::BankAccountCredentialsRequest,
+            types::BankAccountCredentialsResponse,
+        >,
+    >
+    for types::PaymentAuthRouterData<
+        F,
+        types::BankAccountCredentialsRequest,
+        types::BankAccountCredentialsResponse,
+    >
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            TruelayerAccountsResponse,
+            types::BankAccountCredentialsRequest,
+            types::BankAccountCredentialsResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        // The accounts endpoint always lists every consented account, narrow it down here
+        let requested_ids = item.data.request.optional_ids.as_ref().map(|optional_ids| {
+            optional_ids
+                .ids
+                .iter()
+                .map(|id| id.peek().to_owned())
+                .collect::<Vec<_>>()
+        });
+
+        let mut bank_account_vec = Vec::new();
+
+        for account in item.response.results {
+            if requested_ids
+                .as_ref()
+                .is_some_and(|ids| !ids.contains(&account.account_id))
+            {
+                continue;
+            }
+
+            let account_type = account
+                .account_type
+                .as_ref()
+                .and_then(TruelayerAccountType::get_bank_type)
+                .map(|bank_type| bank_type.to_string());
+
+            let TruelayerAccountNumber {
+                iban,
+                number,
+                sort_code,
+                swift_bic,
+            } = account.account_number;
+
+            if let (Some(account_number), Some(sort_code)) = (number, sort_code) {
+                bank_account_vec.push(types::BankAccountDetails {
+                    account_name: account.display_name.clone(),
+                    account_details: types::PaymentMethodTypeDetails::Bacs(
+                        types::BankAccountDetailsBacs {
+                            account_number,
+                            sort_code: Secret::new(sort_code.peek().replace('-', "")),
+                        },
+                    ),
+                    payment_method_type: PaymentMethodType::Bacs,
+                    payment_method: PaymentMethod::BankDebit,
+                    account_id: account.account_id.clone().into(),
+                    account_type: account_type.clone(),
+                    balance: None,
+                });
+            }
+
+            if let Some(iban) = iban {
+                bank_account_vec.push(types::BankAccountDetails {
+                    account_name: account.display_name,
+                    account_details: types::PaymentMethodTypeDetails::Sepa(
+                        types::BankAccountDetailsSepa {
+                            iban,
+                            bic: swift_bic,
+                        },
+                    ),
+                    payment_method_type: PaymentMethodType::Sepa,
+                    payment_method: PaymentMethod::BankDebit,
+                    account_id: account.account_id.into(),
+                    account_type,
+                    balance: None,
+                });
+            }
+        }
+
+        Ok(Self {
+            response: Ok(types::BankAccountCredentialsResponse {
+                credentials: bank_account_vec,
+            }),
+            ..item.data
+        })
+    }
+}
+
+pub struct TruelayerAuthType {
+    pub client_id: Secret<String>,
+    pub client_secret: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for TruelayerAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { client_id, secret } => Ok(Self {
+                client_id: client_id.to_owned(),
+                client_secret: secret.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+pub struct TruelayerErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
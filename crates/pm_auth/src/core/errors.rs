@@ -12,6 +12,11 @@ pub enum ConnectorError {
     RequestEncodingFailed,
     #[error("Invalid connector configuration: {config}")]
     InvalidConnectorConfig { config: &'static str },
+    #[error("{message} is not supported by {connector}")]
+    NotSupported {
+        message: String,
+        connector: &'static str,
+    },
 }
 pub type CustomResult<T, E> = error_stack::Result<T, E>;
 
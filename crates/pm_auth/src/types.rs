@@ -41,6 +41,7 @@ pub type LinkTokenRouterData =
 #[derive(Debug, Clone)]
 pub struct ExchangeTokenRequest {
     pub public_token: String,
+    pub redirect_uri: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -107,7 +108,7 @@ pub struct BankAccountDetailsBacs {
 #[derive(Debug, Clone)]
 pub struct BankAccountDetailsSepa {
     pub iban: Secret<String>,
-    pub bic: Secret<String>,
+    pub bic: Option<Secret<String>>,
 }
 
 pub type BankDetailsRouterData = PaymentAuthRouterData<
@@ -178,6 +179,7 @@ pub type PaymentInitiationRecipientCreateType =
 #[strum(serialize_all = "snake_case")]
 pub enum PaymentMethodAuthConnectors {
     Plaid,
+    Truelayer,
 }
 
 #[derive(Debug, Clone)]
@@ -18,6 +18,7 @@ use fred::{
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         MultipleValues, RedisMap, RedisValue, ScanType, Scanner, SetOptions, XCap, XReadResponse,
+        XReadValue,
     },
 };
 use futures::StreamExt;
@@ -517,6 +518,34 @@ impl super::RedisConnectionPool {
             .await
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_hash_fields_without_modifying_ttl<V>(
+        &self,
+        key: &RedisKey,
+        values: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<RedisMap> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .hset(key.tenant_aware_key(self), values)
+            .await
+            .change_context(errors::RedisError::SetHashFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_fields(
+        &self,
+        key: &RedisKey,
+        fields: Vec<String>,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .hdel(key.tenant_aware_key(self), fields)
+            .await
+            .change_context(errors::RedisError::DeleteHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_hash_field_if_not_exist<V>(
         &self,
@@ -605,6 +634,41 @@ impl super::RedisConnectionPool {
         Ok(values_after_increment)
     }
 
+    /// Increments a field of a hash and sets the expiry of the hash if it has none, in a single
+    /// script so that the hash cannot be left without an expiry
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_field_in_hash_with_expiry<T>(
+        &self,
+        key: &RedisKey,
+        field: T,
+        increment: i64,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError>
+    where
+        T: Debug + ToString,
+    {
+        let lua_script = r#"
+        local value = redis.call("HINCRBY", KEYS[1], ARGV[1], ARGV[2])
+        if redis.call("TTL", KEYS[1]) == -1 then
+            redis.call("EXPIRE", KEYS[1], ARGV[3])
+        end
+        return value
+        "#;
+
+        self.pool
+            .eval(
+                lua_script,
+                vec![key.tenant_aware_key(self)],
+                vec![
+                    field.to_string(),
+                    increment.to_string(),
+                    seconds.to_string(),
+                ],
+            )
+            .await
+            .change_context(errors::RedisError::IncrementHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan(
         &self,
@@ -803,6 +867,23 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamAppendFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_append_entry_and_get_id<F>(
+        &self,
+        stream: &RedisKey,
+        entry_id: &RedisEntryId,
+        fields: F,
+    ) -> CustomResult<String, errors::RedisError>
+    where
+        F: TryInto<MultipleOrderedPairs> + Debug + Send + Sync,
+        F::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .xadd(stream.tenant_aware_key(self), false, None, entry_id, fields)
+            .await
+            .change_context(errors::RedisError::StreamAppendFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_delete_entries<Ids>(
         &self,
@@ -1086,6 +1167,34 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
 
+    /// Transfers the ownership of the pending entries of a consumer group that have been idle for
+    /// at least `min_idle_time` milliseconds to the consumer, starting from the entry `start`.
+    /// Returns the entry from which the next call is to start along with the claimed entries.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn consumer_group_auto_claim_entries(
+        &self,
+        stream: &RedisKey,
+        group: &str,
+        consumer: &str,
+        min_idle_time: u64,
+        start: &RedisEntryId,
+        count: Option<u64>,
+    ) -> CustomResult<(String, Vec<XReadValue<String, String, Option<String>>>), errors::RedisError>
+    {
+        self.pool
+            .xautoclaim_values(
+                stream.tenant_aware_key(self),
+                group,
+                consumer,
+                min_idle_time,
+                start,
+                count,
+                false,
+            )
+            .await
+            .change_context(errors::RedisError::ConsumerGroupClaimFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn evaluate_redis_script<V, T>(
         &self,
@@ -219,6 +219,20 @@ impl RedisConnectionPool {
         }
     }
 
+    /// Calls `on_reconnect` each time the subscriber reconnects to the server, so that the
+    /// messages published while it was disconnected can be accounted for.
+    pub async fn on_subscriber_reconnect<F, Fut>(&self, on_reconnect: F)
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let mut reconnect_rx = self.subscriber.reconnect_rx();
+        while let Ok(server) = reconnect_rx.recv().await {
+            tracing::info!(redis_server = ?server.host, "Redis subscriber reconnected");
+            on_reconnect().await;
+        }
+    }
+
     pub async fn on_unresponsive(&self) {
         let _ = self.pool.clients().iter().map(|client| {
             client.on_unresponsive(|server| {
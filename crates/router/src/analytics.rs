@@ -9,12 +9,14 @@ pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
         api_event::api_events_core, connector_events::connector_events_core, enums::AuthInfo,
-        errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
+        errors::AnalyticsError, opensearch::OpenSearchError,
         outgoing_webhook_event::outgoing_webhook_events_core, routing_events::routing_events_core,
-        sdk_events::sdk_events_core, AnalyticsFlow,
+        sdk_events::sdk_events_core, search::SearchProvider, AnalyticsFlow,
     };
     use api_models::analytics::{
+        alerts::{AlertHistoryRequest, CreateAlertRuleRequest, UpdateAlertRuleRequest},
         api_event::QueryType,
+        exports::{CreateExportRequest, ExportRunId, ExportRunListRequest, UpdateExportRequest},
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
@@ -22,10 +24,12 @@ pub mod routes {
         GetApiEventFiltersRequest, GetApiEventMetricRequest, GetAuthEventFilterRequest,
         GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
         GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
-        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetPaymentSessionFilterRequest,
+        GetPaymentSessionMetricRequest, GetPayoutFilterRequest, GetPayoutMetricRequest,
+        GetRefundFilterRequest, GetRefundMetricRequest, GetSdkEventFiltersRequest,
+        GetSdkEventMetricRequest, MetadataDimensionsRequest, ReportDownloadRequest, ReportRequest,
     };
-    use common_enums::EntityType;
+    use common_enums::{AnalyticsReportType, EntityType};
     use common_utils::types::TimeRange;
     use error_stack::{report, ResultExt};
     use futures::{stream::FuturesUnordered, StreamExt};
@@ -33,7 +37,10 @@ pub mod routes {
     use crate::{
         analytics_validator::request_validator,
         consts::opensearch::SEARCH_INDEXES,
-        core::{api_locking, errors::user::UserErrors, verification::utils},
+        core::{
+            analytics_alert, analytics_export, analytics_metadata, analytics_report, api_locking,
+            errors::user::UserErrors, verification::utils,
+        },
         db::{user::UserInterface, user_role::ListUserRolesByUserIdPayload},
         routes::AppState,
         services::{
@@ -70,6 +77,14 @@ pub mod routes {
                             web::resource("metrics/refunds")
                                 .route(web::post().to(get_merchant_refund_metrics)),
                         )
+                        .service(
+                            web::resource("metrics/payouts")
+                                .route(web::post().to(get_merchant_payout_metrics)),
+                        )
+                        .service(
+                            web::resource("metrics/payment_sessions")
+                                .route(web::post().to(get_merchant_payment_session_metrics)),
+                        )
                         .service(
                             web::resource("filters/payments")
                                 .route(web::post().to(get_merchant_payment_filters)),
@@ -85,6 +100,14 @@ pub mod routes {
                             web::resource("filters/refunds")
                                 .route(web::post().to(get_merchant_refund_filters)),
                         )
+                        .service(
+                            web::resource("filters/payouts")
+                                .route(web::post().to(get_merchant_payout_filters)),
+                        )
+                        .service(
+                            web::resource("filters/payment_sessions")
+                                .route(web::post().to(get_merchant_payment_session_filters)),
+                        )
                         .service(web::resource("{domain}/info").route(web::get().to(get_info)))
                         .service(
                             web::resource("report/dispute")
@@ -106,6 +129,14 @@ pub mod routes {
                             web::resource("report/authentications")
                                 .route(web::post().to(generate_merchant_authentication_report)),
                         )
+                        .service(
+                            web::resource("report/{report_id}")
+                                .route(web::get().to(get_merchant_report)),
+                        )
+                        .service(
+                            web::resource("report/{report_id}/download")
+                                .route(web::get().to(download_report)),
+                        )
                         .service(
                             web::resource("metrics/sdk_events")
                                 .route(web::post().to(get_sdk_event_metrics)),
@@ -195,6 +226,15 @@ pub mod routes {
                                     web::resource("metrics/refunds")
                                         .route(web::post().to(get_merchant_refund_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_merchant_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("metrics/payment_sessions").route(
+                                        web::post().to(get_merchant_payment_session_metrics),
+                                    ),
+                                )
                                 .service(
                                     web::resource("metrics/auth_events")
                                         .route(web::post().to(get_merchant_auth_event_metrics)),
@@ -211,6 +251,15 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_merchant_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_merchant_payout_filters)),
+                                )
+                                .service(
+                                    web::resource("filters/payment_sessions").route(
+                                        web::post().to(get_merchant_payment_session_filters),
+                                    ),
+                                )
                                 .service(
                                     web::resource("filters/auth_events")
                                         .route(web::post().to(get_merchant_auth_events_filters)),
@@ -239,6 +288,44 @@ pub mod routes {
                                         web::post().to(generate_merchant_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/{report_id}")
+                                        .route(web::get().to(get_merchant_report)),
+                                )
+                                .service(
+                                    web::resource("alert_rules")
+                                        .route(web::get().to(list_alert_rules))
+                                        .route(web::post().to(create_alert_rule)),
+                                )
+                                .service(
+                                    web::resource("alert_rules/{rule_id}")
+                                        .route(web::get().to(get_alert_rule))
+                                        .route(web::post().to(update_alert_rule))
+                                        .route(web::delete().to(delete_alert_rule)),
+                                )
+                                .service(
+                                    web::resource("alert_history")
+                                        .route(web::get().to(list_alert_history)),
+                                )
+                                .service(
+                                    web::resource("exports")
+                                        .route(web::get().to(list_exports))
+                                        .route(web::post().to(create_export)),
+                                )
+                                .service(
+                                    web::resource("exports/{export_id}")
+                                        .route(web::get().to(get_export))
+                                        .route(web::post().to(update_export))
+                                        .route(web::delete().to(delete_export)),
+                                )
+                                .service(
+                                    web::resource("exports/{export_id}/runs")
+                                        .route(web::get().to(list_export_runs)),
+                                )
+                                .service(
+                                    web::resource("exports/{export_id}/runs/{run_id}/retry")
+                                        .route(web::post().to(retry_export_run)),
+                                )
                                 .service(
                                     web::resource("metrics/api_events")
                                         .route(web::post().to(get_merchant_api_events_metrics)),
@@ -289,10 +376,26 @@ pub mod routes {
                                     web::resource("metrics/refunds")
                                         .route(web::post().to(get_org_refund_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_org_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("metrics/payment_sessions")
+                                        .route(web::post().to(get_org_payment_session_metrics)),
+                                )
                                 .service(
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_org_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_org_payout_filters)),
+                                )
+                                .service(
+                                    web::resource("filters/payment_sessions")
+                                        .route(web::post().to(get_org_payment_session_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_org_dispute_metrics)),
@@ -329,6 +432,10 @@ pub mod routes {
                                     web::resource("report/authentications")
                                         .route(web::post().to(generate_org_authentication_report)),
                                 )
+                                .service(
+                                    web::resource("report/{report_id}")
+                                        .route(web::get().to(get_org_report)),
+                                )
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_org_sankey)),
@@ -363,14 +470,35 @@ pub mod routes {
                                     web::resource("metrics/refunds")
                                         .route(web::post().to(get_profile_refund_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_profile_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("metrics/payment_sessions")
+                                        .route(web::post().to(get_profile_payment_session_metrics)),
+                                )
                                 .service(
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_profile_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_profile_payout_filters)),
+                                )
+                                .service(
+                                    web::resource("filters/payment_sessions")
+                                        .route(web::post().to(get_profile_payment_session_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_profile_dispute_metrics)),
                                 )
+                                .service(
+                                    web::resource("metadata_dimensions")
+                                        .route(web::get().to(get_profile_metadata_dimensions))
+                                        .route(web::post().to(update_profile_metadata_dimensions)),
+                                )
                                 .service(
                                     web::resource("metrics/auth_events")
                                         .route(web::post().to(get_profile_auth_event_metrics)),
@@ -416,6 +544,10 @@ pub mod routes {
                                         web::post().to(generate_profile_authentication_report),
                                     ),
                                 )
+                                .service(
+                                    web::resource("report/{report_id}")
+                                        .route(web::get().to(get_profile_report)),
+                                )
                                 .service(
                                     web::resource("api_event_logs")
                                         .route(web::get().to(get_profile_api_events)),
@@ -633,6 +765,13 @@ pub mod routes {
                     profile_ids: vec![profile_id.clone()],
                 };
 
+                analytics_metadata::validate_metadata_filters(
+                    &state,
+                    &profile_id,
+                    &req.filters.metadata,
+                )
+                .await?;
+
                 let validator_response = request_validator(
                     AnalyticsRequest {
                         payment_attempt: Some(req.clone()),
@@ -957,6 +1096,13 @@ pub mod routes {
                     profile_ids: vec![profile_id.clone()],
                 };
 
+                analytics_metadata::validate_metadata_filters(
+                    &state,
+                    &profile_id,
+                    &req.filters.metadata,
+                )
+                .await?;
+
                 let validator_response = request_validator(
                     AnalyticsRequest {
                         refund: Some(req.clone()),
@@ -980,11 +1126,11 @@ pub mod routes {
 
     /// # Panics
     ///
-    /// Panics if `json_payload` array does not contain one `GetFrmMetricRequest` element.
-    pub async fn get_frm_metrics(
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_merchant_payout_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetFrmMetricRequest; 1]>,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
     ) -> impl Responder {
         #[allow(clippy::expect_used)]
         // safety: This shouldn't panic owing to the data type
@@ -992,15 +1138,31 @@ pub mod routes {
             .into_inner()
             .to_vec()
             .pop()
-            .expect("Couldn't get GetFrmMetricRequest");
-        let flow = AnalyticsFlow::GetFrmMetrics;
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutsMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             payload,
             |state, auth: AuthenticationData, req, _| async move {
-                analytics::frm::get_metrics(&state.pool, auth.merchant_account.get_id(), req)
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1015,77 +1177,54 @@ pub mod routes {
     #[cfg(feature = "v1")]
     /// # Panics
     ///
-    /// Panics if `json_payload` array does not contain one `GetSdkEventMetricRequest` element.
-    pub async fn get_sdk_event_metrics(
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_org_payout_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetSdkEventMetricRequest; 1]>,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
         #[allow(clippy::expect_used)]
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetSdkEventMetricRequest");
-        let flow = AnalyticsFlow::GetSdkMetrics;
-        Box::pin(api::server_wrap(
-            flow,
-            state,
-            &req,
-            payload,
-            |state, auth: AuthenticationData, req, _| async move {
-                analytics::sdk_events::get_metrics(
-                    &state.pool,
-                    &auth.merchant_account.publishable_key,
-                    req,
-                )
-                .await
-                .map(ApplicationResponse::Json)
-            },
-            &auth::JWTAuth {
-                permission: Permission::MerchantAnalyticsRead,
-            },
-            api_locking::LockAction::NotApplicable,
-        ))
-        .await
-    }
-
-    #[cfg(feature = "v1")]
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetActivePaymentsMetricRequest` element.
-    pub async fn get_active_payments_metrics(
-        state: web::Data<AppState>,
-        req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetActivePaymentsMetricRequest; 1]>,
-    ) -> impl Responder {
         // safety: This shouldn't panic owing to the data type
-        #[allow(clippy::expect_used)]
         let payload = json_payload
             .into_inner()
             .to_vec()
             .pop()
-            .expect("Couldn't get GetActivePaymentsMetricRequest");
-        let flow = AnalyticsFlow::GetActivePaymentsMetrics;
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutsMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             payload,
             |state, auth: AuthenticationData, req, _| async move {
-                analytics::active_payments::get_metrics(
-                    &state.pool,
-                    &auth.merchant_account.publishable_key,
-                    auth.merchant_account.get_id(),
-                    req,
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
                 )
-                .await
-                .map(ApplicationResponse::Json)
-            },
-            &auth::JWTAuth {
-                permission: Permission::MerchantAnalyticsRead,
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -1094,20 +1233,20 @@ pub mod routes {
     #[cfg(feature = "v1")]
     /// # Panics
     ///
-    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
-    pub async fn get_merchant_auth_event_metrics(
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_profile_payout_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
         #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
         let payload = json_payload
             .into_inner()
             .to_vec()
             .pop()
-            .expect("Couldn't get GetAuthEventMetricRequest");
-        let flow = AnalyticsFlow::GetAuthMetrics;
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutsMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
@@ -1116,40 +1255,53 @@ pub mod routes {
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
                     org_id: org_id.clone(),
-                    merchant_ids: vec![merchant_id.clone()],
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
                 };
 
-                analytics::auth_events::get_metrics(&state.pool, &auth, req)
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::MerchantAnalyticsRead,
+                permission: Permission::ProfileAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
         .await
     }
 
-    #[cfg(feature = "v1")]
     /// # Panics
     ///
-    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
-    pub async fn get_profile_auth_event_metrics(
+    /// Panics if `json_payload` array does not contain one `GetPaymentSessionMetricRequest` element.
+    pub async fn get_merchant_payment_session_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
+        json_payload: web::Json<[GetPaymentSessionMetricRequest; 1]>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
         #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
         let payload = json_payload
             .into_inner()
             .to_vec()
             .pop()
-            .expect("Couldn't get GetAuthEventMetricRequest");
-        let flow = AnalyticsFlow::GetAuthMetrics;
+            .expect("Couldn't get GetPaymentSessionMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentSessionsMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
@@ -1158,21 +1310,16 @@ pub mod routes {
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let profile_id = auth
-                    .profile_id
-                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
-                    .change_context(AnalyticsError::AccessForbiddenError)?;
-                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
                     org_id: org_id.clone(),
-                    merchant_id: merchant_id.clone(),
-                    profile_ids: vec![profile_id.clone()],
+                    merchant_ids: vec![merchant_id.clone()],
                 };
-                analytics::auth_events::get_metrics(&state.pool, &auth, req)
+                analytics::payment_sessions::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileAnalyticsRead,
+                permission: Permission::MerchantAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -1182,20 +1329,20 @@ pub mod routes {
     #[cfg(feature = "v1")]
     /// # Panics
     ///
-    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
-    pub async fn get_org_auth_event_metrics(
+    /// Panics if `json_payload` array does not contain one `GetPaymentSessionMetricRequest` element.
+    pub async fn get_org_payment_session_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
+        json_payload: web::Json<[GetPaymentSessionMetricRequest; 1]>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
         #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
         let payload = json_payload
             .into_inner()
             .to_vec()
             .pop()
-            .expect("Couldn't get GetAuthEventMetricRequest");
-        let flow = AnalyticsFlow::GetAuthMetrics;
+            .expect("Couldn't get GetPaymentSessionMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentSessionsMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
@@ -1206,7 +1353,7 @@ pub mod routes {
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-                analytics::auth_events::get_metrics(&state.pool, &auth, req)
+                analytics::payment_sessions::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1225,56 +1372,75 @@ pub mod routes {
         .await
     }
 
-    pub async fn get_merchant_payment_filters(
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentSessionMetricRequest` element.
+    pub async fn get_profile_payment_session_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetPaymentFiltersRequest>,
+        json_payload: web::Json<[GetPaymentSessionMetricRequest; 1]>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetPaymentFilters;
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentSessionMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentSessionsMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            json_payload.into_inner(),
+            payload,
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
                     org_id: org_id.clone(),
-                    merchant_ids: vec![merchant_id.clone()],
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
                 };
-                analytics::payments::get_filters(&state.pool, req, &auth)
+                analytics::payment_sessions::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::MerchantAnalyticsRead,
+                permission: Permission::ProfileAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
         .await
     }
 
-    pub async fn get_merchant_auth_events_filters(
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetFrmMetricRequest` element.
+    pub async fn get_frm_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetAuthEventFilterRequest>,
+        json_payload: web::Json<[GetFrmMetricRequest; 1]>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetAuthEventFilters;
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetFrmMetricRequest");
+        let flow = AnalyticsFlow::GetFrmMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            json_payload.into_inner(),
+            payload,
             |state, auth: AuthenticationData, req, _| async move {
-                let org_id = auth.merchant_account.get_org_id();
-                let merchant_id = auth.merchant_account.get_id();
-
-                let auth: AuthInfo = AuthInfo::MerchantLevel {
-                    org_id: org_id.clone(),
-                    merchant_ids: vec![merchant_id.clone()],
-                };
-                analytics::auth_events::get_filters(&state.pool, req, &auth)
+                analytics::frm::get_metrics(&state.pool, auth.merchant_account.get_id(), req)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1287,54 +1453,148 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_org_auth_events_filters(
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetSdkEventMetricRequest` element.
+    pub async fn get_sdk_event_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetAuthEventFilterRequest>,
+        json_payload: web::Json<[GetSdkEventMetricRequest; 1]>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetAuthEventFilters;
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetSdkEventMetricRequest");
+        let flow = AnalyticsFlow::GetSdkMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            json_payload.into_inner(),
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::sdk_events::get_metrics(
+                    &state.pool,
+                    &auth.merchant_account.publishable_key,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetActivePaymentsMetricRequest` element.
+    pub async fn get_active_payments_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetActivePaymentsMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetActivePaymentsMetricRequest");
+        let flow = AnalyticsFlow::GetActivePaymentsMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::active_payments::get_metrics(
+                    &state.pool,
+                    &auth.merchant_account.publishable_key,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
+    pub async fn get_merchant_auth_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetAuthEventMetricRequest");
+        let flow = AnalyticsFlow::GetAuthMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
-                let auth: AuthInfo = AuthInfo::OrgLevel {
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
                     org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
                 };
 
-                analytics::auth_events::get_filters(&state.pool, req, &auth)
+                analytics::auth_events::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
-            auth::auth_type(
-                &auth::PlatformOrgAdminAuth {
-                    is_admin_auth_allowed: false,
-                    organization_id: None,
-                },
-                &auth::JWTAuth {
-                    permission: Permission::OrganizationAnalyticsRead,
-                },
-                req.headers(),
-            ),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
             api_locking::LockAction::NotApplicable,
         ))
         .await
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_profile_auth_events_filters(
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
+    pub async fn get_profile_auth_event_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetAuthEventFilterRequest>,
+        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetAuthEventFilters;
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetAuthEventMetricRequest");
+        let flow = AnalyticsFlow::GetAuthMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            json_payload.into_inner(),
+            payload,
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
@@ -1342,13 +1602,12 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-
                 let auth: AuthInfo = AuthInfo::ProfileLevel {
                     org_id: org_id.clone(),
                     merchant_id: merchant_id.clone(),
                     profile_ids: vec![profile_id.clone()],
                 };
-                analytics::auth_events::get_filters(&state.pool, req, &auth)
+                analytics::auth_events::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1361,23 +1620,33 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_org_payment_filters(
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
+    pub async fn get_org_auth_event_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetPaymentFiltersRequest>,
+        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetPaymentFilters;
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetAuthEventMetricRequest");
+        let flow = AnalyticsFlow::GetAuthMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            json_payload.into_inner(),
+            payload,
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-                analytics::payments::get_filters(&state.pool, req, &auth)
+                analytics::auth_events::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1396,8 +1665,7 @@ pub mod routes {
         .await
     }
 
-    #[cfg(feature = "v1")]
-    pub async fn get_profile_payment_filters(
+    pub async fn get_merchant_payment_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<GetPaymentFiltersRequest>,
@@ -1411,82 +1679,42 @@ pub mod routes {
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let profile_id = auth
-                    .profile_id
-                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
-                    .change_context(AnalyticsError::AccessForbiddenError)?;
-                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
                     org_id: org_id.clone(),
-                    merchant_id: merchant_id.clone(),
-                    profile_ids: vec![profile_id.clone()],
+                    merchant_ids: vec![merchant_id.clone()],
                 };
                 analytics::payments::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileAnalyticsRead,
+                permission: Permission::MerchantAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
         .await
     }
 
-    #[cfg(feature = "v1")]
-    pub async fn get_payment_intents_filters(
+    pub async fn get_merchant_auth_events_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetPaymentIntentFiltersRequest>,
+        json_payload: web::Json<GetAuthEventFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetPaymentIntentFilters;
+        let flow = AnalyticsFlow::GetAuthEventFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
-                analytics::payment_intents::get_filters(
-                    &state.pool,
-                    req,
-                    auth.merchant_account.get_id(),
-                )
-                .await
-                .map(ApplicationResponse::Json)
-            },
-            auth::auth_type(
-                &auth::PlatformOrgAdminAuth {
-                    is_admin_auth_allowed: false,
-                    organization_id: None,
-                },
-                &auth::JWTAuth {
-                    permission: Permission::MerchantAnalyticsRead,
-                },
-                req.headers(),
-            ),
-            api_locking::LockAction::NotApplicable,
-        ))
-        .await
-    }
-
-    pub async fn get_merchant_refund_filters(
-        state: web::Data<AppState>,
-        req: actix_web::HttpRequest,
-        json_payload: web::Json<GetRefundFilterRequest>,
-    ) -> impl Responder {
-        let flow = AnalyticsFlow::GetRefundFilters;
-        Box::pin(api::server_wrap(
-            flow,
-            state,
-            &req,
-            json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
+
                 let auth: AuthInfo = AuthInfo::MerchantLevel {
                     org_id: org_id.clone(),
                     merchant_ids: vec![merchant_id.clone()],
                 };
-                analytics::refunds::get_filters(&state.pool, req, &auth)
+                analytics::auth_events::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1499,23 +1727,24 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_org_refund_filters(
+    pub async fn get_org_auth_events_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetRefundFilterRequest>,
+        json_payload: web::Json<GetAuthEventFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetRefundFilters;
+        let flow = AnalyticsFlow::GetAuthEventFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+            |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-                analytics::refunds::get_filters(&state.pool, req, &auth)
+
+                analytics::auth_events::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1535,18 +1764,229 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_profile_refund_filters(
+    pub async fn get_profile_auth_events_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetRefundFilterRequest>,
+        json_payload: web::Json<GetAuthEventFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetRefundFilters;
+        let flow = AnalyticsFlow::GetAuthEventFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::auth_events::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_payment_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payments::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_payment_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payments::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_payment_intents_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentIntentFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentIntentFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::payment_intents::get_filters(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.get_id(),
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::MerchantAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_refund_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRefundFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRefundFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::refunds::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_refund_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRefundFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRefundFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::refunds::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_refund_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRefundFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRefundFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
                 let profile_id = auth
@@ -1570,24 +2010,403 @@ pub mod routes {
         .await
     }
 
-    pub async fn get_frm_filters(
+    pub async fn get_merchant_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_payment_session_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentSessionFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentSessionFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPaymentSessionFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payment_sessions::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_payment_session_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentSessionFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentSessionFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPaymentSessionFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payment_sessions::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_payment_session_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentSessionFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentSessionFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetPaymentSessionFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payment_sessions::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_frm_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetFrmFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetFrmFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetFrmFilterRequest, _| async move {
+                analytics::frm::get_filters(&state.pool, req, auth.merchant_account.get_id())
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_sdk_event_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetSdkEventFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetSdkEventFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::sdk_events::get_filters(
+                    &state.pool,
+                    req,
+                    &auth.merchant_account.publishable_key,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_profile_api_events(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Query<api_models::analytics::api_event::ApiLogsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetApiEvents;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let payment_id = match req.query_param.clone() {
+                    QueryType::Payment { payment_id } => payment_id,
+                    QueryType::Refund { payment_id, .. } => payment_id,
+                    QueryType::Dispute { payment_id, .. } => payment_id,
+                };
+                utils::check_if_profile_id_is_present_in_payment_intent(payment_id, &state, &auth)
+                    .await
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                api_events_core(&state.pool, req, auth.merchant_account.get_id())
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_profile_outgoing_webhook_events(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Query<
+            api_models::analytics::outgoing_webhook_event::OutgoingWebhookLogsRequest,
+        >,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetOutgoingWebhookEvents;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                utils::check_if_profile_id_is_present_in_payment_intent(
+                    req.payment_id.clone(),
+                    &state,
+                    &auth,
+                )
+                .await
+                .change_context(AnalyticsError::AccessForbiddenError)?;
+                outgoing_webhook_events_core(&state.pool, req, auth.merchant_account.get_id())
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_sdk_events(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::analytics::sdk_events::SdkEventsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetSdkEvents;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                utils::check_if_profile_id_is_present_in_payment_intent(
+                    req.payment_id.clone(),
+                    &state,
+                    &auth,
+                )
+                .await
+                .change_context(AnalyticsError::AccessForbiddenError)?;
+                sdk_events_core(&state.pool, req, &auth.merchant_account.publishable_key)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn generate_merchant_refund_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetFrmFilterRequest>,
+        json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetFrmFilters;
+        let flow = AnalyticsFlow::GenerateRefundReport;
         Box::pin(api::server_wrap(
             flow,
-            state,
+            state.clone(),
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetFrmFilterRequest, _| async move {
-                analytics::frm::get_filters(&state.pool, req, auth.merchant_account.get_id())
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
+                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
                     .await
-                    .map(ApplicationResponse::Json)
+                    .change_context(AnalyticsError::UnknownError)?;
+
+                let user_email = UserEmail::from_pii_email(user.email)
+                    .change_context(AnalyticsError::UnknownError)?
+                    .get_secret();
+
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let lambda_req = GenerateReportRequest {
+                    request: payload,
+                    merchant_id: Some(merchant_id.clone()),
+                    auth: AuthInfo::MerchantLevel {
+                        org_id: org_id.clone(),
+                        merchant_ids: vec![merchant_id.clone()],
+                    },
+                    email: user_email,
+                };
+
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Refunds,
+                    lambda_req,
+                    &user_id,
+                )
+                .await
             },
             &auth::JWTAuth {
-                permission: Permission::MerchantAnalyticsRead,
+                permission: Permission::MerchantReportRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -1595,93 +2414,194 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_sdk_event_filters(
+    pub async fn generate_org_refund_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetSdkEventFiltersRequest>,
+        json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetSdkEventFilters;
+        let flow = AnalyticsFlow::GenerateRefundReport;
         Box::pin(api::server_wrap(
             flow,
-            state,
+            state.clone(),
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
-                analytics::sdk_events::get_filters(
-                    &state.pool,
-                    req,
-                    &auth.merchant_account.publishable_key,
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
+                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
+                    .await
+                    .change_context(AnalyticsError::UnknownError)?;
+
+                let user_email = UserEmail::from_pii_email(user.email)
+                    .change_context(AnalyticsError::UnknownError)?
+                    .get_secret();
+
+                let org_id = auth.merchant_account.get_org_id();
+                let lambda_req = GenerateReportRequest {
+                    request: payload,
+                    merchant_id: None,
+                    auth: AuthInfo::OrgLevel {
+                        org_id: org_id.clone(),
+                    },
+                    email: user_email,
+                };
+
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Refunds,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::MerchantAnalyticsRead,
+                permission: Permission::OrganizationReportRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
         .await
     }
 
-    pub async fn get_profile_api_events(
+    #[cfg(feature = "v1")]
+    pub async fn generate_profile_refund_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Query<api_models::analytics::api_event::ApiLogsRequest>,
+        json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetApiEvents;
+        let flow = AnalyticsFlow::GenerateRefundReport;
         Box::pin(api::server_wrap(
             flow,
-            state,
+            state.clone(),
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
-                let payment_id = match req.query_param.clone() {
-                    QueryType::Payment { payment_id } => payment_id,
-                    QueryType::Refund { payment_id, .. } => payment_id,
-                    QueryType::Dispute { payment_id, .. } => payment_id,
-                };
-                utils::check_if_profile_id_is_present_in_payment_intent(payment_id, &state, &auth)
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
+                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
                     .await
+                    .change_context(AnalyticsError::UnknownError)?;
+
+                let user_email = UserEmail::from_pii_email(user.email)
+                    .change_context(AnalyticsError::UnknownError)?
+                    .get_secret();
+
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                api_events_core(&state.pool, req, auth.merchant_account.get_id())
-                    .await
-                    .map(ApplicationResponse::Json)
+                let lambda_req = GenerateReportRequest {
+                    request: payload,
+                    merchant_id: Some(merchant_id.clone()),
+                    auth: AuthInfo::ProfileLevel {
+                        org_id: org_id.clone(),
+                        merchant_id: merchant_id.clone(),
+                        profile_ids: vec![profile_id.clone()],
+                    },
+                    email: user_email,
+                };
+
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Refunds,
+                    lambda_req,
+                    &user_id,
+                )
+                .await
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileAnalyticsRead,
+                permission: Permission::ProfileReportRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
         .await
     }
-
-    pub async fn get_profile_outgoing_webhook_events(
+    #[cfg(feature = "v1")]
+    pub async fn generate_merchant_dispute_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Query<
-            api_models::analytics::outgoing_webhook_event::OutgoingWebhookLogsRequest,
-        >,
+        json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetOutgoingWebhookEvents;
+        let flow = AnalyticsFlow::GenerateDisputeReport;
         Box::pin(api::server_wrap(
             flow,
-            state,
+            state.clone(),
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
-                utils::check_if_profile_id_is_present_in_payment_intent(
-                    req.payment_id.clone(),
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
+                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
+                    .await
+                    .change_context(AnalyticsError::UnknownError)?;
+
+                let user_email = UserEmail::from_pii_email(user.email)
+                    .change_context(AnalyticsError::UnknownError)?
+                    .get_secret();
+
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let lambda_req = GenerateReportRequest {
+                    request: payload,
+                    merchant_id: Some(merchant_id.clone()),
+                    auth: AuthInfo::MerchantLevel {
+                        org_id: org_id.clone(),
+                        merchant_ids: vec![merchant_id.clone()],
+                    },
+                    email: user_email,
+                };
+
+                analytics_report::generate_report(
                     &state,
-                    &auth,
+                    AnalyticsReportType::Disputes,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .change_context(AnalyticsError::AccessForbiddenError)?;
-                outgoing_webhook_events_core(&state.pool, req, auth.merchant_account.get_id())
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+    #[cfg(feature = "v1")]
+    pub async fn generate_org_dispute_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<ReportRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GenerateDisputeReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state.clone(),
+            &req,
+            json_payload.into_inner(),
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
+                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
                     .await
-                    .map(ApplicationResponse::Json)
+                    .change_context(AnalyticsError::UnknownError)?;
+
+                let user_email = UserEmail::from_pii_email(user.email)
+                    .change_context(AnalyticsError::UnknownError)?
+                    .get_secret();
+
+                let org_id = auth.merchant_account.get_org_id();
+                let lambda_req = GenerateReportRequest {
+                    request: payload,
+                    merchant_id: None,
+                    auth: AuthInfo::OrgLevel {
+                        org_id: org_id.clone(),
+                    },
+                    email: user_email,
+                };
+
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Disputes,
+                    lambda_req,
+                    &user_id,
+                )
+                .await
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileAnalyticsRead,
+                permission: Permission::OrganizationReportRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -1689,31 +2609,53 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_profile_sdk_events(
+    pub async fn generate_profile_dispute_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<api_models::analytics::sdk_events::SdkEventsRequest>,
+        json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetSdkEvents;
+        let flow = AnalyticsFlow::GenerateDisputeReport;
         Box::pin(api::server_wrap(
             flow,
-            state,
+            state.clone(),
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
-                utils::check_if_profile_id_is_present_in_payment_intent(
-                    req.payment_id.clone(),
+            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
+                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
+                    .await
+                    .change_context(AnalyticsError::UnknownError)?;
+
+                let user_email = UserEmail::from_pii_email(user.email)
+                    .change_context(AnalyticsError::UnknownError)?
+                    .get_secret();
+
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let lambda_req = GenerateReportRequest {
+                    request: payload,
+                    merchant_id: Some(merchant_id.clone()),
+                    auth: AuthInfo::ProfileLevel {
+                        org_id: org_id.clone(),
+                        merchant_id: merchant_id.clone(),
+                        profile_ids: vec![profile_id.clone()],
+                    },
+                    email: user_email,
+                };
+
+                analytics_report::generate_report(
                     &state,
-                    &auth,
+                    AnalyticsReportType::Disputes,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .change_context(AnalyticsError::AccessForbiddenError)?;
-                sdk_events_core(&state.pool, req, &auth.merchant_account.publishable_key)
-                    .await
-                    .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileAnalyticsRead,
+                permission: Permission::ProfileReportRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -1721,12 +2663,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_merchant_refund_report(
+    pub async fn generate_merchant_payout_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateRefundReport;
+        let flow = AnalyticsFlow::GeneratePayoutReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -1753,15 +2695,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Payouts,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1772,12 +2712,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_org_refund_report(
+    pub async fn generate_org_payout_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateRefundReport;
+        let flow = AnalyticsFlow::GeneratePayoutReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -1802,15 +2742,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Payouts,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1821,12 +2759,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_profile_refund_report(
+    pub async fn generate_profile_payout_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateRefundReport;
+        let flow = AnalyticsFlow::GeneratePayoutReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -1840,7 +2778,6 @@ pub mod routes {
                 let user_email = UserEmail::from_pii_email(user.email)
                     .change_context(AnalyticsError::UnknownError)?
                     .get_secret();
-
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
                 let profile_id = auth
@@ -1858,15 +2795,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Payouts,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1875,13 +2810,14 @@ pub mod routes {
         ))
         .await
     }
+
     #[cfg(feature = "v1")]
-    pub async fn generate_merchant_dispute_report(
+    pub async fn generate_merchant_payment_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateDisputeReport;
+        let flow = AnalyticsFlow::GeneratePaymentReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -1908,15 +2844,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Payments,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1925,13 +2859,14 @@ pub mod routes {
         ))
         .await
     }
+
     #[cfg(feature = "v1")]
-    pub async fn generate_org_dispute_report(
+    pub async fn generate_org_payment_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateDisputeReport;
+        let flow = AnalyticsFlow::GeneratePaymentReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -1956,15 +2891,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Payments,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1975,12 +2908,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_profile_dispute_report(
+    pub async fn generate_profile_payment_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateDisputeReport;
+        let flow = AnalyticsFlow::GeneratePaymentReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -1994,7 +2927,6 @@ pub mod routes {
                 let user_email = UserEmail::from_pii_email(user.email)
                     .change_context(AnalyticsError::UnknownError)?
                     .get_secret();
-
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
                 let profile_id = auth
@@ -2012,15 +2944,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Payments,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -2031,12 +2961,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_merchant_payout_report(
+    pub async fn generate_merchant_authentication_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GeneratePayoutReport;
+        let flow = AnalyticsFlow::GenerateAuthenticationReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -2063,15 +2993,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payout_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Authentications,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -2082,12 +3010,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_org_payout_report(
+    pub async fn generate_org_authentication_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GeneratePayoutReport;
+        let flow = AnalyticsFlow::GenerateAuthenticationReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -2112,15 +3040,13 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payout_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Authentications,
+                    lambda_req,
+                    &user_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -2131,12 +3057,12 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_profile_payout_report(
+    pub async fn generate_profile_authentication_report(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
         json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GeneratePayoutReport;
+        let flow = AnalyticsFlow::GenerateAuthenticationReport;
         Box::pin(api::server_wrap(
             flow,
             state.clone(),
@@ -2167,18 +3093,312 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payout_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+                analytics_report::generate_report(
+                    &state,
+                    AnalyticsReportType::Authentications,
+                    lambda_req,
+                    &user_id,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_merchant_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        report_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            report_id.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let auth = AuthInfo::MerchantLevel {
+                    org_id: auth.merchant_account.get_org_id().clone(),
+                    merchant_ids: vec![auth.merchant_account.get_id().clone()],
+                };
+                analytics_report::retrieve_report(&state, &auth, &report_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        report_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            report_id.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let auth = AuthInfo::OrgLevel {
+                    org_id: auth.merchant_account.get_org_id().clone(),
+                };
+                analytics_report::retrieve_report(&state, &auth, &report_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        report_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            report_id.into_inner(),
+            |state, auth: AuthenticationData, report_id, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth = AuthInfo::ProfileLevel {
+                    org_id: auth.merchant_account.get_org_id().clone(),
+                    merchant_id: auth.merchant_account.get_id().clone(),
+                    profile_ids: vec![profile_id],
+                };
+                analytics_report::retrieve_report(&state, &auth, &report_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// Download link of reports signed by the application, the signature of the link being the
+    /// authentication of the request
+    #[cfg(feature = "v1")]
+    pub async fn download_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        report_id: web::Path<String>,
+        query_params: web::Query<ReportDownloadRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        let report_id = report_id.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            query_params.into_inner(),
+            |state, _: (), request, _| {
+                let report_id = report_id.clone();
+                async move { analytics_report::download_report(&state, &report_id, request).await }
+            },
+            &auth::NoAuth,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn create_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<CreateAlertRuleRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics_alert::create_alert_rule(&state, auth.merchant_account.get_id(), req)
+                    .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn list_alert_rules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListAlertRules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| async move {
+                analytics_alert::list_alert_rules(&state, auth.merchant_account.get_id()).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        rule_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            rule_id.into_inner(),
+            |state, auth: AuthenticationData, rule_id, _| async move {
+                analytics_alert::retrieve_alert_rule(
+                    &state,
+                    auth.merchant_account.get_id(),
+                    &rule_id,
+                )
+                .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn update_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        rule_id: web::Path<String>,
+        json_payload: web::Json<UpdateAlertRuleRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::UpdateAlertRule;
+        let rule_id = rule_id.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                let rule_id = rule_id.clone();
+                async move {
+                    analytics_alert::update_alert_rule(
+                        &state,
+                        auth.merchant_account.get_id(),
+                        &rule_id,
+                        req,
+                    )
+                    .await
+                }
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn delete_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        rule_id: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            rule_id.into_inner(),
+            |state, auth: AuthenticationData, rule_id, _| async move {
+                analytics_alert::delete_alert_rule(&state, auth.merchant_account.get_id(), &rule_id)
+                    .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn list_alert_history(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        query_params: web::Query<AlertHistoryRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListAlertHistory;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            query_params.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics_alert::list_alert_history(&state, auth.merchant_account.get_id(), req)
+                    .await
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn create_export(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<CreateExportRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateExport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics_export::create_export(
+                    &state,
+                    auth.merchant_account.get_id(),
+                    auth.merchant_account.get_org_id(),
+                    req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileReportRead,
+                permission: Permission::MerchantAccountWrite,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2186,50 +3406,21 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_merchant_payment_report(
+    pub async fn list_exports(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<ReportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GeneratePaymentReport;
+        let flow = AnalyticsFlow::ListExports;
         Box::pin(api::server_wrap(
             flow,
-            state.clone(),
+            state,
             &req,
-            json_payload.into_inner(),
-            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
-                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
-                    .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let org_id = auth.merchant_account.get_org_id();
-                let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: Some(merchant_id.clone()),
-                    auth: AuthInfo::MerchantLevel {
-                        org_id: org_id.clone(),
-                        merchant_ids: vec![merchant_id.clone()],
-                    },
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+            (),
+            |state, auth: AuthenticationData, _, _| async move {
+                analytics_export::list_exports(&state, auth.merchant_account.get_id()).await
             },
             &auth::JWTAuth {
-                permission: Permission::MerchantReportRead,
+                permission: Permission::MerchantAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2237,48 +3428,27 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_org_payment_report(
+    pub async fn get_export(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<ReportRequest>,
+        export_id: web::Path<String>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GeneratePaymentReport;
+        let flow = AnalyticsFlow::GetExport;
         Box::pin(api::server_wrap(
             flow,
-            state.clone(),
+            state,
             &req,
-            json_payload.into_inner(),
-            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
-                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
-                    .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: None,
-                    auth: AuthInfo::OrgLevel {
-                        org_id: org_id.clone(),
-                    },
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+            export_id.into_inner(),
+            |state, auth: AuthenticationData, export_id, _| async move {
+                analytics_export::retrieve_export(
+                    &state,
+                    auth.merchant_account.get_id(),
+                    &export_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::OrganizationReportRead,
+                permission: Permission::MerchantAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2286,54 +3456,33 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_profile_payment_report(
+    pub async fn update_export(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<ReportRequest>,
+        export_id: web::Path<String>,
+        json_payload: web::Json<UpdateExportRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GeneratePaymentReport;
+        let flow = AnalyticsFlow::UpdateExport;
+        let export_id = export_id.into_inner();
         Box::pin(api::server_wrap(
             flow,
-            state.clone(),
+            state,
             &req,
             json_payload.into_inner(),
-            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
-                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
+            |state, auth: AuthenticationData, req, _| {
+                let export_id = export_id.clone();
+                async move {
+                    analytics_export::update_export(
+                        &state,
+                        auth.merchant_account.get_id(),
+                        &export_id,
+                        req,
+                    )
                     .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-                let org_id = auth.merchant_account.get_org_id();
-                let merchant_id = auth.merchant_account.get_id();
-                let profile_id = auth
-                    .profile_id
-                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
-                    .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: Some(merchant_id.clone()),
-                    auth: AuthInfo::ProfileLevel {
-                        org_id: org_id.clone(),
-                        merchant_id: merchant_id.clone(),
-                        profile_ids: vec![profile_id.clone()],
-                    },
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                }
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileReportRead,
+                permission: Permission::MerchantAccountWrite,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2341,50 +3490,23 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_merchant_authentication_report(
+    pub async fn delete_export(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<ReportRequest>,
+        export_id: web::Path<String>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateAuthenticationReport;
+        let flow = AnalyticsFlow::DeleteExport;
         Box::pin(api::server_wrap(
             flow,
-            state.clone(),
+            state,
             &req,
-            json_payload.into_inner(),
-            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
-                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
+            export_id.into_inner(),
+            |state, auth: AuthenticationData, export_id, _| async move {
+                analytics_export::delete_export(&state, auth.merchant_account.get_id(), &export_id)
                     .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let org_id = auth.merchant_account.get_org_id();
-                let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: Some(merchant_id.clone()),
-                    auth: AuthInfo::MerchantLevel {
-                        org_id: org_id.clone(),
-                        merchant_ids: vec![merchant_id.clone()],
-                    },
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::MerchantReportRead,
+                permission: Permission::MerchantAccountWrite,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2392,48 +3514,33 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_org_authentication_report(
+    pub async fn list_export_runs(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<ReportRequest>,
+        export_id: web::Path<String>,
+        query_params: web::Query<ExportRunListRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateAuthenticationReport;
+        let flow = AnalyticsFlow::ListExportRuns;
+        let export_id = export_id.into_inner();
         Box::pin(api::server_wrap(
             flow,
-            state.clone(),
+            state,
             &req,
-            json_payload.into_inner(),
-            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
-                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
+            query_params.into_inner(),
+            |state, auth: AuthenticationData, req, _| {
+                let export_id = export_id.clone();
+                async move {
+                    analytics_export::list_export_runs(
+                        &state,
+                        auth.merchant_account.get_id(),
+                        &export_id,
+                        req,
+                    )
                     .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-
-                let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: None,
-                    auth: AuthInfo::OrgLevel {
-                        org_id: org_id.clone(),
-                    },
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                }
             },
             &auth::JWTAuth {
-                permission: Permission::OrganizationReportRead,
+                permission: Permission::MerchantAnalyticsRead,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2441,54 +3548,28 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn generate_profile_authentication_report(
+    pub async fn retry_export_run(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<ReportRequest>,
+        path: web::Path<ExportRunId>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GenerateAuthenticationReport;
+        let flow = AnalyticsFlow::RetryExportRun;
         Box::pin(api::server_wrap(
             flow,
-            state.clone(),
+            state,
             &req,
-            json_payload.into_inner(),
-            |state, (auth, user_id): auth::AuthenticationDataWithUserId, payload, _| async move {
-                let user = UserInterface::find_user_by_id(&*state.global_store, &user_id)
-                    .await
-                    .change_context(AnalyticsError::UnknownError)?;
-
-                let user_email = UserEmail::from_pii_email(user.email)
-                    .change_context(AnalyticsError::UnknownError)?
-                    .get_secret();
-                let org_id = auth.merchant_account.get_org_id();
-                let merchant_id = auth.merchant_account.get_id();
-                let profile_id = auth
-                    .profile_id
-                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
-                    .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
-                    request: payload,
-                    merchant_id: Some(merchant_id.clone()),
-                    auth: AuthInfo::ProfileLevel {
-                        org_id: org_id.clone(),
-                        merchant_id: merchant_id.clone(),
-                        profile_ids: vec![profile_id.clone()],
-                    },
-                    email: user_email,
-                };
-
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
+            path.into_inner(),
+            |state, auth: AuthenticationData, path, _| async move {
+                analytics_export::retry_export_run(
+                    &state,
+                    auth.merchant_account.get_id(),
+                    &path.export_id,
+                    &path.run_id,
                 )
                 .await
-                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
-                permission: Permission::ProfileReportRead,
+                permission: Permission::MerchantAccountWrite,
             },
             api_locking::LockAction::NotApplicable,
         ))
@@ -2753,16 +3834,29 @@ pub mod routes {
                     })
                     .collect();
 
-                analytics::search::msearch_results(
-                    state
-                        .opensearch_client
-                        .as_ref()
-                        .ok_or_else(|| error_stack::report!(OpenSearchError::NotEnabled))?,
-                    req,
-                    search_params,
-                    SEARCH_INDEXES.to_vec(),
-                )
-                .await
+                match state.conf.global_search.provider {
+                    SearchProvider::Opensearch => {
+                        analytics::search::msearch_results(
+                            state
+                                .opensearch_client
+                                .as_ref()
+                                .ok_or_else(|| error_stack::report!(OpenSearchError::NotEnabled))?,
+                            req,
+                            search_params,
+                            SEARCH_INDEXES.to_vec(),
+                        )
+                        .await
+                    }
+                    SearchProvider::Postgres => {
+                        analytics::search::postgres::msearch_results(
+                            &state.pool,
+                            req,
+                            search_params,
+                            SEARCH_INDEXES.to_vec(),
+                        )
+                        .await
+                    }
+                }
                 .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
@@ -2904,15 +3998,23 @@ pub mod routes {
                             })
                     })
                     .collect();
-                analytics::search::search_results(
-                    state
-                        .opensearch_client
-                        .as_ref()
-                        .ok_or_else(|| error_stack::report!(OpenSearchError::NotEnabled))?,
-                    req,
-                    search_params,
-                )
-                .await
+                match state.conf.global_search.provider {
+                    SearchProvider::Opensearch => {
+                        analytics::search::search_results(
+                            state
+                                .opensearch_client
+                                .as_ref()
+                                .ok_or_else(|| error_stack::report!(OpenSearchError::NotEnabled))?,
+                            req,
+                            search_params,
+                        )
+                        .await
+                    }
+                    SearchProvider::Postgres => {
+                        analytics::search::postgres::search_results(&state.pool, req, search_params)
+                            .await
+                    }
+                }
                 .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
@@ -3099,6 +4201,13 @@ pub mod routes {
                     merchant_id: merchant_id.clone(),
                     profile_ids: vec![profile_id.clone()],
                 };
+
+                analytics_metadata::validate_metadata_filters(
+                    &state,
+                    &profile_id,
+                    &req.filters.metadata,
+                )
+                .await?;
                 analytics::disputes::get_metrics(&state.pool, &auth, req)
                     .await
                     .map(ApplicationResponse::Json)
@@ -3111,6 +4220,57 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_profile_metadata_dimensions(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetMetadataDimensions;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                analytics_metadata::retrieve_metadata_dimensions(&state, profile_id).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn update_profile_metadata_dimensions(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<MetadataDimensionsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::UpdateMetadataDimensions;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                analytics_metadata::update_metadata_dimensions(&state, profile_id, req).await
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     #[cfg(feature = "v1")]
     /// # Panics
     ///
@@ -287,9 +287,104 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::DisputeListWorkflow => {
                     Ok(Box::new(workflows::dispute_list::DisputeListWorkflow))
                 }
+                storage::ProcessTrackerRunner::DisputeDeadlineWorkflow => {
+                    #[cfg(feature = "v1")]
+                    {
+                        Ok(Box::new(
+                            workflows::dispute_deadline::DisputeDeadlineWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "v1"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable("Cannot run dispute deadline workflow in v2")
+                    }
+                }
+                storage::ProcessTrackerRunner::FileScanWorkflow => {
+                    Ok(Box::new(workflows::file_scan::FileScanWorkflow))
+                }
+                storage::ProcessTrackerRunner::AnalyticsReportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::analytics_report::AnalyticsReportWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run analytics report workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::AnalyticsAlertWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(workflows::analytics_alert::AnalyticsAlertWorkflow))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run analytics alert workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::AnalyticsExportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::analytics_export::AnalyticsExportWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run analytics export workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::KafkaEventsBackfillWorkflow => {
+                    #[cfg(all(feature = "olap", feature = "v1"))]
+                    {
+                        Ok(Box::new(
+                            workflows::kafka_events_backfill::KafkaEventsBackfillWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(all(feature = "olap", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run Kafka events backfill workflow without the olap and v1 features",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::EmailOutboxWorkflow => {
+                    #[cfg(feature = "email")]
+                    {
+                        Ok(Box::new(workflows::email_outbox::EmailOutboxWorkflow))
+                    }
+
+                    #[cfg(not(feature = "email"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run email outbox workflow when email feature is disabled",
+                            )
+                    }
+                }
                 storage::ProcessTrackerRunner::InvoiceSyncflow => {
                     Ok(Box::new(workflows::invoice_sync::InvoiceSyncWorkflow))
                 }
+                storage::ProcessTrackerRunner::SubscriptionBillingWorkflow => Ok(Box::new(
+                    workflows::subscription_billing::SubscriptionBillingWorkflow,
+                )),
                 storage::ProcessTrackerRunner::DeleteTokenizeDataWorkflow => Ok(Box::new(
                     workflows::tokenized_data::DeleteTokenizeDataWorkflow,
                 )),
@@ -327,6 +422,19 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                         )
                     }
                 }
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(all(feature = "payouts", feature = "v1"))]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(all(feature = "payouts", feature = "v1")))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
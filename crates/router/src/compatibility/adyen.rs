@@ -0,0 +1,23 @@
+pub mod app;
+pub mod errors;
+pub mod modifications;
+pub mod payment_methods;
+pub mod payments;
+pub mod webhooks;
+
+use actix_web::{web, Scope};
+
+use crate::routes;
+
+/// Adyen Checkout API (v71) compatible endpoints
+///
+/// The `pspReference` of a payment is its Hyperswitch payment id.
+pub struct AdyenApis;
+
+impl AdyenApis {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/adyen/v71")
+            .service(app::Payments::server(state.clone()))
+            .service(app::PaymentMethods::server(state))
+    }
+}
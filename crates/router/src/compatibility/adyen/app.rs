@@ -0,0 +1,37 @@
+use actix_web::{web, Scope};
+
+use super::{modifications::*, payment_methods::*, payments::*};
+use crate::routes;
+
+pub struct Payments;
+
+impl Payments {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/payments")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payments_create)))
+            .service(web::resource("/details").route(web::post().to(payments_details)))
+            .service(
+                web::resource("/{payment_psp_reference}/captures")
+                    .route(web::post().to(payment_captures)),
+            )
+            .service(
+                web::resource("/{payment_psp_reference}/refunds")
+                    .route(web::post().to(payment_refunds)),
+            )
+            .service(
+                web::resource("/{payment_psp_reference}/cancels")
+                    .route(web::post().to(payment_cancels)),
+            )
+    }
+}
+
+pub struct PaymentMethods;
+
+impl PaymentMethods {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/paymentMethods")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payment_methods_list)))
+    }
+}
@@ -0,0 +1,107 @@
+use common_utils::errors::ErrorSwitch;
+use hyperswitch_domain_models::errors::api_error_response as errors;
+use serde::Serialize;
+
+/// Error body returned by the Adyen Checkout API
+///
+/// Hyperswitch error codes are passed through as `errorCode`, only the envelope follows Adyen.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenErrorResponse {
+    pub status: u16,
+    pub error_code: String,
+    pub message: String,
+    pub error_type: AdyenErrorType,
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdyenErrorType {
+    Validation,
+    Security,
+    Configuration,
+    Internal,
+}
+
+impl ::core::fmt::Display for AdyenErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap_or_else(|_| "API error response".to_string())
+        )
+    }
+}
+
+impl std::error::Error for AdyenErrorResponse {}
+
+impl From<errors::ApiErrorResponse> for AdyenErrorResponse {
+    fn from(value: errors::ApiErrorResponse) -> Self {
+        let status = actix_web::ResponseError::status_code(&value);
+        let error_type = match value {
+            errors::ApiErrorResponse::MerchantConnectorAccountNotFound { .. }
+            | errors::ApiErrorResponse::MerchantConnectorAccountDisabled
+            | errors::ApiErrorResponse::InvalidConnectorConfiguration { .. }
+            | errors::ApiErrorResponse::ConfigNotFound
+            | errors::ApiErrorResponse::IncorrectConnectorNameGiven => {
+                AdyenErrorType::Configuration
+            }
+            _ if status == reqwest::StatusCode::UNAUTHORIZED
+                || status == reqwest::StatusCode::FORBIDDEN =>
+            {
+                AdyenErrorType::Security
+            }
+            _ => match value.error_type() {
+                errors::ErrorType::InvalidRequestError
+                | errors::ErrorType::ObjectNotFound
+                | errors::ErrorType::ValidationError
+                | errors::ErrorType::DuplicateRequest => AdyenErrorType::Validation,
+                errors::ErrorType::RouterError
+                | errors::ErrorType::ProcessingError
+                | errors::ErrorType::BadGateway
+                | errors::ErrorType::ServerNotAvailable
+                | errors::ErrorType::ConnectorError
+                | errors::ErrorType::LockTimeout => AdyenErrorType::Internal,
+            },
+        };
+
+        Self {
+            status: status.as_u16(),
+            error_code: value.error_code(),
+            message: value.error_message(),
+            error_type,
+        }
+    }
+}
+
+impl From<serde_json::Error> for AdyenErrorResponse {
+    fn from(error: serde_json::Error) -> Self {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Failed to parse request: {error}"),
+        }
+        .into()
+    }
+}
+
+impl actix_web::ResponseError for AdyenErrorResponse {
+    fn status_code(&self) -> reqwest::StatusCode {
+        reqwest::StatusCode::from_u16(self.status)
+            .unwrap_or(reqwest::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        use actix_web::http::header;
+
+        actix_web::HttpResponseBuilder::new(self.status_code())
+            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
+            .body(self.to_string())
+    }
+}
+
+impl ErrorSwitch<AdyenErrorResponse> for errors::ApiErrorResponse {
+    fn switch(&self) -> AdyenErrorResponse {
+        self.clone().into()
+    }
+}
+
+impl crate::services::EmbedError for error_stack::Report<AdyenErrorResponse> {}
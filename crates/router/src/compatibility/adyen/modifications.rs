@@ -0,0 +1,197 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow, Tag};
+
+use crate::{
+    compatibility::{adyen::errors, wrap},
+    core::{
+        api_locking::{self, GetLockingInput},
+        payments, refunds,
+    },
+    logger, routes,
+    services::{api, authentication as auth},
+    types::api::{self as api_types, refunds as refund_types},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCapture, payment_id))]
+pub async fn payment_captures(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+    path: web::Path<id_type::PaymentId>,
+) -> HttpResponse {
+    let payment_id = path.into_inner();
+    let adyen_payload: types::AdyenCaptureRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?adyen_payload);
+
+    let payload = payment_types::PaymentsCaptureRequest::from((adyen_payload, payment_id));
+
+    let flow = Flow::PaymentsCapture;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenCaptureResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let platform = auth.into();
+            payments::payments_core::<
+                api_types::Capture,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Capture>,
+            >(
+                state,
+                req_state,
+                platform,
+                None,
+                payments::PaymentCapture,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::AdyenApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreate, payment_id))]
+pub async fn payment_refunds(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+    path: web::Path<id_type::PaymentId>,
+) -> HttpResponse {
+    let payment_id = path.into_inner();
+    let adyen_payload: types::AdyenRefundRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?adyen_payload);
+
+    let create_refund_req = refund_types::RefundRequest::from((adyen_payload, payment_id));
+
+    let flow = Flow::RefundsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenRefundResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_refund_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            refunds::refund_create_core(state, platform, None, req)
+        },
+        &auth::AdyenApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel, payment_id))]
+pub async fn payment_cancels(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+    path: web::Path<id_type::PaymentId>,
+) -> HttpResponse {
+    let payment_id = path.into_inner();
+    let adyen_payload: types::AdyenCancelRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?adyen_payload);
+
+    let payload = payment_types::PaymentsCancelRequest::from((adyen_payload, payment_id));
+
+    let flow = Flow::PaymentsCancel;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenCancelResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let platform = auth.into();
+            payments::payments_core::<
+                api_types::Void,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Void>,
+            >(
+                state,
+                req_state,
+                platform,
+                None,
+                payments::PaymentCancel,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::AdyenApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
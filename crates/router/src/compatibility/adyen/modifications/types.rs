@@ -0,0 +1,128 @@
+use api_models::{payments, refunds};
+use common_utils::{id_type, types::MinorUnit};
+use serde::{Deserialize, Serialize};
+
+use crate::compatibility::adyen::payments::types::AdyenAmount;
+
+/// Adyen processes modifications asynchronously, the outcome is delivered through notifications
+const MODIFICATION_STATUS_RECEIVED: &str = "received";
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureRequest {
+    pub merchant_account: Option<String>,
+    pub amount: AdyenAmount,
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRefundRequest {
+    pub merchant_account: Option<String>,
+    pub amount: AdyenAmount,
+    pub reference: Option<String>,
+    pub merchant_refund_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCancelRequest {
+    pub merchant_account: Option<String>,
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureResponse {
+    pub merchant_account: String,
+    pub payment_psp_reference: id_type::PaymentId,
+    pub psp_reference: String,
+    pub status: &'static str,
+    pub amount: AdyenAmount,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRefundResponse {
+    pub payment_psp_reference: id_type::PaymentId,
+    pub psp_reference: String,
+    pub status: &'static str,
+    pub amount: AdyenAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_refund_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCancelResponse {
+    pub merchant_account: String,
+    pub payment_psp_reference: id_type::PaymentId,
+    pub psp_reference: String,
+    pub status: &'static str,
+}
+
+impl From<(AdyenCaptureRequest, id_type::PaymentId)> for payments::PaymentsCaptureRequest {
+    fn from((item, payment_id): (AdyenCaptureRequest, id_type::PaymentId)) -> Self {
+        Self {
+            payment_id,
+            amount_to_capture: Some(MinorUnit::new(item.amount.value)),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<(AdyenRefundRequest, id_type::PaymentId)> for refunds::RefundRequest {
+    fn from((item, payment_id): (AdyenRefundRequest, id_type::PaymentId)) -> Self {
+        Self {
+            payment_id,
+            amount: Some(MinorUnit::new(item.amount.value)),
+            reason: item.merchant_refund_reason,
+            refund_type: Some(refunds::RefundType::Instant),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<(AdyenCancelRequest, id_type::PaymentId)> for payments::PaymentsCancelRequest {
+    fn from((_item, payment_id): (AdyenCancelRequest, id_type::PaymentId)) -> Self {
+        Self {
+            payment_id,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<payments::PaymentsResponse> for AdyenCaptureResponse {
+    fn from(item: payments::PaymentsResponse) -> Self {
+        Self {
+            merchant_account: item.merchant_id.get_string_repr().to_owned(),
+            psp_reference: item.payment_id.get_string_repr().to_owned(),
+            payment_psp_reference: item.payment_id,
+            status: MODIFICATION_STATUS_RECEIVED,
+            amount: AdyenAmount::new(item.amount_received.unwrap_or(item.amount), item.currency),
+        }
+    }
+}
+
+impl From<refunds::RefundResponse> for AdyenRefundResponse {
+    fn from(item: refunds::RefundResponse) -> Self {
+        Self {
+            payment_psp_reference: item.payment_id,
+            psp_reference: item.refund_id,
+            status: MODIFICATION_STATUS_RECEIVED,
+            amount: AdyenAmount::new(item.amount, item.currency),
+            merchant_refund_reason: item.reason,
+        }
+    }
+}
+
+impl From<payments::PaymentsResponse> for AdyenCancelResponse {
+    fn from(item: payments::PaymentsResponse) -> Self {
+        Self {
+            merchant_account: item.merchant_id.get_string_repr().to_owned(),
+            psp_reference: item.payment_id.get_string_repr().to_owned(),
+            payment_psp_reference: item.payment_id,
+            status: MODIFICATION_STATUS_RECEIVED,
+        }
+    }
+}
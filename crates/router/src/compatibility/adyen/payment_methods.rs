@@ -0,0 +1,60 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::report;
+use router_env::{instrument, tracing, Flow, Tag};
+
+use crate::{
+    compatibility::{adyen::errors, wrap},
+    core::{api_locking, payment_methods::cards},
+    logger, routes,
+    services::{api, authentication as auth},
+    types::api::payment_methods,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsList))]
+pub async fn payment_methods_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::AdyenPaymentMethodsRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let list_payment_methods_req =
+        match payment_methods::PaymentMethodListRequest::try_from(payload) {
+            Ok(req) => req,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    let flow = Flow::PaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentMethodsResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        list_payment_methods_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            cards::list_payment_methods(state, platform, req)
+        },
+        &auth::AdyenApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
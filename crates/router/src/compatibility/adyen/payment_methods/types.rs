@@ -0,0 +1,131 @@
+use api_models::payment_methods;
+use common_utils::types::MinorUnit;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::adyen::payments::types::AdyenAmount, core::errors,
+    types::api::enums as api_enums,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentMethodsRequest {
+    pub merchant_account: Option<String>,
+    pub amount: Option<AdyenAmount>,
+    pub country_code: Option<api_enums::CountryAlpha2>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdyenPaymentMethod {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub payment_method_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub brands: Option<Vec<&'static str>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentMethodsResponse {
+    pub payment_methods: Vec<AdyenPaymentMethod>,
+}
+
+impl TryFrom<AdyenPaymentMethodsRequest> for payment_methods::PaymentMethodListRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: AdyenPaymentMethodsRequest) -> errors::RouterResult<Self> {
+        let currency = item
+            .amount
+            .as_ref()
+            .map(|amount| amount.get_currency())
+            .transpose()?;
+
+        Ok(Self {
+            amount: item.amount.map(|amount| MinorUnit::new(amount.value)),
+            accepted_currencies: currency.map(|currency| vec![currency]),
+            accepted_countries: item.country_code.map(|country| vec![country]),
+            ..Default::default()
+        })
+    }
+}
+
+fn get_adyen_card_brand(card_network: &api_enums::CardNetwork) -> &'static str {
+    match card_network {
+        api_enums::CardNetwork::Visa => "visa",
+        api_enums::CardNetwork::Mastercard => "mc",
+        api_enums::CardNetwork::AmericanExpress => "amex",
+        api_enums::CardNetwork::JCB => "jcb",
+        api_enums::CardNetwork::DinersClub => "diners",
+        api_enums::CardNetwork::Discover => "discover",
+        api_enums::CardNetwork::CartesBancaires => "cartebancaire",
+        api_enums::CardNetwork::UnionPay => "cup",
+        api_enums::CardNetwork::Interac => "interac_card",
+        api_enums::CardNetwork::RuPay => "rupay",
+        api_enums::CardNetwork::Maestro => "maestro",
+        api_enums::CardNetwork::Star => "star",
+        api_enums::CardNetwork::Pulse => "pulse",
+        api_enums::CardNetwork::Accel => "accel",
+        api_enums::CardNetwork::Nyce => "nyce",
+    }
+}
+
+/// Adyen payment method type names, falling back to the Hyperswitch name for payment methods
+/// without a direct Adyen equivalent
+fn get_adyen_payment_method_type(payment_method_type: api_enums::PaymentMethodType) -> String {
+    match payment_method_type {
+        api_enums::PaymentMethodType::ApplePay => "applepay".to_string(),
+        api_enums::PaymentMethodType::GooglePay => "googlepay".to_string(),
+        api_enums::PaymentMethodType::Paypal => "paypal".to_string(),
+        api_enums::PaymentMethodType::Sepa => "sepadirectdebit".to_string(),
+        api_enums::PaymentMethodType::BancontactCard => "bcmc".to_string(),
+        api_enums::PaymentMethodType::AfterpayClearpay => "afterpaytouch".to_string(),
+        api_enums::PaymentMethodType::WeChatPay => "wechatpayQR".to_string(),
+        api_enums::PaymentMethodType::AliPay => "alipay".to_string(),
+        api_enums::PaymentMethodType::Sofort => "directEbanking".to_string(),
+        api_enums::PaymentMethodType::MobilePay => "mobilepay".to_string(),
+        _ => payment_method_type.to_string(),
+    }
+}
+
+impl From<payment_methods::PaymentMethodListResponse> for AdyenPaymentMethodsResponse {
+    fn from(item: payment_methods::PaymentMethodListResponse) -> Self {
+        let mut card_brands: Option<Vec<&'static str>> = None;
+        let mut payment_methods = Vec::new();
+
+        for payment_method_type in item
+            .payment_methods
+            .into_iter()
+            .flat_map(|payment_method| payment_method.payment_method_types)
+        {
+            match payment_method_type.payment_method_type {
+                // Adyen lists credit and debit cards as a single `scheme` payment method
+                api_enums::PaymentMethodType::Credit | api_enums::PaymentMethodType::Debit => {
+                    let card_brands = card_brands.get_or_insert_with(Vec::new);
+                    for card_network in payment_method_type.card_networks.unwrap_or_default() {
+                        let brand = get_adyen_card_brand(&card_network.card_network);
+                        if !card_brands.contains(&brand) {
+                            card_brands.push(brand);
+                        }
+                    }
+                }
+                other => payment_methods.push(AdyenPaymentMethod {
+                    name: other.to_string(),
+                    payment_method_type: get_adyen_payment_method_type(other),
+                    brands: None,
+                }),
+            }
+        }
+
+        if let Some(card_brands) = card_brands {
+            payment_methods.insert(
+                0,
+                AdyenPaymentMethod {
+                    name: "Cards".to_string(),
+                    payment_method_type: "scheme".to_string(),
+                    brands: (!card_brands.is_empty()).then_some(card_brands),
+                },
+            );
+        }
+
+        Self { payment_methods }
+    }
+}
@@ -0,0 +1,173 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow, Tag};
+
+use crate::{
+    compatibility::{adyen::errors, wrap},
+    core::{api_locking::GetLockingInput, payments},
+    logger,
+    routes::{self, payments::get_or_generate_payment_id},
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate, payment_id))]
+pub async fn payments_create(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::AdyenPaymentRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let mut create_payment_req: payment_types::PaymentsRequest = match payload.try_into() {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    if let Err(err) = get_or_generate_payment_id(&mut create_payment_req) {
+        return api::log_and_return_error_response(err);
+    }
+
+    if let Some(api_types::PaymentIdType::PaymentIntentId(payment_id)) =
+        create_payment_req.payment_id.as_ref()
+    {
+        tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+    }
+
+    let flow = Flow::PaymentsCreate;
+    let locking_action = create_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_req,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            let platform = auth.into();
+            let eligible_connectors = req.connector.clone();
+            payments::payments_core::<
+                api_types::Authorize,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Authorize>,
+            >(
+                state,
+                req_state,
+                platform,
+                None,
+                payments::PaymentCreate,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                eligible_connectors,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::AdyenApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// Completes the payment after the shopper returns from a redirect, by syncing it with the
+/// connector
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRetrieveForceSync, payment_id))]
+pub async fn payments_details(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::AdyenPaymentDetailsRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    logger::info!(tag = ?Tag::CompatibilityLayerRequest, payload = ?payload);
+
+    let payment_id = match payload.get_payment_id() {
+        Ok(payment_id) => payment_id,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let payload = payment_types::PaymentsRetrieveRequest {
+        resource_id: api_types::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: None,
+        force_sync: true,
+        connector: None,
+        param: None,
+        merchant_connector_details: None,
+        client_secret: None,
+        expand_attempts: None,
+        expand_captures: None,
+        all_keys_required: None,
+    };
+
+    let flow = Flow::PaymentsRetrieveForceSync;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            let platform = auth.into();
+            payments::payments_core::<
+                api_types::PSync,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::PSync>,
+            >(
+                state,
+                req_state,
+                platform,
+                None,
+                payments::PaymentStatus,
+                payload,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &auth::AdyenApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
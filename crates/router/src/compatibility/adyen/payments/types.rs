@@ -0,0 +1,460 @@
+use api_models::{mandates::RecurringDetails, payments};
+use common_utils::{ext_traits::StringExt, id_type, pii::Email, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types::api::enums as api_enums};
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct AdyenAmount {
+    pub value: i64,
+    pub currency: String,
+}
+
+impl AdyenAmount {
+    pub fn new(amount: MinorUnit, currency: impl ToString) -> Self {
+        Self {
+            value: amount.get_amount_as_i64(),
+            currency: currency.to_string().to_uppercase(),
+        }
+    }
+
+    pub fn get_currency(&self) -> errors::RouterResult<api_enums::Currency> {
+        self.currency
+            .to_uppercase()
+            .parse_enum("currency")
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "amount.currency",
+            })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenName {
+    pub first_name: Option<Secret<String>>,
+    pub last_name: Option<Secret<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAddress {
+    pub city: Option<String>,
+    pub country: Option<api_enums::CountryAlpha2>,
+    pub house_number_or_name: Option<Secret<String>>,
+    pub postal_code: Option<Secret<String>>,
+    pub state_or_province: Option<Secret<String>>,
+    pub street: Option<Secret<String>>,
+}
+
+/// Only `scheme` payment methods are translated, either as raw card details or as a reference to
+/// a stored payment method. Client side encrypted card fields are not supported.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentMethod {
+    #[serde(rename = "type")]
+    pub payment_method_type: String,
+    pub number: Option<cards::CardNumber>,
+    pub expiry_month: Option<Secret<String>>,
+    pub expiry_year: Option<Secret<String>>,
+    pub cvc: Option<Secret<String>>,
+    pub holder_name: Option<Secret<String>>,
+    pub stored_payment_method_id: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum AdyenShopperInteraction {
+    Ecommerce,
+    ContAuth,
+    Moto,
+    #[serde(rename = "POS")]
+    Pos,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBrowserInfo {
+    pub accept_header: Option<String>,
+    pub color_depth: Option<u8>,
+    pub java_enabled: Option<bool>,
+    pub java_script_enabled: Option<bool>,
+    pub language: Option<String>,
+    pub screen_height: Option<u32>,
+    pub screen_width: Option<u32>,
+    pub time_zone_offset: Option<i32>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAdditionalData {
+    pub manual_capture: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentRequest {
+    pub amount: AdyenAmount,
+    pub reference: String,
+    // The merchant account is resolved from the API key
+    pub merchant_account: Option<String>,
+    pub payment_method: AdyenPaymentMethod,
+    pub return_url: Option<url::Url>,
+    pub shopper_reference: Option<id_type::CustomerId>,
+    pub shopper_email: Option<Email>,
+    pub telephone_number: Option<Secret<String>>,
+    pub shopper_name: Option<AdyenName>,
+    pub country_code: Option<api_enums::CountryAlpha2>,
+    pub billing_address: Option<AdyenAddress>,
+    pub delivery_address: Option<AdyenAddress>,
+    pub capture_delay_hours: Option<u32>,
+    pub additional_data: Option<AdyenAdditionalData>,
+    pub store_payment_method: Option<bool>,
+    pub shopper_interaction: Option<AdyenShopperInteraction>,
+    pub shopper_statement: Option<String>,
+    pub browser_info: Option<AdyenBrowserInfo>,
+    #[serde(rename = "shopperIP")]
+    pub shopper_ip: Option<std::net::IpAddr>,
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AdyenPaymentDetails {
+    /// Appended by Hyperswitch to the `returnUrl` when redirecting the shopper back
+    pub payment_id: Option<id_type::PaymentId>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentDetailsRequest {
+    #[serde(default)]
+    pub details: AdyenPaymentDetails,
+    pub payment_data: Option<id_type::PaymentId>,
+}
+
+impl AdyenPaymentDetailsRequest {
+    pub fn get_payment_id(self) -> errors::RouterResult<id_type::PaymentId> {
+        self.payment_data
+            .or(self.details.payment_id)
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "paymentData",
+                })
+            })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub enum AdyenResultCode {
+    Authorised,
+    Refused,
+    Pending,
+    Received,
+    Cancelled,
+    Error,
+    RedirectShopper,
+    PresentToShopper,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAction {
+    #[serde(rename = "type")]
+    pub action_type: &'static str,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<&'static str>,
+    /// Echoed back in `/payments/details` to look up the payment
+    pub payment_data: id_type::PaymentId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentResponse {
+    pub psp_reference: id_type::PaymentId,
+    pub result_code: AdyenResultCode,
+    pub amount: AdyenAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merchant_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<AdyenAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+fn build_address(
+    address: Option<AdyenAddress>,
+    shopper_name: Option<&AdyenName>,
+    email: Option<Email>,
+    phone: Option<Secret<String>>,
+) -> payments::Address {
+    payments::Address {
+        address: address.map(|address| payments::AddressDetails {
+            city: address.city,
+            country: address.country,
+            line1: address.street,
+            line2: address.house_number_or_name,
+            line3: None,
+            zip: address.postal_code,
+            state: address.state_or_province,
+            first_name: shopper_name.and_then(|name| name.first_name.clone()),
+            last_name: shopper_name.and_then(|name| name.last_name.clone()),
+            origin_zip: None,
+        }),
+        phone: phone.map(|number| payments::PhoneDetails {
+            number: Some(number),
+            country_code: None,
+        }),
+        email,
+    }
+}
+
+impl TryFrom<AdyenPaymentMethod> for payments::PaymentMethodData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: AdyenPaymentMethod) -> errors::RouterResult<Self> {
+        Ok(Self::Card(payments::Card {
+            card_number: item
+                .number
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "paymentMethod.number",
+                })?,
+            card_exp_month: item.expiry_month.ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "paymentMethod.expiryMonth",
+                },
+            )?,
+            card_exp_year: item.expiry_year.ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "paymentMethod.expiryYear",
+                },
+            )?,
+            card_holder_name: item.holder_name,
+            card_cvc: item
+                .cvc
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "paymentMethod.cvc",
+                })?,
+            card_issuer: None,
+            card_network: None,
+            bank_code: None,
+            card_issuing_country: None,
+            card_type: None,
+            nick_name: None,
+        }))
+    }
+}
+
+impl TryFrom<AdyenPaymentRequest> for payments::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: AdyenPaymentRequest) -> errors::RouterResult<Self> {
+        if item.payment_method.payment_method_type != "scheme" {
+            return Err(errors::ApiErrorResponse::NotSupported {
+                message: format!(
+                    "Payment method type {} through the Adyen compatibility layer",
+                    item.payment_method.payment_method_type
+                ),
+            }
+            .into());
+        }
+
+        let currency = item.amount.get_currency()?;
+
+        // Adyen captures after `captureDelayHours`, a delayed capture has to be done through
+        // the captures endpoint here
+        let manual_capture = item
+            .additional_data
+            .as_ref()
+            .and_then(|data| data.manual_capture.as_deref())
+            .is_some_and(|manual_capture| manual_capture.eq_ignore_ascii_case("true"));
+        let capture_method = match item.capture_delay_hours {
+            _ if manual_capture => Some(api_enums::CaptureMethod::Manual),
+            Some(0) => Some(api_enums::CaptureMethod::Automatic),
+            Some(_) => Some(api_enums::CaptureMethod::Manual),
+            None => None,
+        };
+
+        let (payment_method_data, recurring_details) =
+            match item.payment_method.stored_payment_method_id.clone() {
+                Some(payment_method_id) => (
+                    None,
+                    Some(RecurringDetails::PaymentMethodId(payment_method_id)),
+                ),
+                None => (
+                    Some(payments::PaymentMethodData::try_from(item.payment_method)?),
+                    None,
+                ),
+            };
+
+        let billing = build_address(
+            item.billing_address.or_else(|| {
+                item.country_code.map(|country| AdyenAddress {
+                    city: None,
+                    country: Some(country),
+                    house_number_or_name: None,
+                    postal_code: None,
+                    state_or_province: None,
+                    street: None,
+                })
+            }),
+            item.shopper_name.as_ref(),
+            item.shopper_email.clone(),
+            item.telephone_number.clone(),
+        );
+
+        let browser_info = item
+            .browser_info
+            .map(|info| crate::types::BrowserInformation {
+                color_depth: info.color_depth,
+                java_enabled: info.java_enabled,
+                java_script_enabled: info.java_script_enabled,
+                language: info.language,
+                screen_height: info.screen_height,
+                screen_width: info.screen_width,
+                time_zone: info.time_zone_offset,
+                ip_address: item.shopper_ip,
+                accept_header: info.accept_header,
+                user_agent: info.user_agent,
+                ..Default::default()
+            })
+            .or_else(|| {
+                item.shopper_ip
+                    .map(|ip_address| crate::types::BrowserInformation {
+                        ip_address: Some(ip_address),
+                        ..Default::default()
+                    })
+            })
+            .map(|browser_info| {
+                serde_json::to_value(browser_info)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("convert to browser info failed")
+            })
+            .transpose()?;
+
+        let name = item.shopper_name.as_ref().and_then(|name| {
+            let full_name = [name.first_name.as_ref(), name.last_name.as_ref()]
+                .into_iter()
+                .flatten()
+                .map(|name| name.peek().as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            (!full_name.is_empty()).then_some(Secret::new(full_name))
+        });
+
+        Ok(Self {
+            amount: Some(MinorUnit::new(item.amount.value).into()),
+            currency: Some(currency),
+            capture_method,
+            confirm: Some(true),
+            customer_id: item.shopper_reference,
+            email: item.shopper_email,
+            name,
+            phone: item.telephone_number,
+            return_url: item.return_url,
+            setup_future_usage: item
+                .store_payment_method
+                .and_then(|store| store.then_some(api_enums::FutureUsage::OffSession)),
+            off_session: item
+                .shopper_interaction
+                .map(|interaction| matches!(interaction, AdyenShopperInteraction::ContAuth)),
+            payment_method_data: payment_method_data.map(|payment_method_data| {
+                payments::PaymentMethodDataRequest {
+                    payment_method_data: Some(payment_method_data),
+                    billing: None,
+                }
+            }),
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            recurring_details,
+            billing: Some(billing),
+            shipping: item.delivery_address.map(|address| {
+                build_address(Some(address), item.shopper_name.as_ref(), None, None)
+            }),
+            statement_descriptor_name: item.shopper_statement,
+            browser_info,
+            metadata: item.metadata,
+            merchant_order_reference_id: Some(item.reference),
+            ..Default::default()
+        })
+    }
+}
+
+impl AdyenResultCode {
+    fn from_payment_status(status: api_enums::IntentStatus, action: Option<&AdyenAction>) -> Self {
+        match status {
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::PartiallyCaptured
+            | api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyCapturedAndCapturable
+            | api_enums::IntentStatus::PartiallyAuthorizedAndRequiresCapture => Self::Authorised,
+            api_enums::IntentStatus::Failed | api_enums::IntentStatus::RequiresPaymentMethod => {
+                Self::Refused
+            }
+            api_enums::IntentStatus::Cancelled
+            | api_enums::IntentStatus::CancelledPostCapture
+            | api_enums::IntentStatus::Expired => Self::Cancelled,
+            api_enums::IntentStatus::Processing => Self::Received,
+            api_enums::IntentStatus::RequiresCustomerAction => match action {
+                Some(action) if action.action_type == "redirect" => Self::RedirectShopper,
+                Some(_) => Self::PresentToShopper,
+                None => Self::Pending,
+            },
+            api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::Conflicted => Self::Pending,
+            api_enums::IntentStatus::RequiresConfirmation => Self::Error,
+        }
+    }
+}
+
+fn get_action(
+    next_action: Option<payments::NextActionData>,
+    payment_id: &id_type::PaymentId,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+) -> Option<AdyenAction> {
+    let (action_type, url, method) = match next_action? {
+        payments::NextActionData::RedirectToUrl { redirect_to_url } => {
+            ("redirect", redirect_to_url, Some("GET"))
+        }
+        payments::NextActionData::RedirectInsidePopup { popup_url, .. } => {
+            ("redirect", popup_url, Some("GET"))
+        }
+        payments::NextActionData::QrCodeInformation {
+            qr_code_url,
+            image_data_url,
+            ..
+        } => ("qrCode", qr_code_url.or(image_data_url)?.to_string(), None),
+        _ => return None,
+    };
+
+    Some(AdyenAction {
+        action_type,
+        url,
+        method,
+        payment_data: payment_id.clone(),
+        payment_method_type: payment_method_type.map(|pmt| pmt.to_string()),
+    })
+}
+
+impl From<payments::PaymentsResponse> for AdyenPaymentResponse {
+    fn from(item: payments::PaymentsResponse) -> Self {
+        let action = get_action(item.next_action, &item.payment_id, item.payment_method_type);
+        let result_code = AdyenResultCode::from_payment_status(item.status, action.as_ref());
+        let is_refused = matches!(result_code, AdyenResultCode::Refused);
+
+        Self {
+            psp_reference: item.payment_id,
+            result_code,
+            amount: AdyenAmount::new(item.amount, item.currency),
+            merchant_reference: item.merchant_order_reference_id,
+            refusal_reason: item.error_message.filter(|_| is_refused),
+            refusal_reason_code: item.error_code.filter(|_| is_refused),
+            action,
+            metadata: item.metadata,
+        }
+    }
+}
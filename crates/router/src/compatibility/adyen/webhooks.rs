@@ -0,0 +1,348 @@
+use api_models::{
+    enums::EventType,
+    webhooks::{self as api},
+};
+use base64::Engine;
+use common_utils::{
+    crypto::SignMessage,
+    ext_traits::Encode,
+    types::{AmountConvertor, StringMinorUnitForConnector},
+};
+use error_stack::ResultExt;
+use serde::Serialize;
+
+use super::payments::types::AdyenAmount;
+use crate::{
+    consts,
+    core::{
+        errors,
+        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+    },
+    headers,
+    services::request::Maskable,
+};
+
+/// Adyen standard notification, carrying a single notification item per webhook
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenOutgoingWebhook {
+    notification_items: Vec<AdyenNotificationItemWrapper>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AdyenNotificationItemWrapper {
+    #[serde(rename = "NotificationRequestItem")]
+    notification_request_item: AdyenNotificationRequestItem,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNotificationAdditionalData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hmac_signature: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNotificationRequestItem {
+    additional_data: AdyenNotificationAdditionalData,
+    amount: AdyenAmount,
+    event_code: &'static str,
+    event_date: String,
+    merchant_account_code: String,
+    merchant_reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_reference: Option<String>,
+    psp_reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    success: &'static str,
+}
+
+impl AdyenNotificationRequestItem {
+    /// Adyen signs the colon separated notification fields, with `\` and `:` escaped
+    fn get_signing_payload(&self) -> String {
+        [
+            self.psp_reference.as_str(),
+            self.original_reference.as_deref().unwrap_or_default(),
+            self.merchant_account_code.as_str(),
+            self.merchant_reference.as_str(),
+            &self.amount.value.to_string(),
+            self.amount.currency.as_str(),
+            self.event_code,
+            self.success,
+        ]
+        .iter()
+        .map(|value| value.replace('\\', "\\\\").replace(':', "\\:"))
+        .collect::<Vec<_>>()
+        .join(":")
+    }
+}
+
+impl OutgoingWebhookType for AdyenOutgoingWebhook {
+    fn get_outgoing_webhooks_signature(
+        &self,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+        let payment_response_hash_key = payment_response_hash_key
+            .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
+            .attach_printable("For adyen compatibility payment_response_hash_key is mandatory")?;
+        // Adyen HMAC keys are hex encoded, and notifications are signed with the decoded key
+        let hmac_key = hex::decode(payment_response_hash_key.as_ref())
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+            .attach_printable(
+                "For adyen compatibility payment_response_hash_key must be a hex encoded HMAC key",
+            )?;
+
+        let mut webhook = self.clone();
+        let mut signature = None;
+        for item in webhook.notification_items.iter_mut() {
+            let item = &mut item.notification_request_item;
+            let hmac_signature = consts::BASE64_ENGINE.encode(
+                common_utils::crypto::HmacSha256::sign_message(
+                    &common_utils::crypto::HmacSha256,
+                    &hmac_key,
+                    item.get_signing_payload().as_bytes(),
+                )
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+                .attach_printable("Failed to sign the message")?,
+            );
+            item.additional_data.hmac_signature = Some(hmac_signature.clone());
+            signature = Some(hmac_signature);
+        }
+
+        let webhook_signature_payload = webhook
+            .encode_to_string_of_json()
+            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+            .attach_printable("failed encoding outgoing webhook payload")?;
+
+        Ok(OutgoingWebhookPayloadWithSignature {
+            payload: webhook_signature_payload.into(),
+            signature,
+        })
+    }
+
+    fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
+        header.push((
+            headers::ADYEN_COMPATIBLE_WEBHOOK_SIGNATURE.to_string(),
+            signature.into(),
+        ))
+    }
+}
+
+/// Maps an event to the Adyen event code and the value of the `success` field
+fn get_adyen_event_code(event_type: EventType) -> (&'static str, bool) {
+    match event_type {
+        EventType::PaymentSucceeded
+        | EventType::PaymentAuthorized
+        | EventType::PaymentPartiallyAuthorized
+        | EventType::InvoicePaid => ("AUTHORISATION", true),
+        EventType::PaymentFailed => ("AUTHORISATION", false),
+        EventType::PaymentCaptured => ("CAPTURE", true),
+        EventType::PaymentProcessing | EventType::ActionRequired => ("PENDING", true),
+        EventType::PaymentCancelled => ("CANCELLATION", true),
+        EventType::PaymentCancelledPostCapture => ("CANCEL_OR_REFUND", true),
+        EventType::PaymentExpired => ("EXPIRE", true),
+        EventType::RefundSucceeded => ("REFUND", true),
+        EventType::RefundFailed => ("REFUND_FAILED", true),
+        EventType::DisputeOpened | EventType::DisputeDeadlineApproaching => {
+            ("NOTIFICATION_OF_CHARGEBACK", true)
+        }
+        EventType::DisputeChallenged => ("INFORMATION_SUPPLIED", true),
+        EventType::DisputeExpired | EventType::DisputeAccepted | EventType::DisputeLost => {
+            ("CHARGEBACK", true)
+        }
+        EventType::DisputeCancelled | EventType::DisputeWon => ("CHARGEBACK_REVERSED", true),
+        EventType::MandateActive => ("RECURRING_CONTRACT", true),
+        EventType::MandateRevoked => ("RECURRING_CONTRACT", false),
+        EventType::PayoutSuccess | EventType::PayoutBatchCompleted => ("PAYOUT_THIRDPARTY", true),
+        EventType::PayoutFailed => ("PAYOUT_THIRDPARTY", false),
+        EventType::PayoutInitiated | EventType::PayoutProcessing => ("PENDING", true),
+        EventType::PayoutCancelled => ("PAYOUT_DECLINE", true),
+        EventType::PayoutExpired => ("PAYOUT_EXPIRE", true),
+        EventType::PayoutReversed => ("PAIDOUT_REVERSED", true),
+    }
+}
+
+impl From<api::OutgoingWebhook> for AdyenOutgoingWebhook {
+    fn from(value: api::OutgoingWebhook) -> Self {
+        let (event_code, success) = get_adyen_event_code(value.event_type);
+        let event_date = value
+            .timestamp
+            .assume_utc()
+            .format(&time::format_description::well_known::Rfc3339)
+            .unwrap_or_default();
+
+        let mut item = AdyenNotificationRequestItem {
+            additional_data: AdyenNotificationAdditionalData::default(),
+            amount: AdyenAmount::default(),
+            event_code,
+            event_date,
+            merchant_account_code: value.merchant_id.get_string_repr().to_owned(),
+            merchant_reference: value.event_id.clone(),
+            original_reference: None,
+            psp_reference: value.event_id,
+            reason: None,
+            success: if success { "true" } else { "false" },
+        };
+
+        match value.content {
+            api::OutgoingWebhookContent::PaymentDetails(payment) => {
+                item.amount = AdyenAmount::new(payment.amount, &payment.currency);
+                item.merchant_reference = payment
+                    .merchant_order_reference_id
+                    .unwrap_or_else(|| payment.payment_id.get_string_repr().to_owned());
+                item.psp_reference = payment.payment_id.get_string_repr().to_owned();
+                item.reason = payment.error_message;
+            }
+            api::OutgoingWebhookContent::RefundDetails(refund) => {
+                item.amount = AdyenAmount::new(refund.amount, &refund.currency);
+                item.merchant_reference = refund.refund_id.clone();
+                item.original_reference = Some(refund.payment_id.get_string_repr().to_owned());
+                item.psp_reference = refund.refund_id;
+                item.reason = refund.error_message.or(refund.reason);
+            }
+            api::OutgoingWebhookContent::DisputeDetails(dispute) => {
+                if let Ok(amount) =
+                    StringMinorUnitForConnector.convert_back(dispute.amount, dispute.currency)
+                {
+                    item.amount = AdyenAmount::new(amount, dispute.currency);
+                }
+                item.merchant_reference = dispute.dispute_id.clone();
+                item.original_reference = Some(dispute.payment_id.get_string_repr().to_owned());
+                item.psp_reference = dispute.dispute_id;
+                item.reason = dispute.connector_reason;
+            }
+            api::OutgoingWebhookContent::MandateDetails(mandate) => {
+                item.merchant_reference = mandate.payment_method_id;
+                item.psp_reference = mandate.mandate_id;
+            }
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutDetails(payout) => {
+                item.amount = AdyenAmount::new(payout.amount, payout.currency);
+                item.merchant_reference = payout
+                    .merchant_order_reference_id
+                    .unwrap_or_else(|| payout.payout_id.get_string_repr().to_owned());
+                item.psp_reference = payout.payout_id.get_string_repr().to_owned();
+                item.reason = payout.error_message;
+            }
+            api::OutgoingWebhookContent::SubscriptionDetails(subscription) => {
+                item.merchant_reference = subscription
+                    .merchant_reference_id
+                    .unwrap_or_else(|| subscription.id.get_string_repr().to_owned());
+                item.psp_reference = subscription.id.get_string_repr().to_owned();
+            }
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutBatchDetails(payout_batch) => {
+                item.merchant_reference = payout_batch.batch_id.clone();
+                item.psp_reference = payout_batch.batch_id;
+            }
+        }
+
+        Self {
+            notification_items: vec![AdyenNotificationItemWrapper {
+                notification_request_item: item,
+            }],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const HMAC_KEY: &str = "44782DEF547AAA06C910C43932B1EB0C71FC68D9D0C057550C48EC2ACF6BA056";
+
+    fn get_notification_item() -> AdyenNotificationRequestItem {
+        AdyenNotificationRequestItem {
+            additional_data: AdyenNotificationAdditionalData::default(),
+            amount: AdyenAmount {
+                value: 1130,
+                currency: "EUR".to_string(),
+            },
+            event_code: "AUTHORISATION",
+            event_date: "2024-01-01T00:00:00Z".to_string(),
+            merchant_account_code: "TestMerchant".to_string(),
+            merchant_reference: "TestPayment-1407325143704".to_string(),
+            original_reference: None,
+            psp_reference: "7914073381342284".to_string(),
+            reason: None,
+            success: "true",
+        }
+    }
+
+    fn get_webhook(item: AdyenNotificationRequestItem) -> AdyenOutgoingWebhook {
+        AdyenOutgoingWebhook {
+            notification_items: vec![AdyenNotificationItemWrapper {
+                notification_request_item: item,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_signing_payload_escapes_separators() {
+        let item = AdyenNotificationRequestItem {
+            psp_reference: "pay\\1".to_string(),
+            merchant_account_code: "mer:x".to_string(),
+            ..get_notification_item()
+        };
+
+        assert_eq!(
+            item.get_signing_payload(),
+            "pay\\\\1::mer\\:x:TestPayment-1407325143704:1130:EUR:AUTHORISATION:true"
+        );
+    }
+
+    #[test]
+    fn test_notification_is_signed_with_decoded_hex_key() {
+        let webhook = get_webhook(get_notification_item());
+
+        let signed = webhook
+            .get_outgoing_webhooks_signature(Some(HMAC_KEY))
+            .unwrap();
+
+        // Signature of the sample notification in the Adyen HMAC verification guide
+        let expected_signature = "coqCmt/IZ4E3CzPvMY8zTjQVL5hYJUiBRg8UU+iCWo0=";
+        assert_eq!(signed.signature.as_deref(), Some(expected_signature));
+        let payload: serde_json::Value =
+            serde_json::from_str(&masking::ExposeInterface::expose(signed.payload)).unwrap();
+        assert_eq!(
+            payload["notificationItems"][0]["NotificationRequestItem"]["additionalData"]
+                ["hmacSignature"],
+            expected_signature
+        );
+    }
+
+    #[test]
+    fn test_signing_requires_a_hex_key() {
+        let webhook = get_webhook(get_notification_item());
+
+        assert!(webhook
+            .get_outgoing_webhooks_signature(Some("not a hex key"))
+            .is_err());
+        assert!(webhook
+            .get_outgoing_webhooks_signature(None::<&str>)
+            .is_err());
+    }
+
+    #[test]
+    fn test_adyen_event_codes() {
+        assert_eq!(
+            get_adyen_event_code(EventType::PaymentSucceeded),
+            ("AUTHORISATION", true)
+        );
+        assert_eq!(
+            get_adyen_event_code(EventType::PaymentFailed),
+            ("AUTHORISATION", false)
+        );
+        assert_eq!(
+            get_adyen_event_code(EventType::RefundSucceeded),
+            ("REFUND", true)
+        );
+        assert_eq!(
+            get_adyen_event_code(EventType::DisputeWon),
+            ("CHARGEBACK_REVERSED", true)
+        );
+    }
+}
@@ -1,6 +1,14 @@
 pub mod app;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod disputes;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod events;
 pub mod payment_intents;
+#[cfg(feature = "v1")]
+pub mod payment_methods;
+#[cfg(all(feature = "v1", feature = "payouts"))]
+pub mod payouts;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -18,13 +26,22 @@ impl StripeApis {
     pub fn server(state: routes::AppState) -> Scope {
         let max_depth = 10;
         let strict = false;
-        web::scope("/vs/v1")
+        let mut route = web::scope("/vs/v1")
             .app_data(web::Data::new(serde_qs::Config::new(max_depth, strict)))
             .service(app::SetupIntents::server(state.clone()))
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::Disputes::server(state.clone()));
+        #[cfg(feature = "olap")]
+        {
+            route = route.service(app::Events::server(state.clone()))
+        }
+        #[cfg(feature = "payouts")]
+        {
+            route = route.service(app::Payouts::server(state.clone()))
+        }
+        route.service(app::PaymentMethods::server(state))
     }
 }
@@ -1,7 +1,11 @@
 use actix_web::{web, Scope};
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+use super::events::*;
+#[cfg(all(feature = "v1", feature = "payouts"))]
+use super::payouts::*;
 #[cfg(feature = "v1")]
-use super::{customers::*, payment_intents::*, setup_intents::*};
+use super::{customers::*, disputes::*, payment_intents::*, payment_methods::*, setup_intents::*};
 use super::{refunds::*, webhooks::*};
 use crate::routes::{self, mandates, webhooks};
 
@@ -117,12 +121,63 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+#[cfg(feature = "v1")]
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::post().to(payment_method_create))
+                    .route(web::get().to(payment_method_list)),
+            )
+            .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)))
+            .service(web::resource("/{id}/attach").route(web::post().to(payment_method_attach)))
             .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
     }
 }
+
+pub struct Events;
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+impl Events {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/events")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(event_list)))
+            .service(web::resource("/{id}").route(web::get().to(event_retrieve)))
+    }
+}
+
+pub struct Disputes;
+
+#[cfg(feature = "v1")]
+impl Disputes {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/disputes")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(dispute_list)))
+            .service(web::resource("/{id}").route(web::get().to(dispute_retrieve)))
+            .service(web::resource("/{id}/close").route(web::post().to(dispute_close)))
+    }
+}
+
+pub struct Payouts;
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+impl Payouts {
+    pub fn server(config: routes::AppState) -> Scope {
+        let mut root = web::resource("").route(web::post().to(payout_create));
+        #[cfg(feature = "olap")]
+        {
+            root = root.route(web::get().to(payout_list))
+        }
+        web::scope("/payouts")
+            .app_data(web::Data::new(config))
+            .service(root)
+            .service(web::resource("/{id}").route(web::get().to(payout_retrieve)))
+            .service(web::resource("/{id}/cancel").route(web::post().to(payout_cancel)))
+    }
+}
@@ -0,0 +1,138 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::disputes as dispute_models;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, disputes},
+    routes,
+    services::{api, authentication as auth},
+    types::api::disputes as dispute_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+pub async fn dispute_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripeDisputeListConstraints>,
+) -> HttpResponse {
+    let payload = match dispute_models::DisputeListGetConstraints::try_from(query.into_inner()) {
+        Ok(payload) => payload,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::DisputesList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.clone().into();
+            disputes::retrieve_disputes_list(
+                state,
+                platform,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = dispute_models::DisputeRetrieveRequest {
+        dispute_id: path.into_inner(),
+        force_sync: None,
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDispute,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.clone().into();
+            disputes::retrieve_dispute(state, platform, auth.profile_id, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Closing a dispute in Stripe concedes it, which maps to accepting the dispute at the connector
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_close(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDispute,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.clone().into();
+            disputes::accept_dispute(state, platform, auth.profile_id, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,132 @@
+use api_models::disputes;
+use common_utils::{
+    id_type,
+    types::{AmountConvertor, StringMinorUnitForConnector, TimeRange},
+};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{compatibility::stripe::webhooks::StripeDisputeStatus, core::errors};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeDisputeListConstraints {
+    pub limit: Option<u32>,
+    pub payment_intent: Option<id_type::PaymentId>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeDispute {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub currency: String,
+    pub payment_intent: id_type::PaymentId,
+    pub reason: String,
+    pub status: StripeDisputeStatus,
+    pub created: i64,
+    pub evidence_details: StripeDisputeEvidenceDetails,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeDisputeListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeDispute>,
+}
+
+fn from_timestamp_to_datetime(time: i64) -> errors::RouterResult<PrimitiveDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(time)
+        .map(|time| PrimitiveDateTime::new(time.date(), time.time()))
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Error while converting timestamp".to_string(),
+        })
+}
+
+impl TryFrom<StripeDisputeListConstraints> for disputes::DisputeListGetConstraints {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeDisputeListConstraints) -> errors::RouterResult<Self> {
+        let time_range = item
+            .created_gte
+            .map(|created_gte| {
+                Ok::<_, Self::Error>(TimeRange {
+                    start_time: from_timestamp_to_datetime(created_gte)?,
+                    end_time: item
+                        .created_lte
+                        .map(from_timestamp_to_datetime)
+                        .transpose()?,
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            dispute_id: None,
+            payment_id: item.payment_intent,
+            limit: item.limit,
+            offset: None,
+            profile_id: None,
+            dispute_status: None,
+            dispute_stage: None,
+            reason: None,
+            connector: None,
+            currency: None,
+            merchant_connector_id: None,
+            time_range,
+        })
+    }
+}
+
+impl TryFrom<disputes::DisputeResponse> for StripeDispute {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: disputes::DisputeResponse) -> errors::RouterResult<Self> {
+        let amount = StringMinorUnitForConnector
+            .convert_back(item.amount, item.currency)
+            .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+                amount_type: "StringMinorUnit",
+            })?;
+
+        Ok(Self {
+            id: item.dispute_id,
+            object: "dispute",
+            amount: amount.get_amount_as_i64(),
+            currency: item.currency.to_string().to_lowercase(),
+            payment_intent: item.payment_id,
+            // Connector reasons are free-form, Stripe falls back to `general` for unknown reasons
+            reason: item
+                .connector_reason
+                .unwrap_or_else(|| "general".to_string()),
+            status: StripeDisputeStatus::from(item.dispute_status),
+            created: item.created_at.assume_utc().unix_timestamp(),
+            evidence_details: StripeDisputeEvidenceDetails {
+                due_by: item
+                    .challenge_required_by
+                    .map(|due_by| due_by.assume_utc().unix_timestamp()),
+            },
+        })
+    }
+}
+
+impl TryFrom<Vec<disputes::DisputeResponse>> for StripeDisputeListResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(items: Vec<disputes::DisputeResponse>) -> errors::RouterResult<Self> {
+        Ok(Self {
+            object: "list",
+            url: "/v1/disputes",
+            has_more: false,
+            data: items
+                .into_iter()
+                .map(StripeDispute::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -135,6 +135,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "Duplicate payout request")]
     DuplicatePayout { payout_id: id_type::PayoutId },
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "status_transition_invalid", message = "{reason}")]
+    StatusTransitionInvalid { reason: String },
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Return url is not available")]
     ReturnUrlUnavailable,
 
@@ -229,7 +232,7 @@ pub enum StripeErrorCode {
 
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "The connector provided in the request is incorrect or not available")]
     IncorrectConnectorNameGiven,
-    #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "No such {object}: '{id}'")]
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such {object}: '{id}'")]
     ResourceMissing { object: String, id: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "File validation failed")]
     FileValidationFailed,
@@ -416,7 +419,6 @@ pub enum StripeErrorCode {
         ShippingCalculationFailed,
         SkuInactive,
         StateUnsupported,
-        StatusTransitionInvalid,
         TaxIdInvalid,
         TaxesCalculationFailed,
         TerminalLocationCountryUnsupported,
@@ -637,8 +639,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 object: "poll".to_owned(),
                 id,
             },
-            errors::ApiErrorResponse::DisputeStatusValidationFailed { reason: _ } => {
-                Self::InternalServerError
+            errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
+                Self::StatusTransitionInvalid { reason }
             }
             errors::ApiErrorResponse::FileValidationFailed { .. } => Self::FileValidationFailed,
             errors::ApiErrorResponse::MissingFile => Self::MissingFile,
@@ -735,6 +737,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::InvalidCardType
             | Self::DuplicateRefundRequest
             | Self::DuplicatePayout { .. }
+            | Self::StatusTransitionInvalid { .. }
             | Self::RefundNotFound
             | Self::CustomerNotFound
             | Self::ConfigNotFound
@@ -0,0 +1,108 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, webhooks::webhook_events},
+    routes,
+    services::{api, authentication as auth},
+    types::api::webhook_events::{
+        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
+    },
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventInitialDeliveryAttemptList))]
+pub async fn event_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripeEventListConstraints>,
+) -> HttpResponse {
+    let constraints = match EventListConstraints::try_from(query.into_inner()) {
+        Ok(constraints) => constraints,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let request_internal = EventListRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        constraints,
+    };
+
+    let flow = Flow::WebhookEventInitialDeliveryAttemptList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeEventListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        request_internal,
+        |state, auth: auth::AuthenticationData, mut request_internal, _| {
+            request_internal.merchant_id = auth.merchant_account.get_id().clone();
+            request_internal.constraints.profile_id = auth.profile_id;
+
+            webhook_events::list_initial_delivery_attempts(
+                state,
+                request_internal.merchant_id,
+                request_internal.constraints,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryAttemptList))]
+pub async fn event_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let request_internal = WebhookDeliveryAttemptListRequestInternal {
+        merchant_id: common_utils::id_type::MerchantId::default(),
+        initial_attempt_id: path.into_inner(),
+    };
+
+    let flow = Flow::WebhookEventDeliveryAttemptList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeEventResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        request_internal,
+        |state, auth: auth::AuthenticationData, request_internal, _| {
+            webhook_events::list_delivery_attempts(
+                state,
+                auth.merchant_account.get_id().clone(),
+                request_internal.initial_attempt_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
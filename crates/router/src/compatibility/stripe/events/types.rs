@@ -0,0 +1,211 @@
+use std::collections::HashSet;
+
+use api_models::webhook_events;
+use common_utils::{ext_traits::StringExt, fp_utils};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    compatibility::stripe::webhooks::get_stripe_event_type, core::errors,
+    types::api::enums as api_enums,
+};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripeEventListConstraints {
+    pub limit: Option<u16>,
+    #[serde(rename = "type")]
+    pub stype: Option<String>,
+    pub delivery_success: Option<bool>,
+    #[serde(rename = "created[lt]")]
+    pub created_lt: Option<i64>,
+    #[serde(rename = "created[gt]")]
+    pub created_gt: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventData {
+    pub object: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: &'static str,
+    pub created: i64,
+    pub data: StripeEventData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripeEventListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeEventResponse>,
+}
+
+impl TryFrom<StripeEventListConstraints> for webhook_events::EventListConstraints {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeEventListConstraints) -> errors::RouterResult<Self> {
+        let event_types = item
+            .stype
+            .as_deref()
+            .map(get_event_types_for_stripe_event_type)
+            .transpose()?;
+
+        // Stripe timestamps are accurate to the second, so exclusive bounds move by a second
+        let created_after = item
+            .created_gte
+            .or(item.created_gt.map(|created| created.saturating_add(1)));
+        let created_before = item
+            .created_lte
+            .or(item.created_lt.map(|created| created.saturating_sub(1)));
+
+        Ok(Self {
+            created_after: from_timestamp_to_datetime(created_after)?,
+            created_before: from_timestamp_to_datetime(created_before)?,
+            limit: item.limit,
+            offset: None,
+            object_id: None,
+            event_id: None,
+            profile_id: None,
+            event_classes: None,
+            event_types,
+            is_delivered: item.delivery_success,
+        })
+    }
+}
+
+/// Returns the event types that are delivered with the given Stripe event type
+fn get_event_types_for_stripe_event_type(
+    stripe_event_type: &str,
+) -> errors::RouterResult<HashSet<api_enums::EventType>> {
+    let event_class = match stripe_event_type.split('.').next() {
+        Some("payment_intent") | Some("action") => Some(api_enums::EventClass::Payments),
+        Some("refund") => Some(api_enums::EventClass::Refunds),
+        Some("dispute") => Some(api_enums::EventClass::Disputes),
+        Some("mandate") => Some(api_enums::EventClass::Mandates),
+        #[cfg(feature = "payouts")]
+        Some("payout") | Some("payout_batch") => Some(api_enums::EventClass::Payouts),
+        Some("invoice") => Some(api_enums::EventClass::Subscriptions),
+        _ => None,
+    };
+
+    let event_types = event_class
+        .map(|event_class| {
+            event_class
+                .event_types()
+                .into_iter()
+                .filter(|event_type| get_stripe_event_type(*event_type) == stripe_event_type)
+                .collect::<HashSet<_>>()
+        })
+        .unwrap_or_default();
+
+    fp_utils::when(event_types.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unrecognized event type: {stripe_event_type}"),
+        })
+    })?;
+    Ok(event_types)
+}
+
+fn from_timestamp_to_datetime(
+    time: Option<i64>,
+) -> errors::RouterResult<Option<PrimitiveDateTime>> {
+    time.map(|time| {
+        time::OffsetDateTime::from_unix_timestamp(time)
+            .map(|time| PrimitiveDateTime::new(time.date(), time.time()))
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Error while converting timestamp".to_string(),
+            })
+    })
+    .transpose()
+}
+
+fn get_stripe_object_name(event_class: api_enums::EventClass) -> &'static str {
+    match event_class {
+        api_enums::EventClass::Payments => "payment_intent",
+        api_enums::EventClass::Refunds => "refund",
+        api_enums::EventClass::Disputes => "dispute",
+        api_enums::EventClass::Mandates => "mandate",
+        #[cfg(feature = "payouts")]
+        api_enums::EventClass::Payouts => "payout",
+        api_enums::EventClass::Subscriptions => "subscription",
+    }
+}
+
+impl From<webhook_events::EventListItemResponse> for StripeEventResponse {
+    fn from(item: webhook_events::EventListItemResponse) -> Self {
+        // Listed events do not carry the webhook payload, only a reference to the object
+        Self {
+            id: item.event_id,
+            object: "event",
+            stype: get_stripe_event_type(item.event_type),
+            created: item.created.assume_utc().unix_timestamp(),
+            data: StripeEventData {
+                object: serde_json::json!({
+                    "id": item.object_id,
+                    "object": get_stripe_object_name(item.event_class),
+                }),
+            },
+        }
+    }
+}
+
+impl TryFrom<Vec<webhook_events::EventRetrieveResponse>> for StripeEventResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(items: Vec<webhook_events::EventRetrieveResponse>) -> errors::RouterResult<Self> {
+        let item = items
+            .into_iter()
+            .find(|item| {
+                item.delivery_attempt == Some(api_enums::WebhookDeliveryAttempt::InitialAttempt)
+            })
+            .ok_or(errors::ApiErrorResponse::EventNotFound)?;
+
+        // Webhooks delivered in the Stripe format carry the object under `data`, while the ones
+        // delivered in the default format carry it under `content`
+        let body: serde_json::Value = item
+            .request
+            .body
+            .peek()
+            .clone()
+            .parse_struct("OutgoingWebhookRequestContent body")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse webhook event request body")?;
+        let object = body
+            .get("data")
+            .or(body.get("content"))
+            .and_then(|content| content.get("object"))
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(Self {
+            id: item.event_information.event_id,
+            object: "event",
+            stype: get_stripe_event_type(item.event_information.event_type),
+            created: item.event_information.created.assume_utc().unix_timestamp(),
+            data: StripeEventData { object },
+        })
+    }
+}
+
+impl From<webhook_events::TotalEventsResponse> for StripeEventListResponse {
+    fn from(item: webhook_events::TotalEventsResponse) -> Self {
+        let has_more = usize::try_from(item.total_count)
+            .map(|total_count| total_count > item.events.len())
+            .unwrap_or_default();
+        Self {
+            object: "list",
+            url: "/v1/events",
+            has_more,
+            data: item.events.into_iter().map(From::from).collect(),
+        }
+    }
+}
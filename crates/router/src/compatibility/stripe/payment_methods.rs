@@ -0,0 +1,265 @@
+pub mod types;
+
+use ::payment_methods::controller::PaymentMethodsController;
+use actix_web::{web, HttpRequest, HttpResponse};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        errors::{self as core_errors, StorageErrorExt},
+        payment_methods::cards,
+    },
+    routes,
+    services::{api, authentication as auth},
+    types::{api::payment_methods, domain, storage},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsCreate))]
+pub async fn payment_method_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodCreateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_payment_method_req = match payment_methods::PaymentMethodCreate::try_from(payload) {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PaymentMethodsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_method_req,
+        |state, auth: auth::AuthenticationData, req, _| async move {
+            let platform = auth.into();
+            Box::pin(cards::get_client_secret_or_add_payment_method(
+                &state, req, &platform,
+            ))
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_methods::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, pm, _| async move {
+            let platform = auth.into();
+            cards::PmCards {
+                state: &state,
+                platform: &platform,
+            }
+            .retrieve_payment_method(pm)
+            .await
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
+pub async fn payment_method_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripePaymentMethodListConstraints>,
+) -> HttpResponse {
+    let constraints = query.into_inner();
+    let customer_id = constraints.customer;
+    let payload = payment_methods::PaymentMethodListRequest {
+        limit: constraints.limit,
+        ..Default::default()
+    };
+
+    let flow = Flow::CustomerPaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            cards::do_list_customer_pm_fetch_customer_if_not_passed(
+                state,
+                platform,
+                Some(req),
+                Some(&customer_id),
+                None,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Attaches the payment method to the customer in the request, moving it over from the customer
+/// it was created for
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsUpdate))]
+pub async fn payment_method_attach(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodAttachRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let customer_id = payload.customer;
+    let payment_method_id = payment_methods::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsUpdate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payment_method_id,
+        |state, auth: auth::AuthenticationData, pm, _| {
+            let customer_id = customer_id.clone();
+            async move {
+                let platform: domain::Platform = auth.into();
+                attach_payment_method(&state, &platform, pm, customer_id).await
+            }
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+async fn attach_payment_method(
+    state: &routes::SessionState,
+    platform: &domain::Platform,
+    pm: payment_methods::PaymentMethodId,
+    customer_id: id_type::CustomerId,
+) -> core_errors::RouterResponse<payment_methods::PaymentMethodResponse> {
+    let db = state.store.as_ref();
+    let merchant_account = platform.get_processor().get_account();
+    let key_store = platform.get_processor().get_key_store();
+
+    db.find_customer_by_customer_id_merchant_id(
+        &customer_id,
+        merchant_account.get_id(),
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(core_errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let payment_method = db
+        .find_payment_method(
+            key_store,
+            &pm.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(core_errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.customer_id != customer_id {
+        db.update_payment_method(
+            key_store,
+            payment_method,
+            storage::PaymentMethodUpdate::CustomerUpdate {
+                customer_id,
+                last_modified_by: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(core_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to attach payment method to customer")?;
+    }
+
+    cards::PmCards { state, platform }
+        .retrieve_payment_method(pm)
+        .await
+}
@@ -0,0 +1,290 @@
+use api_models::payment_methods as api_types;
+use common_utils::{id_type, pii::SecretSerdeValue};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::payment_intents::types::{
+        StripeBillingDetails, StripePaymentMethodType,
+    },
+    core::errors,
+    types::api::enums as api_enums,
+};
+
+#[derive(Default, Serialize, PartialEq, Eq, Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodCard {
+    pub number: cards::CardNumber,
+    pub exp_month: masking::Secret<String>,
+    pub exp_year: masking::Secret<String>,
+    pub cvc: Option<masking::Secret<String>>,
+    pub holder_name: Option<masking::Secret<String>>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodCreateRequest {
+    #[serde(rename = "type", default)]
+    pub stype: StripePaymentMethodType,
+    pub card: Option<StripePaymentMethodCard>,
+    pub billing_details: Option<StripeBillingDetails>,
+    pub customer: Option<id_type::CustomerId>,
+    pub metadata: Option<SecretSerdeValue>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodAttachRequest {
+    pub customer: id_type::CustomerId,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodListConstraints {
+    pub customer: id_type::CustomerId,
+    pub limit: Option<i64>,
+}
+
+#[derive(Default, Serialize, PartialEq, Eq, Debug)]
+pub struct StripePaymentMethodCardResponse {
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub last4: Option<String>,
+    pub fingerprint: Option<masking::Secret<String>>,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: Option<api_enums::PaymentMethod>,
+    pub card: Option<StripePaymentMethodCardResponse>,
+    pub customer: Option<id_type::CustomerId>,
+    pub created: Option<i64>,
+    pub metadata: Option<SecretSerdeValue>,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+pub struct StripePaymentMethodListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePaymentMethodResponse>,
+}
+
+impl TryFrom<StripePaymentMethodCreateRequest> for api_types::PaymentMethodCreate {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripePaymentMethodCreateRequest) -> errors::RouterResult<Self> {
+        let payment_method = api_enums::PaymentMethod::from(item.stype);
+        let card = match (payment_method, item.card) {
+            (api_enums::PaymentMethod::Card, Some(card)) => Some(api_types::CardDetail {
+                card_number: card.number,
+                card_exp_month: card.exp_month,
+                card_exp_year: card.exp_year,
+                card_cvc: card.cvc,
+                card_holder_name: card.holder_name,
+                nick_name: None,
+                card_issuing_country: None,
+                card_network: None,
+                card_issuer: None,
+                card_type: None,
+            }),
+            (api_enums::PaymentMethod::Card, None) => {
+                Err(errors::ApiErrorResponse::MissingRequiredField { field_name: "card" })?
+            }
+            _ => Err(errors::ApiErrorResponse::NotSupported {
+                message: format!("Payment methods of type {payment_method}"),
+            })?,
+        };
+
+        Ok(Self {
+            payment_method: Some(payment_method),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card,
+            metadata: item.metadata,
+            customer_id: item.customer,
+            card_network: None,
+            #[cfg(feature = "payouts")]
+            bank_transfer: None,
+            #[cfg(feature = "payouts")]
+            wallet: None,
+            client_secret: None,
+            payment_method_data: None,
+            billing: item.billing_details.map(From::from),
+            connector_mandate_details: None,
+            network_transaction_id: None,
+        })
+    }
+}
+
+fn to_unix_timestamp(time: Option<time::PrimitiveDateTime>) -> Option<i64> {
+    time.map(|time| time.assume_utc().unix_timestamp())
+}
+
+impl From<api_types::CardDetailFromLocker> for StripePaymentMethodCardResponse {
+    fn from(card: api_types::CardDetailFromLocker) -> Self {
+        Self {
+            brand: card
+                .card_network
+                .map(|network| network.to_string().to_lowercase())
+                .or(card.scheme.map(|scheme| scheme.to_lowercase())),
+            country: card.issuer_country,
+            exp_month: card.expiry_month,
+            exp_year: card.expiry_year,
+            last4: card.last4_digits,
+            fingerprint: card.card_fingerprint,
+        }
+    }
+}
+
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            stype: item.payment_method,
+            card: item.card.map(From::from),
+            customer: item.customer_id,
+            created: to_unix_timestamp(item.created),
+            metadata: item.metadata,
+        }
+    }
+}
+
+impl From<api_types::CustomerPaymentMethod> for StripePaymentMethodResponse {
+    fn from(item: api_types::CustomerPaymentMethod) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            stype: Some(item.payment_method),
+            card: item.card.map(From::from),
+            customer: Some(item.customer_id),
+            created: to_unix_timestamp(item.created),
+            metadata: item.metadata,
+        }
+    }
+}
+
+impl From<api_types::CustomerPaymentMethodsListResponse> for StripePaymentMethodListResponse {
+    fn from(item: api_types::CustomerPaymentMethodsListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/payment_methods",
+            has_more: false,
+            data: item
+                .customer_payment_methods
+                .into_iter()
+                .map(From::from)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    fn parse_create_request(form: &str) -> StripePaymentMethodCreateRequest {
+        serde_qs::Config::new(10, false)
+            .deserialize_str(form)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_card_create_request_conversion() {
+        let request = parse_create_request(
+            "type=card&card[number]=4242424242424242&card[exp_month]=12&card[exp_year]=2030&card[cvc]=123&customer=cus_123&billing_details[name]=John",
+        );
+
+        let payment_method_create = api_types::PaymentMethodCreate::try_from(request).unwrap();
+
+        assert_eq!(
+            payment_method_create.payment_method,
+            Some(api_enums::PaymentMethod::Card)
+        );
+        assert_eq!(
+            payment_method_create
+                .customer_id
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr()),
+            Some("cus_123")
+        );
+        let card = payment_method_create.card.unwrap();
+        assert_eq!(card.card_exp_month.expose(), "12");
+        assert_eq!(card.card_exp_year.expose(), "2030");
+        assert!(payment_method_create.billing.is_some());
+    }
+
+    #[test]
+    fn test_create_request_without_card_details_is_rejected() {
+        let request = parse_create_request("type=card&customer=cus_123");
+
+        assert!(api_types::PaymentMethodCreate::try_from(request).is_err());
+    }
+
+    #[test]
+    fn test_create_request_of_unsupported_type_is_rejected() {
+        let request = parse_create_request("type=wallet&customer=cus_123");
+
+        assert!(api_types::PaymentMethodCreate::try_from(request).is_err());
+    }
+
+    #[test]
+    fn test_payment_method_response_conversion() {
+        let payment_method: api_types::PaymentMethodResponse =
+            serde_json::from_value(serde_json::json!({
+                "merchant_id": "merchant_123",
+                "customer_id": "cus_123",
+                "payment_method_id": "pm_123",
+                "payment_method": "card",
+                "card": {
+                    "scheme": "Visa",
+                    "issuer_country": "US",
+                    "last4_digits": "4242",
+                    "expiry_month": "12",
+                    "expiry_year": "2030",
+                    "card_network": "Visa",
+                    "saved_to_locker": true
+                },
+                "recurring_enabled": true,
+                "installment_payment_enabled": false,
+                "created": "2024-01-01T00:00:00.000Z"
+            }))
+            .unwrap();
+
+        let response = StripePaymentMethodResponse::from(payment_method);
+
+        assert_eq!(response.id, "pm_123");
+        assert_eq!(response.object, "payment_method");
+        assert_eq!(response.stype, Some(api_enums::PaymentMethod::Card));
+        assert_eq!(
+            response
+                .customer
+                .as_ref()
+                .map(|customer_id| customer_id.get_string_repr()),
+            Some("cus_123")
+        );
+        assert_eq!(response.created, Some(1_704_067_200));
+        let card = response.card.unwrap();
+        assert_eq!(card.brand.as_deref(), Some("visa"));
+        assert_eq!(card.country.as_deref(), Some("US"));
+        assert_eq!(card.last4.as_deref(), Some("4242"));
+    }
+
+    #[test]
+    fn test_empty_customer_payment_methods_list_conversion() {
+        let response =
+            StripePaymentMethodListResponse::from(api_types::CustomerPaymentMethodsListResponse {
+                customer_payment_methods: Vec::new(),
+                is_guest_customer: None,
+            });
+
+        assert_eq!(response.object, "list");
+        assert_eq!(response.url, "/v1/payment_methods");
+        assert!(!response.has_more);
+        assert!(response.data.is_empty());
+    }
+}
@@ -0,0 +1,191 @@
+pub mod types;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+#[cfg(feature = "olap")]
+use crate::core::payouts::payouts_list_core;
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{
+        api_locking,
+        payouts::{payouts_cancel_core, payouts_create_core, payouts_retrieve_core},
+    },
+    routes,
+    services::{api, authentication as auth},
+    types::api::payouts as payout_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
+pub async fn payout_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripePayoutCreateRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_payout_req = match payout_types::PayoutCreateRequest::try_from(payload) {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PayoutsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayout,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payout_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            payouts_create_core(state, platform, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
+pub async fn payout_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::PayoutId>,
+) -> HttpResponse {
+    let payload = payout_types::PayoutRetrieveRequest {
+        payout_id: path.into_inner(),
+        force_sync: Some(false),
+        merchant_id: None,
+    };
+
+    let flow = Flow::PayoutsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayout,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.clone().into();
+            payouts_retrieve_core(state, platform, auth.profile_id, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
+pub async fn payout_cancel(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::PayoutId>,
+) -> HttpResponse {
+    let payload = payout_types::PayoutActionRequest {
+        payout_id: path.into_inner(),
+    };
+
+    let flow = Flow::PayoutsCancel;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayout,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            payouts_cancel_core(state, platform, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+pub async fn payout_list(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    query: web::Query<types::StripePayoutListConstraints>,
+) -> HttpResponse {
+    let payload = payout_types::PayoutListConstraints::from(query.into_inner());
+
+    let flow = Flow::PayoutsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayoutListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.clone().into();
+            payouts_list_core(
+                state,
+                platform,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,171 @@
+use api_models::{payments, payouts};
+use common_utils::{ext_traits::StringExt, id_type, pii, types::MinorUnit};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types::api::enums as api_enums};
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripePayoutCreateRequest {
+    pub amount: i64,
+    pub currency: String,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub method: Option<StripePayoutMethod>,
+    pub destination: Option<String>,
+    pub customer: Option<id_type::CustomerId>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StripePayoutListConstraints {
+    pub limit: Option<u32>,
+    pub starting_after: Option<id_type::PayoutId>,
+    pub ending_before: Option<id_type::PayoutId>,
+    pub customer: Option<id_type::CustomerId>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutMethod {
+    #[default]
+    Standard,
+    Instant,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutStatus {
+    Paid,
+    Pending,
+    InTransit,
+    Canceled,
+    Failed,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripePayout {
+    pub id: id_type::PayoutId,
+    pub object: &'static str,
+    pub amount: i64,
+    pub currency: String,
+    pub status: StripePayoutStatus,
+    pub method: StripePayoutMethod,
+    pub created: Option<i64>,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub destination: Option<String>,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StripePayoutListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePayout>,
+}
+
+impl TryFrom<StripePayoutCreateRequest> for payouts::PayoutCreateRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripePayoutCreateRequest) -> errors::RouterResult<Self> {
+        let currency = item
+            .currency
+            .to_uppercase()
+            .parse_enum("currency")
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "currency",
+            })?;
+        let priority = match item.method.unwrap_or_default() {
+            StripePayoutMethod::Standard => api_enums::PayoutSendPriority::Regular,
+            StripePayoutMethod::Instant => api_enums::PayoutSendPriority::Instant,
+        };
+
+        // Stripe processes payouts as soon as they are created
+        Ok(Self {
+            amount: Some(payments::Amount::from(MinorUnit::new(item.amount))),
+            currency: Some(currency),
+            confirm: Some(true),
+            auto_fulfill: Some(true),
+            description: item.description,
+            metadata: item.metadata,
+            payout_method_id: item.destination,
+            customer_id: item.customer,
+            priority: Some(priority),
+            ..Default::default()
+        })
+    }
+}
+
+impl From<StripePayoutListConstraints> for payouts::PayoutListConstraints {
+    fn from(item: StripePayoutListConstraints) -> Self {
+        Self {
+            customer_id: item.customer,
+            starting_after: item.starting_after,
+            ending_before: item.ending_before,
+            limit: item
+                .limit
+                .unwrap_or_else(common_utils::consts::default_payouts_list_limit),
+            created: None,
+            time_range: None,
+        }
+    }
+}
+
+impl From<api_enums::PayoutStatus> for StripePayoutStatus {
+    fn from(status: api_enums::PayoutStatus) -> Self {
+        match status {
+            api_enums::PayoutStatus::Success => Self::Paid,
+            api_enums::PayoutStatus::Initiated => Self::InTransit,
+            api_enums::PayoutStatus::Cancelled => Self::Canceled,
+            api_enums::PayoutStatus::Failed
+            | api_enums::PayoutStatus::Expired
+            | api_enums::PayoutStatus::Reversed
+            | api_enums::PayoutStatus::Ineligible => Self::Failed,
+            api_enums::PayoutStatus::Pending
+            | api_enums::PayoutStatus::RequiresCreation
+            | api_enums::PayoutStatus::RequiresFulfillment
+            | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::RequiresVendorAccountCreation
+            | api_enums::PayoutStatus::RequiresConfirmation => Self::Pending,
+        }
+    }
+}
+
+impl From<payouts::PayoutCreateResponse> for StripePayout {
+    fn from(item: payouts::PayoutCreateResponse) -> Self {
+        let method = match item.priority {
+            Some(api_enums::PayoutSendPriority::Instant) => StripePayoutMethod::Instant,
+            _ => StripePayoutMethod::Standard,
+        };
+        Self {
+            id: item.payout_id,
+            object: "payout",
+            amount: item.amount.get_amount_as_i64(),
+            currency: item.currency.to_string().to_lowercase(),
+            status: StripePayoutStatus::from(item.status),
+            method,
+            created: item.created.map(|t| t.assume_utc().unix_timestamp()),
+            description: item.description,
+            metadata: item.metadata,
+            destination: item.payout_method_id,
+            failure_code: item.error_code,
+            failure_message: item.error_message,
+        }
+    }
+}
+
+impl From<payouts::PayoutListResponse> for StripePayoutListResponse {
+    fn from(item: payouts::PayoutListResponse) -> Self {
+        let has_more = item
+            .total_count
+            .and_then(|total_count| usize::try_from(total_count).ok())
+            .is_some_and(|total_count| total_count > item.size);
+        Self {
+            object: "list",
+            url: "/v1/payouts",
+            has_more,
+            data: item.data.into_iter().map(From::from).collect(),
+        }
+    }
+}
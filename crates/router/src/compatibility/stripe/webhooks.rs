@@ -91,6 +91,8 @@ pub enum StripeWebhookObject {
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
     Subscriptions,
+    #[cfg(feature = "payouts")]
+    PayoutBatch,
 }
 
 #[derive(Serialize, Debug)]
@@ -266,7 +268,7 @@ impl From<DisputeStatus> for StripeDisputeStatus {
     }
 }
 
-fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
+pub(super) fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
     match event_type {
         api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
         api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
@@ -287,6 +289,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
         api_models::enums::EventType::DisputeWon => "dispute.won",
         api_models::enums::EventType::DisputeLost => "dispute.lost",
+        api_models::enums::EventType::DisputeDeadlineApproaching => "dispute.deadline_approaching",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
 
@@ -304,6 +307,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
         api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::PayoutBatchCompleted => "payout_batch.completed",
     }
 }
 
@@ -349,6 +353,10 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api_models::webhooks::OutgoingWebhookContent::SubscriptionDetails(_) => {
                 Self::Subscriptions
             }
+            #[cfg(feature = "payouts")]
+            api_models::webhooks::OutgoingWebhookContent::PayoutBatchDetails(_) => {
+                Self::PayoutBatch
+            }
         }
     }
 }
@@ -321,6 +321,23 @@ impl SecretsHandler for settings::ChatSettings {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for crate::events::EventsConfig {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let events_config = match value.get_inner() {
+            Self::Kafka { kafka } => Self::Kafka {
+                kafka: Box::new(kafka.fetch_secrets(secret_management_client).await?),
+            },
+            events_config => events_config.clone(),
+        };
+
+        Ok(value.transition_state(|_| events_config))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::NetworkTokenizationService {
     async fn convert_to_raw_secret(
@@ -478,6 +495,20 @@ pub(crate) async fn fetch_raw_secrets(
         .await
         .expect("Failed to decrypt chat configs");
 
+    #[allow(clippy::expect_used)]
+    let events =
+        crate::events::EventsConfig::convert_to_raw_secret(conf.events, secret_management_client)
+            .await
+            .expect("Failed to decrypt events configs");
+
+    #[allow(clippy::expect_used)]
+    let file_storage = external_services::file_storage::FileStorageConfig::convert_to_raw_secret(
+        conf.file_storage,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt file storage configs");
+
     #[allow(clippy::expect_used)]
     let superposition =
         external_services::superposition::SuperpositionClientConfig::convert_to_raw_secret(
@@ -516,13 +547,16 @@ pub(crate) async fn fetch_raw_secrets(
         payout_method_filters: conf.payout_method_filters,
         bank_config: conf.bank_config,
         api_keys,
-        file_storage: conf.file_storage,
+        file_storage,
+        file_validation: conf.file_validation,
         tokenization: conf.tokenization,
         connector_customer: conf.connector_customer,
         #[cfg(feature = "dummy_connector")]
         dummy_connector: conf.dummy_connector,
         #[cfg(feature = "email")]
         email: conf.email,
+        #[cfg(feature = "email")]
+        email_outbox: conf.email_outbox,
         user: conf.user,
         mandates: conf.mandates,
         zero_mandates: conf.zero_mandates,
@@ -551,13 +585,15 @@ pub(crate) async fn fetch_raw_secrets(
         analytics,
         #[cfg(feature = "olap")]
         opensearch: conf.opensearch,
+        #[cfg(feature = "olap")]
+        global_search: conf.global_search,
         #[cfg(feature = "kv_store")]
         kv_config: conf.kv_config,
         #[cfg(feature = "frm")]
         frm: conf.frm,
         #[cfg(feature = "olap")]
         report_download_config: conf.report_download_config,
-        events: conf.events,
+        events,
         #[cfg(feature = "olap")]
         connector_onboarding,
         cors: conf.cors,
@@ -5,7 +5,7 @@ use std::{
 };
 
 #[cfg(feature = "olap")]
-use analytics::{opensearch::OpenSearchConfig, ReportConfig};
+use analytics::{opensearch::OpenSearchConfig, search::GlobalSearchConfig, ReportConfig};
 use api_models::enums;
 use common_utils::{ext_traits::ConfigExt, id_type, types::user::EmailThemeConfig};
 use config::{Environment, File};
@@ -14,6 +14,7 @@ use error_stack::ResultExt;
 use external_services::email::EmailSettings;
 use external_services::{
     crm::CrmManagerConfig,
+    file_scanner::FileScannerConfig,
     file_storage::FileStorageConfig,
     grpc_client::GrpcClientSettings,
     managers::{
@@ -97,7 +98,8 @@ pub struct Settings<S: SecretState> {
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: SecretStateContainer<ApiKeys, S>,
-    pub file_storage: FileStorageConfig,
+    pub file_storage: SecretStateContainer<FileStorageConfig, S>,
+    pub file_validation: FileValidationConfig,
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub tokenization: TokenizationConfig,
@@ -106,6 +108,8 @@ pub struct Settings<S: SecretState> {
     pub dummy_connector: DummyConnector,
     #[cfg(feature = "email")]
     pub email: EmailSettings,
+    #[cfg(feature = "email")]
+    pub email_outbox: EmailOutboxSettings,
     pub user: UserSettings,
     pub crm: CrmManagerConfig,
     pub cors: CorsSettings,
@@ -144,7 +148,9 @@ pub struct Settings<S: SecretState> {
     pub report_download_config: ReportConfig,
     #[cfg(feature = "olap")]
     pub opensearch: OpenSearchConfig,
-    pub events: EventsConfig,
+    #[cfg(feature = "olap")]
+    pub global_search: GlobalSearchConfig,
+    pub events: SecretStateContainer<EventsConfig, S>,
     #[cfg(feature = "olap")]
     pub connector_onboarding: SecretStateContainer<ConnectorOnboarding, S>,
     pub unmasked_headers: UnmaskedHeaders,
@@ -211,6 +217,27 @@ pub struct Platform {
     pub allow_connected_merchants: bool,
 }
 
+#[cfg(feature = "email")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct EmailOutboxSettings {
+    /// ARN of the SNS topic Amazon SES publishes bounces and complaints to. Delivery events from
+    /// any other topic are rejected.
+    pub delivery_events_topic_arn: String,
+    /// Number of days emails are kept in the email outbox after they are sent
+    pub retention_days: i64,
+}
+
+#[cfg(feature = "email")]
+impl Default for EmailOutboxSettings {
+    fn default() -> Self {
+        Self {
+            delivery_events_topic_arn: String::new(),
+            retention_days: 30,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct ChatSettings {
@@ -264,6 +291,7 @@ impl TenantConfig {
         #[allow(clippy::expect_used)]
         let event_handler = conf
             .events
+            .get_inner()
             .get_event_handler()
             .await
             .expect("Failed to create event handler");
@@ -297,6 +325,7 @@ impl TenantConfig {
         #[allow(clippy::expect_used)]
         let event_handler = conf
             .events
+            .get_inner()
             .get_event_handler()
             .await
             .expect("Failed to create event handler");
@@ -1020,6 +1049,8 @@ impl Settings<SecuredSecret> {
         self.locker.validate()?;
         self.connectors.validate("connectors")?;
         self.chat.get_inner().validate()?;
+        #[cfg(feature = "email")]
+        self.email_outbox.validate()?;
         self.cors.validate()?;
 
         self.scheduler
@@ -1031,18 +1062,23 @@ impl Settings<SecuredSecret> {
         self.api_keys.get_inner().validate()?;
 
         self.file_storage
+            .get_inner()
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
+        self.file_validation.validate()?;
+
         self.crm
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
-        self.events.validate()?;
+        self.events.get_inner().validate()?;
 
         #[cfg(feature = "olap")]
         self.opensearch.validate()?;
+        #[cfg(feature = "olap")]
+        self.report_download_config.validate()?;
 
         self.encryption_management
             .validate()
@@ -1201,6 +1237,38 @@ impl Default for CellInformation {
     }
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct FileValidationConfig {
+    pub scanner: FileScannerConfig,
+    pub dispute_evidence: FilePurposeValidationConfig,
+}
+
+/// Limits enforced on files uploaded for a specific purpose, before they are stored
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FilePurposeValidationConfig {
+    pub max_file_size_in_bytes: i32,
+    pub max_pdf_pages: usize,
+    /// Mime types accepted for the purpose, matched against the sniffed content type. All types
+    /// are accepted when not set, merchants can still restrict the types for their own uploads.
+    #[serde(deserialize_with = "deserialize_optional_hashset")]
+    pub allowed_file_types: Option<HashSet<String>>,
+    /// Re-encode images so that EXIF and other embedded metadata is not stored
+    pub strip_image_metadata: bool,
+}
+
+impl Default for FilePurposeValidationConfig {
+    fn default() -> Self {
+        Self {
+            max_file_size_in_bytes: 5 * 1024 * 1024,
+            max_pdf_pages: 50,
+            allowed_file_types: None,
+            strip_image_metadata: true,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ThemeSettings {
     pub storage: FileStorageConfig,
@@ -355,3 +355,38 @@ impl super::settings::ChatSettings {
         })
     }
 }
+
+#[cfg(feature = "email")]
+impl super::settings::EmailOutboxSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.retention_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "email outbox retention days must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::FileValidationConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.scanner
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+
+        self.dispute_evidence.validate("dispute_evidence")
+    }
+}
+
+impl super::settings::FilePurposeValidationConfig {
+    pub fn validate(&self, purpose: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_file_size_in_bytes <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "file_validation.{purpose}.max_file_size_in_bytes must be greater than zero"
+            )))
+        })
+    }
+}
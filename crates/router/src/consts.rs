@@ -78,7 +78,17 @@ pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 #[cfg(feature = "email")]
 pub const EMAIL_TOKEN_BLACKLIST_PREFIX: &str = "BET_";
 
+/// Number of times sending an email from the email outbox is retried before it is marked as failed
+#[cfg(feature = "email")]
+pub const MAX_EMAIL_SEND_RETRIES: i32 = 5;
+
+/// Delay before the first retry of an email from the email outbox, doubled on every further retry
+#[cfg(feature = "email")]
+pub const EMAIL_SEND_RETRY_BASE_DELAY_IN_SECONDS: i64 = 60;
+
 pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
+pub const EMAIL_SUBJECT_ANALYTICS_REPORT_READY: &str = "Your Report is Ready";
+pub const EMAIL_SUBJECT_ANALYTICS_ALERT: &str = "Analytics Alert";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
@@ -348,6 +358,45 @@ pub const UCS_AUTH_CURRENCY_AUTH_KEY: &str = "currency-auth-key";
 /// Form field name for challenge request during creq submission
 pub const CREQ_CHALLENGE_REQUEST_KEY: &str = "creq";
 
+/// Maximum number of payout instructions accepted in a single payout batch
+pub const MAX_PAYOUT_BATCH_SIZE: usize = 10000;
+
+/// Number of payout batch items processed in a single run of the payout batch workflow
+pub const PAYOUT_BATCH_PROCESSING_CHUNK_SIZE: i64 = 100;
+
+/// Number of times a payout batch item is retried on transient failures before it is marked as failed
+pub const MAX_PAYOUT_BATCH_ITEM_RETRIES: i32 = 3;
+
+/// Delay before the payout batch workflow picks up items which were scheduled for a retry
+pub const PAYOUT_BATCH_ITEM_RETRY_DELAY_IN_SECONDS: i64 = 60;
+
+/// Maximum number of settlement lines accepted in a single settlement report
+pub const MAX_SETTLEMENT_REPORT_LINES: usize = 50000;
+
+/// Number of settlement lines which are matched against the database concurrently
+pub const SETTLEMENT_RECON_CONCURRENCY: usize = 16;
+
+/// Prefix of the file storage keys of settlement reports, followed by the merchant id
+pub const SETTLEMENT_REPORT_FILE_KEY_PREFIX: &str = "settlement_reports";
+
+/// Default number of recon results returned by the list API
+pub const DEFAULT_RECON_RESULTS_LIST_LIMIT: i64 = 100;
+
+/// Maximum number of recon results returned by the list API
+pub const MAX_RECON_RESULTS_LIST_LIMIT: i64 = 1000;
+
+/// Maximum number of recon results included in a single export
+pub const MAX_RECON_RESULTS_EXPORT_COUNT: i64 = 100000;
+
+/// Number of times a quarantined file is scanned again when the file scanner is unavailable
+pub const MAX_FILE_SCAN_RETRIES: i32 = 5;
+
+/// Delay before a quarantined file is scanned again when the file scanner is unavailable
+pub const FILE_SCAN_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+/// Validity of presigned URLs handed out for downloading files from the file storage
+pub const FILE_DOWNLOAD_URL_EXPIRY_IN_SECONDS: u64 = 60 * 15; // 15 minutes
+
 /// Superposition configuration keys
 pub mod superposition {
     /// CVV requirement configuration key
@@ -1,4 +1,12 @@
 pub mod admin;
+#[cfg(feature = "olap")]
+pub mod analytics_alert;
+#[cfg(feature = "olap")]
+pub mod analytics_export;
+#[cfg(feature = "olap")]
+pub mod analytics_metadata;
+#[cfg(feature = "olap")]
+pub mod analytics_report;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
@@ -21,6 +29,8 @@ pub mod customers;
 #[cfg(feature = "v1")]
 pub mod debit_routing;
 pub mod disputes;
+#[cfg(feature = "email")]
+pub mod email_outbox;
 pub mod encryption;
 pub mod errors;
 pub mod external_service_auth;
@@ -29,8 +39,11 @@ pub mod files;
 pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod kafka_events;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
+pub mod log_control;
 pub mod mandate;
 pub mod metrics;
 pub mod payment_link;
@@ -48,6 +61,7 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod process_tracker;
 pub mod profile_acquirer;
 #[cfg(feature = "v2")]
 pub mod proxy;
@@ -0,0 +1,856 @@
+use analytics::{
+    alerts::{self, AlertAnomaly, AlertSeriesKey, AnomalyThreshold},
+    errors::AnalyticsError,
+    AnalyticsProvider,
+};
+use api_models::analytics::{
+    alerts::{
+        AlertChannel, AlertHistoryRequest, AlertHistoryResponse, AlertMuteWindow, AlertResponse,
+        AlertRuleFilters, AlertRuleListResponse, AlertRuleResponse, CreateAlertRuleRequest,
+        UpdateAlertRuleRequest,
+    },
+    TimeRange,
+};
+use common_enums::{
+    AnalyticsAlertBaseline, AnalyticsAlertDeliveryStatus, AnalyticsAlertDirection,
+    AnalyticsAlertMetric,
+};
+use common_utils::{
+    crypto::{HmacSha512, SignMessage},
+    errors::CustomResult,
+    id_type,
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{errors, webhooks::utils as webhook_utils},
+    headers,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services::{self, ApplicationResponse},
+    types::storage,
+};
+
+const ANALYTICS_ALERT_TASK: &str = "ANALYTICS_ALERT";
+
+const MAX_ALERT_RULES_PER_MERCHANT: usize = 20;
+const MAX_ALERT_RULE_NAME_LENGTH: usize = 64;
+const MAX_ALERT_CHANNELS: usize = 5;
+
+const DEFAULT_EVALUATION_WINDOW_IN_MINS: u32 = 60;
+const MIN_EVALUATION_WINDOW_IN_MINS: u32 = 5;
+const MAX_EVALUATION_WINDOW_IN_MINS: u32 = 1440;
+
+const MIN_BASELINE_PERIODS: u32 = 2;
+const MAX_BASELINE_PERIODS: u32 = 48;
+
+const DEFAULT_DEVIATION_THRESHOLD: f64 = 3.0;
+const DEFAULT_MIN_VOLUME: u32 = 20;
+
+const DEFAULT_ALERT_HISTORY_LIMIT: u32 = 100;
+const MAX_ALERT_HISTORY_LIMIT: u32 = 1000;
+
+pub async fn create_alert_rule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request: CreateAlertRuleRequest,
+) -> CustomResult<ApplicationResponse<AlertRuleResponse>, AnalyticsError> {
+    let existing_rules = state
+        .store
+        .list_analytics_alert_rules_by_merchant_id(merchant_id)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics alert rules")?;
+    if existing_rules.len() >= MAX_ALERT_RULES_PER_MERCHANT {
+        return Err(report!(AnalyticsError::InvalidAlertRule(format!(
+            "at most {MAX_ALERT_RULES_PER_MERCHANT} alert rules can be created"
+        ))));
+    }
+
+    validate_metric(state, request.metric)?;
+    validate_name(&request.name)?;
+    let baseline = request.baseline.unwrap_or(AnalyticsAlertBaseline::Rolling);
+    let evaluation_window_in_mins = request
+        .evaluation_window_in_mins
+        .unwrap_or(DEFAULT_EVALUATION_WINDOW_IN_MINS);
+    validate_evaluation_window(evaluation_window_in_mins)?;
+    let baseline_periods = request
+        .baseline_periods
+        .unwrap_or_else(|| get_default_baseline_periods(baseline));
+    validate_baseline_periods(baseline_periods)?;
+    let deviation_threshold = request
+        .deviation_threshold
+        .unwrap_or(DEFAULT_DEVIATION_THRESHOLD);
+    validate_deviation_threshold(deviation_threshold)?;
+    validate_channels(&request.channels).await?;
+    validate_mute_windows(&request.mute_windows)?;
+
+    let now = common_utils::date_time::now();
+    let alert_rule = state
+        .store
+        .insert_analytics_alert_rule(storage::AnalyticsAlertRuleNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "alert_rule"),
+            merchant_id: merchant_id.clone(),
+            name: request.name,
+            metric: request.metric,
+            filters: encode_value(&request.filters)?,
+            baseline,
+            evaluation_window_in_mins: to_i32(evaluation_window_in_mins)?,
+            baseline_periods: to_i32(baseline_periods)?,
+            deviation_threshold,
+            direction: request
+                .direction
+                .unwrap_or_else(|| get_default_direction(request.metric)),
+            min_volume: to_i32(request.min_volume.unwrap_or(DEFAULT_MIN_VOLUME))?,
+            channels: Secret::new(encode_value(&request.channels)?),
+            mute_windows: encode_value(&request.mute_windows)?,
+            is_enabled: request.is_enabled.unwrap_or(true),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to insert analytics alert rule")?;
+
+    add_analytics_alert_task(state, &alert_rule)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to schedule analytics alert task")?;
+
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+pub async fn list_alert_rules(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<ApplicationResponse<AlertRuleListResponse>, AnalyticsError> {
+    let rules = state
+        .store
+        .list_analytics_alert_rules_by_merchant_id(merchant_id)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics alert rules")?
+        .into_iter()
+        .map(get_alert_rule_response)
+        .collect::<Result<_, _>>()?;
+
+    Ok(ApplicationResponse::Json(AlertRuleListResponse { rules }))
+}
+
+pub async fn retrieve_alert_rule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    rule_id: &str,
+) -> CustomResult<ApplicationResponse<AlertRuleResponse>, AnalyticsError> {
+    let alert_rule = find_alert_rule(state, merchant_id, rule_id).await?;
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+pub async fn update_alert_rule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    rule_id: &str,
+    request: UpdateAlertRuleRequest,
+) -> CustomResult<ApplicationResponse<AlertRuleResponse>, AnalyticsError> {
+    let alert_rule = find_alert_rule(state, merchant_id, rule_id).await?;
+
+    if let Some(name) = &request.name {
+        validate_name(name)?;
+    }
+    if let Some(evaluation_window_in_mins) = request.evaluation_window_in_mins {
+        validate_evaluation_window(evaluation_window_in_mins)?;
+    }
+    if let Some(baseline_periods) = request.baseline_periods {
+        validate_baseline_periods(baseline_periods)?;
+    }
+    if let Some(deviation_threshold) = request.deviation_threshold {
+        validate_deviation_threshold(deviation_threshold)?;
+    }
+    if let Some(channels) = &request.channels {
+        validate_channels(channels).await?;
+    }
+    if let Some(mute_windows) = &request.mute_windows {
+        validate_mute_windows(mute_windows)?;
+    }
+
+    let alert_rule_update = storage::AnalyticsAlertRuleUpdate::Update {
+        name: request.name,
+        filters: request.filters.as_ref().map(encode_value).transpose()?,
+        baseline: request.baseline,
+        evaluation_window_in_mins: request.evaluation_window_in_mins.map(to_i32).transpose()?,
+        baseline_periods: request.baseline_periods.map(to_i32).transpose()?,
+        deviation_threshold: request.deviation_threshold,
+        direction: request.direction,
+        min_volume: request.min_volume.map(to_i32).transpose()?,
+        channels: request
+            .channels
+            .as_ref()
+            .map(encode_value)
+            .transpose()?
+            .map(Secret::new),
+        mute_windows: request
+            .mute_windows
+            .as_ref()
+            .map(encode_value)
+            .transpose()?,
+        is_enabled: request.is_enabled,
+    };
+    let alert_rule = state
+        .store
+        .update_analytics_alert_rule(alert_rule, alert_rule_update)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to update analytics alert rule")?;
+
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+/// Deletes a rule along with its schedule, the alerts it raised are kept in the alert history
+pub async fn delete_alert_rule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    rule_id: &str,
+) -> CustomResult<ApplicationResponse<AlertRuleResponse>, AnalyticsError> {
+    let alert_rule = state
+        .store
+        .delete_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::AlertRuleNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+
+    // The task of the rule finishes itself the next time it runs
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+pub async fn list_alert_history(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request: AlertHistoryRequest,
+) -> CustomResult<ApplicationResponse<AlertHistoryResponse>, AnalyticsError> {
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_ALERT_HISTORY_LIMIT)
+        .min(MAX_ALERT_HISTORY_LIMIT);
+    let alerts = state
+        .store
+        .list_analytics_alert_history_by_merchant_id(
+            merchant_id,
+            request.rule_id.as_deref(),
+            i64::from(limit),
+            i64::from(request.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics alert history")?
+        .into_iter()
+        .map(get_alert_response)
+        .collect();
+
+    Ok(ApplicationResponse::Json(AlertHistoryResponse { alerts }))
+}
+
+async fn find_alert_rule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    rule_id: &str,
+) -> CustomResult<storage::AnalyticsAlertRule, AnalyticsError> {
+    state
+        .store
+        .find_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::AlertRuleNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })
+}
+
+async fn add_analytics_alert_task(
+    state: &SessionState,
+    alert_rule: &storage::AnalyticsAlertRule,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = common_enums::ProcessTrackerRunner::AnalyticsAlertWorkflow;
+    let process_tracker_id = format!("{runner}_{ANALYTICS_ALERT_TASK}_{}", alert_rule.id);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ANALYTICS_ALERT_TASK,
+        runner,
+        ["ANALYTICS", "ALERT"],
+        storage::AnalyticsAlertTrackingData {
+            rule_id: alert_rule.id.clone(),
+        },
+        None,
+        alert_rule.created_at,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "analytics_alert")),
+    );
+    Ok(())
+}
+
+/// Time the rule is to be evaluated at next, one evaluation window from now
+pub fn get_next_evaluation_time(
+    alert_rule: &storage::AnalyticsAlertRule,
+) -> time::PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::minutes(i64::from(
+        alert_rule.evaluation_window_in_mins,
+    )))
+}
+
+/// Compares the metric of the rule over the latest evaluation window with its baseline, records
+/// the anomalies detected in the alert history and delivers them to the channels of the rule,
+/// unless a mute window of the rule is in effect
+pub async fn evaluate_alert_rule(
+    state: &SessionState,
+    alert_rule: &storage::AnalyticsAlertRule,
+) -> CustomResult<(), AnalyticsError> {
+    let filters: AlertRuleFilters = decode_value(alert_rule.filters.clone())?;
+    let channels: Vec<AlertChannel> = decode_value(alert_rule.channels.clone().expose())?;
+    let mute_windows: Vec<AlertMuteWindow> = decode_value(alert_rule.mute_windows.clone())?;
+
+    let now = common_utils::date_time::now();
+    let window = time::Duration::minutes(i64::from(alert_rule.evaluation_window_in_mins));
+    let latest_window = TimeRange {
+        start_time: now.saturating_sub(window),
+        end_time: Some(now),
+    };
+
+    let latest = state
+        .pool
+        .get_alert_series(
+            alert_rule.metric,
+            &alert_rule.merchant_id,
+            &filters,
+            &latest_window,
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to load the latest alert series")?;
+
+    let mut baseline = Vec::new();
+    for period in 1..=alert_rule.baseline_periods {
+        let offset = match alert_rule.baseline {
+            AnalyticsAlertBaseline::Rolling => window.saturating_mul(period),
+            AnalyticsAlertBaseline::DailySeasonal => time::Duration::days(i64::from(period)),
+            AnalyticsAlertBaseline::WeeklySeasonal => time::Duration::weeks(i64::from(period)),
+        };
+        let baseline_window = TimeRange {
+            start_time: latest_window.start_time.saturating_sub(offset),
+            end_time: Some(now.saturating_sub(offset)),
+        };
+        baseline.push(
+            state
+                .pool
+                .get_alert_series(
+                    alert_rule.metric,
+                    &alert_rule.merchant_id,
+                    &filters,
+                    &baseline_window,
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to load a baseline alert series")?,
+        );
+    }
+
+    let anomalies = alerts::detect_anomalies(
+        alert_rule.metric,
+        &latest,
+        &baseline,
+        AnomalyThreshold {
+            deviation_threshold: alert_rule.deviation_threshold,
+            direction: alert_rule.direction,
+            min_volume: u64::try_from(alert_rule.min_volume).unwrap_or_default(),
+        },
+    );
+
+    let is_muted = mute_windows
+        .iter()
+        .any(|mute_window| mute_window.contains(now));
+    let mut alert_history = Vec::with_capacity(anomalies.len());
+    for anomaly in anomalies {
+        // Alerts are recorded as muted unless they are delivered
+        let mut alert = get_alert_history(alert_rule, anomaly, &latest_window, now);
+        if !is_muted {
+            alert.delivery_status = deliver_alert(state, alert_rule, &channels, &alert).await;
+        }
+        alert_history.push(alert);
+    }
+
+    if !alert_history.is_empty() {
+        state
+            .store
+            .insert_analytics_alert_history(alert_history)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to insert analytics alert history")?;
+    }
+
+    state
+        .store
+        .update_analytics_alert_rule(
+            alert_rule.clone(),
+            storage::AnalyticsAlertRuleUpdate::Evaluated {
+                last_evaluated_at: now,
+            },
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to update analytics alert rule")?;
+
+    Ok(())
+}
+
+fn get_alert_history(
+    alert_rule: &storage::AnalyticsAlertRule,
+    anomaly: AlertAnomaly,
+    window: &TimeRange,
+    now: time::PrimitiveDateTime,
+) -> storage::AnalyticsAlertHistoryNew {
+    let AlertSeriesKey {
+        profile_id,
+        connector,
+        payment_method,
+        error_code,
+    } = anomaly.key;
+
+    storage::AnalyticsAlertHistoryNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "alert"),
+        rule_id: alert_rule.id.clone(),
+        merchant_id: alert_rule.merchant_id.clone(),
+        metric: alert_rule.metric,
+        profile_id: profile_id.and_then(|profile_id| {
+            id_type::ProfileId::try_from(std::borrow::Cow::from(profile_id)).ok()
+        }),
+        connector,
+        payment_method,
+        error_code,
+        observed_value: anomaly.observed.value,
+        baseline_mean: anomaly.baseline_mean,
+        baseline_stddev: anomaly.baseline_stddev,
+        sample_size: i64::try_from(anomaly.observed.sample_size).unwrap_or(i64::MAX),
+        window_start: window.start_time,
+        window_end: window.end_time.unwrap_or(now),
+        delivery_status: AnalyticsAlertDeliveryStatus::Muted,
+        created_at: now,
+    }
+}
+
+/// Delivers the alert to every channel of the rule, the delivery is reported as failed if any of
+/// the channels could not be delivered to
+async fn deliver_alert(
+    state: &SessionState,
+    alert_rule: &storage::AnalyticsAlertRule,
+    channels: &[AlertChannel],
+    alert: &storage::AnalyticsAlertHistoryNew,
+) -> AnalyticsAlertDeliveryStatus {
+    let mut delivery_status = AnalyticsAlertDeliveryStatus::Sent;
+    for channel in channels {
+        let result = match channel {
+            AlertChannel::Webhook { url } => send_alert_webhook(state, url, alert).await,
+            AlertChannel::Slack { url } => send_alert_slack(state, url, alert_rule, alert).await,
+            AlertChannel::Email { email } => {
+                send_alert_email(state, email, alert_rule, alert).await
+            }
+        };
+        if let Err(error) = result {
+            logger::error!(?error, rule_id = %alert_rule.id, "Failed to deliver analytics alert");
+            delivery_status = AnalyticsAlertDeliveryStatus::Failed;
+        }
+    }
+    delivery_status
+}
+
+async fn send_alert_webhook(
+    state: &SessionState,
+    webhook_url: &str,
+    alert: &storage::AnalyticsAlertHistoryNew,
+) -> CustomResult<(), AnalyticsError> {
+    // The URL is validated again, as the addresses its host resolves to may have changed since
+    // the rule was created
+    let webhook_url = webhook_utils::validate_webhook_url(webhook_url)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Alert webhook URL is not allowed")?;
+
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &alert.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch merchant key store")?;
+    let webhook_signing_key = db
+        .find_merchant_account_by_merchant_id(&alert.merchant_id, &key_store)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch merchant account")?
+        .payment_response_hash_key
+        .ok_or(AnalyticsError::UnknownError)
+        .attach_printable("Merchant does not have a webhook signing key")?;
+
+    // The alert is delivered as it is recorded once the delivery succeeds
+    let alert = AlertResponse {
+        alert_id: alert.id.clone(),
+        rule_id: alert.rule_id.clone(),
+        metric: alert.metric,
+        profile_id: alert.profile_id.clone(),
+        connector: alert.connector.clone(),
+        payment_method: alert.payment_method.clone(),
+        error_code: alert.error_code.clone(),
+        observed_value: alert.observed_value,
+        baseline_mean: alert.baseline_mean,
+        baseline_stddev: alert.baseline_stddev,
+        sample_size: alert.sample_size,
+        window_start: alert.window_start,
+        window_end: alert.window_end,
+        delivery_status: AnalyticsAlertDeliveryStatus::Sent,
+        created_at: alert.created_at,
+    };
+    let body = serde_json::to_vec(&alert)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to serialize alert webhook")?;
+    let signature = HmacSha512
+        .sign_message(webhook_signing_key.as_bytes(), &body)
+        .map(hex::encode)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to sign alert webhook")?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url.as_str())
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/json")
+        .header(headers::X_WEBHOOK_SIGNATURE, &signature)
+        .set_body(RequestContent::RawBytes(body))
+        .build();
+
+    send_alert_request(state, request).await
+}
+
+async fn send_alert_slack(
+    state: &SessionState,
+    webhook_url: &Secret<String>,
+    alert_rule: &storage::AnalyticsAlertRule,
+    alert: &storage::AnalyticsAlertHistoryNew,
+) -> CustomResult<(), AnalyticsError> {
+    let webhook_url = webhook_utils::validate_webhook_url(webhook_url.peek())
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Alert Slack webhook URL is not allowed")?;
+
+    let text = format!(
+        "*{}*: the {} of {} was {:.2} between {} and {}, against a baseline of {:.2} ± {:.2}",
+        alert_rule.name,
+        alert.metric,
+        describe_alert_series(alert),
+        alert.observed_value,
+        alert.window_start,
+        alert.window_end,
+        alert.baseline_mean,
+        alert.baseline_stddev,
+    );
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url.as_str())
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/json")
+        .set_body(RequestContent::Json(Box::new(
+            serde_json::json!({ "text": text }),
+        )))
+        .build();
+
+    send_alert_request(state, request).await
+}
+
+async fn send_alert_request(
+    state: &SessionState,
+    request: services::Request,
+) -> CustomResult<(), AnalyticsError> {
+    let response = state
+        .api_client
+        .send_request(state, request, None, false)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to send alert")?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(report!(AnalyticsError::UnknownError)).attach_printable(format!(
+            "Alert was responded to with status {}",
+            response.status()
+        ))
+    }
+}
+
+#[cfg(feature = "email")]
+async fn send_alert_email(
+    state: &SessionState,
+    email: &Secret<String, common_utils::pii::EmailStrategy>,
+    alert_rule: &storage::AnalyticsAlertRule,
+    alert: &storage::AnalyticsAlertHistoryNew,
+) -> CustomResult<(), AnalyticsError> {
+    use crate::{
+        services::email::{outbox as email_outbox, types::AnalyticsAlert},
+        types::domain::UserEmail,
+    };
+
+    let email_contents = AnalyticsAlert {
+        recipient_email: UserEmail::new(email.clone())
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Invalid alert email")?,
+        subject: consts::EMAIL_SUBJECT_ANALYTICS_ALERT,
+        rule_name: alert_rule.name.clone(),
+        metric: alert.metric,
+        dimensions: describe_alert_series(alert),
+        observed_value: alert.observed_value,
+        baseline_mean: alert.baseline_mean,
+        baseline_stddev: alert.baseline_stddev,
+        sample_size: alert.sample_size,
+        window_start: alert.window_start,
+        window_end: alert.window_end,
+        theme_id: None,
+    };
+    email_outbox::enqueue_email(state, Box::new(email_contents))
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to queue alert email")
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_alert_email(
+    _state: &SessionState,
+    _email: &Secret<String, common_utils::pii::EmailStrategy>,
+    _alert_rule: &storage::AnalyticsAlertRule,
+    _alert: &storage::AnalyticsAlertHistoryNew,
+) -> CustomResult<(), AnalyticsError> {
+    Err(report!(AnalyticsError::NotImplemented("Email alerts")))
+}
+
+/// Human readable description of the series an alert was raised for
+fn describe_alert_series(alert: &storage::AnalyticsAlertHistoryNew) -> String {
+    let dimensions: Vec<String> = [
+        alert
+            .profile_id
+            .as_ref()
+            .map(|profile_id| format!("profile {}", profile_id.get_string_repr())),
+        alert
+            .connector
+            .as_ref()
+            .map(|connector| format!("connector {connector}")),
+        alert
+            .payment_method
+            .as_ref()
+            .map(|payment_method| format!("payment method {payment_method}")),
+        alert
+            .error_code
+            .as_ref()
+            .map(|error_code| format!("error code {error_code}")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if dimensions.is_empty() {
+        "all payments".to_string()
+    } else {
+        dimensions.join(", ")
+    }
+}
+
+fn validate_metric(
+    state: &SessionState,
+    metric: AnalyticsAlertMetric,
+) -> CustomResult<(), AnalyticsError> {
+    match (metric, &state.pool) {
+        (AnalyticsAlertMetric::Latency, AnalyticsProvider::Sqlx(_)) => {
+            Err(report!(AnalyticsError::InvalidAlertRule(
+                "latency alerts require the clickhouse analytics source".to_string()
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_name(name: &str) -> CustomResult<(), AnalyticsError> {
+    if name.trim().is_empty() || name.chars().count() > MAX_ALERT_RULE_NAME_LENGTH {
+        return Err(report!(AnalyticsError::InvalidAlertRule(format!(
+            "name must be between 1 and {MAX_ALERT_RULE_NAME_LENGTH} characters long"
+        ))));
+    }
+    Ok(())
+}
+
+fn validate_evaluation_window(evaluation_window_in_mins: u32) -> CustomResult<(), AnalyticsError> {
+    if !(MIN_EVALUATION_WINDOW_IN_MINS..=MAX_EVALUATION_WINDOW_IN_MINS)
+        .contains(&evaluation_window_in_mins)
+    {
+        return Err(report!(AnalyticsError::InvalidAlertRule(format!(
+            "evaluation window must be between {MIN_EVALUATION_WINDOW_IN_MINS} and \
+            {MAX_EVALUATION_WINDOW_IN_MINS} minutes"
+        ))));
+    }
+    Ok(())
+}
+
+fn validate_baseline_periods(baseline_periods: u32) -> CustomResult<(), AnalyticsError> {
+    if !(MIN_BASELINE_PERIODS..=MAX_BASELINE_PERIODS).contains(&baseline_periods) {
+        return Err(report!(AnalyticsError::InvalidAlertRule(format!(
+            "baseline periods must be between {MIN_BASELINE_PERIODS} and {MAX_BASELINE_PERIODS}"
+        ))));
+    }
+    Ok(())
+}
+
+fn validate_deviation_threshold(deviation_threshold: f64) -> CustomResult<(), AnalyticsError> {
+    if !deviation_threshold.is_finite() || deviation_threshold <= 0.0 {
+        return Err(report!(AnalyticsError::InvalidAlertRule(
+            "deviation threshold must be a positive number".to_string()
+        )));
+    }
+    Ok(())
+}
+
+/// Channel URLs have to be public https URLs, so that alerts cannot be used to reach internal
+/// services
+async fn validate_channels(channels: &[AlertChannel]) -> CustomResult<(), AnalyticsError> {
+    if channels.is_empty() || channels.len() > MAX_ALERT_CHANNELS {
+        return Err(report!(AnalyticsError::InvalidAlertRule(format!(
+            "between 1 and {MAX_ALERT_CHANNELS} channels must be given"
+        ))));
+    }
+
+    for channel in channels {
+        let channel_url = match channel {
+            AlertChannel::Webhook { url } => url.as_str(),
+            AlertChannel::Slack { url } => url.peek().as_str(),
+            AlertChannel::Email { .. } => continue,
+        };
+        webhook_utils::validate_webhook_url(channel_url)
+            .await
+            .map_err(|error| {
+                let message = error.current_context().to_string();
+                error.change_context(AnalyticsError::InvalidAlertRule(message))
+            })?;
+    }
+    Ok(())
+}
+
+fn validate_mute_windows(mute_windows: &[AlertMuteWindow]) -> CustomResult<(), AnalyticsError> {
+    if mute_windows
+        .iter()
+        .any(|mute_window| mute_window.start_time >= mute_window.end_time)
+    {
+        return Err(report!(AnalyticsError::InvalidAlertRule(
+            "mute windows must start before they end".to_string()
+        )));
+    }
+    Ok(())
+}
+
+/// Seasonal baselines look further back per period, and need fewer periods
+fn get_default_baseline_periods(baseline: AnalyticsAlertBaseline) -> u32 {
+    match baseline {
+        AnalyticsAlertBaseline::Rolling => 24,
+        AnalyticsAlertBaseline::DailySeasonal => 7,
+        AnalyticsAlertBaseline::WeeklySeasonal => 4,
+    }
+}
+
+/// The success rate is watched for drops, the other metrics are watched for rises
+fn get_default_direction(metric: AnalyticsAlertMetric) -> AnalyticsAlertDirection {
+    match metric {
+        AnalyticsAlertMetric::SuccessRate => AnalyticsAlertDirection::Drop,
+        AnalyticsAlertMetric::DeclineCodeShare
+        | AnalyticsAlertMetric::Latency
+        | AnalyticsAlertMetric::Volume => AnalyticsAlertDirection::Rise,
+    }
+}
+
+fn get_alert_rule_response(
+    alert_rule: storage::AnalyticsAlertRule,
+) -> CustomResult<AlertRuleResponse, AnalyticsError> {
+    Ok(AlertRuleResponse {
+        filters: decode_value(alert_rule.filters)?,
+        channels: decode_value(alert_rule.channels.expose())?,
+        mute_windows: decode_value(alert_rule.mute_windows)?,
+        evaluation_window_in_mins: to_u32(alert_rule.evaluation_window_in_mins)?,
+        baseline_periods: to_u32(alert_rule.baseline_periods)?,
+        min_volume: to_u32(alert_rule.min_volume)?,
+        rule_id: alert_rule.id,
+        name: alert_rule.name,
+        metric: alert_rule.metric,
+        baseline: alert_rule.baseline,
+        deviation_threshold: alert_rule.deviation_threshold,
+        direction: alert_rule.direction,
+        is_enabled: alert_rule.is_enabled,
+        last_evaluated_at: alert_rule.last_evaluated_at,
+        created_at: alert_rule.created_at,
+        modified_at: alert_rule.modified_at,
+    })
+}
+
+fn get_alert_response(alert: storage::AnalyticsAlertHistory) -> AlertResponse {
+    AlertResponse {
+        alert_id: alert.id,
+        rule_id: alert.rule_id,
+        metric: alert.metric,
+        profile_id: alert.profile_id,
+        connector: alert.connector,
+        payment_method: alert.payment_method,
+        error_code: alert.error_code,
+        observed_value: alert.observed_value,
+        baseline_mean: alert.baseline_mean,
+        baseline_stddev: alert.baseline_stddev,
+        sample_size: alert.sample_size,
+        window_start: alert.window_start,
+        window_end: alert.window_end,
+        delivery_status: alert.delivery_status,
+        created_at: alert.created_at,
+    }
+}
+
+fn encode_value<T: serde::Serialize>(value: &T) -> CustomResult<serde_json::Value, AnalyticsError> {
+    serde_json::to_value(value)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to serialize alert rule")
+}
+
+fn decode_value<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+) -> CustomResult<T, AnalyticsError> {
+    serde_json::from_value(value)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to deserialize alert rule")
+}
+
+fn to_i32(value: u32) -> CustomResult<i32, AnalyticsError> {
+    i32::try_from(value).change_context(AnalyticsError::InvalidAlertRule(format!(
+        "{value} is out of range"
+    )))
+}
+
+fn to_u32(value: i32) -> CustomResult<u32, AnalyticsError> {
+    u32::try_from(value)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Alert rule has a negative value")
+}
@@ -0,0 +1,1022 @@
+use std::collections::{HashMap, HashSet};
+
+use analytics::{
+    errors::AnalyticsError,
+    exports,
+    reports::{ReportError, ReportSink},
+};
+use api_models::analytics::{
+    exports::{
+        CreateExportRequest, ExportDestination, ExportListResponse, ExportManifest,
+        ExportManifestFile, ExportResponse, ExportRunListRequest, ExportRunListResponse,
+        ExportRunResponse, UpdateExportRequest,
+    },
+    TimeRange,
+};
+use common_enums::{AnalyticsExportFrequency, AnalyticsExportRunStatus, AnalyticsReportType};
+use common_utils::{
+    encryption::Encryption,
+    errors::CustomResult,
+    id_type, type_name,
+    types::{authentication::AuthInfo, keymanager::Identifier},
+};
+use error_stack::{report, AttachmentKind, FrameKind, ResultExt};
+use external_services::file_storage::FileUpload;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+use sha2::Digest;
+
+use crate::{
+    consts,
+    core::errors,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+const ANALYTICS_EXPORT_TASK: &str = "ANALYTICS_EXPORT";
+
+const MAX_EXPORTS_PER_MERCHANT: usize = 20;
+const MAX_EXPORT_NAME_LENGTH: usize = 64;
+const MAX_FILTER_VALUES: usize = 100;
+const MAX_FILTER_VALUE_LENGTH: usize = 64;
+const MAX_PATH_LENGTH: usize = 255;
+
+const DEFAULT_EXPORT_RUN_LIST_LIMIT: u32 = 100;
+const MAX_EXPORT_RUN_LIST_LIMIT: u32 = 1000;
+
+pub async fn create_export(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    organization_id: &id_type::OrganizationId,
+    request: CreateExportRequest,
+) -> CustomResult<ApplicationResponse<ExportResponse>, AnalyticsError> {
+    let existing_exports = state
+        .store
+        .list_analytics_exports_by_merchant_id(merchant_id)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics exports")?;
+    if existing_exports.len() >= MAX_EXPORTS_PER_MERCHANT {
+        return Err(report!(AnalyticsError::InvalidExport(format!(
+            "at most {MAX_EXPORTS_PER_MERCHANT} exports can be created"
+        ))));
+    }
+
+    validate_name(&request.name)?;
+    let columns = get_export_columns(request.dataset, request.columns)?;
+    validate_filters(request.dataset, &request.filters)?;
+    let schedule_hour = request.schedule_hour.unwrap_or_default();
+    validate_schedule_hour(schedule_hour)?;
+    validate_destination(&request.destination)?;
+
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    let destination = encrypt_destination(state, &key_store, &request.destination).await?;
+    let now = common_utils::date_time::now();
+    let export = state
+        .store
+        .insert_analytics_export(storage::AnalyticsExportNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "export"),
+            merchant_id: merchant_id.clone(),
+            organization_id: organization_id.clone(),
+            name: request.name,
+            dataset: request.dataset,
+            columns: encode_value(&columns)?,
+            filters: encode_value(&request.filters)?,
+            format: request.format,
+            frequency: request.frequency,
+            schedule_hour: i32::from(schedule_hour),
+            destination,
+            is_enabled: request.is_enabled.unwrap_or(true),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to insert analytics export")?;
+
+    // The first run exports the latest period which has ended
+    add_analytics_export_task(state, &export.id, None, export.created_at)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to schedule analytics export task")?;
+
+    get_export_response(state, &key_store, export)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn list_exports(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<ApplicationResponse<ExportListResponse>, AnalyticsError> {
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    let exports = state
+        .store
+        .list_analytics_exports_by_merchant_id(merchant_id)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics exports")?;
+    let exports = futures::future::try_join_all(
+        exports
+            .into_iter()
+            .map(|export| get_export_response(state, &key_store, export)),
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(ExportListResponse { exports }))
+}
+
+pub async fn retrieve_export(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+) -> CustomResult<ApplicationResponse<ExportResponse>, AnalyticsError> {
+    let export = find_export(state, merchant_id, export_id).await?;
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    get_export_response(state, &key_store, export)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Updates an export. A new schedule hour takes effect after the next scheduled run.
+pub async fn update_export(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+    request: UpdateExportRequest,
+) -> CustomResult<ApplicationResponse<ExportResponse>, AnalyticsError> {
+    let export = find_export(state, merchant_id, export_id).await?;
+
+    if let Some(name) = &request.name {
+        validate_name(name)?;
+    }
+    let columns = request
+        .columns
+        .map(|columns| get_export_columns(export.dataset, Some(columns)))
+        .transpose()?;
+    if let Some(filters) = &request.filters {
+        validate_filters(export.dataset, filters)?;
+    }
+    if let Some(schedule_hour) = request.schedule_hour {
+        validate_schedule_hour(schedule_hour)?;
+    }
+    if let Some(destination) = &request.destination {
+        validate_destination(destination)?;
+    }
+
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    let destination = match &request.destination {
+        Some(destination) => Some(encrypt_destination(state, &key_store, destination).await?),
+        None => None,
+    };
+    let export_update = storage::AnalyticsExportUpdate::Update {
+        name: request.name,
+        columns: columns.as_ref().map(encode_value).transpose()?,
+        filters: request.filters.as_ref().map(encode_value).transpose()?,
+        format: request.format,
+        schedule_hour: request.schedule_hour.map(i32::from),
+        destination,
+        is_enabled: request.is_enabled,
+    };
+    let export = state
+        .store
+        .update_analytics_export(export, export_update)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to update analytics export")?;
+
+    get_export_response(state, &key_store, export)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Deletes an export along with its schedule, the files already delivered are kept
+pub async fn delete_export(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+) -> CustomResult<ApplicationResponse<ExportResponse>, AnalyticsError> {
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    let export = state
+        .store
+        .delete_analytics_export_by_merchant_id_export_id(merchant_id, export_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::ExportNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+
+    // The task of the export finishes itself the next time it runs
+    get_export_response(state, &key_store, export)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn list_export_runs(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+    request: ExportRunListRequest,
+) -> CustomResult<ApplicationResponse<ExportRunListResponse>, AnalyticsError> {
+    let export = find_export(state, merchant_id, export_id).await?;
+    let limit = request
+        .limit
+        .unwrap_or(DEFAULT_EXPORT_RUN_LIST_LIMIT)
+        .min(MAX_EXPORT_RUN_LIST_LIMIT);
+    let runs = state
+        .store
+        .list_analytics_export_runs_by_merchant_id_export_id(
+            merchant_id,
+            &export.id,
+            i64::from(limit),
+            i64::from(request.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to list analytics export runs")?
+        .into_iter()
+        .map(get_export_run_response)
+        .collect();
+
+    Ok(ApplicationResponse::Json(ExportRunListResponse { runs }))
+}
+
+/// Schedules a failed run to be exported again, the files of the run are replaced once the
+/// export succeeds
+pub async fn retry_export_run(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+    run_id: &str,
+) -> CustomResult<ApplicationResponse<ExportRunResponse>, AnalyticsError> {
+    let export = find_export(state, merchant_id, export_id).await?;
+    let export_run = state
+        .store
+        .find_analytics_export_run_by_merchant_id_run_id(merchant_id, run_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::ExportRunNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })?;
+    if export_run.export_id != export.id {
+        return Err(report!(AnalyticsError::ExportRunNotFound));
+    }
+    if export_run.status != AnalyticsExportRunStatus::Failed {
+        return Err(report!(AnalyticsError::InvalidExport(
+            "only failed runs can be retried".to_string()
+        )));
+    }
+
+    let export_run = state
+        .store
+        .update_analytics_export_run(export_run, storage::AnalyticsExportRunUpdate::Pending)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to update analytics export run")?;
+
+    add_analytics_export_task(
+        state,
+        &export.id,
+        Some(&export_run),
+        common_utils::date_time::now(),
+    )
+    .await
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to schedule analytics export retry task")?;
+
+    Ok(ApplicationResponse::Json(get_export_run_response(
+        export_run,
+    )))
+}
+
+async fn find_export(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    export_id: &str,
+) -> CustomResult<storage::AnalyticsExport, AnalyticsError> {
+    state
+        .store
+        .find_analytics_export_by_merchant_id_export_id(merchant_id, export_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::ExportNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })
+}
+
+/// Schedules the recurring task of an export, or a one-off task re-running a single run of the
+/// export when a run is given
+async fn add_analytics_export_task(
+    state: &SessionState,
+    export_id: &str,
+    export_run: Option<&storage::AnalyticsExportRun>,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = common_enums::ProcessTrackerRunner::AnalyticsExportWorkflow;
+    let process_tracker_id = match export_run {
+        Some(export_run) => format!(
+            "{runner}_{ANALYTICS_EXPORT_TASK}_{}_{}",
+            export_run.id, export_run.attempt_count
+        ),
+        None => format!("{runner}_{ANALYTICS_EXPORT_TASK}_{export_id}"),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ANALYTICS_EXPORT_TASK,
+        runner,
+        ["ANALYTICS", "EXPORT"],
+        storage::AnalyticsExportTrackingData {
+            export_id: export_id.to_owned(),
+            run_id: export_run.map(|export_run| export_run.id.clone()),
+        },
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "analytics_export")),
+    );
+    Ok(())
+}
+
+/// Start of the period of the frequency which contains the time. Weeks start on Monday.
+fn get_period_start(
+    frequency: AnalyticsExportFrequency,
+    time: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    let date = time.date();
+    let period_start_date = match frequency {
+        AnalyticsExportFrequency::Daily => date,
+        AnalyticsExportFrequency::Weekly => date.saturating_sub(time::Duration::days(i64::from(
+            date.weekday().number_days_from_monday(),
+        ))),
+        AnalyticsExportFrequency::Monthly => {
+            date.saturating_sub(time::Duration::days(i64::from(date.day()) - 1))
+        }
+    };
+    period_start_date.midnight()
+}
+
+fn get_next_period_start(
+    frequency: AnalyticsExportFrequency,
+    period_start: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    match frequency {
+        AnalyticsExportFrequency::Daily => period_start.saturating_add(time::Duration::days(1)),
+        AnalyticsExportFrequency::Weekly => period_start.saturating_add(time::Duration::weeks(1)),
+        // 31 days from the first day of a month always falls in the next month
+        AnalyticsExportFrequency::Monthly => get_period_start(
+            frequency,
+            period_start.saturating_add(time::Duration::days(31)),
+        ),
+    }
+}
+
+/// Periods become due for export at the schedule hour (UTC) of the day after they end
+fn get_due_time_offset(export: &storage::AnalyticsExport) -> time::Duration {
+    time::Duration::hours(i64::from(export.schedule_hour))
+}
+
+/// Start of the latest period of the export which is due for export
+pub fn get_latest_due_period_start(export: &storage::AnalyticsExport) -> time::PrimitiveDateTime {
+    let current_period_start = get_period_start(
+        export.frequency,
+        common_utils::date_time::now().saturating_sub(get_due_time_offset(export)),
+    );
+    get_period_start(
+        export.frequency,
+        current_period_start.saturating_sub(time::Duration::days(1)),
+    )
+}
+
+/// Time the next period of the export becomes due for export at
+pub fn get_next_run_time(export: &storage::AnalyticsExport) -> time::PrimitiveDateTime {
+    let current_period_start = get_period_start(
+        export.frequency,
+        common_utils::date_time::now().saturating_sub(get_due_time_offset(export)),
+    );
+    get_next_period_start(export.frequency, current_period_start)
+        .saturating_add(get_due_time_offset(export))
+}
+
+/// Exports the rows of the dataset created within the period starting at the given time, and
+/// delivers the file along with its manifest to the destination of the export. A period is
+/// exported once, unless its earlier run failed. Files are named after the period, so that
+/// re-running a period replaces its files.
+pub async fn run_export(
+    state: &SessionState,
+    export: &storage::AnalyticsExport,
+    period_start: time::PrimitiveDateTime,
+) -> CustomResult<storage::AnalyticsExportRun, AnalyticsError> {
+    let export_run = find_or_create_export_run(state, export, period_start).await?;
+    if export_run.status == AnalyticsExportRunStatus::Succeeded {
+        return Ok(export_run);
+    }
+
+    let attempt_count = export_run.attempt_count + 1;
+    let export_run = state
+        .store
+        .update_analytics_export_run(
+            export_run,
+            storage::AnalyticsExportRunUpdate::Running { attempt_count },
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to update analytics export run")?;
+
+    let (export_run_update, result) = match export_period(state, export, &export_run).await {
+        Ok(export_run_update) => (export_run_update, Ok(())),
+        Err(error) => (
+            storage::AnalyticsExportRunUpdate::Failed {
+                error_message: get_error_message(&error),
+            },
+            Err(error),
+        ),
+    };
+    let export_run = state
+        .store
+        .update_analytics_export_run(export_run, export_run_update)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to update analytics export run")?;
+
+    result.map(|()| export_run)
+}
+
+async fn find_or_create_export_run(
+    state: &SessionState,
+    export: &storage::AnalyticsExport,
+    period_start: time::PrimitiveDateTime,
+) -> CustomResult<storage::AnalyticsExportRun, AnalyticsError> {
+    match state
+        .store
+        .find_analytics_export_run_by_export_id_period_start(&export.id, period_start)
+        .await
+    {
+        Ok(export_run) => return Ok(export_run),
+        Err(error) if !error.current_context().is_db_not_found() => {
+            return Err(error.change_context(AnalyticsError::UnknownError))
+                .attach_printable("Failed to find analytics export run");
+        }
+        Err(_) => {}
+    }
+
+    let now = common_utils::date_time::now();
+    let export_run = storage::AnalyticsExportRunNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "export_run"),
+        export_id: export.id.clone(),
+        merchant_id: export.merchant_id.clone(),
+        period_start,
+        period_end: get_next_period_start(export.frequency, period_start),
+        status: AnalyticsExportRunStatus::Pending,
+        attempt_count: 0,
+        created_at: now,
+        modified_at: now,
+    };
+    match state.store.insert_analytics_export_run(export_run).await {
+        Ok(export_run) => Ok(export_run),
+        // The run of the period was created concurrently
+        Err(error) if error.current_context().is_db_unique_violation() => state
+            .store
+            .find_analytics_export_run_by_export_id_period_start(&export.id, period_start)
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to find analytics export run"),
+        Err(error) => Err(error.change_context(AnalyticsError::UnknownError))
+            .attach_printable("Failed to insert analytics export run"),
+    }
+}
+
+async fn export_period(
+    state: &SessionState,
+    export: &storage::AnalyticsExport,
+    export_run: &storage::AnalyticsExportRun,
+) -> CustomResult<storage::AnalyticsExportRunUpdate, AnalyticsError> {
+    let columns: Vec<String> = decode_value(export.columns.clone())?;
+    let filters: HashMap<String, Vec<String>> = decode_value(export.filters.clone())?;
+    let key_store = get_merchant_key_store(state, &export.merchant_id).await?;
+    let destination = decrypt_destination(state, &key_store, export.destination.clone()).await?;
+
+    let auth = AuthInfo::MerchantLevel {
+        org_id: export.organization_id.clone(),
+        merchant_ids: vec![export.merchant_id.clone()],
+    };
+    // The end of a time range is inclusive, while the end of the period starts the next period
+    let time_range = TimeRange {
+        start_time: export_run.period_start,
+        end_time: Some(
+            export_run
+                .period_end
+                .saturating_sub(time::Duration::microseconds(1)),
+        ),
+    };
+
+    let file_name = get_export_file_name(export, export_run);
+    let mut upload = ExportFileUpload {
+        upload: ExportUpload::start(state, export, &destination, file_name.clone()).await?,
+        hasher: sha2::Sha256::new(),
+    };
+    let export_summary = match state
+        .pool
+        .generate_export(
+            export.dataset,
+            &auth,
+            &time_range,
+            &filters,
+            &columns,
+            export.format,
+            state.conf.report_download_config.page_size,
+            &mut upload,
+        )
+        .await
+    {
+        Ok(export_summary) => export_summary,
+        Err(error) => {
+            if let Err(abort_error) = upload.upload.abort().await {
+                logger::error!(?abort_error, "Failed to abort upload of export file");
+            }
+            return Err(error.change_context(AnalyticsError::UnknownError))
+                .attach_printable("Failed to generate export file");
+        }
+    };
+    let ExportFileUpload { upload, hasher } = upload;
+    upload.complete().await?;
+    let checksum = hex::encode(hasher.finalize());
+
+    let manifest = ExportManifest {
+        export_id: export.id.clone(),
+        run_id: export_run.id.clone(),
+        merchant_id: export.merchant_id.clone(),
+        dataset: export.dataset,
+        format: export.format,
+        columns,
+        period_start: export_run.period_start,
+        period_end: export_run.period_end,
+        generated_at: common_utils::date_time::now(),
+        files: vec![ExportManifestFile {
+            name: file_name.clone(),
+            size: export_summary.file_size,
+            row_count: export_summary.row_count,
+            sha256: checksum.clone(),
+        }],
+    };
+    let manifest = serde_json::to_vec_pretty(&manifest)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to serialize export manifest")?;
+
+    // The manifest is delivered last, so that its presence signals the file is complete
+    let mut manifest_upload = ExportUpload::start(
+        state,
+        export,
+        &destination,
+        get_export_manifest_name(export, export_run),
+    )
+    .await?;
+    if let Err(error) = manifest_upload.write_part(manifest).await {
+        if let Err(abort_error) = manifest_upload.abort().await {
+            logger::error!(?abort_error, "Failed to abort upload of export manifest");
+        }
+        return Err(error);
+    }
+    manifest_upload.complete().await?;
+
+    Ok(storage::AnalyticsExportRunUpdate::Succeeded {
+        file_name,
+        file_size: i64::try_from(export_summary.file_size)
+            .change_context(AnalyticsError::UnknownError)?,
+        row_count: i64::try_from(export_summary.row_count)
+            .change_context(AnalyticsError::UnknownError)?,
+        checksum,
+    })
+}
+
+/// Upload of a file to the destination of an export
+enum ExportUpload {
+    FileStorage(Box<dyn FileUpload>),
+    #[cfg(feature = "sftp")]
+    Sftp(external_services::sftp::SftpUpload),
+}
+
+impl ExportUpload {
+    async fn start(
+        state: &SessionState,
+        export: &storage::AnalyticsExport,
+        destination: &ExportDestination,
+        name: String,
+    ) -> CustomResult<Self, AnalyticsError> {
+        match destination {
+            ExportDestination::FileStorage { path_prefix } => {
+                let file_key = format!(
+                    "exports/{}/{}/{name}",
+                    export.merchant_id.get_string_repr(),
+                    path_prefix.as_deref().unwrap_or(&export.id)
+                );
+                state
+                    .file_storage_client
+                    .start_upload(&file_key)
+                    .await
+                    .map(Self::FileStorage)
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to start upload of export file")
+            }
+            #[cfg(feature = "sftp")]
+            ExportDestination::Sftp {
+                host,
+                port,
+                username,
+                password,
+                private_key,
+                host_key_fingerprint,
+                directory,
+            } => {
+                use external_services::sftp;
+
+                let config = sftp::SftpConfig {
+                    host: host.clone(),
+                    port: port.unwrap_or(sftp::DEFAULT_PORT),
+                    username: username.clone(),
+                    password: password.clone(),
+                    private_key: private_key.clone(),
+                    host_key_fingerprint: host_key_fingerprint.clone(),
+                };
+                Ok(Self::Sftp(sftp::start_upload(
+                    config,
+                    directory.clone(),
+                    name,
+                )))
+            }
+            #[cfg(not(feature = "sftp"))]
+            ExportDestination::Sftp { .. } => Err(report!(AnalyticsError::NotImplemented(
+                "SFTP export destinations"
+            ))),
+        }
+    }
+
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), AnalyticsError> {
+        match self {
+            Self::FileStorage(upload) => upload
+                .write_part(part)
+                .await
+                .change_context(AnalyticsError::UnknownError),
+            #[cfg(feature = "sftp")]
+            Self::Sftp(upload) => upload
+                .write_part(part)
+                .await
+                .change_context(AnalyticsError::UnknownError),
+        }
+        .attach_printable("Failed to upload part of export file")
+    }
+
+    async fn complete(self) -> CustomResult<(), AnalyticsError> {
+        match self {
+            Self::FileStorage(upload) => upload
+                .complete()
+                .await
+                .change_context(AnalyticsError::UnknownError),
+            #[cfg(feature = "sftp")]
+            Self::Sftp(upload) => upload
+                .complete()
+                .await
+                .change_context(AnalyticsError::UnknownError),
+        }
+        .attach_printable("Failed to complete upload of export file")
+    }
+
+    async fn abort(self) -> CustomResult<(), AnalyticsError> {
+        match self {
+            Self::FileStorage(upload) => upload
+                .abort()
+                .await
+                .change_context(AnalyticsError::UnknownError),
+            #[cfg(feature = "sftp")]
+            Self::Sftp(upload) => upload
+                .abort()
+                .await
+                .change_context(AnalyticsError::UnknownError),
+        }
+        .attach_printable("Failed to abort upload of export file")
+    }
+}
+
+/// Upload of an export file, which computes the checksum of the file as its parts are written
+struct ExportFileUpload {
+    upload: ExportUpload,
+    hasher: sha2::Sha256,
+}
+
+#[async_trait::async_trait]
+impl ReportSink for ExportFileUpload {
+    async fn write_part(&mut self, part: Vec<u8>) -> CustomResult<(), ReportError> {
+        self.hasher.update(&part);
+        self.upload
+            .write_part(part)
+            .await
+            .change_context(ReportError::FileUploadFailure)
+    }
+}
+
+/// Describes the failure of a run by its error and the latest description attached to it
+fn get_error_message(error: &error_stack::Report<AnalyticsError>) -> String {
+    let description = error.frames().find_map(|frame| match frame.kind() {
+        FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+            Some(attachment.to_string())
+        }
+        _ => None,
+    });
+    match description {
+        Some(description) => format!("{}: {description}", error.current_context()),
+        None => error.current_context().to_string(),
+    }
+}
+
+/// Name of the export file of a run, derived from its period so that re-runs replace the file
+fn get_export_file_name(
+    export: &storage::AnalyticsExport,
+    export_run: &storage::AnalyticsExportRun,
+) -> String {
+    format!(
+        "{}_{}.{}",
+        export.dataset,
+        export_run.period_start.date(),
+        export.format.get_file_extension()
+    )
+}
+
+fn get_export_manifest_name(
+    export: &storage::AnalyticsExport,
+    export_run: &storage::AnalyticsExportRun,
+) -> String {
+    format!(
+        "{}_{}.manifest.json",
+        export.dataset,
+        export_run.period_start.date()
+    )
+}
+
+fn validate_name(name: &str) -> CustomResult<(), AnalyticsError> {
+    if name.trim().is_empty() || name.chars().count() > MAX_EXPORT_NAME_LENGTH {
+        return Err(report!(AnalyticsError::InvalidExport(format!(
+            "name must be between 1 and {MAX_EXPORT_NAME_LENGTH} characters long"
+        ))));
+    }
+    Ok(())
+}
+
+/// Columns of the dataset the export includes, all of them unless a selection is given
+fn get_export_columns(
+    dataset: AnalyticsReportType,
+    columns: Option<Vec<String>>,
+) -> CustomResult<Vec<String>, AnalyticsError> {
+    let dataset_columns = exports::get_dataset_columns(dataset);
+    let Some(columns) = columns else {
+        return Ok(dataset_columns.iter().map(ToString::to_string).collect());
+    };
+
+    if columns.is_empty() {
+        return Err(report!(AnalyticsError::InvalidExport(
+            "at least one column must be selected".to_string()
+        )));
+    }
+    let mut selected_columns = HashSet::with_capacity(columns.len());
+    for column in &columns {
+        if !dataset_columns.contains(&column.as_str()) {
+            return Err(report!(AnalyticsError::InvalidExport(format!(
+                "{column} is not a column of the {dataset} dataset"
+            ))));
+        }
+        if !selected_columns.insert(column) {
+            return Err(report!(AnalyticsError::InvalidExport(format!(
+                "{column} is selected more than once"
+            ))));
+        }
+    }
+    Ok(columns)
+}
+
+/// Filter values end up in the export queries, and are restricted to characters which can not
+/// alter them
+fn validate_filters(
+    dataset: AnalyticsReportType,
+    filters: &HashMap<String, Vec<String>>,
+) -> CustomResult<(), AnalyticsError> {
+    let dataset_columns = exports::get_dataset_columns(dataset);
+    for (column, values) in filters {
+        if !dataset_columns.contains(&column.as_str()) {
+            return Err(report!(AnalyticsError::InvalidExport(format!(
+                "{column} is not a column of the {dataset} dataset"
+            ))));
+        }
+        if values.is_empty() || values.len() > MAX_FILTER_VALUES {
+            return Err(report!(AnalyticsError::InvalidExport(format!(
+                "between 1 and {MAX_FILTER_VALUES} values must be given for the {column} filter"
+            ))));
+        }
+        let is_valid_value = |value: &String| {
+            !value.is_empty()
+                && value.len() <= MAX_FILTER_VALUE_LENGTH
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'))
+        };
+        if !values.iter().all(is_valid_value) {
+            return Err(report!(AnalyticsError::InvalidExport(format!(
+                "values of the {column} filter may only contain letters, digits, '_', '-', '.' \
+                 and ':'"
+            ))));
+        }
+    }
+    Ok(())
+}
+
+fn validate_schedule_hour(schedule_hour: u8) -> CustomResult<(), AnalyticsError> {
+    if schedule_hour > 23 {
+        return Err(report!(AnalyticsError::InvalidExport(
+            "schedule hour must be between 0 and 23".to_string()
+        )));
+    }
+    Ok(())
+}
+
+fn validate_destination(destination: &ExportDestination) -> CustomResult<(), AnalyticsError> {
+    match destination {
+        ExportDestination::FileStorage { path_prefix } => {
+            let is_valid_path_prefix = path_prefix.as_deref().is_none_or(|path_prefix| {
+                path_prefix.len() <= MAX_PATH_LENGTH
+                    && path_prefix.split('/').all(|segment| {
+                        !segment.is_empty()
+                            && segment != "."
+                            && segment != ".."
+                            && segment
+                                .chars()
+                                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                    })
+            });
+            if !is_valid_path_prefix {
+                return Err(report!(AnalyticsError::InvalidExport(
+                    "path prefix must be a relative path of letters, digits, '_', '-' and '.'"
+                        .to_string()
+                )));
+            }
+        }
+        ExportDestination::Sftp {
+            host,
+            username,
+            password,
+            private_key,
+            host_key_fingerprint,
+            directory,
+            ..
+        } => {
+            if !cfg!(feature = "sftp") {
+                return Err(report!(AnalyticsError::NotImplemented(
+                    "SFTP export destinations"
+                )));
+            }
+            if host.trim().is_empty() || username.trim().is_empty() {
+                return Err(report!(AnalyticsError::InvalidExport(
+                    "host and username must be given for SFTP destinations".to_string()
+                )));
+            }
+            if password.is_none() && private_key.is_none() {
+                return Err(report!(AnalyticsError::InvalidExport(
+                    "a password or a private key must be given for SFTP destinations".to_string()
+                )));
+            }
+            if !host_key_fingerprint.starts_with("SHA256:") {
+                return Err(report!(AnalyticsError::InvalidExport(
+                    "host key fingerprint must be a SHA256 fingerprint".to_string()
+                )));
+            }
+            if directory.is_empty()
+                || directory.len() > MAX_PATH_LENGTH
+                || directory.split('/').any(|segment| segment == "..")
+            {
+                return Err(report!(AnalyticsError::InvalidExport(
+                    "directory must be a path without '..' segments".to_string()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn get_export_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    export: storage::AnalyticsExport,
+) -> CustomResult<ExportResponse, AnalyticsError> {
+    let destination = decrypt_destination(state, key_store, export.destination).await?;
+    Ok(ExportResponse {
+        columns: decode_value(export.columns)?,
+        filters: decode_value(export.filters)?,
+        schedule_hour: u8::try_from(export.schedule_hour)
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Export has an invalid schedule hour")?,
+        destination: destination.into(),
+        export_id: export.id,
+        name: export.name,
+        dataset: export.dataset,
+        format: export.format,
+        frequency: export.frequency,
+        is_enabled: export.is_enabled,
+        created_at: export.created_at,
+        modified_at: export.modified_at,
+    })
+}
+
+fn get_export_run_response(export_run: storage::AnalyticsExportRun) -> ExportRunResponse {
+    ExportRunResponse {
+        run_id: export_run.id,
+        export_id: export_run.export_id,
+        period_start: export_run.period_start,
+        period_end: export_run.period_end,
+        status: export_run.status,
+        file_name: export_run.file_name,
+        file_size: export_run.file_size,
+        row_count: export_run.row_count,
+        checksum: export_run.checksum,
+        attempt_count: export_run.attempt_count,
+        error_message: export_run.error_message,
+        created_at: export_run.created_at,
+        completed_at: export_run.completed_at,
+    }
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<domain::MerchantKeyStore, AnalyticsError> {
+    let db = &*state.store;
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch merchant key store")
+}
+
+/// Destinations hold the credentials of SFTP servers, and are stored encrypted with the key of
+/// the merchant
+async fn encrypt_destination(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    destination: &ExportDestination,
+) -> CustomResult<Encryption, AnalyticsError> {
+    domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::AnalyticsExport),
+        domain::types::CryptoOperation::Encrypt(Secret::new(encode_value(destination)?)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Encryption::from)
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to encrypt export destination")
+}
+
+async fn decrypt_destination(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    destination: Encryption,
+) -> CustomResult<ExportDestination, AnalyticsError> {
+    let destination = domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::AnalyticsExport),
+        domain::types::CryptoOperation::Decrypt(destination),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(AnalyticsError::UnknownError)
+    .attach_printable("Failed to decrypt export destination")?;
+    decode_value(destination.into_inner().expose())
+}
+
+fn encode_value<T: serde::Serialize>(value: &T) -> CustomResult<serde_json::Value, AnalyticsError> {
+    serde_json::to_value(value)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to serialize export")
+}
+
+fn decode_value<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+) -> CustomResult<T, AnalyticsError> {
+    serde_json::from_value(value)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to deserialize export")
+}
@@ -0,0 +1,185 @@
+use analytics::errors::AnalyticsError;
+use api_models::analytics::{
+    is_valid_metadata_dimension, MetadataDimensionFilters, MetadataDimensionsRequest,
+    MetadataDimensionsResponse, MAX_METADATA_DIMENSIONS_PER_PROFILE,
+};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{routes::SessionState, services::ApplicationResponse};
+
+/// Metadata keys the profile registered as analytics dimensions, empty if it registered none
+pub async fn get_metadata_dimensions(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> CustomResult<Vec<String>, AnalyticsError> {
+    let key = profile_id.get_analytics_metadata_dimensions_key();
+    match state.store.find_config_by_key(&key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("Vec<String>")
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Analytics metadata dimensions config has invalid structure"),
+        Err(e) if e.current_context().is_db_not_found() => Ok(Vec::new()),
+        Err(e) => Err(e)
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Error fetching analytics metadata dimensions config"),
+    }
+}
+
+pub async fn retrieve_metadata_dimensions(
+    state: &SessionState,
+    profile_id: id_type::ProfileId,
+) -> CustomResult<ApplicationResponse<MetadataDimensionsResponse>, AnalyticsError> {
+    let metadata_keys = get_metadata_dimensions(state, &profile_id).await?;
+    Ok(ApplicationResponse::Json(MetadataDimensionsResponse {
+        profile_id,
+        metadata_keys,
+    }))
+}
+
+/// Replaces the metadata keys registered as analytics dimensions for the profile
+pub async fn update_metadata_dimensions(
+    state: &SessionState,
+    profile_id: id_type::ProfileId,
+    request: MetadataDimensionsRequest,
+) -> CustomResult<ApplicationResponse<MetadataDimensionsResponse>, AnalyticsError> {
+    let mut metadata_keys: Vec<String> = Vec::new();
+    for metadata_key in request.metadata_keys {
+        if !is_valid_metadata_dimension(&metadata_key) {
+            return Err(report!(AnalyticsError::InvalidMetadataDimensions(format!(
+                "{metadata_key} is not a valid metadata key, keys can only contain up to 64 \
+                alphanumeric characters, underscores and hyphens"
+            ))));
+        }
+        if !metadata_keys.contains(&metadata_key) {
+            metadata_keys.push(metadata_key);
+        }
+    }
+    if metadata_keys.len() > MAX_METADATA_DIMENSIONS_PER_PROFILE {
+        return Err(report!(AnalyticsError::InvalidMetadataDimensions(format!(
+            "at most {MAX_METADATA_DIMENSIONS_PER_PROFILE} metadata keys can be registered"
+        ))));
+    }
+
+    let key = profile_id.get_analytics_metadata_dimensions_key();
+    let config = metadata_keys
+        .encode_to_string_of_json()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Error serializing analytics metadata dimensions")?;
+    match state.store.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            state
+                .store
+                .update_config_by_key(
+                    &key,
+                    configs::ConfigUpdate::Update {
+                        config: Some(config),
+                    },
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Error updating analytics metadata dimensions")?;
+        }
+        Err(e) if e.current_context().is_db_not_found() => {
+            state
+                .store
+                .insert_config(configs::ConfigNew { key, config })
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Error inserting analytics metadata dimensions")?;
+        }
+        Err(error) => {
+            logger::error!(?error);
+            Err(error)
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Error fetching analytics metadata dimensions config")?;
+        }
+    };
+
+    Ok(ApplicationResponse::Json(MetadataDimensionsResponse {
+        profile_id,
+        metadata_keys,
+    }))
+}
+
+/// Rejects metadata keys that the profile has not registered as analytics dimensions
+pub async fn validate_metadata_filters(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    filters: &MetadataDimensionFilters,
+) -> CustomResult<(), AnalyticsError> {
+    if filters.get_keys().next().is_none() {
+        return Ok(());
+    }
+    let registered_keys = get_metadata_dimensions(state, profile_id).await?;
+    validate_registered_metadata_keys(&registered_keys, filters)
+}
+
+fn validate_registered_metadata_keys(
+    registered_keys: &[String],
+    filters: &MetadataDimensionFilters,
+) -> CustomResult<(), AnalyticsError> {
+    match filters
+        .get_keys()
+        .find(|metadata_key| !registered_keys.contains(metadata_key))
+    {
+        Some(metadata_key) => Err(report!(AnalyticsError::InvalidMetadataDimensions(format!(
+            "{metadata_key} is not registered as an analytics dimension for the profile"
+        )))),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn get_filters(values: &[&str], group_by: &[&str]) -> MetadataDimensionFilters {
+        MetadataDimensionFilters {
+            values: values
+                .iter()
+                .map(|key| (key.to_string(), vec!["value".to_string()]))
+                .collect::<BTreeMap<_, _>>(),
+            group_by: group_by.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_registered_metadata_keys_are_accepted() {
+        let registered_keys = ["order_type".to_string(), "channel".to_string()];
+
+        assert!(validate_registered_metadata_keys(
+            &registered_keys,
+            &get_filters(&["order_type"], &["channel"])
+        )
+        .is_ok());
+        assert!(validate_registered_metadata_keys(&[], &get_filters(&[], &[])).is_ok());
+    }
+
+    #[test]
+    fn test_unregistered_metadata_keys_are_rejected() {
+        let registered_keys = ["order_type".to_string()];
+
+        for filters in [
+            get_filters(&["channel"], &[]),
+            get_filters(&["order_type"], &["channel"]),
+        ] {
+            let error = validate_registered_metadata_keys(&registered_keys, &filters).unwrap_err();
+
+            assert!(matches!(
+                error.current_context(),
+                AnalyticsError::InvalidMetadataDimensions(message) if message.starts_with("channel ")
+            ));
+        }
+    }
+}
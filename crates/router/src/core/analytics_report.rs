@@ -0,0 +1,743 @@
+use analytics::{errors::AnalyticsError, lambda_utils::invoke_lambda, ReportConfig, ReportEngine};
+use api_models::analytics::{
+    GenerateReportRequest, ReportDownloadRequest, ReportResponse, TimeRange,
+};
+use common_enums::{AnalyticsReportStatus, AnalyticsReportType};
+use common_utils::{
+    crypto::{HmacSha256, SignMessage, VerifySignature},
+    errors::CustomResult,
+    types::authentication::AuthInfo,
+};
+use error_stack::{report, ResultExt};
+use external_services::file_storage::FileStorageError;
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{errors, webhooks::utils as webhook_utils},
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services::ApplicationResponse,
+    types::storage,
+};
+
+const ANALYTICS_REPORT_TASK: &str = "ANALYTICS_REPORT";
+
+/// Requests a report from the configured report engine. The lambda functions email the report
+/// once it is ready, while the in-process engine records the report so that it can be polled for.
+pub async fn generate_report(
+    state: &SessionState,
+    report_type: AnalyticsReportType,
+    request: GenerateReportRequest,
+    requested_by: &str,
+) -> CustomResult<ApplicationResponse<Option<ReportResponse>>, AnalyticsError> {
+    let report_config = &state.conf.report_download_config;
+    match report_config.engine {
+        ReportEngine::Lambda => {
+            let json_bytes =
+                serde_json::to_vec(&request).map_err(|_| AnalyticsError::UnknownError)?;
+            invoke_lambda(
+                get_lambda_function(report_config, report_type),
+                &report_config.region,
+                &json_bytes,
+            )
+            .await
+            .map(|()| ApplicationResponse::Json(None))
+        }
+        ReportEngine::InProcess => {
+            let analytics_report = create_report(state, report_type, request, requested_by).await?;
+            let response = get_report_response(state, analytics_report).await?;
+            Ok(ApplicationResponse::Json(Some(response)))
+        }
+    }
+}
+
+fn get_lambda_function(report_config: &ReportConfig, report_type: AnalyticsReportType) -> &str {
+    match report_type {
+        AnalyticsReportType::Payments => &report_config.payment_function,
+        AnalyticsReportType::Refunds => &report_config.refund_function,
+        AnalyticsReportType::Disputes => &report_config.dispute_function,
+        AnalyticsReportType::Payouts => &report_config.payout_function,
+        AnalyticsReportType::Authentications => &report_config.authentication_function,
+    }
+}
+
+async fn create_report(
+    state: &SessionState,
+    report_type: AnalyticsReportType,
+    request: GenerateReportRequest,
+    requested_by: &str,
+) -> CustomResult<storage::AnalyticsReport, AnalyticsError> {
+    let GenerateReportRequest {
+        request,
+        auth,
+        email,
+        ..
+    } = request;
+
+    let mut notification_emails = vec![email];
+    for email in request.emails.unwrap_or_default() {
+        if !notification_emails
+            .iter()
+            .any(|existing| existing.peek() == email.peek())
+        {
+            notification_emails.push(email);
+        }
+    }
+
+    if let Some(webhook_url) = &request.webhook_url {
+        validate_report_webhook_url(&auth, webhook_url).await?;
+    }
+
+    let now = common_utils::date_time::now();
+    let analytics_report = state
+        .store
+        .insert_analytics_report(storage::AnalyticsReportNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "report"),
+            organization_id: get_org_id(&auth).clone(),
+            report_type,
+            status: AnalyticsReportStatus::Pending,
+            auth_info: serde_json::to_value(&auth)
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to serialize report auth info")?,
+            start_time: request.time_range.start_time,
+            end_time: request.time_range.end_time,
+            requested_by: requested_by.to_string(),
+            notification_emails: Some(Secret::new(
+                serde_json::to_value(&notification_emails)
+                    .change_context(AnalyticsError::UnknownError)
+                    .attach_printable("Failed to serialize report notification emails")?,
+            )),
+            webhook_url: request.webhook_url,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to insert analytics report")?;
+
+    add_analytics_report_task(state, &analytics_report)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to schedule analytics report task")?;
+
+    Ok(analytics_report)
+}
+
+/// Report webhooks are signed with the webhook signing key of the merchant the report is generated
+/// for, so they can only be requested for reports of a single merchant
+async fn validate_report_webhook_url(
+    auth: &AuthInfo,
+    webhook_url: &str,
+) -> CustomResult<(), AnalyticsError> {
+    if get_report_merchant_id(auth).is_none() {
+        return Err(report!(AnalyticsError::InvalidReportRequest(
+            "Webhooks can only be sent for reports of a single merchant".to_string()
+        )));
+    }
+
+    webhook_utils::validate_webhook_url(webhook_url)
+        .await
+        .map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(AnalyticsError::InvalidReportRequest(message))
+        })?;
+    Ok(())
+}
+
+/// The merchant a report is generated for, if it covers a single merchant
+fn get_report_merchant_id(auth: &AuthInfo) -> Option<&common_utils::id_type::MerchantId> {
+    match auth {
+        AuthInfo::MerchantLevel { merchant_ids, .. } => match merchant_ids.as_slice() {
+            [merchant_id] => Some(merchant_id),
+            _ => None,
+        },
+        AuthInfo::ProfileLevel { merchant_id, .. } => Some(merchant_id),
+        AuthInfo::OrgLevel { .. } => None,
+    }
+}
+
+async fn add_analytics_report_task(
+    state: &SessionState,
+    analytics_report: &storage::AnalyticsReport,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = common_enums::ProcessTrackerRunner::AnalyticsReportWorkflow;
+    let process_tracker_id = format!("{runner}_{ANALYTICS_REPORT_TASK}_{}", analytics_report.id);
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ANALYTICS_REPORT_TASK,
+        runner,
+        ["ANALYTICS", "REPORT"],
+        storage::AnalyticsReportTrackingData {
+            report_id: analytics_report.id.clone(),
+        },
+        None,
+        analytics_report.created_at,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    state.store.insert_process(process_tracker_entry).await?;
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "analytics_report")),
+    );
+    Ok(())
+}
+
+/// Retrieves a report requested earlier, provided the requester has access to all the data in it
+pub async fn retrieve_report(
+    state: &SessionState,
+    auth: &AuthInfo,
+    report_id: &str,
+) -> CustomResult<ApplicationResponse<ReportResponse>, AnalyticsError> {
+    let analytics_report = find_report(state, report_id).await?;
+
+    let report_auth = get_report_auth_info(&analytics_report)?;
+    // Reports of others are reported as not found, so as not to reveal their existence
+    if !is_report_accessible(auth, &report_auth) {
+        return Err(report!(AnalyticsError::ReportNotFound));
+    }
+
+    get_report_response(state, analytics_report)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Serves the file of a report through a download link signed by the application, for file
+/// storage backends which cannot generate presigned URLs
+pub async fn download_report(
+    state: &SessionState,
+    report_id: &str,
+    request: ReportDownloadRequest,
+) -> CustomResult<ApplicationResponse<()>, AnalyticsError> {
+    verify_download_link(state, report_id, &request)?;
+
+    let analytics_report = find_report(state, report_id).await?;
+    let file_key = analytics_report
+        .file_key
+        .ok_or(AnalyticsError::ReportNotFound)
+        .attach_printable("Report has not been generated yet")?;
+
+    let file = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to retrieve report file")?;
+
+    Ok(ApplicationResponse::FileData((file, mime::TEXT_CSV)))
+}
+
+async fn find_report(
+    state: &SessionState,
+    report_id: &str,
+) -> CustomResult<storage::AnalyticsReport, AnalyticsError> {
+    state
+        .store
+        .find_analytics_report_by_id(report_id)
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(AnalyticsError::ReportNotFound)
+            } else {
+                error.change_context(AnalyticsError::UnknownError)
+            }
+        })
+}
+
+pub fn get_report_auth_info(
+    analytics_report: &storage::AnalyticsReport,
+) -> CustomResult<AuthInfo, AnalyticsError> {
+    serde_json::from_value(analytics_report.auth_info.clone())
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to deserialize report auth info")
+}
+
+pub fn get_report_time_range(analytics_report: &storage::AnalyticsReport) -> TimeRange {
+    TimeRange {
+        start_time: analytics_report.start_time,
+        end_time: analytics_report.end_time,
+    }
+}
+
+/// Key of the report file in the file storage
+pub fn get_report_file_key(report_id: &str) -> String {
+    format!(
+        "reports/{report_id}.{}",
+        analytics::reports::ReportFile::EXTENSION
+    )
+}
+
+pub async fn get_report_response(
+    state: &SessionState,
+    analytics_report: storage::AnalyticsReport,
+) -> CustomResult<ReportResponse, AnalyticsError> {
+    let download_url = match (analytics_report.status, &analytics_report.file_key) {
+        (AnalyticsReportStatus::Completed, Some(file_key)) => Some(
+            get_download_url(state, &analytics_report.id, file_key)
+                .await?
+                .0,
+        ),
+        _ => None,
+    };
+
+    Ok(ReportResponse {
+        time_range: get_report_time_range(&analytics_report),
+        report_id: analytics_report.id,
+        report_type: analytics_report.report_type,
+        status: analytics_report.status,
+        row_count: analytics_report.row_count,
+        file_size: analytics_report.file_size,
+        download_url,
+        error_message: analytics_report.error_message,
+        created_at: analytics_report.created_at,
+        completed_at: analytics_report.completed_at,
+    })
+}
+
+/// Generates a time limited link to download the file of a report, along with the time the link
+/// expires at. A presigned URL of the file storage is preferred, and a link signed by the
+/// application is used when the file storage backend does not support presigned URLs.
+pub async fn get_download_url(
+    state: &SessionState,
+    report_id: &str,
+    file_key: &str,
+) -> CustomResult<(String, time::PrimitiveDateTime), AnalyticsError> {
+    let expiry_in_secs = state
+        .conf
+        .report_download_config
+        .download_link_expiry_in_secs;
+    let expires_at = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        i64::try_from(expiry_in_secs)
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Invalid report download link expiry")?,
+    ));
+
+    match state
+        .file_storage_client
+        .generate_presigned_url(file_key, std::time::Duration::from_secs(expiry_in_secs))
+        .await
+    {
+        Ok(download_url) => Ok((download_url, expires_at)),
+        Err(error) if error.current_context() == &FileStorageError::PresignedUrlNotSupported => {
+            let expires_at_timestamp = expires_at.assume_utc().unix_timestamp();
+            let signature = sign_download_link(state, report_id, expires_at_timestamp)?;
+            let download_url = format!(
+                "{}/analytics/v1/report/{report_id}/download?expires_at={expires_at_timestamp}&signature={signature}",
+                state.base_url
+            );
+            Ok((download_url, expires_at))
+        }
+        Err(error) => Err(error
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to generate presigned URL for report")),
+    }
+}
+
+fn sign_download_link(
+    state: &SessionState,
+    report_id: &str,
+    expires_at: i64,
+) -> CustomResult<String, AnalyticsError> {
+    HmacSha256
+        .sign_message(
+            state
+                .conf
+                .report_download_config
+                .signing_key
+                .peek()
+                .as_bytes(),
+            format!("{report_id}:{expires_at}").as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to sign report download link")
+}
+
+fn verify_download_link(
+    state: &SessionState,
+    report_id: &str,
+    request: &ReportDownloadRequest,
+) -> CustomResult<(), AnalyticsError> {
+    if request.expires_at < common_utils::date_time::now_unix_timestamp() {
+        return Err(report!(AnalyticsError::InvalidReportDownloadLink))
+            .attach_printable("Report download link has expired");
+    }
+
+    let signature = hex::decode(&request.signature)
+        .change_context(AnalyticsError::InvalidReportDownloadLink)
+        .attach_printable("Failed to decode report download link signature")?;
+
+    let is_valid = HmacSha256
+        .verify_signature(
+            state
+                .conf
+                .report_download_config
+                .signing_key
+                .peek()
+                .as_bytes(),
+            &signature,
+            format!("{report_id}:{}", request.expires_at).as_bytes(),
+        )
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to verify report download link signature")?;
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(report!(AnalyticsError::InvalidReportDownloadLink))
+    }
+}
+
+fn get_org_id(auth: &AuthInfo) -> &common_utils::id_type::OrganizationId {
+    match auth {
+        AuthInfo::OrgLevel { org_id }
+        | AuthInfo::MerchantLevel { org_id, .. }
+        | AuthInfo::ProfileLevel { org_id, .. } => org_id,
+    }
+}
+
+/// A report is accessible to a requester whose access covers all the entities the report was
+/// generated for
+fn is_report_accessible(requester: &AuthInfo, report_auth: &AuthInfo) -> bool {
+    match (requester, report_auth) {
+        (AuthInfo::OrgLevel { org_id }, report_auth) => get_org_id(report_auth) == org_id,
+        (
+            AuthInfo::MerchantLevel {
+                org_id,
+                merchant_ids,
+            },
+            AuthInfo::MerchantLevel {
+                org_id: report_org_id,
+                merchant_ids: report_merchant_ids,
+            },
+        ) => {
+            org_id == report_org_id
+                && report_merchant_ids
+                    .iter()
+                    .all(|merchant_id| merchant_ids.contains(merchant_id))
+        }
+        (
+            AuthInfo::MerchantLevel {
+                org_id,
+                merchant_ids,
+            },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                merchant_id: report_merchant_id,
+                ..
+            },
+        ) => org_id == report_org_id && merchant_ids.contains(report_merchant_id),
+        (
+            AuthInfo::ProfileLevel {
+                org_id,
+                merchant_id,
+                profile_ids,
+            },
+            AuthInfo::ProfileLevel {
+                org_id: report_org_id,
+                merchant_id: report_merchant_id,
+                profile_ids: report_profile_ids,
+            },
+        ) => {
+            org_id == report_org_id
+                && merchant_id == report_merchant_id
+                && report_profile_ids
+                    .iter()
+                    .all(|profile_id| profile_ids.contains(profile_id))
+        }
+        (AuthInfo::MerchantLevel { .. }, AuthInfo::OrgLevel { .. })
+        | (
+            AuthInfo::ProfileLevel { .. },
+            AuthInfo::OrgLevel { .. } | AuthInfo::MerchantLevel { .. },
+        ) => false,
+    }
+}
+
+/// Notifies the recipients of a completed report by email, and the webhook URL given in the
+/// request of the report by a webhook signed with the webhook signing key of the merchant
+pub async fn send_report_notifications(
+    state: &SessionState,
+    analytics_report: &storage::AnalyticsReport,
+) -> CustomResult<(), AnalyticsError> {
+    let response = get_report_response(state, analytics_report.clone()).await?;
+
+    #[cfg(feature = "email")]
+    if let Err(error) = send_report_email(state, analytics_report).await {
+        logger::error!(?error, report_id = %analytics_report.id, "Failed to email report");
+    }
+
+    if let Some(webhook_url) = &analytics_report.webhook_url {
+        if let Err(error) =
+            send_report_webhook(state, analytics_report, webhook_url, &response).await
+        {
+            logger::error!(
+                ?error,
+                report_id = %analytics_report.id,
+                "Failed to send report webhook"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_report_email(
+    state: &SessionState,
+    analytics_report: &storage::AnalyticsReport,
+) -> CustomResult<(), AnalyticsError> {
+    use common_utils::{pii::EmailStrategy, types::user::ThemeLineage};
+    use masking::ExposeInterface;
+
+    use crate::{
+        services::email::{outbox as email_outbox, types::AnalyticsReportReady},
+        types::domain::UserEmail,
+        utils::user::theme as theme_utils,
+    };
+
+    let Some(file_key) = &analytics_report.file_key else {
+        return Ok(());
+    };
+    let recipients: Vec<Secret<String, EmailStrategy>> = analytics_report
+        .notification_emails
+        .clone()
+        .map(|emails| serde_json::from_value(emails.expose()))
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to deserialize report notification emails")?
+        .unwrap_or_default();
+    if recipients.is_empty() {
+        return Ok(());
+    }
+
+    let (download_url, link_expires_at) =
+        get_download_url(state, &analytics_report.id, file_key).await?;
+
+    let lineage = match get_report_auth_info(analytics_report)? {
+        AuthInfo::OrgLevel { org_id } => ThemeLineage::Organization {
+            tenant_id: state.tenant.tenant_id.clone(),
+            org_id,
+        },
+        AuthInfo::MerchantLevel {
+            org_id,
+            merchant_ids,
+        } => match merchant_ids.as_slice() {
+            [merchant_id] => ThemeLineage::Merchant {
+                tenant_id: state.tenant.tenant_id.clone(),
+                org_id,
+                merchant_id: merchant_id.clone(),
+            },
+            _ => ThemeLineage::Organization {
+                tenant_id: state.tenant.tenant_id.clone(),
+                org_id,
+            },
+        },
+        AuthInfo::ProfileLevel {
+            org_id,
+            merchant_id,
+            profile_ids,
+        } => match profile_ids.as_slice() {
+            [profile_id] => ThemeLineage::Profile {
+                tenant_id: state.tenant.tenant_id.clone(),
+                org_id,
+                merchant_id,
+                profile_id: profile_id.clone(),
+            },
+            _ => ThemeLineage::Merchant {
+                tenant_id: state.tenant.tenant_id.clone(),
+                org_id,
+                merchant_id,
+            },
+        },
+    };
+    let theme = theme_utils::get_most_specific_theme_using_lineage(state, lineage)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to get theme"))
+        .ok()
+        .flatten();
+
+    for recipient in recipients {
+        let email_contents = AnalyticsReportReady {
+            recipient_email: UserEmail::new(recipient)
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Invalid report notification email")?,
+            subject: consts::EMAIL_SUBJECT_ANALYTICS_REPORT_READY,
+            download_url: download_url.clone(),
+            report_type: analytics_report.report_type,
+            time_range: get_report_time_range(analytics_report),
+            link_expires_at,
+            theme_id: theme.as_ref().map(|theme| theme.theme_id.clone()),
+            theme_config: theme
+                .as_ref()
+                .map(|theme| theme.email_config())
+                .unwrap_or(state.conf.theme.email_config.clone()),
+        };
+        email_outbox::enqueue_email(state, Box::new(email_contents))
+            .await
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to queue report email")?;
+    }
+
+    Ok(())
+}
+
+async fn send_report_webhook(
+    state: &SessionState,
+    analytics_report: &storage::AnalyticsReport,
+    webhook_url: &str,
+    response: &ReportResponse,
+) -> CustomResult<(), AnalyticsError> {
+    use common_utils::{crypto::HmacSha512, request::RequestContent};
+
+    use crate::{headers, services};
+
+    // The URL is validated again, as the addresses its host resolves to may have changed since
+    // the report was requested
+    let webhook_url = webhook_utils::validate_webhook_url(webhook_url)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Report webhook URL is not allowed")?;
+
+    let auth = get_report_auth_info(analytics_report)?;
+    let merchant_id = get_report_merchant_id(&auth)
+        .ok_or(AnalyticsError::UnknownError)
+        .attach_printable("Report webhooks can only be sent for reports of a single merchant")?;
+    let db = &*state.store;
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch merchant key store")?;
+    let webhook_signing_key = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch merchant account")?
+        .payment_response_hash_key
+        .ok_or(AnalyticsError::UnknownError)
+        .attach_printable("Merchant does not have a webhook signing key")?;
+
+    let body = serde_json::to_vec(response)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to serialize report webhook")?;
+    let signature = HmacSha512
+        .sign_message(webhook_signing_key.as_bytes(), &body)
+        .map(hex::encode)
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to sign report webhook")?;
+
+    // The webhook is sent with the outgoing webhook client, through the proxy of the application
+    // and without following redirects
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(webhook_url.as_str())
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/json")
+        .header(headers::X_WEBHOOK_SIGNATURE, &signature)
+        .set_body(RequestContent::RawBytes(body))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(state, request, None, false)
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to send report webhook")?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(report!(AnalyticsError::UnknownError)).attach_printable(format!(
+            "Report webhook was responded to with status {}",
+            response.status()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+
+    use super::*;
+
+    fn get_org_id() -> id_type::OrganizationId {
+        id_type::OrganizationId::try_from_string("org_1".to_string()).unwrap()
+    }
+
+    fn get_merchant_id(merchant_id: &'static str) -> id_type::MerchantId {
+        id_type::MerchantId::try_from(Cow::from(merchant_id)).unwrap()
+    }
+
+    #[test]
+    fn test_report_merchant_id() {
+        let auth = AuthInfo::MerchantLevel {
+            org_id: get_org_id(),
+            merchant_ids: vec![get_merchant_id("merchant_1")],
+        };
+        assert_eq!(
+            get_report_merchant_id(&auth),
+            Some(&get_merchant_id("merchant_1"))
+        );
+
+        let auth = AuthInfo::MerchantLevel {
+            org_id: get_org_id(),
+            merchant_ids: vec![get_merchant_id("merchant_1"), get_merchant_id("merchant_2")],
+        };
+        assert_eq!(get_report_merchant_id(&auth), None);
+
+        let auth = AuthInfo::OrgLevel {
+            org_id: get_org_id(),
+        };
+        assert_eq!(get_report_merchant_id(&auth), None);
+    }
+
+    #[tokio::test]
+    async fn test_report_webhook_requires_single_merchant() {
+        let auth = AuthInfo::OrgLevel {
+            org_id: get_org_id(),
+        };
+        let error = validate_report_webhook_url(&auth, "https://8.8.8.8/webhooks")
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            AnalyticsError::InvalidReportRequest(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_report_webhook_rejects_internal_urls() {
+        let auth = AuthInfo::MerchantLevel {
+            org_id: get_org_id(),
+            merchant_ids: vec![get_merchant_id("merchant_1")],
+        };
+
+        for webhook_url in [
+            "http://8.8.8.8/webhooks",
+            "https://localhost/webhooks",
+            "https://127.0.0.1:8080/webhooks",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[fd00::1]/webhooks",
+        ] {
+            let error = validate_report_webhook_url(&auth, webhook_url)
+                .await
+                .unwrap_err();
+            assert!(matches!(
+                error.current_context(),
+                AnalyticsError::InvalidReportRequest(_)
+            ));
+        }
+
+        assert!(
+            validate_report_webhook_url(&auth, "https://8.8.8.8/webhooks")
+                .await
+                .is_ok()
+        );
+    }
+}
@@ -3,7 +3,10 @@ use common_utils::pii;
 use error_stack::ResultExt;
 use external_services::http_client::client;
 use masking::PeekInterface;
-use pm_auth::connector::plaid::transformers::PlaidAuthType;
+use pm_auth::{
+    connector::{plaid::transformers::PlaidAuthType, truelayer::transformers::TruelayerAuthType},
+    types as pm_auth_types,
+};
 
 use crate::{core::errors, types, types::transformers::ForeignTryFrom};
 
@@ -579,7 +582,17 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
                 Ok(())
             }
             api_enums::Connector::Plaid => {
-                PlaidAuthType::foreign_try_from(self.auth_type)?;
+                let auth_type =
+                    pm_auth_types::ConnectorAuthType::foreign_try_from(self.auth_type.clone())?;
+                PlaidAuthType::try_from(&auth_type)
+                    .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+                Ok(())
+            }
+            api_enums::Connector::Truelayer => {
+                let auth_type =
+                    pm_auth_types::ConnectorAuthType::foreign_try_from(self.auth_type.clone())?;
+                TruelayerAuthType::try_from(&auth_type)
+                    .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
                 Ok(())
             }
             api_enums::Connector::Threedsecureio => {
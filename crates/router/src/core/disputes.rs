@@ -3,13 +3,15 @@ use std::{collections::HashMap, ops::Deref, str::FromStr};
 use api_models::{
     admin::MerchantConnectorInfo, disputes as dispute_models, files as files_api_models,
 };
-use common_utils::ext_traits::{Encode, ValueExt};
+use common_utils::ext_traits::{Encode, StringExt, ValueExt};
 use error_stack::ResultExt;
 use router_env::{
     instrument, logger,
     tracing::{self, Instrument},
 };
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod evidence_bundle;
 pub mod transformers;
 
 use super::{
@@ -641,6 +643,95 @@ pub async fn retrieve_dispute_evidence(
     Ok(services::ApplicationResponse::Json(dispute_evidence_vec))
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip(state))]
+pub async fn generate_dispute_evidence_bundle(
+    state: SessionState,
+    platform: domain::Platform,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: disputes::DisputeId,
+) -> RouterResponse<dispute_models::DisputeEvidenceBundleResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(merchant_id, &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id,
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+
+    let template = evidence_bundle::get_evidence_template(&dispute);
+    let bundle_data =
+        evidence_bundle::collect_evidence_bundle_data(&state, &platform, &dispute).await?;
+    let bundle = evidence_bundle::build_evidence_bundle(&dispute, template, &bundle_data)?;
+    let file_size = i32::try_from(bundle.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Dispute evidence bundle is too large")?;
+
+    let file_id = common_utils::generate_id(crate::consts::ID_LENGTH, "file");
+    let file_name = format!("dispute_evidence_{}.zip", dispute.dispute_id);
+    let file_key = format!("{}/{}", merchant_id.get_string_repr(), file_id);
+    state
+        .file_storage_client
+        .upload_file(&file_key, bundle)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload dispute evidence bundle")?;
+
+    let file_new = diesel_models::file::FileMetadataNew {
+        file_id: file_id.clone(),
+        merchant_id: merchant_id.clone(),
+        file_name: Some(file_name.clone()),
+        file_size,
+        file_type: "application/zip".to_string(),
+        provider_file_id: Some(file_key.clone()),
+        file_upload_provider: Some(diesel_models::enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: dispute.profile_id.clone(),
+        merchant_connector_id: None,
+        scan_status: Some(diesel_models::enums::FileScanStatus::NotScanned),
+    };
+    state
+        .store
+        .insert_file_metadata(file_new)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert file_metadata for dispute evidence bundle")?;
+
+    let download_url = state
+        .file_storage_client
+        .generate_presigned_url(
+            &file_key,
+            std::time::Duration::from_secs(crate::consts::FILE_DOWNLOAD_URL_EXPIRY_IN_SECONDS),
+        )
+        .await
+        .inspect_err(|error| {
+            if error.current_context()
+                != &external_services::file_storage::FileStorageError::PresignedUrlNotSupported
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to generate download url for evidence bundle"
+                );
+            }
+        })
+        .ok();
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidenceBundleResponse {
+            dispute_id: dispute.dispute_id,
+            file_id,
+            file_name,
+            file_size,
+            download_url,
+            template,
+            recommended_evidence: evidence_bundle::get_recommended_evidence(template),
+        },
+    ))
+}
+
 pub async fn delete_evidence(
     state: SessionState,
     platform: domain::Platform,
@@ -879,6 +970,7 @@ pub async fn update_dispute_data(
     let dispute_data = DisputePayload::from(dispute_details.clone());
     let dispute_object = webhooks::incoming::get_or_update_dispute_object(
         state.clone(),
+        &platform,
         option_dispute,
         dispute_data,
         platform.get_processor().get_account().get_id(),
@@ -890,7 +982,7 @@ pub async fn update_dispute_data(
     )
     .await?;
     let disputes_response: dispute_models::DisputeResponse = dispute_object.clone().foreign_into();
-    let event_type: storage_enums::EventType = dispute_details.dispute_status.into();
+    let event_type: storage_enums::EventType = dispute_object.dispute_status.into();
 
     Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
         state.clone(),
@@ -1049,3 +1141,299 @@ pub async fn schedule_dispute_sync_task(
     }
     Ok(())
 }
+
+pub async fn get_dispute_automation_config(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> errors::RouterResult<disputes::DisputeAutomationConfig> {
+    match db
+        .find_config_by_key(&merchant_id.get_dispute_automation_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DisputeAutomationConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Dispute automation config has invalid structure"),
+        Err(error) if error.current_context().is_db_not_found() => {
+            Ok(disputes::DisputeAutomationConfig::default())
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch dispute automation config")),
+    }
+}
+
+pub fn is_auto_accept_rule_matching(
+    rule: &disputes::DisputeAutoAcceptRule,
+    dispute: &diesel_models::dispute::Dispute,
+) -> bool {
+    dispute.dispute_currency == Some(rule.currency)
+        && dispute.dispute_amount <= rule.max_amount
+        && rule
+            .connectors
+            .as_ref()
+            .is_none_or(|connectors| connectors.contains(&dispute.connector))
+        && rule.reason_codes.as_ref().is_none_or(|reason_codes| {
+            dispute
+                .connector_reason_code
+                .as_ref()
+                .is_some_and(|reason_code| reason_codes.contains(reason_code))
+        })
+}
+
+/// Accepts an open dispute when it matches one of the merchant's auto accept rules, returning the
+/// updated dispute if it was accepted
+#[cfg(feature = "v1")]
+pub async fn apply_dispute_auto_accept_rules(
+    state: &SessionState,
+    platform: &domain::Platform,
+    automation_config: &disputes::DisputeAutomationConfig,
+    dispute: &diesel_models::dispute::Dispute,
+) -> errors::RouterResult<Option<diesel_models::dispute::Dispute>> {
+    if dispute.dispute_stage != storage_enums::DisputeStage::Dispute
+        || dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        || !automation_config
+            .auto_accept_rules
+            .iter()
+            .any(|rule| is_auto_accept_rule_matching(rule, dispute))
+    {
+        return Ok(None);
+    }
+
+    logger::info!(dispute_id = %dispute.dispute_id, "Auto accepting dispute");
+    Box::pin(accept_dispute(
+        state.clone(),
+        platform.clone(),
+        None,
+        disputes::DisputeId {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    ))
+    .await?;
+
+    state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(&dispute.merchant_id, &dispute.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        })
+        .map(Some)
+}
+
+/// Applies the auto accept rules to the disputes of the merchant that are already open, so that
+/// a changed automation config does not wait for the next connector update of each dispute
+#[cfg(feature = "v1")]
+async fn apply_dispute_auto_accept_rules_to_open_disputes(
+    state: &SessionState,
+    platform: &domain::Platform,
+    automation_config: &disputes::DisputeAutomationConfig,
+) -> errors::RouterResult<()> {
+    let constraints = hyperswitch_domain_models::disputes::DisputeListConstraints {
+        dispute_id: None,
+        payment_id: None,
+        limit: None,
+        offset: None,
+        profile_id: None,
+        dispute_status: Some(vec![storage_enums::DisputeStatus::DisputeOpened]),
+        dispute_stage: Some(vec![storage_enums::DisputeStage::Dispute]),
+        reason: None,
+        connector: None,
+        merchant_connector_id: None,
+        currency: None,
+        time_range: None,
+    };
+    let open_disputes = state
+        .store
+        .find_disputes_by_constraints(
+            platform.get_processor().get_account().get_id(),
+            &constraints,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list open disputes")?;
+
+    for dispute in open_disputes {
+        // One dispute failing to be accepted at the connector should not stop the others
+        if let Err(error) =
+            apply_dispute_auto_accept_rules(state, platform, automation_config, &dispute).await
+        {
+            logger::error!(?error, dispute_id = %dispute.dispute_id, "Failed to auto accept dispute");
+        }
+    }
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_dispute_automation_config(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<disputes::DisputeAutomationConfig> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let automation_config = get_dispute_automation_config(&*state.store, merchant_id).await?;
+    Ok(services::ApplicationResponse::Json(automation_config))
+}
+
+/// Replaces the dispute automation config of the merchant
+#[instrument(skip_all)]
+pub async fn update_dispute_automation_config(
+    state: SessionState,
+    platform: domain::Platform,
+    req: disputes::DisputeAutomationConfig,
+) -> RouterResponse<disputes::DisputeAutomationConfig> {
+    validate_dispute_automation_config(&req)?;
+
+    let db = &*state.store;
+    let key = platform
+        .get_processor()
+        .get_account()
+        .get_id()
+        .get_dispute_automation_config_key();
+    let config = req
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize dispute automation config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                diesel_models::configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update dispute automation config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(diesel_models::configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert dispute automation config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch dispute automation config")?,
+    };
+
+    #[cfg(feature = "v1")]
+    if !req.auto_accept_rules.is_empty() {
+        let automation_config = req.clone();
+        tokio::spawn(
+            async move {
+                apply_dispute_auto_accept_rules_to_open_disputes(
+                    &state,
+                    &platform,
+                    &automation_config,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to apply dispute auto accept rules")
+                })
+            }
+            .in_current_span(),
+        );
+    }
+
+    Ok(services::ApplicationResponse::Json(req))
+}
+
+fn validate_dispute_automation_config(
+    config: &disputes::DisputeAutomationConfig,
+) -> errors::RouterResult<()> {
+    if config.reminder_hours_before_deadline == Some(0) {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "reminder_hours_before_deadline must be greater than zero".to_string(),
+            }
+        ));
+    }
+    for (index, rule) in config.auto_accept_rules.iter().enumerate() {
+        let invalid_rule = |message: &str| {
+            error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("auto_accept_rules[{index}]: {message}"),
+            })
+        };
+        if rule.max_amount.get_amount_as_i64() < 0 {
+            return Err(invalid_rule("max_amount cannot be negative"));
+        }
+        if rule
+            .connectors
+            .as_ref()
+            .is_some_and(|connectors| connectors.is_empty())
+        {
+            return Err(invalid_rule(
+                "connectors cannot be empty, omit it to match all connectors",
+            ));
+        }
+        if rule
+            .reason_codes
+            .as_ref()
+            .is_some_and(|reason_codes| reason_codes.is_empty())
+        {
+            return Err(invalid_rule(
+                "reason_codes cannot be empty, omit it to match all reason codes",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Schedules the deadline workflow for an open dispute if it does not have one yet. An already
+/// scheduled task is only brought forward when `is_deadline_changed` is set, so that it picks up a
+/// changed `challenge_required_by` without repeating reminders on every update
+#[cfg(feature = "v1")]
+pub async fn schedule_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    dispute: &diesel_models::dispute::Dispute,
+    is_deadline_changed: bool,
+) -> common_utils::errors::CustomResult<(), errors::StorageError> {
+    if dispute.dispute_stage != storage_enums::DisputeStage::Dispute
+        || dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+    {
+        return Ok(());
+    }
+
+    let runner = common_enums::ProcessTrackerRunner::DisputeDeadlineWorkflow;
+    let task = "DISPUTE_DEADLINE";
+    let tag = ["DISPUTE", "DEADLINE"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    );
+    let schedule_time = common_utils::date_time::now();
+
+    match db.find_process_by_id(&process_tracker_id).await? {
+        Some(process) if is_deadline_changed => {
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await?;
+        }
+        Some(_) => {}
+        None => {
+            TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "dispute_deadline")),
+            );
+            let tracking_data = disputes::DisputeDeadlinePTData {
+                dispute_id: dispute.dispute_id.clone(),
+                merchant_id: dispute.merchant_id.clone(),
+            };
+            let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+                process_tracker_id,
+                task,
+                runner,
+                tag,
+                tracking_data,
+                None,
+                schedule_time,
+                common_types::consts::API_VERSION,
+            )
+            .map_err(errors::StorageError::from)?;
+            db.insert_process(process_tracker_entry).await?;
+        }
+    }
+    Ok(())
+}
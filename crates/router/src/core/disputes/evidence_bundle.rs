@@ -0,0 +1,1071 @@
+use std::fmt::Write as _;
+
+use api_models::disputes::{DisputeEvidenceTemplate, EvidenceType};
+use common_utils::{ext_traits::ValueExt, types::MinorUnit};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::logger;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+const EVIDENCE_PDF_FILE_NAME: &str = "evidence_summary.pdf";
+const EVIDENCE_JSON_FILE_NAME: &str = "evidence.json";
+
+/// Number of text lines that fit on a single US letter page of the generated PDF
+const PDF_LINES_PER_PAGE: usize = 48;
+/// Lines longer than this are wrapped, so that they stay within the page margins
+const PDF_MAX_LINE_LENGTH: usize = 95;
+
+/// Picks the evidence template for a dispute, based on the network reason code shared by the
+/// connector and falling back to the reason text when the code is unknown
+pub fn get_evidence_template(dispute: &diesel_models::dispute::Dispute) -> DisputeEvidenceTemplate {
+    dispute
+        .connector_reason_code
+        .as_deref()
+        .and_then(get_template_from_reason_code)
+        .or_else(|| {
+            dispute
+                .connector_reason
+                .as_deref()
+                .and_then(get_template_from_reason)
+        })
+        .unwrap_or(DisputeEvidenceTemplate::General)
+}
+
+fn get_template_from_reason_code(reason_code: &str) -> Option<DisputeEvidenceTemplate> {
+    match reason_code.trim().to_uppercase().as_str() {
+        // Visa
+        "10.1" | "10.2" | "10.3" | "10.4" | "10.5" => Some(DisputeEvidenceTemplate::Fraud),
+        "13.1" => Some(DisputeEvidenceTemplate::ProductNotReceived),
+        "13.2" => Some(DisputeEvidenceTemplate::CancelledRecurring),
+        "13.3" | "13.4" | "13.5" => Some(DisputeEvidenceTemplate::ProductUnacceptable),
+        "13.6" | "13.7" => Some(DisputeEvidenceTemplate::CreditNotProcessed),
+        "12.6" | "12.6.1" | "12.6.2" => Some(DisputeEvidenceTemplate::Duplicate),
+        // Mastercard
+        "4837" | "4840" | "4849" | "4863" | "4870" | "4871" => Some(DisputeEvidenceTemplate::Fraud),
+        "4855" => Some(DisputeEvidenceTemplate::ProductNotReceived),
+        "4841" => Some(DisputeEvidenceTemplate::CancelledRecurring),
+        "4853" => Some(DisputeEvidenceTemplate::ProductUnacceptable),
+        "4860" => Some(DisputeEvidenceTemplate::CreditNotProcessed),
+        "4834" => Some(DisputeEvidenceTemplate::Duplicate),
+        // American Express
+        "F10" | "F14" | "F24" | "F29" | "FR2" | "FR4" | "FR6" => {
+            Some(DisputeEvidenceTemplate::Fraud)
+        }
+        "C08" => Some(DisputeEvidenceTemplate::ProductNotReceived),
+        "C28" => Some(DisputeEvidenceTemplate::CancelledRecurring),
+        "C31" | "C32" => Some(DisputeEvidenceTemplate::ProductUnacceptable),
+        "C02" | "C05" => Some(DisputeEvidenceTemplate::CreditNotProcessed),
+        "P08" => Some(DisputeEvidenceTemplate::Duplicate),
+        _ => None,
+    }
+}
+
+fn get_template_from_reason(reason: &str) -> Option<DisputeEvidenceTemplate> {
+    let reason = reason.to_lowercase();
+    let contains_any = |keywords: &[&str]| keywords.iter().any(|keyword| reason.contains(keyword));
+
+    if contains_any(&["fraud", "unauthorized", "unrecognized", "not authorized"]) {
+        Some(DisputeEvidenceTemplate::Fraud)
+    } else if contains_any(&["not received", "non receipt", "non-receipt"]) {
+        Some(DisputeEvidenceTemplate::ProductNotReceived)
+    } else if contains_any(&["duplicate", "paid by other means"]) {
+        Some(DisputeEvidenceTemplate::Duplicate)
+    } else if contains_any(&["recurring", "subscription"]) {
+        Some(DisputeEvidenceTemplate::CancelledRecurring)
+    } else if contains_any(&["credit not processed", "refund"]) {
+        Some(DisputeEvidenceTemplate::CreditNotProcessed)
+    } else if contains_any(&["not as described", "defective", "unacceptable", "damaged"]) {
+        Some(DisputeEvidenceTemplate::ProductUnacceptable)
+    } else {
+        None
+    }
+}
+
+/// Evidence the merchant still has to provide, as the router has no record of it
+pub fn get_recommended_evidence(template: DisputeEvidenceTemplate) -> Vec<EvidenceType> {
+    match template {
+        DisputeEvidenceTemplate::Fraud => vec![
+            EvidenceType::CustomerCommunication,
+            EvidenceType::CustomerSignature,
+            EvidenceType::Receipt,
+        ],
+        DisputeEvidenceTemplate::ProductNotReceived => vec![
+            EvidenceType::ShippingDocumentation,
+            EvidenceType::CustomerCommunication,
+            EvidenceType::Receipt,
+        ],
+        DisputeEvidenceTemplate::ProductUnacceptable => vec![
+            EvidenceType::ServiceDocumentation,
+            EvidenceType::RefundPolicy,
+            EvidenceType::CustomerCommunication,
+        ],
+        DisputeEvidenceTemplate::Duplicate => vec![
+            EvidenceType::InvoiceShowingDistinctTransactions,
+            EvidenceType::Receipt,
+        ],
+        DisputeEvidenceTemplate::CreditNotProcessed => vec![
+            EvidenceType::RefundPolicy,
+            EvidenceType::CustomerCommunication,
+        ],
+        DisputeEvidenceTemplate::CancelledRecurring => vec![
+            EvidenceType::RecurringTransactionAgreement,
+            EvidenceType::CancellationPolicy,
+            EvidenceType::CustomerCommunication,
+        ],
+        DisputeEvidenceTemplate::General => vec![
+            EvidenceType::Receipt,
+            EvidenceType::CustomerCommunication,
+            EvidenceType::UncategorizedFile,
+        ],
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum EvidenceSectionKind {
+    Dispute,
+    Payment,
+    Customer,
+    ShippingAddress,
+    Authentication,
+    Refunds,
+    Order,
+}
+
+/// Sections included in the bundle for a template, most relevant first
+fn get_evidence_sections(template: DisputeEvidenceTemplate) -> &'static [EvidenceSectionKind] {
+    use EvidenceSectionKind::*;
+    match template {
+        DisputeEvidenceTemplate::Fraud => {
+            &[Dispute, Payment, Authentication, Customer, ShippingAddress]
+        }
+        DisputeEvidenceTemplate::ProductNotReceived => {
+            &[Dispute, Payment, ShippingAddress, Order, Customer]
+        }
+        DisputeEvidenceTemplate::ProductUnacceptable => {
+            &[Dispute, Payment, Order, Refunds, Customer]
+        }
+        DisputeEvidenceTemplate::Duplicate => &[Dispute, Payment, Refunds],
+        DisputeEvidenceTemplate::CreditNotProcessed => &[Dispute, Payment, Refunds, Customer],
+        DisputeEvidenceTemplate::CancelledRecurring => &[Dispute, Payment, Customer, Refunds],
+        DisputeEvidenceTemplate::General => &[
+            Dispute,
+            Payment,
+            Customer,
+            ShippingAddress,
+            Authentication,
+            Refunds,
+            Order,
+        ],
+    }
+}
+
+/// Data the router already holds about a disputed payment
+pub struct EvidenceBundleData {
+    pub payment_intent: storage::PaymentIntent,
+    pub payment_attempt: storage::PaymentAttempt,
+    pub customer: Option<domain::Customer>,
+    pub shipping_address: Option<domain::Address>,
+    pub authentication: Option<storage::Authentication>,
+    pub refunds: Vec<diesel_models::refund::Refund>,
+}
+
+pub async fn collect_evidence_bundle_data(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: &diesel_models::dispute::Dispute,
+) -> RouterResult<EvidenceBundleData> {
+    let db = &*state.store;
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let key_store = platform.get_processor().get_key_store();
+    let storage_scheme = platform.get_processor().get_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &dispute.attempt_id,
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // The remaining data only enriches the bundle, failing to fetch it should not fail the bundle
+    let customer = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => db
+            .find_customer_optional_by_customer_id_merchant_id(
+                customer_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .map_err(|error| logger::warn!(?error, "Failed to fetch customer for evidence"))
+            .ok()
+            .flatten(),
+        None => None,
+    };
+    let shipping_address = match payment_intent.shipping_address_id.as_deref() {
+        Some(address_id) => db
+            .find_address_by_merchant_id_payment_id_address_id(
+                merchant_id,
+                &dispute.payment_id,
+                address_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .map(|payment_address| payment_address.address)
+            .map_err(|error| logger::warn!(?error, "Failed to fetch shipping address for evidence"))
+            .ok(),
+        None => None,
+    };
+    let authentication = match payment_attempt.authentication_id.as_ref() {
+        Some(authentication_id) => db
+            .find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+            .await
+            .map_err(|error| logger::warn!(?error, "Failed to fetch authentication for evidence"))
+            .ok(),
+        None => None,
+    };
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(&dispute.payment_id, merchant_id, storage_scheme)
+        .await
+        .map_err(|error| logger::warn!(?error, "Failed to fetch refunds for evidence"))
+        .unwrap_or_default();
+
+    Ok(EvidenceBundleData {
+        payment_intent,
+        payment_attempt,
+        customer,
+        shipping_address,
+        authentication,
+        refunds,
+    })
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EvidenceDocument {
+    dispute_id: String,
+    template: DisputeEvidenceTemplate,
+    recommended_evidence: Vec<EvidenceType>,
+    generated_at: String,
+    sections: Vec<EvidenceSection>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EvidenceSection {
+    title: &'static str,
+    fields: Vec<EvidenceField>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct EvidenceField {
+    label: String,
+    value: String,
+}
+
+#[derive(Default)]
+struct EvidenceSectionBuilder {
+    fields: Vec<EvidenceField>,
+}
+
+impl EvidenceSectionBuilder {
+    fn field(mut self, label: impl Into<String>, value: Option<impl ToString>) -> Self {
+        if let Some(value) = value {
+            self.fields.push(EvidenceField {
+                label: label.into(),
+                value: value.to_string(),
+            });
+        }
+        self
+    }
+
+    fn build(self, title: &'static str) -> Option<EvidenceSection> {
+        (!self.fields.is_empty()).then_some(EvidenceSection {
+            title,
+            fields: self.fields,
+        })
+    }
+}
+
+fn format_timestamp(timestamp: time::PrimitiveDateTime) -> String {
+    format!(
+        "{} {:02}:{:02}:{:02} UTC",
+        timestamp.date(),
+        timestamp.hour(),
+        timestamp.minute(),
+        timestamp.second()
+    )
+}
+
+fn format_amount(amount: MinorUnit, currency: Option<storage_enums::Currency>) -> String {
+    match currency {
+        Some(currency) => currency
+            .to_currency_base_unit(amount.get_amount_as_i64())
+            .map(|base_amount| format!("{base_amount} {currency}"))
+            .unwrap_or_else(|_| format!("{amount} {currency} (minor units)")),
+        None => amount.to_string(),
+    }
+}
+
+fn build_dispute_section(dispute: &diesel_models::dispute::Dispute) -> Option<EvidenceSection> {
+    EvidenceSectionBuilder::default()
+        .field("Dispute ID", Some(&dispute.dispute_id))
+        .field("Connector", Some(&dispute.connector))
+        .field("Connector dispute ID", Some(&dispute.connector_dispute_id))
+        .field(
+            "Disputed amount",
+            Some(format_amount(
+                dispute.dispute_amount,
+                dispute.dispute_currency,
+            )),
+        )
+        .field("Stage", Some(dispute.dispute_stage))
+        .field("Status", Some(dispute.dispute_status))
+        .field("Reason code", dispute.connector_reason_code.as_ref())
+        .field("Reason", dispute.connector_reason.as_ref())
+        .field(
+            "Respond by",
+            dispute.challenge_required_by.map(format_timestamp),
+        )
+        .field("Opened at", Some(format_timestamp(dispute.created_at)))
+        .build("Dispute")
+}
+
+fn build_payment_section(data: &EvidenceBundleData) -> Option<EvidenceSection> {
+    let payment_intent = &data.payment_intent;
+    let payment_attempt = &data.payment_attempt;
+    let card = payment_attempt
+        .payment_method_data
+        .as_ref()
+        .and_then(|payment_method_data| payment_method_data.get("card"));
+    let card_field = |key: &str| {
+        card.and_then(|card| card.get(key))
+            .and_then(|value| value.as_str())
+            .map(ToOwned::to_owned)
+    };
+
+    EvidenceSectionBuilder::default()
+        .field(
+            "Payment ID",
+            Some(payment_intent.payment_id.get_string_repr()),
+        )
+        .field(
+            "Amount",
+            Some(format_amount(
+                payment_attempt.net_amount.get_total_amount(),
+                payment_attempt.currency,
+            )),
+        )
+        .field("Payment status", Some(payment_intent.status))
+        .field("Attempt status", Some(payment_attempt.status))
+        .field("Payment method", payment_attempt.payment_method)
+        .field("Payment method type", payment_attempt.payment_method_type)
+        .field("Card network", card_field("card_network"))
+        .field("Card last four digits", card_field("last4"))
+        .field("Card issuer", card_field("card_issuer"))
+        .field("Card issuing country", card_field("card_issuing_country"))
+        .field(
+            "Connector transaction ID",
+            payment_attempt.connector_transaction_id.as_ref(),
+        )
+        .field(
+            "Network transaction ID",
+            payment_attempt.network_transaction_id.as_ref(),
+        )
+        .field("Mandate ID", payment_attempt.mandate_id.as_ref())
+        .field("Description", payment_intent.description.as_ref())
+        .field(
+            "Statement descriptor",
+            payment_intent.statement_descriptor_name.as_ref(),
+        )
+        .field(
+            "Created at",
+            Some(format_timestamp(payment_intent.created_at)),
+        )
+        .build("Payment")
+}
+
+fn build_customer_section(data: &EvidenceBundleData) -> Option<EvidenceSection> {
+    let customer = data.customer.as_ref()?;
+    EvidenceSectionBuilder::default()
+        .field("Customer ID", Some(customer.customer_id.get_string_repr()))
+        .field(
+            "Name",
+            customer.name.as_ref().map(|name| name.get_inner().peek()),
+        )
+        .field(
+            "Email",
+            customer
+                .email
+                .as_ref()
+                .map(|email| email.get_inner().peek()),
+        )
+        .field(
+            "Phone",
+            customer.phone.as_ref().map(|phone| {
+                format!(
+                    "{}{}",
+                    customer.phone_country_code.as_deref().unwrap_or_default(),
+                    phone.get_inner().peek()
+                )
+            }),
+        )
+        .field(
+            "Customer since",
+            Some(format_timestamp(customer.created_at)),
+        )
+        .build("Customer")
+}
+
+fn build_shipping_address_section(data: &EvidenceBundleData) -> Option<EvidenceSection> {
+    let address = data.shipping_address.as_ref()?;
+    let name = [address.first_name.as_ref(), address.last_name.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(|name| name.get_inner().peek().as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    EvidenceSectionBuilder::default()
+        .field("Name", (!name.is_empty()).then_some(name))
+        .field(
+            "Line 1",
+            address.line1.as_ref().map(|line| line.get_inner().peek()),
+        )
+        .field(
+            "Line 2",
+            address.line2.as_ref().map(|line| line.get_inner().peek()),
+        )
+        .field("City", address.city.as_ref())
+        .field(
+            "State",
+            address.state.as_ref().map(|state| state.get_inner().peek()),
+        )
+        .field(
+            "Postal code",
+            address.zip.as_ref().map(|zip| zip.get_inner().peek()),
+        )
+        .field("Country", address.country)
+        .build("Shipping address")
+}
+
+fn build_authentication_section(data: &EvidenceBundleData) -> Option<EvidenceSection> {
+    let authentication = data.authentication.as_ref()?;
+    EvidenceSectionBuilder::default()
+        .field(
+            "Authentication ID",
+            Some(authentication.authentication_id.get_string_repr()),
+        )
+        .field(
+            "Authentication connector",
+            authentication.authentication_connector.as_ref(),
+        )
+        .field(
+            "Authentication status",
+            Some(authentication.authentication_status),
+        )
+        .field("Transaction status", authentication.trans_status.as_ref())
+        .field("ECI", authentication.eci.as_ref())
+        .field("3DS version", authentication.message_version.as_ref())
+        .field(
+            "3DS server transaction ID",
+            authentication.threeds_server_transaction_id.as_ref(),
+        )
+        .field(
+            "Directory server transaction ID",
+            authentication.ds_trans_id.as_ref(),
+        )
+        .field(
+            "Authenticated at",
+            Some(format_timestamp(authentication.created_at)),
+        )
+        .build("3DS authentication")
+}
+
+fn build_refunds_section(data: &EvidenceBundleData) -> Option<EvidenceSection> {
+    data.refunds
+        .iter()
+        .enumerate()
+        .fold(
+            EvidenceSectionBuilder::default(),
+            |builder, (index, refund)| {
+                let number = index + 1;
+                builder
+                    .field(
+                        format!("Refund {number}"),
+                        Some(format!(
+                            "{} - {} - {}",
+                            refund.refund_id,
+                            format_amount(refund.refund_amount, Some(refund.currency)),
+                            refund.refund_status
+                        )),
+                    )
+                    .field(
+                        format!("Refund {number} created at"),
+                        Some(format_timestamp(refund.created_at)),
+                    )
+                    .field(
+                        format!("Refund {number} reason"),
+                        refund.refund_reason.as_ref(),
+                    )
+            },
+        )
+        .build("Refund history")
+}
+
+fn build_order_section(data: &EvidenceBundleData) -> Option<EvidenceSection> {
+    data.payment_intent
+        .order_details
+        .iter()
+        .flatten()
+        .filter_map(|order_detail| {
+            order_detail
+                .peek()
+                .clone()
+                .parse_value::<api_models::payments::OrderDetailsWithAmount>(
+                    "OrderDetailsWithAmount",
+                )
+                .map_err(|error| logger::warn!(?error, "Failed to parse order details"))
+                .ok()
+        })
+        .fold(
+            EvidenceSectionBuilder::default(),
+            |builder, order_detail| {
+                builder.field(
+                    order_detail.product_name,
+                    Some(format!(
+                        "{} x {}",
+                        order_detail.quantity,
+                        format_amount(order_detail.amount, data.payment_intent.currency)
+                    )),
+                )
+            },
+        )
+        .build("Order details")
+}
+
+fn build_evidence_document(
+    dispute: &diesel_models::dispute::Dispute,
+    template: DisputeEvidenceTemplate,
+    data: &EvidenceBundleData,
+) -> EvidenceDocument {
+    let sections = get_evidence_sections(template)
+        .iter()
+        .filter_map(|section| match section {
+            EvidenceSectionKind::Dispute => build_dispute_section(dispute),
+            EvidenceSectionKind::Payment => build_payment_section(data),
+            EvidenceSectionKind::Customer => build_customer_section(data),
+            EvidenceSectionKind::ShippingAddress => build_shipping_address_section(data),
+            EvidenceSectionKind::Authentication => build_authentication_section(data),
+            EvidenceSectionKind::Refunds => build_refunds_section(data),
+            EvidenceSectionKind::Order => build_order_section(data),
+        })
+        .collect();
+
+    EvidenceDocument {
+        dispute_id: dispute.dispute_id.clone(),
+        template,
+        recommended_evidence: get_recommended_evidence(template),
+        generated_at: format_timestamp(common_utils::date_time::now()),
+        sections,
+    }
+}
+
+/// Builds the evidence bundle for a dispute, a ZIP archive holding a PDF summary meant for the
+/// connector and the same data as JSON, meant for the merchant's own tooling
+pub fn build_evidence_bundle(
+    dispute: &diesel_models::dispute::Dispute,
+    template: DisputeEvidenceTemplate,
+    data: &EvidenceBundleData,
+) -> RouterResult<Vec<u8>> {
+    let document = build_evidence_document(dispute, template, data);
+    let evidence_json = serde_json::to_vec_pretty(&document)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize dispute evidence")?;
+    let evidence_pdf = render_evidence_pdf(&document);
+
+    let mut archive = ZipArchive::new(common_utils::date_time::now());
+    archive
+        .add_file(EVIDENCE_PDF_FILE_NAME, &evidence_pdf)
+        .and_then(|()| archive.add_file(EVIDENCE_JSON_FILE_NAME, &evidence_json))
+        .and_then(|()| archive.finish())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build dispute evidence archive")
+}
+
+enum PdfLine {
+    Title(String),
+    Heading(String),
+    Text(String),
+    Blank,
+}
+
+fn render_evidence_pdf(document: &EvidenceDocument) -> Vec<u8> {
+    let mut lines = vec![
+        PdfLine::Title(format!("Dispute evidence - {}", document.dispute_id)),
+        PdfLine::Text(format!("Evidence template: {}", document.template)),
+        PdfLine::Text(format!("Generated at: {}", document.generated_at)),
+    ];
+    for section in &document.sections {
+        lines.push(PdfLine::Blank);
+        lines.push(PdfLine::Heading(section.title.to_string()));
+        for field in &section.fields {
+            let text = format!("{}: {}", field.label, field.value);
+            lines.extend(wrap_pdf_text(&text).into_iter().map(PdfLine::Text));
+        }
+    }
+    render_pdf(&lines)
+}
+
+fn wrap_pdf_text(text: &str) -> Vec<String> {
+    let mut wrapped = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.len() + word.len() + 1 > PDF_MAX_LINE_LENGTH {
+            wrapped.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    wrapped.push(current);
+    wrapped
+}
+
+/// Escapes text for a PDF string literal, replacing characters the standard Helvetica font
+/// cannot render without an embedded encoding
+fn escape_pdf_text(text: &str) -> String {
+    text.chars().fold(
+        String::with_capacity(text.len()),
+        |mut escaped, character| {
+            match character {
+                '\\' | '(' | ')' => {
+                    escaped.push('\\');
+                    escaped.push(character);
+                }
+                ' '..='~' => escaped.push(character),
+                _ => escaped.push('?'),
+            }
+            escaped
+        },
+    )
+}
+
+/// Renders the lines as a minimal PDF 1.4 document, using the standard Helvetica font so that
+/// no font has to be embedded
+fn render_pdf(lines: &[PdfLine]) -> Vec<u8> {
+    let pages = lines.chunks(PDF_LINES_PER_PAGE).collect::<Vec<_>>();
+    // Objects 1 to 3 hold the catalog, the page tree and the font, every page takes two more
+    let object_count = 3 + pages.len() * 2;
+    let mut objects = Vec::with_capacity(object_count);
+
+    let page_refs = (0..pages.len())
+        .map(|index| format!("{} 0 R", 4 + index * 2))
+        .collect::<Vec<_>>()
+        .join(" ");
+    objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+    objects.push(format!(
+        "<< /Type /Pages /Kids [{page_refs}] /Count {} >>",
+        pages.len()
+    ));
+    objects.push("<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string());
+
+    for (index, page_lines) in pages.iter().enumerate() {
+        let mut content = String::from("BT\n14 TL\n50 742 Td\n");
+        for line in page_lines.iter() {
+            let _ = match line {
+                PdfLine::Title(text) => {
+                    writeln!(content, "/F1 16 Tf ({}) Tj T* T*", escape_pdf_text(text))
+                }
+                PdfLine::Heading(text) => {
+                    writeln!(content, "/F1 12 Tf ({}) Tj T*", escape_pdf_text(text))
+                }
+                PdfLine::Text(text) => {
+                    writeln!(content, "/F1 10 Tf ({}) Tj T*", escape_pdf_text(text))
+                }
+                PdfLine::Blank => writeln!(content, "T*"),
+            };
+        }
+        content.push_str("ET");
+
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+             /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            5 + index * 2
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{content}\nendstream",
+            content.len()
+        ));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(object_count);
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        let _ = write!(pdf, "{} 0 obj\n{object}\nendobj\n", index + 1);
+    }
+    let xref_offset = pdf.len();
+    let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", object_count + 1);
+    for offset in offsets {
+        let _ = write!(pdf, "{offset:010} 00000 n \n");
+    }
+    let _ = write!(
+        pdf,
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        object_count + 1
+    );
+    pdf.into_bytes()
+}
+
+/// Writes a ZIP archive with uncompressed (stored) entries, the evidence documents are small
+/// and connectors accept stored entries everywhere
+struct ZipArchive {
+    buffer: Vec<u8>,
+    central_directory: Vec<u8>,
+    entry_count: u16,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl ZipArchive {
+    const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x04034b50;
+    const CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x02014b50;
+    const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+    const VERSION: u16 = 20;
+
+    fn new(modified_at: time::PrimitiveDateTime) -> Self {
+        let dos_time = (u16::from(modified_at.hour()) << 11)
+            | (u16::from(modified_at.minute()) << 5)
+            | (u16::from(modified_at.second()) / 2);
+        let dos_date = (u16::try_from(modified_at.year() - 1980).unwrap_or_default() << 9)
+            | (u16::from(u8::from(modified_at.month())) << 5)
+            | u16::from(modified_at.day());
+        Self {
+            buffer: Vec::new(),
+            central_directory: Vec::new(),
+            entry_count: 0,
+            dos_time,
+            dos_date,
+        }
+    }
+
+    fn add_file(&mut self, name: &str, data: &[u8]) -> Result<(), std::num::TryFromIntError> {
+        let offset = u32::try_from(self.buffer.len())?;
+        let size = u32::try_from(data.len())?;
+        let name_length = u16::try_from(name.len())?;
+        let crc = crc32fast::hash(data);
+
+        let mut local_header = Vec::with_capacity(30 + name.len());
+        local_header.extend_from_slice(&Self::LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+        local_header.extend_from_slice(&Self::VERSION.to_le_bytes());
+        self.write_entry_fields(&mut local_header, crc, size, name_length);
+        // Extra field length
+        local_header.extend_from_slice(&0u16.to_le_bytes());
+        local_header.extend_from_slice(name.as_bytes());
+
+        let mut directory_header = Vec::with_capacity(46 + name.len());
+        directory_header.extend_from_slice(&Self::CENTRAL_DIRECTORY_HEADER_SIGNATURE.to_le_bytes());
+        // Version made by and version needed to extract
+        directory_header.extend_from_slice(&Self::VERSION.to_le_bytes());
+        directory_header.extend_from_slice(&Self::VERSION.to_le_bytes());
+        self.write_entry_fields(&mut directory_header, crc, size, name_length);
+        // Extra field length, comment length, disk number, internal and external attributes
+        directory_header.extend_from_slice(&[0; 12]);
+        directory_header.extend_from_slice(&offset.to_le_bytes());
+        directory_header.extend_from_slice(name.as_bytes());
+
+        self.buffer.extend_from_slice(&local_header);
+        self.buffer.extend_from_slice(data);
+        self.central_directory.extend_from_slice(&directory_header);
+        self.entry_count = self.entry_count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Fields shared by the local file header and the central directory header
+    fn write_entry_fields(&self, header: &mut Vec<u8>, crc: u32, size: u32, name_length: u16) {
+        // General purpose flags and compression method (stored)
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());
+        header.extend_from_slice(&self.dos_time.to_le_bytes());
+        header.extend_from_slice(&self.dos_date.to_le_bytes());
+        header.extend_from_slice(&crc.to_le_bytes());
+        // Compressed and uncompressed sizes are the same for stored entries
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());
+        header.extend_from_slice(&name_length.to_le_bytes());
+    }
+
+    fn finish(&mut self) -> Result<Vec<u8>, std::num::TryFromIntError> {
+        let directory_offset = u32::try_from(self.buffer.len())?;
+        let directory_size = u32::try_from(self.central_directory.len())?;
+        let mut archive = std::mem::take(&mut self.buffer);
+        archive.append(&mut self.central_directory);
+
+        archive.extend_from_slice(&Self::END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        // Number of this disk and the disk holding the central directory
+        archive.extend_from_slice(&[0; 4]);
+        archive.extend_from_slice(&self.entry_count.to_le_bytes());
+        archive.extend_from_slice(&self.entry_count.to_le_bytes());
+        archive.extend_from_slice(&directory_size.to_le_bytes());
+        archive.extend_from_slice(&directory_offset.to_le_bytes());
+        // Comment length
+        archive.extend_from_slice(&0u16.to_le_bytes());
+        Ok(archive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use super::*;
+
+    #[test]
+    fn test_template_from_reason_code() {
+        assert_eq!(
+            get_template_from_reason_code("10.4"),
+            Some(DisputeEvidenceTemplate::Fraud)
+        );
+        assert_eq!(
+            get_template_from_reason_code("4855"),
+            Some(DisputeEvidenceTemplate::ProductNotReceived)
+        );
+        assert_eq!(
+            get_template_from_reason_code("p08"),
+            Some(DisputeEvidenceTemplate::Duplicate)
+        );
+        assert_eq!(get_template_from_reason_code("unknown"), None);
+    }
+
+    #[test]
+    fn test_template_from_reason() {
+        assert_eq!(
+            get_template_from_reason("Customer did not recognise the charge: Fraudulent"),
+            Some(DisputeEvidenceTemplate::Fraud)
+        );
+        assert_eq!(
+            get_template_from_reason("Merchandise not received"),
+            Some(DisputeEvidenceTemplate::ProductNotReceived)
+        );
+        assert_eq!(get_template_from_reason("general"), None);
+    }
+
+    #[test]
+    fn test_zip_archive_layout() {
+        let mut archive = ZipArchive::new(common_utils::date_time::now());
+        archive.add_file("a.txt", b"hello").unwrap();
+        archive.add_file("b.txt", b"world").unwrap();
+        let bytes = archive.finish().unwrap();
+
+        assert_eq!(
+            &bytes[..4],
+            &ZipArchive::LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes()
+        );
+        let end_of_directory = &bytes[bytes.len() - 22..];
+        assert_eq!(
+            &end_of_directory[..4],
+            &ZipArchive::END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes()
+        );
+        // Total number of entries
+        assert_eq!(&end_of_directory[10..12], &2u16.to_le_bytes());
+        // CRC-32 of the first entry
+        assert_eq!(&bytes[14..18], &crc32fast::hash(b"hello").to_le_bytes());
+    }
+
+    #[test]
+    fn test_pdf_text_is_escaped() {
+        assert_eq!(escape_pdf_text("a (b) \\ c"), "a \\(b\\) \\\\ c");
+        assert_eq!(escape_pdf_text("caf\u{e9}"), "caf?");
+    }
+
+    fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// Reads the entries of an archive back through its central directory, checking that the
+    /// local header each entry points to agrees with it
+    fn read_zip_entries(bytes: &[u8]) -> Vec<(String, Vec<u8>)> {
+        let end_of_directory = bytes.len() - 22;
+        assert_eq!(
+            u32_at(bytes, end_of_directory),
+            ZipArchive::END_OF_CENTRAL_DIRECTORY_SIGNATURE
+        );
+        let entry_count = usize::from(u16_at(bytes, end_of_directory + 10));
+        let directory_size = usize::try_from(u32_at(bytes, end_of_directory + 12)).unwrap();
+        let directory_offset = usize::try_from(u32_at(bytes, end_of_directory + 16)).unwrap();
+        assert_eq!(directory_offset + directory_size, end_of_directory);
+
+        let mut entries = Vec::with_capacity(entry_count);
+        let mut position = directory_offset;
+        for _ in 0..entry_count {
+            assert_eq!(
+                u32_at(bytes, position),
+                ZipArchive::CENTRAL_DIRECTORY_HEADER_SIGNATURE
+            );
+            let crc = u32_at(bytes, position + 16);
+            let size = usize::try_from(u32_at(bytes, position + 20)).unwrap();
+            let name_length = usize::from(u16_at(bytes, position + 28));
+            let local_offset = usize::try_from(u32_at(bytes, position + 42)).unwrap();
+            let name = &bytes[position + 46..position + 46 + name_length];
+
+            assert_eq!(
+                u32_at(bytes, local_offset),
+                ZipArchive::LOCAL_FILE_HEADER_SIGNATURE
+            );
+            assert_eq!(u32_at(bytes, local_offset + 14), crc);
+            assert_eq!(usize::from(u16_at(bytes, local_offset + 26)), name_length);
+            assert_eq!(
+                &bytes[local_offset + 30..local_offset + 30 + name_length],
+                name
+            );
+            let data_offset = local_offset + 30 + name_length;
+            let data = &bytes[data_offset..data_offset + size];
+            assert_eq!(crc32fast::hash(data), crc);
+
+            entries.push((String::from_utf8(name.to_vec()).unwrap(), data.to_vec()));
+            position += 46 + name_length;
+        }
+        assert_eq!(position, end_of_directory);
+        entries
+    }
+
+    /// Checks the cross references of the document, the stream lengths and that every string
+    /// literal in the content streams is closed where it should be
+    fn assert_valid_pdf(pdf: &[u8]) {
+        assert!(pdf.is_ascii());
+        let text = std::str::from_utf8(pdf).unwrap();
+        assert!(text.starts_with("%PDF-1.4\n"));
+
+        let trailer = text.rsplit("startxref\n").next().unwrap();
+        let xref_offset = trailer
+            .strip_suffix("\n%%EOF\n")
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        let mut xref = text[xref_offset..].lines();
+        assert_eq!(xref.next(), Some("xref"));
+        let size = xref
+            .next()
+            .and_then(|line| line.strip_prefix("0 "))
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+        assert_eq!(xref.next(), Some("0000000000 65535 f "));
+        for object_number in 1..size {
+            let offset = xref.next().unwrap()[..10].parse::<usize>().unwrap();
+            assert!(text[offset..].starts_with(&format!("{object_number} 0 obj\n")));
+        }
+
+        for (position, _) in text.match_indices("<< /Length ") {
+            let rest = &text[position + 11..];
+            let (length, rest) = rest.split_once(" >>\nstream\n").unwrap();
+            let length = length.parse::<usize>().unwrap();
+            assert!(rest[length..].starts_with("\nendstream"));
+
+            let mut is_escaped = false;
+            let mut is_in_string = false;
+            for character in rest[..length].chars() {
+                match (is_escaped, character) {
+                    (true, _) => is_escaped = false,
+                    (false, '\\') => is_escaped = true,
+                    (false, '(') => {
+                        assert!(!is_in_string, "unescaped ( within a string");
+                        is_in_string = true;
+                    }
+                    (false, ')') => {
+                        assert!(is_in_string, "unescaped ) outside a string");
+                        is_in_string = false;
+                    }
+                    (false, '\n') => assert!(!is_in_string, "string spans multiple lines"),
+                    _ => {}
+                }
+            }
+            assert!(!is_in_string);
+        }
+    }
+
+    fn get_document(fields: Vec<(String, String)>) -> EvidenceDocument {
+        EvidenceDocument {
+            dispute_id: "dp_1".to_string(),
+            template: DisputeEvidenceTemplate::Fraud,
+            recommended_evidence: Vec::new(),
+            generated_at: "2025-01-01 00:00:00 UTC".to_string(),
+            sections: vec![EvidenceSection {
+                title: "Payment details",
+                fields: fields
+                    .into_iter()
+                    .map(|(label, value)| EvidenceField { label, value })
+                    .collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_pdf_pages() {
+        let fields = (0..200)
+            .map(|index| (format!("Field {index}"), "value".to_string()))
+            .collect();
+        let pdf = render_evidence_pdf(&get_document(fields));
+        assert_valid_pdf(&pdf);
+        let text = String::from_utf8(pdf).unwrap();
+        // Three header lines, a blank line and a heading precede the fields
+        let page_count = 205_usize.div_ceil(PDF_LINES_PER_PAGE);
+        assert!(text.contains(&format!("/Count {page_count} >>")));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn proptest_zip_archive_round_trip(
+            files in proptest::collection::vec(
+                ("[a-zA-Z0-9_./-]{1,64}", proptest::collection::vec(proptest::num::u8::ANY, 0..2048)),
+                1..8,
+            )
+        ) {
+            let mut archive = ZipArchive::new(common_utils::date_time::now());
+            for (name, data) in &files {
+                archive.add_file(name, data).unwrap();
+            }
+            let bytes = archive.finish().unwrap();
+            proptest::prop_assert_eq!(read_zip_entries(&bytes), files);
+        }
+
+        #[test]
+        fn proptest_pdf_is_well_formed(
+            fields in proptest::collection::vec(("\\PC{0,40}", "\\PC{0,400}"), 0..150)
+        ) {
+            assert_valid_pdf(&render_evidence_pdf(&get_document(fields)));
+        }
+
+        #[test]
+        fn proptest_pdf_text_wrapping(text in "\\PC{0,1000}") {
+            let lines = wrap_pdf_text(&text);
+            for line in &lines {
+                proptest::prop_assert!(
+                    line.len() <= PDF_MAX_LINE_LENGTH || !line.contains(' ')
+                );
+            }
+            proptest::prop_assert_eq!(
+                lines.join(" ").split_whitespace().collect::<Vec<_>>(),
+                text.split_whitespace().collect::<Vec<_>>()
+            );
+        }
+    }
+}
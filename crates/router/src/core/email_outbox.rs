@@ -0,0 +1,504 @@
+use api_models::email as email_api;
+use base64::Engine;
+use common_utils::pii;
+use error_stack::{report, ResultExt};
+use openssl::{hash::MessageDigest, sign::Verifier, x509::X509};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    services,
+    types::storage::{self, enums},
+    SessionState,
+};
+
+/// Records the bounces and complaints Amazon SES publishes to the configured SNS topic. Emails to
+/// a recipient with a recorded bounce or complaint are suppressed by the email outbox. The request
+/// is authenticated by verifying the SNS signature against the certificate SNS signed it with.
+#[instrument(skip_all)]
+pub async fn record_email_delivery_event(
+    state: SessionState,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<Vec<email_api::EmailDeliveryEventResponse>> {
+    // SNS sends its messages as `text/plain`, so the body is parsed here rather than by the route
+    let sns_message: email_api::SnsMessage = serde_json::from_slice(&body)
+        .change_context(errors::ApiErrorResponse::WebhookBadRequest)
+        .attach_printable("Failed to parse SNS message")?;
+
+    let topic_arn = &state.conf.email_outbox.delivery_events_topic_arn;
+    if topic_arn.is_empty() || sns_message.topic_arn != *topic_arn {
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("SNS message is not from the configured delivery events topic");
+    }
+    if !is_sns_url(&sns_message.signing_cert_url, true) {
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+        .attach_printable("SNS signing certificate is not hosted by SNS");
+    }
+    let certificate = get_from_sns(&state, &sns_message.signing_cert_url).await?;
+    verify_sns_message_signature(&sns_message, &certificate)?;
+
+    match sns_message.message_type {
+        email_api::SnsMessageType::SubscriptionConfirmation => {
+            let subscribe_url = sns_message
+                .subscribe_url
+                .as_deref()
+                .filter(|subscribe_url| is_sns_url(subscribe_url, false))
+                .ok_or(errors::ApiErrorResponse::WebhookBadRequest)
+                .attach_printable("SNS subscription confirmation has an invalid subscribe URL")?;
+            get_from_sns(&state, subscribe_url).await?;
+            logger::info!(topic_arn = %sns_message.topic_arn, "Confirmed SNS subscription");
+            Ok(services::ApplicationResponse::Json(Vec::new()))
+        }
+        email_api::SnsMessageType::UnsubscribeConfirmation => {
+            logger::warn!(
+                topic_arn = %sns_message.topic_arn,
+                "Unsubscribed from the email delivery events topic"
+            );
+            Ok(services::ApplicationResponse::Json(Vec::new()))
+        }
+        email_api::SnsMessageType::Notification => {
+            let notification: email_api::SesNotification =
+                serde_json::from_str(&sns_message.message)
+                    .change_context(errors::ApiErrorResponse::WebhookBadRequest)
+                    .attach_printable("Failed to parse SES notification")?;
+            let mut responses = Vec::new();
+            for (recipient, status, reason) in get_delivery_events(notification) {
+                if let Some(response) =
+                    record_recipient_delivery_event(&state, &recipient, status, reason).await?
+                {
+                    responses.push(response);
+                }
+            }
+            Ok(services::ApplicationResponse::Json(responses))
+        }
+    }
+}
+
+/// Records the delivery event against the latest email sent to the recipient. Events for
+/// recipients without a sent email are acknowledged and ignored, so that SNS does not retry them.
+async fn record_recipient_delivery_event(
+    state: &SessionState,
+    recipient: &pii::Email,
+    status: enums::EmailOutboxStatus,
+    reason: Option<String>,
+) -> RouterResult<Option<email_api::EmailDeliveryEventResponse>> {
+    let db = state.store.as_ref();
+    let Some(email_outbox) = db
+        .list_email_outbox_entries_by_recipient_and_statuses(
+            recipient,
+            vec![enums::EmailOutboxStatus::Sent],
+            1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list email outbox entries of the recipient")?
+        .pop()
+    else {
+        logger::warn!(%status, "Ignoring delivery event for a recipient without a sent email");
+        return Ok(None);
+    };
+
+    let email_outbox = db
+        .update_email_outbox_entry(
+            email_outbox,
+            storage::EmailOutboxUpdate::DeliveryEventReceived {
+                status,
+                last_error: reason,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record email delivery event")?;
+
+    Ok(Some(email_api::EmailDeliveryEventResponse {
+        outbox_id: email_outbox.id,
+        status: email_outbox.status,
+    }))
+}
+
+/// Recipients the SES notification reports as undeliverable, along with the status to record and
+/// the reason reported by SES. Transient bounces are retried by SES and do not suppress emails.
+fn get_delivery_events(
+    notification: email_api::SesNotification,
+) -> Vec<(pii::Email, enums::EmailOutboxStatus, Option<String>)> {
+    match notification.notification_type {
+        email_api::SesNotificationType::Bounce => notification
+            .bounce
+            .filter(|bounce| bounce.bounce_type == email_api::SesBounceType::Permanent)
+            .map(|bounce| {
+                bounce
+                    .bounced_recipients
+                    .into_iter()
+                    .map(|recipient| {
+                        (
+                            recipient.email_address,
+                            enums::EmailOutboxStatus::Bounced,
+                            recipient.diagnostic_code,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        email_api::SesNotificationType::Complaint => notification
+            .complaint
+            .map(|complaint| {
+                complaint
+                    .complained_recipients
+                    .into_iter()
+                    .map(|recipient| {
+                        (
+                            recipient.email_address,
+                            enums::EmailOutboxStatus::Complained,
+                            complaint.complaint_feedback_type.clone(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        email_api::SesNotificationType::Other => Vec::new(),
+    }
+}
+
+/// Whether the URL points to SNS over HTTPS. Signing certificates must also be `.pem` files.
+fn is_sns_url(url: &str, is_signing_certificate: bool) -> bool {
+    let Ok(url) = url::Url::parse(url) else {
+        return false;
+    };
+    let is_sns_host = url.host_str().is_some_and(|host| {
+        host.strip_prefix("sns.")
+            .and_then(|host| {
+                host.strip_suffix(".amazonaws.com")
+                    .or_else(|| host.strip_suffix(".amazonaws.com.cn"))
+            })
+            .is_some_and(|region| {
+                !region.is_empty()
+                    && region.chars().all(|character| {
+                        character.is_ascii_lowercase()
+                            || character.is_ascii_digit()
+                            || character == '-'
+                    })
+            })
+    });
+
+    url.scheme() == "https"
+        && url.port().is_none()
+        && is_sns_host
+        && (!is_signing_certificate || url.path().ends_with(".pem"))
+}
+
+async fn get_from_sns(state: &SessionState, url: &str) -> RouterResult<Vec<u8>> {
+    let request = services::Request::new(services::Method::Get, url);
+    services::call_connector_api(state, request, "email_delivery_events_sns")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call SNS")?
+        .map(|response| response.response.to_vec())
+        .map_err(|response| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "SNS responded with status {}",
+                response.status_code
+            ))
+        })
+}
+
+/// The string SNS signs for a message: the signed fields of the message type in alphabetical
+/// order, each name and value followed by a newline
+fn get_sns_string_to_sign(sns_message: &email_api::SnsMessage) -> RouterResult<String> {
+    let (message_type, fields) = match sns_message.message_type {
+        email_api::SnsMessageType::Notification => (
+            "Notification",
+            vec![
+                ("Message", Some(&sns_message.message)),
+                ("MessageId", Some(&sns_message.message_id)),
+                ("Subject", sns_message.subject.as_ref()),
+                ("Timestamp", Some(&sns_message.timestamp)),
+                ("TopicArn", Some(&sns_message.topic_arn)),
+            ],
+        ),
+        email_api::SnsMessageType::SubscriptionConfirmation
+        | email_api::SnsMessageType::UnsubscribeConfirmation => {
+            let (Some(subscribe_url), Some(token)) =
+                (&sns_message.subscribe_url, &sns_message.token)
+            else {
+                return Err(report!(errors::ApiErrorResponse::WebhookBadRequest))
+                    .attach_printable("SNS subscription message is missing its URL or token");
+            };
+            (
+                if sns_message.message_type == email_api::SnsMessageType::SubscriptionConfirmation {
+                    "SubscriptionConfirmation"
+                } else {
+                    "UnsubscribeConfirmation"
+                },
+                vec![
+                    ("Message", Some(&sns_message.message)),
+                    ("MessageId", Some(&sns_message.message_id)),
+                    ("SubscribeURL", Some(subscribe_url)),
+                    ("Timestamp", Some(&sns_message.timestamp)),
+                    ("Token", Some(token)),
+                    ("TopicArn", Some(&sns_message.topic_arn)),
+                ],
+            )
+        }
+    };
+
+    Ok(fields
+        .into_iter()
+        .filter_map(|(name, value)| value.map(|value| format!("{name}\n{value}\n")))
+        .chain(std::iter::once(format!("Type\n{message_type}\n")))
+        .collect())
+}
+
+/// Verifies the SNS signature of the message with the public key of the signing certificate
+fn verify_sns_message_signature(
+    sns_message: &email_api::SnsMessage,
+    certificate: &[u8],
+) -> RouterResult<()> {
+    let message_digest = match sns_message.signature_version.as_str() {
+        "1" => MessageDigest::sha1(),
+        "2" => MessageDigest::sha256(),
+        _ => {
+            return Err(report!(
+                errors::ApiErrorResponse::WebhookAuthenticationFailed
+            ))
+            .attach_printable("Unsupported SNS signature version")
+        }
+    };
+    let signature = consts::BASE64_ENGINE
+        .decode(&sns_message.signature)
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("SNS signature is not valid base64")?;
+    let string_to_sign = get_sns_string_to_sign(sns_message)?;
+
+    let public_key = X509::from_pem(certificate)
+        .and_then(|certificate| certificate.public_key())
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Failed to read the SNS signing certificate")?;
+    let is_signature_valid = Verifier::new(message_digest, &public_key)
+        .and_then(|mut verifier| {
+            verifier.update(string_to_sign.as_bytes())?;
+            verifier.verify(&signature)
+        })
+        .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+        .attach_printable("Failed to verify the SNS signature")?;
+
+    if !is_signature_valid {
+        logger::warn!("Rejecting SNS message with an invalid signature");
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use openssl::{
+        asn1::Asn1Time, hash::MessageDigest, pkey::PKey, rsa::Rsa, sign::Signer, x509::X509Builder,
+    };
+
+    use super::*;
+
+    const TOPIC_ARN: &str = "arn:aws:sns:us-east-1:123456789012:ses-notifications";
+
+    fn get_sns_message(message_type: email_api::SnsMessageType) -> email_api::SnsMessage {
+        let is_notification = message_type == email_api::SnsMessageType::Notification;
+        email_api::SnsMessage {
+            message_type,
+            message_id: "22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324".to_string(),
+            topic_arn: TOPIC_ARN.to_string(),
+            subject: None,
+            message: r#"{"notificationType":"Bounce"}"#.to_string(),
+            timestamp: "2025-01-01T00:00:00.000Z".to_string(),
+            signature_version: "2".to_string(),
+            signature: String::new(),
+            signing_cert_url: "https://sns.us-east-1.amazonaws.com/SimpleNotificationService.pem"
+                .to_string(),
+            subscribe_url: (!is_notification).then(|| {
+                "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription".to_string()
+            }),
+            token: (!is_notification).then(|| "token".to_string()),
+        }
+    }
+
+    /// A self signed certificate and the key it certifies, standing in for the SNS certificate
+    fn get_signing_certificate() -> (Vec<u8>, PKey<openssl::pkey::Private>) {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut builder = X509Builder::new().unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+        (builder.build().to_pem().unwrap(), key)
+    }
+
+    fn sign(sns_message: &mut email_api::SnsMessage, key: &PKey<openssl::pkey::Private>) {
+        let mut signer = Signer::new(MessageDigest::sha256(), key).unwrap();
+        signer
+            .update(get_sns_string_to_sign(sns_message).unwrap().as_bytes())
+            .unwrap();
+        sns_message.signature = consts::BASE64_ENGINE.encode(signer.sign_to_vec().unwrap());
+    }
+
+    #[test]
+    fn test_get_sns_string_to_sign() {
+        let mut sns_message = get_sns_message(email_api::SnsMessageType::Notification);
+        assert_eq!(
+            get_sns_string_to_sign(&sns_message).unwrap(),
+            "Message\n{\"notificationType\":\"Bounce\"}\n\
+             MessageId\n22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324\n\
+             Timestamp\n2025-01-01T00:00:00.000Z\n\
+             TopicArn\narn:aws:sns:us-east-1:123456789012:ses-notifications\n\
+             Type\nNotification\n"
+        );
+
+        sns_message.subject = Some("Bounce".to_string());
+        assert!(get_sns_string_to_sign(&sns_message).unwrap().contains(
+            "MessageId\n22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324\nSubject\nBounce\nTimestamp"
+        ));
+
+        let sns_message = get_sns_message(email_api::SnsMessageType::SubscriptionConfirmation);
+        assert_eq!(
+            get_sns_string_to_sign(&sns_message).unwrap(),
+            "Message\n{\"notificationType\":\"Bounce\"}\n\
+             MessageId\n22b80b92-fdea-4c2c-8f9d-bdfb0c7bf324\n\
+             SubscribeURL\nhttps://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription\n\
+             Timestamp\n2025-01-01T00:00:00.000Z\n\
+             Token\ntoken\n\
+             TopicArn\narn:aws:sns:us-east-1:123456789012:ses-notifications\n\
+             Type\nSubscriptionConfirmation\n"
+        );
+    }
+
+    #[test]
+    fn test_is_sns_url() {
+        assert!(is_sns_url(
+            "https://sns.us-east-1.amazonaws.com/SimpleNotificationService-1234.pem",
+            true
+        ));
+        assert!(is_sns_url(
+            "https://sns.cn-north-1.amazonaws.com.cn/SimpleNotificationService.pem",
+            true
+        ));
+        assert!(is_sns_url(
+            "https://sns.us-east-1.amazonaws.com/?Action=ConfirmSubscription",
+            false
+        ));
+
+        for url in [
+            "http://sns.us-east-1.amazonaws.com/SimpleNotificationService.pem",
+            "https://sns.us-east-1.amazonaws.com.attacker.com/SimpleNotificationService.pem",
+            "https://attacker.com/sns.us-east-1.amazonaws.com/SimpleNotificationService.pem",
+            "https://sns.us-east-1.amazonaws.com:8443/SimpleNotificationService.pem",
+            "https://sns.us-east-1.amazonaws.com/SimpleNotificationService.txt",
+            "https://s3.us-east-1.amazonaws.com/SimpleNotificationService.pem",
+        ] {
+            assert!(!is_sns_url(url, true), "{url}");
+        }
+    }
+
+    #[test]
+    fn test_valid_signature_is_accepted() {
+        let (certificate, key) = get_signing_certificate();
+        let mut sns_message = get_sns_message(email_api::SnsMessageType::Notification);
+        sign(&mut sns_message, &key);
+
+        assert!(verify_sns_message_signature(&sns_message, &certificate).is_ok());
+    }
+
+    #[test]
+    fn test_tampered_message_or_other_certificate_is_rejected() {
+        let (certificate, key) = get_signing_certificate();
+        let mut sns_message = get_sns_message(email_api::SnsMessageType::Notification);
+        sign(&mut sns_message, &key);
+
+        let (other_certificate, _) = get_signing_certificate();
+        assert!(verify_sns_message_signature(&sns_message, &other_certificate).is_err());
+
+        sns_message.message = r#"{"notificationType":"Complaint"}"#.to_string();
+        assert!(verify_sns_message_signature(&sns_message, &certificate).is_err());
+    }
+
+    #[test]
+    fn test_unsupported_signature_version_is_rejected() {
+        let (certificate, key) = get_signing_certificate();
+        let mut sns_message = get_sns_message(email_api::SnsMessageType::Notification);
+        sign(&mut sns_message, &key);
+        sns_message.signature_version = "3".to_string();
+
+        assert!(verify_sns_message_signature(&sns_message, &certificate).is_err());
+    }
+
+    #[test]
+    fn test_get_delivery_events() {
+        let notification: email_api::SesNotification = serde_json::from_str(
+            r#"{
+                "notificationType": "Bounce",
+                "bounce": {
+                    "bounceType": "Permanent",
+                    "bouncedRecipients": [{
+                        "emailAddress": "user@example.com",
+                        "diagnosticCode": "smtp; 550 5.1.1 user unknown"
+                    }]
+                },
+                "mail": {"messageId": "0100018c"}
+            }"#,
+        )
+        .unwrap();
+        let delivery_events = get_delivery_events(notification);
+        assert_eq!(delivery_events.len(), 1);
+        assert_eq!(
+            delivery_events
+                .first()
+                .map(|(_, status, reason)| (*status, reason.as_deref())),
+            Some((
+                enums::EmailOutboxStatus::Bounced,
+                Some("smtp; 550 5.1.1 user unknown")
+            ))
+        );
+
+        // Transient bounces are retried by SES and do not suppress further emails
+        let notification: email_api::SesNotification = serde_json::from_str(
+            r#"{
+                "notificationType": "Bounce",
+                "bounce": {
+                    "bounceType": "Transient",
+                    "bouncedRecipients": [{"emailAddress": "user@example.com"}]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(get_delivery_events(notification).is_empty());
+
+        let notification: email_api::SesNotification = serde_json::from_str(
+            r#"{
+                "notificationType": "Complaint",
+                "complaint": {
+                    "complainedRecipients": [{"emailAddress": "user@example.com"}],
+                    "complaintFeedbackType": "abuse"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            get_delivery_events(notification)
+                .first()
+                .map(|(_, status, reason)| (*status, reason.as_deref())),
+            Some((enums::EmailOutboxStatus::Complained, Some("abuse")))
+        );
+
+        let notification: email_api::SesNotification =
+            serde_json::from_str(r#"{"notificationType": "Delivery"}"#).unwrap();
+        assert!(get_delivery_events(notification).is_empty());
+    }
+}
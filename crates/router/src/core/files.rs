@@ -1,7 +1,10 @@
 pub mod helpers;
+pub mod validation;
 
 use api_models::files;
+use diesel_models::enums::FileScanStatus;
 use error_stack::ResultExt;
+use router_env::logger;
 
 use super::errors::{self, RouterResponse};
 use crate::{
@@ -14,22 +17,22 @@ use crate::{
 pub async fn files_create_core(
     state: SessionState,
     platform: domain::Platform,
-    create_file_request: api::CreateFileRequest,
+    mut create_file_request: api::CreateFileRequest,
 ) -> RouterResponse<files::CreateFileResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let merchant_allowed_file_types =
+        helpers::get_merchant_allowed_file_types(&*state.store, merchant_id).await?;
+    validation::validate_and_sanitize_file(
+        &state.conf.file_validation,
+        merchant_allowed_file_types.as_ref(),
+        &mut create_file_request,
+    )?;
     helpers::validate_file_upload(&state, platform.clone(), create_file_request.clone()).await?;
     let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
-    let file_key = format!(
-        "{}/{}",
-        platform
-            .get_processor()
-            .get_account()
-            .get_id()
-            .get_string_repr(),
-        file_id
-    );
+    let is_scan_enabled = state.conf.file_validation.scanner.is_enabled();
     let file_new: diesel_models::FileMetadataNew = diesel_models::file::FileMetadataNew {
         file_id: file_id.clone(),
-        merchant_id: platform.get_processor().get_account().get_id().clone(),
+        merchant_id: merchant_id.clone(),
         file_name: create_file_request.file_name.clone(),
         file_size: create_file_request.file_size,
         file_type: create_file_request.file_type.to_string(),
@@ -39,6 +42,11 @@ pub async fn files_create_core(
         connector_label: None,
         profile_id: None,
         merchant_connector_id: None,
+        scan_status: Some(if is_scan_enabled {
+            FileScanStatus::Pending
+        } else {
+            FileScanStatus::NotScanned
+        }),
     };
 
     let file_metadata_object = state
@@ -47,35 +55,66 @@ pub async fn files_create_core(
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to insert file_metadata")?;
-    let (provider_file_id, file_upload_provider, profile_id, merchant_connector_id) = Box::pin(
-        helpers::upload_and_get_provider_provider_file_id_profile_id(
+
+    if !is_scan_enabled {
+        helpers::release_file(
             &state,
             &platform,
+            file_metadata_object,
             &create_file_request,
-            file_key.clone(),
-        ),
-    )
-    .await?;
+            FileScanStatus::NotScanned,
+        )
+        .await?;
+        return Ok(ApplicationResponse::Json(files::CreateFileResponse {
+            file_id,
+            scan_status: Some(FileScanStatus::NotScanned),
+        }));
+    }
 
-    // Update file metadata
-    let update_file_metadata = diesel_models::file::FileMetadataUpdate::Update {
-        provider_file_id: Some(provider_file_id),
-        file_upload_provider: Some(file_upload_provider),
-        available: true,
-        profile_id,
-        merchant_connector_id,
-    };
+    // The file stays in quarantine, and is not sent to the connector, until it is found clean
+    let quarantine_file_key = helpers::get_quarantine_file_key(merchant_id, &file_id);
     state
-        .store
-        .as_ref()
-        .update_file_metadata(file_metadata_object, update_file_metadata)
+        .file_storage_client
+        .upload_file(&quarantine_file_key, create_file_request.file.clone())
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable_lazy(|| {
-            format!("Unable to update file_metadata with file_id: {file_id}")
-        })?;
+        .attach_printable("Failed to quarantine file")?;
+
+    let scan_status = match state
+        .file_scanner_client
+        .scan_file(&create_file_request.file)
+        .await
+    {
+        Ok(scan_result) => {
+            helpers::handle_file_scan_result(
+                &state,
+                &platform,
+                file_metadata_object,
+                &create_file_request,
+                scan_result,
+            )
+            .await?
+        }
+        Err(error) => {
+            logger::error!(?error, "File scan failed, the file remains quarantined");
+            helpers::add_file_scan_task(&*state.store, &file_metadata_object, &create_file_request)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to schedule file scan task")?;
+            FileScanStatus::Pending
+        }
+    };
+
+    if scan_status == FileScanStatus::Infected {
+        return Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "file was flagged by the malware scanner".to_string(),
+        }
+        .into());
+    }
+
     Ok(ApplicationResponse::Json(files::CreateFileResponse {
         file_id,
+        scan_status: Some(scan_status),
     }))
 }
 
@@ -1,15 +1,21 @@
+use std::collections::HashSet;
+
 use actix_multipart::Field;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, ext_traits::StringExt};
 use error_stack::ResultExt;
+use external_services::file_scanner::FileScanResult;
 use futures::TryStreamExt;
 use hyperswitch_domain_models::router_response_types::disputes::FileInfo;
+use router_env::logger;
 
 use crate::{
+    consts,
     core::{
         errors::{self, utils::ConnectorErrorExt, StorageErrorExt},
         payments, utils,
     },
-    routes::SessionState,
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
     services,
     types::{self, api, domain, transformers::ForeignTryFrom},
 };
@@ -23,6 +29,30 @@ pub async fn read_string(field: &mut Field) -> Option<String> {
     }
 }
 
+/// Content types the merchant restricted its uploads to, `None` when the merchant has not
+/// restricted them. The restriction is stored in the configs table against
+/// `MerchantId::get_allowed_file_types_config_key`.
+pub async fn get_merchant_allowed_file_types(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> CustomResult<Option<HashSet<String>>, errors::ApiErrorResponse> {
+    match db
+        .find_config_by_key(&merchant_id.get_allowed_file_types_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("HashSet<String>")
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Allowed file types config has invalid structure"),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch allowed file types config")),
+    }
+}
+
 pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
@@ -97,6 +127,23 @@ pub async fn delete_file_using_file_id(
         )
         .await
         .change_context(errors::ApiErrorResponse::FileNotFound)?;
+    match file_metadata_object.scan_status {
+        Some(diesel_models::enums::FileScanStatus::Pending) => {
+            return state
+                .file_storage_client
+                .delete_file(&get_quarantine_file_key(
+                    &file_metadata_object.merchant_id,
+                    &file_metadata_object.file_id,
+                ))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError);
+        }
+        // The quarantined copy is removed as soon as a file is found infected
+        Some(diesel_models::enums::FileScanStatus::Infected) => return Ok(()),
+        Some(diesel_models::enums::FileScanStatus::Clean)
+        | Some(diesel_models::enums::FileScanStatus::NotScanned)
+        | None => (),
+    }
     let (provider, provider_file_id) = match (
         file_metadata_object.file_upload_provider,
         file_metadata_object.provider_file_id,
@@ -400,3 +447,163 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
         }
     }
 }
+
+/// Key under which a file is kept in the file storage until it has been scanned
+pub fn get_quarantine_file_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    file_id: &str,
+) -> String {
+    format!("quarantine/{}/{}", merchant_id.get_string_repr(), file_id)
+}
+
+/// Uploads the file to its final destination and makes it available
+pub async fn release_file(
+    state: &SessionState,
+    platform: &domain::Platform,
+    file_metadata: diesel_models::FileMetadata,
+    create_file_request: &api::CreateFileRequest,
+    scan_status: diesel_models::enums::FileScanStatus,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let file_id = file_metadata.file_id.clone();
+    let file_key = format!(
+        "{}/{}",
+        file_metadata.merchant_id.get_string_repr(),
+        file_id
+    );
+    let (provider_file_id, file_upload_provider, profile_id, merchant_connector_id) =
+        Box::pin(upload_and_get_provider_provider_file_id_profile_id(
+            state,
+            platform,
+            create_file_request,
+            file_key,
+        ))
+        .await?;
+
+    let update_file_metadata = diesel_models::file::FileMetadataUpdate::Update {
+        provider_file_id: Some(provider_file_id),
+        file_upload_provider: Some(file_upload_provider),
+        available: true,
+        profile_id,
+        merchant_connector_id,
+        scan_status: Some(scan_status),
+    };
+    state
+        .store
+        .update_file_metadata(file_metadata, update_file_metadata)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to update file_metadata with file_id: {file_id}")
+        })?;
+    Ok(())
+}
+
+/// Releases a quarantined file that was found clean, or marks it as infected. The quarantined
+/// copy is removed in both cases.
+pub async fn handle_file_scan_result(
+    state: &SessionState,
+    platform: &domain::Platform,
+    file_metadata: diesel_models::FileMetadata,
+    create_file_request: &api::CreateFileRequest,
+    scan_result: FileScanResult,
+) -> CustomResult<diesel_models::enums::FileScanStatus, errors::ApiErrorResponse> {
+    let quarantine_file_key =
+        get_quarantine_file_key(&file_metadata.merchant_id, &file_metadata.file_id);
+
+    let scan_status = match scan_result {
+        FileScanResult::Clean | FileScanResult::NotScanned => {
+            let scan_status = if scan_result == FileScanResult::Clean {
+                diesel_models::enums::FileScanStatus::Clean
+            } else {
+                diesel_models::enums::FileScanStatus::NotScanned
+            };
+            release_file(
+                state,
+                platform,
+                file_metadata,
+                create_file_request,
+                scan_status,
+            )
+            .await?;
+            scan_status
+        }
+        FileScanResult::Infected { signature } => {
+            logger::warn!(
+                file_id = %file_metadata.file_id,
+                %signature,
+                "Uploaded file was flagged by the file scanner"
+            );
+            let file_id = file_metadata.file_id.clone();
+            state
+                .store
+                .update_file_metadata(
+                    file_metadata,
+                    diesel_models::file::FileMetadataUpdate::ScanStatusUpdate {
+                        scan_status: diesel_models::enums::FileScanStatus::Infected,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Unable to update file_metadata with file_id: {file_id}")
+                })?;
+            diesel_models::enums::FileScanStatus::Infected
+        }
+    };
+
+    // A leftover quarantined copy is never served, failing to remove it is not fatal
+    state
+        .file_storage_client
+        .delete_file(&quarantine_file_key)
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to delete quarantined file {quarantine_file_key}"
+            )
+        })
+        .ok();
+
+    Ok(scan_status)
+}
+
+/// Schedules a task that scans the quarantined file again, used when the file scanner is
+/// unavailable at upload time
+pub async fn add_file_scan_task(
+    db: &dyn StorageInterface,
+    file_metadata: &diesel_models::FileMetadata,
+    create_file_request: &api::CreateFileRequest,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = diesel_models::enums::ProcessTrackerRunner::FileScanWorkflow;
+    let task = "FILE_SCAN";
+    let tag = ["FILE", "SCAN"];
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        task,
+        &file_metadata.file_id,
+        &file_metadata.merchant_id,
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        consts::FILE_SCAN_RETRY_DELAY_IN_SECONDS,
+    ));
+    let tracking_data = api::FileScanPTData {
+        merchant_id: file_metadata.merchant_id.clone(),
+        file_id: file_metadata.file_id.clone(),
+        purpose: create_file_request.purpose.clone(),
+        dispute_id: create_file_request.dispute_id.clone(),
+    };
+    let process_tracker_entry = diesel_models::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+    db.insert_process(process_tracker_entry).await?;
+    TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "file_scan")));
+    Ok(())
+}
@@ -0,0 +1,361 @@
+use std::{collections::HashSet, io::Cursor};
+
+use common_utils::errors::CustomResult;
+use error_stack::{report, ResultExt};
+
+use crate::{
+    configs::settings::{FilePurposeValidationConfig, FileValidationConfig},
+    core::errors,
+    types::api,
+};
+
+const PDF_MIME_TYPE: &str = "application/pdf";
+const PNG_MIME_TYPE: &str = "image/png";
+const JPEG_MIME_TYPE: &str = "image/jpeg";
+
+/// PDF names that make a document run code or open other resources when it is viewed
+const PDF_ACTIVE_CONTENT_NAMES: [&[u8]; 4] = [b"/JavaScript", b"/JS", b"/Launch", b"/EmbeddedFile"];
+
+fn validation_failed(reason: impl Into<String>) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::FileValidationFailed {
+        reason: reason.into(),
+    }
+}
+
+pub fn get_purpose_validation_config<'a>(
+    config: &'a FileValidationConfig,
+    purpose: &api::FilePurpose,
+) -> &'a FilePurposeValidationConfig {
+    match purpose {
+        api::FilePurpose::DisputeEvidence => &config.dispute_evidence,
+    }
+}
+
+/// Validates the content of an uploaded file against the limits configured for its purpose, and
+/// strips embedded metadata from images. The request is updated in place with the sanitized file.
+///
+/// The content type is restricted by the deployment configuration and by the merchant, when
+/// either of them sets the allowed file types. Files of any type are accepted otherwise.
+pub fn validate_and_sanitize_file(
+    config: &FileValidationConfig,
+    merchant_allowed_file_types: Option<&HashSet<String>>,
+    create_file_request: &mut api::CreateFileRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let purpose_config = get_purpose_validation_config(config, &create_file_request.purpose);
+
+    // The declared content type is not trusted, the type is always derived from the magic bytes
+    let kind = infer::get(&create_file_request.file);
+    validate_file_type(
+        kind.map(|kind| kind.mime_type()),
+        [
+            purpose_config.allowed_file_types.as_ref(),
+            merchant_allowed_file_types,
+        ],
+    )?;
+
+    validate_file_size(purpose_config, create_file_request.file.len())?;
+
+    // Files of unknown types can only get here when no restriction applies, they are stored as
+    // uploaded with the declared content type
+    let Some(kind) = kind else {
+        return Ok(());
+    };
+    let mime_type = kind.mime_type();
+
+    if let Some(extension) = create_file_request
+        .file_name
+        .as_deref()
+        .and_then(|file_name| file_name.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_lowercase())
+    {
+        if !is_extension_allowed_for_mime_type(&extension, kind) {
+            return Err(report!(validation_failed(format!(
+                "file extension .{extension} does not match the file content {mime_type}"
+            ))));
+        }
+    }
+
+    match mime_type {
+        PDF_MIME_TYPE => validate_pdf(purpose_config, &create_file_request.file)?,
+        PNG_MIME_TYPE if purpose_config.strip_image_metadata => {
+            create_file_request.file = reencode_png(&create_file_request.file)?;
+        }
+        JPEG_MIME_TYPE if purpose_config.strip_image_metadata => {
+            create_file_request.file = strip_jpeg_metadata(&create_file_request.file)?;
+        }
+        _ => (),
+    }
+
+    create_file_request.file_size = i32::try_from(create_file_request.file.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("File size error")?;
+    create_file_request.file_type = mime_type
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::MissingFileContentType)
+        .attach_printable("File content type error")?;
+
+    Ok(())
+}
+
+/// Checks the sniffed content type against every list of allowed file types that is set
+fn validate_file_type<'a>(
+    mime_type: Option<&str>,
+    allowed_file_types: impl IntoIterator<Item = Option<&'a HashSet<String>>>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    for allowed_file_types in allowed_file_types.into_iter().flatten() {
+        match mime_type {
+            Some(mime_type) if allowed_file_types.contains(mime_type) => (),
+            Some(mime_type) => {
+                return Err(report!(validation_failed(format!(
+                    "file type {mime_type} is not allowed"
+                ))))
+            }
+            None => {
+                return Err(report!(validation_failed(
+                    "file type could not be determined from its content"
+                )))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_extension_allowed_for_mime_type(extension: &str, kind: infer::Type) -> bool {
+    match kind.mime_type() {
+        JPEG_MIME_TYPE => matches!(extension, "jpg" | "jpeg" | "jpe"),
+        _ => extension == kind.extension(),
+    }
+}
+
+fn validate_file_size(
+    purpose_config: &FilePurposeValidationConfig,
+    file_size: usize,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let max_file_size = usize::try_from(purpose_config.max_file_size_in_bytes)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid max file size configured")?;
+
+    if file_size > max_file_size {
+        Err(report!(validation_failed(format!(
+            "file size exceeds the limit of {max_file_size} bytes"
+        ))))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_pdf(
+    purpose_config: &FilePurposeValidationConfig,
+    file: &[u8],
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    if let Some(name) = PDF_ACTIVE_CONTENT_NAMES
+        .iter()
+        .find(|name| contains_pdf_name(file, name))
+    {
+        return Err(report!(validation_failed(format!(
+            "pdf files with active content ({}) are not allowed",
+            String::from_utf8_lossy(name)
+        ))));
+    }
+
+    let page_count = count_pdf_pages(file);
+    if page_count > purpose_config.max_pdf_pages {
+        return Err(report!(validation_failed(format!(
+            "pdf has {page_count} pages, the limit is {}",
+            purpose_config.max_pdf_pages
+        ))));
+    }
+
+    Ok(())
+}
+
+fn is_pdf_delimiter(byte: u8) -> bool {
+    byte.is_ascii_whitespace() || b"()<>[]{}/%".contains(&byte)
+}
+
+/// Finds the positions right after every occurrence of the PDF name `name`, names that merely
+/// start with `name` are skipped
+fn find_pdf_name<'a>(file: &'a [u8], name: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+    file.windows(name.len())
+        .enumerate()
+        .filter(move |(_, window)| *window == name)
+        .map(move |(position, _)| position + name.len())
+        .filter(move |end| file.get(*end).is_none_or(|byte| is_pdf_delimiter(*byte)))
+}
+
+fn contains_pdf_name(file: &[u8], name: &[u8]) -> bool {
+    find_pdf_name(file, name).next().is_some()
+}
+
+/// Counts the `/Type /Page` objects of the document. Page objects inside compressed object
+/// streams are not visible here, so this is a lower bound for such documents.
+fn count_pdf_pages(file: &[u8]) -> usize {
+    find_pdf_name(file, b"/Type")
+        .filter(|end| {
+            let value = file.get(*end..).unwrap_or_default();
+            let value_start = value
+                .iter()
+                .position(|byte| !byte.is_ascii_whitespace())
+                .unwrap_or(value.len());
+            let value = value.get(value_start..).unwrap_or_default();
+            value.starts_with(b"/Page")
+                && value
+                    .get(b"/Page".len())
+                    .is_none_or(|byte| is_pdf_delimiter(*byte))
+        })
+        .count()
+}
+
+/// Decodes and encodes the image again, which drops all ancillary chunks (text, EXIF, time)
+/// as well as anything appended to the image data
+fn reencode_png(file: &[u8]) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+    let image = image::load_from_memory_with_format(file, image::ImageFormat::Png)
+        .change_context(validation_failed("png file could not be decoded"))?;
+    let mut reencoded = Cursor::new(Vec::new());
+    image
+        .write_to(&mut reencoded, image::ImageFormat::Png)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to re-encode png file")?;
+    Ok(reencoded.into_inner())
+}
+
+/// JPEG markers that carry metadata, APP0 (JFIF), APP2 (ICC profile) and APP14 (Adobe color
+/// transform) are kept since they affect how the image is rendered
+fn is_jpeg_metadata_marker(marker: u8) -> bool {
+    matches!(marker, 0xE1 | 0xE3..=0xED | 0xEF | 0xFE)
+}
+
+/// Removes the metadata segments of a JPEG file without decoding the image data
+fn strip_jpeg_metadata(file: &[u8]) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+    let truncated = || report!(validation_failed("jpeg file is truncated"));
+
+    let mut stripped = Vec::with_capacity(file.len());
+    let header = file.get(..2).ok_or_else(truncated)?;
+    stripped.extend_from_slice(header);
+    let mut position = 2;
+
+    loop {
+        if file.get(position) != Some(&0xFF) {
+            return Err(report!(validation_failed("jpeg file is malformed")));
+        }
+        // Markers may be preceded by any number of fill bytes
+        while file.get(position + 1) == Some(&0xFF) {
+            position += 1;
+        }
+        let marker = *file.get(position + 1).ok_or_else(truncated)?;
+
+        match marker {
+            // Start of scan, the entropy coded image data follows and is kept as is
+            0xDA => {
+                stripped.extend_from_slice(file.get(position..).ok_or_else(truncated)?);
+                return Ok(stripped);
+            }
+            // End of image
+            0xD9 => {
+                stripped.extend_from_slice(&[0xFF, 0xD9]);
+                return Ok(stripped);
+            }
+            // Standalone markers without a length
+            0x01 | 0xD0..=0xD7 => {
+                stripped.extend_from_slice(&[0xFF, marker]);
+                position += 2;
+            }
+            _ => {
+                let length = file.get(position + 2..position + 4).ok_or_else(truncated)?;
+                let length = usize::from(u16::from_be_bytes([
+                    *length.first().ok_or_else(truncated)?,
+                    *length.get(1).ok_or_else(truncated)?,
+                ]));
+                let segment = file
+                    .get(position..position + 2 + length)
+                    .ok_or_else(truncated)?;
+                if !is_jpeg_metadata_marker(marker) {
+                    stripped.extend_from_slice(segment);
+                }
+                position += 2 + length;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_count_pdf_pages() {
+        let pdf = b"%PDF-1.4\n1 0 obj << /Type /Pages /Kids [2 0 R 3 0 R] /Count 2 >> endobj\n\
+            2 0 obj << /Type /Page /Parent 1 0 R >> endobj\n\
+            3 0 obj <</Type/Page/Parent 1 0 R>> endobj\n%%EOF";
+        assert_eq!(count_pdf_pages(pdf), 2);
+    }
+
+    #[test]
+    fn test_pdf_active_content_detection() {
+        let pdf = b"%PDF-1.4\n1 0 obj << /S /JavaScript /JS (app.alert(1)) >> endobj";
+        assert!(contains_pdf_name(pdf, b"/JavaScript"));
+        assert!(contains_pdf_name(pdf, b"/JS"));
+        assert!(!contains_pdf_name(b"%PDF-1.4\n<< /JSONData 1 >>", b"/JS"));
+    }
+
+    #[test]
+    fn test_strip_jpeg_metadata() {
+        let jpeg = [
+            &[0xFF, 0xD8][..],
+            // APP0 (JFIF), kept
+            &[0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46],
+            // APP1 (EXIF), stripped
+            &[0xFF, 0xE1, 0x00, 0x05, 0x45, 0x78, 0x69],
+            // COM, stripped
+            &[0xFF, 0xFE, 0x00, 0x03, 0x41],
+            // Start of scan followed by image data
+            &[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9],
+        ]
+        .concat();
+
+        let stripped = strip_jpeg_metadata(&jpeg).unwrap();
+
+        assert_eq!(
+            stripped,
+            [
+                &[0xFF, 0xD8][..],
+                &[0xFF, 0xE0, 0x00, 0x04, 0x4A, 0x46],
+                &[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_all_file_types_allowed_by_default() {
+        assert!(validate_file_type(Some("text/plain"), [None, None]).is_ok());
+        assert!(validate_file_type(None, [None, None]).is_ok());
+    }
+
+    #[test]
+    fn test_file_types_restricted_by_config_and_merchant() {
+        let config_types = HashSet::from([PDF_MIME_TYPE.to_string(), PNG_MIME_TYPE.to_string()]);
+        let merchant_types = HashSet::from([PDF_MIME_TYPE.to_string()]);
+
+        assert!(validate_file_type(Some(PNG_MIME_TYPE), [Some(&config_types), None]).is_ok());
+        assert!(validate_file_type(Some(PNG_MIME_TYPE), [None, Some(&merchant_types)]).is_err());
+        assert!(validate_file_type(
+            Some(PNG_MIME_TYPE),
+            [Some(&config_types), Some(&merchant_types)]
+        )
+        .is_err());
+        assert!(validate_file_type(
+            Some(PDF_MIME_TYPE),
+            [Some(&config_types), Some(&merchant_types)]
+        )
+        .is_ok());
+        assert!(validate_file_type(None, [None, Some(&merchant_types)]).is_err());
+    }
+
+    #[test]
+    fn test_strip_truncated_jpeg() {
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE1, 0x00, 0x10, 0x45];
+        assert!(strip_jpeg_metadata(&jpeg).is_err());
+    }
+}
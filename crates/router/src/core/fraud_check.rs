@@ -44,6 +44,8 @@ use crate::{
     utils::ValueExt,
 };
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod native;
 pub mod operation;
 pub mod types;
 
@@ -92,15 +94,20 @@ where
     // To construct connector flow specific api
     dyn Connector: services::api::ConnectorIntegration<F, Req, frm_types::FraudCheckResponseData>,
 {
-    let merchant_connector_account = payments::construct_profile_id_and_get_mca(
-        state,
-        platform,
-        payment_data,
-        &frm_data.connector_details.connector_name,
-        None,
-        false,
-    )
-    .await?;
+    let is_native_frm = native::is_native_frm(&frm_data.connector_details.connector_name);
+    let merchant_connector_account = if is_native_frm {
+        native::get_merchant_connector_account()
+    } else {
+        payments::construct_profile_id_and_get_mca(
+            state,
+            platform,
+            payment_data,
+            &frm_data.connector_details.connector_name,
+            None,
+            false,
+        )
+        .await?
+    };
 
     frm_data
         .payment_attempt
@@ -131,6 +138,20 @@ where
     ) {
         frm_data.fraud_check.last_step = FraudCheckLastStep::TransactionOrRecordRefund
     }
+    if is_native_frm {
+        // The payment is evaluated only once, the subsequent flows reuse the decision
+        router_data.response = Ok(
+            if matches!(
+                frm_data.fraud_check.last_step,
+                FraudCheckLastStep::Processing
+            ) {
+                native::evaluate_payment(state, platform, payment_data, customer).await?
+            } else {
+                native::get_fraud_check_response(&frm_data.fraud_check)
+            },
+        );
+        return Ok(router_data);
+    }
 
     let connector =
         FraudCheckConnectorData::get_connector_by_name(&frm_data.connector_details.connector_name)?;
@@ -209,6 +230,26 @@ where
                 .attach_printable("profile_id is not set in payment_intent")?
                 .clone();
 
+            if native::is_native_frm(&frm_routing_algorithm_struct.data) {
+                return match native::get_native_frm_config(
+                    db,
+                    platform.get_processor().get_account().get_id(),
+                )
+                .await?
+                {
+                    Some(native_frm_config) => get_frm_configs_object(
+                        payment_data,
+                        &native_frm_config.frm_configs,
+                        frm_routing_algorithm_struct,
+                        profile_id,
+                    ),
+                    None => {
+                        logger::info!("Native fraud engine is not configured for the merchant");
+                        Ok((false, None, None, None))
+                    }
+                };
+            }
+
             #[cfg(feature = "v1")]
             let merchant_connector_account_from_db_option = db
                 .find_merchant_connector_account_by_profile_id_connector_name(
@@ -275,96 +316,12 @@ where
                                 })
                                 .collect::<Result<Vec<_>, _>>()?;
 
-                            let mut is_frm_connector_enabled = false;
-                            let mut is_frm_pm_enabled = false;
-                            let connector = payment_data.get_payment_attempt().connector.clone();
-                            let filtered_frm_config = frm_configs_struct
-                                .iter()
-                                .filter(|frm_config| match (&connector, &frm_config.gateway) {
-                                    (Some(current_connector), Some(configured_connector)) => {
-                                        let is_enabled =
-                                            *current_connector == configured_connector.to_string();
-                                        if is_enabled {
-                                            is_frm_connector_enabled = true;
-                                        }
-                                        is_enabled
-                                    }
-                                    (None, _) | (_, None) => true,
-                                })
-                                .collect::<Vec<_>>();
-                            let filtered_payment_methods = filtered_frm_config
-                                .iter()
-                                .map(|frm_config| {
-                                    let filtered_frm_config_by_pm = frm_config
-                                        .payment_methods
-                                        .iter()
-                                        .filter(|frm_config_pm| {
-                                            match (
-                                                payment_data.get_payment_attempt().payment_method,
-                                                frm_config_pm.payment_method,
-                                            ) {
-                                                (
-                                                    Some(current_pm),
-                                                    Some(configured_connector_pm),
-                                                ) => {
-                                                    let is_enabled = current_pm.to_string()
-                                                        == configured_connector_pm.to_string();
-                                                    if is_enabled {
-                                                        is_frm_pm_enabled = true;
-                                                    }
-                                                    is_enabled
-                                                }
-                                                (None, _) | (_, None) => true,
-                                            }
-                                        })
-                                        .collect::<Vec<_>>();
-                                    filtered_frm_config_by_pm
-                                })
-                                .collect::<Vec<_>>()
-                                .concat();
-                            let is_frm_enabled = is_frm_connector_enabled && is_frm_pm_enabled;
-                            logger::debug!(
-                                "is_frm_connector_enabled {:?}, is_frm_pm_enabled:  {:?}, is_frm_enabled :{:?}",
-                                is_frm_connector_enabled,
-                                is_frm_pm_enabled,
-                                is_frm_enabled
-                            );
-                            // filtered_frm_config...
-                            // Panic Safety: we are first checking if the object is present... only if present, we try to fetch index 0
-                            let frm_configs_object = FrmConfigsObject {
-                                frm_enabled_gateway: filtered_frm_config
-                                    .first()
-                                    .and_then(|c| c.gateway),
-                                frm_enabled_pm: filtered_payment_methods
-                                    .first()
-                                    .and_then(|pm| pm.payment_method),
-                                // flow type should be consumed from payment_method.flow. To provide backward compatibility, if we don't find it there, we consume it from payment_method.payment_method_types[0].flow_type.
-                                frm_preferred_flow_type: filtered_payment_methods
-                                    .first()
-                                    .and_then(|pm| pm.flow.clone())
-                                    .or(filtered_payment_methods.first().and_then(|pm| {
-                                        pm.payment_method_types.as_ref().and_then(|pmt| {
-                                            pmt.first().map(|pmts| pmts.flow.clone())
-                                        })
-                                    }))
-                                    .ok_or(errors::ApiErrorResponse::InvalidDataFormat {
-                                            field_name: "frm_configs".to_string(),
-                                            expected_format: r#"[{ "gateway": "stripe", "payment_methods": [{ "payment_method": "card","flow": "post"}]}]"#.to_string(),
-                                    })?,
-                            };
-                            logger::debug!(
-                                "frm_routing_configs: {:?} {:?} {:?} {:?}",
+                            get_frm_configs_object(
+                                payment_data,
+                                &frm_configs_struct,
                                 frm_routing_algorithm_struct,
                                 profile_id,
-                                frm_configs_object,
-                                is_frm_enabled
-                            );
-                            Ok((
-                                is_frm_enabled,
-                                Some(frm_routing_algorithm_struct),
-                                Some(profile_id),
-                                Some(frm_configs_object),
-                            ))
+                            )
                         }
                         None => {
                             logger::error!("Cannot find frm_configs for FRM provider");
@@ -382,6 +339,110 @@ where
     }
 }
 
+/// Determines whether FRM is enabled for the payment, based on the gateway and payment method of
+/// the payment attempt and the FRM configs of the FRM provider
+#[cfg(feature = "v1")]
+fn get_frm_configs_object<F, D>(
+    payment_data: &D,
+    frm_configs_struct: &[api_models::admin::FrmConfigs],
+    frm_routing_algorithm_struct: FrmRoutingAlgorithm,
+    profile_id: common_utils::id_type::ProfileId,
+) -> RouterResult<(
+    bool,
+    Option<FrmRoutingAlgorithm>,
+    Option<common_utils::id_type::ProfileId>,
+    Option<FrmConfigsObject>,
+)>
+where
+    F: Send + Clone,
+    D: payments::OperationSessionGetters<F> + Send + Sync + Clone,
+{
+    let mut is_frm_connector_enabled = false;
+    let mut is_frm_pm_enabled = false;
+    let connector = payment_data.get_payment_attempt().connector.clone();
+    let filtered_frm_config = frm_configs_struct
+        .iter()
+        .filter(|frm_config| match (&connector, &frm_config.gateway) {
+            (Some(current_connector), Some(configured_connector)) => {
+                let is_enabled = *current_connector == configured_connector.to_string();
+                if is_enabled {
+                    is_frm_connector_enabled = true;
+                }
+                is_enabled
+            }
+            (None, _) | (_, None) => true,
+        })
+        .collect::<Vec<_>>();
+    let filtered_payment_methods = filtered_frm_config
+        .iter()
+        .map(|frm_config| {
+            let filtered_frm_config_by_pm = frm_config
+                .payment_methods
+                .iter()
+                .filter(|frm_config_pm| {
+                    match (
+                        payment_data.get_payment_attempt().payment_method,
+                        frm_config_pm.payment_method,
+                    ) {
+                        (Some(current_pm), Some(configured_connector_pm)) => {
+                            let is_enabled =
+                                current_pm.to_string() == configured_connector_pm.to_string();
+                            if is_enabled {
+                                is_frm_pm_enabled = true;
+                            }
+                            is_enabled
+                        }
+                        (None, _) | (_, None) => true,
+                    }
+                })
+                .collect::<Vec<_>>();
+            filtered_frm_config_by_pm
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    let is_frm_enabled = is_frm_connector_enabled && is_frm_pm_enabled;
+    logger::debug!(
+        "is_frm_connector_enabled {:?}, is_frm_pm_enabled:  {:?}, is_frm_enabled :{:?}",
+        is_frm_connector_enabled,
+        is_frm_pm_enabled,
+        is_frm_enabled
+    );
+    // filtered_frm_config...
+    // Panic Safety: we are first checking if the object is present... only if present, we try to fetch index 0
+    let frm_configs_object = FrmConfigsObject {
+        frm_enabled_gateway: filtered_frm_config.first().and_then(|c| c.gateway),
+        frm_enabled_pm: filtered_payment_methods
+            .first()
+            .and_then(|pm| pm.payment_method),
+        // flow type should be consumed from payment_method.flow. To provide backward compatibility, if we don't find it there, we consume it from payment_method.payment_method_types[0].flow_type.
+        frm_preferred_flow_type: filtered_payment_methods
+            .first()
+            .and_then(|pm| pm.flow.clone())
+            .or(filtered_payment_methods.first().and_then(|pm| {
+                pm.payment_method_types
+                    .as_ref()
+                    .and_then(|pmt| pmt.first().map(|pmts| pmts.flow.clone()))
+            }))
+            .ok_or(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "frm_configs".to_string(),
+                expected_format: r#"[{ "gateway": "stripe", "payment_methods": [{ "payment_method": "card","flow": "post"}]}]"#.to_string(),
+            })?,
+    };
+    logger::debug!(
+        "frm_routing_configs: {:?} {:?} {:?} {:?}",
+        frm_routing_algorithm_struct,
+        profile_id,
+        frm_configs_object,
+        is_frm_enabled
+    );
+    Ok((
+        is_frm_enabled,
+        Some(frm_routing_algorithm_struct),
+        Some(profile_id),
+        Some(frm_configs_object),
+    ))
+}
+
 #[cfg(feature = "v2")]
 #[allow(clippy::too_many_arguments)]
 pub async fn make_frm_data_and_fraud_check_operation<F, D>(
@@ -797,6 +858,13 @@ pub async fn frm_fulfillment_core(
                 .await
                 .change_context(invalid_request_error.to_owned())?;
             match existing_fraud_check {
+                Some(fraud_check) if native::is_native_frm(&fraud_check.frm_name) => {
+                    Err(errors::ApiErrorResponse::NotSupported {
+                        message: "Fulfillment is not supported by the native fraud engine"
+                            .to_string(),
+                    }
+                    .into())
+                }
                 Some(fraud_check) => {
                     if (matches!(fraud_check.frm_transaction_type, FraudCheckType::PreFrm)
                         && fraud_check.last_step == FraudCheckLastStep::TransactionOrRecordRefund)
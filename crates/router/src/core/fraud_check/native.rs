@@ -0,0 +1,657 @@
+//! In-process fraud engine, which computes a risk score for a payment from the signals already
+//! known to the router instead of calling an external FRM connector. The engine is selected by
+//! setting the `data` of the FRM routing algorithm of the merchant to [`NATIVE_FRM_NAME`], and is
+//! configured using [`NativeFrmConfig`].
+
+use std::{net::IpAddr, str::FromStr};
+
+use common_enums::{Country, CountryAlpha2, CountryAlpha3};
+use common_utils::{date_time, ext_traits::Encode};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::router_request_types::BrowserInformation;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::{
+        card_testing_guard::utils as card_testing_guard_utils,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers},
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api::fraud_check::{NativeFrmConfig, NativeFrmSignalWeights, NativeFrmThresholds},
+        domain, fraud_check as frm_types,
+        storage::{enums::FraudCheckStatus, fraud_check::FraudCheck},
+        ResponseId,
+    },
+    utils::StringExt,
+};
+
+/// Name of the native fraud engine, used in place of the FRM connector name
+pub const NATIVE_FRM_NAME: &str = "hyperswitch_fraud_engine";
+
+const VELOCITY_KEY_PREFIX: &str = "NATIVE_FRM_VELOCITY";
+const COUNT_FIELD: &str = "count";
+/// Period for which the chargebacks received for a card are considered
+const CHARGEBACK_WINDOW: time::Duration = time::Duration::days(180);
+/// Maximum risk score of a payment
+const MAX_SCORE: i32 = 100;
+
+/// Signals contributing to the risk score of a payment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NativeFrmSignal {
+    CardCountryMismatch,
+    Velocity,
+    RecentlyRegisteredEmailDomain,
+    CardTestingGuard,
+    PreviousChargebacks,
+}
+
+impl NativeFrmSignal {
+    fn get_weight(self, weights: &NativeFrmSignalWeights) -> u8 {
+        match self {
+            Self::CardCountryMismatch => weights.card_country_mismatch,
+            Self::Velocity => weights.velocity,
+            Self::RecentlyRegisteredEmailDomain => weights.recently_registered_email_domain,
+            Self::CardTestingGuard => weights.card_testing_guard,
+            Self::PreviousChargebacks => weights.previous_chargebacks,
+        }
+    }
+}
+
+pub fn is_native_frm(frm_name: &str) -> bool {
+    frm_name == NATIVE_FRM_NAME
+}
+
+/// Returns the native fraud engine config of the merchant, the engine is disabled when it is not
+/// configured
+pub async fn get_native_frm_config(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<Option<NativeFrmConfig>> {
+    match db
+        .find_config_by_key(&merchant_id.get_native_frm_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("NativeFrmConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Native fraud engine config has invalid structure")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch native fraud engine config")),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_native_frm_config(
+    state: SessionState,
+    platform: domain::Platform,
+) -> RouterResponse<NativeFrmConfig> {
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let config = get_native_frm_config(&*state.store, merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Native fraud engine config not found".to_string(),
+        })?;
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+/// Replaces the native fraud engine config of the merchant
+#[instrument(skip_all)]
+pub async fn update_native_frm_config(
+    state: SessionState,
+    platform: domain::Platform,
+    req: NativeFrmConfig,
+) -> RouterResponse<NativeFrmConfig> {
+    validate_native_frm_config(&req)?;
+
+    let db = &*state.store;
+    let key = platform
+        .get_processor()
+        .get_account()
+        .get_id()
+        .get_native_frm_config_key();
+    let config = req
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize native fraud engine config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                diesel_models::configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update native fraud engine config")?,
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(diesel_models::configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert native fraud engine config")?,
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch native fraud engine config")?,
+    };
+
+    Ok(services::ApplicationResponse::Json(req))
+}
+
+fn validate_native_frm_config(config: &NativeFrmConfig) -> RouterResult<()> {
+    let invalid_config = |message: &str| {
+        error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        })
+    };
+    if config.thresholds.review > config.thresholds.decline {
+        return Err(invalid_config(
+            "thresholds.review cannot be greater than thresholds.decline",
+        ));
+    }
+    if config.velocity.window_in_secs == 0 {
+        return Err(invalid_config(
+            "velocity.window_in_secs must be greater than zero",
+        ));
+    }
+    Ok(())
+}
+
+/// The native fraud engine does not require any credentials, this is only used to construct the
+/// router data of the FRM flows
+pub fn get_merchant_connector_account() -> helpers::MerchantConnectorAccountType {
+    helpers::MerchantConnectorAccountType::CacheVal(api_models::admin::MerchantConnectorDetails {
+        connector_account_details: masking::Secret::new(
+            serde_json::json!({ "auth_type": "NoKey" }),
+        ),
+        metadata: None,
+    })
+}
+
+/// Computes the risk score of the payment and maps it to a decision using the thresholds
+/// configured by the merchant
+#[instrument(skip_all)]
+pub async fn evaluate_payment<F, D>(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_data: &D,
+    customer: &Option<domain::Customer>,
+) -> RouterResult<frm_types::FraudCheckResponseData>
+where
+    F: Clone,
+    D: payments::OperationSessionGetters<F> + Send + Sync + Clone,
+{
+    let merchant_id = platform.get_processor().get_account().get_id();
+    let config = get_native_frm_config(&*state.store, merchant_id)
+        .await?
+        .unwrap_or_default();
+
+    let payment_attempt = payment_data.get_payment_attempt();
+    let ip_address = payment_attempt
+        .browser_info
+        .clone()
+        .and_then(|browser_info| {
+            serde_json::from_value::<BrowserInformation>(browser_info)
+                .map_err(|error| logger::warn!(?error, "Failed to parse browser_info"))
+                .ok()
+        })
+        .and_then(|browser_info| browser_info.ip_address);
+
+    let mut signals = Vec::new();
+
+    let card_issuing_country =
+        payment_data
+            .get_payment_method_data()
+            .and_then(|payment_method_data| match payment_method_data {
+                domain::PaymentMethodData::Card(card) => card.card_issuing_country.clone(),
+                _ => None,
+            });
+    let billing_country = payment_data
+        .get_billing_address()
+        .and_then(|address| address.address)
+        .and_then(|address| address.country);
+    let shipping_country = payment_data
+        .get_address()
+        .get_shipping()
+        .and_then(|address| address.address.as_ref())
+        .and_then(|address| address.country);
+
+    if has_card_country_mismatch(
+        card_issuing_country.as_deref(),
+        &[billing_country, shipping_country],
+    ) {
+        signals.push(NativeFrmSignal::CardCountryMismatch);
+    }
+    if exceeds_velocity_limit(state, merchant_id, payment_data, ip_address, &config).await {
+        signals.push(NativeFrmSignal::Velocity);
+    }
+    if uses_recently_registered_email_domain(payment_data, customer, &config) {
+        signals.push(NativeFrmSignal::RecentlyRegisteredEmailDomain);
+    }
+    if has_card_testing_guard_hits(state, platform, payment_data, ip_address).await? {
+        signals.push(NativeFrmSignal::CardTestingGuard);
+    }
+    if let Some(fingerprint_id) = &payment_attempt.fingerprint_id {
+        if has_previous_chargebacks(&*state.store, merchant_id, fingerprint_id).await? {
+            signals.push(NativeFrmSignal::PreviousChargebacks);
+        }
+    }
+
+    let score = get_risk_score(&signals, &config.signal_weights);
+    let status = get_decision(score, &config.thresholds);
+    logger::debug!(?signals, score, ?status, "Native fraud engine decision");
+
+    Ok(frm_types::FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::NoResponseId,
+        status,
+        connector_metadata: None,
+        reason: Some(serde_json::json!({ "signals": signals })),
+        score: Some(score),
+    })
+}
+
+/// Returns the decision previously made for the payment, as the payment is evaluated only once
+pub fn get_fraud_check_response(fraud_check: &FraudCheck) -> frm_types::FraudCheckResponseData {
+    frm_types::FraudCheckResponseData::TransactionResponse {
+        resource_id: ResponseId::NoResponseId,
+        status: fraud_check.frm_status,
+        connector_metadata: fraud_check.metadata.clone(),
+        reason: fraud_check.frm_reason.clone(),
+        score: fraud_check.frm_score,
+    }
+}
+
+/// The risk score is the sum of the weights of the triggered signals, capped at [`MAX_SCORE`]
+fn get_risk_score(signals: &[NativeFrmSignal], weights: &NativeFrmSignalWeights) -> i32 {
+    signals
+        .iter()
+        .map(|signal| i32::from(signal.get_weight(weights)))
+        .sum::<i32>()
+        .min(MAX_SCORE)
+}
+
+fn get_decision(score: i32, thresholds: &NativeFrmThresholds) -> FraudCheckStatus {
+    if score >= i32::from(thresholds.decline) {
+        FraudCheckStatus::Fraud
+    } else if score >= i32::from(thresholds.review) {
+        FraudCheckStatus::ManualReview
+    } else {
+        FraudCheckStatus::Legit
+    }
+}
+
+/// Compares the issuing country of the card against the billing and shipping countries of the
+/// payment. The country of the IP address of the customer is not considered, since IP addresses
+/// are not geolocated.
+fn has_card_country_mismatch(
+    card_issuing_country: Option<&str>,
+    payment_countries: &[Option<CountryAlpha2>],
+) -> bool {
+    let Some(card_issuing_country) = card_issuing_country.and_then(resolve_country) else {
+        return false;
+    };
+
+    payment_countries
+        .iter()
+        .flatten()
+        .any(|country| *country != card_issuing_country)
+}
+
+/// The issuing country of the card could either be an ISO code or the name of the country
+fn resolve_country(country: &str) -> Option<CountryAlpha2> {
+    let normalized_country = country
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .collect::<String>()
+        .to_uppercase();
+
+    CountryAlpha2::from_str(&normalized_country)
+        .ok()
+        .or_else(|| {
+            CountryAlpha3::from_str(&normalized_country)
+                .ok()
+                .map(|country| Country::from_alpha3(country).to_alpha2())
+        })
+        .or_else(|| {
+            Country::iter()
+                .find(|country| country.to_string().to_uppercase() == normalized_country)
+                .map(Country::to_alpha2)
+        })
+}
+
+/// Counts the payment attempt against the customer, card and IP address of the payment, and
+/// checks whether any of them made more attempts than allowed within the velocity window. The
+/// signal is not triggered when the counters are unavailable, so that payments are not blocked by
+/// an outage of Redis.
+async fn exceeds_velocity_limit<F, D>(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_data: &D,
+    ip_address: Option<IpAddr>,
+    config: &NativeFrmConfig,
+) -> bool
+where
+    D: payments::OperationSessionGetters<F>,
+{
+    let identifiers = [
+        payment_data
+            .get_payment_intent()
+            .customer_id
+            .as_ref()
+            .map(|customer_id| format!("customer_{}", customer_id.get_string_repr())),
+        payment_data
+            .get_payment_attempt()
+            .fingerprint_id
+            .as_ref()
+            .map(|fingerprint_id| format!("card_{fingerprint_id}")),
+        ip_address.map(|ip_address| format!("ip_{ip_address}")),
+    ];
+
+    let result: RouterResult<bool> = async {
+        let redis_conn = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?;
+
+        let mut exceeds_limit = false;
+        for identifier in identifiers.into_iter().flatten() {
+            let key = format!(
+                "{VELOCITY_KEY_PREFIX}_{}_{identifier}",
+                merchant_id.get_string_repr()
+            );
+            // The window starts with the first attempt, as the expiry is only set on a new counter
+            let attempts = redis_conn
+                .increment_field_in_hash_with_expiry(
+                    &key.as_str().into(),
+                    COUNT_FIELD,
+                    1,
+                    config.velocity.window_in_secs.into(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to increment velocity counter")?;
+
+            exceeds_limit |= u64::try_from(attempts)
+                .is_ok_and(|attempts| attempts > u64::from(config.velocity.max_attempts));
+        }
+
+        Ok(exceeds_limit)
+    }
+    .await;
+
+    result.unwrap_or_else(|error| {
+        logger::error!(?error, "Failed to evaluate velocity of native fraud engine");
+        false
+    })
+}
+
+fn uses_recently_registered_email_domain<F, D>(
+    payment_data: &D,
+    customer: &Option<domain::Customer>,
+    config: &NativeFrmConfig,
+) -> bool
+where
+    D: payments::OperationSessionGetters<F>,
+{
+    let email = payment_data
+        .get_billing_address()
+        .and_then(|address| address.email)
+        .or_else(|| {
+            customer
+                .as_ref()
+                .and_then(|customer| customer.email.clone())
+                .map(common_utils::pii::Email::from)
+        });
+
+    email.is_some_and(|email| {
+        email
+            .peek()
+            .rsplit_once('@')
+            .is_some_and(|(_, email_domain)| {
+                config
+                    .recently_registered_email_domains
+                    .iter()
+                    .any(|domain| domain.eq_ignore_ascii_case(email_domain))
+            })
+    })
+}
+
+/// Checks whether the card testing guard of the profile recorded any unsuccessful payments for
+/// the card, the card and IP address, or the customer of the payment
+async fn has_card_testing_guard_hits<F, D>(
+    state: &SessionState,
+    platform: &domain::Platform,
+    payment_data: &D,
+    ip_address: Option<IpAddr>,
+) -> RouterResult<bool>
+where
+    D: payments::OperationSessionGetters<F>,
+{
+    let Some(profile_id) = payment_data.get_payment_intent().profile_id.as_ref() else {
+        return Ok(false);
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(platform.get_processor().get_key_store(), profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    let Some(card_testing_guard_config) = &business_profile.card_testing_guard_config else {
+        return Ok(false);
+    };
+
+    let mut cache_keys = Vec::new();
+    if let Some(domain::PaymentMethodData::Card(card)) = payment_data.get_payment_method_data() {
+        if business_profile.card_testing_secret_key.is_some() {
+            let fingerprint = card_testing_guard_utils::generate_fingerprint(
+                card.card_number.clone(),
+                &business_profile,
+            )
+            .await?;
+
+            if card_testing_guard_config.is_guest_user_card_blocking_enabled {
+                cache_keys.push(format!(
+                    "{}_{}",
+                    consts::GUEST_USER_CARD_BLOCKING_CACHE_KEY_PREFIX,
+                    fingerprint.peek()
+                ));
+            }
+            if let Some(ip_address) =
+                ip_address.filter(|_| card_testing_guard_config.is_card_ip_blocking_enabled)
+            {
+                cache_keys.push(format!(
+                    "{}_{}_{}",
+                    consts::CARD_IP_BLOCKING_CACHE_KEY_PREFIX,
+                    fingerprint.peek(),
+                    ip_address
+                ));
+            }
+        }
+    }
+    if let Some(customer_id) = payment_data
+        .get_payment_intent()
+        .customer_id
+        .as_ref()
+        .filter(|_| card_testing_guard_config.is_customer_id_blocking_enabled)
+    {
+        cache_keys.push(format!(
+            "{}_{}_{}",
+            consts::CUSTOMER_ID_BLOCKING_PREFIX,
+            profile_id.get_string_repr(),
+            customer_id.get_string_repr(),
+        ));
+    }
+
+    for cache_key in cache_keys {
+        if services::card_testing_guard::get_blocked_count_from_cache(state, &cache_key)
+            .await?
+            .is_some_and(|blocked_count| blocked_count > 0)
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+async fn has_previous_chargebacks(
+    db: &dyn StorageInterface,
+    merchant_id: &common_utils::id_type::MerchantId,
+    fingerprint_id: &str,
+) -> RouterResult<bool> {
+    let chargeback_count = db
+        .count_chargebacks_by_merchant_id_fingerprint_id(
+            merchant_id,
+            fingerprint_id,
+            date_time::now().saturating_sub(CHARGEBACK_WINDOW),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count chargebacks received for the card")?;
+
+    Ok(chargeback_count > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::api::fraud_check::NativeFrmVelocityConfig;
+
+    #[test]
+    fn test_resolve_country() {
+        assert_eq!(resolve_country("US"), Some(CountryAlpha2::US));
+        assert_eq!(resolve_country("gb"), Some(CountryAlpha2::GB));
+        assert_eq!(resolve_country("IND"), Some(CountryAlpha2::IN));
+        assert_eq!(resolve_country("Germany"), Some(CountryAlpha2::DE));
+        assert_eq!(
+            resolve_country("UNITED STATES OF AMERICA"),
+            Some(CountryAlpha2::US)
+        );
+        assert_eq!(resolve_country("U.S."), Some(CountryAlpha2::US));
+        assert_eq!(resolve_country("Atlantis"), None);
+        assert_eq!(resolve_country(""), None);
+    }
+
+    #[test]
+    fn test_has_country_mismatch() {
+        // Either the billing or the shipping country could differ from the issuing country
+        assert!(has_card_country_mismatch(
+            Some("US"),
+            &[Some(CountryAlpha2::US), Some(CountryAlpha2::GB)]
+        ));
+        assert!(has_card_country_mismatch(
+            Some("FRANCE"),
+            &[Some(CountryAlpha2::DE), None]
+        ));
+        assert!(!has_card_country_mismatch(
+            Some("USA"),
+            &[Some(CountryAlpha2::US), Some(CountryAlpha2::US)]
+        ));
+        // The signal is not triggered when there is nothing to compare
+        assert!(!has_card_country_mismatch(Some("US"), &[None, None]));
+        assert!(!has_card_country_mismatch(
+            None,
+            &[Some(CountryAlpha2::GB), None]
+        ));
+        assert!(!has_card_country_mismatch(
+            Some("Atlantis"),
+            &[Some(CountryAlpha2::GB), None]
+        ));
+    }
+
+    #[test]
+    fn test_score_is_mapped_to_decision() {
+        let weights = NativeFrmSignalWeights::default();
+        let thresholds = NativeFrmThresholds::default();
+
+        let score = get_risk_score(&[], &weights);
+        assert_eq!(score, 0);
+        assert_eq!(get_decision(score, &thresholds), FraudCheckStatus::Legit);
+
+        let score = get_risk_score(
+            &[
+                NativeFrmSignal::CardCountryMismatch,
+                NativeFrmSignal::Velocity,
+            ],
+            &weights,
+        );
+        assert_eq!(score, 50);
+        assert_eq!(
+            get_decision(score, &thresholds),
+            FraudCheckStatus::ManualReview
+        );
+
+        let score = get_risk_score(
+            &[
+                NativeFrmSignal::PreviousChargebacks,
+                NativeFrmSignal::CardTestingGuard,
+                NativeFrmSignal::RecentlyRegisteredEmailDomain,
+            ],
+            &weights,
+        );
+        assert_eq!(score, 75);
+        assert_eq!(
+            get_decision(score, &thresholds),
+            FraudCheckStatus::ManualReview
+        );
+
+        // The score is capped, even if the weights add up to more
+        let score = get_risk_score(
+            &[
+                NativeFrmSignal::CardCountryMismatch,
+                NativeFrmSignal::Velocity,
+                NativeFrmSignal::RecentlyRegisteredEmailDomain,
+                NativeFrmSignal::CardTestingGuard,
+                NativeFrmSignal::PreviousChargebacks,
+            ],
+            &weights,
+        );
+        assert_eq!(score, MAX_SCORE);
+        assert_eq!(get_decision(score, &thresholds), FraudCheckStatus::Fraud);
+
+        // Payments are declined rather than reviewed when both thresholds are met
+        let thresholds = NativeFrmThresholds {
+            review: 25,
+            decline: 25,
+        };
+        assert_eq!(get_decision(25, &thresholds), FraudCheckStatus::Fraud);
+        assert_eq!(get_decision(24, &thresholds), FraudCheckStatus::Legit);
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(validate_native_frm_config(&NativeFrmConfig::default()).is_ok());
+
+        let config = NativeFrmConfig {
+            thresholds: NativeFrmThresholds {
+                review: 70,
+                decline: 60,
+            },
+            ..Default::default()
+        };
+        assert!(validate_native_frm_config(&config).is_err());
+
+        let config = NativeFrmConfig {
+            velocity: NativeFrmVelocityConfig {
+                window_in_secs: 0,
+                max_attempts: 5,
+            },
+            ..Default::default()
+        };
+        assert!(validate_native_frm_config(&config).is_err());
+    }
+}
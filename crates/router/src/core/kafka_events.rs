@@ -0,0 +1,244 @@
+use api_models::kafka_events as kafka_events_api;
+use common_utils::types::TimeRange;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::kv_store::RedisConnInterface;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::kafka_store::TenantID,
+    events::EventsHandler,
+    routes::metrics::TASKS_ADDED_COUNT,
+    services::{self, kafka::KafkaProducer},
+    types::storage,
+    SessionState,
+};
+
+/// Number of payment intents loaded at a time
+const BACKFILL_PAGE_SIZE: u32 = 100;
+
+const KAFKA_EVENTS_BACKFILL_TASK: &str = "KAFKA_EVENTS_BACKFILL";
+
+/// Schedules a task publishing the events of the payments of a merchant created in the requested
+/// time range to Kafka again, along with the events of their attempts and refunds. The events go
+/// through the outbox if it is enabled. The progress of the backfill is tracked by the task, which
+/// can be looked up with the process tracker tasks API.
+///
+/// Only the current state of each record is published, so the time range should be limited to
+/// the period in which events were lost to avoid counting the records twice in analytics.
+#[instrument(skip_all)]
+pub async fn backfill_events(
+    state: SessionState,
+    request: kafka_events_api::KafkaEventsBackfillRequest,
+) -> RouterResponse<kafka_events_api::KafkaEventsBackfillResponse> {
+    get_kafka_producer(&state)?;
+    let db = state.store.as_ref();
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &request.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    db.find_merchant_account_by_merchant_id(&request.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    // The end of the time range is fixed so that payments created during the backfill do not
+    // shift the pages, since payment intents are listed by their creation time
+    let tracking_data = kafka_events_api::KafkaEventsBackfillTrackingData {
+        merchant_id: request.merchant_id.clone(),
+        time_range: TimeRange {
+            start_time: request.time_range.start_time,
+            end_time: Some(
+                request
+                    .time_range
+                    .end_time
+                    .unwrap_or_else(common_utils::date_time::now),
+            ),
+        },
+        payment_intents_count: 0,
+        payment_attempts_count: 0,
+        refunds_count: 0,
+    };
+
+    let runner = storage::ProcessTrackerRunner::KafkaEventsBackfillWorkflow;
+    let task_id = format!(
+        "{runner}_{KAFKA_EVENTS_BACKFILL_TASK}_{}",
+        common_utils::generate_id(consts::ID_LENGTH, "backfill")
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        task_id.clone(),
+        KAFKA_EVENTS_BACKFILL_TASK,
+        runner,
+        ["KAFKA", "BACKFILL"],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct Kafka events backfill task")?;
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule Kafka events backfill task")?;
+    TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "kafka_events_backfill")),
+    );
+
+    Ok(services::ApplicationResponse::Json(
+        kafka_events_api::KafkaEventsBackfillResponse {
+            merchant_id: request.merchant_id,
+            task_id,
+        },
+    ))
+}
+
+/// Publishes the events of the next page of payment intents of the backfill along with the
+/// events of their attempts and refunds, and returns the tracking data advanced past the page.
+/// The second value is whether the page was the last one.
+pub async fn backfill_events_page(
+    state: &SessionState,
+    tracking_data: kafka_events_api::KafkaEventsBackfillTrackingData,
+) -> RouterResult<(kafka_events_api::KafkaEventsBackfillTrackingData, bool)> {
+    let kafka_producer = get_kafka_producer(state)?;
+    let db = state.store.as_ref();
+    let tenant_id = TenantID(state.tenant.tenant_id.get_string_repr().to_owned());
+    let merchant_id = &tracking_data.merchant_id;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let storage_scheme = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .storage_scheme;
+
+    let mut constraints = PaymentIntentFetchConstraints::from(tracking_data.time_range);
+    if let PaymentIntentFetchConstraints::List(params) = &mut constraints {
+        params.offset = tracking_data.payment_intents_count;
+        params.limit = Some(BACKFILL_PAGE_SIZE);
+    }
+    let payment_intents = db
+        .filter_payment_intent_by_constraints(merchant_id, &constraints, &key_store, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payment intents in the time range")?;
+
+    let mut payment_attempts_count = 0;
+    let mut refunds_count = 0;
+    for payment_intent in payment_intents.iter() {
+        let payment_id = payment_intent.get_id();
+
+        kafka_producer
+            .log_payment_intent(
+                payment_intent,
+                None,
+                tenant_id.clone(),
+                state.infra_components.clone(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to publish payment intent event")?;
+
+        let payment_attempts = db
+            .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list attempts of the payment")?;
+        for payment_attempt in payment_attempts.iter() {
+            kafka_producer
+                .log_payment_attempt(payment_attempt, None, tenant_id.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to publish payment attempt event")?;
+        }
+        payment_attempts_count += payment_attempts.len();
+
+        let refunds = db
+            .find_refund_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list refunds of the payment")?;
+        for refund in refunds.iter() {
+            kafka_producer
+                .log_refund(refund, None, tenant_id.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to publish refund event")?;
+        }
+        refunds_count += refunds.len();
+    }
+
+    let to_count = |count: usize| {
+        u32::try_from(count)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Too many records to backfill")
+    };
+    let tracking_data = kafka_events_api::KafkaEventsBackfillTrackingData {
+        payment_intents_count: tracking_data
+            .payment_intents_count
+            .saturating_add(to_count(payment_intents.len())?),
+        payment_attempts_count: tracking_data
+            .payment_attempts_count
+            .saturating_add(u64::from(to_count(payment_attempts_count)?)),
+        refunds_count: tracking_data
+            .refunds_count
+            .saturating_add(u64::from(to_count(refunds_count)?)),
+        ..tracking_data
+    };
+
+    logger::info!(
+        merchant_id = ?tracking_data.merchant_id,
+        payment_intents_count = tracking_data.payment_intents_count,
+        payment_attempts_count = tracking_data.payment_attempts_count,
+        refunds_count = tracking_data.refunds_count,
+        "Published page of Kafka events for backfill"
+    );
+
+    Ok((tracking_data, is_last_page(payment_intents.len())))
+}
+
+fn get_kafka_producer(state: &SessionState) -> RouterResult<KafkaProducer> {
+    let EventsHandler::Kafka(kafka_producer) = &state.event_handler else {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Kafka events are not enabled".to_string(),
+        }
+        .into());
+    };
+
+    let mut kafka_producer = kafka_producer.clone();
+    if kafka_producer.is_outbox_enabled() {
+        kafka_producer.set_outbox_connection(
+            state
+                .store
+                .get_redis_conn()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get redis connection for Kafka outbox")?,
+        );
+    }
+    Ok(kafka_producer)
+}
+
+fn is_last_page(page_len: usize) -> bool {
+    usize::try_from(BACKFILL_PAGE_SIZE).is_ok_and(|page_size| page_len < page_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_last_page() {
+        assert!(is_last_page(0));
+        assert!(is_last_page(99));
+        assert!(!is_last_page(100));
+    }
+}
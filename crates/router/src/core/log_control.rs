@@ -0,0 +1,211 @@
+use std::borrow::Cow;
+
+use api_models::log_control as log_control_api;
+use common_utils::{date_time, fp_utils::when, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{
+    instrument,
+    logger::control::{self, DebugOverride, LogControlUpdate, VerboseLevel},
+    tracing,
+};
+use storage_impl::redis::{kv_store::RedisConnInterface, pub_sub::PubSubInterface};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    services, SessionState,
+};
+
+/// Time in seconds after which verbose logging is disabled, if not specified
+const DEFAULT_DEBUG_LOGGING_EXPIRY: u32 = 15 * 60;
+/// Maximum time in seconds for which verbose logging can be enabled
+const MAX_DEBUG_LOGGING_EXPIRY: u32 = 24 * 60 * 60;
+
+#[instrument(skip_all)]
+pub async fn retrieve_log_control(
+    _state: SessionState,
+) -> RouterResponse<log_control_api::LogControlResponse> {
+    Ok(services::ApplicationResponse::Json(get_log_control_status()))
+}
+
+#[instrument(skip_all)]
+pub async fn update_log_filter(
+    state: SessionState,
+    request: log_control_api::LogFilterUpdateRequest,
+) -> RouterResponse<log_control_api::LogControlResponse> {
+    control::validate_filter_directive(&request.filter_directive).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid filtering directive: {error}"),
+        })
+    })?;
+
+    publish_and_apply(
+        &state,
+        LogControlUpdate::SetFilterDirective {
+            directive: Some(request.filter_directive),
+        },
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_log_control_status()))
+}
+
+#[instrument(skip_all)]
+pub async fn reset_log_filter(
+    state: SessionState,
+) -> RouterResponse<log_control_api::LogControlResponse> {
+    publish_and_apply(
+        &state,
+        LogControlUpdate::SetFilterDirective { directive: None },
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(get_log_control_status()))
+}
+
+#[instrument(skip_all)]
+pub async fn create_debug_logging(
+    state: SessionState,
+    request: log_control_api::DebugLoggingCreateRequest,
+) -> RouterResponse<log_control_api::DebugLoggingResponse> {
+    when(
+        request.merchant_id.is_none()
+            && request.profile_id.is_none()
+            && request.connector.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "At least one of merchant_id, profile_id and connector must be specified"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let expires_in = request.expires_in.unwrap_or(DEFAULT_DEBUG_LOGGING_EXPIRY);
+    when(
+        expires_in == 0 || expires_in > MAX_DEBUG_LOGGING_EXPIRY,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "expires_in must be between 1 and {MAX_DEBUG_LOGGING_EXPIRY} seconds"
+                ),
+            })
+        },
+    )?;
+
+    let expires_at = date_time::now().saturating_add(time::Duration::seconds(expires_in.into()));
+    let debug_override = DebugOverride {
+        id: common_utils::generate_id(consts::ID_LENGTH, "dbg"),
+        merchant_id: request
+            .merchant_id
+            .map(|merchant_id| merchant_id.get_string_repr().to_owned()),
+        profile_id: request
+            .profile_id
+            .map(|profile_id| profile_id.get_string_repr().to_owned()),
+        connector: request.connector.map(|connector| connector.to_string()),
+        level: match request.level {
+            log_control_api::DebugLogLevel::Info => VerboseLevel::Info,
+            log_control_api::DebugLogLevel::Debug => VerboseLevel::Debug,
+            log_control_api::DebugLogLevel::Trace => VerboseLevel::Trace,
+        },
+        sample_traces: request.sample_traces,
+        expires_at: expires_at.assume_utc().unix_timestamp(),
+    };
+
+    publish_and_apply(
+        &state,
+        LogControlUpdate::AddDebugOverride(debug_override.clone()),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(debug_logging_response(
+        debug_override,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_debug_logging(
+    state: SessionState,
+    request: log_control_api::DebugLoggingDeleteRequest,
+) -> RouterResponse<()> {
+    let exists = control::status()
+        .debug_overrides
+        .iter()
+        .any(|debug_override| debug_override.id == request.id);
+    when(!exists, || {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Debug logging with id {} not found", request.id),
+        })
+    })?;
+
+    publish_and_apply(
+        &state,
+        LogControlUpdate::RemoveDebugOverride { id: request.id },
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Persists the update so that instances starting later apply it, publishes it to all instances,
+/// and applies it to the current instance without waiting for it to be received back.
+async fn publish_and_apply(
+    state: &SessionState,
+    update: LogControlUpdate,
+) -> errors::RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_cache_store()
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .persist_log_control_update(&update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to persist log control update")?;
+    redis_conn
+        .publish_log_control_update(&update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to publish log control update")?;
+
+    control::apply(update)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to apply log control update")
+}
+
+fn get_log_control_status() -> log_control_api::LogControlResponse {
+    let status = control::status();
+
+    log_control_api::LogControlResponse {
+        filter_directive: status.filter_directive,
+        debug_logging: status
+            .debug_overrides
+            .into_iter()
+            .map(debug_logging_response)
+            .collect(),
+    }
+}
+
+fn debug_logging_response(debug_override: DebugOverride) -> log_control_api::DebugLoggingResponse {
+    log_control_api::DebugLoggingResponse {
+        id: debug_override.id,
+        merchant_id: debug_override
+            .merchant_id
+            .and_then(|merchant_id| id_type::MerchantId::wrap(merchant_id).ok()),
+        profile_id: debug_override
+            .profile_id
+            .and_then(|profile_id| id_type::ProfileId::try_from(Cow::Owned(profile_id)).ok()),
+        connector: debug_override.connector,
+        level: match debug_override.level {
+            VerboseLevel::Info => log_control_api::DebugLogLevel::Info,
+            VerboseLevel::Debug => log_control_api::DebugLogLevel::Debug,
+            VerboseLevel::Trace => log_control_api::DebugLogLevel::Trace,
+        },
+        sample_traces: debug_override.sample_traces,
+        expires_at: time::OffsetDateTime::from_unix_timestamp(debug_override.expires_at)
+            .map(|expires_at| time::PrimitiveDateTime::new(expires_at.date(), expires_at.time()))
+            .unwrap_or(time::PrimitiveDateTime::MAX),
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(feature = "olap")]
 use strum::IntoEnumIterator;
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
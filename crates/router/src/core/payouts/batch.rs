@@ -0,0 +1,949 @@
+use std::collections::HashSet;
+
+use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
+use actix_web::ResponseError;
+use api_models::{enums as api_enums, webhooks};
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{Encode, ValueExt},
+    id_type::{self, GenerateId},
+    type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use rustc_hash::FxHashMap;
+use scheduler::utils as pt_utils;
+use tracing_futures::Instrument;
+
+use super::payouts_create_core;
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::payouts, domain, storage},
+    utils,
+};
+
+#[derive(Debug, MultipartForm)]
+pub struct PayoutBatchCsvForm {
+    #[multipart(limit = "10MB")]
+    pub file: Bytes,
+    pub profile_id: Option<Text<id_type::ProfileId>>,
+}
+
+/// Parses the uploaded CSV file into a batch create request.
+///
+/// Unlike other bulk APIs, a single malformed record rejects the whole file, so that a batch is
+/// never created with only a subset of the instructions the merchant intended to submit.
+pub fn get_payout_batch_create_request_from_csv(
+    form: PayoutBatchCsvForm,
+) -> Result<payouts::PayoutBatchCreateRequest, errors::ApiErrorResponse> {
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .from_reader(form.file.data.as_ref());
+    let items = csv_reader
+        .deserialize::<payouts::PayoutBatchCsvRecord>()
+        .enumerate()
+        .map(|(i, result)| {
+            result.map(payouts::PayoutCreateRequest::from).map_err(|e| {
+                logger::error!("Error parsing record {}: {}", i + 1, e);
+                errors::ApiErrorResponse::PreconditionFailed {
+                    message: format!("Failed to parse record {} of the CSV file: {e}", i + 1),
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    logger::info!("Parsed a total of {} records", items.len());
+
+    Ok(payouts::PayoutBatchCreateRequest {
+        profile_id: form.profile_id.map(|profile_id| profile_id.0),
+        items,
+        metadata: None,
+    })
+}
+
+/// Validates the items of a batch without touching the database
+fn validate_payout_batch_items(
+    merchant_id: &id_type::MerchantId,
+    items: &[payouts::PayoutCreateRequest],
+) -> RouterResult<()> {
+    if items.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A payout batch must contain at least one item".to_string(),
+        }));
+    }
+    if items.len() > consts::MAX_PAYOUT_BATCH_SIZE {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "A payout batch cannot contain more than {} items",
+                consts::MAX_PAYOUT_BATCH_SIZE
+            ),
+        }));
+    }
+
+    let mut payout_ids = HashSet::new();
+    for (index, item) in items.iter().enumerate() {
+        let line_number = index + 1;
+        let invalid_item = |message: &str| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Item {line_number}: {message}"),
+            })
+        };
+
+        if item.amount.is_none() {
+            return Err(invalid_item("amount is required"));
+        }
+        if item.currency.is_none() {
+            return Err(invalid_item("currency is required"));
+        }
+        if item.payout_method_data.is_some() {
+            return Err(invalid_item(
+                "payout_method_data is not supported for batch payouts, use payout_method_id or payout_token instead",
+            ));
+        }
+        if item.payout_link == Some(true) {
+            return Err(invalid_item(
+                "payout links are not supported for batch payouts",
+            ));
+        }
+        if item.confirm == Some(false) {
+            return Err(invalid_item("batch payouts are always confirmed"));
+        }
+        if item
+            .merchant_id
+            .as_ref()
+            .is_some_and(|item_merchant_id| item_merchant_id != merchant_id)
+        {
+            return Err(invalid_item(
+                "merchant_id does not match the merchant account",
+            ));
+        }
+        if let Some(payout_id) = item.payout_id.as_ref() {
+            if !payout_ids.insert(payout_id) {
+                return Err(invalid_item(&format!(
+                    "payout_id {} is repeated within the batch",
+                    payout_id.get_string_repr()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rejects the batch if any of the payout IDs provided in its items already exist, using a
+/// single lookup for the whole batch
+async fn validate_uniqueness_of_payout_ids(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    items: &[payouts::PayoutCreateRequest],
+) -> RouterResult<()> {
+    let payout_ids = items
+        .iter()
+        .filter_map(|item| item.payout_id.clone())
+        .collect::<Vec<_>>();
+    if payout_ids.is_empty() {
+        return Ok(());
+    }
+
+    let existing_payouts = db
+        .filter_payouts_by_merchant_id_payout_ids(
+            merchant_account.get_id(),
+            &payout_ids,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while finding existing payouts of the batch")?;
+
+    let existing_payout_ids = existing_payouts
+        .into_iter()
+        .map(|payout| payout.payout_id)
+        .collect::<Vec<_>>();
+    match get_first_existing_payout_id(&payout_ids, &existing_payout_ids) {
+        Some(payout_id) => Err(report!(errors::ApiErrorResponse::DuplicatePayout {
+            payout_id
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Returns the first payout ID, in the order of the batch items, which belongs to an existing
+/// payout
+fn get_first_existing_payout_id(
+    payout_ids: &[id_type::PayoutId],
+    existing_payout_ids: &[id_type::PayoutId],
+) -> Option<id_type::PayoutId> {
+    let existing_payout_ids = existing_payout_ids.iter().collect::<HashSet<_>>();
+    payout_ids
+        .iter()
+        .find(|payout_id| existing_payout_ids.contains(payout_id))
+        .cloned()
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_create_core(
+    state: SessionState,
+    platform: domain::Platform,
+    req: payouts::PayoutBatchCreateRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_account = platform.get_processor().get_account();
+    let merchant_id = merchant_account.get_id();
+
+    validate_payout_batch_items(merchant_id, &req.items)?;
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        None,
+        None,
+        &platform,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    validate_uniqueness_of_payout_ids(db, merchant_account, &req.items).await?;
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "payout_batch");
+    let now = common_utils::date_time::now();
+    let mut batch_items = Vec::with_capacity(req.items.len());
+    let mut payout_requests = FxHashMap::default();
+
+    for (index, mut item) in req.items.into_iter().enumerate() {
+        let line_number = i32::try_from(index + 1)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        if item
+            .profile_id
+            .as_ref()
+            .is_some_and(|item_profile_id| item_profile_id != &profile_id)
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Item {line_number}: profile_id does not match the profile of the batch"
+                ),
+            }));
+        }
+
+        let payout_id = item
+            .payout_id
+            .clone()
+            .unwrap_or_else(id_type::PayoutId::generate);
+
+        item.payout_id = Some(payout_id.clone());
+        item.merchant_id = Some(merchant_id.clone());
+        item.profile_id = Some(profile_id.clone());
+        item.confirm = Some(true);
+
+        let payout_request = item
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize payout batch item")?;
+
+        let item_id = utils::generate_id(consts::ID_LENGTH, "payout_batch_item");
+        payout_requests.insert(item_id.clone(), Secret::new(payout_request));
+        batch_items.push((item_id, line_number, payout_id));
+    }
+
+    // Payout requests hold the customer and payout method details, and are stored encrypted
+    let mut payout_requests =
+        domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+            &(&state).into(),
+            type_name!(storage::PayoutBatchItem),
+            domain::types::CryptoOperation::BatchEncrypt(payout_requests),
+            Identifier::Merchant(merchant_id.clone()),
+            platform
+                .get_processor()
+                .get_key_store()
+                .key
+                .get_inner()
+                .peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_batchoperation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt payout requests of payout batch items")?;
+    let batch_items = batch_items
+        .into_iter()
+        .map(|(item_id, line_number, payout_id)| {
+            let payout_request = payout_requests
+                .remove(&item_id)
+                .map(Encryption::from)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Payout request of payout batch item was not encrypted")?;
+            Ok(storage::PayoutBatchItemNew {
+                id: item_id,
+                batch_id: batch_id.clone(),
+                merchant_id: merchant_id.clone(),
+                line_number,
+                payout_id,
+                status: storage_enums::PayoutBatchItemStatus::Pending,
+                payout_request,
+                retry_count: 0,
+                created_at: now,
+                modified_at: now,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let total_count = i32::try_from(batch_items.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let process_tracker_entry = get_payout_batch_workflow_task(&batch_id, merchant_id, now)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct payout batch workflow task")?;
+    let batch_new = storage::PayoutBatchNew {
+        id: batch_id,
+        merchant_id: merchant_id.clone(),
+        profile_id,
+        status: storage_enums::PayoutBatchStatus::Pending,
+        total_count,
+        processed_count: 0,
+        failed_count: 0,
+        cancelled_count: 0,
+        metadata: req.metadata,
+        created_at: now,
+        modified_at: now,
+        completed_at: None,
+    };
+
+    let batch = db
+        .insert_payout_batch(batch_new, batch_items, process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payout batch")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_batch_response(batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_retrieve_core(
+    state: SessionState,
+    platform: domain::Platform,
+    batch_id: String,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let batch = find_payout_batch(&state, &platform, &batch_id).await?;
+    Ok(services::ApplicationResponse::Json(
+        get_payout_batch_response(batch),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_list_core(
+    state: SessionState,
+    platform: domain::Platform,
+    constraints: payouts::PayoutBatchListConstraints,
+) -> RouterResponse<payouts::PayoutBatchListResponse> {
+    let batches = state
+        .store
+        .list_payout_batches_by_merchant_id(
+            platform.get_processor().get_account().get_id(),
+            i64::from(constraints.limit),
+            constraints.offset.map(i64::from).unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batches")?;
+
+    let data = batches
+        .into_iter()
+        .map(get_payout_batch_response)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_items_list_core(
+    state: SessionState,
+    platform: domain::Platform,
+    batch_id: String,
+    constraints: payouts::PayoutBatchItemsListConstraints,
+) -> RouterResponse<payouts::PayoutBatchItemsResponse> {
+    let batch = find_payout_batch(&state, &platform, &batch_id).await?;
+    let items = state
+        .store
+        .list_payout_batch_items_by_batch_id(
+            &batch.id,
+            constraints.status,
+            Some(i64::from(constraints.limit)),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list payout batch items")?;
+
+    let data = items
+        .into_iter()
+        .map(|item| payouts::PayoutBatchItemResponse {
+            item_id: item.id,
+            line_number: item.line_number,
+            payout_id: item.payout_id,
+            status: item.status,
+            payout_status: item.payout_status,
+            retry_count: item.retry_count,
+            error_code: item.error_code,
+            error_message: item.error_message,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutBatchItemsResponse {
+            batch_id: batch.id,
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Cancels the items of the batch which have not been picked up yet. Payouts which were already
+/// created for the batch are not affected, they can be cancelled individually.
+#[instrument(skip_all)]
+pub async fn payout_batch_cancel_core(
+    state: SessionState,
+    platform: domain::Platform,
+    batch_id: String,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let batch = find_payout_batch(&state, &platform, &batch_id).await?;
+
+    if batch.status.is_terminal_status() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Payout batch {} cannot be cancelled for status {}",
+                batch.id, batch.status
+            ),
+        }));
+    }
+
+    state
+        .store
+        .cancel_pending_payout_batch_items_by_batch_id(&batch.id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel pending payout batch items")?;
+
+    let batch = update_payout_batch_counts(&state, &platform, batch).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_payout_batch_response(batch),
+    ))
+}
+
+async fn find_payout_batch(
+    state: &SessionState,
+    platform: &domain::Platform,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(
+            platform.get_processor().get_account().get_id(),
+            batch_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch {batch_id} not found"),
+        })
+}
+
+/// Recomputes the aggregate counts and status of the batch from its items, and triggers the
+/// outgoing webhook if the batch has just reached a terminal status
+pub async fn update_payout_batch_counts(
+    state: &SessionState,
+    platform: &domain::Platform,
+    batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let db = &*state.store;
+    let processed_count = count_payout_batch_items(
+        db,
+        &batch.id,
+        storage_enums::PayoutBatchItemStatus::Processed,
+    )
+    .await?;
+    let failed_count =
+        count_payout_batch_items(db, &batch.id, storage_enums::PayoutBatchItemStatus::Failed)
+            .await?;
+    let cancelled_count = count_payout_batch_items(
+        db,
+        &batch.id,
+        storage_enums::PayoutBatchItemStatus::Cancelled,
+    )
+    .await?;
+
+    let status = get_payout_batch_status(
+        batch.total_count,
+        processed_count,
+        failed_count,
+        cancelled_count,
+    );
+    let was_terminal = batch.status.is_terminal_status();
+
+    let batch = db
+        .update_payout_batch(
+            batch,
+            storage::PayoutBatchUpdate::CountsUpdate {
+                status,
+                processed_count,
+                failed_count,
+                cancelled_count,
+                completed_at: status
+                    .is_terminal_status()
+                    .then(common_utils::date_time::now),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch")?;
+
+    if !was_terminal && batch.status.is_terminal_status() {
+        trigger_payout_batch_webhook(state, platform, &batch).await?;
+    }
+
+    Ok(batch)
+}
+
+/// Derives the status of a batch from the counts of its items in each terminal status
+fn get_payout_batch_status(
+    total_count: i32,
+    processed_count: i32,
+    failed_count: i32,
+    cancelled_count: i32,
+) -> storage_enums::PayoutBatchStatus {
+    let pending_count = total_count - processed_count - failed_count - cancelled_count;
+    if pending_count > 0 {
+        storage_enums::PayoutBatchStatus::Processing
+    } else if processed_count == total_count {
+        storage_enums::PayoutBatchStatus::Completed
+    } else if processed_count > 0 {
+        storage_enums::PayoutBatchStatus::PartiallyCompleted
+    } else if cancelled_count == total_count {
+        storage_enums::PayoutBatchStatus::Cancelled
+    } else {
+        storage_enums::PayoutBatchStatus::Failed
+    }
+}
+
+async fn count_payout_batch_items(
+    db: &dyn StorageInterface,
+    batch_id: &str,
+    status: storage_enums::PayoutBatchItemStatus,
+) -> RouterResult<i32> {
+    let count = db
+        .count_payout_batch_items_by_batch_id_status(batch_id, status)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count payout batch items")?;
+    i32::try_from(count).change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+pub async fn trigger_payout_batch_webhook(
+    state: &SessionState,
+    platform: &domain::Platform,
+    batch: &storage::PayoutBatch,
+) -> RouterResult<()> {
+    let Some(event_type) = Option::<api_enums::EventType>::from(batch.status) else {
+        logger::warn!("Outgoing webhook not sent because of missing event type status mapping");
+        return Ok(());
+    };
+
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            platform.get_processor().get_key_store(),
+            &batch.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: batch.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let cloned_state = state.clone();
+    let cloned_platform = platform.clone();
+    let primary_object_id = batch.id.clone();
+    let primary_object_created_at = batch.created_at;
+    let response = get_payout_batch_response(batch.clone());
+
+    // This spawns this futures in a background thread, the exception inside this future won't affect
+    // the current thread and the lifecycle of spawn thread is not handled by runtime.
+    // So when server shutdown won't wait for this thread's completion.
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_platform,
+                business_profile,
+                event_type,
+                storage_enums::EventClass::Payouts,
+                primary_object_id,
+                storage_enums::EventObjectType::PayoutBatchDetails,
+                webhooks::OutgoingWebhookContent::PayoutBatchDetails(Box::new(response)),
+                Some(primary_object_created_at),
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+
+    Ok(())
+}
+
+pub fn get_payout_batch_response(batch: storage::PayoutBatch) -> payouts::PayoutBatchResponse {
+    payouts::PayoutBatchResponse {
+        pending_count: batch.get_pending_count(),
+        batch_id: batch.id,
+        merchant_id: batch.merchant_id,
+        profile_id: batch.profile_id,
+        status: batch.status,
+        total_count: batch.total_count,
+        processed_count: batch.processed_count,
+        failed_count: batch.failed_count,
+        cancelled_count: batch.cancelled_count,
+        metadata: batch.metadata,
+        created_at: batch.created_at,
+        completed_at: batch.completed_at,
+    }
+}
+
+/// Task processing the items of a batch, which is inserted along with the batch
+fn get_payout_batch_workflow_task(
+    batch_id: &str,
+    merchant_id: &id_type::MerchantId,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<storage::ProcessTrackerNew, errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let task = "PAYOUT_BATCH_PROCESSING";
+    let tag = ["PAYOUTS", "BATCH"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(runner, task, batch_id, merchant_id);
+    let tracking_data = storage::PayoutBatchTrackingData {
+        batch_id: batch_id.to_owned(),
+        merchant_id: merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+    Ok(process_tracker_entry)
+}
+
+/// Outcome of processing a single item of a batch
+pub enum PayoutBatchItemOutcome {
+    Processed,
+    RetryScheduled,
+    Failed,
+}
+
+/// Creates the payout for a single item of the batch. Transient (5xx) failures are retried up to
+/// `MAX_PAYOUT_BATCH_ITEM_RETRIES` times, any other failure marks the item as failed.
+#[instrument(skip_all, fields(payout_batch_item_id = %item.id))]
+pub async fn process_payout_batch_item(
+    state: &SessionState,
+    platform: &domain::Platform,
+    item: storage::PayoutBatchItem,
+) -> RouterResult<PayoutBatchItemOutcome> {
+    let db = &*state.store;
+    let merchant_account = platform.get_processor().get_account();
+
+    // A previous run may have created the payout before failing to record the outcome
+    if let Some(payout) = db
+        .find_optional_payout_by_merchant_id_payout_id(
+            merchant_account.get_id(),
+            &item.payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+    {
+        db.update_payout_batch_item(
+            item,
+            storage::PayoutBatchItemUpdate::Processed {
+                payout_status: payout.status,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        return Ok(PayoutBatchItemOutcome::Processed);
+    }
+
+    let payout_request = item
+        .payout_request
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Payout request of pending payout batch item was cleared")?;
+    let key_store = platform.get_processor().get_key_store();
+    let request: payouts::PayoutCreateRequest =
+        domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+            &state.into(),
+            type_name!(storage::PayoutBatchItem),
+            domain::types::CryptoOperation::Decrypt(payout_request),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key_store.key.get_inner().peek(),
+        )
+        .await
+        .and_then(|val| val.try_into_operation())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt payout request of payout batch item")?
+        .into_inner()
+        .expose()
+        .parse_value("PayoutCreateRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let result = Box::pin(payouts_create_core(
+        state.clone(),
+        platform.clone(),
+        request,
+    ))
+    .await
+    .and_then(|response| {
+        response
+            .get_json_body()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+    });
+
+    let (update, outcome) = match result {
+        Ok(response) => (
+            storage::PayoutBatchItemUpdate::Processed {
+                payout_status: response.status,
+            },
+            PayoutBatchItemOutcome::Processed,
+        ),
+        Err(error) => {
+            let error = error.current_context();
+            logger::error!(?error, "Failed to create payout for payout batch item");
+            let retry_count = item.retry_count + 1;
+            let error_code = Some(error.error_code());
+            let error_message = Some(error.error_message());
+            if should_retry_payout_batch_item(error.status_code().is_server_error(), retry_count) {
+                (
+                    storage::PayoutBatchItemUpdate::RetryScheduled {
+                        retry_count,
+                        error_code,
+                        error_message,
+                    },
+                    PayoutBatchItemOutcome::RetryScheduled,
+                )
+            } else {
+                (
+                    storage::PayoutBatchItemUpdate::Failed {
+                        retry_count,
+                        error_code,
+                        error_message,
+                    },
+                    PayoutBatchItemOutcome::Failed,
+                )
+            }
+        }
+    };
+
+    db.update_payout_batch_item(item, update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch item")?;
+
+    Ok(outcome)
+}
+
+/// Only transient (5xx) failures are retried, up to `MAX_PAYOUT_BATCH_ITEM_RETRIES` times
+fn should_retry_payout_batch_item(is_server_error: bool, retry_count: i32) -> bool {
+    is_server_error && retry_count <= consts::MAX_PAYOUT_BATCH_ITEM_RETRIES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_merchant_id() -> id_type::MerchantId {
+        id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap()
+    }
+
+    fn get_payout_id(payout_id: &'static str) -> id_type::PayoutId {
+        id_type::PayoutId::try_from(std::borrow::Cow::from(payout_id)).unwrap()
+    }
+
+    fn get_valid_item() -> payouts::PayoutCreateRequest {
+        payouts::PayoutCreateRequest {
+            amount: Some(api_models::payments::Amount::from(
+                common_utils::types::MinorUnit::new(100),
+            )),
+            currency: Some(api_enums::Currency::USD),
+            ..Default::default()
+        }
+    }
+
+    fn assert_invalid_item(items: &[payouts::PayoutCreateRequest], expected_message: &str) {
+        let error = validate_payout_batch_items(&get_merchant_id(), items).unwrap_err();
+        match error.current_context() {
+            errors::ApiErrorResponse::InvalidRequestData { message } => {
+                assert!(message.contains(expected_message), "{message}")
+            }
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_valid_batch_items() {
+        let items = vec![
+            get_valid_item(),
+            payouts::PayoutCreateRequest {
+                payout_id: Some(get_payout_id("payout_1")),
+                merchant_id: Some(get_merchant_id()),
+                confirm: Some(true),
+                ..get_valid_item()
+            },
+        ];
+        assert!(validate_payout_batch_items(&get_merchant_id(), &items).is_ok());
+    }
+
+    #[test]
+    fn test_batch_size_limits() {
+        assert_invalid_item(&[], "at least one item");
+        let items = vec![get_valid_item(); consts::MAX_PAYOUT_BATCH_SIZE + 1];
+        assert_invalid_item(&items, "cannot contain more than");
+    }
+
+    #[test]
+    fn test_invalid_batch_items() {
+        let invalid_items = [
+            (
+                payouts::PayoutCreateRequest {
+                    amount: None,
+                    ..get_valid_item()
+                },
+                "amount is required",
+            ),
+            (
+                payouts::PayoutCreateRequest {
+                    currency: None,
+                    ..get_valid_item()
+                },
+                "currency is required",
+            ),
+            (
+                payouts::PayoutCreateRequest {
+                    payout_link: Some(true),
+                    ..get_valid_item()
+                },
+                "payout links are not supported",
+            ),
+            (
+                payouts::PayoutCreateRequest {
+                    confirm: Some(false),
+                    ..get_valid_item()
+                },
+                "always confirmed",
+            ),
+            (
+                payouts::PayoutCreateRequest {
+                    merchant_id: Some(
+                        id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2"))
+                            .unwrap(),
+                    ),
+                    ..get_valid_item()
+                },
+                "merchant_id does not match",
+            ),
+        ];
+        for (item, expected_message) in invalid_items {
+            assert_invalid_item(&[get_valid_item(), item], expected_message);
+        }
+    }
+
+    #[test]
+    fn test_error_reports_line_number_of_item() {
+        let items = vec![
+            get_valid_item(),
+            get_valid_item(),
+            payouts::PayoutCreateRequest {
+                amount: None,
+                ..get_valid_item()
+            },
+        ];
+        assert_invalid_item(&items, "Item 3: amount is required");
+    }
+
+    #[test]
+    fn test_repeated_payout_id_within_batch() {
+        let item = payouts::PayoutCreateRequest {
+            payout_id: Some(get_payout_id("payout_1")),
+            ..get_valid_item()
+        };
+        assert_invalid_item(
+            &[item.clone(), get_valid_item(), item],
+            "Item 3: payout_id payout_1 is repeated within the batch",
+        );
+    }
+
+    #[test]
+    fn test_first_existing_payout_id_follows_item_order() {
+        let payout_ids = [
+            get_payout_id("payout_1"),
+            get_payout_id("payout_2"),
+            get_payout_id("payout_3"),
+        ];
+        assert_eq!(get_first_existing_payout_id(&payout_ids, &[]), None);
+        assert_eq!(
+            get_first_existing_payout_id(
+                &payout_ids,
+                &[get_payout_id("payout_3"), get_payout_id("payout_2")]
+            ),
+            Some(get_payout_id("payout_2"))
+        );
+    }
+
+    #[test]
+    fn test_batch_status_from_item_counts() {
+        let cases = [
+            ((10, 0, 0, 0), storage_enums::PayoutBatchStatus::Processing),
+            ((10, 4, 3, 2), storage_enums::PayoutBatchStatus::Processing),
+            ((10, 10, 0, 0), storage_enums::PayoutBatchStatus::Completed),
+            (
+                (10, 4, 3, 3),
+                storage_enums::PayoutBatchStatus::PartiallyCompleted,
+            ),
+            ((10, 0, 0, 10), storage_enums::PayoutBatchStatus::Cancelled),
+            ((10, 0, 7, 3), storage_enums::PayoutBatchStatus::Failed),
+            ((10, 0, 10, 0), storage_enums::PayoutBatchStatus::Failed),
+        ];
+        for ((total, processed, failed, cancelled), expected_status) in cases {
+            assert_eq!(
+                get_payout_batch_status(total, processed, failed, cancelled),
+                expected_status,
+                "total {total}, processed {processed}, failed {failed}, cancelled {cancelled}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_only_server_errors_are_retried_within_limit() {
+        assert!(should_retry_payout_batch_item(true, 1));
+        assert!(should_retry_payout_batch_item(
+            true,
+            consts::MAX_PAYOUT_BATCH_ITEM_RETRIES
+        ));
+        assert!(!should_retry_payout_batch_item(
+            true,
+            consts::MAX_PAYOUT_BATCH_ITEM_RETRIES + 1
+        ));
+        assert!(!should_retry_payout_batch_item(false, 1));
+    }
+}
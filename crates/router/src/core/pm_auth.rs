@@ -19,20 +19,17 @@ use error_stack::ResultExt;
 use helpers::PaymentAuthConnectorDataExt;
 use hyperswitch_domain_models::payments::PaymentIntent;
 use masking::{ExposeInterface, PeekInterface, Secret};
-use pm_auth::{
-    connector::plaid::transformers::PlaidAuthType,
-    types::{
-        self as pm_auth_types,
-        api::{
-            auth_service::{BankAccountCredentials, ExchangeToken, LinkToken},
-            BoxedConnectorIntegration, PaymentAuthConnectorData,
-        },
+use pm_auth::types::{
+    self as pm_auth_types,
+    api::{
+        auth_service::{BankAccountCredentials, ExchangeToken, LinkToken},
+        BoxedConnectorIntegration, PaymentAuthConnectorData,
     },
 };
 
 use crate::{
     core::{
-        errors::{self, ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
+        errors::{ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::cards,
         payments::helpers as oss_helpers,
         pm_auth::helpers as pm_auth_helpers,
@@ -41,7 +38,7 @@ use crate::{
     logger,
     routes::SessionState,
     services::{pm_auth as pm_auth_services, ApplicationResponse},
-    types::{self, domain, storage, transformers::ForeignTryFrom},
+    types::{domain, storage},
 };
 
 #[cfg(feature = "v1")]
@@ -160,11 +157,7 @@ pub async fn create_link_token(
         connector: Some(connector_name.to_string()),
         request: pm_auth_types::LinkTokenRequest {
             client_name: "HyperSwitch".to_string(),
-            country_codes: Some(vec![billing_country.ok_or(
-                ApiErrorResponse::MissingRequiredField {
-                    field_name: "billing_country",
-                },
-            )?]),
+            country_codes: billing_country.map(|country| vec![country]),
             language: payload.language,
             user_info: payment_intent.and_then(|pi| pi.customer_id),
             client_platform: headers
@@ -221,26 +214,11 @@ pub async fn create_link_token(
     todo!()
 }
 
-impl ForeignTryFrom<&types::ConnectorAuthType> for PlaidAuthType {
-    type Error = errors::ConnectorError;
-
-    fn foreign_try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
-        match auth_type {
-            types::ConnectorAuthType::BodyKey { api_key, key1 } => {
-                Ok::<Self, errors::ConnectorError>(Self {
-                    client_id: api_key.to_owned(),
-                    secret: key1.to_owned(),
-                })
-            }
-            _ => Err(errors::ConnectorError::FailedToObtainAuthType),
-        }
-    }
-}
-
 pub async fn exchange_token_core(
     state: SessionState,
     platform: domain::Platform,
     payload: api_models::pm_auth::ExchangeTokenCreateRequest,
+    headers: Option<hyperswitch_domain_models::payments::HeaderPayload>,
 ) -> RouterResponse<()> {
     let db = &*state.store;
 
@@ -278,10 +256,13 @@ pub async fn exchange_token_core(
 
     let auth_type = helpers::get_connector_auth_type(merchant_connector_account.clone())?;
 
+    let redirect_uri = headers.and_then(|header| header.x_redirect_uri);
+
     let access_token = get_access_token_from_exchange_api(
         &connector,
         connector_name,
         &payload,
+        redirect_uri,
         &auth_type,
         &state,
     )
@@ -653,6 +634,7 @@ async fn get_access_token_from_exchange_api(
     connector: &PaymentAuthConnectorData,
     connector_name: &str,
     payload: &api_models::pm_auth::ExchangeTokenCreateRequest,
+    redirect_uri: Option<String>,
     auth_type: &pm_auth_types::ConnectorAuthType,
     state: &SessionState,
 ) -> RouterResult<Secret<String>> {
@@ -669,6 +651,7 @@ async fn get_access_token_from_exchange_api(
         connector: Some(connector_name.to_string()),
         request: pm_auth_types::ExchangeTokenRequest {
             public_token: payload.public_token.clone(),
+            redirect_uri,
         },
         response: Ok(pm_auth_types::ExchangeTokenResponse {
             access_token: "".to_string(),
@@ -0,0 +1,479 @@
+use api_models::process_tracker::tasks as tasks_api;
+use common_utils::{date_time, fp_utils::when};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::app::ReqState,
+    services,
+    types::storage::{self, business_status, enums as storage_enums},
+    SessionState,
+};
+
+/// Number of tasks returned when listing tasks, if not specified
+const DEFAULT_TASK_LIST_LIMIT: u32 = 100;
+/// Maximum number of tasks which can be listed or updated in a single request
+const MAX_TASK_LIMIT: u32 = 1000;
+
+#[instrument(skip_all)]
+pub async fn list_tasks(
+    state: SessionState,
+    request: tasks_api::ProcessTrackerTaskListRequest,
+) -> RouterResponse<tasks_api::ProcessTrackerTaskListResponse> {
+    let limit = validate_limit(request.limit)?;
+    let tasks = state
+        .store
+        .find_processes_by_constraints(
+            &get_filter_constraints(request.filter),
+            Some(limit.into()),
+            request.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker tasks")?;
+
+    Ok(services::ApplicationResponse::Json(
+        tasks_api::ProcessTrackerTaskListResponse {
+            count: tasks.len(),
+            data: tasks.into_iter().map(task_response).collect(),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_task(
+    state: SessionState,
+    request: tasks_api::ProcessTrackerTaskId,
+) -> RouterResponse<tasks_api::ProcessTrackerTaskResponse> {
+    let task = find_task(&state, &request.task_id).await?;
+
+    Ok(services::ApplicationResponse::Json(task_response(task)))
+}
+
+#[instrument(skip_all)]
+pub async fn perform_task_action(
+    state: SessionState,
+    req_state: ReqState,
+    request: tasks_api::ProcessTrackerTaskActionRequest,
+) -> RouterResponse<tasks_api::ProcessTrackerTaskResponse> {
+    validate_action(&request.action)?;
+    let task = find_task(&state, &request.task_id).await?;
+
+    let applicable_statuses = get_applicable_statuses(&request.action);
+    when(!applicable_statuses.contains(&task.status), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The action cannot be performed on a task with status `{}`",
+                task.status
+            ),
+        })
+    })?;
+
+    let updated_task = state
+        .store
+        .update_processes_by_ids_and_statuses(
+            vec![task.id.clone()],
+            vec![task.status],
+            get_task_update(&request.action, task.status),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update process tracker task")?
+        .pop()
+        // The task could have been picked up by the scheduler in the meantime
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The status of the task changed while performing the action".to_string(),
+        })?;
+
+    emit_audit_event(&req_state, &task, &request.action, false);
+
+    Ok(services::ApplicationResponse::Json(task_response(
+        updated_task,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn perform_bulk_task_action(
+    state: SessionState,
+    req_state: ReqState,
+    request: tasks_api::ProcessTrackerBulkActionRequest,
+) -> RouterResponse<tasks_api::ProcessTrackerBulkActionResponse> {
+    validate_action(&request.action)?;
+    let limit = validate_limit(request.limit)?;
+    let tasks = state
+        .store
+        .find_processes_by_constraints(
+            &get_filter_constraints(request.filter),
+            Some(limit.into()),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker tasks")?;
+    let matched_count = tasks.len();
+
+    let mut updated_task_ids = Vec::new();
+    // The update to be performed depends on the current status of the task, so the tasks are
+    // updated in batches of the same status
+    for status in get_applicable_statuses(&request.action) {
+        let tasks_with_status: Vec<_> =
+            tasks.iter().filter(|task| task.status == *status).collect();
+        if tasks_with_status.is_empty() {
+            continue;
+        }
+
+        let updated_tasks = state
+            .store
+            .update_processes_by_ids_and_statuses(
+                tasks_with_status
+                    .iter()
+                    .map(|task| task.id.clone())
+                    .collect(),
+                vec![*status],
+                get_task_update(&request.action, *status),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update process tracker tasks")?;
+
+        for task in tasks_with_status {
+            if updated_tasks
+                .iter()
+                .any(|updated_task| updated_task.id == task.id)
+            {
+                emit_audit_event(&req_state, task, &request.action, true);
+                updated_task_ids.push(task.id.clone());
+            }
+        }
+    }
+
+    let skipped_task_ids = tasks
+        .into_iter()
+        .filter(|task| !updated_task_ids.contains(&task.id))
+        .map(|task| task.id)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        tasks_api::ProcessTrackerBulkActionResponse {
+            matched_count,
+            updated_task_ids,
+            skipped_task_ids,
+        },
+    ))
+}
+
+async fn find_task(state: &SessionState, task_id: &str) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process tracker task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Process tracker task with id {task_id} not found"),
+            })
+        })
+}
+
+fn validate_limit(limit: Option<u32>) -> RouterResult<u32> {
+    let limit = limit.unwrap_or(DEFAULT_TASK_LIST_LIMIT);
+    when(limit == 0 || limit > MAX_TASK_LIMIT, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit must be between 1 and {MAX_TASK_LIMIT}"),
+        })
+    })?;
+
+    Ok(limit)
+}
+
+/// Tasks cannot be rescheduled to a time in the past, as the producer only fetches the tasks
+/// scheduled within a window around the current time, and would never pick them up.
+fn validate_action(action: &tasks_api::ProcessTrackerTaskAction) -> RouterResult<()> {
+    match action {
+        tasks_api::ProcessTrackerTaskAction::Reschedule { schedule_time } => {
+            when(*schedule_time < date_time::now(), || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "schedule_time must not be in the past".to_string(),
+                }))
+            })
+        }
+        tasks_api::ProcessTrackerTaskAction::RetryNow
+        | tasks_api::ProcessTrackerTaskAction::Pause
+        | tasks_api::ProcessTrackerTaskAction::Cancel => Ok(()),
+    }
+}
+
+fn get_filter_constraints(
+    filter: tasks_api::ProcessTrackerTaskFilter,
+) -> storage::ProcessTrackerFilterConstraints {
+    storage::ProcessTrackerFilterConstraints {
+        runner: filter.runner,
+        statuses: filter.status,
+        merchant_id: filter.merchant_id,
+        schedule_time_range: filter.time_range,
+    }
+}
+
+/// Returns the statuses of the tasks on which the action can be performed. Tasks which have been
+/// picked up by the scheduler for execution cannot be modified.
+fn get_applicable_statuses(
+    action: &tasks_api::ProcessTrackerTaskAction,
+) -> &'static [storage_enums::ProcessTrackerStatus] {
+    use storage_enums::ProcessTrackerStatus;
+
+    match action {
+        tasks_api::ProcessTrackerTaskAction::Reschedule { .. }
+        | tasks_api::ProcessTrackerTaskAction::RetryNow => &[
+            ProcessTrackerStatus::New,
+            ProcessTrackerStatus::Pending,
+            ProcessTrackerStatus::Review,
+            ProcessTrackerStatus::Finish,
+        ],
+        tasks_api::ProcessTrackerTaskAction::Pause => {
+            &[ProcessTrackerStatus::New, ProcessTrackerStatus::Pending]
+        }
+        tasks_api::ProcessTrackerTaskAction::Cancel => &[
+            ProcessTrackerStatus::New,
+            ProcessTrackerStatus::Pending,
+            ProcessTrackerStatus::Review,
+        ],
+    }
+}
+
+fn get_task_update(
+    action: &tasks_api::ProcessTrackerTaskAction,
+    current_status: storage_enums::ProcessTrackerStatus,
+) -> storage::ProcessTrackerUpdate {
+    use storage_enums::ProcessTrackerStatus;
+
+    let schedule_time = match action {
+        tasks_api::ProcessTrackerTaskAction::Reschedule { schedule_time } => *schedule_time,
+        tasks_api::ProcessTrackerTaskAction::RetryNow => date_time::now(),
+        tasks_api::ProcessTrackerTaskAction::Pause => {
+            return storage::ProcessTrackerUpdate::StatusUpdate {
+                status: ProcessTrackerStatus::Review,
+                business_status: None,
+            }
+        }
+        tasks_api::ProcessTrackerTaskAction::Cancel => {
+            return storage::ProcessTrackerUpdate::StatusUpdate {
+                status: ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status::CANCELLED)),
+            }
+        }
+    };
+
+    match current_status {
+        // Finished tasks are executed again from scratch
+        ProcessTrackerStatus::Finish => storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: Some(String::from(business_status::PENDING)),
+            status: Some(ProcessTrackerStatus::New),
+            updated_at: Some(date_time::now()),
+        },
+        ProcessTrackerStatus::New => storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(ProcessTrackerStatus::New),
+            updated_at: Some(date_time::now()),
+        },
+        ProcessTrackerStatus::Pending
+        | ProcessTrackerStatus::Review
+        | ProcessTrackerStatus::Processing
+        | ProcessTrackerStatus::ProcessStarted => storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(ProcessTrackerStatus::Pending),
+            updated_at: Some(date_time::now()),
+        },
+    }
+}
+
+fn emit_audit_event(
+    req_state: &ReqState,
+    task: &storage::ProcessTracker,
+    action: &tasks_api::ProcessTrackerTaskAction,
+    is_bulk_action: bool,
+) {
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ProcessTrackerTaskUpdate {
+            task_id: task.id.clone(),
+            runner: task.runner.clone(),
+            previous_status: task.status,
+            action: action.clone(),
+            is_bulk_action,
+        }))
+        .emit();
+}
+
+fn task_response(task: storage::ProcessTracker) -> tasks_api::ProcessTrackerTaskResponse {
+    tasks_api::ProcessTrackerTaskResponse {
+        id: task.id,
+        name: task.name,
+        tag: task.tag,
+        runner: task.runner,
+        retry_count: task.retry_count,
+        schedule_time: task.schedule_time,
+        rule: task.rule,
+        tracking_data: task.tracking_data,
+        business_status: task.business_status,
+        status: task.status,
+        event: task.event,
+        created_at: task.created_at,
+        updated_at: task.updated_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::types::keymanager::KeyManagerState;
+    use scheduler::db::process_tracker::ProcessTrackerInterface;
+    use storage_impl::mock_db::MockDb;
+
+    use super::*;
+
+    fn reschedule_action(offset: time::Duration) -> tasks_api::ProcessTrackerTaskAction {
+        tasks_api::ProcessTrackerTaskAction::Reschedule {
+            schedule_time: date_time::now().saturating_add(offset),
+        }
+    }
+
+    #[test]
+    fn test_reschedule_to_past_is_rejected() {
+        assert!(validate_action(&reschedule_action(time::Duration::minutes(-1))).is_err());
+        assert!(validate_action(&reschedule_action(time::Duration::minutes(1))).is_ok());
+        assert!(validate_action(&tasks_api::ProcessTrackerTaskAction::RetryNow).is_ok());
+    }
+
+    #[test]
+    fn test_finished_tasks_are_executed_from_scratch() {
+        let schedule_time = date_time::now().saturating_add(time::Duration::minutes(5));
+        let task_update = get_task_update(
+            &tasks_api::ProcessTrackerTaskAction::Reschedule { schedule_time },
+            storage_enums::ProcessTrackerStatus::Finish,
+        );
+
+        assert!(matches!(
+            task_update,
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: Some(0),
+                schedule_time: Some(updated_schedule_time),
+                business_status: Some(ref business_status),
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                ..
+            } if updated_schedule_time == schedule_time
+                && business_status == business_status::PENDING
+        ));
+    }
+
+    #[test]
+    fn test_paused_tasks_are_resumed_as_pending() {
+        // The retry count of unfinished tasks is retained
+        assert!(matches!(
+            get_task_update(
+                &tasks_api::ProcessTrackerTaskAction::RetryNow,
+                storage_enums::ProcessTrackerStatus::Review,
+            ),
+            storage::ProcessTrackerUpdate::Update {
+                retry_count: None,
+                status: Some(storage_enums::ProcessTrackerStatus::Pending),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_cancelled_tasks_are_finished() {
+        assert!(matches!(
+            get_task_update(
+                &tasks_api::ProcessTrackerTaskAction::Cancel,
+                storage_enums::ProcessTrackerStatus::Pending,
+            ),
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(ref business_status),
+            } if business_status == business_status::CANCELLED
+        ));
+        assert!(
+            !get_applicable_statuses(&tasks_api::ProcessTrackerTaskAction::Cancel)
+                .contains(&storage_enums::ProcessTrackerStatus::Finish)
+        );
+        assert!(
+            !get_applicable_statuses(&tasks_api::ProcessTrackerTaskAction::Pause)
+                .contains(&storage_enums::ProcessTrackerStatus::ProcessStarted)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_only_tasks_with_expected_statuses_are_updated() {
+        let db = MockDb::new(
+            &redis_interface::RedisSettings::default(),
+            KeyManagerState::new(),
+        )
+        .await
+        .unwrap();
+
+        for task_id in ["task_1", "task_2"] {
+            db.insert_process(
+                storage::ProcessTrackerNew::new(
+                    task_id,
+                    "PAYMENTS_SYNC_WORKFLOW",
+                    common_enums::ProcessTrackerRunner::PaymentsSyncWorkflow,
+                    ["PAYMENT"],
+                    serde_json::json!({}),
+                    None,
+                    date_time::now(),
+                    common_types::consts::API_VERSION,
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        }
+        db.update_processes_by_ids_and_statuses(
+            vec!["task_2".to_string()],
+            vec![storage_enums::ProcessTrackerStatus::New],
+            get_task_update(
+                &tasks_api::ProcessTrackerTaskAction::Pause,
+                storage_enums::ProcessTrackerStatus::New,
+            ),
+        )
+        .await
+        .unwrap();
+
+        // Neither the paused task, nor the task which was not selected, are cancelled
+        let updated_tasks = db
+            .update_processes_by_ids_and_statuses(
+                vec!["task_1".to_string(), "task_2".to_string()],
+                vec![storage_enums::ProcessTrackerStatus::New],
+                get_task_update(
+                    &tasks_api::ProcessTrackerTaskAction::Cancel,
+                    storage_enums::ProcessTrackerStatus::New,
+                ),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(updated_tasks.len(), 1);
+        let task_1 = db.find_process_by_id("task_1").await.unwrap().unwrap();
+        assert_eq!(task_1.status, storage_enums::ProcessTrackerStatus::Finish);
+        assert_eq!(task_1.business_status, business_status::CANCELLED);
+        let task_2 = db.find_process_by_id("task_2").await.unwrap().unwrap();
+        assert_eq!(task_2.status, storage_enums::ProcessTrackerStatus::Review);
+    }
+}
@@ -21,6 +21,9 @@ use crate::{
     SessionState,
 };
 
+#[cfg(feature = "v1")]
+pub mod settlement;
+
 #[allow(unused_variables)]
 pub async fn send_recon_request(
     state: SessionState,
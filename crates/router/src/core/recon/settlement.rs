@@ -0,0 +1,527 @@
+pub mod parsers;
+
+use api_models::recon as recon_api;
+use common_utils::{ext_traits::OptionExt, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use futures::stream::{self, StreamExt};
+use router_env::{instrument, logger, tracing};
+
+use self::parsers::SettlementLine;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+    },
+    services::ApplicationResponse,
+    types::{
+        api::{self, enums as api_enums},
+        domain, storage,
+    },
+    SessionState,
+};
+
+/// Transaction on record that a settlement line was matched with
+struct MatchedTransaction {
+    payment_id: Option<common_utils::id_type::PaymentId>,
+    attempt_id: Option<String>,
+    refund_id: Option<String>,
+    amount: MinorUnit,
+    currency: Option<api_enums::Currency>,
+}
+
+#[instrument(skip_all)]
+pub async fn ingest_settlement_report(
+    state: SessionState,
+    platform: domain::Platform,
+    request: recon_api::SettlementReportIngestRequest,
+) -> RouterResponse<recon_api::SettlementReportResponse> {
+    let merchant_id = platform.get_processor().get_account().get_id().clone();
+    let report_data = get_settlement_report_data(&state, &platform, &request).await?;
+
+    let lines = parsers::get_settlement_report_parser(request.connector).parse(&report_data)?;
+    if lines.len() > consts::MAX_SETTLEMENT_REPORT_LINES {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Settlement report cannot have more than {} settlement lines",
+                consts::MAX_SETTLEMENT_REPORT_LINES
+            ),
+        }));
+    }
+    logger::info!("Reconciling {} settlement lines", lines.len());
+
+    let report_id = common_utils::generate_id(consts::ID_LENGTH, "setr");
+    let created_at = common_utils::date_time::now();
+    let recon_results = stream::iter(lines)
+        .map(|line| reconcile_settlement_line(&state, &platform, request.connector, line))
+        .buffered(consts::SETTLEMENT_RECON_CONCURRENCY)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .map(|result| {
+            result.map(|(line, status, matched_transaction)| {
+                build_recon_result(
+                    &report_id,
+                    &merchant_id,
+                    line,
+                    status,
+                    matched_transaction,
+                    created_at,
+                )
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    let mismatched_count = recon_results
+        .iter()
+        .filter(|result| result.status.is_mismatch())
+        .count();
+    let total_count = recon_results.len();
+    let settlement_report = storage::SettlementReportNew {
+        id: report_id,
+        merchant_id,
+        connector: request.connector.to_string(),
+        file_id: request.file_id,
+        file_key: request.file_key,
+        total_count: get_count(total_count)?,
+        matched_count: get_count(total_count.saturating_sub(mismatched_count))?,
+        mismatched_count: get_count(mismatched_count)?,
+        created_at,
+    };
+
+    let settlement_report = state
+        .store
+        .insert_settlement_report(settlement_report, recon_results)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert settlement report")?;
+
+    Ok(ApplicationResponse::Json(get_settlement_report_response(
+        settlement_report,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_settlement_report(
+    state: SessionState,
+    platform: domain::Platform,
+    report_id: String,
+) -> RouterResponse<recon_api::SettlementReportResponse> {
+    let settlement_report = state
+        .store
+        .find_settlement_report_by_merchant_id_report_id(
+            platform.get_processor().get_account().get_id(),
+            &report_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Settlement report does not exist in our records".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_settlement_report_response(
+        settlement_report,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_settlement_reports(
+    state: SessionState,
+    platform: domain::Platform,
+    query: recon_api::SettlementReportListQuery,
+) -> RouterResponse<recon_api::SettlementReportListResponse> {
+    let settlement_reports = state
+        .store
+        .list_settlement_reports_by_merchant_id(
+            platform.get_processor().get_account().get_id(),
+            get_list_limit(query.limit),
+            query.offset.unwrap_or_default().max(0),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list settlement reports")?;
+
+    let data = settlement_reports
+        .into_iter()
+        .map(get_settlement_report_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        recon_api::SettlementReportListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_recon_results(
+    state: SessionState,
+    platform: domain::Platform,
+    query: recon_api::ReconResultListQuery,
+) -> RouterResponse<recon_api::ReconResultListResponse> {
+    let recon_results = state
+        .store
+        .list_recon_results_by_merchant_id_constraints(
+            platform.get_processor().get_account().get_id(),
+            query.report_id.as_deref(),
+            query.status,
+            Some(get_list_limit(query.limit)),
+            Some(query.offset.unwrap_or_default().max(0)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list recon results")?;
+
+    let data = recon_results
+        .into_iter()
+        .map(get_recon_result_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        recon_api::ReconResultListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Exports the recon results matching the query as a CSV file, so that mismatches can be worked
+/// on outside of the dashboard
+#[instrument(skip_all)]
+pub async fn export_recon_results(
+    state: SessionState,
+    platform: domain::Platform,
+    query: recon_api::ReconResultListQuery,
+) -> RouterResponse<()> {
+    let recon_results = state
+        .store
+        .list_recon_results_by_merchant_id_constraints(
+            platform.get_processor().get_account().get_id(),
+            query.report_id.as_deref(),
+            query.status,
+            Some(consts::MAX_RECON_RESULTS_EXPORT_COUNT),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list recon results for export")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for recon_result in recon_results {
+        csv_writer
+            .serialize(get_recon_result_response(recon_result))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize recon result")?;
+    }
+    let file_data = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write recon results export")?;
+
+    Ok(ApplicationResponse::FileData((file_data, mime::TEXT_CSV)))
+}
+
+/// Reads the settlement report either from the files API or from file storage. Files dropped into
+/// file storage are only accessible under the prefix of the merchant.
+async fn get_settlement_report_data(
+    state: &SessionState,
+    platform: &domain::Platform,
+    request: &recon_api::SettlementReportIngestRequest,
+) -> RouterResult<Vec<u8>> {
+    match (&request.file_id, &request.file_key) {
+        (Some(file_id), None) => file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+            state,
+            Some(file_id.clone()),
+            None,
+            platform,
+            api::FileDataRequired::Required,
+        )
+        .await?
+        .file_data
+        .get_required_value("file_data")
+        .change_context(errors::ApiErrorResponse::FileNotAvailable),
+        (None, Some(file_key)) => {
+            let merchant_prefix = format!(
+                "{}/{}/",
+                consts::SETTLEMENT_REPORT_FILE_KEY_PREFIX,
+                platform
+                    .get_processor()
+                    .get_account()
+                    .get_id()
+                    .get_string_repr()
+            );
+            if !file_key.starts_with(&merchant_prefix) || file_key.contains("..") {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("file_key must be a file under `{merchant_prefix}`"),
+                }));
+            }
+            state
+                .file_storage_client
+                .retrieve_file(file_key)
+                .await
+                .change_context(errors::ApiErrorResponse::FileNotFound)
+                .attach_printable("Failed to retrieve settlement report from file storage")
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of file_id and file_key must be provided".to_string(),
+        })),
+    }
+}
+
+/// Whether a payment attempt can be settled by the given connector: it must have been processed
+/// by that connector and have had funds captured
+fn is_settleable_attempt(
+    status: api_enums::AttemptStatus,
+    attempt_connector: Option<&str>,
+    settling_connector: api_enums::Connector,
+) -> bool {
+    attempt_connector == Some(settling_connector.to_string().as_str())
+        && matches!(
+            status,
+            api_enums::AttemptStatus::Charged
+                | api_enums::AttemptStatus::PartialCharged
+                | api_enums::AttemptStatus::PartialChargedAndChargeable
+        )
+}
+
+/// Matches a settlement line with the payment attempt or refund it settles, and compares the
+/// settled amount and currency with those on record. Payments are compared against the amount
+/// captured on them.
+async fn reconcile_settlement_line(
+    state: &SessionState,
+    platform: &domain::Platform,
+    connector: api_enums::Connector,
+    line: SettlementLine,
+) -> RouterResult<(
+    SettlementLine,
+    api_enums::ReconResultStatus,
+    Option<MatchedTransaction>,
+)> {
+    let merchant_account = platform.get_processor().get_account();
+    let matched_transaction = match line.transaction_type {
+        api_enums::SettlementTransactionType::Payment => {
+            match state
+                .store
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    merchant_account.get_id(),
+                    &line.connector_transaction_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_attempt)
+                    if is_settleable_attempt(
+                        payment_attempt.status,
+                        payment_attempt.connector.as_deref(),
+                        connector,
+                    ) =>
+                {
+                    let payment_intent = state
+                        .store
+                        .find_payment_intent_by_payment_id_merchant_id(
+                            &payment_attempt.payment_id,
+                            merchant_account.get_id(),
+                            platform.get_processor().get_key_store(),
+                            merchant_account.storage_scheme,
+                        )
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to find payment intent of settled attempt")?;
+
+                    Ok(MatchedTransaction {
+                        amount: payment_intent.amount_captured.unwrap_or(MinorUnit::new(0)),
+                        currency: payment_attempt.currency,
+                        payment_id: Some(payment_attempt.payment_id),
+                        attempt_id: Some(payment_attempt.attempt_id),
+                        refund_id: None,
+                    })
+                }
+                Ok(payment_attempt) => {
+                    logger::info!(
+                        attempt_id = %payment_attempt.attempt_id,
+                        attempt_status = ?payment_attempt.status,
+                        attempt_connector = ?payment_attempt.connector,
+                        "Settlement line matches no charged attempt of the settling connector"
+                    );
+                    return Ok((line, api_enums::ReconResultStatus::Missing, None));
+                }
+                Err(error) => Err(error),
+            }
+        }
+        api_enums::SettlementTransactionType::Refund => state
+            .store
+            .find_refund_by_merchant_id_connector_refund_id_connector(
+                merchant_account.get_id(),
+                &line.connector_transaction_id,
+                &connector.to_string(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map(|refund| MatchedTransaction {
+                amount: refund.refund_amount,
+                currency: Some(refund.currency),
+                payment_id: Some(refund.payment_id),
+                attempt_id: Some(refund.attempt_id),
+                refund_id: Some(refund.refund_id),
+            }),
+    };
+
+    let matched_transaction = match matched_transaction {
+        Ok(matched_transaction) => matched_transaction,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let status = match line.transaction_type {
+                api_enums::SettlementTransactionType::Payment => {
+                    api_enums::ReconResultStatus::Missing
+                }
+                api_enums::SettlementTransactionType::Refund => {
+                    api_enums::ReconResultStatus::UnexpectedRefund
+                }
+            };
+            return Ok((line, status, None));
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find transaction of settlement line"))
+        }
+    };
+
+    let status = if matched_transaction.currency != Some(line.currency) {
+        api_enums::ReconResultStatus::CurrencyMismatch
+    } else if matched_transaction.amount != line.amount {
+        api_enums::ReconResultStatus::AmountMismatch
+    } else {
+        api_enums::ReconResultStatus::Matched
+    };
+
+    Ok((line, status, Some(matched_transaction)))
+}
+
+fn build_recon_result(
+    report_id: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    line: SettlementLine,
+    status: api_enums::ReconResultStatus,
+    matched_transaction: Option<MatchedTransaction>,
+    created_at: time::PrimitiveDateTime,
+) -> storage::ReconResultNew {
+    let (payment_id, attempt_id, refund_id, expected_amount, expected_currency) =
+        match matched_transaction {
+            Some(transaction) => (
+                transaction.payment_id,
+                transaction.attempt_id,
+                transaction.refund_id,
+                Some(transaction.amount),
+                transaction.currency,
+            ),
+            None => (None, None, None, None, None),
+        };
+
+    storage::ReconResultNew {
+        id: common_utils::generate_id(consts::ID_LENGTH, "recon"),
+        report_id: report_id.to_owned(),
+        merchant_id: merchant_id.to_owned(),
+        line_number: line.line_number,
+        connector_transaction_id: line.connector_transaction_id,
+        transaction_type: line.transaction_type,
+        status,
+        payment_id,
+        attempt_id,
+        refund_id,
+        expected_amount,
+        expected_currency,
+        settled_amount: line.amount,
+        settled_currency: line.currency,
+        fee_amount: line.fee,
+        settled_at: line.settled_at,
+        created_at,
+    }
+}
+
+fn get_count(count: usize) -> RouterResult<i32> {
+    i32::try_from(count)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Settlement line count does not fit in i32")
+}
+
+fn get_list_limit(limit: Option<i64>) -> i64 {
+    limit
+        .unwrap_or(consts::DEFAULT_RECON_RESULTS_LIST_LIMIT)
+        .clamp(1, consts::MAX_RECON_RESULTS_LIST_LIMIT)
+}
+
+fn get_settlement_report_response(
+    settlement_report: storage::SettlementReport,
+) -> recon_api::SettlementReportResponse {
+    recon_api::SettlementReportResponse {
+        report_id: settlement_report.id,
+        connector: settlement_report.connector,
+        file_id: settlement_report.file_id,
+        file_key: settlement_report.file_key,
+        total_count: settlement_report.total_count,
+        matched_count: settlement_report.matched_count,
+        mismatched_count: settlement_report.mismatched_count,
+        created_at: settlement_report.created_at,
+    }
+}
+
+fn get_recon_result_response(recon_result: storage::ReconResult) -> recon_api::ReconResultResponse {
+    recon_api::ReconResultResponse {
+        id: recon_result.id,
+        report_id: recon_result.report_id,
+        line_number: recon_result.line_number,
+        connector_transaction_id: recon_result.connector_transaction_id,
+        transaction_type: recon_result.transaction_type,
+        status: recon_result.status,
+        payment_id: recon_result.payment_id,
+        attempt_id: recon_result.attempt_id,
+        refund_id: recon_result.refund_id,
+        expected_amount: recon_result.expected_amount,
+        expected_currency: recon_result.expected_currency,
+        settled_amount: recon_result.settled_amount,
+        settled_currency: recon_result.settled_currency,
+        fee_amount: recon_result.fee_amount,
+        settled_at: recon_result.settled_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_charged_attempts_of_the_settling_connector_are_settleable() {
+        let connector = api_enums::Connector::Adyen;
+
+        assert!(is_settleable_attempt(
+            api_enums::AttemptStatus::Charged,
+            Some("adyen"),
+            connector
+        ));
+        assert!(is_settleable_attempt(
+            api_enums::AttemptStatus::PartialCharged,
+            Some("adyen"),
+            connector
+        ));
+        assert!(!is_settleable_attempt(
+            api_enums::AttemptStatus::Charged,
+            Some("stripe"),
+            connector
+        ));
+        assert!(!is_settleable_attempt(
+            api_enums::AttemptStatus::Charged,
+            None,
+            connector
+        ));
+        assert!(!is_settleable_attempt(
+            api_enums::AttemptStatus::Authorized,
+            Some("adyen"),
+            connector
+        ));
+        assert!(!is_settleable_attempt(
+            api_enums::AttemptStatus::Failure,
+            Some("adyen"),
+            connector
+        ));
+    }
+}
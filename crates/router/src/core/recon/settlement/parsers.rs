@@ -0,0 +1,368 @@
+use std::str::FromStr;
+
+use api_models::enums as api_enums;
+use common_utils::types::{
+    AmountConvertor, MinorUnit, StringMajorUnit, StringMajorUnitForConnector,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::core::errors::{self, RouterResult};
+
+/// Settlement report timestamps of Stripe and Adyen, both reported without an offset
+const SETTLEMENT_REPORT_DATE_TIME_FORMAT: &[time::format_description::FormatItem<'static>] =
+    time::macros::format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+
+/// A transaction settled by the connector, as read from a line of its settlement report
+#[derive(Debug, Clone)]
+pub struct SettlementLine {
+    /// Line of the report, starting at 1 for the first record after the header
+    pub line_number: i32,
+    pub connector_transaction_id: String,
+    pub transaction_type: api_enums::SettlementTransactionType,
+    /// Gross amount settled, always positive irrespective of the direction of the transaction
+    pub amount: MinorUnit,
+    pub currency: api_enums::Currency,
+    pub fee: Option<MinorUnit>,
+    pub settled_at: Option<PrimitiveDateTime>,
+}
+
+/// Reads the settlement lines out of a settlement report of a connector. Lines which do not
+/// settle a payment or a refund, such as payouts to the merchant or standalone fees, are skipped.
+pub trait SettlementReportParser {
+    fn parse(&self, data: &[u8]) -> RouterResult<Vec<SettlementLine>>;
+}
+
+/// Returns the parser for the settlement reports of the connector. Connectors without a dedicated
+/// parser are expected to provide the generic CSV format.
+pub fn get_settlement_report_parser(
+    connector: api_enums::Connector,
+) -> Box<dyn SettlementReportParser + Send + Sync> {
+    match connector {
+        api_enums::Connector::Stripe => Box::new(StripeSettlementReportParser),
+        api_enums::Connector::Adyen => Box::new(AdyenSettlementReportParser),
+        _ => Box::new(GenericSettlementReportParser),
+    }
+}
+
+/// Deserializes every record of the CSV report, failing on the first malformed record so that a
+/// report is never reconciled partially
+fn deserialize_records<T: serde::de::DeserializeOwned>(data: &[u8]) -> RouterResult<Vec<T>> {
+    csv::ReaderBuilder::new()
+        .has_headers(true)
+        .trim(csv::Trim::All)
+        .from_reader(data)
+        .deserialize::<T>()
+        .enumerate()
+        .map(|(index, record)| {
+            record.map_err(|error| {
+                logger::error!(?error, "Failed to parse settlement report record");
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Failed to parse record {} of the settlement report: {error}",
+                        index + 1
+                    ),
+                })
+            })
+        })
+        .collect()
+}
+
+fn get_line_number(index: usize) -> RouterResult<i32> {
+    i32::try_from(index + 1).change_context(errors::ApiErrorResponse::InvalidRequestData {
+        message: "Settlement report has too many records".to_string(),
+    })
+}
+
+fn parse_currency(line_number: i32, currency: &str) -> RouterResult<api_enums::Currency> {
+    api_enums::Currency::from_str(&currency.to_uppercase()).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid currency `{currency}` in record {line_number}"),
+        },
+    )
+}
+
+/// Converts an amount reported in major units to minor units, dropping its sign
+fn parse_major_amount(
+    line_number: i32,
+    amount: StringMajorUnit,
+    currency: api_enums::Currency,
+) -> RouterResult<MinorUnit> {
+    StringMajorUnitForConnector
+        .convert_back(amount, currency)
+        .map(|amount| MinorUnit::new(amount.get_amount_as_i64().saturating_abs()))
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid amount in record {line_number}"),
+        })
+}
+
+fn parse_date_time(date_time: Option<&str>) -> Option<PrimitiveDateTime> {
+    date_time
+        .filter(|date_time| !date_time.is_empty())
+        .and_then(|date_time| {
+            PrimitiveDateTime::parse(date_time, SETTLEMENT_REPORT_DATE_TIME_FORMAT).ok()
+        })
+}
+
+/// Parses the itemized balance change from activity report of Stripe. Payments are matched on the
+/// payment intent id when the report includes the optional `payment_intent_id` column, and on the
+/// source id otherwise.
+pub struct StripeSettlementReportParser;
+
+#[derive(Debug, serde::Deserialize)]
+struct StripeSettlementRecord {
+    reporting_category: String,
+    source_id: String,
+    payment_intent_id: Option<String>,
+    currency: String,
+    gross: StringMajorUnit,
+    fee: Option<StringMajorUnit>,
+    created_utc: Option<String>,
+}
+
+impl SettlementReportParser for StripeSettlementReportParser {
+    fn parse(&self, data: &[u8]) -> RouterResult<Vec<SettlementLine>> {
+        let records = deserialize_records::<StripeSettlementRecord>(data)?;
+        let mut lines = Vec::with_capacity(records.len());
+
+        for (index, record) in records.into_iter().enumerate() {
+            let line_number = get_line_number(index)?;
+            let (transaction_type, connector_transaction_id) =
+                match record.reporting_category.as_str() {
+                    "charge" => (
+                        api_enums::SettlementTransactionType::Payment,
+                        record
+                            .payment_intent_id
+                            .filter(|payment_intent_id| !payment_intent_id.is_empty())
+                            .unwrap_or(record.source_id),
+                    ),
+                    "refund" => (
+                        api_enums::SettlementTransactionType::Refund,
+                        record.source_id,
+                    ),
+                    _ => continue,
+                };
+            let currency = parse_currency(line_number, &record.currency)?;
+
+            lines.push(SettlementLine {
+                line_number,
+                connector_transaction_id,
+                transaction_type,
+                amount: parse_major_amount(line_number, record.gross, currency)?,
+                currency,
+                fee: record
+                    .fee
+                    .map(|fee| parse_major_amount(line_number, fee, currency))
+                    .transpose()?,
+                settled_at: parse_date_time(record.created_utc.as_deref()),
+            });
+        }
+
+        Ok(lines)
+    }
+}
+
+/// Parses the settlement details report of Adyen. Payments are matched on their PSP reference and
+/// refunds on the PSP reference of the refund modification.
+pub struct AdyenSettlementReportParser;
+
+#[derive(Debug, serde::Deserialize)]
+struct AdyenSettlementRecord {
+    #[serde(rename = "Psp Reference")]
+    psp_reference: String,
+    #[serde(rename = "Modification Reference")]
+    modification_reference: Option<String>,
+    #[serde(rename = "Type")]
+    record_type: String,
+    #[serde(rename = "Creation Date")]
+    creation_date: Option<String>,
+    #[serde(rename = "Gross Currency")]
+    gross_currency: String,
+    #[serde(rename = "Gross Debit (GC)")]
+    gross_debit: Option<StringMajorUnit>,
+    #[serde(rename = "Gross Credit (GC)")]
+    gross_credit: Option<StringMajorUnit>,
+    #[serde(rename = "Net Currency")]
+    net_currency: Option<String>,
+    #[serde(rename = "Commission (NC)")]
+    commission: Option<StringMajorUnit>,
+    #[serde(rename = "Markup (NC)")]
+    markup: Option<StringMajorUnit>,
+    #[serde(rename = "Scheme Fees (NC)")]
+    scheme_fees: Option<StringMajorUnit>,
+    #[serde(rename = "Interchange (NC)")]
+    interchange: Option<StringMajorUnit>,
+}
+
+impl SettlementReportParser for AdyenSettlementReportParser {
+    fn parse(&self, data: &[u8]) -> RouterResult<Vec<SettlementLine>> {
+        let records = deserialize_records::<AdyenSettlementRecord>(data)?;
+        let mut lines = Vec::with_capacity(records.len());
+
+        for (index, record) in records.into_iter().enumerate() {
+            let line_number = get_line_number(index)?;
+            let (transaction_type, connector_transaction_id, gross) =
+                match record.record_type.as_str() {
+                    "Settled" => (
+                        api_enums::SettlementTransactionType::Payment,
+                        record.psp_reference,
+                        record.gross_credit,
+                    ),
+                    "Refunded" => (
+                        api_enums::SettlementTransactionType::Refund,
+                        record
+                            .modification_reference
+                            .filter(|reference| !reference.is_empty())
+                            .unwrap_or(record.psp_reference),
+                        record.gross_debit,
+                    ),
+                    _ => continue,
+                };
+            let currency = parse_currency(line_number, &record.gross_currency)?;
+            let gross = gross.ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Missing gross amount in record {line_number}"),
+            })?;
+
+            // Fees are reported in the net currency, which only differs from the gross currency
+            // for payments settled with a currency conversion
+            let fee_currency = record
+                .net_currency
+                .as_deref()
+                .map(|net_currency| parse_currency(line_number, net_currency))
+                .transpose()?
+                .unwrap_or(currency);
+            let fee = [
+                record.commission,
+                record.markup,
+                record.scheme_fees,
+                record.interchange,
+            ]
+            .into_iter()
+            .flatten()
+            .map(|fee| parse_major_amount(line_number, fee, fee_currency))
+            .collect::<RouterResult<Vec<_>>>()?
+            .into_iter()
+            .reduce(|total, fee| total + fee);
+
+            lines.push(SettlementLine {
+                line_number,
+                connector_transaction_id,
+                transaction_type,
+                amount: parse_major_amount(line_number, gross, currency)?,
+                currency,
+                fee,
+                settled_at: parse_date_time(record.creation_date.as_deref()),
+            });
+        }
+
+        Ok(lines)
+    }
+}
+
+/// Parses settlement reports in the connector agnostic format, with amounts in minor units:
+/// `connector_transaction_id,transaction_type,amount,currency,fee,settled_at`
+pub struct GenericSettlementReportParser;
+
+#[derive(Debug, serde::Deserialize)]
+struct GenericSettlementRecord {
+    connector_transaction_id: String,
+    transaction_type: api_enums::SettlementTransactionType,
+    amount: MinorUnit,
+    currency: api_enums::Currency,
+    fee: Option<MinorUnit>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    settled_at: Option<PrimitiveDateTime>,
+}
+
+impl SettlementReportParser for GenericSettlementReportParser {
+    fn parse(&self, data: &[u8]) -> RouterResult<Vec<SettlementLine>> {
+        deserialize_records::<GenericSettlementRecord>(data)?
+            .into_iter()
+            .enumerate()
+            .map(|(index, record)| {
+                Ok(SettlementLine {
+                    line_number: get_line_number(index)?,
+                    connector_transaction_id: record.connector_transaction_id,
+                    transaction_type: record.transaction_type,
+                    amount: MinorUnit::new(record.amount.get_amount_as_i64().saturating_abs()),
+                    currency: record.currency,
+                    fee: record.fee,
+                    settled_at: record.settled_at,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+    use super::*;
+
+    #[test]
+    fn test_stripe_settlement_report_parsing() {
+        let report = concat!(
+            "balance_transaction_id,created_utc,currency,gross,fee,net,reporting_category,",
+            "source_id,payment_intent_id\n",
+            "txn_1,2024-01-15 10:22:33,usd,10.00,0.59,9.41,charge,ch_1,pi_1\n",
+            "txn_2,2024-01-15 11:00:00,usd,-5.50,0.00,-5.50,refund,re_1,pi_1\n",
+            "txn_3,2024-01-16 00:00:00,usd,-4.41,0.00,-4.41,payout,po_1,\n",
+        );
+
+        let lines = StripeSettlementReportParser
+            .parse(report.as_bytes())
+            .unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].connector_transaction_id, "pi_1");
+        assert_eq!(lines[0].amount, MinorUnit::new(1000));
+        assert_eq!(lines[0].fee, Some(MinorUnit::new(59)));
+        assert_eq!(lines[0].currency, api_enums::Currency::USD);
+        assert!(lines[0].settled_at.is_some());
+        assert_eq!(
+            lines[1].transaction_type,
+            api_enums::SettlementTransactionType::Refund
+        );
+        assert_eq!(lines[1].connector_transaction_id, "re_1");
+        assert_eq!(lines[1].amount, MinorUnit::new(550));
+        assert_eq!(lines[1].line_number, 2);
+    }
+
+    #[test]
+    fn test_adyen_settlement_report_parsing() {
+        let report = concat!(
+            "Psp Reference,Modification Reference,Type,Creation Date,Gross Currency,",
+            "Gross Debit (GC),Gross Credit (GC),Net Currency,Commission (NC),Markup (NC),",
+            "Scheme Fees (NC),Interchange (NC)\n",
+            "8815,8815,Settled,2024-01-15 10:22:33,EUR,,20.00,EUR,0.10,0.05,0.02,0.20\n",
+            "8815,9921,Refunded,2024-01-16 10:22:33,EUR,20.00,,EUR,,,,\n",
+            ",,MerchantPayout,2024-01-17 10:22:33,EUR,19.63,,EUR,,,,\n",
+        );
+
+        let lines = AdyenSettlementReportParser
+            .parse(report.as_bytes())
+            .unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].connector_transaction_id, "8815");
+        assert_eq!(lines[0].amount, MinorUnit::new(2000));
+        assert_eq!(lines[0].fee, Some(MinorUnit::new(37)));
+        assert_eq!(lines[1].connector_transaction_id, "9921");
+        assert_eq!(lines[1].fee, None);
+    }
+
+    #[test]
+    fn test_generic_settlement_report_rejects_malformed_records() {
+        let report = concat!(
+            "connector_transaction_id,transaction_type,amount,currency,fee,settled_at\n",
+            "txn_1,payment,1000,USD,30,2024-01-15T10:22:33.000Z\n",
+            "txn_2,chargeback,1000,USD,,\n",
+        );
+
+        assert!(GenericSettlementReportParser
+            .parse(report.as_bytes())
+            .is_err());
+    }
+}
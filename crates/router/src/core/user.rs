@@ -50,7 +50,10 @@ use crate::{
     },
 };
 #[cfg(feature = "email")]
-use crate::{services::email::types as email_types, utils::user as user_utils};
+use crate::{
+    services::email::{outbox as email_outbox, types as email_types},
+    utils::user as user_utils,
+};
 
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
@@ -96,18 +99,13 @@ pub async fn signup_with_merchant_id(
             .unwrap_or(state.conf.theme.email_config.clone()),
     };
 
-    let send_email_result = state
-        .email_client
-        .compose_and_send_email(
-            user_utils::get_base_url(&state),
-            Box::new(email_contents),
-            state.conf.proxy.https_url.as_ref(),
-        )
-        .await;
+    let send_email_result = email_outbox::enqueue_email(&state, Box::new(email_contents)).await;
 
     logger::info!(?send_email_result);
     Ok(ApplicationResponse::Json(user_api::AuthorizeResponse {
-        is_email_sent: send_email_result.is_ok(),
+        is_email_sent: send_email_result.is_ok_and(|outbox_entry| {
+            outbox_entry.status == common_enums::EmailOutboxStatus::Pending
+        }),
         user_id: user_from_db.get_user_id().to_string(),
     }))
 }
@@ -517,15 +515,9 @@ pub async fn forgot_password(
             .unwrap_or(state.conf.theme.email_config.clone()),
     };
 
-    state
-        .email_client
-        .compose_and_send_email(
-            user_utils::get_base_url(&state),
-            Box::new(email_contents),
-            state.conf.proxy.https_url.as_ref(),
-        )
+    email_outbox::enqueue_email(&state, Box::new(email_contents))
         .await
-        .map_err(|e| e.change_context(UserErrors::InternalServerError))?;
+        .change_context(UserErrors::InternalServerError)?;
 
     Ok(ApplicationResponse::StatusOk)
 }
@@ -929,17 +921,11 @@ async fn handle_existing_user_invitation(
                 .unwrap_or(state.conf.theme.email_config.clone()),
         };
 
-        is_email_sent = state
-            .email_client
-            .compose_and_send_email(
-                user_utils::get_base_url(state),
-                Box::new(email_contents),
-                state.conf.proxy.https_url.as_ref(),
-            )
-            .await
-            .map(|email_result| logger::info!(?email_result))
-            .map_err(|email_result| logger::error!(?email_result))
-            .is_ok();
+        let send_email_result = email_outbox::enqueue_email(state, Box::new(email_contents)).await;
+        logger::info!(?send_email_result);
+        is_email_sent = send_email_result.is_ok_and(|outbox_entry| {
+            outbox_entry.status == common_enums::EmailOutboxStatus::Pending
+        });
     }
     #[cfg(not(feature = "email"))]
     {
@@ -1084,16 +1070,11 @@ async fn handle_new_user_invitation(
                 .map(|theme| theme.email_config())
                 .unwrap_or(state.conf.theme.email_config.clone()),
         };
-        let send_email_result = state
-            .email_client
-            .compose_and_send_email(
-                user_utils::get_base_url(state),
-                Box::new(email_contents),
-                state.conf.proxy.https_url.as_ref(),
-            )
-            .await;
+        let send_email_result = email_outbox::enqueue_email(state, Box::new(email_contents)).await;
         logger::info!(?send_email_result);
-        is_email_sent = send_email_result.is_ok();
+        is_email_sent = send_email_result.is_ok_and(|outbox_entry| {
+            outbox_entry.status == common_enums::EmailOutboxStatus::Pending
+        });
     }
     #[cfg(not(feature = "email"))]
     {
@@ -1239,13 +1220,7 @@ pub async fn resend_invite(
             .unwrap_or(state.conf.theme.email_config.clone()),
     };
 
-    state
-        .email_client
-        .compose_and_send_email(
-            user_utils::get_base_url(&state),
-            Box::new(email_contents),
-            state.conf.proxy.https_url.as_ref(),
-        )
+    email_outbox::enqueue_email(&state, Box::new(email_contents))
         .await
         .change_context(UserErrors::InternalServerError)?;
 
@@ -33,7 +33,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payment_methods,
         payment_methods::cards,
@@ -1987,6 +1987,7 @@ pub async fn get_payment_attempt_from_object_reference_id(
 #[allow(clippy::too_many_arguments)]
 pub async fn get_or_update_dispute_object(
     state: SessionState,
+    platform: &domain::Platform,
     option_dispute: Option<diesel_models::dispute::Dispute>,
     dispute_details: api::disputes::DisputePayload,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -2034,11 +2035,14 @@ pub async fn get_or_update_dispute_object(
                 organization_id: organization_id.clone(),
                 dispute_currency: Some(dispute_details.currency),
             };
-            state
+            let dispute = state
                 .store
                 .insert_dispute(new_dispute.clone())
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            let dispute = try_apply_dispute_auto_accept_rules(&state, platform, dispute).await;
+            try_schedule_dispute_deadline_task(db, &dispute, true).await;
+            Ok(dispute)
         }
         Some(dispute) => {
             logger::info!("Dispute Already exists, Updating the dispute details");
@@ -2060,13 +2064,58 @@ pub async fn get_or_update_dispute_object(
                 challenge_required_by: dispute_details.challenge_required_by,
                 connector_updated_at: dispute_details.updated_at,
             };
-            db.update_dispute(dispute, update_dispute)
+            let previous_challenge_required_by = dispute.challenge_required_by;
+            let previous_dispute_status = dispute.dispute_status;
+            let dispute = db
+                .update_dispute(dispute, update_dispute)
                 .await
-                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+            let is_deadline_changed = dispute.challenge_required_by
+                != previous_challenge_required_by
+                || dispute.dispute_status != previous_dispute_status;
+            let dispute = try_apply_dispute_auto_accept_rules(&state, platform, dispute).await;
+            try_schedule_dispute_deadline_task(db, &dispute, is_deadline_changed).await;
+            Ok(dispute)
+        }
+    }
+}
+
+async fn try_apply_dispute_auto_accept_rules(
+    state: &SessionState,
+    platform: &domain::Platform,
+    dispute: diesel_models::dispute::Dispute,
+) -> diesel_models::dispute::Dispute {
+    let auto_accepted_dispute = async {
+        let automation_config =
+            disputes::get_dispute_automation_config(&*state.store, &dispute.merchant_id).await?;
+        disputes::apply_dispute_auto_accept_rules(state, platform, &automation_config, &dispute)
+            .await
+    }
+    .await;
+    // Failing to auto accept the dispute should not fail the webhook, the merchant can still
+    // act on the dispute themselves
+    match auto_accepted_dispute {
+        Ok(auto_accepted_dispute) => auto_accepted_dispute.unwrap_or(dispute),
+        Err(error) => {
+            logger::error!(?error, "Failed to apply dispute auto accept rules");
+            dispute
         }
     }
 }
 
+async fn try_schedule_dispute_deadline_task(
+    db: &dyn StorageInterface,
+    dispute: &diesel_models::dispute::Dispute,
+    is_deadline_changed: bool,
+) {
+    // Failing to schedule the reminder should not fail the webhook
+    if let Err(error) =
+        disputes::schedule_dispute_deadline_task(db, dispute, is_deadline_changed).await
+    {
+        logger::error!(?error, "Failed to schedule dispute deadline task");
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn external_authentication_incoming_webhook_flow(
@@ -2488,6 +2537,7 @@ async fn disputes_incoming_webhook_flow(
 
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
+            &platform,
             option_dispute,
             dispute_details,
             platform.get_processor().get_account().get_id(),
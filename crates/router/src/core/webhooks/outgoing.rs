@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use api_models::{
+    enums::OutgoingWebhookFormat,
     webhook_events::{OutgoingWebhookRequestContent, OutgoingWebhookResponseContent},
     webhooks,
 };
@@ -21,6 +22,8 @@ use router_env::{
 };
 
 use super::{types, utils, MERCHANT_ID};
+#[cfg(feature = "adyen")]
+use crate::compatibility::adyen::webhooks as adyen_webhooks;
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -697,15 +700,23 @@ pub(crate) fn get_outgoing_webhook_request(
         })
     }
 
-    match platform
-        .get_processor()
-        .get_account()
-        .get_compatible_connector()
-    {
+    let webhook_format = get_outgoing_webhook_format(
+        business_profile.get_outgoing_webhook_format(),
+        platform
+            .get_processor()
+            .get_account()
+            .get_compatible_connector(),
+    );
+
+    match webhook_format {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
+        OutgoingWebhookFormat::Stripe => get_outgoing_webhook_request_inner::<
             stripe_webhooks::StripeOutgoingWebhook,
         >(outgoing_webhook, business_profile),
+        #[cfg(feature = "adyen")]
+        OutgoingWebhookFormat::Adyen => get_outgoing_webhook_request_inner::<
+            adyen_webhooks::AdyenOutgoingWebhook,
+        >(outgoing_webhook, business_profile),
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
@@ -713,6 +724,19 @@ pub(crate) fn get_outgoing_webhook_request(
     }
 }
 
+/// The format configured on the profile takes precedence over the compatible connector configured
+/// on the merchant account. Only the Stripe format follows the compatible connector, the Adyen
+/// format has to be enabled on the profile.
+fn get_outgoing_webhook_format(
+    profile_webhook_format: Option<OutgoingWebhookFormat>,
+    compatible_connector: Option<api_models::enums::Connector>,
+) -> OutgoingWebhookFormat {
+    profile_webhook_format.unwrap_or(match compatible_connector {
+        Some(api_models::enums::Connector::Stripe) => OutgoingWebhookFormat::Stripe,
+        _ => OutgoingWebhookFormat::Hyperswitch,
+    })
+}
+
 #[derive(Debug)]
 enum ScheduleWebhookRetry {
     WithProcessTracker(Box<storage::ProcessTracker>),
@@ -1014,6 +1038,12 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                     payment_id: subscription.get_optional_payment_id(),
                 }
             }
+            #[cfg(feature = "payouts")]
+            webhooks::OutgoingWebhookContent::PayoutBatchDetails(payout_batch_response) => {
+                Self::PayoutBatch {
+                    batch_id: payout_batch_response.batch_id.clone(),
+                }
+            }
         }
     }
 }
@@ -1068,5 +1098,50 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             payment_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PayoutBatch { batch_id } => {
+            OutgoingWebhookEventContent::PayoutBatch {
+                batch_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adyen_webhook_format_is_opt_in() {
+        assert_eq!(
+            get_outgoing_webhook_format(None, Some(api_models::enums::Connector::Adyen)),
+            OutgoingWebhookFormat::Hyperswitch
+        );
+        assert_eq!(
+            get_outgoing_webhook_format(
+                Some(OutgoingWebhookFormat::Adyen),
+                Some(api_models::enums::Connector::Adyen)
+            ),
+            OutgoingWebhookFormat::Adyen
+        );
+    }
+
+    #[test]
+    fn test_profile_webhook_format_takes_precedence() {
+        assert_eq!(
+            get_outgoing_webhook_format(None, Some(api_models::enums::Connector::Stripe)),
+            OutgoingWebhookFormat::Stripe
+        );
+        assert_eq!(
+            get_outgoing_webhook_format(
+                Some(OutgoingWebhookFormat::Hyperswitch),
+                Some(api_models::enums::Connector::Stripe)
+            ),
+            OutgoingWebhookFormat::Hyperswitch
+        );
+        assert_eq!(
+            get_outgoing_webhook_format(None, None),
+            OutgoingWebhookFormat::Hyperswitch
+        );
+    }
+}
@@ -664,6 +664,10 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 payment_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::PayoutBatch { batch_id } => Self::PayoutBatch {
+                batch_id,
+                content: serde_json::Value::Null,
+            },
         }
     }
 }
@@ -4,10 +4,11 @@ use base64::Engine;
 use common_utils::{
     consts,
     crypto::{self, GenerateDigest},
-    errors::CustomResult,
+    errors::{CustomResult, ValidationError},
     ext_traits::ValueExt,
+    validation,
 };
-use error_stack::{Report, ResultExt};
+use error_stack::{report, Report, ResultExt};
 use redis_interface as redis;
 use router_env::tracing;
 
@@ -31,6 +32,36 @@ const IRRELEVANT_ATTEMPT_ID_IN_SOURCE_VERIFICATION_FLOW: &str =
 const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_SOURCE_VERIFICATION_FLOW: &str =
     "irrelevant_connector_request_reference_id_in_source_verification_flow";
 
+/// Validates a URL that webhooks are sent to. Besides the URL itself, every address its host
+/// resolves to has to be public, so that webhooks cannot be used to reach internal services.
+pub async fn validate_webhook_url(url: &str) -> CustomResult<url::Url, ValidationError> {
+    let url = validation::validate_public_https_url(url)?;
+
+    if let Some(url::Host::Domain(domain)) = url.host() {
+        let port = url.port_or_known_default().unwrap_or(443);
+        let addresses = tokio::net::lookup_host((domain, port))
+            .await
+            .map_err(|error| {
+                report!(ValidationError::InvalidValue {
+                    message: format!("Failed to resolve webhook URL host: {error}"),
+                })
+            })?
+            .collect::<Vec<_>>();
+
+        if addresses.is_empty()
+            || addresses
+                .iter()
+                .any(|address| !validation::is_public_ip_address(&address.ip()))
+        {
+            return Err(report!(ValidationError::InvalidValue {
+                message: "Webhook URL must point to a public host".to_string(),
+            }));
+        }
+    }
+
+    Ok(url)
+}
+
 /// Check whether the merchant has configured to disable the webhook `event` for the `connector`
 /// First check for the key "whconf_{merchant_id}_{connector_id}" in redis,
 /// if not found, fetch from configs table in database
@@ -1,4 +1,7 @@
 pub mod address;
+pub mod analytics_alert;
+pub mod analytics_export;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -13,6 +16,7 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod email_outbox;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
@@ -30,11 +34,13 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method_session;
+pub mod payout_batch;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -95,6 +101,9 @@ pub trait StorageInterface:
     + Sync
     + dyn_clone::DynClone
     + address::AddressInterface
+    + analytics_alert::AnalyticsAlertInterface
+    + analytics_export::AnalyticsExportInterface
+    + analytics_report::AnalyticsReportInterface
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface<Error = StorageError>
@@ -118,10 +127,13 @@ pub trait StorageInterface:
     + blocklist::BlocklistInterface
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + dynamic_routing_stats::DynamicRoutingStatsInterface
+    + email_outbox::EmailOutboxInterface
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
+    + payout_batch::PayoutBatchInterface
     + refund::RefundInterface
+    + settlement_report::SettlementReportInterface
     + reverse_lookup::ReverseLookupInterface
     + CardsInfoInterface<Error = StorageError>
     + merchant_key_store::MerchantKeyStoreInterface<Error = StorageError>
@@ -147,6 +159,7 @@ pub trait StorageInterface:
     + callback_mapper::CallbackMapperInterface
     + storage_impl::subscription::SubscriptionInterface<Error = StorageError>
     + storage_impl::invoice::InvoiceInterface<Error = StorageError>
+    + hyperswitch_domain_models::subscription_plan::SubscriptionPlanInterface<Error = StorageError>
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -0,0 +1,235 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsAlertInterface {
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn find_analytics_alert_rule_by_id(
+        &self,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError>;
+
+    async fn update_analytics_alert_rule(
+        &self,
+        this: storage::AnalyticsAlertRule,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn insert_analytics_alert_history(
+        &self,
+        alert_history: Vec<storage::AnalyticsAlertHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_analytics_alert_history_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertHistory>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        alert_rule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_rule_by_id(
+        &self,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::find_by_id(&conn, rule_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::find_by_merchant_id_id(&conn, merchant_id, rule_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_rule(
+        &self,
+        this: storage::AnalyticsAlertRule,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, alert_rule_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlertRule::delete_by_merchant_id_id(&conn, merchant_id, rule_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert_history(
+        &self,
+        alert_history: Vec<storage::AnalyticsAlertHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlertHistoryNew::batch_insert(alert_history, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alert_history_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        rule_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertHistory>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertHistory::list_by_merchant_id_rule_id(
+            &conn,
+            merchant_id,
+            rule_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertInterface for MockDb {
+    async fn insert_analytics_alert_rule(
+        &self,
+        _alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_alert_rule_by_id(
+        &self,
+        _rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_alert_rule(
+        &self,
+        _this: storage::AnalyticsAlertRule,
+        _alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_analytics_alert_history(
+        &self,
+        _alert_history: Vec<storage::AnalyticsAlertHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_alert_history_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _rule_id: Option<&str>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertHistory>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
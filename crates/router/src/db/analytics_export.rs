@@ -0,0 +1,317 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsExportInterface {
+    async fn insert_analytics_export(
+        &self,
+        export: storage::AnalyticsExportNew,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError>;
+
+    async fn find_analytics_export_by_id(
+        &self,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError>;
+
+    async fn find_analytics_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError>;
+
+    async fn list_analytics_exports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsExport>, errors::StorageError>;
+
+    async fn update_analytics_export(
+        &self,
+        this: storage::AnalyticsExport,
+        export_update: storage::AnalyticsExportUpdate,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError>;
+
+    async fn delete_analytics_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError>;
+
+    async fn insert_analytics_export_run(
+        &self,
+        export_run: storage::AnalyticsExportRunNew,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError>;
+
+    async fn find_analytics_export_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        run_id: &str,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError>;
+
+    async fn find_analytics_export_run_by_export_id_period_start(
+        &self,
+        export_id: &str,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError>;
+
+    async fn list_analytics_export_runs_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsExportRun>, errors::StorageError>;
+
+    async fn update_analytics_export_run(
+        &self,
+        this: storage::AnalyticsExportRun,
+        export_run_update: storage::AnalyticsExportRunUpdate,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsExportInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_export(
+        &self,
+        export: storage::AnalyticsExportNew,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        export
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_export_by_id(
+        &self,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsExport::find_by_id(&conn, export_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsExport::find_by_merchant_id_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_exports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsExport>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsExport::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_export(
+        &self,
+        this: storage::AnalyticsExport,
+        export_update: storage::AnalyticsExportUpdate,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, export_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsExport::delete_by_merchant_id_id(&conn, merchant_id, export_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_analytics_export_run(
+        &self,
+        export_run: storage::AnalyticsExportRunNew,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        export_run
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_export_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        run_id: &str,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsExportRun::find_by_merchant_id_id(&conn, merchant_id, run_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_export_run_by_export_id_period_start(
+        &self,
+        export_id: &str,
+        period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsExportRun::find_by_export_id_period_start(&conn, export_id, period_start)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_export_runs_by_merchant_id_export_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        export_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsExportRun>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsExportRun::list_by_merchant_id_export_id(
+            &conn,
+            merchant_id,
+            export_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_export_run(
+        &self,
+        this: storage::AnalyticsExportRun,
+        export_run_update: storage::AnalyticsExportRunUpdate,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, export_run_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsExportInterface for MockDb {
+    async fn insert_analytics_export(
+        &self,
+        _export: storage::AnalyticsExportNew,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_export_by_id(
+        &self,
+        _export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_export_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_exports_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsExport>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_export(
+        &self,
+        _this: storage::AnalyticsExport,
+        _export_update: storage::AnalyticsExportUpdate,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_analytics_export_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_analytics_export_run(
+        &self,
+        _export_run: storage::AnalyticsExportRunNew,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_export_run_by_merchant_id_run_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _run_id: &str,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_export_run_by_export_id_period_start(
+        &self,
+        _export_id: &str,
+        _period_start: time::PrimitiveDateTime,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_analytics_export_runs_by_merchant_id_export_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _export_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsExportRun>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_export_run(
+        &self,
+        _this: storage::AnalyticsExportRun,
+        _export_run_update: storage::AnalyticsExportRunUpdate,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,94 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsReportInterface {
+    async fn insert_analytics_report(
+        &self,
+        analytics_report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError>;
+
+    async fn find_analytics_report_by_id(
+        &self,
+        report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError>;
+
+    async fn update_analytics_report(
+        &self,
+        this: storage::AnalyticsReport,
+        analytics_report_update: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_report(
+        &self,
+        analytics_report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        analytics_report
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_report_by_id(
+        &self,
+        report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsReport::find_by_id(&conn, report_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_report(
+        &self,
+        this: storage::AnalyticsReport,
+        analytics_report_update: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, analytics_report_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportInterface for MockDb {
+    async fn insert_analytics_report(
+        &self,
+        _analytics_report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_analytics_report_by_id(
+        &self,
+        _report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_analytics_report(
+        &self,
+        _this: storage::AnalyticsReport,
+        _analytics_report_update: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
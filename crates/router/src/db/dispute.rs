@@ -55,6 +55,16 @@ pub trait DisputeInterface {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::StorageError>;
+
+    /// Counts the chargebacks received since `created_after` for payments made using the card
+    /// with the given fingerprint
+    #[cfg(feature = "v1")]
+    async fn count_chargebacks_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -154,6 +164,25 @@ impl DisputeInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn count_chargebacks_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::count_chargebacks_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+            created_after,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -448,6 +477,41 @@ impl DisputeInterface for MockDb {
             .into_iter()
             .collect::<Vec<(common_enums::DisputeStatus, i64)>>())
     }
+
+    #[cfg(feature = "v1")]
+    async fn count_chargebacks_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let attempt_ids = self
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .filter(|attempt| {
+                attempt.merchant_id == *merchant_id
+                    && attempt.fingerprint_id.as_deref() == Some(fingerprint_id)
+            })
+            .map(|attempt| attempt.attempt_id.clone())
+            .collect::<Vec<_>>();
+
+        let chargeback_count = self
+            .disputes
+            .lock()
+            .await
+            .iter()
+            .filter(|d| {
+                d.merchant_id == *merchant_id
+                    && d.dispute_stage != common_enums::DisputeStage::PreDispute
+                    && d.created_at >= created_after
+                    && attempt_ids.contains(&d.attempt_id)
+            })
+            .count();
+
+        i64::try_from(chargeback_count).map_err(|_| report!(errors::StorageError::MockDbError))
+    }
 }
 
 #[cfg(test)]
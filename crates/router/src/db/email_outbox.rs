@@ -0,0 +1,149 @@
+use common_utils::pii;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait EmailOutboxInterface {
+    async fn insert_email_outbox_entry(
+        &self,
+        email_outbox: storage::EmailOutboxNew,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError>;
+
+    async fn find_email_outbox_entry_by_id(
+        &self,
+        outbox_id: &str,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError>;
+
+    async fn list_email_outbox_entries_by_recipient_and_statuses(
+        &self,
+        recipient: &pii::Email,
+        statuses: Vec<enums::EmailOutboxStatus>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EmailOutbox>, errors::StorageError>;
+
+    async fn update_email_outbox_entry(
+        &self,
+        this: storage::EmailOutbox,
+        email_outbox_update: storage::EmailOutboxUpdate,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError>;
+
+    async fn delete_email_outbox_entry_by_id(
+        &self,
+        outbox_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl EmailOutboxInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_email_outbox_entry(
+        &self,
+        email_outbox: storage::EmailOutboxNew,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        email_outbox
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_email_outbox_entry_by_id(
+        &self,
+        outbox_id: &str,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EmailOutbox::find_by_id(&conn, outbox_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_email_outbox_entries_by_recipient_and_statuses(
+        &self,
+        recipient: &pii::Email,
+        statuses: Vec<enums::EmailOutboxStatus>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EmailOutbox>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::EmailOutbox::list_by_recipient_and_statuses(&conn, recipient, statuses, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_email_outbox_entry(
+        &self,
+        this: storage::EmailOutbox,
+        email_outbox_update: storage::EmailOutboxUpdate,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, email_outbox_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_email_outbox_entry_by_id(
+        &self,
+        outbox_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::EmailOutbox::delete_by_id(&conn, outbox_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailOutboxInterface for MockDb {
+    async fn insert_email_outbox_entry(
+        &self,
+        _email_outbox: storage::EmailOutboxNew,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_email_outbox_entry_by_id(
+        &self,
+        _outbox_id: &str,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_email_outbox_entries_by_recipient_and_statuses(
+        &self,
+        _recipient: &pii::Email,
+        _statuses: Vec<enums::EmailOutboxStatus>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::EmailOutbox>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_email_outbox_entry(
+        &self,
+        _this: storage::EmailOutbox,
+        _email_outbox_update: storage::EmailOutboxUpdate,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_email_outbox_entry_by_id(
+        &self,
+        _outbox_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -1172,6 +1172,7 @@ mod tests {
                 refund_statuses_enabled: None,
                 payout_statuses_enabled: None,
                 multiple_webhooks_list: None,
+                webhook_format: None,
             }),
             sub_merchants_enabled: None,
             parent_merchant_id: None,
@@ -1238,6 +1239,7 @@ mod tests {
                 refund_statuses_enabled: None,
                 payout_statuses_enabled: None,
                 multiple_webhooks_list: None,
+                webhook_format: None,
             }),
             metadata: None,
             routing_algorithm: None,
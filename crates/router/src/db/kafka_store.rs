@@ -4,7 +4,7 @@ use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_enums::enums::MerchantStorageScheme;
 use common_utils::{
     errors::CustomResult,
-    id_type,
+    id_type, pii,
     types::{keymanager::KeyManagerState, user::ThemeLineage, TenantConfig},
 };
 #[cfg(feature = "v2")]
@@ -32,6 +32,7 @@ use hyperswitch_domain_models::{
         Subscription as DomainSubscription, SubscriptionInterface,
         SubscriptionUpdate as DomainSubscriptionUpdate,
     },
+    subscription_plan::SubscriptionPlanInterface,
 };
 #[cfg(not(feature = "payouts"))]
 use hyperswitch_domain_models::{PayoutAttemptInterface, PayoutsInterface};
@@ -63,6 +64,9 @@ use crate::{
     db::{
         self,
         address::AddressInterface,
+        analytics_alert::AnalyticsAlertInterface,
+        analytics_export::AnalyticsExportInterface,
+        analytics_report::AnalyticsReportInterface,
         api_keys::ApiKeyInterface,
         authentication::AuthenticationInterface,
         authorization::AuthorizationInterface,
@@ -72,6 +76,7 @@ use crate::{
         configs::ConfigInterface,
         customers::CustomerInterface,
         dispute::DisputeInterface,
+        email_outbox::EmailOutboxInterface,
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
         file::FileMetadataInterface,
@@ -84,15 +89,23 @@ use crate::{
         merchant_connector_account::{ConnectorAccessToken, MerchantConnectorAccountInterface},
         merchant_key_store::MerchantKeyStoreInterface,
         payment_link::PaymentLinkInterface,
+        payout_batch::PayoutBatchInterface,
         refund::RefundInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        settlement_report::SettlementReportInterface,
         tokenization::TokenizationInterface,
         unified_translations::UnifiedTranslationsInterface,
         AccountsStorageInterface, CommonStorageInterface, GlobalStorageInterface,
         MasterKeyInterface, StorageInterface,
     },
-    services::{kafka::KafkaProducer, Store},
+    services::{
+        kafka::{
+            outbox::{KafkaOutboxOldRecord, KafkaOutboxRecord},
+            KafkaProducer,
+        },
+        Store,
+    },
     types::{domain, storage, AccessToken},
 };
 #[derive(Debug, Clone, Serialize)]
@@ -113,6 +126,15 @@ impl KafkaStore {
         tenant_config: &dyn TenantConfig,
     ) -> Self {
         kafka_producer.set_tenancy(tenant_config);
+        if kafka_producer.is_outbox_enabled() {
+            match store.get_redis_conn() {
+                Ok(redis_conn) => kafka_producer.set_outbox_connection(redis_conn),
+                Err(error) => logger::error!(
+                    ?error,
+                    "Failed to get redis connection for Kafka outbox, events will be sent to Kafka directly"
+                ),
+            }
+        }
         Self {
             kafka_producer,
             diesel_store: store,
@@ -550,14 +572,33 @@ impl DisputeInterface for KafkaStore {
         &self,
         dispute_new: storage::DisputeNew,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Dispute {
+                    merchant_id: dispute_new.merchant_id.clone(),
+                    dispute_id: dispute_new.dispute_id.clone(),
+                },
+                None,
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let dispute = self.diesel_store.insert_dispute(dispute_new).await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_dispute(&dispute, None, self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to add analytics entry for Dispute {dispute:?}", error_message=?er);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to add analytics entry for Dispute {dispute:?}", error_message=?er);
+            }
         };
 
         Ok(dispute)
@@ -603,16 +644,35 @@ impl DisputeInterface for KafkaStore {
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Dispute {
+                    merchant_id: this.merchant_id.clone(),
+                    dispute_id: this.dispute_id.clone(),
+                },
+                Some(KafkaOutboxOldRecord::Dispute(&this)),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let dispute_new = self
             .diesel_store
             .update_dispute(this.clone(), dispute)
             .await?;
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_dispute(&dispute_new, Some(this), self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to add analytics entry for Dispute {dispute_new:?}", error_message=?er);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to add analytics entry for Dispute {dispute_new:?}", error_message=?er);
+            }
         };
 
         Ok(dispute_new)
@@ -628,6 +688,22 @@ impl DisputeInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn count_chargebacks_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+        created_after: PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_chargebacks_by_merchant_id_fingerprint_id(
+                merchant_id,
+                fingerprint_id,
+                created_after,
+            )
+            .await
+    }
+
     async fn get_dispute_status_with_count(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -1134,6 +1210,422 @@ impl ConnectorAccessToken for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl AnalyticsAlertInterface for KafkaStore {
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_alert_rule(alert_rule)
+            .await
+    }
+
+    async fn find_analytics_alert_rule_by_id(
+        &self,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_alert_rule_by_id(rule_id)
+            .await
+    }
+
+    async fn find_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id)
+            .await
+    }
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_alert_rules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_analytics_alert_rule(
+        &self,
+        this: storage::AnalyticsAlertRule,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_alert_rule(this, alert_rule_update)
+            .await
+    }
+
+    async fn delete_analytics_alert_rule_by_merchant_id_rule_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .delete_analytics_alert_rule_by_merchant_id_rule_id(merchant_id, rule_id)
+            .await
+    }
+
+    async fn insert_analytics_alert_history(
+        &self,
+        alert_history: Vec<storage::AnalyticsAlertHistoryNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_alert_history(alert_history)
+            .await
+    }
+
+    async fn list_analytics_alert_history_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        rule_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertHistory>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_alert_history_by_merchant_id(merchant_id, rule_id, limit, offset)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsExportInterface for KafkaStore {
+    async fn insert_analytics_export(
+        &self,
+        export: storage::AnalyticsExportNew,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        self.diesel_store.insert_analytics_export(export).await
+    }
+
+    async fn find_analytics_export_by_id(
+        &self,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_export_by_id(export_id)
+            .await
+    }
+
+    async fn find_analytics_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_export_by_merchant_id_export_id(merchant_id, export_id)
+            .await
+    }
+
+    async fn list_analytics_exports_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsExport>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_exports_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_analytics_export(
+        &self,
+        this: storage::AnalyticsExport,
+        export_update: storage::AnalyticsExportUpdate,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_export(this, export_update)
+            .await
+    }
+
+    async fn delete_analytics_export_by_merchant_id_export_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        export_id: &str,
+    ) -> CustomResult<storage::AnalyticsExport, errors::StorageError> {
+        self.diesel_store
+            .delete_analytics_export_by_merchant_id_export_id(merchant_id, export_id)
+            .await
+    }
+
+    async fn insert_analytics_export_run(
+        &self,
+        export_run: storage::AnalyticsExportRunNew,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_export_run(export_run)
+            .await
+    }
+
+    async fn find_analytics_export_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        run_id: &str,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_export_run_by_merchant_id_run_id(merchant_id, run_id)
+            .await
+    }
+
+    async fn find_analytics_export_run_by_export_id_period_start(
+        &self,
+        export_id: &str,
+        period_start: PrimitiveDateTime,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_export_run_by_export_id_period_start(export_id, period_start)
+            .await
+    }
+
+    async fn list_analytics_export_runs_by_merchant_id_export_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        export_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::AnalyticsExportRun>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_export_runs_by_merchant_id_export_id(
+                merchant_id,
+                export_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    async fn update_analytics_export_run(
+        &self,
+        this: storage::AnalyticsExportRun,
+        export_run_update: storage::AnalyticsExportRunUpdate,
+    ) -> CustomResult<storage::AnalyticsExportRun, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_export_run(this, export_run_update)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsReportInterface for KafkaStore {
+    async fn insert_analytics_report(
+        &self,
+        analytics_report: storage::AnalyticsReportNew,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_report(analytics_report)
+            .await
+    }
+
+    async fn find_analytics_report_by_id(
+        &self,
+        report_id: &str,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_report_by_id(report_id)
+            .await
+    }
+
+    async fn update_analytics_report(
+        &self,
+        this: storage::AnalyticsReport,
+        analytics_report_update: storage::AnalyticsReportUpdate,
+    ) -> CustomResult<storage::AnalyticsReport, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_report(this, analytics_report_update)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailOutboxInterface for KafkaStore {
+    async fn insert_email_outbox_entry(
+        &self,
+        email_outbox: storage::EmailOutboxNew,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        self.diesel_store
+            .insert_email_outbox_entry(email_outbox)
+            .await
+    }
+
+    async fn find_email_outbox_entry_by_id(
+        &self,
+        outbox_id: &str,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        self.diesel_store
+            .find_email_outbox_entry_by_id(outbox_id)
+            .await
+    }
+
+    async fn list_email_outbox_entries_by_recipient_and_statuses(
+        &self,
+        recipient: &pii::Email,
+        statuses: Vec<enums::EmailOutboxStatus>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::EmailOutbox>, errors::StorageError> {
+        self.diesel_store
+            .list_email_outbox_entries_by_recipient_and_statuses(recipient, statuses, limit)
+            .await
+    }
+
+    async fn update_email_outbox_entry(
+        &self,
+        this: storage::EmailOutbox,
+        email_outbox_update: storage::EmailOutboxUpdate,
+    ) -> CustomResult<storage::EmailOutbox, errors::StorageError> {
+        self.diesel_store
+            .update_email_outbox_entry(this, email_outbox_update)
+            .await
+    }
+
+    async fn delete_email_outbox_entry_by_id(
+        &self,
+        outbox_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_email_outbox_entry_by_id(outbox_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for KafkaStore {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+        recon_results: Vec<storage::ReconResultNew>,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .insert_settlement_report(settlement_report, recon_results)
+            .await
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_report_by_merchant_id_report_id(merchant_id, report_id)
+            .await
+    }
+
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError> {
+        self.diesel_store
+            .list_settlement_reports_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn list_recon_results_by_merchant_id_constraints(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        report_id: Option<&str>,
+        status: Option<enums::ReconResultStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconResult>, errors::StorageError> {
+        self.diesel_store
+            .list_recon_results_by_merchant_id_constraints(
+                merchant_id,
+                report_id,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+        process_tracker_entry: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch(payout_batch, payout_batch_items, process_tracker_entry)
+            .await
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batches_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch(this, payout_batch_update)
+            .await
+    }
+
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        status: Option<enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .list_payout_batch_items_by_batch_id(batch_id, status, limit, offset)
+            .await
+    }
+
+    async fn count_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .count_payout_batch_items_by_batch_id_status(batch_id, status)
+            .await
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item(this, payout_batch_item_update)
+            .await
+    }
+
+    async fn cancel_pending_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .cancel_pending_payout_batch_items_by_batch_id(batch_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl FileMetadataInterface for KafkaStore {
     async fn insert_file_metadata(
@@ -1411,17 +1903,37 @@ impl PaymentAttemptInterface for KafkaStore {
         payment_attempt: storage::PaymentAttemptNew,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentAttempt, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::PaymentAttempt {
+                    merchant_id: payment_attempt.merchant_id.clone(),
+                    attempt_id: payment_attempt.attempt_id.clone(),
+                    storage_scheme,
+                },
+                None,
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let attempt = self
             .diesel_store
             .insert_payment_attempt(payment_attempt, storage_scheme)
             .await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_payment_attempt(&attempt, None, self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+            }
         }
 
         Ok(attempt)
@@ -1457,6 +1969,19 @@ impl PaymentAttemptInterface for KafkaStore {
         payment_attempt: storage::PaymentAttemptUpdate,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentAttempt, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::PaymentAttempt {
+                    merchant_id: this.merchant_id.clone(),
+                    attempt_id: this.attempt_id.clone(),
+                    storage_scheme,
+                },
+                Some(KafkaOutboxOldRecord::PaymentAttempt(&this)),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let mut attempt = self
             .diesel_store
             .update_payment_attempt_with_attempt_id(
@@ -1469,12 +1994,19 @@ impl PaymentAttemptInterface for KafkaStore {
         let debit_routing_savings = payment_attempt.get_debit_routing_savings();
 
         attempt.set_debit_routing_savings(debit_routing_savings);
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_payment_attempt(&attempt, Some(this), self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+            }
         }
 
         Ok(attempt)
@@ -1779,6 +2311,32 @@ impl PaymentIntentInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PaymentIntent, errors::StorageError> {
+        let state = self
+            .diesel_store
+            .get_keymanager_state()
+            .attach_printable("Missing KeyManagerState")?;
+        let infra_values =
+            state.add_confirm_value_in_infra_values(payment_intent.is_confirm_operation());
+        #[cfg(feature = "v1")]
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::PaymentIntent {
+                    merchant_id: this.merchant_id.clone(),
+                    payment_id: this.payment_id.clone(),
+                    storage_scheme,
+                    infra_values: infra_values.clone(),
+                },
+                Some(KafkaOutboxOldRecord::PaymentIntent(
+                    &this,
+                    infra_values.clone(),
+                )),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
+        #[cfg(feature = "v2")]
+        let pending_write_id = None;
         let intent = self
             .diesel_store
             .update_payment_intent(
@@ -1788,21 +2346,19 @@ impl PaymentIntentInterface for KafkaStore {
                 storage_scheme,
             )
             .await?;
-        let state = self
-            .diesel_store
-            .get_keymanager_state()
-            .attach_printable("Missing KeyManagerState")?;
-        if let Err(er) = self
+        match self
             .kafka_producer
-            .log_payment_intent(
-                &intent,
-                Some(this),
-                self.tenant_id.clone(),
-                state.add_confirm_value_in_infra_values(payment_intent.is_confirm_operation()),
-            )
+            .log_payment_intent(&intent, Some(this), self.tenant_id.clone(), infra_values)
             .await
         {
-            logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
+            }
         };
 
         Ok(intent)
@@ -1819,12 +2375,29 @@ impl PaymentIntentInterface for KafkaStore {
             .diesel_store
             .get_keymanager_state()
             .attach_printable("Missing KeyManagerState")?;
+        #[cfg(feature = "v1")]
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::PaymentIntent {
+                    merchant_id: new.merchant_id.clone(),
+                    payment_id: new.payment_id.clone(),
+                    storage_scheme,
+                    infra_values: state.infra_values.clone(),
+                },
+                None,
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
+        #[cfg(feature = "v2")]
+        let pending_write_id = None;
         let intent = self
             .diesel_store
             .insert_payment_intent(new, key_store, storage_scheme)
             .await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_payment_intent(
                 &intent,
@@ -1834,7 +2407,14 @@ impl PaymentIntentInterface for KafkaStore {
             )
             .await
         {
-            logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
+            }
         };
 
         Ok(intent)
@@ -2277,11 +2857,27 @@ impl PayoutAttemptInterface for KafkaStore {
         payouts: &storage::Payouts,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Payout {
+                    merchant_id: this.merchant_id.clone(),
+                    payout_id: this.payout_id.clone(),
+                    payout_attempt_id: this.payout_attempt_id.clone(),
+                    storage_scheme,
+                },
+                Some(KafkaOutboxOldRecord::Payout(KafkaPayout::from_storage(
+                    payouts, this,
+                ))),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let updated_payout_attempt = self
             .diesel_store
             .update_payout_attempt(this, payout_attempt_update, payouts, storage_scheme)
             .await?;
-        if let Err(err) = self
+        match self
             .kafka_producer
             .log_payout(
                 &KafkaPayout::from_storage(payouts, &updated_payout_attempt),
@@ -2290,7 +2886,14 @@ impl PayoutAttemptInterface for KafkaStore {
             )
             .await
         {
-            logger::error!(message="Failed to update analytics entry for Payouts {payouts:?}\n{updated_payout_attempt:?}", error_message=?err);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(err) => {
+                logger::error!(message="Failed to update analytics entry for Payouts {payouts:?}\n{updated_payout_attempt:?}", error_message=?err);
+            }
         };
 
         Ok(updated_payout_attempt)
@@ -2302,11 +2905,25 @@ impl PayoutAttemptInterface for KafkaStore {
         payouts: &storage::Payouts,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Payout {
+                    merchant_id: payout_attempt.merchant_id.clone(),
+                    payout_id: payout_attempt.payout_id.clone(),
+                    payout_attempt_id: payout_attempt.payout_attempt_id.clone(),
+                    storage_scheme,
+                },
+                None,
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let payout_attempt_new = self
             .diesel_store
             .insert_payout_attempt(payout_attempt, payouts, storage_scheme)
             .await?;
-        if let Err(err) = self
+        match self
             .kafka_producer
             .log_payout(
                 &KafkaPayout::from_storage(payouts, &payout_attempt_new),
@@ -2315,7 +2932,14 @@ impl PayoutAttemptInterface for KafkaStore {
             )
             .await
         {
-            logger::error!(message="Failed to add analytics entry for Payouts {payouts:?}\n{payout_attempt_new:?}", error_message=?err);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(err) => {
+                logger::error!(message="Failed to add analytics entry for Payouts {payouts:?}\n{payout_attempt_new:?}", error_message=?err);
+            }
         };
 
         Ok(payout_attempt_new)
@@ -2361,11 +2985,28 @@ impl PayoutsInterface for KafkaStore {
         payout_attempt: &storage::PayoutAttempt,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Payouts, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Payout {
+                    merchant_id: this.merchant_id.clone(),
+                    payout_id: this.payout_id.clone(),
+                    payout_attempt_id: payout_attempt.payout_attempt_id.clone(),
+                    storage_scheme,
+                },
+                Some(KafkaOutboxOldRecord::Payout(KafkaPayout::from_storage(
+                    this,
+                    payout_attempt,
+                ))),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let payout = self
             .diesel_store
             .update_payout(this, payout_update, payout_attempt, storage_scheme)
             .await?;
-        if let Err(err) = self
+        match self
             .kafka_producer
             .log_payout(
                 &KafkaPayout::from_storage(&payout, payout_attempt),
@@ -2374,7 +3015,14 @@ impl PayoutsInterface for KafkaStore {
             )
             .await
         {
-            logger::error!(message="Failed to update analytics entry for Payouts {payout:?}\n{payout_attempt:?}", error_message=?err);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(err) => {
+                logger::error!(message="Failed to update analytics entry for Payouts {payout:?}\n{payout_attempt:?}", error_message=?err);
+            }
         };
         Ok(payout)
     }
@@ -2400,6 +3048,17 @@ impl PayoutsInterface for KafkaStore {
             .await
     }
 
+    async fn filter_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payout_ids: &[id_type::PayoutId],
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::Payouts>, errors::StorageError> {
+        self.diesel_store
+            .filter_payouts_by_merchant_id_payout_ids(merchant_id, payout_ids, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payouts_by_constraints(
         &self,
@@ -2558,6 +3217,28 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: &storage::ProcessTrackerFilterConstraints,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_constraints(constraints, limit, offset)
+            .await
+    }
+
+    async fn update_processes_by_ids_and_statuses(
+        &self,
+        task_ids: Vec<String>,
+        statuses: Vec<ProcessTrackerStatus>,
+        task_update: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .update_processes_by_ids_and_statuses(task_ids, statuses, task_update)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2667,17 +3348,40 @@ impl RefundInterface for KafkaStore {
         refund: diesel_refund::RefundUpdate,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
+        #[cfg(feature = "v1")]
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Refund {
+                    merchant_id: this.merchant_id.clone(),
+                    refund_id: this.refund_id.clone(),
+                    storage_scheme,
+                },
+                Some(KafkaOutboxOldRecord::Refund(&this)),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
+        #[cfg(feature = "v2")]
+        let pending_write_id = None;
         let refund = self
             .diesel_store
             .update_refund(this.clone(), refund, storage_scheme)
             .await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_refund(&refund, Some(this), self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to insert analytics event for Refund Update {refund?}", error_message=?er);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to insert analytics event for Refund Update {refund?}", error_message=?er);
+            }
         }
         Ok(refund)
     }
@@ -2713,14 +3417,37 @@ impl RefundInterface for KafkaStore {
         new: diesel_refund::RefundNew,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<diesel_refund::Refund, errors::StorageError> {
+        #[cfg(feature = "v1")]
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Refund {
+                    merchant_id: new.merchant_id.clone(),
+                    refund_id: new.refund_id.clone(),
+                    storage_scheme,
+                },
+                None,
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
+        #[cfg(feature = "v2")]
+        let pending_write_id = None;
         let refund = self.diesel_store.insert_refund(new, storage_scheme).await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_refund(&refund, None, self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to insert analytics event for Refund Create {refund?}", error_message=?er);
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to insert analytics event for Refund Create {refund?}", error_message=?er);
+            }
         }
         Ok(refund)
     }
@@ -3686,17 +4413,36 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         authentication: storage::AuthenticationNew,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Authentication {
+                    merchant_id: authentication.merchant_id.clone(),
+                    authentication_id: authentication.authentication_id.clone(),
+                },
+                None,
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let auth = self
             .diesel_store
             .insert_authentication(authentication)
             .await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_authentication(&auth, None, self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to log analytics event for authentication {auth:?}", error_message=?er)
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to log analytics event for authentication {auth:?}", error_message=?er)
+            }
         }
 
         Ok(auth)
@@ -3730,6 +4476,18 @@ impl AuthenticationInterface for KafkaStore {
         previous_state: storage::Authentication,
         authentication_update: storage::AuthenticationUpdate,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
+        let pending_write_id = self
+            .kafka_producer
+            .add_pending_write(
+                &KafkaOutboxRecord::Authentication {
+                    merchant_id: previous_state.merchant_id.clone(),
+                    authentication_id: previous_state.authentication_id.clone(),
+                },
+                Some(KafkaOutboxOldRecord::Authentication(&previous_state)),
+                self.tenant_id.clone(),
+            )
+            .await
+            .change_context(errors::StorageError::KafkaError)?;
         let auth = self
             .diesel_store
             .update_authentication_by_merchant_id_authentication_id(
@@ -3738,12 +4496,19 @@ impl AuthenticationInterface for KafkaStore {
             )
             .await?;
 
-        if let Err(er) = self
+        match self
             .kafka_producer
             .log_authentication(&auth, Some(previous_state.clone()), self.tenant_id.clone())
             .await
         {
-            logger::error!(message="Failed to log analytics event for authentication {auth:?}", error_message=?er)
+            Ok(()) => {
+                self.kafka_producer
+                    .remove_pending_write(pending_write_id)
+                    .await
+            }
+            Err(er) => {
+                logger::error!(message="Failed to log analytics event for authentication {auth:?}", error_message=?er)
+            }
         }
 
         Ok(auth)
@@ -4254,6 +5019,90 @@ impl InvoiceInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl SubscriptionPlanInterface for KafkaStore {
+    type Error = errors::StorageError;
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        plan: diesel_models::subscription_plan::SubscriptionPlanNew,
+    ) -> CustomResult<diesel_models::subscription_plan::SubscriptionPlan, errors::StorageError>
+    {
+        self.diesel_store.insert_subscription_plan(plan).await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan_price(
+        &self,
+        price: diesel_models::subscription_plan::SubscriptionPlanPriceNew,
+    ) -> CustomResult<diesel_models::subscription_plan::SubscriptionPlanPrice, errors::StorageError>
+    {
+        self.diesel_store
+            .insert_subscription_plan_price(price)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_profile_id_plan_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        plan_id: &str,
+    ) -> CustomResult<diesel_models::subscription_plan::SubscriptionPlan, errors::StorageError>
+    {
+        self.diesel_store
+            .find_subscription_plan_by_profile_id_plan_id(profile_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<diesel_models::subscription_plan::SubscriptionPlan>, errors::StorageError>
+    {
+        self.diesel_store
+            .list_subscription_plans_by_merchant_id_profile_id(
+                merchant_id,
+                profile_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plan_prices_by_profile_id_plan_ids(
+        &self,
+        profile_id: &id_type::ProfileId,
+        plan_ids: Vec<String>,
+    ) -> CustomResult<
+        Vec<diesel_models::subscription_plan::SubscriptionPlanPrice>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .list_subscription_plan_prices_by_profile_id_plan_ids(profile_id, plan_ids)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_price_by_profile_id_price_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        price_id: &str,
+    ) -> CustomResult<
+        Option<diesel_models::subscription_plan::SubscriptionPlanPrice>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .find_subscription_plan_price_by_profile_id_price_id(profile_id, price_id)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl SubscriptionInterface for KafkaStore {
     type Error = errors::StorageError;
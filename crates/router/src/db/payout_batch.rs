@@ -0,0 +1,264 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    /// Inserts a batch along with its items and the task processing them
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+        process_tracker_entry: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError>;
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        status: Option<enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn count_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+
+    async fn cancel_pending_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+        payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+        process_tracker_entry: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        // A batch is never left behind without all of its items or without the task completing it
+        let payout_batch = conn
+            .transaction_async(|conn| async move {
+                let payout_batch = payout_batch
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                // Items are inserted in chunks to stay well within the bind parameter limit of
+                // Postgres
+                for items in payout_batch_items.chunks(1000) {
+                    storage::PayoutBatchItemNew::batch_insert(items.to_vec(), &conn)
+                        .await
+                        .map_err(errors::StorageError::from)?;
+                }
+                process_tracker_entry
+                    .insert_process(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                Ok::<_, errors::StorageError>(payout_batch)
+            })
+            .await?;
+        Ok(payout_batch)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+        status: Option<enums::PayoutBatchItemStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::list_by_batch_id(&conn, batch_id, status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_payout_batch_items_by_batch_id_status(
+        &self,
+        batch_id: &str,
+        status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::count_by_batch_id_status(&conn, batch_id, status)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn cancel_pending_payout_batch_items_by_batch_id(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PayoutBatchItem::cancel_pending_by_batch_id(&conn, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        _payout_batch: storage::PayoutBatchNew,
+        _payout_batch_items: Vec<storage::PayoutBatchItemNew>,
+        _process_tracker_entry: storage::ProcessTrackerNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_batches_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::PayoutBatch>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch(
+        &self,
+        _this: storage::PayoutBatch,
+        _payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_payout_batch_items_by_batch_id(
+        &self,
+        _batch_id: &str,
+        _status: Option<enums::PayoutBatchItemStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_payout_batch_items_by_batch_id_status(
+        &self,
+        _batch_id: &str,
+        _status: enums::PayoutBatchItemStatus,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        _this: storage::PayoutBatchItem,
+        _payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn cancel_pending_payout_batch_items_by_batch_id(
+        &self,
+        _batch_id: &str,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,153 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait SettlementReportInterface {
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+        recon_results: Vec<storage::ReconResultNew>,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError>;
+
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError>;
+
+    async fn list_recon_results_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<&str>,
+        status: Option<enums::ReconResultStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconResult>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_settlement_report(
+        &self,
+        settlement_report: storage::SettlementReportNew,
+        recon_results: Vec<storage::ReconResultNew>,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let settlement_report = settlement_report
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+        // Results are inserted in chunks to stay well within the bind parameter limit of Postgres
+        for results in recon_results.chunks(1000) {
+            storage::ReconResultNew::batch_insert(results.to_vec(), &conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+        }
+        Ok(settlement_report)
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReport::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_recon_results_by_merchant_id_constraints(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        report_id: Option<&str>,
+        status: Option<enums::ReconResultStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconResult>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconResult::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            report_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReportInterface for MockDb {
+    async fn insert_settlement_report(
+        &self,
+        _settlement_report: storage::SettlementReportNew,
+        _recon_results: Vec<storage::ReconResultNew>,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_settlement_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_id: &str,
+    ) -> CustomResult<storage::SettlementReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_settlement_reports_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReport>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_recon_results_by_merchant_id_constraints(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _report_id: Option<&str>,
+        _status: Option<enums::ReconResultStatus>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconResult>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
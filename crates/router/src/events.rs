@@ -19,10 +19,17 @@ pub mod api_logs;
 pub mod audit_events;
 pub mod connector_api_logs;
 pub mod event_logger;
+pub mod file;
+#[cfg(feature = "olap")]
+pub mod loader;
+pub mod nats;
 pub mod outgoing_webhook_logs;
+pub mod redis_stream;
 pub mod routing_api_logs;
-#[derive(Debug, Serialize, Clone, Copy)]
+pub mod sink;
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, strum::Display)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentIntent,
     FraudCheck,
@@ -48,6 +55,16 @@ pub enum EventsConfig {
     Kafka {
         kafka: Box<KafkaSettings>,
     },
+    #[serde(rename = "redis_stream")]
+    RedisStream {
+        redis_stream: Box<redis_stream::RedisStreamSinkSettings>,
+    },
+    Nats {
+        nats: Box<nats::NatsSinkSettings>,
+    },
+    File {
+        file: Box<file::FileSinkSettings>,
+    },
     #[default]
     Logs,
 }
@@ -56,6 +73,8 @@ pub enum EventsConfig {
 #[derive(Debug, Clone)]
 pub enum EventsHandler {
     Kafka(KafkaProducer),
+    /// Redis stream, NATS or file event sink
+    Sink(sink::EventSink),
     Logs(event_logger::EventLogger),
 }
 
@@ -79,6 +98,24 @@ impl EventsConfig {
                     .await
                     .change_context(StorageError::InitializationError)?,
             ),
+            Self::RedisStream { redis_stream } => EventsHandler::Sink(sink::EventSink::spawn(
+                redis_stream::RedisStreamWriter::new(redis_stream)
+                    .await
+                    .change_context(StorageError::InitializationError)?,
+                &redis_stream.buffer,
+            )),
+            Self::Nats { nats } => EventsHandler::Sink(sink::EventSink::spawn(
+                nats::NatsWriter::new(nats)
+                    .await
+                    .change_context(StorageError::InitializationError)?,
+                &nats.buffer,
+            )),
+            Self::File { file } => EventsHandler::Sink(sink::EventSink::spawn(
+                file::FileWriter::new(file)
+                    .await
+                    .change_context(StorageError::InitializationError)?,
+                &file.buffer,
+            )),
             Self::Logs => EventsHandler::Logs(event_logger::EventLogger::default()),
         })
     }
@@ -86,6 +123,9 @@ impl EventsConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         match self {
             Self::Kafka { kafka } => kafka.validate(),
+            Self::RedisStream { redis_stream } => redis_stream.validate(),
+            Self::Nats { nats } => nats.validate(),
+            Self::File { file } => file.validate(),
             Self::Logs => Ok(()),
         }
     }
@@ -97,12 +137,15 @@ impl EventsHandler {
             Self::Kafka(kafka) => kafka.log_event(event).unwrap_or_else(|e| {
                 logger::error!("Failed to log event: {:?}", e);
             }),
+            Self::Sink(sink) => sink.log_event(event),
             Self::Logs(logger) => logger.log_event(event),
         };
     }
     pub fn add_tenant(&mut self, tenant_config: &dyn TenantConfig) {
-        if let Self::Kafka(kafka_producer) = self {
-            kafka_producer.set_tenancy(tenant_config);
+        match self {
+            Self::Kafka(kafka_producer) => kafka_producer.set_tenancy(tenant_config),
+            Self::Sink(sink) => sink.set_tenancy(tenant_config),
+            Self::Logs(_) => {}
         }
     }
 }
@@ -121,6 +164,7 @@ impl MessagingInterface for EventsHandler {
     {
         match self {
             Self::Kafka(a) => a.send_message(data, metadata, timestamp),
+            Self::Sink(a) => a.send_message(data, metadata, timestamp),
             Self::Logs(a) => a.send_message(data, metadata, timestamp),
         }
     }
@@ -1,4 +1,5 @@
-use api_models::payments::Amount;
+use api_models::{payments::Amount, process_tracker::tasks::ProcessTrackerTaskAction};
+use common_enums::ProcessTrackerStatus;
 use common_utils::types::MinorUnit;
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
@@ -39,6 +40,14 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    ProcessTrackerTaskUpdate {
+        task_id: String,
+        runner: Option<String>,
+        previous_status: ProcessTrackerStatus,
+        action: ProcessTrackerTaskAction,
+        /// Whether the action was performed on all tasks matching a filter
+        is_bulk_action: bool,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +92,7 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::ProcessTrackerTaskUpdate { .. } => "process_tracker_task_update",
         };
         format!(
             "{event_type}-{}",
@@ -0,0 +1,518 @@
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use common_utils::{errors::CustomResult, fp_utils::when};
+use error_stack::ResultExt;
+use events::EventsError;
+use router_env::logger;
+use tokio::{fs, io::AsyncWriteExt};
+
+#[cfg(feature = "olap")]
+use super::loader::{EventSinkBatch, EventSinkReader};
+use super::{
+    sink::{EventSinkBufferSettings, EventSinkRecord, EventSinkWriter, EventsLoaderSettings},
+    EventType,
+};
+use crate::core::errors::ApplicationError;
+
+const FILE_EXTENSION: &str = "ndjson";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct FileSinkSettings {
+    /// Directory to which events are written, events of each type are written to the file
+    /// `<event_type>.ndjson`
+    pub directory: PathBuf,
+    /// Size in bytes after which a file is rotated
+    pub max_file_size: u64,
+    /// Time in seconds after which a file is rotated
+    pub max_file_age: u64,
+    /// Number of rotated files retained for each event type, the oldest files are deleted first
+    pub max_rotated_files: usize,
+    #[serde(flatten)]
+    pub buffer: EventSinkBufferSettings,
+    pub loader: EventsLoaderSettings,
+}
+
+impl Default for FileSinkSettings {
+    fn default() -> Self {
+        Self {
+            directory: PathBuf::from("events"),
+            max_file_size: 100 * 1024 * 1024,
+            max_file_age: 60 * 60,
+            max_rotated_files: 24,
+            buffer: EventSinkBufferSettings::default(),
+            loader: EventsLoaderSettings::default(),
+        }
+    }
+}
+
+impl FileSinkSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        when(self.directory.as_os_str().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "File events sink directory must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_file_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "File events sink max file size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.max_file_age == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "File events sink max file age must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.max_rotated_files == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "File events sink max rotated files must be greater than zero".into(),
+            ))
+        })?;
+
+        self.buffer.validate()?;
+        self.loader.validate()
+    }
+
+    fn active_file_path(&self, event_type: EventType) -> PathBuf {
+        self.directory
+            .join(format!("{event_type}.{FILE_EXTENSION}"))
+    }
+
+    /// Rotated files are named `<event_type>.<rotated_at>.ndjson`, where `rotated_at` is the
+    /// time of rotation in nanoseconds, so that they sort in the order in which they were written
+    fn rotated_file_path(&self, event_type: EventType) -> PathBuf {
+        self.directory.join(format!(
+            "{event_type}.{:020}.{FILE_EXTENSION}",
+            time::OffsetDateTime::now_utc().unix_timestamp_nanos()
+        ))
+    }
+}
+
+/// Returns the rotated files in the directory of the sink, oldest first.
+async fn list_rotated_files(directory: &Path) -> CustomResult<Vec<(String, PathBuf)>, EventsError> {
+    let mut entries = fs::read_dir(directory)
+        .await
+        .change_context(EventsError::GenericError)
+        .attach_printable_lazy(|| format!("Failed to read directory {}", directory.display()))?;
+
+    let mut rotated_files = Vec::new();
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .change_context(EventsError::GenericError)?
+    {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        let mut parts = file_name.split('.');
+        if let (Some(event_type), Some(rotated_at), Some(FILE_EXTENSION), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        {
+            if rotated_at.chars().all(|c| c.is_ascii_digit()) {
+                rotated_files.push((event_type.to_owned(), entry.path()));
+            }
+        }
+    }
+    rotated_files.sort_by(|(_, left), (_, right)| left.file_name().cmp(&right.file_name()));
+
+    Ok(rotated_files)
+}
+
+struct ActiveFile {
+    file: fs::File,
+    size: u64,
+    opened_at: tokio::time::Instant,
+}
+
+/// Appends events as newline delimited JSON to a file per event type, and rotates the files by
+/// size and age.
+pub struct FileWriter {
+    settings: FileSinkSettings,
+    active_files: HashMap<EventType, ActiveFile>,
+}
+
+impl FileWriter {
+    pub async fn new(settings: &FileSinkSettings) -> CustomResult<Self, EventsError> {
+        fs::create_dir_all(&settings.directory)
+            .await
+            .change_context(EventsError::GenericError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to create events directory {}",
+                    settings.directory.display()
+                )
+            })?;
+
+        Ok(Self {
+            settings: settings.clone(),
+            active_files: HashMap::new(),
+        })
+    }
+
+    async fn append(
+        &mut self,
+        event_type: EventType,
+        lines: Vec<u8>,
+    ) -> CustomResult<(), EventsError> {
+        let path = self.settings.active_file_path(event_type);
+        let active_file = match self.active_files.remove(&event_type) {
+            Some(active_file) => active_file,
+            None => {
+                let file = fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&path)
+                    .await
+                    .change_context(EventsError::PublishError)
+                    .attach_printable_lazy(|| format!("Failed to open {}", path.display()))?;
+                let size = file
+                    .metadata()
+                    .await
+                    .change_context(EventsError::PublishError)?
+                    .len();
+                ActiveFile {
+                    file,
+                    size,
+                    opened_at: tokio::time::Instant::now(),
+                }
+            }
+        };
+        let ActiveFile {
+            mut file,
+            size,
+            opened_at,
+        } = active_file;
+
+        file.write_all(&lines)
+            .await
+            .change_context(EventsError::PublishError)
+            .attach_printable_lazy(|| format!("Failed to write to {}", path.display()))?;
+        let size = size.saturating_add(u64::try_from(lines.len()).unwrap_or(u64::MAX));
+
+        let should_rotate = size >= self.settings.max_file_size
+            || opened_at.elapsed() >= Duration::from_secs(self.settings.max_file_age);
+        if should_rotate {
+            file.flush()
+                .await
+                .change_context(EventsError::PublishError)?;
+            drop(file);
+            self.rotate(event_type, path).await?;
+        } else {
+            self.active_files.insert(
+                event_type,
+                ActiveFile {
+                    file,
+                    size,
+                    opened_at,
+                },
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn rotate(&self, event_type: EventType, path: PathBuf) -> CustomResult<(), EventsError> {
+        let rotated_path = self.settings.rotated_file_path(event_type);
+        fs::rename(&path, &rotated_path)
+            .await
+            .change_context(EventsError::PublishError)
+            .attach_printable_lazy(|| format!("Failed to rotate {}", path.display()))?;
+
+        let event_type = event_type.to_string();
+        let rotated_files = list_rotated_files(&self.settings.directory)
+            .await?
+            .into_iter()
+            .filter(|(file_event_type, _)| *file_event_type == event_type)
+            .collect::<Vec<_>>();
+        let excess_files = rotated_files
+            .len()
+            .saturating_sub(self.settings.max_rotated_files);
+
+        for (_, path) in rotated_files.into_iter().take(excess_files) {
+            if let Err(error) = fs::remove_file(&path).await {
+                logger::error!(?error, path = %path.display(), "Failed to delete rotated events file");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl EventSinkWriter for FileWriter {
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn write(&mut self, records: Vec<EventSinkRecord>) -> CustomResult<(), EventsError> {
+        let mut lines_by_event_type = HashMap::<_, Vec<u8>>::new();
+        for record in records {
+            let lines = lines_by_event_type.entry(record.event_type).or_default();
+            serde_json::to_writer(&mut *lines, &record)
+                .change_context(EventsError::SerializationError)?;
+            lines.push(b'\n');
+        }
+
+        for (event_type, lines) in lines_by_event_type {
+            self.append(event_type, lines).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the rotated files of the event types that are loaded into the analytics database one
+/// file at a time, and deletes each file once it is loaded. Files that are still being written to
+/// are loaded once they are rotated.
+#[cfg(feature = "olap")]
+pub struct FileReader {
+    directory: PathBuf,
+    event_types: Vec<String>,
+}
+
+#[cfg(feature = "olap")]
+impl FileReader {
+    pub fn new(settings: &FileSinkSettings, event_types: &[EventType]) -> Self {
+        Self {
+            directory: settings.directory.clone(),
+            event_types: event_types.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+impl EventSinkReader for FileReader {
+    /// Path of the file read
+    type Handle = PathBuf;
+
+    fn name(&self) -> &'static str {
+        "file"
+    }
+
+    async fn read(&mut self) -> CustomResult<Option<EventSinkBatch<Self::Handle>>, EventsError> {
+        let next_file = list_rotated_files(&self.directory)
+            .await?
+            .into_iter()
+            .find(|(event_type, _)| self.event_types.contains(event_type));
+        let Some((_, path)) = next_file else {
+            return Ok(None);
+        };
+
+        let contents = fs::read_to_string(&path)
+            .await
+            .change_context(EventsError::GenericError)
+            .attach_printable_lazy(|| format!("Failed to read {}", path.display()))?;
+
+        let records = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| {
+                serde_json::from_str::<EventSinkRecord>(line)
+                    .inspect_err(|error| {
+                        logger::error!(?error, path = %path.display(), "Skipping invalid event");
+                    })
+                    .ok()
+            })
+            .collect();
+
+        Ok(Some(EventSinkBatch {
+            records,
+            handle: path,
+        }))
+    }
+
+    async fn acknowledge(&mut self, handle: Self::Handle) -> CustomResult<(), EventsError> {
+        fs::remove_file(&handle)
+            .await
+            .change_context(EventsError::GenericError)
+            .attach_printable_lazy(|| format!("Failed to delete {}", handle.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn test_settings(max_file_size: u64, max_rotated_files: usize) -> FileSinkSettings {
+        FileSinkSettings {
+            directory: std::env::temp_dir()
+                .join(format!("hyperswitch_events_{}", uuid::Uuid::new_v4())),
+            max_file_size,
+            max_rotated_files,
+            ..Default::default()
+        }
+    }
+
+    fn test_record(event_type: EventType) -> EventSinkRecord {
+        EventSinkRecord {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            event_type,
+            tenant_id: Some("public".into()),
+            key: "merchant_1".into(),
+            timestamp: 1_700_000_000_000,
+            event: serde_json::json!({ "merchant_id": "merchant_1" }),
+        }
+    }
+
+    async fn rotated_files_count(settings: &FileSinkSettings, event_type: EventType) -> usize {
+        let event_type = event_type.to_string();
+        list_rotated_files(&settings.directory)
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|(file_event_type, _)| *file_event_type == event_type)
+            .count()
+    }
+
+    #[test]
+    fn test_settings_validation() {
+        assert!(FileSinkSettings::default().validate().is_ok());
+
+        let settings = FileSinkSettings {
+            directory: PathBuf::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = FileSinkSettings {
+            max_file_size: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = FileSinkSettings {
+            max_file_age: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = FileSinkSettings {
+            max_rotated_files: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_files_are_rotated_by_size() {
+        let settings = test_settings(1, 10);
+        let mut writer = FileWriter::new(&settings).await.unwrap();
+
+        writer
+            .write(vec![test_record(EventType::ApiLogs)])
+            .await
+            .unwrap();
+
+        assert_eq!(rotated_files_count(&settings, EventType::ApiLogs).await, 1);
+        assert!(!settings.active_file_path(EventType::ApiLogs).exists());
+
+        fs::remove_dir_all(&settings.directory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_files_are_not_rotated_below_max_size() {
+        let settings = test_settings(1024 * 1024, 10);
+        let mut writer = FileWriter::new(&settings).await.unwrap();
+
+        writer
+            .write(vec![
+                test_record(EventType::ApiLogs),
+                test_record(EventType::ApiLogs),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(rotated_files_count(&settings, EventType::ApiLogs).await, 0);
+        let contents = fs::read_to_string(settings.active_file_path(EventType::ApiLogs))
+            .await
+            .unwrap();
+        assert_eq!(contents.lines().count(), 2);
+
+        fs::remove_dir_all(&settings.directory).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_oldest_rotated_files_are_deleted() {
+        let settings = test_settings(1, 2);
+        let mut writer = FileWriter::new(&settings).await.unwrap();
+
+        for _ in 0..3 {
+            writer
+                .write(vec![test_record(EventType::ApiLogs)])
+                .await
+                .unwrap();
+        }
+        writer
+            .write(vec![test_record(EventType::RoutingApiLogs)])
+            .await
+            .unwrap();
+
+        assert_eq!(rotated_files_count(&settings, EventType::ApiLogs).await, 2);
+        assert_eq!(
+            rotated_files_count(&settings, EventType::RoutingApiLogs).await,
+            1
+        );
+
+        fs::remove_dir_all(&settings.directory).await.unwrap();
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_rotated_files_are_read_and_deleted_once_loaded() {
+        let settings = test_settings(1, 10);
+        let mut writer = FileWriter::new(&settings).await.unwrap();
+        let record = test_record(EventType::ApiLogs);
+
+        writer.write(vec![record.clone()]).await.unwrap();
+        writer
+            .write(vec![test_record(EventType::PaymentIntent)])
+            .await
+            .unwrap();
+
+        let mut reader = FileReader::new(&settings, &[EventType::ApiLogs]);
+        let batch = reader.read().await.unwrap().unwrap();
+        assert_eq!(batch.records.len(), 1);
+        assert_eq!(
+            batch.records.first().map(|record| record.event_id.as_str()),
+            Some(record.event_id.as_str())
+        );
+
+        reader.acknowledge(batch.handle).await.unwrap();
+        // Files of event types that are not loaded are left in place
+        assert!(reader.read().await.unwrap().is_none());
+        assert_eq!(
+            rotated_files_count(&settings, EventType::PaymentIntent).await,
+            1
+        );
+
+        fs::remove_dir_all(&settings.directory).await.unwrap();
+    }
+
+    #[cfg(feature = "olap")]
+    #[tokio::test]
+    async fn test_invalid_lines_are_skipped() {
+        let settings = test_settings(1, 10);
+        fs::create_dir_all(&settings.directory).await.unwrap();
+        let record = serde_json::to_string(&test_record(EventType::ApiLogs)).unwrap();
+        fs::write(
+            settings.rotated_file_path(EventType::ApiLogs),
+            format!("{record}\nnot json\n\n"),
+        )
+        .await
+        .unwrap();
+
+        let mut reader = FileReader::new(&settings, &[EventType::ApiLogs]);
+        let batch = reader.read().await.unwrap().unwrap();
+        assert_eq!(batch.records.len(), 1);
+
+        fs::remove_dir_all(&settings.directory).await.unwrap();
+    }
+}
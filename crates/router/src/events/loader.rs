@@ -0,0 +1,282 @@
+use std::{collections::HashMap, time::Duration};
+
+use analytics::{
+    event_tables::{EventRow, EventTable},
+    AnalyticsProvider,
+};
+use common_utils::{errors::CustomResult, id_type};
+use error_stack::report;
+use events::EventsError;
+use router_env::{logger, metric_attributes};
+
+use super::{
+    file::FileReader,
+    nats::NatsReader,
+    redis_stream::RedisStreamReader,
+    sink::{EventSinkRecord, EventsLoaderSettings},
+    EventType, EventsConfig,
+};
+use crate::{
+    core::errors::ApplicationError,
+    routes::{metrics, AppState},
+};
+
+/// Event types that are loaded into the analytics database, along with their tables. Events of the
+/// storage types are not written to event sinks, as the sqlx analytics provider reads the primary
+/// tables of the application directly.
+const LOADED_EVENT_TYPES: [(EventType, EventTable); 4] = [
+    (EventType::ApiLogs, EventTable::ApiEvents),
+    (EventType::ConnectorApiLogs, EventTable::ConnectorEvents),
+    (
+        EventType::OutgoingWebhookLogs,
+        EventTable::OutgoingWebhookEvents,
+    ),
+    (EventType::RoutingApiLogs, EventTable::RoutingEvents),
+];
+
+pub struct EventSinkBatch<H> {
+    pub records: Vec<EventSinkRecord>,
+    pub handle: H,
+}
+
+/// Reads the events written to an event sink back, for the events loader.
+#[async_trait::async_trait]
+pub trait EventSinkReader: Send + 'static {
+    /// Identifies the events of a batch, for acknowledging them once they are loaded
+    type Handle: Send;
+
+    /// Name of the sink, used in logs and metrics
+    fn name(&self) -> &'static str;
+
+    /// Reads the next batch of events, returns `None` if there are no events to load.
+    async fn read(&mut self) -> CustomResult<Option<EventSinkBatch<Self::Handle>>, EventsError>;
+
+    /// Marks the events of a batch as loaded so that they are not read again.
+    async fn acknowledge(&mut self, handle: Self::Handle) -> CustomResult<(), EventsError>;
+}
+
+/// Loads the events written to an event sink into the event tables of the sqlx analytics
+/// database of their tenants.
+///
+/// A batch is acknowledged only once all of its events are inserted, and events that are inserted
+/// more than once are skipped, so every event is loaded exactly once.
+pub struct EventsLoader<R> {
+    reader: R,
+    pools: HashMap<id_type::TenantId, AnalyticsProvider>,
+    settings: EventsLoaderSettings,
+}
+
+impl<R: EventSinkReader> EventsLoader<R> {
+    pub async fn run(mut self) {
+        let sink = self.reader.name();
+        logger::info!(sink, "Starting events loader");
+
+        loop {
+            let should_wait = match self.reader.read().await {
+                Ok(Some(batch)) => match self.load(batch.records).await {
+                    Ok(()) => {
+                        if let Err(error) = self.reader.acknowledge(batch.handle).await {
+                            logger::error!(?error, sink, "Failed to acknowledge loaded events");
+                        }
+                        false
+                    }
+                    Err(error) => {
+                        metrics::EVENTS_LOADER_FAILURES.add(1, metric_attributes!(("sink", sink)));
+                        logger::error!(?error, sink, "Failed to load events");
+                        true
+                    }
+                },
+                Ok(None) => true,
+                Err(error) => {
+                    metrics::EVENTS_LOADER_FAILURES.add(1, metric_attributes!(("sink", sink)));
+                    logger::error!(?error, sink, "Failed to read events");
+                    true
+                }
+            };
+
+            if should_wait {
+                tokio::time::sleep(Duration::from_millis(self.settings.poll_interval)).await;
+            }
+        }
+    }
+
+    async fn load(&self, records: Vec<EventSinkRecord>) -> CustomResult<(), EventsError> {
+        let sink = self.reader.name();
+        let mut rows_by_table = HashMap::<_, Vec<_>>::new();
+        let mut skipped_events = 0;
+
+        for record in records {
+            let table = LOADED_EVENT_TYPES
+                .iter()
+                .find(|(event_type, _)| *event_type == record.event_type)
+                .map(|(_, table)| *table);
+            let pool = record
+                .tenant_id
+                .clone()
+                .and_then(|tenant_id| id_type::TenantId::try_from_string(tenant_id).ok())
+                .and_then(|tenant_id| self.pools.get(&tenant_id).map(|_| tenant_id));
+
+            match (table, pool) {
+                (Some(table), Some(tenant_id)) => rows_by_table
+                    .entry((tenant_id, table))
+                    .or_default()
+                    .push(EventRow {
+                        event_id: record.event_id,
+                        created_at: record.timestamp,
+                        event: record.event,
+                    }),
+                _ => {
+                    logger::warn!(
+                        sink,
+                        event_id = %record.event_id,
+                        event_type = ?record.event_type,
+                        tenant_id = ?record.tenant_id,
+                        "Skipping event that cannot be loaded"
+                    );
+                    skipped_events += 1;
+                }
+            }
+        }
+        metrics::EVENTS_LOADER_EVENTS_SKIPPED
+            .add(skipped_events, metric_attributes!(("sink", sink)));
+
+        let chunk_size = usize::try_from(self.settings.batch_size).unwrap_or(usize::MAX);
+        for ((tenant_id, table), rows) in rows_by_table {
+            let Some(pool) = self.pools.get(&tenant_id) else {
+                continue;
+            };
+
+            for rows in rows.chunks(chunk_size) {
+                let inserted_events = pool.insert_events(table, rows).await.map_err(|error| {
+                    report!(EventsError::GenericError).attach_printable(format!(
+                        "Failed to insert events of tenant {tenant_id:?} into {table:?}: {error:?}"
+                    ))
+                })?;
+                metrics::EVENTS_LOADER_EVENTS_LOADED
+                    .add(inserted_events, metric_attributes!(("sink", sink)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Spawns the events loader of the configured event sink, if it is enabled.
+pub async fn spawn_events_loader(state: &AppState) -> Result<(), ApplicationError> {
+    let event_types = LOADED_EVENT_TYPES.map(|(event_type, _)| event_type);
+
+    match state.conf.events.get_inner() {
+        EventsConfig::RedisStream { redis_stream } if redis_stream.loader.enabled => {
+            let reader = RedisStreamReader::new(redis_stream, &event_types)
+                .await
+                .map_err(log_reader_error)?;
+            spawn(state, reader, &redis_stream.loader);
+        }
+        EventsConfig::Nats { nats } if nats.loader.enabled => {
+            let reader = NatsReader::new(nats, &event_types)
+                .await
+                .map_err(log_reader_error)?;
+            spawn(state, reader, &nats.loader);
+        }
+        EventsConfig::File { file } if file.loader.enabled => {
+            spawn(state, FileReader::new(file, &event_types), &file.loader);
+        }
+        EventsConfig::Kafka { .. }
+        | EventsConfig::RedisStream { .. }
+        | EventsConfig::Nats { .. }
+        | EventsConfig::File { .. }
+        | EventsConfig::Logs => {}
+    }
+
+    Ok(())
+}
+
+fn spawn<R: EventSinkReader>(state: &AppState, reader: R, settings: &EventsLoaderSettings) {
+    tokio::spawn(
+        EventsLoader {
+            reader,
+            pools: state.pools.clone(),
+            settings: settings.clone(),
+        }
+        .run(),
+    );
+}
+
+fn log_reader_error(error: error_stack::Report<EventsError>) -> ApplicationError {
+    logger::error!(?error, "Failed to create events loader");
+    ApplicationError::ConfigurationError
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    /// Reader that has no events, the tests call `EventsLoader::load` directly
+    struct TestReader;
+
+    #[async_trait::async_trait]
+    impl EventSinkReader for TestReader {
+        type Handle = ();
+
+        fn name(&self) -> &'static str {
+            "test"
+        }
+
+        async fn read(
+            &mut self,
+        ) -> CustomResult<Option<EventSinkBatch<Self::Handle>>, EventsError> {
+            Ok(None)
+        }
+
+        async fn acknowledge(&mut self, _handle: Self::Handle) -> CustomResult<(), EventsError> {
+            Ok(())
+        }
+    }
+
+    fn test_record(event_type: EventType, tenant_id: Option<&str>) -> EventSinkRecord {
+        EventSinkRecord {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            event_type,
+            tenant_id: tenant_id.map(ToOwned::to_owned),
+            key: "merchant_1".into(),
+            timestamp: 0,
+            event: serde_json::json!({ "merchant_id": "merchant_1" }),
+        }
+    }
+
+    #[test]
+    fn test_event_types_are_loaded_into_distinct_tables() {
+        let tables = LOADED_EVENT_TYPES
+            .iter()
+            .map(|(_, table)| table.table_name())
+            .collect::<std::collections::HashSet<_>>();
+
+        assert_eq!(tables.len(), LOADED_EVENT_TYPES.len());
+        assert!(!LOADED_EVENT_TYPES
+            .iter()
+            .any(|(event_type, _)| *event_type == EventType::PaymentIntent));
+    }
+
+    #[tokio::test]
+    async fn test_events_that_cannot_be_loaded_are_skipped() {
+        let loader = EventsLoader {
+            reader: TestReader,
+            pools: HashMap::new(),
+            settings: EventsLoaderSettings::default(),
+        };
+
+        // Events of storage types, events without a tenant and events of unknown tenants are
+        // skipped without failing the batch, so that the batch is acknowledged
+        let result = loader
+            .load(vec![
+                test_record(EventType::PaymentIntent, Some("public")),
+                test_record(EventType::ApiLogs, None),
+                test_record(EventType::ApiLogs, Some("unknown_tenant")),
+            ])
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
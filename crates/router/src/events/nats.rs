@@ -0,0 +1,293 @@
+use std::time::Duration;
+
+use async_nats::jetstream;
+#[cfg(feature = "olap")]
+use common_utils::ext_traits::BytesExt;
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt, fp_utils::when};
+use error_stack::{report, ResultExt};
+use events::EventsError;
+#[cfg(feature = "olap")]
+use futures::StreamExt;
+#[cfg(feature = "olap")]
+use router_env::logger;
+
+#[cfg(feature = "olap")]
+use super::loader::{EventSinkBatch, EventSinkReader};
+use super::{
+    sink::{EventSinkBufferSettings, EventSinkRecord, EventSinkWriter, EventsLoaderSettings},
+    EventType,
+};
+use crate::core::errors::ApplicationError;
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct NatsSinkSettings {
+    /// URL of the NATS server, credentials can be included in the URL
+    pub url: String,
+    /// JetStream stream that captures the subjects to which events are published
+    pub stream_name: String,
+    /// Events of each type are published to the subject `<subject_prefix>.<event_type>`
+    pub subject_prefix: String,
+    /// Time in seconds for which events are retained in the stream
+    pub max_age: u64,
+    #[serde(flatten)]
+    pub buffer: EventSinkBufferSettings,
+    pub loader: EventsLoaderSettings,
+}
+
+impl Default for NatsSinkSettings {
+    fn default() -> Self {
+        Self {
+            url: "nats://localhost:4222".into(),
+            stream_name: "HYPERSWITCH_EVENTS".into(),
+            subject_prefix: "hyperswitch.events".into(),
+            max_age: 7 * 24 * 60 * 60,
+            buffer: EventSinkBufferSettings::default(),
+            loader: EventsLoaderSettings::default(),
+        }
+    }
+}
+
+impl NatsSinkSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        when(self.url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "NATS events sink URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.stream_name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "NATS events sink stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.subject_prefix.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "NATS events sink subject prefix must not be empty".into(),
+            ))
+        })?;
+
+        self.buffer.validate()?;
+        self.loader.validate()
+    }
+
+    fn subject(&self, event_type: EventType) -> String {
+        format!("{}.{event_type}", self.subject_prefix)
+    }
+}
+
+/// Connects to the NATS server and creates the stream of the events if it does not exist.
+async fn create_stream(
+    settings: &NatsSinkSettings,
+) -> CustomResult<(jetstream::Context, jetstream::stream::Stream), EventsError> {
+    let client = async_nats::connect(settings.url.as_str())
+        .await
+        .change_context(EventsError::GenericError)
+        .attach_printable("Failed to connect to NATS server")?;
+    let context = jetstream::new(client);
+
+    let stream = context
+        .get_or_create_stream(jetstream::stream::Config {
+            name: settings.stream_name.clone(),
+            subjects: vec![format!("{}.>", settings.subject_prefix)],
+            max_age: Duration::from_secs(settings.max_age),
+            ..Default::default()
+        })
+        .await
+        .change_context(EventsError::GenericError)
+        .attach_printable_lazy(|| {
+            format!("Failed to create NATS stream {}", settings.stream_name)
+        })?;
+
+    Ok((context, stream))
+}
+
+pub struct NatsWriter {
+    context: jetstream::Context,
+    settings: NatsSinkSettings,
+}
+
+impl NatsWriter {
+    pub async fn new(settings: &NatsSinkSettings) -> CustomResult<Self, EventsError> {
+        let (context, _) = create_stream(settings).await?;
+
+        Ok(Self {
+            context,
+            settings: settings.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EventSinkWriter for NatsWriter {
+    fn name(&self) -> &'static str {
+        "nats"
+    }
+
+    async fn write(&mut self, records: Vec<EventSinkRecord>) -> CustomResult<(), EventsError> {
+        let mut acknowledgements = Vec::with_capacity(records.len());
+
+        // Events are published together and their acknowledgements are awaited afterwards
+        for record in records {
+            let payload =
+                serde_json::to_vec(&record).change_context(EventsError::SerializationError)?;
+            acknowledgements.push(
+                self.context
+                    .publish(self.settings.subject(record.event_type), payload.into())
+                    .await
+                    .change_context(EventsError::PublishError)?,
+            );
+        }
+
+        for acknowledgement in acknowledgements {
+            acknowledgement
+                .await
+                .change_context(EventsError::PublishError)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the events of the types that are loaded into the analytics database through a durable
+/// pull consumer, so that events are acknowledged only once they are loaded.
+#[cfg(feature = "olap")]
+pub struct NatsReader {
+    consumer: jetstream::consumer::PullConsumer,
+    batch_size: usize,
+}
+
+#[cfg(feature = "olap")]
+impl NatsReader {
+    pub async fn new(
+        settings: &NatsSinkSettings,
+        event_types: &[EventType],
+    ) -> CustomResult<Self, EventsError> {
+        let (_, stream) = create_stream(settings).await?;
+        let consumer_name = settings.loader.consumer_group.clone();
+
+        let consumer = stream
+            .get_or_create_consumer(
+                &consumer_name,
+                jetstream::consumer::pull::Config {
+                    durable_name: Some(consumer_name.clone()),
+                    filter_subjects: event_types
+                        .iter()
+                        .map(|event_type| settings.subject(*event_type))
+                        .collect(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .change_context(EventsError::GenericError)
+            .attach_printable_lazy(|| format!("Failed to create NATS consumer {consumer_name}"))?;
+
+        Ok(Self {
+            consumer,
+            batch_size: usize::try_from(settings.loader.batch_size).unwrap_or(usize::MAX),
+        })
+    }
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+impl EventSinkReader for NatsReader {
+    type Handle = Vec<jetstream::Message>;
+
+    fn name(&self) -> &'static str {
+        "nats"
+    }
+
+    async fn read(&mut self) -> CustomResult<Option<EventSinkBatch<Self::Handle>>, EventsError> {
+        let mut messages = self
+            .consumer
+            .fetch()
+            .max_messages(self.batch_size)
+            .messages()
+            .await
+            .change_context(EventsError::GenericError)
+            .attach_printable("Failed to fetch events from NATS")?;
+
+        let mut records = Vec::new();
+        let mut handle = Vec::new();
+        while let Some(message) = messages.next().await {
+            let message = message
+                .map_err(|error| report!(EventsError::GenericError).attach_printable(error))?;
+
+            let parsed_record: CustomResult<EventSinkRecord, _> =
+                message.payload.parse_struct("EventSinkRecord");
+            match parsed_record {
+                Ok(record) => records.push(record),
+                Err(error) => {
+                    logger::error!(?error, subject = %message.subject, "Skipping invalid event");
+                }
+            }
+            handle.push(message);
+        }
+
+        Ok((!handle.is_empty()).then_some(EventSinkBatch { records, handle }))
+    }
+
+    async fn acknowledge(&mut self, handle: Self::Handle) -> CustomResult<(), EventsError> {
+        for message in handle {
+            message
+                .ack()
+                .await
+                .map_err(|error| report!(EventsError::GenericError).attach_printable(error))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_validation() {
+        assert!(NatsSinkSettings::default().validate().is_ok());
+
+        let settings = NatsSinkSettings {
+            url: String::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = NatsSinkSettings {
+            stream_name: String::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = NatsSinkSettings {
+            subject_prefix: String::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = NatsSinkSettings {
+            loader: EventsLoaderSettings {
+                enabled: true,
+                batch_size: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_subject_is_captured_by_stream() {
+        let settings = NatsSinkSettings::default();
+
+        assert_eq!(
+            settings.subject(EventType::ConnectorApiLogs),
+            "hyperswitch.events.connector_api_logs"
+        );
+        assert!(settings
+            .subject(EventType::ApiLogs)
+            .starts_with(&format!("{}.", settings.subject_prefix)));
+    }
+}
@@ -78,6 +78,10 @@ pub enum OutgoingWebhookEventContent {
         payment_id: Option<common_utils::id_type::PaymentId>,
         content: Value,
     },
+    PayoutBatch {
+        batch_id: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -126,6 +130,14 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                         .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
                 })
             }
+            #[cfg(feature = "payouts")]
+            Self::PayoutBatchDetails(payout_batch_payload) => {
+                Some(OutgoingWebhookEventContent::PayoutBatch {
+                    batch_id: payout_batch_payload.batch_id.clone(),
+                    content: masking::masked_serialize(&payout_batch_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
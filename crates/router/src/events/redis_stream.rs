@@ -0,0 +1,366 @@
+use std::{collections::HashSet, sync::Arc};
+
+#[cfg(feature = "olap")]
+use common_utils::ext_traits::StringExt;
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt, fp_utils::when};
+use error_stack::ResultExt;
+use events::EventsError;
+#[cfg(feature = "olap")]
+use redis_interface::errors::RedisError;
+use redis_interface::{
+    RedisConnectionPool, RedisEntryId, RedisSettings, StreamCapKind, StreamCapTrim,
+};
+#[cfg(feature = "olap")]
+use router_env::logger;
+
+#[cfg(feature = "olap")]
+use super::loader::{EventSinkBatch, EventSinkReader};
+use super::{
+    sink::{EventSinkBufferSettings, EventSinkRecord, EventSinkWriter, EventsLoaderSettings},
+    EventType,
+};
+use crate::core::errors::ApplicationError;
+
+const EVENT_FIELD: &str = "event";
+#[cfg(feature = "olap")]
+const LOADER_CONSUMER_NAME: &str = "events_loader";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct RedisStreamSinkSettings {
+    /// Redis instance to which events are written
+    pub redis: RedisSettings,
+    /// Events of each type are written to the stream `<stream_prefix>_<event_type>`
+    pub stream_prefix: String,
+    /// Approximate number of events retained in each stream, streams are not trimmed if unset
+    pub max_stream_length: Option<u64>,
+    #[serde(flatten)]
+    pub buffer: EventSinkBufferSettings,
+    pub loader: EventsLoaderSettings,
+}
+
+impl Default for RedisStreamSinkSettings {
+    fn default() -> Self {
+        Self {
+            redis: RedisSettings::default(),
+            stream_prefix: "hyperswitch_events".into(),
+            max_stream_length: None,
+            buffer: EventSinkBufferSettings::default(),
+            loader: EventsLoaderSettings::default(),
+        }
+    }
+}
+
+impl RedisStreamSinkSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.redis
+            .validate()
+            .map_err(|error| ApplicationError::InvalidConfigurationValueError(error.to_string()))?;
+
+        when(self.stream_prefix.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Redis stream events sink stream prefix must not be empty".into(),
+            ))
+        })?;
+
+        when(self.max_stream_length == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Redis stream events sink max stream length must be greater than zero".into(),
+            ))
+        })?;
+
+        self.buffer.validate()?;
+        self.loader.validate()
+    }
+
+    fn stream_name(&self, event_type: EventType) -> String {
+        format!("{}_{event_type}", self.stream_prefix)
+    }
+}
+
+async fn create_connection(
+    settings: &RedisStreamSinkSettings,
+) -> CustomResult<Arc<RedisConnectionPool>, EventsError> {
+    RedisConnectionPool::new(&settings.redis)
+        .await
+        .map(Arc::new)
+        .change_context(EventsError::GenericError)
+        .attach_printable("Failed to create redis connection for events sink")
+}
+
+pub struct RedisStreamWriter {
+    redis_conn: Arc<RedisConnectionPool>,
+    settings: RedisStreamSinkSettings,
+}
+
+impl RedisStreamWriter {
+    pub async fn new(settings: &RedisStreamSinkSettings) -> CustomResult<Self, EventsError> {
+        Ok(Self {
+            redis_conn: create_connection(settings).await?,
+            settings: settings.clone(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl EventSinkWriter for RedisStreamWriter {
+    fn name(&self) -> &'static str {
+        "redis_stream"
+    }
+
+    async fn write(&mut self, records: Vec<EventSinkRecord>) -> CustomResult<(), EventsError> {
+        let mut streams = HashSet::new();
+
+        for record in records {
+            let stream_name = self.settings.stream_name(record.event_type);
+            let serialized_record =
+                serde_json::to_string(&record).change_context(EventsError::SerializationError)?;
+
+            self.redis_conn
+                .stream_append_entry(
+                    &stream_name.as_str().into(),
+                    &RedisEntryId::AutoGeneratedID,
+                    vec![(EVENT_FIELD, serialized_record)],
+                )
+                .await
+                .change_context(EventsError::PublishError)
+                .attach_printable_lazy(|| format!("Failed to add event to stream {stream_name}"))?;
+            streams.insert(stream_name);
+        }
+
+        if let Some(max_stream_length) = self.settings.max_stream_length {
+            let max_stream_length = max_stream_length.to_string();
+            for stream_name in streams {
+                self.redis_conn
+                    .stream_trim_entries(
+                        &stream_name.as_str().into(),
+                        (
+                            StreamCapKind::MaxLen,
+                            StreamCapTrim::AlmostExact,
+                            max_stream_length.as_str(),
+                        ),
+                    )
+                    .await
+                    .change_context(EventsError::PublishError)
+                    .attach_printable_lazy(|| format!("Failed to trim stream {stream_name}"))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the streams of the event types that are loaded into the analytics database through a
+/// consumer group, so that events are deleted from the streams only once they are loaded.
+#[cfg(feature = "olap")]
+pub struct RedisStreamReader {
+    redis_conn: Arc<RedisConnectionPool>,
+    stream_names: Vec<String>,
+    settings: RedisStreamSinkSettings,
+}
+
+#[cfg(feature = "olap")]
+impl RedisStreamReader {
+    pub async fn new(
+        settings: &RedisStreamSinkSettings,
+        event_types: &[EventType],
+    ) -> CustomResult<Self, EventsError> {
+        let redis_conn = create_connection(settings).await?;
+        let stream_names = event_types
+            .iter()
+            .map(|event_type| settings.stream_name(*event_type))
+            .collect::<Vec<_>>();
+
+        for stream_name in stream_names.iter() {
+            // The consumer group already exists if the loader ran earlier
+            let _ = redis_conn
+                .consumer_group_create(
+                    &stream_name.as_str().into(),
+                    &settings.loader.consumer_group,
+                    &first_entry_id(),
+                )
+                .await;
+        }
+
+        Ok(Self {
+            redis_conn,
+            stream_names,
+            settings: settings.clone(),
+        })
+    }
+
+    async fn read_entries_after(
+        &self,
+        entry_id: &str,
+    ) -> CustomResult<Vec<(String, String, Option<String>)>, RedisError> {
+        match self
+            .redis_conn
+            .stream_read_with_options(
+                self.stream_names.clone(),
+                vec![entry_id; self.stream_names.len()],
+                Some(self.settings.loader.batch_size),
+                None,
+                Some((
+                    self.settings.loader.consumer_group.as_str(),
+                    LOADER_CONSUMER_NAME,
+                )),
+            )
+            .await
+        {
+            Ok(response) => Ok(response
+                .into_iter()
+                .flat_map(|(stream_name, entries)| {
+                    entries.into_iter().map(move |(entry_id, mut fields)| {
+                        (
+                            stream_name.clone(),
+                            entry_id,
+                            fields.remove(EVENT_FIELD).flatten(),
+                        )
+                    })
+                })
+                .collect()),
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    RedisError::StreamEmptyOrNotAvailable
+                ) =>
+            {
+                Ok(Vec::new())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[cfg(feature = "olap")]
+#[async_trait::async_trait]
+impl EventSinkReader for RedisStreamReader {
+    /// Entry IDs of the events read, along with their streams
+    type Handle = Vec<(String, String)>;
+
+    fn name(&self) -> &'static str {
+        "redis_stream"
+    }
+
+    async fn read(&mut self) -> CustomResult<Option<EventSinkBatch<Self::Handle>>, EventsError> {
+        // Events that were read earlier but not acknowledged are loaded before any new events
+        let mut entries = self
+            .read_entries_after("0")
+            .await
+            .change_context(EventsError::GenericError)?;
+        if entries.is_empty() {
+            entries = self
+                .read_entries_after(">")
+                .await
+                .change_context(EventsError::GenericError)?;
+        }
+        if entries.is_empty() {
+            return Ok(None);
+        }
+
+        let mut records = Vec::with_capacity(entries.len());
+        let mut handle = Vec::with_capacity(entries.len());
+        for (stream_name, entry_id, serialized_record) in entries {
+            // Entries deleted from the stream while they were pending are returned without fields
+            if let Some(serialized_record) = serialized_record {
+                let parsed_record: CustomResult<EventSinkRecord, _> =
+                    serialized_record.parse_struct("EventSinkRecord");
+                match parsed_record {
+                    Ok(record) => records.push(record),
+                    Err(error) => {
+                        logger::error!(?error, %stream_name, %entry_id, "Skipping invalid event");
+                    }
+                }
+            }
+            handle.push((stream_name, entry_id));
+        }
+
+        Ok(Some(EventSinkBatch { records, handle }))
+    }
+
+    async fn acknowledge(&mut self, handle: Self::Handle) -> CustomResult<(), EventsError> {
+        let mut entry_ids_by_stream = std::collections::HashMap::<_, Vec<_>>::new();
+        for (stream_name, entry_id) in handle {
+            entry_ids_by_stream
+                .entry(stream_name)
+                .or_default()
+                .push(entry_id);
+        }
+
+        for (stream_name, entry_ids) in entry_ids_by_stream {
+            let stream = stream_name.as_str().into();
+            self.redis_conn
+                .stream_acknowledge_entries(
+                    &stream,
+                    &self.settings.loader.consumer_group,
+                    entry_ids.clone(),
+                )
+                .await
+                .change_context(EventsError::GenericError)?;
+            self.redis_conn
+                .stream_delete_entries(&stream, entry_ids)
+                .await
+                .change_context(EventsError::GenericError)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "olap")]
+fn first_entry_id() -> RedisEntryId {
+    RedisEntryId::UserSpecifiedID {
+        milliseconds: "0".into(),
+        sequence_number: "0".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settings_validation() {
+        assert!(RedisStreamSinkSettings::default().validate().is_ok());
+
+        let settings = RedisStreamSinkSettings {
+            stream_prefix: String::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = RedisStreamSinkSettings {
+            max_stream_length: Some(0),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = RedisStreamSinkSettings {
+            redis: RedisSettings {
+                host: String::new(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = RedisStreamSinkSettings {
+            buffer: EventSinkBufferSettings {
+                write_batch_size: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_stream_name() {
+        let settings = RedisStreamSinkSettings::default();
+
+        assert_eq!(
+            settings.stream_name(EventType::OutgoingWebhookLogs),
+            "hyperswitch_events_outgoing_webhook_logs"
+        );
+    }
+}
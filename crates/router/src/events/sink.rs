@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+
+use common_utils::{
+    errors::CustomResult, ext_traits::ConfigExt, fp_utils::when, types::TenantConfig,
+};
+use error_stack::ResultExt;
+use events::{EventsError, Message, MessagingInterface};
+use masking::ErasedMaskSerialize;
+use router_env::{logger, metric_attributes};
+use time::PrimitiveDateTime;
+use tokio::sync::mpsc;
+
+use super::EventType;
+use crate::{
+    core::errors::ApplicationError,
+    routes::metrics,
+    services::kafka::{get_event_timestamp, KafkaMessage},
+};
+
+/// An event as it is written to an event sink.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EventSinkRecord {
+    /// Unique identifier of the record, used to skip records that are loaded more than once
+    pub event_id: String,
+    pub event_type: EventType,
+    pub tenant_id: Option<String>,
+    pub key: String,
+    /// Time at which the event was created, in milliseconds since the unix epoch
+    pub timestamp: i64,
+    pub event: serde_json::Value,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct EventSinkBufferSettings {
+    /// Number of events that can be buffered before they are written to the sink, events are
+    /// dropped when the buffer is full
+    pub buffer_size: usize,
+    /// Maximum number of events written to the sink at a time
+    pub write_batch_size: usize,
+}
+
+impl Default for EventSinkBufferSettings {
+    fn default() -> Self {
+        Self {
+            buffer_size: 10000,
+            write_batch_size: 100,
+        }
+    }
+}
+
+impl EventSinkBufferSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        when(self.buffer_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Event sink buffer size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.write_batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Event sink write batch size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+/// Settings of the task that loads the events written to a sink into the analytics database
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct EventsLoaderSettings {
+    pub enabled: bool,
+    /// Consumer group, or durable consumer, through which the loader reads events
+    pub consumer_group: String,
+    /// Maximum number of events read from the sink at a time
+    pub batch_size: u64,
+    /// Time in milliseconds the loader waits when there are no events to load
+    pub poll_interval: u64,
+}
+
+impl Default for EventsLoaderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            consumer_group: "hyperswitch_events_loader".into(),
+            batch_size: 500,
+            poll_interval: 1000,
+        }
+    }
+}
+
+impl EventsLoaderSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.consumer_group.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Events loader consumer group must not be empty".into(),
+            ))
+        })?;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Events loader batch size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+/// Writes batches of events to a sink, routing each event by its `EventType`.
+#[async_trait::async_trait]
+pub trait EventSinkWriter: Send + 'static {
+    /// Name of the sink, used in logs and metrics
+    fn name(&self) -> &'static str;
+
+    async fn write(&mut self, records: Vec<EventSinkRecord>) -> CustomResult<(), EventsError>;
+}
+
+/// Events handler that buffers events in memory and writes them to a sink from a background
+/// task, so that logging an event never waits on the sink.
+#[derive(Debug, Clone)]
+pub struct EventSink {
+    name: &'static str,
+    sender: mpsc::Sender<EventSinkRecord>,
+    tenant_id: Option<String>,
+}
+
+impl EventSink {
+    pub fn spawn<W: EventSinkWriter>(writer: W, settings: &EventSinkBufferSettings) -> Self {
+        let name = writer.name();
+        let (sender, receiver) = mpsc::channel(settings.buffer_size);
+        tokio::spawn(run_writer(writer, receiver, settings.write_batch_size));
+
+        Self {
+            name,
+            sender,
+            tenant_id: None,
+        }
+    }
+
+    pub fn set_tenancy(&mut self, tenant_config: &dyn TenantConfig) {
+        self.tenant_id = Some(tenant_config.get_tenant_id().get_string_repr().to_owned());
+    }
+
+    pub(super) fn log_event<T: KafkaMessage>(&self, event: &T) {
+        let record = event
+            .value()
+            .and_then(|value| {
+                serde_json::from_slice(&value)
+                    .change_context(crate::services::kafka::KafkaError::GenericError)
+            })
+            .map(|value| {
+                self.new_record(
+                    event.event_type(),
+                    event.key(),
+                    get_event_timestamp(event),
+                    value,
+                )
+            });
+
+        match record {
+            Ok(record) => self.send(record),
+            Err(error) => logger::error!(?error, sink = self.name, "Failed to serialize event"),
+        }
+    }
+
+    fn new_record(
+        &self,
+        event_type: EventType,
+        key: String,
+        timestamp: i64,
+        event: serde_json::Value,
+    ) -> EventSinkRecord {
+        EventSinkRecord {
+            event_id: common_utils::generate_time_ordered_id_without_prefix(),
+            event_type,
+            tenant_id: self.tenant_id.clone(),
+            key,
+            timestamp,
+            event,
+        }
+    }
+
+    fn send(&self, record: EventSinkRecord) {
+        if let Err(error) = self.sender.try_send(record) {
+            metrics::EVENT_SINK_EVENTS_DROPPED.add(1, metric_attributes!(("sink", self.name)));
+            logger::error!(
+                ?error,
+                sink = self.name,
+                "Failed to buffer event for event sink"
+            );
+        }
+    }
+}
+
+impl MessagingInterface for EventSink {
+    type MessageClass = EventType;
+
+    fn send_message<T>(
+        &self,
+        data: T,
+        metadata: HashMap<String, String>,
+        timestamp: PrimitiveDateTime,
+    ) -> error_stack::Result<(), EventsError>
+    where
+        T: Message<Class = Self::MessageClass> + ErasedMaskSerialize,
+    {
+        let mut event = data
+            .masked_serialize()
+            .change_context(EventsError::SerializationError)?;
+        if let serde_json::Value::Object(ref mut map) = event {
+            map.extend(
+                metadata
+                    .into_iter()
+                    .map(|(key, value)| (key, serde_json::Value::String(value))),
+            );
+        }
+        let timestamp = i64::try_from(timestamp.assume_utc().unix_timestamp_nanos() / 1_000_000)
+            .unwrap_or_else(|_| timestamp.assume_utc().unix_timestamp() * 1_000);
+
+        self.send(self.new_record(
+            data.get_message_class(),
+            data.identifier(),
+            timestamp,
+            event,
+        ));
+        Ok(())
+    }
+}
+
+async fn run_writer<W: EventSinkWriter>(
+    mut writer: W,
+    mut receiver: mpsc::Receiver<EventSinkRecord>,
+    write_batch_size: usize,
+) {
+    let name = writer.name();
+    let mut records = Vec::with_capacity(write_batch_size);
+
+    // The task ends once every handle of the sink has been dropped and the buffer is drained
+    while receiver.recv_many(&mut records, write_batch_size).await > 0 {
+        let records_count = u64::try_from(records.len()).unwrap_or(u64::MAX);
+
+        match writer.write(std::mem::take(&mut records)).await {
+            Ok(()) => metrics::EVENT_SINK_EVENTS_WRITTEN
+                .add(records_count, metric_attributes!(("sink", name))),
+            Err(error) => {
+                metrics::EVENT_SINK_WRITE_FAILURES
+                    .add(records_count, metric_attributes!(("sink", name)));
+                logger::error!(?error, sink = name, "Failed to write events to event sink");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    /// Writer that hands the batches written to it to the test
+    struct TestWriter {
+        batches: mpsc::UnboundedSender<Vec<EventSinkRecord>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventSinkWriter for TestWriter {
+        fn name(&self) -> &'static str {
+            "test"
+        }
+
+        async fn write(&mut self, records: Vec<EventSinkRecord>) -> CustomResult<(), EventsError> {
+            self.batches
+                .send(records)
+                .map_err(|_| error_stack::report!(EventsError::PublishError))
+        }
+    }
+
+    fn spawn_test_sink(
+        settings: &EventSinkBufferSettings,
+    ) -> (EventSink, mpsc::UnboundedReceiver<Vec<EventSinkRecord>>) {
+        let (batches, receiver) = mpsc::unbounded_channel();
+        (EventSink::spawn(TestWriter { batches }, settings), receiver)
+    }
+
+    #[test]
+    fn test_buffer_settings_validation() {
+        assert!(EventSinkBufferSettings::default().validate().is_ok());
+
+        let settings = EventSinkBufferSettings {
+            buffer_size: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = EventSinkBufferSettings {
+            write_batch_size: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_loader_settings_validation() {
+        // Settings of a disabled loader are not validated
+        let settings = EventsLoaderSettings {
+            consumer_group: String::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+
+        let settings = EventsLoaderSettings {
+            enabled: true,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+
+        let settings = EventsLoaderSettings {
+            enabled: true,
+            consumer_group: String::new(),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = EventsLoaderSettings {
+            enabled: true,
+            batch_size: 0,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_events_are_written_in_batches() {
+        let settings = EventSinkBufferSettings {
+            buffer_size: 10,
+            write_batch_size: 2,
+        };
+        let (sink, mut receiver) = spawn_test_sink(&settings);
+
+        for index in 0..5 {
+            sink.send(sink.new_record(
+                EventType::ApiLogs,
+                format!("key_{index}"),
+                0,
+                serde_json::json!({ "index": index }),
+            ));
+        }
+        // Dropping the sink ends the writer once the buffer is drained
+        drop(sink);
+
+        let mut keys = Vec::new();
+        while let Some(batch) = receiver.recv().await {
+            assert!(!batch.is_empty() && batch.len() <= settings.write_batch_size);
+            keys.extend(batch.into_iter().map(|record| record.key));
+        }
+        assert_eq!(keys, ["key_0", "key_1", "key_2", "key_3", "key_4"]);
+    }
+
+    #[tokio::test]
+    async fn test_records_have_unique_ids() {
+        let (sink, _receiver) = spawn_test_sink(&EventSinkBufferSettings::default());
+
+        let first = sink.new_record(EventType::ApiLogs, "key".into(), 0, serde_json::Value::Null);
+        let second = sink.new_record(EventType::ApiLogs, "key".into(), 0, serde_json::Value::Null);
+
+        assert_ne!(first.event_id, second.event_id);
+        assert_eq!(first.tenant_id, None);
+    }
+}
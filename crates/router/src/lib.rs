@@ -1,4 +1,4 @@
-#[cfg(all(feature = "stripe", feature = "v1"))]
+#[cfg(all(any(feature = "stripe", feature = "adyen"), feature = "v1"))]
 pub mod compatibility;
 pub mod configs;
 pub mod connection;
@@ -80,6 +80,7 @@ pub mod headers {
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
+    pub const ADYEN_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "HmacSignature";
     pub const X_CLIENT_VERSION: &str = "X-Client-Version";
     pub const X_CLIENT_SOURCE: &str = "X-Client-Source";
     pub const X_PAYMENT_CONFIRM_SOURCE: &str = "X-Payment-Confirm-Source";
@@ -223,7 +224,8 @@ pub fn mk_app(
                 .service(routes::ConnectorOnboarding::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
-                .service(routes::FeatureMatrix::server(state.clone()));
+                .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::ProcessTracker::server(state.clone()));
         }
 
         #[cfg(feature = "v2")]
@@ -251,17 +253,38 @@ pub fn mk_app(
             .service(routes::Cards::server(state.clone()));
     }
 
+    #[cfg(all(feature = "adyen", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::AdyenApis::server(state.clone()));
+    }
+
     #[cfg(all(feature = "oltp", feature = "v2"))]
     {
         server_app = server_app.service(routes::Proxy::server(state.clone()));
     }
 
+    #[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::FraudCheck::server(state.clone()));
+    }
+
     #[cfg(all(feature = "recon", feature = "v1"))]
     {
         server_app = server_app.service(routes::Recon::server(state.clone()));
     }
 
+    #[cfg(all(feature = "olap", feature = "email", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::EmailOutbox::server(state.clone()));
+    }
+
+    #[cfg(all(feature = "olap", feature = "v1"))]
+    {
+        server_app = server_app.service(routes::KafkaEvents::server(state.clone()));
+    }
+
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::LogControl::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
 
     server_app
@@ -281,6 +304,9 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
         errors::ApplicationError::ApiClientError(error.current_context().clone())
     })?);
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
+    services::kafka::outbox::spawn_outbox_relays(&state)?;
+    #[cfg(feature = "olap")]
+    events::loader::spawn_events_loader(&state).await?;
     let request_body_limit = server.request_body_limit;
 
     let server_builder =
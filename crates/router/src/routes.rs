@@ -17,6 +17,8 @@ pub mod customers;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
+#[cfg(all(feature = "olap", feature = "email"))]
+pub mod email_outbox;
 pub mod ephemeral_key;
 pub mod feature_matrix;
 pub mod files;
@@ -25,9 +27,12 @@ pub mod fraud_check;
 pub mod gsm;
 pub mod health;
 pub mod hypersense;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod kafka_events;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
+pub mod log_control;
 pub mod mandates;
 pub mod metrics;
 #[cfg(feature = "v1")]
@@ -85,6 +90,12 @@ pub mod chat;
 
 #[cfg(feature = "dummy_connector")]
 pub use self::app::DummyConnector;
+#[cfg(all(feature = "olap", feature = "email", feature = "v1"))]
+pub use self::app::EmailOutbox;
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+pub use self::app::FraudCheck;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::KafkaEvents;
 #[cfg(feature = "v2")]
 pub use self::app::PaymentMethodSession;
 #[cfg(all(feature = "oltp", feature = "v2"))]
@@ -94,8 +105,8 @@ pub use self::app::Recon;
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Authentication, Cache, Cards, Chat, Configs,
     ConnectorOnboarding, Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm,
-    Health, Hypersense, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
-    PaymentMethods, Payments, Poll, ProcessTracker, ProcessTrackerDeprecated, Profile,
+    Health, Hypersense, LogControl, Mandates, MerchantAccount, MerchantConnectorAccount,
+    PaymentLink, PaymentMethods, Payments, Poll, ProcessTracker, ProcessTrackerDeprecated, Profile,
     ProfileAcquirer, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, ThreeDsDecisionRule,
     User, UserDeprecated, Webhooks,
 };
@@ -105,6 +116,8 @@ pub use self::app::{Blocklist, Organization, Routing, Subscription, Verify, Webh
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(feature = "v2")]
 pub use self::app::{RecoveryDataBackfill, Tokenization};
+#[cfg(all(feature = "adyen", feature = "v1"))]
+pub use super::compatibility::adyen::AdyenApis;
 #[cfg(all(feature = "stripe", feature = "v1"))]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
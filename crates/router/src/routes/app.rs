@@ -19,6 +19,7 @@ use external_services::email::{
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 use external_services::grpc_client::revenue_recovery::GrpcRecoveryHeaders;
 use external_services::{
+    file_scanner::FileScannerInterface,
     file_storage::FileStorageInterface,
     grpc_client::{GrpcClients, GrpcHeaders, GrpcHeadersUcs, GrpcHeadersUcsBuilderInitial},
     superposition::SuperpositionClient,
@@ -68,7 +69,7 @@ use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_ve
 use super::webhooks::*;
 use super::{
     admin, api_keys, cache::*, chat, connector_onboarding, disputes, files, gsm, health::*,
-    profiles, relay, user, user_role,
+    log_control, profiles, relay, user, user_role,
 };
 #[cfg(feature = "v1")]
 use super::{
@@ -90,11 +91,15 @@ use crate::routes::authentication;
 use crate::routes::cards_info::{
     card_iin_info, create_cards_info, migrate_cards_info, update_cards_info,
 };
+#[cfg(all(feature = "olap", feature = "email"))]
+use crate::routes::email_outbox as email_outbox_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::feature_matrix;
-#[cfg(all(feature = "frm", feature = "oltp"))]
+#[cfg(all(feature = "frm", any(feature = "oltp", feature = "olap")))]
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "olap", feature = "v1"))]
+use crate::routes::kafka_events as kafka_events_routes;
+#[cfg(all(feature = "olap", feature = "v1"))]
 use crate::routes::profile_acquirer;
 #[cfg(all(feature = "recon", feature = "olap"))]
 use crate::routes::recon as recon_routes;
@@ -133,6 +138,7 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub file_scanner_client: Arc<dyn FileScannerInterface>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
@@ -308,6 +314,7 @@ pub struct AppState {
     pub opensearch_client: Option<Arc<OpenSearchClient>>,
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub file_scanner_client: Arc<dyn FileScannerInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub grpc_client: Arc<GrpcClients>,
     pub theme_storage_client: Arc<dyn FileStorageInterface>,
@@ -422,6 +429,7 @@ impl AppState {
             #[allow(clippy::expect_used)]
             let event_handler = conf
                 .events
+                .get_inner()
                 .get_event_handler()
                 .await
                 .expect("Failed to create event handler");
@@ -474,7 +482,12 @@ impl AppState {
             #[cfg(feature = "email")]
             let email_client = Arc::new(create_email_client(&conf).await);
 
-            let file_storage_client = conf.file_storage.get_file_storage_client().await;
+            let file_storage_client = conf
+                .file_storage
+                .get_inner()
+                .get_file_storage_client()
+                .await;
+            let file_scanner_client = conf.file_validation.scanner.get_file_scanner_client();
             let theme_storage_client = conf.theme.storage.get_file_storage_client().await;
             let crm_client = conf.crm.get_crm_client().await;
 
@@ -514,6 +527,7 @@ impl AppState {
                 opensearch_client,
                 request_id: None,
                 file_storage_client,
+                file_scanner_client,
                 encryption_client,
                 grpc_client,
                 theme_storage_client,
@@ -572,7 +586,7 @@ impl AppState {
                     )
                     .await,
                 ),
-                EventsHandler::Logs(_) => Box::new(
+                EventsHandler::Sink(_) | EventsHandler::Logs(_) => Box::new(
                     #[allow(clippy::expect_used)]
                     get_store(
                         conf,
@@ -633,6 +647,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            file_scanner_client: self.file_scanner_client.clone(),
             request_id: self.request_id.clone(),
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -1305,7 +1320,9 @@ impl Subscription {
             ))
             .service(web::resource("/estimate").route(web::get().to(subscription::get_estimate)))
             .service(
-                web::resource("/plans").route(web::get().to(subscription::get_subscription_plans)),
+                web::resource("/plans")
+                    .route(web::get().to(subscription::get_subscription_plans))
+                    .route(web::post().to(subscription::create_subscription_plan)),
             )
             .service(web::resource("/list").route(web::get().to(subscription::list_subscriptions)))
             .service(
@@ -1528,9 +1545,23 @@ impl Payouts {
                 .service(
                     web::resource("/profile/filter")
                         .route(web::post().to(payouts_list_available_filters_for_profile)),
-                );
+                )
+                .service(web::resource("/batch/list").route(web::get().to(payout_batch_list)));
         }
         route = route
+            .service(web::resource("/batch").route(web::post().to(payout_batch_create)))
+            .service(
+                web::resource("/batch/csv").route(web::post().to(payout_batch_create_from_csv)),
+            )
+            .service(web::resource("/batch/{batch_id}").route(web::get().to(payout_batch_retrieve)))
+            .service(
+                web::resource("/batch/{batch_id}/items")
+                    .route(web::get().to(payout_batch_items_list)),
+            )
+            .service(
+                web::resource("/batch/{batch_id}/cancel")
+                    .route(web::post().to(payout_batch_cancel)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1756,6 +1787,52 @@ impl Recon {
                 web::resource("/verify_token")
                     .route(web::get().to(recon_routes::verify_recon_token)),
             )
+            .service(
+                web::resource("/settlements")
+                    .route(web::post().to(recon_routes::ingest_settlement_report))
+                    .route(web::get().to(recon_routes::list_settlement_reports)),
+            )
+            .service(
+                web::resource("/settlements/{report_id}")
+                    .route(web::get().to(recon_routes::retrieve_settlement_report)),
+            )
+            .service(
+                web::resource("/results").route(web::get().to(recon_routes::list_recon_results)),
+            )
+            .service(
+                web::resource("/results/export")
+                    .route(web::get().to(recon_routes::export_recon_results)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "email", feature = "v1"))]
+pub struct EmailOutbox;
+
+#[cfg(all(feature = "olap", feature = "email", feature = "v1"))]
+impl EmailOutbox {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/email")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/events")
+                    .route(web::post().to(email_outbox_routes::record_email_delivery_event)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct KafkaEvents;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl KafkaEvents {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/kafka_events")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/backfill")
+                    .route(web::post().to(kafka_events_routes::backfill_kafka_events)),
+            )
     }
 }
 
@@ -1800,6 +1877,22 @@ impl Blocklist {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+pub struct FraudCheck;
+
+#[cfg(all(feature = "olap", feature = "frm", feature = "v1"))]
+impl FraudCheck {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/fraud_check")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/native_config")
+                    .route(web::get().to(frm_routes::retrieve_native_frm_config))
+                    .route(web::post().to(frm_routes::update_native_frm_config)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Organization;
 
@@ -2232,6 +2325,15 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/{dispute_id}/bundle")
+                    .route(web::post().to(disputes::generate_dispute_evidence_bundle)),
+            )
+            .service(
+                web::resource("/automation_config")
+                    .route(web::get().to(disputes::retrieve_dispute_automation_config))
+                    .route(web::post().to(disputes::update_dispute_automation_config)),
+            )
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
@@ -2282,6 +2384,28 @@ impl Cache {
     }
 }
 
+pub struct LogControl;
+
+impl LogControl {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/log_control")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(log_control::retrieve_log_control)))
+            .service(
+                web::resource("/filter")
+                    .route(web::put().to(log_control::update_log_filter))
+                    .route(web::delete().to(log_control::reset_log_filter)),
+            )
+            .service(
+                web::resource("/debug").route(web::post().to(log_control::create_debug_logging)),
+            )
+            .service(
+                web::resource("/debug/{id}")
+                    .route(web::delete().to(log_control::delete_debug_logging)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -3114,6 +3238,22 @@ impl ProcessTrackerDeprecated {
 #[cfg(feature = "olap")]
 pub struct ProcessTracker;
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        use super::process_tracker::tasks;
+        web::scope("/process_tracker/tasks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::post().to(tasks::list_tasks)))
+            .service(web::resource("/bulk").route(web::post().to(tasks::perform_bulk_task_action)))
+            .service(web::resource("/{task_id}").route(web::get().to(tasks::retrieve_task)))
+            .service(
+                web::resource("/{task_id}/action")
+                    .route(web::post().to(tasks::perform_task_action)),
+            )
+    }
+}
+
 #[cfg(all(feature = "olap", feature = "v2"))]
 impl ProcessTracker {
     pub fn server(state: AppState) -> Scope {
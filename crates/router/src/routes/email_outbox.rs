@@ -0,0 +1,48 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, email_outbox},
+    services::{api, authentication as auth},
+};
+
+/// Email - Record Delivery Event
+///
+/// To record the bounces and complaints Amazon SES publishes to the configured SNS topic for emails
+/// sent through the email outbox. No further emails are sent to a recipient with a recorded bounce
+/// or complaint. The request is authenticated by its SNS signature rather than an API key, and
+/// subscription confirmations from the topic are confirmed.
+#[utoipa::path(
+    post,
+    path = "/email/events",
+    request_body(
+        content = SnsMessage,
+        description = "Message published by Amazon SES to the configured SNS topic, delivered by SNS as `text/plain`",
+    ),
+    responses(
+        (status = 200, description = "Delivery events recorded for the recipients of sent emails", body = Vec<EmailDeliveryEventResponse>),
+        (status = 400, description = "Invalid SNS message or SES notification"),
+        (status = 401, description = "Message is not from the configured topic or its SNS signature is invalid")
+    ),
+    tag = "Email",
+    operation_id = "Record Email Delivery Event",
+)]
+#[instrument(skip_all, fields(flow = ?Flow::EmailDeliveryEventRecord))]
+pub async fn record_email_delivery_event(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> impl Responder {
+    let flow = Flow::EmailDeliveryEventRecord;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| email_outbox::record_email_delivery_event(state, body.clone()),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,11 +1,17 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::Flow;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use router_env::{instrument, tracing};
 
 use crate::{
     core::{api_locking, fraud_check as frm_core},
     services::{self, api},
     AppState,
 };
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::{
+    services::authorization::permissions::Permission, types::api::fraud_check::NativeFrmConfig,
+};
 
 #[cfg(feature = "v1")]
 pub async fn frm_fulfillment(
@@ -31,3 +37,64 @@ pub async fn frm_fulfillment(
     ))
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::NativeFrmConfigRetrieve))]
+pub async fn retrieve_native_frm_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::NativeFrmConfigRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: services::authentication::AuthenticationData, _, _| {
+            frm_core::native::retrieve_native_frm_config(state, auth.into())
+        },
+        services::authentication::auth_type(
+            &services::authentication::HeaderAuth(services::authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &services::authentication::JWTAuth {
+                permission: Permission::MerchantConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::NativeFrmConfigUpdate))]
+pub async fn update_native_frm_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<NativeFrmConfig>,
+) -> HttpResponse {
+    let flow = Flow::NativeFrmConfigUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: services::authentication::AuthenticationData, req, _| {
+            frm_core::native::update_native_frm_config(state, auth.into(), req)
+        },
+        services::authentication::auth_type(
+            &services::authentication::HeaderAuth(services::authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &services::authentication::JWTAuth {
+                permission: Permission::MerchantConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
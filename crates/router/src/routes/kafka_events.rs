@@ -0,0 +1,28 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::kafka_events as kafka_events_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, kafka_events},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::KafkaEventsBackfill))]
+pub async fn backfill_kafka_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<kafka_events_api::KafkaEventsBackfillRequest>,
+) -> impl Responder {
+    let flow = Flow::KafkaEventsBackfill;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| kafka_events::backfill_events(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -31,6 +31,9 @@ pub enum ApiIdentifier {
     Forex,
     RustLockerMigration,
     Gsm,
+    EmailOutbox,
+    KafkaEvents,
+    LogControl,
     Role,
     User,
     UserRole,
@@ -94,6 +97,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::UpdateSubscription
             | Flow::GetSubscriptionEstimate
             | Flow::GetPlansForSubscription
+            | Flow::CreateSubscriptionPlan
             | Flow::PauseSubscription
             | Flow::ResumeSubscription
             | Flow::CancelSubscription => Self::Subscription,
@@ -106,7 +110,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::NativeFrmConfigRetrieve
+            | Flow::NativeFrmConfigUpdate => Self::MerchantConnector,
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
             | Flow::ConfigKeyUpdate
@@ -187,7 +193,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
-            | Flow::PayoutLinkInitiate => Self::Payouts,
+            | Flow::PayoutLinkInitiate
+            | Flow::PayoutBatchCreate
+            | Flow::PayoutBatchRetrieve
+            | Flow::PayoutBatchItemsList
+            | Flow::PayoutBatchCancel
+            | Flow::PayoutBatchList => Self::Payouts,
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
             | Flow::RefundsRetrieveForceSync
@@ -217,6 +228,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
+            | Flow::DisputesEvidenceBundle
+            | Flow::DisputeAutomationConfigRetrieve
+            | Flow::DisputeAutomationConfigUpdate
             | Flow::DeleteDisputeEvidence => Self::Disputes,
             Flow::CardsInfo
             | Flow::CardsInfoCreate
@@ -242,6 +256,13 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
             | Flow::GsmRuleDelete => Self::Gsm,
+            Flow::EmailDeliveryEventRecord => Self::EmailOutbox,
+            Flow::KafkaEventsBackfill => Self::KafkaEvents,
+            Flow::LogControlRetrieve
+            | Flow::LogFilterUpdate
+            | Flow::LogFilterReset
+            | Flow::DebugLoggingCreate
+            | Flow::DebugLoggingDelete => Self::LogControl,
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
             Flow::UserConnectAccount
             | Flow::UserSignUp
@@ -335,7 +356,12 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::SettlementReportIngest
+            | Flow::SettlementReportRetrieve
+            | Flow::SettlementReportList
+            | Flow::ReconResultsList
+            | Flow::ReconResultsExport => Self::Recon,
             Flow::RetrievePollStatus => Self::Poll,
             Flow::FeatureMatrix => Self::Documentation,
             Flow::TokenizeCard
@@ -350,7 +376,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionUpdateSavedPaymentMethod
             | Flow::PaymentMethodSessionDeleteSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
-            Flow::RevenueRecoveryRetrieve | Flow::RevenueRecoveryResume => Self::ProcessTracker,
+            Flow::RevenueRecoveryRetrieve
+            | Flow::RevenueRecoveryResume
+            | Flow::ProcessTrackerTaskList
+            | Flow::ProcessTrackerTaskRetrieve
+            | Flow::ProcessTrackerTaskUpdate
+            | Flow::ProcessTrackerTaskBulkUpdate => Self::ProcessTracker,
             Flow::AuthenticationCreate
             | Flow::AuthenticationEligibility
             | Flow::AuthenticationSync
@@ -0,0 +1,99 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::log_control as log_control_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, log_control},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::LogControlRetrieve))]
+pub async fn retrieve_log_control(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::LogControlRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| log_control::retrieve_log_control(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterUpdate))]
+pub async fn update_log_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<log_control_api::LogFilterUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::LogFilterUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| log_control::update_log_filter(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterReset))]
+pub async fn reset_log_filter(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::LogFilterReset;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| log_control::reset_log_filter(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DebugLoggingCreate))]
+pub async fn create_debug_logging(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<log_control_api::DebugLoggingCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::DebugLoggingCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| log_control::create_debug_logging(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DebugLoggingDelete))]
+pub async fn delete_debug_logging(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::DebugLoggingDelete;
+    let payload = log_control_api::DebugLoggingDeleteRequest {
+        id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| log_control::delete_debug_logging(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,7 +1,9 @@
 pub mod bg_metrics_collector;
 pub mod request;
 
-use router_env::{counter_metric, global_meter, histogram_metric_f64};
+use router_env::{
+    counter_metric, gauge_metric, global_meter, histogram_metric_f64, histogram_metric_u64,
+};
 
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
@@ -146,3 +148,23 @@ counter_metric!(PAYMENT_METHOD_TYPES_MISCONFIGURATION_METRIC, GLOBAL_METER);
 
 // AI chat metric to track number of chat request
 counter_metric!(CHAT_REQUEST_COUNT, GLOBAL_METER);
+
+// Kafka outbox metrics
+counter_metric!(KAFKA_OUTBOX_EVENTS_ENQUEUED, GLOBAL_METER);
+counter_metric!(KAFKA_OUTBOX_ENQUEUE_FAILURES, GLOBAL_METER);
+counter_metric!(KAFKA_OUTBOX_EVENTS_PUBLISHED, GLOBAL_METER);
+counter_metric!(KAFKA_OUTBOX_PUBLISH_FAILURES, GLOBAL_METER);
+counter_metric!(KAFKA_OUTBOX_EVENTS_DEAD_LETTERED, GLOBAL_METER);
+counter_metric!(KAFKA_OUTBOX_PENDING_WRITES_PUBLISHED, GLOBAL_METER);
+// Time in seconds between an event being added to the outbox and it being published to Kafka
+histogram_metric_u64!(KAFKA_OUTBOX_RELAY_DELAY_SECONDS, GLOBAL_METER);
+// No. of events in the outbox that are yet to be published to Kafka
+gauge_metric!(KAFKA_OUTBOX_PENDING_EVENTS, GLOBAL_METER);
+
+// Event sink metrics
+counter_metric!(EVENT_SINK_EVENTS_WRITTEN, GLOBAL_METER);
+counter_metric!(EVENT_SINK_WRITE_FAILURES, GLOBAL_METER); // No. of events that could not be written
+counter_metric!(EVENT_SINK_EVENTS_DROPPED, GLOBAL_METER); // No. of events dropped as the buffer was full
+counter_metric!(EVENTS_LOADER_EVENTS_LOADED, GLOBAL_METER);
+counter_metric!(EVENTS_LOADER_EVENTS_SKIPPED, GLOBAL_METER);
+counter_metric!(EVENTS_LOADER_FAILURES, GLOBAL_METER);
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{
     body::{BoxBody, MessageBody},
     web, HttpRequest, HttpResponse, Responder,
@@ -243,6 +245,198 @@ pub async fn payouts_fulfill(
     .await
 }
 
+/// Payouts - Batch Create
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            batch::payout_batch_create_core(state, platform, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Create from CSV
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCreate))]
+pub async fn payout_batch_create_from_csv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    MultipartForm(form): MultipartForm<batch::PayoutBatchCsvForm>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCreate;
+    let payload = match batch::get_payout_batch_create_request_from_csv(form) {
+        Ok(payload) => payload,
+        Err(e) => return api::log_and_return_error_response(e.into()),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let platform = auth.into();
+            batch::payout_batch_create_core(state, platform, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Retrieve
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchRetrieve))]
+pub async fn payout_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let platform = auth.into();
+            batch::payout_batch_retrieve_core(state, platform, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Items List
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchItemsList))]
+pub async fn payout_batch_items_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_params: web::Query<payout_types::PayoutBatchItemsListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchItemsList;
+    let batch_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            let platform = auth.into();
+            batch::payout_batch_items_list_core(state, platform, batch_id.clone(), constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Cancel
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchCancel))]
+pub async fn payout_batch_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchCancel;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            let platform = auth.into();
+            batch::payout_batch_cancel_core(state, platform, batch_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        }),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch List
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutBatchList))]
+pub async fn payout_batch_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_params: web::Query<payout_types::PayoutBatchListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::PayoutBatchList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            let platform = auth.into();
+            batch::payout_batch_list_core(state, platform, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPayoutRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - List
 #[cfg(feature = "olap")]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
@@ -74,6 +74,15 @@ pub async fn exchange_token(
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
+
+    let header_payload =
+        match hyperswitch_domain_models::payments::HeaderPayload::foreign_try_from(req.headers()) {
+            Ok(headers) => headers,
+            Err(err) => {
+                return api::log_and_return_error_response(err);
+            }
+        };
+
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -81,7 +90,12 @@ pub async fn exchange_token(
         payload,
         |state, auth, payload, _| {
             let platform = auth.into();
-            crate::core::pm_auth::exchange_token_core(state, platform, payload)
+            crate::core::pm_auth::exchange_token_core(
+                state,
+                platform,
+                payload,
+                Some(header_payload.clone()),
+            )
         },
         &*auth,
         api_locking::LockAction::NotApplicable,
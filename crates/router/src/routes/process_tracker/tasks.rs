@@ -0,0 +1,95 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::process_tracker::tasks as tasks_api;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, process_tracker},
+    routes::AppState,
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskList))]
+pub async fn list_tasks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<tasks_api::ProcessTrackerTaskListRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, _| process_tracker::list_tasks(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskRetrieve))]
+pub async fn retrieve_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskRetrieve;
+    let payload = tasks_api::ProcessTrackerTaskId {
+        task_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::retrieve_task(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskUpdate))]
+pub async fn perform_task_action(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<tasks_api::ProcessTrackerTaskActionRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.task_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, req_state| {
+            process_tracker::perform_task_action(state, req_state, payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskBulkUpdate))]
+pub async fn perform_bulk_task_action(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<tasks_api::ProcessTrackerBulkActionRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskBulkUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload, req_state| {
+            process_tracker::perform_bulk_task_action(state, req_state, payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
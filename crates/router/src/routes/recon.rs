@@ -77,3 +77,153 @@ pub async fn verify_recon_token(state: web::Data<AppState>, http_req: HttpReques
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn ingest_settlement_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_api::SettlementReportIngestRequest>,
+) -> HttpResponse {
+    let flow = Flow::SettlementReportIngest;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: authentication::AuthenticationData, req, _| {
+            recon::settlement::ingest_settlement_report(state, auth.into(), req)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantRunReconWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_settlement_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::SettlementReportRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: authentication::AuthenticationData, report_id, _| {
+            recon::settlement::retrieve_settlement_report(state, auth.into(), report_id)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn list_settlement_reports(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::SettlementReportListQuery>,
+) -> HttpResponse {
+    let flow = Flow::SettlementReportList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: authentication::AuthenticationData, query, _| {
+            recon::settlement::list_settlement_reports(state, auth.into(), query)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn list_recon_results(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::ReconResultListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ReconResultsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: authentication::AuthenticationData, query, _| {
+            recon::settlement::list_recon_results(state, auth.into(), query)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn export_recon_results(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::ReconResultListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ReconResultsExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: authentication::AuthenticationData, query, _| {
+            recon::settlement::export_recon_results(state, auth.into(), query)
+        },
+        authentication::auth_type(
+            &authentication::HeaderAuth(authentication::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &authentication::JWTAuth {
+                permission: Permission::MerchantReconReportsRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
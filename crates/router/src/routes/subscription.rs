@@ -292,6 +292,47 @@ pub async fn get_subscription_plans(
     .await
 }
 
+#[instrument(skip_all)]
+pub async fn create_subscription_plan(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<subscription_types::CreateSubscriptionPlanRequest>,
+) -> impl Responder {
+    let flow = Flow::CreateSubscriptionPlan;
+    let profile_id = match extract_profile_id(&req) {
+        Ok(id) => id,
+        Err(response) => return response,
+    };
+
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        move |state, auth: auth::AuthenticationData, payload, _| {
+            let platform = auth.into();
+            subscriptions::create_subscription_plan(
+                state.into(),
+                platform,
+                profile_id.clone(),
+                payload,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            }),
+            &auth::JWTAuth {
+                permission: Permission::ProfileSubscriptionWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Add support for get subscription by id
 #[instrument(skip_all)]
 pub async fn get_subscription(
@@ -152,7 +152,7 @@ pub enum AuthFlow {
 #[allow(clippy::too_many_arguments)]
 #[instrument(
     skip(request, payload, state, func, api_auth, incoming_request_header),
-    fields(merchant_id)
+    fields(merchant_id, profile_id)
 )]
 pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     flow: &'a impl router_env::types::FlowMetric,
@@ -256,6 +256,9 @@ where
     app_state.add_flow_name(flow.to_string());
 
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
+    if let Some(profile_id) = auth_type.get_profile_id() {
+        tracing::Span::current().record("profile_id", profile_id.get_string_repr().to_owned());
+    }
 
     let output = {
         lock_action
@@ -230,6 +230,25 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    pub fn get_profile_id(&self) -> Option<&id_type::ProfileId> {
+        match self {
+            Self::MerchantJwtWithProfileId { profile_id, .. }
+            | Self::InternalMerchantIdProfileId { profile_id, .. } => profile_id.as_ref(),
+            Self::ApiKey { .. }
+            | Self::AdminApiKey
+            | Self::AdminApiAuthWithMerchantId { .. }
+            | Self::OrganizationJwt { .. }
+            | Self::MerchantJwt { .. }
+            | Self::UserJwt { .. }
+            | Self::SinglePurposeJwt { .. }
+            | Self::SinglePurposeOrLoginJwt { .. }
+            | Self::MerchantId { .. }
+            | Self::PublishableKey { .. }
+            | Self::WebhookAuth { .. }
+            | Self::NoAuth => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, serde::Deserialize, strum::Display)]
@@ -706,6 +725,43 @@ where
     }
 }
 
+/// API key authentication for Adyen client libraries, which send the API key in the `X-API-Key`
+/// header instead of the `api-key` header
+#[cfg(all(feature = "adyen", feature = "v1"))]
+#[derive(Debug, Default)]
+pub struct AdyenApiKeyAuth;
+
+#[cfg(all(feature = "adyen", feature = "v1"))]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for AdyenApiKeyAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let mut request_headers = request_headers.clone();
+        if !request_headers.contains_key(headers::API_KEY) {
+            if let Some(api_key) = request_headers.get(headers::X_API_KEY).cloned() {
+                request_headers.insert(
+                    actix_web::http::header::HeaderName::from_static("api-key"),
+                    api_key,
+                );
+            }
+        }
+
+        let api_auth = ApiKeyAuth {
+            is_connected_allowed: false,
+            is_platform_allowed: false,
+        };
+        api_auth
+            .authenticate_and_fetch(&request_headers, state)
+            .await
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct PlatformOrgAdminAuth {
     pub is_admin_auth_allowed: bool,
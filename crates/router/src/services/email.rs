@@ -1 +1,2 @@
+pub mod outbox;
 pub mod types;
@@ -0,0 +1,419 @@
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    encryption::Encryption,
+    errors::CustomResult,
+    pii,
+};
+use error_stack::ResultExt;
+use external_services::email::{
+    EmailContents, EmailData, EmailError, EmailResult, IntermediateString,
+};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::{metrics::TASKS_ADDED_COUNT, SessionState},
+    services::email::types::OutboxEmailData,
+    types::storage::{self, enums},
+    utils::user::{self as user_utils, theme as theme_utils},
+};
+
+const EMAIL_OUTBOX_TASK: &str = "EMAIL_OUTBOX";
+
+/// Renders the email and queues it in the email outbox, from where it is sent by the
+/// `EmailOutboxWorkflow`. An email to a recipient for whom an earlier email bounced or was
+/// complained about is recorded as suppressed, and is not sent.
+pub async fn enqueue_email(
+    state: &SessionState,
+    email_data: Box<dyn OutboxEmailData + Send>,
+) -> RouterResult<storage::EmailOutbox> {
+    let email_type = email_data.get_email_type();
+    let theme_id = email_data.get_theme_id().map(ToOwned::to_owned);
+    let locale = get_preferred_locale(&state.locale).map(ToOwned::to_owned);
+
+    let custom_template = match theme_id.as_deref() {
+        Some(theme_id) => {
+            get_custom_template(
+                state,
+                theme_id,
+                locale.as_deref(),
+                email_data.get_template_name(),
+            )
+            .await
+        }
+        None => None,
+    };
+
+    let EmailContents {
+        subject,
+        body,
+        recipient,
+    } = email_data
+        .get_email_data_with_template(user_utils::get_base_url(state), custom_template.as_deref())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build email contents")?;
+
+    let db = &*state.store;
+    let is_recipient_suppressed = is_recipient_suppressed(db, &recipient)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to look up the delivery history of the recipient")?;
+
+    // Suppressed emails are never sent, so their contents are not kept
+    let body = if is_recipient_suppressed {
+        None
+    } else {
+        let encrypted_body = GcmAes256
+            .encode_message(db.get_master_key(), body.into_inner().as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt email body")?;
+        Some(Encryption::new(encrypted_body.into()))
+    };
+
+    let now = common_utils::date_time::now();
+    let email_outbox = db
+        .insert_email_outbox_entry(storage::EmailOutboxNew {
+            id: common_utils::generate_id(consts::ID_LENGTH, "email_outbox"),
+            recipient,
+            subject,
+            body,
+            email_type,
+            status: if is_recipient_suppressed {
+                enums::EmailOutboxStatus::Suppressed
+            } else {
+                enums::EmailOutboxStatus::Pending
+            },
+            theme_id,
+            locale,
+            retry_count: 0,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert email outbox entry")?;
+
+    if is_recipient_suppressed {
+        logger::warn!(
+            outbox_id = %email_outbox.id,
+            "Email not sent as an earlier email to the recipient bounced or was complained about"
+        );
+    }
+
+    // The task of a suppressed email only removes the entry once its retention period is over
+    add_email_outbox_task(db, &email_outbox)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule email outbox task")?;
+
+    Ok(email_outbox)
+}
+
+/// Sends an email from the email outbox as it was rendered when it was queued. The body is stored
+/// encrypted with the master key, as it may contain tokens such as password reset links.
+pub async fn send_outbox_email(
+    state: &SessionState,
+    email_outbox: &storage::EmailOutbox,
+) -> EmailResult<()> {
+    let encrypted_body = email_outbox
+        .body
+        .clone()
+        .ok_or(EmailError::ContentBuildFailure)
+        .attach_printable("Body of the email is no longer stored in the email outbox")?;
+    let body = GcmAes256
+        .decode_message(state.store.get_master_key(), encrypted_body.into_inner())
+        .change_context(EmailError::ContentBuildFailure)
+        .attach_printable("Failed to decrypt email body")
+        .and_then(|body| {
+            String::from_utf8(body)
+                .change_context(EmailError::ContentBuildFailure)
+                .attach_printable("Decrypted email body is not valid UTF-8")
+        })?;
+    state
+        .email_client
+        .compose_and_send_email(
+            user_utils::get_base_url(state),
+            Box::new(OutboxEmail {
+                recipient: email_outbox.recipient.clone(),
+                subject: email_outbox.subject.clone(),
+                body,
+            }),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+}
+
+/// Whether an earlier email to the recipient bounced or was complained about, in which case no
+/// further emails are sent to the recipient
+pub async fn is_recipient_suppressed(
+    db: &dyn StorageInterface,
+    recipient: &pii::Email,
+) -> CustomResult<bool, errors::StorageError> {
+    db.list_email_outbox_entries_by_recipient_and_statuses(
+        recipient,
+        vec![
+            enums::EmailOutboxStatus::Bounced,
+            enums::EmailOutboxStatus::Complained,
+        ],
+        1,
+    )
+    .await
+    .map(|email_outbox_entries| !email_outbox_entries.is_empty())
+}
+
+async fn add_email_outbox_task(
+    db: &dyn StorageInterface,
+    email_outbox: &storage::EmailOutbox,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = enums::ProcessTrackerRunner::EmailOutboxWorkflow;
+    let process_tracker_id = format!("{runner}_{EMAIL_OUTBOX_TASK}_{}", email_outbox.id);
+    let tracking_data = storage::EmailOutboxTrackingData {
+        outbox_id: email_outbox.id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        EMAIL_OUTBOX_TASK,
+        runner,
+        ["EMAIL", "OUTBOX"],
+        tracking_data,
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .map_err(errors::StorageError::from)?;
+    db.insert_process(process_tracker_entry).await?;
+    TASKS_ADDED_COUNT.add(1, router_env::metric_attributes!(("flow", "email_outbox")));
+    Ok(())
+}
+
+/// Whether an email which reached a final status has been kept for the retention period, after
+/// which it is removed from the email outbox. Bounces and complaints are kept, as they suppress
+/// further emails to the recipient.
+pub fn is_retention_period_over(
+    email_outbox: &storage::EmailOutbox,
+    retention_period: time::Duration,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    email_outbox.status != enums::EmailOutboxStatus::Pending
+        && !email_outbox.status.is_undeliverable()
+        && get_retention_deadline(email_outbox, retention_period) <= now
+}
+
+/// Time at which an email is removed from the email outbox, counted from when it was last sent
+/// or attempted
+pub fn get_retention_deadline(
+    email_outbox: &storage::EmailOutbox,
+    retention_period: time::Duration,
+) -> time::PrimitiveDateTime {
+    email_outbox
+        .sent_at
+        .unwrap_or(email_outbox.modified_at)
+        .saturating_add(retention_period)
+}
+
+/// Picks the most preferred locale from an `Accept-Language` header value. Locales are used to
+/// build file keys, so anything which is not a plain language tag is ignored.
+fn get_preferred_locale(accept_language: &str) -> Option<&str> {
+    accept_language
+        .split(',')
+        .next()
+        .and_then(|language_range| language_range.split(';').next())
+        .map(str::trim)
+        .filter(|locale| {
+            !locale.is_empty()
+                && locale.len() <= 32
+                && locale
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '-')
+        })
+}
+
+/// Keys of the customized templates of a theme in the order they are looked up in: the exact
+/// locale, the language of the locale and finally the template without any locale
+fn get_custom_template_file_keys(
+    theme_id: &str,
+    locale: Option<&str>,
+    template_name: &str,
+) -> Vec<std::path::PathBuf> {
+    let mut locales = Vec::new();
+    if let Some(locale) = locale {
+        locales.push(locale);
+        if let Some((language, _)) = locale.split_once('-') {
+            locales.push(language);
+        }
+    }
+
+    locales
+        .into_iter()
+        .map(|locale| format!("email_templates/{locale}/{template_name}.html"))
+        .chain(std::iter::once(format!(
+            "email_templates/{template_name}.html"
+        )))
+        .map(|file_name| theme_utils::get_specific_file_key(theme_id, &file_name))
+        .collect()
+}
+
+/// Retrieves the customized template of the theme for the locale. The built-in template is used
+/// when the theme has no customized template, so failures to retrieve one are not errors.
+async fn get_custom_template(
+    state: &SessionState,
+    theme_id: &str,
+    locale: Option<&str>,
+    template_name: &str,
+) -> Option<String> {
+    for file_key in get_custom_template_file_keys(theme_id, locale, template_name) {
+        let Ok(template) = theme_utils::retrieve_file_from_theme_bucket(state, &file_key).await
+        else {
+            continue;
+        };
+
+        match String::from_utf8(template) {
+            Ok(template) => return Some(template),
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    ?file_key,
+                    "Ignoring customized email template which is not valid UTF-8"
+                );
+            }
+        }
+    }
+    None
+}
+
+/// Contents of an email from the email outbox, which are sent as they are
+struct OutboxEmail {
+    recipient: pii::Email,
+    subject: String,
+    body: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for OutboxEmail {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: IntermediateString::new(self.body.clone()),
+            recipient: self.recipient.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_get_preferred_locale() {
+        assert_eq!(get_preferred_locale("en"), Some("en"));
+        assert_eq!(
+            get_preferred_locale("fr-CA,fr;q=0.9,en;q=0.8"),
+            Some("fr-CA")
+        );
+        assert_eq!(get_preferred_locale(" de ;q=0.5"), Some("de"));
+        assert_eq!(get_preferred_locale("../../secrets"), None);
+        assert_eq!(get_preferred_locale(""), None);
+    }
+
+    #[test]
+    fn test_get_custom_template_file_keys() {
+        let file_keys = get_custom_template_file_keys("theme_1", Some("fr-CA"), "reset");
+        assert_eq!(
+            file_keys,
+            vec![
+                std::path::PathBuf::from("themes/theme_1/email_templates/fr-CA/reset.html"),
+                std::path::PathBuf::from("themes/theme_1/email_templates/fr/reset.html"),
+                std::path::PathBuf::from("themes/theme_1/email_templates/reset.html"),
+            ]
+        );
+
+        let file_keys = get_custom_template_file_keys("theme_1", None, "invite");
+        assert_eq!(
+            file_keys,
+            vec![std::path::PathBuf::from(
+                "themes/theme_1/email_templates/invite.html"
+            )]
+        );
+    }
+
+    fn get_email_outbox(
+        status: enums::EmailOutboxStatus,
+        sent_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::EmailOutbox {
+        let created_at = time::macros::datetime!(2025-01-01 00:00);
+        storage::EmailOutbox {
+            id: "email_outbox_1".to_string(),
+            recipient: pii::Email::from_str("user@example.com").unwrap(),
+            subject: "Reset your password".to_string(),
+            body: None,
+            email_type: enums::EmailOutboxType::ResetPassword,
+            status,
+            theme_id: None,
+            locale: None,
+            retry_count: 0,
+            last_error: None,
+            created_at,
+            modified_at: created_at.saturating_add(time::Duration::hours(1)),
+            sent_at,
+        }
+    }
+
+    #[test]
+    fn test_retention_deadline_counts_from_sending() {
+        let retention_period = time::Duration::days(30);
+        let sent_at = time::macros::datetime!(2025-01-02 00:00);
+
+        let email_outbox = get_email_outbox(enums::EmailOutboxStatus::Sent, Some(sent_at));
+        assert_eq!(
+            get_retention_deadline(&email_outbox, retention_period),
+            time::macros::datetime!(2025-02-01 00:00)
+        );
+
+        // Emails which were never sent are retained from their last update
+        let email_outbox = get_email_outbox(enums::EmailOutboxStatus::Failed, None);
+        assert_eq!(
+            get_retention_deadline(&email_outbox, retention_period),
+            time::macros::datetime!(2025-01-31 01:00)
+        );
+    }
+
+    #[test]
+    fn test_is_retention_period_over() {
+        let retention_period = time::Duration::days(30);
+        let sent_at = time::macros::datetime!(2025-01-02 00:00);
+        let before_deadline = time::macros::datetime!(2025-01-31 23:59);
+        let after_deadline = time::macros::datetime!(2025-02-01 00:00);
+
+        let sent = get_email_outbox(enums::EmailOutboxStatus::Sent, Some(sent_at));
+        assert!(!is_retention_period_over(
+            &sent,
+            retention_period,
+            before_deadline
+        ));
+        assert!(is_retention_period_over(
+            &sent,
+            retention_period,
+            after_deadline
+        ));
+
+        // Pending emails are not removed, and bounces and complaints suppress further emails
+        for status in [
+            enums::EmailOutboxStatus::Pending,
+            enums::EmailOutboxStatus::Bounced,
+            enums::EmailOutboxStatus::Complained,
+        ] {
+            let email_outbox = get_email_outbox(status, Some(sent_at));
+            assert!(!is_retention_period_over(
+                &email_outbox,
+                retention_period,
+                after_deadline
+            ));
+        }
+    }
+}
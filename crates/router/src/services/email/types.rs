@@ -1,5 +1,5 @@
 use api_models::user::dashboard_metadata::ProdIntent;
-use common_enums::{EntityType, MerchantProductType};
+use common_enums::{EmailOutboxType, EntityType, MerchantProductType};
 use common_utils::{errors::CustomResult, pii, types::user::EmailThemeConfig};
 use error_stack::ResultExt;
 use external_services::email::{EmailContents, EmailData, EmailError};
@@ -80,9 +80,166 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    AnalyticsReportReady {
+        link: String,
+        report_type: String,
+        start_time: String,
+        end_time: String,
+        expires_at: String,
+    },
+    AnalyticsAlert {
+        rule_name: String,
+        metric: String,
+        dimensions: String,
+        observed_value: String,
+        baseline_mean: String,
+        baseline_stddev: String,
+        sample_size: String,
+        window_start: String,
+        window_end: String,
+    },
     WelcomeToCommunity,
 }
 
+impl EmailBody {
+    /// Values of the email which customized templates can refer to as `{{name}}`, named the same
+    /// as the arguments of the built-in templates
+    pub fn get_template_variables(&self) -> Vec<(&'static str, String)> {
+        match self {
+            Self::Verify {
+                link,
+                entity_name,
+                entity_logo_url,
+                primary_color,
+                background_color,
+                foreground_color,
+            } => vec![
+                ("link", link.clone()),
+                ("entity_name", entity_name.clone()),
+                ("entity_logo_url", entity_logo_url.clone()),
+                ("primary_color", primary_color.clone()),
+                ("background_color", background_color.clone()),
+                ("foreground_color", foreground_color.clone()),
+            ],
+            Self::Reset {
+                link,
+                user_name,
+                entity_name,
+                entity_logo_url,
+                primary_color,
+                background_color,
+                foreground_color,
+            }
+            | Self::MagicLink {
+                link,
+                user_name,
+                entity_name,
+                entity_logo_url,
+                primary_color,
+                background_color,
+                foreground_color,
+            }
+            | Self::InviteUser {
+                link,
+                user_name,
+                entity_name,
+                entity_logo_url,
+                primary_color,
+                background_color,
+                foreground_color,
+            }
+            | Self::AcceptInviteFromEmail {
+                link,
+                user_name,
+                entity_name,
+                entity_logo_url,
+                primary_color,
+                background_color,
+                foreground_color,
+            } => vec![
+                ("link", link.clone()),
+                ("username", user_name.clone()),
+                ("entity_name", entity_name.clone()),
+                ("entity_logo_url", entity_logo_url.clone()),
+                ("primary_color", primary_color.clone()),
+                ("background_color", background_color.clone()),
+                ("foreground_color", foreground_color.clone()),
+            ],
+            Self::BizEmailProd {
+                user_name,
+                poc_email,
+                legal_business_name,
+                business_location,
+                business_website,
+                product_type,
+            } => vec![
+                ("username", user_name.clone()),
+                ("poc_email", poc_email.clone()),
+                ("legal_business_name", legal_business_name.clone()),
+                ("business_location", business_location.clone()),
+                ("business_website", business_website.clone()),
+                ("product_type", product_type.to_string()),
+            ],
+            Self::ReconActivation { user_name } => vec![("username", user_name.clone())],
+            Self::ProFeatureRequest {
+                feature_name,
+                merchant_id,
+                user_name,
+                user_email,
+            } => vec![
+                ("feature_name", feature_name.clone()),
+                ("merchant_id", merchant_id.get_string_repr().to_owned()),
+                ("username", user_name.clone()),
+                ("user_email", user_email.clone()),
+            ],
+            Self::ApiKeyExpiryReminder {
+                expires_in,
+                api_key_name,
+                prefix,
+            } => vec![
+                ("expires_in", expires_in.to_string()),
+                ("api_key_name", api_key_name.clone()),
+                ("prefix", prefix.clone()),
+            ],
+            Self::AnalyticsReportReady {
+                link,
+                report_type,
+                start_time,
+                end_time,
+                expires_at,
+            } => vec![
+                ("link", link.clone()),
+                ("report_type", report_type.clone()),
+                ("start_time", start_time.clone()),
+                ("end_time", end_time.clone()),
+                ("expires_at", expires_at.clone()),
+            ],
+            Self::AnalyticsAlert {
+                rule_name,
+                metric,
+                dimensions,
+                observed_value,
+                baseline_mean,
+                baseline_stddev,
+                sample_size,
+                window_start,
+                window_end,
+            } => vec![
+                ("rule_name", rule_name.clone()),
+                ("metric", metric.clone()),
+                ("dimensions", dimensions.clone()),
+                ("observed_value", observed_value.clone()),
+                ("baseline_mean", baseline_mean.clone()),
+                ("baseline_stddev", baseline_stddev.clone()),
+                ("sample_size", sample_size.clone()),
+                ("window_start", window_start.clone()),
+                ("window_end", window_end.clone()),
+            ],
+            Self::WelcomeToCommunity => Vec::new(),
+        }
+    }
+}
+
 pub mod html {
     use crate::services::email::types::EmailBody;
 
@@ -239,11 +396,89 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::AnalyticsReportReady {
+                link,
+                report_type,
+                start_time,
+                end_time,
+                expires_at,
+            } => format!(
+                include_str!("assets/analytics_report_ready.html"),
+                link = link,
+                report_type = report_type,
+                start_time = start_time,
+                end_time = end_time,
+                expires_at = expires_at,
+            ),
+            EmailBody::AnalyticsAlert {
+                rule_name,
+                metric,
+                dimensions,
+                observed_value,
+                baseline_mean,
+                baseline_stddev,
+                sample_size,
+                window_start,
+                window_end,
+            } => format!(
+                include_str!("assets/analytics_alert.html"),
+                rule_name = rule_name,
+                metric = metric,
+                dimensions = dimensions,
+                observed_value = observed_value,
+                baseline_mean = baseline_mean,
+                baseline_stddev = baseline_stddev,
+                sample_size = sample_size,
+                window_start = window_start,
+                window_end = window_end,
+            ),
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
         }
     }
+
+    /// Renders the body from the customized template when one is available, and from the
+    /// built-in template otherwise
+    pub fn get_html_body_from_template(
+        email_body: EmailBody,
+        custom_template: Option<&str>,
+    ) -> String {
+        match custom_template {
+            Some(custom_template) => render_custom_template(&email_body, custom_template),
+            None => get_html_body(email_body),
+        }
+    }
+
+    /// Replaces the `{{name}}` placeholders of a customized template with the values of the
+    /// email. Placeholders the email has no value for are left as they are.
+    pub fn render_custom_template(email_body: &EmailBody, custom_template: &str) -> String {
+        email_body
+            .get_template_variables()
+            .into_iter()
+            .fold(custom_template.to_string(), |rendered, (name, value)| {
+                rendered.replace(&format!("{{{{{name}}}}}"), &value)
+            })
+    }
+}
+
+/// Emails which are sent through the email outbox. The body of these emails can be customized per
+/// theme and locale by uploading a template to the storage of the theme.
+#[async_trait::async_trait]
+pub trait OutboxEmailData: EmailData {
+    fn get_email_type(&self) -> EmailOutboxType;
+
+    /// Name of the template file the body is rendered from, without the extension
+    fn get_template_name(&self) -> &'static str;
+
+    fn get_theme_id(&self) -> Option<&str>;
+
+    /// Get the email contents, rendering the body from the customized template when one is given
+    async fn get_email_data_with_template(
+        &self,
+        base_url: &str,
+        custom_template: Option<&str>,
+    ) -> CustomResult<EmailContents, EmailError>;
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -381,6 +616,29 @@ pub struct ResetPassword {
 #[async_trait::async_trait]
 impl EmailData for ResetPassword {
     async fn get_email_data(&self, base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        self.get_email_data_with_template(base_url, None).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxEmailData for ResetPassword {
+    fn get_email_type(&self) -> EmailOutboxType {
+        EmailOutboxType::ResetPassword
+    }
+
+    fn get_template_name(&self) -> &'static str {
+        "reset"
+    }
+
+    fn get_theme_id(&self) -> Option<&str> {
+        self.theme_id.as_deref()
+    }
+
+    async fn get_email_data_with_template(
+        &self,
+        base_url: &str,
+        custom_template: Option<&str>,
+    ) -> CustomResult<EmailContents, EmailError> {
         let token = EmailToken::new_token(
             self.recipient_email.clone(),
             None,
@@ -398,15 +656,18 @@ impl EmailData for ResetPassword {
             &self.theme_id,
         );
 
-        let body = html::get_html_body(EmailBody::Reset {
-            link: reset_password_link,
-            user_name: self.user_name.clone().get_secret().expose(),
-            entity_name: self.theme_config.entity_name.clone(),
-            entity_logo_url: self.theme_config.entity_logo_url.clone(),
-            primary_color: self.theme_config.primary_color.clone(),
-            background_color: self.theme_config.background_color.clone(),
-            foreground_color: self.theme_config.foreground_color.clone(),
-        });
+        let body = html::get_html_body_from_template(
+            EmailBody::Reset {
+                link: reset_password_link,
+                user_name: self.user_name.clone().get_secret().expose(),
+                entity_name: self.theme_config.entity_name.clone(),
+                entity_logo_url: self.theme_config.entity_logo_url.clone(),
+                primary_color: self.theme_config.primary_color.clone(),
+                background_color: self.theme_config.background_color.clone(),
+                foreground_color: self.theme_config.foreground_color.clone(),
+            },
+            custom_template,
+        );
 
         Ok(EmailContents {
             subject: format!(
@@ -482,6 +743,29 @@ pub struct InviteUser {
 #[async_trait::async_trait]
 impl EmailData for InviteUser {
     async fn get_email_data(&self, base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        self.get_email_data_with_template(base_url, None).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxEmailData for InviteUser {
+    fn get_email_type(&self) -> EmailOutboxType {
+        EmailOutboxType::InviteUser
+    }
+
+    fn get_template_name(&self) -> &'static str {
+        "invite"
+    }
+
+    fn get_theme_id(&self) -> Option<&str> {
+        self.theme_id.as_deref()
+    }
+
+    async fn get_email_data_with_template(
+        &self,
+        base_url: &str,
+        custom_template: Option<&str>,
+    ) -> CustomResult<EmailContents, EmailError> {
         let token = EmailToken::new_token(
             self.recipient_email.clone(),
             Some(self.entity.clone()),
@@ -498,15 +782,18 @@ impl EmailData for InviteUser {
             &self.auth_id,
             &self.theme_id,
         );
-        let body = html::get_html_body(EmailBody::AcceptInviteFromEmail {
-            link: invite_user_link,
-            user_name: self.user_name.clone().get_secret().expose(),
-            entity_name: self.theme_config.entity_name.clone(),
-            entity_logo_url: self.theme_config.entity_logo_url.clone(),
-            primary_color: self.theme_config.primary_color.clone(),
-            background_color: self.theme_config.background_color.clone(),
-            foreground_color: self.theme_config.foreground_color.clone(),
-        });
+        let body = html::get_html_body_from_template(
+            EmailBody::AcceptInviteFromEmail {
+                link: invite_user_link,
+                user_name: self.user_name.clone().get_secret().expose(),
+                entity_name: self.theme_config.entity_name.clone(),
+                entity_logo_url: self.theme_config.entity_logo_url.clone(),
+                primary_color: self.theme_config.primary_color.clone(),
+                background_color: self.theme_config.background_color.clone(),
+                foreground_color: self.theme_config.foreground_color.clone(),
+            },
+            custom_template,
+        );
 
         Ok(EmailContents {
             subject: format!(
@@ -657,14 +944,169 @@ pub struct ApiKeyExpiryReminder {
 
 #[async_trait::async_trait]
 impl EmailData for ApiKeyExpiryReminder {
-    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+    async fn get_email_data(&self, base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        self.get_email_data_with_template(base_url, None).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxEmailData for ApiKeyExpiryReminder {
+    fn get_email_type(&self) -> EmailOutboxType {
+        EmailOutboxType::ApiKeyExpiryReminder
+    }
+
+    fn get_template_name(&self) -> &'static str {
+        "api_key_expiry_reminder"
+    }
+
+    fn get_theme_id(&self) -> Option<&str> {
+        self.theme_id.as_deref()
+    }
+
+    async fn get_email_data_with_template(
+        &self,
+        _base_url: &str,
+        custom_template: Option<&str>,
+    ) -> CustomResult<EmailContents, EmailError> {
         let recipient = self.recipient_email.clone().into_inner();
 
-        let body = html::get_html_body(EmailBody::ApiKeyExpiryReminder {
-            expires_in: self.expires_in,
-            api_key_name: self.api_key_name.clone(),
-            prefix: self.prefix.clone(),
-        });
+        let body = html::get_html_body_from_template(
+            EmailBody::ApiKeyExpiryReminder {
+                expires_in: self.expires_in,
+                api_key_name: self.api_key_name.clone(),
+                prefix: self.prefix.clone(),
+            },
+            custom_template,
+        );
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct AnalyticsReportReady {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub download_url: String,
+    pub report_type: common_enums::AnalyticsReportType,
+    pub time_range: common_utils::types::TimeRange,
+    pub link_expires_at: time::PrimitiveDateTime,
+    pub theme_id: Option<String>,
+    pub theme_config: EmailThemeConfig,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsReportReady {
+    async fn get_email_data(&self, base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        self.get_email_data_with_template(base_url, None).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxEmailData for AnalyticsReportReady {
+    fn get_email_type(&self) -> EmailOutboxType {
+        EmailOutboxType::AnalyticsReportReady
+    }
+
+    fn get_template_name(&self) -> &'static str {
+        "analytics_report_ready"
+    }
+
+    fn get_theme_id(&self) -> Option<&str> {
+        self.theme_id.as_deref()
+    }
+
+    async fn get_email_data_with_template(
+        &self,
+        _base_url: &str,
+        custom_template: Option<&str>,
+    ) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body_from_template(
+            EmailBody::AnalyticsReportReady {
+                link: self.download_url.clone(),
+                report_type: self.report_type.to_string(),
+                start_time: self.time_range.start_time.to_string(),
+                end_time: self
+                    .time_range
+                    .end_time
+                    .map(|end_time| end_time.to_string())
+                    .unwrap_or_else(|| "now".to_string()),
+                expires_at: self.link_expires_at.to_string(),
+            },
+            custom_template,
+        );
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient,
+        })
+    }
+}
+
+pub struct AnalyticsAlert {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub rule_name: String,
+    pub metric: common_enums::AnalyticsAlertMetric,
+    /// Description of the series the anomaly was detected in
+    pub dimensions: String,
+    pub observed_value: f64,
+    pub baseline_mean: f64,
+    pub baseline_stddev: f64,
+    pub sample_size: i64,
+    pub window_start: time::PrimitiveDateTime,
+    pub window_end: time::PrimitiveDateTime,
+    pub theme_id: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsAlert {
+    async fn get_email_data(&self, base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        self.get_email_data_with_template(base_url, None).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxEmailData for AnalyticsAlert {
+    fn get_email_type(&self) -> EmailOutboxType {
+        EmailOutboxType::AnalyticsAlert
+    }
+
+    fn get_template_name(&self) -> &'static str {
+        "analytics_alert"
+    }
+
+    fn get_theme_id(&self) -> Option<&str> {
+        self.theme_id.as_deref()
+    }
+
+    async fn get_email_data_with_template(
+        &self,
+        _base_url: &str,
+        custom_template: Option<&str>,
+    ) -> CustomResult<EmailContents, EmailError> {
+        let recipient = self.recipient_email.clone().into_inner();
+
+        let body = html::get_html_body_from_template(
+            EmailBody::AnalyticsAlert {
+                rule_name: self.rule_name.clone(),
+                metric: self.metric.to_string(),
+                dimensions: self.dimensions.clone(),
+                observed_value: format!("{:.2}", self.observed_value),
+                baseline_mean: format!("{:.2}", self.baseline_mean),
+                baseline_stddev: format!("{:.2}", self.baseline_stddev),
+                sample_size: self.sample_size.to_string(),
+                window_start: self.window_start.to_string(),
+                window_end: self.window_end.to_string(),
+            },
+            custom_template,
+        );
 
         Ok(EmailContents {
             subject: self.subject.to_string(),
@@ -3,6 +3,9 @@ use std::{collections::HashMap, sync::Arc};
 use common_utils::{errors::CustomResult, types::TenantConfig};
 use error_stack::{report, ResultExt};
 use events::{EventsError, Message, MessagingInterface};
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
 use num_traits::ToPrimitive;
 use rdkafka::{
     config::FromClientConfig,
@@ -17,10 +20,12 @@ use diesel_models::fraud_check::FraudCheck;
 use crate::{events::EventType, services::kafka::fraud_check_event::KafkaFraudCheckEvent};
 mod authentication;
 mod authentication_event;
+pub mod config;
 mod dispute;
 mod dispute_event;
 mod fraud_check;
 mod fraud_check_event;
+pub mod outbox;
 mod payment_attempt;
 mod payment_attempt_event;
 mod payment_intent;
@@ -30,16 +35,24 @@ mod refund_event;
 pub mod revenue_recovery;
 use diesel_models::{authentication::Authentication, refund::Refund};
 use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
+use redis_interface::RedisConnectionPool;
 use serde::Serialize;
 use time::{OffsetDateTime, PrimitiveDateTime};
 
 #[cfg(feature = "payouts")]
 use self::payout::KafkaPayout;
 use self::{
-    authentication::KafkaAuthentication, authentication_event::KafkaAuthenticationEvent,
-    dispute::KafkaDispute, dispute_event::KafkaDisputeEvent, payment_attempt::KafkaPaymentAttempt,
-    payment_attempt_event::KafkaPaymentAttemptEvent, payment_intent::KafkaPaymentIntent,
-    payment_intent_event::KafkaPaymentIntentEvent, refund::KafkaRefund,
+    authentication::KafkaAuthentication,
+    authentication_event::KafkaAuthenticationEvent,
+    config::{KafkaPartitionKeyStrategy, KafkaProducerSettings, KafkaSecuritySettings},
+    dispute::KafkaDispute,
+    dispute_event::KafkaDisputeEvent,
+    outbox::KafkaOutboxSettings,
+    payment_attempt::KafkaPaymentAttempt,
+    payment_attempt_event::KafkaPaymentAttemptEvent,
+    payment_intent::KafkaPaymentIntent,
+    payment_intent_event::KafkaPaymentIntentEvent,
+    refund::KafkaRefund,
     refund_event::KafkaRefundEvent,
 };
 use crate::{services::kafka::fraud_check::KafkaFraudCheck, types::storage::Dispute};
@@ -163,6 +176,10 @@ pub struct KafkaSettings {
     authentication_analytics_topic: String,
     routing_logs_topic: String,
     revenue_recovery_topic: String,
+    security: KafkaSecuritySettings,
+    producer: KafkaProducerSettings,
+    partition_key_strategies: HashMap<EventType, KafkaPartitionKeyStrategy>,
+    outbox: KafkaOutboxSettings,
 }
 
 impl KafkaSettings {
@@ -256,7 +273,32 @@ impl KafkaSettings {
             ))
         })?;
 
-        Ok(())
+        self.security.validate()?;
+        self.producer.validate()?;
+        config::validate_partition_key_strategies(&self.partition_key_strategies)?;
+        self.outbox.validate()
+    }
+
+    /// Fetches the SASL password and the client private key from the secrets manager
+    pub async fn fetch_secrets(
+        &self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        Ok(Self {
+            security: self
+                .security
+                .fetch_secrets(secret_management_client)
+                .await?,
+            ..self.clone()
+        })
+    }
+
+    fn client_config(&self) -> rdkafka::ClientConfig {
+        let mut client_config = rdkafka::ClientConfig::new();
+        client_config.set("bootstrap.servers", self.brokers.join(","));
+        self.security.apply(&mut client_config);
+        self.producer.apply(&mut client_config);
+        client_config
     }
 }
 
@@ -279,6 +321,9 @@ pub struct KafkaProducer {
     ckh_database_name: Option<String>,
     routing_logs_topic: String,
     revenue_recovery_topic: String,
+    partition_key_strategies: HashMap<EventType, KafkaPartitionKeyStrategy>,
+    outbox_settings: KafkaOutboxSettings,
+    outbox_connection: Option<Arc<RedisConnectionPool>>,
 }
 
 struct RdKafkaProducer(ThreadedProducer<DefaultProducerContext>);
@@ -297,6 +342,8 @@ pub enum KafkaError {
     NotImplemented,
     #[error("Kafka Initialization Error")]
     InitializationError,
+    #[error("Kafka Outbox Error")]
+    OutboxError,
 }
 
 #[allow(unused)]
@@ -308,10 +355,8 @@ impl KafkaProducer {
     pub async fn create(conf: &KafkaSettings) -> MQResult<Self> {
         Ok(Self {
             producer: Arc::new(RdKafkaProducer(
-                ThreadedProducer::from_config(
-                    rdkafka::ClientConfig::new().set("bootstrap.servers", conf.brokers.join(",")),
-                )
-                .change_context(KafkaError::InitializationError)?,
+                ThreadedProducer::from_config(&conf.client_config())
+                    .change_context(KafkaError::InitializationError)?,
             )),
 
             fraud_check_analytics_topic: conf.fraud_check_analytics_topic.clone(),
@@ -330,31 +375,150 @@ impl KafkaProducer {
             ckh_database_name: None,
             routing_logs_topic: conf.routing_logs_topic.clone(),
             revenue_recovery_topic: conf.revenue_recovery_topic.clone(),
+            partition_key_strategies: conf.partition_key_strategies.clone(),
+            outbox_settings: conf.outbox.clone(),
+            outbox_connection: None,
         })
     }
 
+    pub fn is_outbox_enabled(&self) -> bool {
+        self.outbox_settings.enabled
+    }
+
+    /// Sets the connection used to write storage events to the outbox stream
+    pub fn set_outbox_connection(&mut self, redis_conn: Arc<RedisConnectionPool>) {
+        self.outbox_connection = Some(redis_conn);
+    }
+
     pub fn log_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
         router_env::logger::debug!("Logging Kafka Event {event:?}");
         let topic = self.get_topic(event.event_type());
+        let key = self.get_partition_key(event.event_type(), || event.key());
+        let payload = event.value()?;
+        let mut record = BaseRecord::to(topic)
+            .payload(&payload)
+            .timestamp(get_event_timestamp(event));
+        if let Some(key) = key.as_ref() {
+            record = record.key(key);
+        }
         self.producer
             .0
-            .send(
-                BaseRecord::to(topic)
-                    .key(&event.key())
-                    .payload(&event.value()?)
-                    .timestamp(event.creation_timestamp().unwrap_or_else(|| {
-                        (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000)
-                            .try_into()
-                            .unwrap_or_else(|_| {
-                                // kafka producer accepts milliseconds
-                                // try converting nanos to millis if that fails convert seconds to millis
-                                OffsetDateTime::now_utc().unix_timestamp() * 1_000
-                            })
-                    })),
-            )
+            .send(record)
             .map_err(|(error, record)| report!(error).attach_printable(format!("{record:?}")))
             .change_context(KafkaError::GenericError)
     }
+
+    /// Writes the event to the outbox stream if the outbox is enabled, from where it is published
+    /// to Kafka by the outbox relay. Otherwise, the event is sent to Kafka directly.
+    async fn log_storage_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
+        let Some(redis_conn) = &self.outbox_connection else {
+            return self.log_event(event);
+        };
+
+        router_env::logger::debug!("Adding Kafka Event to outbox {event:?}");
+        let entry = self.get_outbox_entry(event)?;
+        outbox::enqueue_event(redis_conn, &self.outbox_settings, &entry).await
+    }
+
+    fn get_outbox_entry<T: KafkaMessage>(&self, event: &T) -> MQResult<outbox::KafkaOutboxEntry> {
+        outbox::KafkaOutboxEntry::from_event(
+            self.get_topic(event.event_type()),
+            self.get_partition_key(event.event_type(), || event.key()),
+            event,
+            get_event_timestamp(event),
+        )
+    }
+
+    /// Adds a pending write for the record to the outbox before the record is written to the
+    /// database, if the outbox is enabled. `old_record` is the state of the record before an
+    /// update. Returns the ID of the pending write, to be removed with
+    /// [`Self::remove_pending_write`] once the events of the write are added to the outbox.
+    pub async fn add_pending_write(
+        &self,
+        record: &outbox::KafkaOutboxRecord,
+        old_record: Option<outbox::KafkaOutboxOldRecord<'_>>,
+        tenant_id: TenantID,
+    ) -> MQResult<Option<String>> {
+        let Some(redis_conn) = &self.outbox_connection else {
+            return Ok(None);
+        };
+
+        let old_events = old_record
+            .map(|old_record| self.get_old_event_entry(&old_record, tenant_id))
+            .transpose()?;
+        outbox::add_pending_write(
+            redis_conn,
+            &self.outbox_settings,
+            record,
+            old_events.as_slice(),
+        )
+        .await
+        .map(Some)
+    }
+
+    /// Removes a pending write once the events of the write are added to the outbox. A pending
+    /// write that could not be removed is published again by the outbox relay.
+    pub async fn remove_pending_write(&self, pending_write_id: Option<String>) {
+        let (Some(redis_conn), Some(pending_write_id)) =
+            (&self.outbox_connection, pending_write_id)
+        else {
+            return;
+        };
+
+        if let Err(error) =
+            outbox::remove_pending_write(redis_conn, &self.outbox_settings, &pending_write_id).await
+        {
+            router_env::logger::error!(?error, "Failed to remove Kafka outbox pending write");
+        }
+    }
+
+    fn get_old_event_entry(
+        &self,
+        old_record: &outbox::KafkaOutboxOldRecord<'_>,
+        tenant_id: TenantID,
+    ) -> MQResult<outbox::KafkaOutboxEntry> {
+        let ckh_database_name = self.ckh_database_name.clone();
+        match old_record {
+            outbox::KafkaOutboxOldRecord::PaymentIntent(intent, infra_values) => self
+                .get_outbox_entry(&KafkaEvent::old(
+                    &KafkaPaymentIntent::from_storage(intent, infra_values.clone()),
+                    tenant_id,
+                    ckh_database_name,
+                )),
+            outbox::KafkaOutboxOldRecord::PaymentAttempt(attempt) => {
+                self.get_outbox_entry(&KafkaEvent::old(
+                    &KafkaPaymentAttempt::from_storage(attempt),
+                    tenant_id,
+                    ckh_database_name,
+                ))
+            }
+            outbox::KafkaOutboxOldRecord::Refund(refund) => {
+                self.get_outbox_entry(&KafkaEvent::old(
+                    &KafkaRefund::from_storage(refund),
+                    tenant_id,
+                    ckh_database_name,
+                ))
+            }
+            outbox::KafkaOutboxOldRecord::Dispute(dispute) => {
+                self.get_outbox_entry(&KafkaEvent::old(
+                    &KafkaDispute::from_storage(dispute),
+                    tenant_id,
+                    ckh_database_name,
+                ))
+            }
+            outbox::KafkaOutboxOldRecord::Authentication(authentication) => {
+                self.get_outbox_entry(&KafkaEvent::old(
+                    &KafkaAuthentication::from_storage(authentication),
+                    tenant_id,
+                    ckh_database_name,
+                ))
+            }
+            #[cfg(feature = "payouts")]
+            outbox::KafkaOutboxOldRecord::Payout(payout) => {
+                self.get_outbox_entry(&KafkaEvent::old(payout, tenant_id, ckh_database_name))
+            }
+        }
+    }
     pub async fn log_fraud_check(
         &self,
         attempt: &FraudCheck,
@@ -362,29 +526,32 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_attempt {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &KafkaFraudCheck::from_storage(&negative_event),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative fraud check event {negative_event:?}")
             })?;
         };
 
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             &KafkaFraudCheck::from_storage(attempt),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add positive fraud check event {attempt:?}")
         })?;
 
-        self.log_event(&KafkaConsolidatedEvent::new(
+        self.log_storage_event(&KafkaConsolidatedEvent::new(
             &KafkaFraudCheckEvent::from_storage(attempt),
             tenant_id.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add consolidated fraud check  event {attempt:?}")
         })
@@ -397,27 +564,30 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_attempt {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &KafkaPaymentAttempt::from_storage(&negative_event),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative attempt event {negative_event:?}")
             })?;
         };
 
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             &KafkaPaymentAttempt::from_storage(attempt),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add positive attempt event {attempt:?}"))?;
 
-        self.log_event(&KafkaConsolidatedEvent::new(
+        self.log_storage_event(&KafkaConsolidatedEvent::new(
             &KafkaPaymentAttemptEvent::from_storage(attempt),
             tenant_id.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add consolidated attempt event {attempt:?}"))
     }
 
@@ -426,11 +596,12 @@ impl KafkaProducer {
         delete_old_attempt: &PaymentAttempt,
         tenant_id: TenantID,
     ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(
+        self.log_storage_event(&KafkaEvent::old(
             &KafkaPaymentAttempt::from_storage(delete_old_attempt),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add negative attempt event {delete_old_attempt:?}")
         })
@@ -443,29 +614,32 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_authentication {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &KafkaAuthentication::from_storage(&negative_event),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative authentication event {negative_event:?}")
             })?;
         };
 
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             &KafkaAuthentication::from_storage(authentication),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add positive authentication event {authentication:?}")
         })?;
 
-        self.log_event(&KafkaConsolidatedEvent::new(
+        self.log_storage_event(&KafkaConsolidatedEvent::new(
             &KafkaAuthenticationEvent::from_storage(authentication),
             tenant_id.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add consolidated authentication event {authentication:?}")
         })
@@ -479,27 +653,30 @@ impl KafkaProducer {
         infra_values: Option<Value>,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_intent {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &KafkaPaymentIntent::from_storage(&negative_event, infra_values.clone()),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative intent event {negative_event:?}")
             })?;
         };
 
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             &KafkaPaymentIntent::from_storage(intent, infra_values.clone()),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add positive intent event {intent:?}"))?;
 
-        self.log_event(&KafkaConsolidatedEvent::new(
+        self.log_storage_event(&KafkaConsolidatedEvent::new(
             &KafkaPaymentIntentEvent::from_storage(intent, infra_values.clone()),
             tenant_id.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add consolidated intent event {intent:?}"))
     }
 
@@ -509,11 +686,12 @@ impl KafkaProducer {
         tenant_id: TenantID,
         infra_values: Option<Value>,
     ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(
+        self.log_storage_event(&KafkaEvent::old(
             &KafkaPaymentIntent::from_storage(delete_old_intent, infra_values),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add negative intent event {delete_old_intent:?}")
         })
@@ -526,27 +704,30 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_refund {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &KafkaRefund::from_storage(&negative_event),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative refund event {negative_event:?}")
             })?;
         };
 
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             &KafkaRefund::from_storage(refund),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add positive refund event {refund:?}"))?;
 
-        self.log_event(&KafkaConsolidatedEvent::new(
+        self.log_storage_event(&KafkaConsolidatedEvent::new(
             &KafkaRefundEvent::from_storage(refund),
             tenant_id.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add consolidated refund event {refund:?}"))
     }
 
@@ -555,11 +736,12 @@ impl KafkaProducer {
         delete_old_refund: &Refund,
         tenant_id: TenantID,
     ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(
+        self.log_storage_event(&KafkaEvent::old(
             &KafkaRefund::from_storage(delete_old_refund),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add negative refund event {delete_old_refund:?}")
         })
@@ -572,27 +754,30 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_dispute {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &KafkaDispute::from_storage(&negative_event),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative dispute event {negative_event:?}")
             })?;
         };
 
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             &KafkaDispute::from_storage(dispute),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add positive dispute event {dispute:?}"))?;
 
-        self.log_event(&KafkaConsolidatedEvent::new(
+        self.log_storage_event(&KafkaConsolidatedEvent::new(
             &KafkaDisputeEvent::from_storage(dispute),
             tenant_id.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add consolidated dispute event {dispute:?}"))
     }
 
@@ -601,11 +786,12 @@ impl KafkaProducer {
         delete_old_dispute: &Dispute,
         tenant_id: TenantID,
     ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(
+        self.log_storage_event(&KafkaEvent::old(
             &KafkaDispute::from_storage(delete_old_dispute),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add negative dispute event {delete_old_dispute:?}")
         })
@@ -619,20 +805,22 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_payout {
-            self.log_event(&KafkaEvent::old(
+            self.log_storage_event(&KafkaEvent::old(
                 &negative_event,
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
+            .await
             .attach_printable_lazy(|| {
                 format!("Failed to add negative payout event {negative_event:?}")
             })?;
         };
-        self.log_event(&KafkaEvent::new(
+        self.log_storage_event(&KafkaEvent::new(
             payout,
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| format!("Failed to add positive payout event {payout:?}"))
     }
 
@@ -642,16 +830,35 @@ impl KafkaProducer {
         delete_old_payout: &KafkaPayout<'_>,
         tenant_id: TenantID,
     ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(
+        self.log_storage_event(&KafkaEvent::old(
             delete_old_payout,
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
+        .await
         .attach_printable_lazy(|| {
             format!("Failed to add negative payout event {delete_old_payout:?}")
         })
     }
 
+    /// Returns the key with which an event is to be sent, based on the partition key strategy
+    /// configured for the topic of the event
+    fn get_partition_key(
+        &self,
+        event_type: EventType,
+        event_key: impl FnOnce() -> String,
+    ) -> Option<String> {
+        match self
+            .partition_key_strategies
+            .get(&event_type)
+            .copied()
+            .unwrap_or_default()
+        {
+            KafkaPartitionKeyStrategy::EventKey => Some(event_key()),
+            KafkaPartitionKeyStrategy::None => None,
+        }
+    }
+
     pub fn get_topic(&self, event: EventType) -> &str {
         match event {
             EventType::FraudCheck => &self.fraud_check_analytics_topic,
@@ -673,6 +880,18 @@ impl KafkaProducer {
     }
 }
 
+pub(crate) fn get_event_timestamp<T: KafkaMessage>(event: &T) -> i64 {
+    event.creation_timestamp().unwrap_or_else(|| {
+        (OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000)
+            .try_into()
+            .unwrap_or_else(|_| {
+                // kafka producer accepts milliseconds
+                // try converting nanos to millis if that fails convert seconds to millis
+                OffsetDateTime::now_utc().unix_timestamp() * 1_000
+            })
+    })
+}
+
 impl Drop for RdKafkaProducer {
     fn drop(&mut self) {
         // Flush the producer to send any pending messages
@@ -698,6 +917,7 @@ impl MessagingInterface for KafkaProducer {
         T: Message<Class = Self::MessageClass> + masking::ErasedMaskSerialize,
     {
         let topic = self.get_topic(data.get_message_class());
+        let key = self.get_partition_key(data.get_message_class(), || data.identifier());
         let json_data = data
             .masked_serialize()
             .and_then(|mut value| {
@@ -720,23 +940,24 @@ impl MessagingInterface for KafkaProducer {
             key: "clickhouse_database",
             value: self.ckh_database_name.as_ref(),
         });
+        let mut record = BaseRecord::to(topic)
+            .payload(&json_data)
+            .headers(headers)
+            .timestamp(
+                (timestamp.assume_utc().unix_timestamp_nanos() / 1_000_000)
+                    .to_i64()
+                    .unwrap_or_else(|| {
+                        // kafka producer accepts milliseconds
+                        // try converting nanos to millis if that fails convert seconds to millis
+                        timestamp.assume_utc().unix_timestamp() * 1_000
+                    }),
+            );
+        if let Some(key) = key.as_ref() {
+            record = record.key(key);
+        }
         self.producer
             .0
-            .send(
-                BaseRecord::to(topic)
-                    .key(&data.identifier())
-                    .payload(&json_data)
-                    .headers(headers)
-                    .timestamp(
-                        (timestamp.assume_utc().unix_timestamp_nanos() / 1_000_000)
-                            .to_i64()
-                            .unwrap_or_else(|| {
-                                // kafka producer accepts milliseconds
-                                // try converting nanos to millis if that fails convert seconds to millis
-                                timestamp.assume_utc().unix_timestamp() * 1_000
-                            }),
-                    ),
-            )
+            .send(record)
             .map_err(|(error, record)| report!(error).attach_printable(format!("{record:?}")))
             .change_context(KafkaError::GenericError)
             .change_context(EventsError::PublishError)
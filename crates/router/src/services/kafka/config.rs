@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+
+use common_utils::{errors::CustomResult, ext_traits::ConfigExt, fp_utils::when};
+use hyperswitch_interfaces::secrets_interface::{
+    SecretManagementInterface, SecretsManagementError,
+};
+use masking::{PeekInterface, Secret};
+
+use crate::{core::errors::ApplicationError, events::EventType};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KafkaSecurityProtocol {
+    #[default]
+    Plaintext,
+    Ssl,
+    SaslPlaintext,
+    SaslSsl,
+}
+
+impl KafkaSecurityProtocol {
+    fn uses_sasl(self) -> bool {
+        matches!(self, Self::SaslPlaintext | Self::SaslSsl)
+    }
+
+    fn uses_ssl(self) -> bool {
+        matches!(self, Self::Ssl | Self::SaslSsl)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaSaslMechanism {
+    Plain,
+    ScramSha256,
+    ScramSha512,
+}
+
+impl KafkaSaslMechanism {
+    fn as_config_value(self) -> &'static str {
+        match self {
+            Self::Plain => "PLAIN",
+            Self::ScramSha256 => "SCRAM-SHA-256",
+            Self::ScramSha512 => "SCRAM-SHA-512",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct KafkaSecuritySettings {
+    pub protocol: KafkaSecurityProtocol,
+    pub sasl_mechanism: Option<KafkaSaslMechanism>,
+    pub sasl_username: Option<String>,
+    /// Fetched from the secrets manager
+    pub sasl_password: Option<Secret<String>>,
+    /// PEM encoded certificate of the CA used to verify the brokers
+    pub ca_certificate: Option<String>,
+    /// PEM encoded client certificate, for mutual TLS
+    pub client_certificate: Option<String>,
+    /// PEM encoded private key of the client certificate, fetched from the secrets manager
+    pub client_private_key: Option<Secret<String>>,
+}
+
+impl KafkaSecuritySettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        if self.protocol.uses_sasl() {
+            when(self.sasl_mechanism.is_none(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka SASL mechanism must be set when using a SASL security protocol".into(),
+                ))
+            })?;
+
+            when(
+                self.sasl_username
+                    .as_ref()
+                    .is_none_or(|username| username.is_default_or_empty()),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "Kafka SASL username must not be empty".into(),
+                    ))
+                },
+            )?;
+
+            when(
+                self.sasl_password
+                    .as_ref()
+                    .is_none_or(|password| password.peek().is_default_or_empty()),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "Kafka SASL password must not be empty".into(),
+                    ))
+                },
+            )?;
+        } else {
+            when(self.sasl_mechanism.is_some(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "Kafka SASL mechanism cannot be used with the {} security protocol",
+                    self.protocol
+                )))
+            })?;
+        }
+
+        let uses_certificates = self.ca_certificate.is_some()
+            || self.client_certificate.is_some()
+            || self.client_private_key.is_some();
+        when(uses_certificates && !self.protocol.uses_ssl(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "Kafka certificates cannot be used with the {} security protocol",
+                self.protocol
+            )))
+        })?;
+
+        when(
+            self.client_certificate.is_some() != self.client_private_key.is_some(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka client certificate and client private key must be set together".into(),
+                ))
+            },
+        )
+    }
+
+    pub async fn fetch_secrets(
+        &self,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<Self, SecretsManagementError> {
+        let sasl_password = match &self.sasl_password {
+            Some(sasl_password) => Some(
+                secret_management_client
+                    .get_secret(sasl_password.clone())
+                    .await?,
+            ),
+            None => None,
+        };
+        let client_private_key = match &self.client_private_key {
+            Some(client_private_key) => Some(
+                secret_management_client
+                    .get_secret(client_private_key.clone())
+                    .await?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            sasl_password,
+            client_private_key,
+            ..self.clone()
+        })
+    }
+
+    pub fn apply(&self, client_config: &mut rdkafka::ClientConfig) {
+        client_config.set("security.protocol", self.protocol.to_string());
+
+        if let Some(sasl_mechanism) = self.sasl_mechanism {
+            client_config.set("sasl.mechanism", sasl_mechanism.as_config_value());
+        }
+        if let Some(sasl_username) = &self.sasl_username {
+            client_config.set("sasl.username", sasl_username);
+        }
+        if let Some(sasl_password) = &self.sasl_password {
+            client_config.set("sasl.password", sasl_password.peek());
+        }
+        if let Some(ca_certificate) = &self.ca_certificate {
+            client_config.set("ssl.ca.pem", ca_certificate);
+        }
+        if let Some(client_certificate) = &self.client_certificate {
+            client_config.set("ssl.certificate.pem", client_certificate);
+        }
+        if let Some(client_private_key) = &self.client_private_key {
+            client_config.set("ssl.key.pem", client_private_key.peek());
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KafkaCompression {
+    #[default]
+    None,
+    Gzip,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaAcks {
+    /// Wait for the leader and all in-sync replicas to acknowledge a message
+    #[default]
+    All,
+    /// Wait for the leader alone to acknowledge a message
+    Leader,
+    /// Do not wait for an acknowledgement
+    None,
+}
+
+impl KafkaAcks {
+    fn as_config_value(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Leader => "1",
+            Self::None => "0",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct KafkaProducerSettings {
+    pub compression: KafkaCompression,
+    /// Maximum size in bytes of a batch of messages sent to a partition
+    pub batch_size: Option<u32>,
+    /// Time in milliseconds to wait for messages to accumulate before a batch is sent
+    pub linger_ms: Option<u32>,
+    pub acks: KafkaAcks,
+    /// Ensures that messages are written exactly once and in order to a partition, requires
+    /// `acks` to be `all`
+    pub enable_idempotence: bool,
+}
+
+impl KafkaProducerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        when(self.batch_size == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka producer batch size must be greater than zero".into(),
+            ))
+        })?;
+
+        // Upper bound of `linger.ms` accepted by librdkafka
+        when(
+            self.linger_ms.is_some_and(|linger_ms| linger_ms > 900_000),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka producer linger must not be greater than 900000 milliseconds".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.enable_idempotence && self.acks != KafkaAcks::All,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka idempotent producer requires acks to be set to all".into(),
+                ))
+            },
+        )
+    }
+
+    pub fn apply(&self, client_config: &mut rdkafka::ClientConfig) {
+        client_config
+            .set("compression.type", self.compression.to_string())
+            .set("acks", self.acks.as_config_value())
+            .set("enable.idempotence", self.enable_idempotence.to_string());
+
+        if let Some(batch_size) = self.batch_size {
+            client_config.set("batch.size", batch_size.to_string());
+        }
+        if let Some(linger_ms) = self.linger_ms {
+            client_config.set("linger.ms", linger_ms.to_string());
+        }
+    }
+}
+
+/// Determines the key with which the events of a topic are sent, and hence the partition to
+/// which they are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KafkaPartitionKeyStrategy {
+    /// Events are sent with the key of the event, so that the events of an entity are written to
+    /// the same partition in order
+    #[default]
+    EventKey,
+    /// Events are sent without a key and are spread across partitions by the producer. Not
+    /// allowed for the events written to collapsing tables, see [`requires_event_key`].
+    None,
+}
+
+/// Returns whether the events of the type are written to analytics tables that collapse the
+/// earlier state of an entity with its next state. The events of an entity must then be written
+/// to the same partition, so that the cancelling event of a state is not consumed before the
+/// state itself.
+fn requires_event_key(event_type: EventType) -> bool {
+    match event_type {
+        EventType::PaymentIntent
+        | EventType::FraudCheck
+        | EventType::PaymentAttempt
+        | EventType::Refund
+        | EventType::Dispute
+        | EventType::Consolidated
+        | EventType::Authentication => true,
+        #[cfg(feature = "payouts")]
+        EventType::Payout => true,
+        EventType::ApiLogs
+        | EventType::ConnectorApiLogs
+        | EventType::OutgoingWebhookLogs
+        | EventType::AuditEvent
+        | EventType::RoutingApiLogs
+        | EventType::RevenueRecovery => false,
+    }
+}
+
+pub fn validate_partition_key_strategies(
+    partition_key_strategies: &HashMap<EventType, KafkaPartitionKeyStrategy>,
+) -> Result<(), ApplicationError> {
+    partition_key_strategies
+        .iter()
+        .try_for_each(|(event_type, strategy)| {
+            when(
+                *strategy == KafkaPartitionKeyStrategy::None && requires_event_key(*event_type),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "Kafka events of type {event_type} must be sent with the event key"
+                    )))
+                },
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sasl_settings() -> KafkaSecuritySettings {
+        KafkaSecuritySettings {
+            protocol: KafkaSecurityProtocol::SaslSsl,
+            sasl_mechanism: Some(KafkaSaslMechanism::ScramSha512),
+            sasl_username: Some("hyperswitch".to_string()),
+            sasl_password: Some(Secret::new("password".to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_security_settings_are_valid() {
+        assert!(KafkaSecuritySettings::default().validate().is_ok());
+        assert!(get_sasl_settings().validate().is_ok());
+    }
+
+    #[test]
+    fn test_sasl_protocol_requires_credentials() {
+        let settings = KafkaSecuritySettings {
+            sasl_mechanism: None,
+            ..get_sasl_settings()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaSecuritySettings {
+            sasl_username: Some(String::new()),
+            ..get_sasl_settings()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaSecuritySettings {
+            sasl_password: None,
+            ..get_sasl_settings()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_sasl_mechanism_requires_sasl_protocol() {
+        let settings = KafkaSecuritySettings {
+            protocol: KafkaSecurityProtocol::Ssl,
+            ..get_sasl_settings()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_certificates_require_ssl_protocol() {
+        let settings = KafkaSecuritySettings {
+            ca_certificate: Some("ca".to_string()),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaSecuritySettings {
+            protocol: KafkaSecurityProtocol::Ssl,
+            ca_certificate: Some("ca".to_string()),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_client_certificate_requires_private_key() {
+        let settings = KafkaSecuritySettings {
+            protocol: KafkaSecurityProtocol::Ssl,
+            client_certificate: Some("certificate".to_string()),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaSecuritySettings {
+            client_private_key: Some(Secret::new("private_key".to_string())),
+            ..settings
+        };
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_producer_settings_validation() {
+        assert!(KafkaProducerSettings::default().validate().is_ok());
+
+        let settings = KafkaProducerSettings {
+            batch_size: Some(0),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaProducerSettings {
+            linger_ms: Some(900_001),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaProducerSettings {
+            linger_ms: Some(900_000),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_idempotent_producer_requires_all_acks() {
+        let settings = KafkaProducerSettings {
+            acks: KafkaAcks::Leader,
+            enable_idempotence: true,
+            ..Default::default()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaProducerSettings {
+            acks: KafkaAcks::All,
+            ..settings
+        };
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_collapsing_events_require_event_key() {
+        let strategies = HashMap::from([(EventType::ApiLogs, KafkaPartitionKeyStrategy::None)]);
+        assert!(validate_partition_key_strategies(&strategies).is_ok());
+
+        let strategies = HashMap::from([
+            (EventType::ApiLogs, KafkaPartitionKeyStrategy::None),
+            (EventType::PaymentAttempt, KafkaPartitionKeyStrategy::None),
+        ]);
+        assert!(validate_partition_key_strategies(&strategies).is_err());
+
+        let strategies = HashMap::from([(
+            EventType::PaymentAttempt,
+            KafkaPartitionKeyStrategy::EventKey,
+        )]);
+        assert!(validate_partition_key_strategies(&strategies).is_ok());
+    }
+}
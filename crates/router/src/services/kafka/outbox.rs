@@ -0,0 +1,1077 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use common_enums::enums::MerchantStorageScheme;
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{ConfigExt, StringExt},
+    fp_utils::when,
+    id_type,
+};
+use diesel_models::{authentication::Authentication, refund::Refund};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
+use rdkafka::{
+    producer::{FutureProducer, FutureRecord},
+    util::Timeout,
+};
+use redis_interface::{errors::RedisError, RedisConnectionPool, RedisEntryId, SetnxReply};
+use router_env::{logger, metric_attributes};
+use serde_json::Value;
+use storage_impl::redis::kv_store::RedisConnInterface;
+
+#[cfg(feature = "payouts")]
+use super::payout::KafkaPayout;
+use super::{KafkaError, KafkaMessage, KafkaProducer, KafkaSettings, MQResult};
+use crate::{
+    core::errors::{self, ApplicationError},
+    db::{kafka_store::TenantID, StorageInterface},
+    events::{EventsConfig, EventsHandler},
+    routes::{metrics, AppState},
+    types::storage::Dispute,
+};
+
+const OUTBOX_EVENT_FIELD: &str = "event";
+const OUTBOX_PUSHED_AT_FIELD: &str = "pushed_at";
+const OUTBOX_ERROR_FIELD: &str = "error";
+const PENDING_WRITE_RECORD_FIELD: &str = "record";
+const PENDING_WRITE_OLD_EVENTS_FIELD: &str = "old_events";
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct KafkaOutboxSettings {
+    /// Write events to the outbox stream instead of sending them to Kafka directly. Storage
+    /// writes fail if they cannot be added to the pending writes stream.
+    pub enabled: bool,
+    pub stream_name: String,
+    /// Stream to which events are moved once they could not be delivered after
+    /// `max_delivery_attempts` attempts
+    pub dead_letter_stream_name: String,
+    pub consumer_group: String,
+    /// Number of events read from the outbox stream in a single iteration of the relay
+    pub batch_size: u64,
+    /// Time in milliseconds the relay waits when the outbox is empty or Kafka is unavailable
+    pub poll_interval: u64,
+    /// Time in milliseconds to wait for Kafka to acknowledge an event
+    pub delivery_timeout: u64,
+    pub max_delivery_attempts: u32,
+    /// Time in milliseconds after which the events read but not acknowledged by a relay are
+    /// claimed by another relay, for instance when the pod running the relay was replaced
+    pub claim_min_idle_time: u64,
+    /// Stream to which storage writes are added before they are made, until their events are
+    /// added to the outbox stream
+    pub pending_writes_stream_name: String,
+    /// Time in milliseconds after which the events of a pending storage write are published from
+    /// the state of the record in the database
+    pub pending_write_timeout: u64,
+}
+
+impl Default for KafkaOutboxSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            stream_name: "KAFKA_OUTBOX_STREAM".into(),
+            dead_letter_stream_name: "KAFKA_OUTBOX_DEAD_LETTER_STREAM".into(),
+            consumer_group: "KAFKA_OUTBOX_GROUP".into(),
+            batch_size: 100,
+            poll_interval: 500,
+            delivery_timeout: 5000,
+            max_delivery_attempts: 10,
+            claim_min_idle_time: 600000,
+            pending_writes_stream_name: "KAFKA_OUTBOX_PENDING_WRITES_STREAM".into(),
+            pending_write_timeout: 60000,
+        }
+    }
+}
+
+impl KafkaOutboxSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.stream_name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka outbox stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka outbox dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.consumer_group.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka outbox consumer group must not be empty".into(),
+            ))
+        })?;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka outbox batch size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.max_delivery_attempts == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka outbox max delivery attempts must be greater than zero".into(),
+            ))
+        })?;
+
+        // A relay may take up to the delivery timeout to publish each event of a batch, the
+        // events of a batch must not be claimed by another relay in the meantime
+        when(
+            self.claim_min_idle_time <= self.batch_size.saturating_mul(self.delivery_timeout),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka outbox claim min idle time must be greater than the time taken to publish a batch of events".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.pending_writes_stream_name.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka outbox pending writes stream name must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(self.pending_write_timeout == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka outbox pending write timeout must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+/// A Kafka record as it is stored in the outbox stream, serialized exactly as it would have been
+/// sent to Kafka.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct KafkaOutboxEntry {
+    pub topic: String,
+    pub key: Option<String>,
+    pub payload: String,
+    pub timestamp: i64,
+}
+
+impl KafkaOutboxEntry {
+    pub fn from_event<T: KafkaMessage>(
+        topic: &str,
+        key: Option<String>,
+        event: &T,
+        timestamp: i64,
+    ) -> MQResult<Self> {
+        Ok(Self {
+            topic: topic.to_owned(),
+            key,
+            payload: String::from_utf8(event.value()?)
+                .change_context(KafkaError::GenericError)
+                .attach_printable("Kafka event payload is not valid UTF-8")?,
+            timestamp,
+        })
+    }
+}
+
+pub async fn enqueue_event(
+    redis_conn: &RedisConnectionPool,
+    settings: &KafkaOutboxSettings,
+    entry: &KafkaOutboxEntry,
+) -> MQResult<()> {
+    let serialized_entry = serde_json::to_string(entry)
+        .change_context(KafkaError::GenericError)
+        .attach_printable("Failed to serialize Kafka outbox entry")?;
+
+    redis_conn
+        .stream_append_entry(
+            &settings.stream_name.as_str().into(),
+            &RedisEntryId::AutoGeneratedID,
+            vec![
+                (OUTBOX_EVENT_FIELD, serialized_entry),
+                (
+                    OUTBOX_PUSHED_AT_FIELD,
+                    common_utils::date_time::now_unix_timestamp().to_string(),
+                ),
+            ],
+        )
+        .await
+        .map(|_| metrics::KAFKA_OUTBOX_EVENTS_ENQUEUED.add(1, &[]))
+        .inspect_err(|_| metrics::KAFKA_OUTBOX_ENQUEUE_FAILURES.add(1, &[]))
+        .change_context(KafkaError::OutboxError)
+        .attach_printable_lazy(|| {
+            format!("Failed to add event for topic {} to outbox", entry.topic)
+        })
+}
+
+/// A record whose events are added to the outbox once it is written to the database, identified
+/// so that its events can be published from its state in the database if they were not added to
+/// the outbox after the write.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KafkaOutboxRecord {
+    #[cfg(feature = "v1")]
+    PaymentIntent {
+        merchant_id: id_type::MerchantId,
+        payment_id: id_type::PaymentId,
+        storage_scheme: MerchantStorageScheme,
+        infra_values: Option<Value>,
+    },
+    #[cfg(feature = "v1")]
+    PaymentAttempt {
+        merchant_id: id_type::MerchantId,
+        attempt_id: String,
+        storage_scheme: MerchantStorageScheme,
+    },
+    #[cfg(feature = "v1")]
+    Refund {
+        merchant_id: id_type::MerchantId,
+        refund_id: String,
+        storage_scheme: MerchantStorageScheme,
+    },
+    Dispute {
+        merchant_id: id_type::MerchantId,
+        dispute_id: String,
+    },
+    Authentication {
+        merchant_id: id_type::MerchantId,
+        authentication_id: id_type::AuthenticationId,
+    },
+    #[cfg(feature = "payouts")]
+    Payout {
+        merchant_id: id_type::MerchantId,
+        payout_id: id_type::PayoutId,
+        payout_attempt_id: String,
+        storage_scheme: MerchantStorageScheme,
+    },
+}
+
+/// The state of a record before it is updated, from which the event that cancels this state in
+/// analytics is built
+pub enum KafkaOutboxOldRecord<'a> {
+    PaymentIntent(&'a PaymentIntent, Option<Value>),
+    PaymentAttempt(&'a PaymentAttempt),
+    Refund(&'a Refund),
+    Dispute(&'a Dispute),
+    Authentication(&'a Authentication),
+    #[cfg(feature = "payouts")]
+    Payout(KafkaPayout<'a>),
+}
+
+/// Adds a storage write to the pending writes stream before the record is written to the
+/// database, along with the events that cancel the earlier state of the record. Returns the ID
+/// of the pending write, which is to be removed once the events of the write are in the outbox.
+///
+/// Appending to the outbox after the database write is not atomic with the write, the pending
+/// write ensures that the events of the write are published by the relay even if the process
+/// stops or Redis is unavailable in between.
+pub async fn add_pending_write(
+    redis_conn: &RedisConnectionPool,
+    settings: &KafkaOutboxSettings,
+    record: &KafkaOutboxRecord,
+    old_events: &[KafkaOutboxEntry],
+) -> MQResult<String> {
+    let serialized_record = serde_json::to_string(record)
+        .change_context(KafkaError::GenericError)
+        .attach_printable("Failed to serialize Kafka outbox record")?;
+    let serialized_old_events = serde_json::to_string(old_events)
+        .change_context(KafkaError::GenericError)
+        .attach_printable("Failed to serialize Kafka outbox events")?;
+
+    redis_conn
+        .stream_append_entry_and_get_id(
+            &settings.pending_writes_stream_name.as_str().into(),
+            &RedisEntryId::AutoGeneratedID,
+            vec![
+                (PENDING_WRITE_RECORD_FIELD, serialized_record),
+                (PENDING_WRITE_OLD_EVENTS_FIELD, serialized_old_events),
+            ],
+        )
+        .await
+        .change_context(KafkaError::OutboxError)
+        .attach_printable_lazy(|| format!("Failed to add pending write for {record:?} to outbox"))
+}
+
+pub async fn remove_pending_write(
+    redis_conn: &RedisConnectionPool,
+    settings: &KafkaOutboxSettings,
+    pending_write_id: &str,
+) -> MQResult<()> {
+    redis_conn
+        .stream_delete_entries(
+            &settings.pending_writes_stream_name.as_str().into(),
+            pending_write_id,
+        )
+        .await
+        .map(|_| ())
+        .change_context(KafkaError::OutboxError)
+        .attach_printable_lazy(|| {
+            format!("Failed to remove pending write {pending_write_id} from outbox")
+        })
+}
+
+/// Publishes the events written to the outbox stream of a tenant to Kafka.
+///
+/// Events are read through a consumer group and acknowledged only after Kafka has confirmed their
+/// delivery, so an event is published at least once. Events that were read but not acknowledged,
+/// for instance because the relay was restarted, are published again before any new events,
+/// either by the same relay or by another relay once they were idle for the claim min idle time.
+///
+/// The relay also publishes the events of the pending storage writes that timed out, from the
+/// state of their records in the database.
+pub struct KafkaOutboxRelay {
+    producer: FutureProducer,
+    redis_conn: Arc<RedisConnectionPool>,
+    settings: KafkaOutboxSettings,
+    tenant_id: String,
+    /// Name of the relay in the consumer group, unique to every relay so that the relays of
+    /// different pods do not read each other's events
+    consumer_name: String,
+    store: Box<dyn StorageInterface>,
+    /// Used to add the events of the records of timed out pending writes to the outbox
+    kafka_producer: KafkaProducer,
+}
+
+impl KafkaOutboxRelay {
+    pub fn new(
+        conf: &KafkaSettings,
+        redis_conn: Arc<RedisConnectionPool>,
+        tenant_id: String,
+        store: Box<dyn StorageInterface>,
+        mut kafka_producer: KafkaProducer,
+    ) -> MQResult<Self> {
+        kafka_producer.set_outbox_connection(Arc::clone(&redis_conn));
+        Ok(Self {
+            producer: conf
+                .client_config()
+                .create()
+                .change_context(KafkaError::InitializationError)?,
+            redis_conn,
+            settings: conf.outbox.clone(),
+            tenant_id,
+            consumer_name: get_consumer_name(),
+            store,
+            kafka_producer,
+        })
+    }
+
+    pub async fn run(self) {
+        logger::info!(
+            tenant_id = %self.tenant_id,
+            consumer_name = %self.consumer_name,
+            "Starting Kafka outbox relay"
+        );
+
+        // The consumer group already exists if the relay ran earlier
+        let _ = self
+            .redis_conn
+            .consumer_group_create(
+                &self.settings.stream_name.as_str().into(),
+                &self.settings.consumer_group,
+                &first_entry_id(),
+            )
+            .await;
+
+        let mut delivery_attempts = HashMap::new();
+
+        loop {
+            self.record_backlog().await;
+            self.publish_timed_out_pending_writes().await;
+
+            let should_wait = match self.read_entries().await {
+                Ok(entries) if entries.is_empty() => true,
+                Ok(entries) => self.relay_entries(entries, &mut delivery_attempts).await,
+                Err(error) => {
+                    logger::error!(?error, tenant_id = %self.tenant_id, "Failed to read from Kafka outbox");
+                    true
+                }
+            };
+
+            if should_wait {
+                tokio::time::sleep(Duration::from_millis(self.settings.poll_interval)).await;
+            }
+        }
+    }
+
+    /// Reads the events that were read earlier by this relay but not acknowledged, then the events
+    /// claimed from other relays, and new events if there are neither.
+    async fn read_entries(&self) -> CustomResult<Vec<StreamEntry>, RedisError> {
+        let pending_entries = self.read_entries_after(first_entry_id()).await?;
+        if !pending_entries.is_empty() {
+            return Ok(pending_entries);
+        }
+
+        let claimed_entries = self.claim_idle_entries().await?;
+        if !claimed_entries.is_empty() {
+            return Ok(claimed_entries);
+        }
+
+        self.read_entries_after(RedisEntryId::UndeliveredEntryID)
+            .await
+    }
+
+    /// Claims the events that were read but not acknowledged by other relays for the claim min
+    /// idle time, such as the relays of pods that were stopped
+    async fn claim_idle_entries(&self) -> CustomResult<Vec<StreamEntry>, RedisError> {
+        self.redis_conn
+            .consumer_group_auto_claim_entries(
+                &self.settings.stream_name.as_str().into(),
+                &self.settings.consumer_group,
+                &self.consumer_name,
+                self.settings.claim_min_idle_time,
+                &first_entry_id(),
+                Some(self.settings.batch_size),
+            )
+            .await
+            .map(|(_, entries)| entries)
+    }
+
+    async fn read_entries_after(
+        &self,
+        entry_id: RedisEntryId,
+    ) -> CustomResult<Vec<StreamEntry>, RedisError> {
+        match self
+            .redis_conn
+            .stream_read_with_options(
+                self.settings.stream_name.as_str(),
+                entry_id,
+                Some(self.settings.batch_size),
+                None,
+                Some((
+                    self.settings.consumer_group.as_str(),
+                    self.consumer_name.as_str(),
+                )),
+            )
+            .await
+        {
+            Ok(response) => Ok(response.into_values().flatten().collect()),
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    RedisError::StreamEmptyOrNotAvailable
+                ) =>
+            {
+                Ok(Vec::new())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Publishes the entries in order, and stops at the first entry that could not be published
+    /// so that it is retried before the entries that follow it. Returns `true` if the relay
+    /// should wait before reading from the outbox again.
+    async fn relay_entries(
+        &self,
+        entries: Vec<StreamEntry>,
+        delivery_attempts: &mut HashMap<String, u32>,
+    ) -> bool {
+        let mut relayed_entry_ids = Vec::new();
+        let mut should_wait = false;
+
+        for (entry_id, mut fields) in entries {
+            // Entries deleted from the stream while they were pending are returned without fields
+            let Some(serialized_entry) = fields.remove(OUTBOX_EVENT_FIELD).flatten() else {
+                relayed_entry_ids.push(entry_id);
+                continue;
+            };
+
+            let parsed_entry: CustomResult<KafkaOutboxEntry, _> =
+                serialized_entry.parse_struct("KafkaOutboxEntry");
+            let publish_result = match parsed_entry.change_context(KafkaError::GenericError) {
+                Ok(entry) => self.publish(&entry).await,
+                Err(error) => Err(error),
+            };
+
+            match publish_result {
+                Ok(()) => {
+                    metrics::KAFKA_OUTBOX_EVENTS_PUBLISHED.add(1, &[]);
+                    if let Some(pushed_at) = fields.remove(OUTBOX_PUSHED_AT_FIELD).flatten() {
+                        self.record_delay(&pushed_at);
+                    }
+                    delivery_attempts.remove(&entry_id);
+                    relayed_entry_ids.push(entry_id);
+                }
+                Err(error) => {
+                    metrics::KAFKA_OUTBOX_PUBLISH_FAILURES.add(1, &[]);
+                    let attempts = delivery_attempts
+                        .get(&entry_id)
+                        .copied()
+                        .unwrap_or_default()
+                        .saturating_add(1);
+                    delivery_attempts.insert(entry_id.clone(), attempts);
+                    logger::error!(?error, %entry_id, attempts, "Failed to publish Kafka outbox event");
+
+                    if attempts < self.settings.max_delivery_attempts {
+                        should_wait = true;
+                        break;
+                    }
+
+                    match self.dead_letter(serialized_entry, &error).await {
+                        Ok(()) => {
+                            delivery_attempts.remove(&entry_id);
+                            relayed_entry_ids.push(entry_id);
+                        }
+                        Err(error) => {
+                            logger::error!(?error, %entry_id, "Failed to dead letter Kafka outbox event");
+                            should_wait = true;
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Err(error) = self.acknowledge_entries(relayed_entry_ids).await {
+            logger::error!(?error, tenant_id = %self.tenant_id, "Failed to acknowledge Kafka outbox events");
+        }
+
+        should_wait
+    }
+
+    async fn publish(&self, entry: &KafkaOutboxEntry) -> MQResult<()> {
+        let mut record = FutureRecord::to(&entry.topic)
+            .payload(&entry.payload)
+            .timestamp(entry.timestamp);
+        if let Some(key) = entry.key.as_ref() {
+            record = record.key(key);
+        }
+        self.producer
+            .send(
+                record,
+                Timeout::After(Duration::from_millis(self.settings.delivery_timeout)),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|(error, _)| report!(error))
+            .change_context(KafkaError::GenericError)
+            .attach_printable_lazy(|| format!("Failed to publish event to topic {}", entry.topic))
+    }
+
+    async fn dead_letter(
+        &self,
+        serialized_entry: String,
+        error: &error_stack::Report<KafkaError>,
+    ) -> CustomResult<(), RedisError> {
+        self.redis_conn
+            .stream_append_entry(
+                &self.settings.dead_letter_stream_name.as_str().into(),
+                &RedisEntryId::AutoGeneratedID,
+                vec![
+                    (OUTBOX_EVENT_FIELD, serialized_entry),
+                    (OUTBOX_ERROR_FIELD, format!("{error:?}")),
+                    (
+                        OUTBOX_PUSHED_AT_FIELD,
+                        common_utils::date_time::now_unix_timestamp().to_string(),
+                    ),
+                ],
+            )
+            .await
+            .map(|_| metrics::KAFKA_OUTBOX_EVENTS_DEAD_LETTERED.add(1, &[]))
+    }
+
+    async fn acknowledge_entries(&self, entry_ids: Vec<String>) -> CustomResult<(), RedisError> {
+        if entry_ids.is_empty() {
+            return Ok(());
+        }
+
+        let stream_name = self.settings.stream_name.as_str().into();
+        self.redis_conn
+            .stream_acknowledge_entries(
+                &stream_name,
+                &self.settings.consumer_group,
+                entry_ids.clone(),
+            )
+            .await?;
+        self.redis_conn
+            .stream_delete_entries(&stream_name, entry_ids)
+            .await
+            .map(|_| ())
+    }
+
+    /// Publishes the events of the pending writes that were not removed within the pending write
+    /// timeout. Pending writes are read in the order in which they were added, so reading stops
+    /// at the first pending write that has not timed out.
+    async fn publish_timed_out_pending_writes(&self) {
+        let pending_writes = match self
+            .redis_conn
+            .stream_read_with_options(
+                self.settings.pending_writes_stream_name.as_str(),
+                first_entry_id(),
+                Some(self.settings.batch_size),
+                None,
+                None,
+            )
+            .await
+        {
+            Ok(response) => response.into_values().flatten(),
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    RedisError::StreamEmptyOrNotAvailable
+                ) =>
+            {
+                return;
+            }
+            Err(error) => {
+                logger::error!(?error, tenant_id = %self.tenant_id, "Failed to read Kafka outbox pending writes");
+                return;
+            }
+        };
+
+        let timed_out_before = common_utils::date_time::now_unix_timestamp()
+            .saturating_mul(1000)
+            .saturating_sub(i64::try_from(self.settings.pending_write_timeout).unwrap_or(i64::MAX));
+        for (pending_write_id, fields) in pending_writes {
+            if !is_pending_write_timed_out(&pending_write_id, timed_out_before) {
+                break;
+            }
+
+            if let Err(error) = self.publish_pending_write(&pending_write_id, fields).await {
+                logger::error!(?error, %pending_write_id, "Failed to publish Kafka outbox pending write");
+            }
+        }
+    }
+
+    async fn publish_pending_write(
+        &self,
+        pending_write_id: &str,
+        mut fields: HashMap<String, Option<String>>,
+    ) -> MQResult<()> {
+        // The relays of all pods read the same pending writes, the lock ensures that the events
+        // of a pending write are published by one of them. The lock is left to expire, so that
+        // the pending write is retried if its events could not be published.
+        let lock_key = format!(
+            "{}_{pending_write_id}",
+            self.settings.pending_writes_stream_name
+        );
+        let lock_expiry = i64::try_from(self.settings.pending_write_timeout / 1000)
+            .unwrap_or(i64::MAX)
+            .max(1);
+        let lock_reply = self
+            .redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &lock_key.as_str().into(),
+                self.consumer_name.as_str(),
+                Some(lock_expiry),
+            )
+            .await
+            .change_context(KafkaError::OutboxError)
+            .attach_printable("Failed to lock Kafka outbox pending write")?;
+        if lock_reply != SetnxReply::KeySet {
+            return Ok(());
+        }
+
+        let record: CustomResult<Option<KafkaOutboxRecord>, _> = fields
+            .remove(PENDING_WRITE_RECORD_FIELD)
+            .flatten()
+            .map(|record| record.parse_struct("KafkaOutboxRecord"))
+            .transpose();
+        let old_events: CustomResult<Option<Vec<KafkaOutboxEntry>>, _> = fields
+            .remove(PENDING_WRITE_OLD_EVENTS_FIELD)
+            .flatten()
+            .map(|old_events| old_events.parse_struct("Vec<KafkaOutboxEntry>"))
+            .transpose();
+
+        match (record, old_events) {
+            (Ok(Some(record)), Ok(old_events)) => {
+                self.add_record_events(&record, old_events.unwrap_or_default())
+                    .await?;
+                metrics::KAFKA_OUTBOX_PENDING_WRITES_PUBLISHED.add(1, &[]);
+            }
+            (record, old_events) => {
+                // The pending write can never be published, it is removed so that it does not
+                // block the pending writes added after it
+                logger::error!(
+                    record = ?record.map(|_| ()),
+                    old_events = ?old_events.map(|_| ()),
+                    %pending_write_id,
+                    "Invalid Kafka outbox pending write"
+                );
+            }
+        }
+
+        remove_pending_write(&self.redis_conn, &self.settings, pending_write_id).await
+    }
+
+    /// Adds the events of a record to the outbox from its state in the database, after the events
+    /// that cancel its earlier state. Nothing is added if the record does not exist, since the
+    /// write of the record failed in that case.
+    async fn add_record_events(
+        &self,
+        record: &KafkaOutboxRecord,
+        old_events: Vec<KafkaOutboxEntry>,
+    ) -> MQResult<()> {
+        let db = self.store.as_ref();
+        let tenant_id = TenantID(self.tenant_id.clone());
+
+        match record {
+            #[cfg(feature = "v1")]
+            KafkaOutboxRecord::PaymentIntent {
+                merchant_id,
+                payment_id,
+                storage_scheme,
+                infra_values,
+            } => {
+                let key_store = db
+                    .get_merchant_key_store_by_merchant_id(
+                        merchant_id,
+                        &db.get_master_key().to_vec().into(),
+                    )
+                    .await
+                    .change_context(KafkaError::OutboxError)
+                    .attach_printable("Failed to get merchant key store")?;
+                let Some(payment_intent) = find_record(
+                    db.find_payment_intent_by_payment_id_merchant_id(
+                        payment_id,
+                        merchant_id,
+                        &key_store,
+                        *storage_scheme,
+                    )
+                    .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                self.add_old_events(&old_events).await?;
+                self.kafka_producer
+                    .log_payment_intent(&payment_intent, None, tenant_id, infra_values.clone())
+                    .await
+            }
+            #[cfg(feature = "v1")]
+            KafkaOutboxRecord::PaymentAttempt {
+                merchant_id,
+                attempt_id,
+                storage_scheme,
+            } => {
+                let Some(payment_attempt) = find_record(
+                    db.find_payment_attempt_by_attempt_id_merchant_id(
+                        attempt_id,
+                        merchant_id,
+                        *storage_scheme,
+                    )
+                    .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                self.add_old_events(&old_events).await?;
+                self.kafka_producer
+                    .log_payment_attempt(&payment_attempt, None, tenant_id)
+                    .await
+            }
+            #[cfg(feature = "v1")]
+            KafkaOutboxRecord::Refund {
+                merchant_id,
+                refund_id,
+                storage_scheme,
+            } => {
+                let Some(refund) = find_record(
+                    db.find_refund_by_merchant_id_refund_id(
+                        merchant_id,
+                        refund_id,
+                        *storage_scheme,
+                    )
+                    .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                self.add_old_events(&old_events).await?;
+                self.kafka_producer
+                    .log_refund(&refund, None, tenant_id)
+                    .await
+            }
+            KafkaOutboxRecord::Dispute {
+                merchant_id,
+                dispute_id,
+            } => {
+                let Some(dispute) = find_record(
+                    db.find_dispute_by_merchant_id_dispute_id(merchant_id, dispute_id)
+                        .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                self.add_old_events(&old_events).await?;
+                self.kafka_producer
+                    .log_dispute(&dispute, None, tenant_id)
+                    .await
+            }
+            KafkaOutboxRecord::Authentication {
+                merchant_id,
+                authentication_id,
+            } => {
+                let Some(authentication) = find_record(
+                    db.find_authentication_by_merchant_id_authentication_id(
+                        merchant_id,
+                        authentication_id,
+                    )
+                    .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                self.add_old_events(&old_events).await?;
+                self.kafka_producer
+                    .log_authentication(&authentication, None, tenant_id)
+                    .await
+            }
+            #[cfg(feature = "payouts")]
+            KafkaOutboxRecord::Payout {
+                merchant_id,
+                payout_id,
+                payout_attempt_id,
+                storage_scheme,
+            } => {
+                let Some(payout) = find_record(
+                    db.find_payout_by_merchant_id_payout_id(
+                        merchant_id,
+                        payout_id,
+                        *storage_scheme,
+                    )
+                    .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                let Some(payout_attempt) = find_record(
+                    db.find_payout_attempt_by_merchant_id_payout_attempt_id(
+                        merchant_id,
+                        payout_attempt_id,
+                        *storage_scheme,
+                    )
+                    .await,
+                )?
+                else {
+                    return Ok(());
+                };
+                self.add_old_events(&old_events).await?;
+                self.kafka_producer
+                    .log_payout(
+                        &KafkaPayout::from_storage(&payout, &payout_attempt),
+                        None,
+                        tenant_id,
+                    )
+                    .await
+            }
+        }
+    }
+
+    async fn add_old_events(&self, old_events: &[KafkaOutboxEntry]) -> MQResult<()> {
+        for old_event in old_events {
+            enqueue_event(&self.redis_conn, &self.settings, old_event).await?;
+        }
+        Ok(())
+    }
+
+    fn record_delay(&self, pushed_at: &str) {
+        let published_at = common_utils::date_time::now_unix_timestamp();
+        let delay = pushed_at
+            .parse::<i64>()
+            .ok()
+            .and_then(|pushed_at| u64::try_from(published_at - pushed_at).ok());
+
+        match delay {
+            Some(delay) => metrics::KAFKA_OUTBOX_RELAY_DELAY_SECONDS.record(
+                delay,
+                metric_attributes!(("tenant", self.tenant_id.clone())),
+            ),
+            None => logger::error!(pushed_at, published_at, "Invalid Kafka outbox delay"),
+        }
+    }
+
+    async fn record_backlog(&self) {
+        match self
+            .redis_conn
+            .stream_get_length(&self.settings.stream_name.as_str().into())
+            .await
+        {
+            Ok(length) => metrics::KAFKA_OUTBOX_PENDING_EVENTS.record(
+                u64::try_from(length).unwrap_or(u64::MAX),
+                metric_attributes!(("tenant", self.tenant_id.clone())),
+            ),
+            Err(error) => {
+                logger::error!(?error, tenant_id = %self.tenant_id, "Failed to get Kafka outbox length")
+            }
+        }
+    }
+}
+
+type StreamEntry = (String, HashMap<String, Option<String>>);
+
+/// Returns the record if it exists, or `None` if it was not found
+fn find_record<T>(result: CustomResult<T, errors::StorageError>) -> MQResult<Option<T>> {
+    match result {
+        Ok(record) => Ok(Some(record)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(KafkaError::OutboxError)
+            .attach_printable("Failed to find Kafka outbox record")),
+    }
+}
+
+/// Returns whether the pending write was added before the given time, in milliseconds since the
+/// Unix epoch. Stream entry IDs start with the time at which the entry was added.
+fn is_pending_write_timed_out(pending_write_id: &str, timed_out_before: i64) -> bool {
+    match pending_write_id
+        .split_once('-')
+        .and_then(|(milliseconds, _)| milliseconds.parse::<i64>().ok())
+    {
+        Some(added_at) => added_at < timed_out_before,
+        // IDs are always generated by Redis, an invalid ID is handled as timed out so that it
+        // does not block the pending writes added after it
+        None => true,
+    }
+}
+
+fn get_consumer_name() -> String {
+    format!("kafka_outbox_relay_{}", uuid::Uuid::new_v4())
+}
+
+fn first_entry_id() -> RedisEntryId {
+    RedisEntryId::UserSpecifiedID {
+        milliseconds: "0".into(),
+        sequence_number: "0".into(),
+    }
+}
+
+/// Spawns a relay for the outbox stream of every tenant, if events are written to the outbox.
+pub fn spawn_outbox_relays(state: &AppState) -> Result<(), ApplicationError> {
+    let EventsConfig::Kafka { kafka } = state.conf.events.get_inner() else {
+        return Ok(());
+    };
+    if !kafka.outbox.enabled {
+        return Ok(());
+    }
+
+    let app_state = Arc::new(state.clone());
+    for (tenant_id, store) in state.stores.iter() {
+        let session_state = Arc::clone(&app_state)
+            .get_session_state(tenant_id, None, || ApplicationError::ConfigurationError)
+            .inspect_err(|_| {
+                logger::error!(
+                    ?tenant_id,
+                    "Failed to get session state for Kafka outbox relay"
+                )
+            })?;
+        let EventsHandler::Kafka(kafka_producer) = session_state.event_handler else {
+            logger::error!("Kafka outbox is enabled without the Kafka events handler");
+            return Err(ApplicationError::ConfigurationError);
+        };
+        let redis_conn = store.get_redis_conn().map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for Kafka outbox relay"
+            );
+            ApplicationError::ConfigurationError
+        })?;
+        let relay = KafkaOutboxRelay::new(
+            kafka,
+            redis_conn,
+            tenant_id.get_string_repr().to_owned(),
+            session_state.store,
+            kafka_producer,
+        )
+        .map_err(|error| {
+            logger::error!(?error, "Failed to create Kafka outbox relay");
+            ApplicationError::ConfigurationError
+        })?;
+        tokio::spawn(relay.run());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_enabled_settings() -> KafkaOutboxSettings {
+        KafkaOutboxSettings {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_default_settings_are_valid() {
+        assert!(KafkaOutboxSettings::default().validate().is_ok());
+        assert!(get_enabled_settings().validate().is_ok());
+    }
+
+    #[test]
+    fn test_claim_min_idle_time_must_exceed_batch_delivery_time() {
+        let settings = KafkaOutboxSettings {
+            batch_size: 10,
+            delivery_timeout: 1000,
+            claim_min_idle_time: 10000,
+            ..get_enabled_settings()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaOutboxSettings {
+            claim_min_idle_time: 10001,
+            ..settings
+        };
+        assert!(settings.validate().is_ok());
+    }
+
+    #[test]
+    fn test_pending_write_settings_are_validated() {
+        let settings = KafkaOutboxSettings {
+            pending_writes_stream_name: String::new(),
+            ..get_enabled_settings()
+        };
+        assert!(settings.validate().is_err());
+
+        let settings = KafkaOutboxSettings {
+            pending_write_timeout: 0,
+            ..get_enabled_settings()
+        };
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_pending_write_timeout() {
+        assert!(is_pending_write_timed_out("1000-0", 1001));
+        assert!(!is_pending_write_timed_out("1000-0", 1000));
+        assert!(!is_pending_write_timed_out("2000-5", 1000));
+        assert!(is_pending_write_timed_out("invalid", 1000));
+    }
+
+    #[test]
+    fn test_consumer_names_are_unique() {
+        let consumer_name = get_consumer_name();
+        assert!(consumer_name.starts_with("kafka_outbox_relay_"));
+        assert_ne!(consumer_name, get_consumer_name());
+    }
+
+    #[test]
+    fn test_record_serialization() {
+        let record = KafkaOutboxRecord::Dispute {
+            merchant_id: id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap(),
+            dispute_id: "dispute_1".to_string(),
+        };
+
+        let serialized_record = serde_json::to_value(&record).unwrap();
+        assert_eq!(
+            serialized_record,
+            serde_json::json!({
+                "type": "dispute",
+                "merchant_id": "merchant_1",
+                "dispute_id": "dispute_1",
+            })
+        );
+
+        let deserialized_record: KafkaOutboxRecord =
+            serde_json::from_value(serialized_record).unwrap();
+        assert!(matches!(
+            deserialized_record,
+            KafkaOutboxRecord::Dispute { dispute_id, .. } if dispute_id == "dispute_1"
+        ));
+    }
+}
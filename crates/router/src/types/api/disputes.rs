@@ -1,3 +1,4 @@
+pub use api_models::disputes::{DisputeAutoAcceptRule, DisputeAutomationConfig};
 pub use hyperswitch_interfaces::{
     api::disputes::{
         AcceptDispute, DefendDispute, Dispute, DisputeSync, FetchDisputes, SubmitEvidence,
@@ -79,3 +80,9 @@ pub struct DisputeListPTData {
     pub created_from: time::PrimitiveDateTime,
     pub created_till: time::PrimitiveDateTime,
 }
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct DisputeDeadlinePTData {
+    pub dispute_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -68,3 +68,13 @@ pub struct CreateFileRequest {
     pub purpose: FilePurpose,
     pub dispute_id: Option<String>,
 }
+
+/// Tracking data of the task that scans a quarantined file again when the scanner was unavailable
+/// at upload time
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FileScanPTData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub purpose: FilePurpose,
+    pub dispute_id: Option<String>,
+}
@@ -68,3 +68,93 @@ impl FraudCheckConnectorData {
         }
     }
 }
+
+/// Configuration of the native fraud engine for a merchant, stored in the configs table against
+/// `MerchantId::get_native_frm_config_key`
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct NativeFrmConfig {
+    /// Gateways, payment methods and flow types for which the engine is run, in the same format as
+    /// the `frm_configs` of an FRM connector account
+    #[serde(default)]
+    pub frm_configs: Vec<api_models::admin::FrmConfigs>,
+    #[serde(default)]
+    pub signal_weights: NativeFrmSignalWeights,
+    #[serde(default)]
+    pub thresholds: NativeFrmThresholds,
+    #[serde(default)]
+    pub velocity: NativeFrmVelocityConfig,
+    /// Email domains known to have been registered recently, such as disposable email providers
+    #[serde(default)]
+    pub recently_registered_email_domains: Vec<String>,
+}
+
+impl common_utils::events::ApiEventMetric for NativeFrmConfig {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// Contribution of each signal to the risk score of a payment, when the signal is triggered. The
+/// risk score is the sum of the weights of the triggered signals, capped at 100.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NativeFrmSignalWeights {
+    /// The card was issued in a country other than the billing or shipping country. The country
+    /// of the IP address of the customer is not considered.
+    pub card_country_mismatch: u8,
+    /// The customer, card or IP address exceeded the configured number of payment attempts
+    pub velocity: u8,
+    /// The email address of the customer belongs to a recently registered domain
+    pub recently_registered_email_domain: u8,
+    /// The card testing guard recorded unsuccessful payments for the card or the customer
+    pub card_testing_guard: u8,
+    /// Chargebacks were previously received for the card
+    pub previous_chargebacks: u8,
+}
+
+impl Default for NativeFrmSignalWeights {
+    fn default() -> Self {
+        Self {
+            card_country_mismatch: 25,
+            velocity: 25,
+            recently_registered_email_domain: 15,
+            card_testing_guard: 20,
+            previous_chargebacks: 40,
+        }
+    }
+}
+
+/// Risk scores at which payments are sent for manual review or declined
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NativeFrmThresholds {
+    pub review: u8,
+    pub decline: u8,
+}
+
+impl Default for NativeFrmThresholds {
+    fn default() -> Self {
+        Self {
+            review: 50,
+            decline: 80,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct NativeFrmVelocityConfig {
+    /// Duration of the window within which payment attempts are counted
+    pub window_in_secs: u32,
+    /// Number of payment attempts within the window above which the velocity signal is triggered
+    pub max_attempts: u32,
+}
+
+impl Default for NativeFrmVelocityConfig {
+    fn default() -> Self {
+        Self {
+            window_in_secs: 60 * 60,
+            max_attempts: 5,
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub use api_models::payouts::{
     AchBankTransfer, BacsBankTransfer, Bank as BankPayout, BankRedirect as BankRedirectPayout,
     CardPayout, Passthrough as PassthroughPayout, PaymentMethodTypeInfo, PayoutActionRequest,
-    PayoutAttemptResponse, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutAttemptResponse, PayoutBatchCreateRequest, PayoutBatchCsvRecord, PayoutBatchItemResponse,
+    PayoutBatchItemsListConstraints, PayoutBatchItemsResponse, PayoutBatchListConstraints,
+    PayoutBatchListResponse, PayoutBatchResponse, PayoutCreateRequest, PayoutCreateResponse,
     PayoutEnabledPaymentMethodsInfo, PayoutLinkResponse, PayoutListConstraints,
     PayoutListFilterConstraints, PayoutListFilters, PayoutListFiltersV2, PayoutListResponse,
     PayoutMethodData, PayoutMethodDataResponse, PayoutRequest, PayoutRetrieveBody,
@@ -209,6 +209,11 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
                     message: "Taxjar is not a routable connector".to_string(),
                 })?
             }
+            api_enums::Connector::Truelayer => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "Truelayer is not a routable connector".to_string(),
+                })?
+            }
             api_enums::Connector::Phonepe => Self::Phonepe,
             api_enums::Connector::Paytm => Self::Paytm,
         })
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use error_stack::ResultExt;
 use pm_auth::{
-    connector::plaid,
+    connector::{plaid, truelayer},
     types::{
         self as pm_auth_types,
         api::{BoxedPaymentAuthConnector, PaymentAuthConnectorData},
@@ -32,6 +32,9 @@ impl PaymentAuthConnectorDataExt for PaymentAuthConnectorData {
     ) -> errors::CustomResult<BoxedPaymentAuthConnector, ApiErrorResponse> {
         match connector_name {
             pm_auth_types::PaymentMethodAuthConnectors::Plaid => Ok(Box::new(&plaid::Plaid)),
+            pm_auth_types::PaymentMethodAuthConnectors::Truelayer => {
+                Ok(Box::new(&truelayer::Truelayer))
+            }
         }
     }
 }
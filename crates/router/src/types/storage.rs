@@ -1,4 +1,7 @@
 pub mod address;
+pub mod analytics_alert;
+pub mod analytics_export;
+pub mod analytics_report;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -14,6 +17,7 @@ pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
 pub mod dynamic_routing_stats;
+pub mod email_outbox;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
@@ -33,6 +37,7 @@ pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
 #[cfg(feature = "v2")]
@@ -42,14 +47,15 @@ pub mod revenue_recovery_redis_operation;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_report;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
 
 pub use diesel_models::{
-    process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
-    ProcessTrackerUpdate,
+    process_tracker::business_status, ProcessTracker, ProcessTrackerFilterConstraints,
+    ProcessTrackerNew, ProcessTrackerRunner, ProcessTrackerUpdate,
 };
 #[cfg(feature = "v1")]
 pub use hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptNew;
@@ -71,13 +77,14 @@ pub use hyperswitch_domain_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
-    dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    address::*, analytics_alert::*, analytics_export::*, analytics_report::*, api_keys::*,
+    authentication::*, authorization::*, blocklist::*, blocklist_fingerprint::*,
+    blocklist_lookup::*, business_profile::*, callback_mapper::*, capture::*, cards_info::*,
+    configs::*, customers::*, dashboard_metadata::*, dispute::*, dynamic_routing_stats::*,
+    email_outbox::*, ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*,
+    hyperswitch_ai_interaction::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
+    payout_batch::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, settlement_report::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*,
 };
@@ -0,0 +1,9 @@
+pub use diesel_models::analytics_alert::{
+    AnalyticsAlertHistory, AnalyticsAlertHistoryNew, AnalyticsAlertRule, AnalyticsAlertRuleNew,
+    AnalyticsAlertRuleUpdate, AnalyticsAlertRuleUpdateInternal,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AnalyticsAlertTrackingData {
+    pub rule_id: String,
+}
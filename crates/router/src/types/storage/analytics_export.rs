@@ -0,0 +1,12 @@
+pub use diesel_models::analytics_export::{
+    AnalyticsExport, AnalyticsExportNew, AnalyticsExportRun, AnalyticsExportRunNew,
+    AnalyticsExportRunUpdate, AnalyticsExportRunUpdateInternal, AnalyticsExportUpdate,
+    AnalyticsExportUpdateInternal,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AnalyticsExportTrackingData {
+    pub export_id: String,
+    /// Set when the task re-runs a single failed run instead of following the export schedule
+    pub run_id: Option<String>,
+}
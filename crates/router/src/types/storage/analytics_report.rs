@@ -0,0 +1,8 @@
+pub use diesel_models::analytics_report::{
+    AnalyticsReport, AnalyticsReportNew, AnalyticsReportUpdate, AnalyticsReportUpdateInternal,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AnalyticsReportTrackingData {
+    pub report_id: String,
+}
@@ -2,6 +2,8 @@ use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
 pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+#[cfg(feature = "v1")]
+use diesel_models::schema::payment_attempt::dsl as payment_attempt_dsl;
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::disputes;
@@ -22,6 +24,14 @@ pub trait DisputeDbExt: Sized {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::DatabaseError>;
+
+    #[cfg(feature = "v1")]
+    async fn count_chargebacks_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -142,4 +152,36 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    #[cfg(feature = "v1")]
+    async fn count_chargebacks_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        created_after: time::PrimitiveDateTime,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        // Disputes do not hold the fingerprint of the card, which is looked up from the payment
+        // attempts of the merchant
+        let attempt_ids = payment_attempt_dsl::payment_attempt
+            .filter(payment_attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payment_attempt_dsl::fingerprint_id.eq(fingerprint_id.to_owned()))
+            .select(payment_attempt_dsl::attempt_id);
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::dispute_stage.ne(common_enums::DisputeStage::PreDispute))
+            .filter(dsl::created_at.ge(created_after))
+            .filter(dsl::attempt_id.eq_any(attempt_ids))
+            .count();
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting chargebacks by fingerprint")
+    }
 }
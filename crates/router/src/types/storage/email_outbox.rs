@@ -0,0 +1,8 @@
+pub use diesel_models::email_outbox::{
+    EmailOutbox, EmailOutboxNew, EmailOutboxUpdate, EmailOutboxUpdateInternal,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct EmailOutboxTrackingData {
+    pub outbox_id: String,
+}
@@ -0,0 +1,10 @@
+pub use diesel_models::payout_batch::{
+    PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+    PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate, PayoutBatchUpdateInternal,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::settlement_report::{
+    ReconResult, ReconResultNew, SettlementReport, SettlementReportNew,
+};
@@ -778,6 +778,7 @@ impl ForeignFrom<storage::FileMetadata> for api_models::files::FileMetadataRespo
             file_size: file_metadata.file_size,
             file_type: file_metadata.file_type,
             available: file_metadata.available,
+            scan_status: file_metadata.scan_status,
         }
     }
 }
@@ -2077,6 +2078,7 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
             multiple_webhooks_list: None,
+            webhook_format: item.webhook_format,
         }
     }
 }
@@ -2096,6 +2098,7 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_statuses_enabled: item.payment_statuses_enabled,
             refund_statuses_enabled: item.refund_statuses_enabled,
             payout_statuses_enabled: item.payout_statuses_enabled,
+            webhook_format: item.webhook_format,
         }
     }
 }
@@ -1,10 +1,22 @@
+#[cfg(feature = "olap")]
+pub mod analytics_alert;
+#[cfg(feature = "olap")]
+pub mod analytics_export;
+#[cfg(feature = "olap")]
+pub mod analytics_report;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+#[cfg(feature = "email")]
+pub mod email_outbox;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod kafka_events_backfill;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(all(feature = "payouts", feature = "v1"))]
+pub mod payout_batch;
 
 pub mod refund_router;
 
@@ -16,4 +28,11 @@ pub mod process_dispute;
 
 pub mod dispute_list;
 
+#[cfg(feature = "v1")]
+pub mod dispute_deadline;
+
+pub mod file_scan;
+
 pub mod invoice_sync;
+
+pub mod subscription_billing;
@@ -0,0 +1,71 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{core::analytics_alert, errors, routes::SessionState, types::storage};
+
+pub struct AnalyticsAlertWorkflow;
+
+/// This workflow evaluates an alert rule once every evaluation window of the rule, and keeps
+/// rescheduling itself until the rule is deleted
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsAlertWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::AnalyticsAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsAlertTrackingData")?;
+
+        let alert_rule = match db
+            .find_analytics_alert_rule_by_id(&tracking_data.rule_id)
+            .await
+        {
+            Ok(alert_rule) => alert_rule,
+            Err(error) if error.current_context().is_db_not_found() => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        // A failed evaluation is not retried, the rule is evaluated again in the next window
+        if alert_rule.is_enabled {
+            if let Err(error) = analytics_alert::evaluate_alert_rule(state, &alert_rule).await {
+                logger::error!(
+                    ?error,
+                    rule_id = %alert_rule.id,
+                    "Failed to evaluate analytics alert rule"
+                );
+            }
+        }
+
+        db.as_scheduler()
+            .reset_process(
+                process,
+                analytics_alert::get_next_evaluation_time(&alert_rule),
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
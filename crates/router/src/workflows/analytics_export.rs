@@ -0,0 +1,91 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{core::analytics_export, errors, routes::SessionState, types::storage};
+
+pub struct AnalyticsExportWorkflow;
+
+/// This workflow exports the latest period of an export once the period is due, and keeps
+/// rescheduling itself until the export is deleted. Tasks created for a single run export the
+/// period of the run again and finish.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsExportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::AnalyticsExportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsExportTrackingData")?;
+
+        let export = match db
+            .find_analytics_export_by_id(&tracking_data.export_id)
+            .await
+        {
+            Ok(export) => export,
+            Err(error) if error.current_context().is_db_not_found() => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        if let Some(run_id) = &tracking_data.run_id {
+            let export_run = db
+                .find_analytics_export_run_by_merchant_id_run_id(&export.merchant_id, run_id)
+                .await?;
+            // The failure is recorded on the run, which can be retried again
+            if let Err(error) =
+                analytics_export::run_export(state, &export, export_run.period_start).await
+            {
+                logger::error!(
+                    ?error,
+                    export_id = %export.id,
+                    run_id = %export_run.id,
+                    "Failed to retry analytics export run"
+                );
+            }
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        // A failed run is not retried automatically, it can be retried through the API
+        if export.is_enabled {
+            let period_start = analytics_export::get_latest_due_period_start(&export);
+            if let Err(error) = analytics_export::run_export(state, &export, period_start).await {
+                logger::error!(
+                    ?error,
+                    export_id = %export.id,
+                    "Failed to run analytics export"
+                );
+            }
+        }
+
+        db.as_scheduler()
+            .reset_process(process, analytics_export::get_next_run_time(&export))
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
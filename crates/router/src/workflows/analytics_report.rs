@@ -0,0 +1,166 @@
+use analytics::reports::{ReportError, ReportSink};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use external_services::file_storage::FileUpload;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::analytics_report,
+    errors,
+    routes::SessionState,
+    types::storage::{self, enums as storage_enums},
+};
+
+pub struct AnalyticsReportWorkflow;
+
+/// This workflow generates a report requested with the in-process report engine, stores the report
+/// in the file storage and notifies the recipients of the report once it is ready
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsReportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::AnalyticsReportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsReportTrackingData")?;
+
+        let analytics_report = db
+            .find_analytics_report_by_id(&tracking_data.report_id)
+            .await?;
+
+        // A report is generated only once, even if the task is picked up again
+        if analytics_report.status != storage_enums::AnalyticsReportStatus::Pending {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let analytics_report = db
+            .update_analytics_report(analytics_report, storage::AnalyticsReportUpdate::Processing)
+            .await?;
+        let report_id = analytics_report.id.clone();
+
+        let analytics_report = match generate_report(state, &analytics_report).await {
+            Ok(report_update) => {
+                db.update_analytics_report(analytics_report, report_update)
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(?error, %report_id, "Failed to generate analytics report");
+                db.update_analytics_report(
+                    analytics_report,
+                    storage::AnalyticsReportUpdate::Failed {
+                        error_message: error.current_context().to_string(),
+                    },
+                )
+                .await?;
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::FAILURE)
+                    .await
+                    .map_err(Into::into);
+            }
+        };
+
+        if let Err(error) =
+            analytics_report::send_report_notifications(state, &analytics_report).await
+        {
+            logger::error!(?error, %report_id, "Failed to send analytics report notifications");
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Writes a report file to the file storage as it is generated
+struct ReportFileUpload(Box<dyn FileUpload>);
+
+#[async_trait::async_trait]
+impl ReportSink for ReportFileUpload {
+    async fn write_part(&mut self, part: Vec<u8>) -> errors::CustomResult<(), ReportError> {
+        self.0
+            .write_part(part)
+            .await
+            .change_context(ReportError::FileUploadFailure)
+            .attach_printable("Failed to upload part of report file")
+    }
+}
+
+async fn generate_report(
+    state: &SessionState,
+    analytics_report: &storage::AnalyticsReport,
+) -> errors::CustomResult<storage::AnalyticsReportUpdate, ReportError> {
+    let auth = analytics_report::get_report_auth_info(analytics_report)
+        .change_context(ReportError::QueryBuildingError)?;
+    let file_key = analytics_report::get_report_file_key(&analytics_report.id);
+
+    let mut upload = ReportFileUpload(
+        state
+            .file_storage_client
+            .start_upload(&file_key)
+            .await
+            .change_context(ReportError::FileUploadFailure)
+            .attach_printable("Failed to start upload of report file")?,
+    );
+    let report_summary = match state
+        .pool
+        .generate_report(
+            analytics_report.report_type,
+            &auth,
+            &analytics_report::get_report_time_range(analytics_report),
+            state.conf.report_download_config.page_size,
+            &mut upload,
+        )
+        .await
+    {
+        Ok(report_summary) => report_summary,
+        Err(error) => {
+            if let Err(abort_error) = upload.0.abort().await {
+                logger::error!(?abort_error, "Failed to abort upload of report file");
+            }
+            return Err(error);
+        }
+    };
+    upload
+        .0
+        .complete()
+        .await
+        .change_context(ReportError::FileUploadFailure)
+        .attach_printable("Failed to complete upload of report file")?;
+
+    let file_size = i64::try_from(report_summary.file_size)
+        .change_context(ReportError::FileWriteFailure)
+        .attach_printable("Report file size does not fit in i64")?;
+    let row_count = i64::try_from(report_summary.row_count)
+        .change_context(ReportError::FileWriteFailure)
+        .attach_printable("Report row count does not fit in i64")?;
+
+    Ok(storage::AnalyticsReportUpdate::Completed {
+        file_key,
+        file_size,
+        row_count,
+    })
+}
@@ -9,12 +9,9 @@ use crate::{
     consts, errors,
     logger::error,
     routes::{metrics, SessionState},
-    services::email::types::ApiKeyExpiryReminder,
+    services::email::{outbox as email_outbox, types::ApiKeyExpiryReminder},
     types::{api, domain::UserEmail, storage},
-    utils::{
-        user::{self as user_utils, theme as theme_utils},
-        OptionExt,
-    },
+    utils::{user::theme as theme_utils, OptionExt},
 };
 
 pub struct ApiKeyExpiryWorkflow;
@@ -103,16 +100,9 @@ impl ProcessTrackerWorkflow<SessionState> for ApiKeyExpiryWorkflow {
                 .unwrap_or(state.conf.theme.email_config.clone()),
         };
 
-        state
-            .email_client
-            .clone()
-            .compose_and_send_email(
-                user_utils::get_base_url(state),
-                Box::new(email_contents),
-                state.conf.proxy.https_url.as_ref(),
-            )
-            .await
-            .map_err(errors::ProcessTrackerError::EEmailError)?;
+        // The outbox takes care of retrying the email, so that a failure to send it does not
+        // hold back the reminders which are yet to be scheduled
+        email_outbox::enqueue_email(state, Box::new(email_contents)).await?;
 
         // If all the mails have been sent, then retry_count would be equal to length of the expiry_reminder_days vector
         if retry_count
@@ -0,0 +1,141 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::{disputes, webhooks},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{
+        api::{self, disputes as dispute_types},
+        domain, storage,
+        storage::enums as storage_enums,
+        transformers::ForeignInto,
+    },
+};
+
+pub struct DisputeDeadlineWorkflow;
+
+/// This workflow reminds the merchant through an outgoing webhook when an open dispute is close to
+/// its `challenge_required_by`
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DisputeDeadlineWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: dispute_types::DisputeDeadlinePTData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeDeadlinePTData")?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The dispute has moved on since the task was scheduled, nothing left to do
+        if dispute.dispute_stage != storage_enums::DisputeStage::Dispute
+            || dispute.dispute_status != storage_enums::DisputeStatus::DisputeOpened
+        {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+        );
+
+        let automation_config =
+            disputes::get_dispute_automation_config(db, &tracking_data.merchant_id).await?;
+
+        let reminder_time = automation_config
+            .reminder_hours_before_deadline
+            .zip(dispute.challenge_required_by)
+            .map(|(hours, deadline)| {
+                (
+                    deadline.saturating_sub(time::Duration::hours(i64::from(hours))),
+                    deadline,
+                )
+            });
+        let now = common_utils::date_time::now();
+
+        match reminder_time {
+            Some((reminder_time, _)) if now < reminder_time => {
+                db.as_scheduler()
+                    .retry_process(process, reminder_time)
+                    .await?;
+            }
+            Some((_, deadline)) if now < deadline => {
+                let profile_id = dispute
+                    .profile_id
+                    .clone()
+                    .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+                let business_profile = db
+                    .find_business_profile_by_profile_id(
+                        platform.get_processor().get_key_store(),
+                        &profile_id,
+                    )
+                    .await?;
+                let dispute_response: api_models::disputes::DisputeResponse =
+                    dispute.clone().foreign_into();
+
+                Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+                    state.clone(),
+                    platform,
+                    business_profile,
+                    storage_enums::EventType::DisputeDeadlineApproaching,
+                    storage_enums::EventClass::Disputes,
+                    dispute.dispute_id.clone(),
+                    storage_enums::EventObjectType::DisputeDetails,
+                    api::OutgoingWebhookContent::DisputeDetails(Box::new(dispute_response)),
+                    Some(dispute.created_at),
+                ))
+                .await?;
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            // Reminders are disabled, the connector did not share a deadline or it has passed
+            _ => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
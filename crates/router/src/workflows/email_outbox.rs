@@ -0,0 +1,154 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    consts,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    services::email::outbox,
+    types::{storage, storage::enums as storage_enums},
+};
+
+pub struct EmailOutboxWorkflow;
+
+/// This workflow sends an email queued in the email outbox, and retries it with an exponential
+/// backoff while the email provider fails to accept it. Once the email reaches a final status,
+/// the task is rescheduled to remove the entry at the end of its retention period.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for EmailOutboxWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::EmailOutboxTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("EmailOutboxTrackingData")?;
+
+        let email_outbox = db
+            .find_email_outbox_entry_by_id(&tracking_data.outbox_id)
+            .await?;
+
+        if email_outbox.status != storage_enums::EmailOutboxStatus::Pending {
+            return apply_retention(state, process, email_outbox).await;
+        }
+
+        // A bounce or complaint may have been reported for the recipient since the email was queued
+        if outbox::is_recipient_suppressed(db, &email_outbox.recipient).await? {
+            let email_outbox = db
+                .update_email_outbox_entry(email_outbox, storage::EmailOutboxUpdate::Suppressed)
+                .await?;
+            return apply_retention(state, process, email_outbox).await;
+        }
+
+        match outbox::send_outbox_email(state, &email_outbox).await {
+            Ok(()) => {
+                let retry_count = email_outbox.retry_count;
+                let email_outbox = db
+                    .update_email_outbox_entry(
+                        email_outbox,
+                        storage::EmailOutboxUpdate::Sent { retry_count },
+                    )
+                    .await?;
+                apply_retention(state, process, email_outbox).await?;
+            }
+            Err(error) => {
+                logger::error!(?error, outbox_id = %email_outbox.id, "Failed to send email");
+                let last_error = error.current_context().to_string();
+                let retry_count = email_outbox.retry_count + 1;
+
+                if retry_count > consts::MAX_EMAIL_SEND_RETRIES {
+                    let email_outbox = db
+                        .update_email_outbox_entry(
+                            email_outbox,
+                            storage::EmailOutboxUpdate::Failed {
+                                retry_count,
+                                last_error,
+                            },
+                        )
+                        .await?;
+                    apply_retention(state, process, email_outbox).await?;
+                } else {
+                    let backoff_factor = 2_i64.saturating_pow(
+                        u32::try_from(email_outbox.retry_count).unwrap_or(u32::MAX),
+                    );
+                    let schedule_time =
+                        common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                            consts::EMAIL_SEND_RETRY_BASE_DELAY_IN_SECONDS
+                                .saturating_mul(backoff_factor),
+                        ));
+                    db.update_email_outbox_entry(
+                        email_outbox,
+                        storage::EmailOutboxUpdate::RetryScheduled {
+                            retry_count,
+                            last_error,
+                        },
+                    )
+                    .await?;
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+/// Removes the email outbox entry once its retention period is over, or reschedules the task to
+/// the end of the retention period. Bounces and complaints are kept to suppress further emails.
+async fn apply_retention(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    email_outbox: storage::EmailOutbox,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let retention_period = time::Duration::days(state.conf.email_outbox.retention_days);
+
+    if email_outbox.status.is_undeliverable() {
+        return db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await
+            .map_err(Into::into);
+    }
+
+    if outbox::is_retention_period_over(
+        &email_outbox,
+        retention_period,
+        common_utils::date_time::now(),
+    ) {
+        db.delete_email_outbox_entry_by_id(&email_outbox.id).await?;
+        return db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await
+            .map_err(Into::into);
+    }
+
+    db.as_scheduler()
+        .reset_process(
+            process,
+            outbox::get_retention_deadline(&email_outbox, retention_period),
+        )
+        .await
+        .map_err(Into::into)
+}
@@ -0,0 +1,148 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    consts,
+    core::files::helpers,
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{api, domain, storage, storage::enums as storage_enums},
+};
+
+pub struct FileScanWorkflow;
+
+/// This workflow scans a quarantined file again when the file scanner was unavailable at upload
+/// time, and releases the file once it is found clean
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for FileScanWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::FileScanPTData = process
+            .tracking_data
+            .clone()
+            .parse_value("FileScanPTData")?;
+
+        let file_metadata = match db
+            .find_file_metadata_by_merchant_id_file_id(
+                &tracking_data.merchant_id,
+                &tracking_data.file_id,
+            )
+            .await
+        {
+            Ok(file_metadata) => file_metadata,
+            // The file was deleted while it was quarantined
+            Err(error) if error.current_context().is_db_not_found() => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        if file_metadata.scan_status != Some(storage_enums::FileScanStatus::Pending) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        let file = state
+            .file_storage_client
+            .retrieve_file(&helpers::get_quarantine_file_key(
+                &file_metadata.merchant_id,
+                &file_metadata.file_id,
+            ))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve quarantined file")?;
+
+        let scan_result = match state.file_scanner_client.scan_file(&file).await {
+            Ok(scan_result) => scan_result,
+            Err(error) => {
+                logger::error!(?error, file_id = %file_metadata.file_id, "File scan failed");
+                if process.retry_count >= consts::MAX_FILE_SCAN_RETRIES {
+                    db.as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await?;
+                } else {
+                    let schedule_time = common_utils::date_time::now().saturating_add(
+                        time::Duration::seconds(consts::FILE_SCAN_RETRY_DELAY_IN_SECONDS),
+                    );
+                    db.as_scheduler()
+                        .retry_process(process, schedule_time)
+                        .await?;
+                }
+                return Ok(());
+            }
+        };
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+        );
+
+        let create_file_request = api::CreateFileRequest {
+            file,
+            file_name: file_metadata.file_name.clone(),
+            file_size: file_metadata.file_size,
+            file_type: file_metadata
+                .file_type
+                .parse::<mime::Mime>()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse file content type")?,
+            purpose: tracking_data.purpose,
+            dispute_id: tracking_data.dispute_id,
+        };
+
+        helpers::handle_file_scan_result(
+            state,
+            &platform,
+            file_metadata,
+            &create_file_request,
+            scan_result,
+        )
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,93 @@
+use api_models::kafka_events as kafka_events_api;
+use common_utils::ext_traits::{Encode, ValueExt};
+use diesel_models::process_tracker::business_status;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::kafka_events,
+    errors,
+    routes::SessionState,
+    types::{storage, storage::enums as storage_enums},
+};
+
+/// Number of pages of payment intents published in a single run of the task, after which the
+/// task is rescheduled so that a large backfill does not hold a consumer for too long
+const BACKFILL_PAGES_PER_RUN: usize = 10;
+
+pub struct KafkaEventsBackfillWorkflow;
+
+/// This workflow publishes the events of the payments in the time range of a backfill page by
+/// page. The tracking data is updated after every page, so that it reports the progress of the
+/// backfill and a retried run resumes from the last page published.
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for KafkaEventsBackfillWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let mut tracking_data: kafka_events_api::KafkaEventsBackfillTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("KafkaEventsBackfillTrackingData")?;
+        let mut process = process;
+
+        for _ in 0..BACKFILL_PAGES_PER_RUN {
+            let (next_tracking_data, is_complete) =
+                kafka_events::backfill_events_page(state, tracking_data).await?;
+            tracking_data = next_tracking_data;
+
+            if is_complete {
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: None,
+                            schedule_time: None,
+                            tracking_data: Some(tracking_data.encode_to_value()?),
+                            business_status: Some(String::from(business_status::COMPLETED_BY_PT)),
+                            status: Some(storage_enums::ProcessTrackerStatus::Finish),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+                return Ok(());
+            }
+
+            process = db
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(tracking_data.encode_to_value()?),
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+        }
+
+        db.as_scheduler()
+            .reset_process(process, common_utils::date_time::now())
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
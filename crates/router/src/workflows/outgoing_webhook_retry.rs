@@ -496,7 +496,14 @@ async fn get_outgoing_webhook_content_and_event_type(
                     }
                 }
                 .map(Box::new)?;
-            let event_type = Some(EventType::from(dispute_response.dispute_status));
+            // Deadline reminders are only valid as long as the dispute is still open
+            let event_type = match (tracking_data.event_type, dispute_response.dispute_status) {
+                (
+                    EventType::DisputeDeadlineApproaching,
+                    common_enums::DisputeStatus::DisputeOpened,
+                ) => Some(EventType::DisputeDeadlineApproaching),
+                (_, dispute_status) => Some(EventType::from(dispute_status)),
+            };
             logger::debug!(current_resource_status=%dispute_response.dispute_status);
 
             Ok((
@@ -536,6 +543,29 @@ async fn get_outgoing_webhook_content_and_event_type(
             ))
         }
         #[cfg(feature = "payouts")]
+        diesel_models::enums::EventClass::Payouts
+            if tracking_data.primary_object_type
+                == diesel_models::enums::EventObjectType::PayoutBatchDetails =>
+        {
+            let payout_batch = state
+                .store
+                .find_payout_batch_by_merchant_id_batch_id(
+                    merchant_account.get_id(),
+                    &tracking_data.primary_object_id,
+                )
+                .await?;
+
+            let event_type: Option<EventType> = payout_batch.status.into();
+            logger::debug!(current_resource_status=%payout_batch.status);
+
+            Ok((
+                OutgoingWebhookContent::PayoutBatchDetails(Box::new(
+                    payouts::batch::get_payout_batch_response(payout_batch),
+                )),
+                event_type,
+            ))
+        }
+        #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
             let request = payout_models::PayoutRequest::PayoutActionRequest(
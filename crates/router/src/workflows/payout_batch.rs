@@ -0,0 +1,192 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{enums as storage_enums, process_tracker::business_status};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::payouts::batch,
+    errors as core_errors,
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+        let platform = domain::Platform::new(
+            merchant_account.clone(),
+            key_store.clone(),
+            merchant_account,
+            key_store,
+        );
+
+        let mut payout_batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+
+        // Batch was cancelled while the task was waiting to be picked up
+        if payout_batch.status.is_terminal_status() {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await
+                .map_err(Into::into);
+        }
+
+        if payout_batch.status == storage_enums::PayoutBatchStatus::Pending {
+            payout_batch = db
+                .update_payout_batch(
+                    payout_batch,
+                    storage::PayoutBatchUpdate::StatusUpdate {
+                        status: storage_enums::PayoutBatchStatus::Processing,
+                    },
+                )
+                .await?;
+        }
+
+        let pending_items = db
+            .list_payout_batch_items_by_batch_id(
+                &payout_batch.id,
+                Some(storage_enums::PayoutBatchItemStatus::Pending),
+                Some(consts::PAYOUT_BATCH_PROCESSING_CHUNK_SIZE),
+                None,
+            )
+            .await?;
+
+        let mut is_retry_scheduled = false;
+        for item in pending_items {
+            let outcome = batch::process_payout_batch_item(state, &platform, item).await?;
+            if matches!(outcome, batch::PayoutBatchItemOutcome::RetryScheduled) {
+                is_retry_scheduled = true;
+            }
+        }
+
+        let payout_batch =
+            batch::update_payout_batch_counts(state, &platform, payout_batch).await?;
+
+        match get_next_step(payout_batch.status, is_retry_scheduled) {
+            PayoutBatchWorkflowStep::Finish => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+            PayoutBatchWorkflowStep::Reschedule { delay_in_seconds } => {
+                let schedule_time = common_utils::date_time::now()
+                    .saturating_add(time::Duration::seconds(delay_in_seconds));
+                logger::info!(
+                    batch_id = %payout_batch.id,
+                    pending_count = payout_batch.get_pending_count(),
+                    "Rescheduling payout batch workflow"
+                );
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum PayoutBatchWorkflowStep {
+    Finish,
+    Reschedule { delay_in_seconds: i64 },
+}
+
+/// Decides what happens to the task once a chunk of items has been processed. The next chunk is
+/// picked up right away, unless some of the items have to wait for a retry.
+fn get_next_step(
+    status: storage_enums::PayoutBatchStatus,
+    is_retry_scheduled: bool,
+) -> PayoutBatchWorkflowStep {
+    if status.is_terminal_status() {
+        PayoutBatchWorkflowStep::Finish
+    } else if is_retry_scheduled {
+        PayoutBatchWorkflowStep::Reschedule {
+            delay_in_seconds: consts::PAYOUT_BATCH_ITEM_RETRY_DELAY_IN_SECONDS,
+        }
+    } else {
+        PayoutBatchWorkflowStep::Reschedule {
+            delay_in_seconds: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_batch_finishes_task() {
+        for status in [
+            storage_enums::PayoutBatchStatus::Completed,
+            storage_enums::PayoutBatchStatus::PartiallyCompleted,
+            storage_enums::PayoutBatchStatus::Failed,
+            storage_enums::PayoutBatchStatus::Cancelled,
+        ] {
+            assert_eq!(
+                get_next_step(status, false),
+                PayoutBatchWorkflowStep::Finish
+            );
+            assert_eq!(get_next_step(status, true), PayoutBatchWorkflowStep::Finish);
+        }
+    }
+
+    #[test]
+    fn test_processing_batch_picks_up_next_chunk_immediately() {
+        assert_eq!(
+            get_next_step(storage_enums::PayoutBatchStatus::Processing, false),
+            PayoutBatchWorkflowStep::Reschedule {
+                delay_in_seconds: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_processing_batch_waits_for_retries() {
+        assert_eq!(
+            get_next_step(storage_enums::PayoutBatchStatus::Processing, true),
+            PayoutBatchWorkflowStep::Reschedule {
+                delay_in_seconds: consts::PAYOUT_BATCH_ITEM_RETRY_DELAY_IN_SECONDS
+            }
+        );
+    }
+}
@@ -0,0 +1,84 @@
+use async_trait::async_trait;
+use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+use subscriptions::{
+    storage::native_billing::SubscriptionBillingTrackingData, workflows::subscription_billing,
+};
+
+use crate::{routes::SessionState, types::storage, utils};
+
+const SUBSCRIPTION_BILLING_WORKFLOW: &str = "SUBSCRIPTION_BILLING";
+
+pub struct SubscriptionBillingWorkflow;
+
+#[async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SubscriptionBillingWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data = process
+            .tracking_data
+            .clone()
+            .parse_value::<SubscriptionBillingTrackingData>("SubscriptionBillingTrackingData")?;
+        let subscription_state = state.clone().into();
+        match process.name.as_deref() {
+            Some(SUBSCRIPTION_BILLING_WORKFLOW) => {
+                let (handler, settled_invoice) =
+                    Box::pin(subscription_billing::perform_subscription_billing(
+                        &subscription_state,
+                        process,
+                        tracking_data,
+                    ))
+                    .await?;
+
+                if let Some((invoice, payments_response)) = settled_invoice {
+                    utils::trigger_subscriptions_outgoing_webhook(
+                        state,
+                        payments_response,
+                        &invoice,
+                        &handler.subscription,
+                        &handler.merchant_account,
+                        &handler.key_store,
+                        &handler.profile,
+                    )
+                    .await
+                    .map_err(|e| {
+                        logger::error!("Failed to trigger subscriptions outgoing webhook: {e:?}");
+                        errors::ProcessTrackerError::FlowExecutionError {
+                            flow: "Trigger Subscriptions Outgoing Webhook",
+                        }
+                    })?;
+                }
+
+                Ok(())
+            }
+            _ => Err(errors::ProcessTrackerError::JobNotFound),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(?error, "Encountered error in subscription billing workflow");
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ pub use tracing::{debug, error, event as log, info, warn};
 pub use tracing_attributes::instrument;
 
 pub mod config;
+pub mod control;
 mod defaults;
 pub use crate::config::Config;
 
@@ -11,7 +12,10 @@ pub use crate::config::Config;
 pub mod types;
 pub use types::{Category, Flow, Level, Tag};
 
+#[cfg(feature = "actix_web")]
+mod prometheus;
 mod setup;
+mod tail_sampling;
 pub use setup::{setup, TelemetryGuard};
 
 pub mod formatter;
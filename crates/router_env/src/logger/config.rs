@@ -103,6 +103,41 @@ pub struct LogTelemetry {
     pub route_to_trace: Option<Vec<String>>,
     /// Interval for collecting the metrics (such as gauge) in background thread
     pub bg_metrics_collection_interval_in_secs: Option<u16>,
+    /// Serving metrics for Prometheus to scrape, alongside or instead of pushing them over OTLP.
+    pub prometheus: LogPrometheus,
+    /// Sampling traces once their requests complete, instead of when they start.
+    pub tail_sampling: LogTailSampling,
+}
+
+/// Sampling traces once their requests complete. Traces of requests which fail with a server
+/// error or a connector error, or which are slower than the latency threshold, are always kept,
+/// while the remaining traces are kept at the sampling rate.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LogTailSampling {
+    /// Whether traces are sampled once their requests complete.
+    pub enabled: bool,
+    /// Traces of requests slower than this duration (in milliseconds) are always kept.
+    pub latency_threshold_in_ms: u64,
+    /// Maximum number of spans buffered across all traces, spans are dropped once it is reached.
+    /// It also limits the number of root spans which have started but not ended.
+    pub max_buffered_spans: usize,
+    /// Time (in seconds) after which the buffered spans of incomplete traces are dropped.
+    pub max_trace_duration_in_secs: u64,
+}
+
+/// Serving metrics for Prometheus to scrape.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LogPrometheus {
+    /// Whether the metrics are served.
+    pub enabled: bool,
+    /// Host on which the metrics are served.
+    pub host: String,
+    /// Port on which the metrics are served.
+    pub port: u16,
+    /// Path at which the metrics are served.
+    pub path: String,
 }
 
 /// Telemetry / tracing.
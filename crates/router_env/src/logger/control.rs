@@ -0,0 +1,513 @@
+//! Controlling logging and trace sampling at runtime.
+//!
+//! The filtering directive of the console and file logging layers can be replaced at runtime, and
+//! verbose logging and trace sampling can be enabled temporarily for the requests of a specific
+//! merchant, profile or connector. The state is local to the process, the application is
+//! responsible for persisting it and for propagating updates to other instances.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, LazyLock, PoisonError, RwLock,
+};
+
+use serde::{Deserialize, Serialize};
+use tracing::{subscriber::Interest, Metadata, Subscriber};
+use tracing_subscriber::{
+    filter::{LevelFilter, ParseError},
+    layer::{Context, Filter},
+    registry::LookupSpan,
+    EnvFilter,
+};
+
+use super::storage::Storage;
+
+/// Span fields matched against the merchant ID, profile ID and connector of debug overrides.
+const MERCHANT_ID_FIELD: &str = "merchant_id";
+const PROFILE_ID_FIELD: &str = "profile_id";
+const CONNECTOR_FIELD: &str = "connector_name";
+
+static FILTERS: LazyLock<RwLock<Vec<FilterHandle>>> = LazyLock::new(Default::default);
+static FILTER_DIRECTIVE: LazyLock<RwLock<Option<String>>> = LazyLock::new(Default::default);
+static DEBUG_OVERRIDES: LazyLock<RwLock<Vec<DebugOverride>>> = LazyLock::new(Default::default);
+/// Whether any debug overrides exist, checked before acquiring the lock on the overrides.
+static HAS_DEBUG_OVERRIDES: AtomicBool = AtomicBool::new(false);
+
+/// Level of verbose logging enabled by a debug override.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerboseLevel {
+    /// Info and more severe events.
+    Info,
+    /// Debug and more severe events.
+    Debug,
+    /// All events.
+    Trace,
+}
+
+impl VerboseLevel {
+    fn enables(self, level: &tracing::Level) -> bool {
+        let max_level = match self {
+            Self::Info => tracing::Level::INFO,
+            Self::Debug => tracing::Level::DEBUG,
+            Self::Trace => tracing::Level::TRACE,
+        };
+        *level <= max_level
+    }
+}
+
+/// Verbose logging and forced trace sampling for the requests matching all of the specified
+/// merchant ID, profile ID and connector, until the override expires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DebugOverride {
+    /// Unique identifier of the override.
+    pub id: String,
+    /// Merchant ID of the requests.
+    pub merchant_id: Option<String>,
+    /// Profile ID of the requests.
+    pub profile_id: Option<String>,
+    /// Connector called by the requests.
+    pub connector: Option<String>,
+    /// Level of the events logged for the requests.
+    pub level: VerboseLevel,
+    /// Whether the traces of the requests are sampled irrespective of the sampling rate.
+    pub sample_traces: bool,
+    /// Unix timestamp (in seconds) at which the override expires.
+    pub expires_at: i64,
+}
+
+impl DebugOverride {
+    fn is_expired(&self, now: i64) -> bool {
+        self.expires_at <= now
+    }
+
+    fn matches(&self, field_value: impl Fn(&str) -> Option<String>) -> bool {
+        [
+            (MERCHANT_ID_FIELD, &self.merchant_id),
+            (PROFILE_ID_FIELD, &self.profile_id),
+            (CONNECTOR_FIELD, &self.connector),
+        ]
+        .into_iter()
+        .all(|(field, expected)| {
+            expected
+                .as_ref()
+                .is_none_or(|expected| field_value(field).as_ref() == Some(expected))
+        })
+    }
+}
+
+/// Update to the logging state of a process.
+///
+/// Updates are idempotent, so that an update can be applied more than once, such as when it is
+/// received by a process that has already applied it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LogControlUpdate {
+    /// Replaces the filtering directive of all logging layers, or restores the configured
+    /// directives if none is specified.
+    SetFilterDirective {
+        /// Filtering directive, in the format accepted by [`EnvFilter`].
+        directive: Option<String>,
+    },
+    /// Adds a debug override, or replaces the override with the same ID.
+    AddDebugOverride(DebugOverride),
+    /// Removes a debug override.
+    RemoveDebugOverride {
+        /// ID of the override.
+        id: String,
+    },
+}
+
+/// Current logging state of the process.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogControlStatus {
+    /// Filtering directive set at runtime, the configured directives are in effect if none is set.
+    pub filter_directive: Option<String>,
+    /// Unexpired debug overrides.
+    pub debug_overrides: Vec<DebugOverride>,
+}
+
+/// Validates a filtering directive without applying it.
+pub fn validate_filter_directive(directive: &str) -> Result<(), ParseError> {
+    build_env_filter(directive).map(|_| ())
+}
+
+/// Applies an update to the logging state of the process.
+pub fn apply(update: LogControlUpdate) -> Result<(), ParseError> {
+    match update {
+        LogControlUpdate::SetFilterDirective { directive } => set_filter_directive(directive)?,
+        LogControlUpdate::AddDebugOverride(debug_override) => {
+            update_debug_overrides(|overrides| {
+                overrides.retain(|existing| existing.id != debug_override.id);
+                overrides.push(debug_override);
+            });
+        }
+        LogControlUpdate::RemoveDebugOverride { id } => {
+            update_debug_overrides(|overrides| overrides.retain(|existing| existing.id != id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Replaces the logging state of the process, such as with the state persisted by the
+/// application when the process starts. Debug overrides that are not part of the state are
+/// removed, and expired overrides are ignored.
+pub fn restore(status: LogControlStatus) -> Result<(), ParseError> {
+    let LogControlStatus {
+        filter_directive,
+        debug_overrides,
+    } = status;
+
+    set_filter_directive(filter_directive)?;
+    update_debug_overrides(|overrides| *overrides = debug_overrides);
+
+    Ok(())
+}
+
+/// Returns the current logging state of the process.
+pub fn status() -> LogControlStatus {
+    // Pruning the expired overrides, so that verbose callsites are disabled again
+    update_debug_overrides(|_| ());
+
+    LogControlStatus {
+        filter_directive: FILTER_DIRECTIVE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+        debug_overrides: DEBUG_OVERRIDES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone(),
+    }
+}
+
+fn set_filter_directive(directive: Option<String>) -> Result<(), ParseError> {
+    let filters = FILTERS.read().unwrap_or_else(PoisonError::into_inner);
+
+    // All filters are built before any of them is replaced, so that an invalid directive is not
+    // partially applied
+    let env_filters = filters
+        .iter()
+        .map(|filter| build_env_filter(directive.as_deref().unwrap_or(&filter.configured)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (filter, env_filter) in filters.iter().zip(env_filters) {
+        *filter.inner.write().unwrap_or_else(PoisonError::into_inner) = env_filter;
+    }
+    *FILTER_DIRECTIVE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = directive;
+
+    tracing::callsite::rebuild_interest_cache();
+    Ok(())
+}
+
+fn update_debug_overrides(update: impl FnOnce(&mut Vec<DebugOverride>)) {
+    let now = now();
+    let mut overrides = DEBUG_OVERRIDES
+        .write()
+        .unwrap_or_else(PoisonError::into_inner);
+    let previously_enabled = !overrides.is_empty();
+    update(&mut overrides);
+    overrides.retain(|debug_override| !debug_override.is_expired(now));
+
+    let enabled = !overrides.is_empty();
+    HAS_DEBUG_OVERRIDES.store(enabled, Ordering::Release);
+    drop(overrides);
+
+    if enabled || previously_enabled {
+        tracing::callsite::rebuild_interest_cache();
+    }
+}
+
+/// Returns whether a debug override matching the span field values exists, which satisfies the
+/// predicate.
+fn any_debug_override(
+    field_value: impl Fn(&str) -> Option<String>,
+    predicate: impl Fn(&DebugOverride) -> bool,
+) -> bool {
+    if !HAS_DEBUG_OVERRIDES.load(Ordering::Acquire) {
+        return false;
+    }
+
+    let now = now();
+    DEBUG_OVERRIDES
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .any(|debug_override| {
+            !debug_override.is_expired(now)
+                && predicate(debug_override)
+                && debug_override.matches(&field_value)
+        })
+}
+
+/// Returns whether the traces of spans with these attributes must be sampled.
+pub(super) fn should_force_sample(attributes: &[opentelemetry::KeyValue]) -> bool {
+    any_debug_override(
+        |field| {
+            attributes
+                .iter()
+                .find(|attribute| attribute.key.as_str() == field)
+                .map(|attribute| attribute.value.as_str().into_owned())
+        },
+        |debug_override| debug_override.sample_traces,
+    )
+}
+
+fn now() -> i64 {
+    time::OffsetDateTime::now_utc().unix_timestamp()
+}
+
+fn build_env_filter(directive: &str) -> Result<EnvFilter, ParseError> {
+    EnvFilter::builder()
+        .with_default_directive(LevelFilter::WARN.into())
+        .parse(directive)
+}
+
+#[derive(Debug)]
+struct FilterHandle {
+    inner: Arc<RwLock<EnvFilter>>,
+    configured: String,
+}
+
+/// Filter of a logging layer whose directive can be replaced at runtime, and which additionally
+/// enables verbose events within the spans of the requests matching a debug override.
+#[derive(Debug)]
+pub(super) struct DynamicFilter {
+    inner: Arc<RwLock<EnvFilter>>,
+}
+
+impl DynamicFilter {
+    /// Registers the filter of a logging layer, so that its directive can be replaced at runtime.
+    pub(super) fn new(env_filter: EnvFilter) -> Self {
+        let configured = env_filter.to_string();
+        let inner = Arc::new(RwLock::new(env_filter));
+
+        FILTERS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(FilterHandle {
+                inner: inner.clone(),
+                configured,
+            });
+
+        Self { inner }
+    }
+
+    fn with_inner<T>(&self, f: impl FnOnce(&EnvFilter) -> T) -> T {
+        f(&self.inner.read().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl<S> Filter<S> for DynamicFilter
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn enabled(&self, metadata: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        if self.with_inner(|inner| Filter::<S>::enabled(inner, metadata, cx)) {
+            return true;
+        }
+
+        // Only info, debug and trace events are enabled by debug overrides. Warnings are also
+        // logged while span storage is being updated, so looking the storage up for them could
+        // deadlock.
+        if !metadata.is_event() || *metadata.level() < tracing::Level::INFO {
+            return false;
+        }
+
+        let Some(span) = cx.lookup_current() else {
+            return false;
+        };
+        let extensions = span.extensions();
+        let Some(storage) = extensions.get::<Storage<'_>>() else {
+            return false;
+        };
+
+        any_debug_override(
+            |field| {
+                storage
+                    .values
+                    .get(field)
+                    .and_then(|value| value.as_str().map(ToOwned::to_owned))
+            },
+            |debug_override| debug_override.level.enables(metadata.level()),
+        )
+    }
+
+    fn callsite_enabled(&self, metadata: &'static Metadata<'static>) -> Interest {
+        if HAS_DEBUG_OVERRIDES.load(Ordering::Acquire) {
+            Interest::sometimes()
+        } else {
+            self.with_inner(|inner| Filter::<S>::callsite_enabled(inner, metadata))
+        }
+    }
+
+    fn max_level_hint(&self) -> Option<LevelFilter> {
+        if HAS_DEBUG_OVERRIDES.load(Ordering::Acquire) {
+            None
+        } else {
+            self.with_inner(|inner| Filter::<S>::max_level_hint(inner))
+        }
+    }
+
+    fn on_new_span(
+        &self,
+        attrs: &tracing::span::Attributes<'_>,
+        id: &tracing::span::Id,
+        ctx: Context<'_, S>,
+    ) {
+        self.with_inner(|inner| Filter::<S>::on_new_span(inner, attrs, id, ctx));
+    }
+
+    fn on_record(
+        &self,
+        id: &tracing::span::Id,
+        values: &tracing::span::Record<'_>,
+        ctx: Context<'_, S>,
+    ) {
+        self.with_inner(|inner| Filter::<S>::on_record(inner, id, values, ctx));
+    }
+
+    fn on_enter(&self, id: &tracing::span::Id, ctx: Context<'_, S>) {
+        self.with_inner(|inner| Filter::<S>::on_enter(inner, id, ctx));
+    }
+
+    fn on_exit(&self, id: &tracing::span::Id, ctx: Context<'_, S>) {
+        self.with_inner(|inner| Filter::<S>::on_exit(inner, id, ctx));
+    }
+
+    fn on_close(&self, id: tracing::span::Id, ctx: Context<'_, S>) {
+        self.with_inner(|inner| Filter::<S>::on_close(inner, id, ctx));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn debug_override(id: &str, merchant_id: Option<&str>, expires_at: i64) -> DebugOverride {
+        DebugOverride {
+            id: id.to_owned(),
+            merchant_id: merchant_id.map(ToOwned::to_owned),
+            profile_id: None,
+            connector: Some("stripe".to_owned()),
+            level: VerboseLevel::Debug,
+            sample_traces: true,
+            expires_at,
+        }
+    }
+
+    fn override_ids() -> Vec<String> {
+        status()
+            .debug_overrides
+            .into_iter()
+            .map(|debug_override| debug_override.id)
+            .collect()
+    }
+
+    #[test]
+    fn test_verbose_level_enables() {
+        assert!(VerboseLevel::Info.enables(&tracing::Level::WARN));
+        assert!(VerboseLevel::Info.enables(&tracing::Level::INFO));
+        assert!(!VerboseLevel::Info.enables(&tracing::Level::DEBUG));
+        assert!(VerboseLevel::Debug.enables(&tracing::Level::DEBUG));
+        assert!(!VerboseLevel::Debug.enables(&tracing::Level::TRACE));
+        assert!(VerboseLevel::Trace.enables(&tracing::Level::TRACE));
+    }
+
+    #[test]
+    fn test_debug_override_matches_all_specified_fields() {
+        let debug_override = debug_override("dbg_matches", Some("merchant_1"), i64::MAX);
+        let fields = |merchant_id: &'static str, connector: &'static str| {
+            move |field: &str| match field {
+                MERCHANT_ID_FIELD => Some(merchant_id.to_owned()),
+                CONNECTOR_FIELD => Some(connector.to_owned()),
+                _ => None,
+            }
+        };
+
+        // The profile ID is not specified by the override, so any profile matches
+        assert!(debug_override.matches(fields("merchant_1", "stripe")));
+        assert!(!debug_override.matches(fields("merchant_2", "stripe")));
+        assert!(!debug_override.matches(fields("merchant_1", "adyen")));
+        assert!(!debug_override.matches(|_| None));
+    }
+
+    #[test]
+    fn test_validate_filter_directive() {
+        assert!(validate_filter_directive("router=debug,warn").is_ok());
+        assert!(validate_filter_directive("router=verbose").is_err());
+    }
+
+    #[test]
+    fn test_updates_and_restore() {
+        // The state is global to the process, so it is exercised by a single test
+        let now = now();
+
+        apply(LogControlUpdate::AddDebugOverride(debug_override(
+            "dbg_added",
+            Some("merchant_1"),
+            now + 60,
+        )))
+        .unwrap();
+        // Updates are idempotent
+        apply(LogControlUpdate::AddDebugOverride(debug_override(
+            "dbg_added",
+            Some("merchant_1"),
+            now + 60,
+        )))
+        .unwrap();
+        assert_eq!(override_ids(), ["dbg_added"]);
+        assert!(should_force_sample(&[
+            opentelemetry::KeyValue::new(MERCHANT_ID_FIELD, "merchant_1"),
+            opentelemetry::KeyValue::new(CONNECTOR_FIELD, "stripe"),
+        ]));
+
+        apply(LogControlUpdate::RemoveDebugOverride {
+            id: "dbg_added".to_owned(),
+        })
+        .unwrap();
+        apply(LogControlUpdate::RemoveDebugOverride {
+            id: "dbg_added".to_owned(),
+        })
+        .unwrap();
+        assert!(override_ids().is_empty());
+
+        // Expired overrides are not restored, and overrides missing from the state are removed
+        apply(LogControlUpdate::AddDebugOverride(debug_override(
+            "dbg_removed",
+            None,
+            now + 60,
+        )))
+        .unwrap();
+        restore(LogControlStatus {
+            filter_directive: Some("router=debug".to_owned()),
+            debug_overrides: vec![
+                debug_override("dbg_restored", None, now + 60),
+                debug_override("dbg_expired", None, now - 1),
+            ],
+        })
+        .unwrap();
+        assert_eq!(
+            status(),
+            LogControlStatus {
+                filter_directive: Some("router=debug".to_owned()),
+                debug_overrides: vec![debug_override("dbg_restored", None, now + 60)],
+            }
+        );
+
+        // An invalid directive is not applied
+        assert!(restore(LogControlStatus {
+            filter_directive: Some("router=verbose".to_owned()),
+            debug_overrides: Vec::new(),
+        })
+        .is_err());
+        assert_eq!(status().filter_directive.as_deref(), Some("router=debug"));
+
+        restore(LogControlStatus::default()).unwrap();
+        assert_eq!(status(), LogControlStatus::default());
+    }
+}
@@ -20,3 +20,25 @@ impl Default for super::config::LogConsole {
         }
     }
 }
+
+impl Default for super::config::LogPrometheus {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            host: "127.0.0.1".into(),
+            port: 9464,
+            path: "/metrics".into(),
+        }
+    }
+}
+
+impl Default for super::config::LogTailSampling {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            latency_threshold_in_ms: 2000,
+            max_buffered_spans: 100_000,
+            max_trace_duration_in_secs: 300,
+        }
+    }
+}
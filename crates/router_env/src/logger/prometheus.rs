@@ -0,0 +1,67 @@
+//! Serving metrics for Prometheus to scrape.
+//!
+//! The metrics are served by an actix-web server of their own, listening on the configured host
+//! and port rather than on the address of the application, and running on the Tokio runtime of
+//! the application.
+
+use actix_web::{web, App, HttpResponse, HttpServer};
+use opentelemetry_prometheus::{PrometheusExporter, ResourceSelector};
+use prometheus::{Encoder, Registry, TextEncoder};
+
+use super::config::LogPrometheus;
+
+/// Builds the reader exporting metrics to Prometheus, and starts serving them on the configured
+/// host, port and path.
+pub(super) fn setup_exporter(config: &LogPrometheus) -> Result<PrometheusExporter, String> {
+    let runtime = tokio::runtime::Handle::try_current().map_err(|error| {
+        format!("Prometheus metrics can only be served from within a Tokio runtime: {error}")
+    })?;
+    let registry = Registry::new();
+
+    let exporter = opentelemetry_prometheus::exporter()
+        .with_registry(registry.clone())
+        // Resource attributes (such as the pod name) are added to each metric, as is the case
+        // with metrics exported over OTLP
+        .with_resource_constant_labels(ResourceSelector::All)
+        .build()
+        .map_err(|error| format!("Failed to build Prometheus exporter: {error}"))?;
+
+    let path = config.path.clone();
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(web::Data::new(registry.clone()))
+            .route(&path, web::get().to(metrics))
+    })
+    .workers(1)
+    .bind((config.host.as_str(), config.port))
+    .map_err(|error| {
+        format!(
+            "Failed to bind Prometheus metrics server to {}:{}: {error}",
+            config.host, config.port
+        )
+    })?
+    .run();
+
+    runtime.spawn(async move {
+        if let Err(error) = server.await {
+            tracing::error!(?error, "Prometheus metrics server stopped");
+        }
+    });
+
+    Ok(exporter)
+}
+
+async fn metrics(registry: web::Data<Registry>) -> HttpResponse {
+    let encoder = TextEncoder::new();
+    let mut body = Vec::new();
+
+    match encoder.encode(&registry.gather(), &mut body) {
+        Ok(()) => HttpResponse::Ok()
+            .content_type(encoder.format_type())
+            .body(body),
+        Err(error) => {
+            tracing::error!(?error, "Failed to encode Prometheus metrics");
+            HttpResponse::InternalServerError().finish()
+        }
+    }
+}
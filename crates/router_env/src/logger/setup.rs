@@ -7,6 +7,10 @@ use serde_json::ser::{CompactFormatter, PrettyFormatter};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer};
 
+use super::{
+    control::{self, DynamicFilter},
+    tail_sampling::TailSamplingProcessor,
+};
 use crate::{config, FormattingLayer, StorageSubscription};
 
 /// Contains guards necessary for logging and metrics collection.
@@ -33,7 +37,7 @@ pub fn setup(
         None
     };
 
-    if config.telemetry.metrics_enabled {
+    if config.telemetry.metrics_enabled || config.telemetry.prometheus.enabled {
         setup_metrics_pipeline(&config.telemetry)
     };
 
@@ -56,7 +60,7 @@ pub fn setup(
         );
         println!("Using file logging filter: {file_filter}");
         let layer = FormattingLayer::new(service_name, file_writer, CompactFormatter)?
-            .with_filter(file_filter);
+            .with_filter(DynamicFilter::new(file_filter));
         Some(layer)
     } else {
         None
@@ -79,6 +83,7 @@ pub fn setup(
             &crates_to_filter,
         );
         println!("Using console logging filter: {console_filter}");
+        let console_filter = DynamicFilter::new(console_filter);
 
         match config.console.log_format {
             config::LogFormat::Default => {
@@ -219,6 +224,41 @@ impl<T: opentelemetry_sdk::trace::ShouldSample + Clone + 'static>
     }
 }
 
+/// Sampler which samples the spans of requests being debugged irrespective of the decision of the
+/// wrapped sampler, see [`control::DebugOverride`]
+#[derive(Clone, Debug)]
+struct DebugOverrideSampler<T: opentelemetry_sdk::trace::ShouldSample + Clone + 'static>(T);
+
+impl<T: opentelemetry_sdk::trace::ShouldSample + Clone + 'static>
+    opentelemetry_sdk::trace::ShouldSample for DebugOverrideSampler<T>
+{
+    fn should_sample(
+        &self,
+        parent_context: Option<&opentelemetry::Context>,
+        trace_id: opentelemetry::trace::TraceId,
+        name: &str,
+        span_kind: &opentelemetry::trace::SpanKind,
+        attributes: &[opentelemetry::KeyValue],
+        links: &[opentelemetry::trace::Link],
+    ) -> opentelemetry::trace::SamplingResult {
+        use opentelemetry::trace::TraceContextExt;
+
+        if control::should_force_sample(attributes) {
+            opentelemetry::trace::SamplingResult {
+                decision: opentelemetry::trace::SamplingDecision::RecordAndSample,
+                attributes: Vec::new(),
+                trace_state: match parent_context {
+                    Some(ctx) => ctx.span().span_context().trace_state().clone(),
+                    None => opentelemetry::trace::TraceState::default(),
+                },
+            }
+        } else {
+            self.0
+                .should_sample(parent_context, trace_id, name, span_kind, attributes, links)
+        }
+    }
+}
+
 fn setup_tracing_pipeline(
     config: &config::LogTelemetry,
     service_name: &str,
@@ -257,25 +297,45 @@ fn setup_tracing_pipeline(
         exporter_result.expect("Failed to build traces exporter")
     };
 
-    let mut provider_builder = trace::TracerProvider::builder()
-        .with_span_processor(
-            trace::BatchSpanProcessor::builder(
-                exporter,
-                // The runtime would have to be updated if a different web framework is used
-                opentelemetry_sdk::runtime::TokioCurrentThread,
-            )
-            .with_batch_config(batch_config)
-            .build(),
+    let batch_processor = trace::BatchSpanProcessor::builder(
+        exporter,
+        // The runtime would have to be updated if a different web framework is used
+        opentelemetry_sdk::runtime::TokioCurrentThread,
+    )
+    .with_batch_config(batch_config)
+    .build();
+    let sampling_rate = config.sampling_rate.unwrap_or(1.0);
+
+    // With tail sampling, all traces of the routes to be traced are recorded, and the sampling
+    // rate is applied once their requests complete
+    let (provider_builder, sampler) = if config.tail_sampling.enabled {
+        (
+            trace::TracerProvider::builder().with_span_processor(TailSamplingProcessor::new(
+                batch_processor,
+                &config.tail_sampling,
+                sampling_rate,
+            )),
+            trace::Sampler::AlwaysOn,
         )
-        .with_sampler(trace::Sampler::ParentBased(Box::new(ConditionalSampler(
-            TraceAssertion {
-                clauses: config
-                    .route_to_trace
-                    .clone()
-                    .map(|inner| inner.into_iter().map(TraceUrlAssert::from).collect()),
-                default: false,
-            },
-            trace::Sampler::TraceIdRatioBased(config.sampling_rate.unwrap_or(1.0)),
+    } else {
+        (
+            trace::TracerProvider::builder().with_span_processor(batch_processor),
+            trace::Sampler::TraceIdRatioBased(sampling_rate),
+        )
+    };
+
+    let mut provider_builder = provider_builder
+        .with_sampler(DebugOverrideSampler(trace::Sampler::ParentBased(Box::new(
+            ConditionalSampler(
+                TraceAssertion {
+                    clauses: config
+                        .route_to_trace
+                        .clone()
+                        .map(|inner| inner.into_iter().map(TraceUrlAssert::from).collect()),
+                    default: false,
+                },
+                sampler,
+            ),
         ))))
         .with_resource(opentelemetry_sdk::Resource::new(vec![
             opentelemetry::KeyValue::new("service.name", service_name.to_owned()),
@@ -293,6 +353,54 @@ fn setup_tracing_pipeline(
 }
 
 fn setup_metrics_pipeline(config: &config::LogTelemetry) {
+    let mut provider_builder = opentelemetry_sdk::metrics::SdkMeterProvider::builder();
+
+    if config.metrics_enabled {
+        match setup_otlp_metrics_reader(config) {
+            Some(reader) => provider_builder = provider_builder.with_reader(reader),
+            // Metrics are not collected at all if they cannot be exported, unless they are served
+            // to Prometheus
+            None if !config.prometheus.enabled => return,
+            None => {}
+        }
+    }
+
+    if config.prometheus.enabled {
+        #[cfg(feature = "actix_web")]
+        let reader_result = super::prometheus::setup_exporter(&config.prometheus);
+        #[cfg(not(feature = "actix_web"))]
+        let reader_result: Result<opentelemetry_prometheus::PrometheusExporter, String> =
+            Err("Prometheus metrics can only be served with the `actix_web` feature".into());
+
+        if config.ignore_errors {
+            #[allow(clippy::print_stderr)] // The logger hasn't been initialized yet
+            match reader_result {
+                Ok(reader) => provider_builder = provider_builder.with_reader(reader),
+                Err(error) => eprintln!("Failed to set up Prometheus exporter: {error}"),
+            }
+        } else {
+            // Safety: This is conditional, there is an option to avoid this behavior at runtime.
+            #[allow(clippy::expect_used)]
+            let reader = reader_result.expect("Failed to set up Prometheus exporter");
+            provider_builder = provider_builder.with_reader(reader);
+        }
+    }
+
+    let provider = provider_builder
+        .with_resource(opentelemetry_sdk::Resource::new([
+            opentelemetry::KeyValue::new(
+                "pod",
+                std::env::var("POD_NAME").unwrap_or(String::from("hyperswitch-server-default")),
+            ),
+        ]))
+        .build();
+
+    opentelemetry::global::set_meter_provider(provider);
+}
+
+fn setup_otlp_metrics_reader(
+    config: &config::LogTelemetry,
+) -> Option<opentelemetry_sdk::metrics::PeriodicReader> {
     use opentelemetry_otlp::WithExportConfig;
 
     let exporter_result = opentelemetry_otlp::MetricExporter::builder()
@@ -305,34 +413,23 @@ fn setup_metrics_pipeline(config: &config::LogTelemetry) {
         #[allow(clippy::print_stderr)] // The logger hasn't been initialized yet
         exporter_result
             .inspect_err(|error| eprintln!("Failed to build metrics exporter: {error:?}"))
-            .ok();
-        return;
+            .ok()?
     } else {
         // Safety: This is conditional, there is an option to avoid this behavior at runtime.
         #[allow(clippy::expect_used)]
         exporter_result.expect("Failed to build metrics exporter")
     };
 
-    let reader = opentelemetry_sdk::metrics::PeriodicReader::builder(
-        exporter,
-        // The runtime would have to be updated if a different web framework is used
-        opentelemetry_sdk::runtime::TokioCurrentThread,
+    Some(
+        opentelemetry_sdk::metrics::PeriodicReader::builder(
+            exporter,
+            // The runtime would have to be updated if a different web framework is used
+            opentelemetry_sdk::runtime::TokioCurrentThread,
+        )
+        .with_interval(Duration::from_secs(3))
+        .with_timeout(Duration::from_secs(10))
+        .build(),
     )
-    .with_interval(Duration::from_secs(3))
-    .with_timeout(Duration::from_secs(10))
-    .build();
-
-    let provider = opentelemetry_sdk::metrics::SdkMeterProvider::builder()
-        .with_reader(reader)
-        .with_resource(opentelemetry_sdk::Resource::new([
-            opentelemetry::KeyValue::new(
-                "pod",
-                std::env::var("POD_NAME").unwrap_or(String::from("hyperswitch-server-default")),
-            ),
-        ]))
-        .build();
-
-    opentelemetry::global::set_meter_provider(provider);
 }
 
 fn get_envfilter(
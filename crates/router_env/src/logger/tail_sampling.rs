@@ -0,0 +1,498 @@
+//! Tail-based sampling of traces.
+//!
+//! The spans of a trace are buffered until its local root span (such as the root span of a
+//! request, see [`crate::root_span`]) ends, and all of them are then either exported or dropped.
+//! Traces of failed or slow requests are always exported, while the remaining traces are sampled
+//! at the configured sampling rate.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError, Weak},
+    time::{Duration, Instant},
+};
+
+use opentelemetry::{
+    trace::{SpanId, Status, TraceId, TraceResult},
+    Context, Value,
+};
+use opentelemetry_sdk::{
+    export::trace::SpanData,
+    trace::{Sampler, ShouldSample, Span, SpanProcessor},
+    Resource,
+};
+
+use super::{config::LogTailSampling, control};
+use crate::metric_attributes;
+
+crate::global_meter!(TRACING_METER, "TRACING");
+
+crate::counter_metric!(TAIL_SAMPLING_TRACES_KEPT, TRACING_METER);
+crate::counter_metric!(TAIL_SAMPLING_TRACES_DROPPED, TRACING_METER);
+crate::counter_metric!(TAIL_SAMPLING_SPANS_DROPPED, TRACING_METER);
+
+/// Attribute of the spans of connector calls which failed with a server error or did not receive
+/// a response.
+const CONNECTOR_ERROR_ATTRIBUTE: &str = "connector_error";
+const HTTP_STATUS_CODE_ATTRIBUTES: [&str; 2] = ["http.status_code", "http.response.status_code"];
+/// Interval at which the spans of traces whose root span has not ended within the maximum
+/// duration are dropped.
+const EVICTION_INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+struct BufferedTrace {
+    spans: Vec<SpanData>,
+    started_at: Instant,
+    /// Reason for which the trace must be kept irrespective of the sampling rate
+    keep_reason: Option<&'static str>,
+}
+
+#[derive(Debug, Default)]
+struct TailSamplingState {
+    traces: HashMap<TraceId, BufferedTrace>,
+    /// Spans whose parent is either absent or belongs to another process, along with the time at
+    /// which they started
+    local_roots: HashMap<SpanId, Instant>,
+    buffered_spans: usize,
+}
+
+impl TailSamplingState {
+    /// Drops the spans of traces whose root span has not ended within the maximum duration, along
+    /// with the root spans that have not ended within it.
+    fn evict_expired_traces(&mut self, max_trace_duration: Duration) {
+        self.local_roots
+            .retain(|_, started_at| started_at.elapsed() <= max_trace_duration);
+
+        let mut evicted_spans = 0;
+        self.traces.retain(|_, trace| {
+            let expired = trace.started_at.elapsed() > max_trace_duration;
+            if expired {
+                evicted_spans += trace.spans.len();
+            }
+            !expired
+        });
+
+        if evicted_spans > 0 {
+            self.buffered_spans = self.buffered_spans.saturating_sub(evicted_spans);
+            TAIL_SAMPLING_SPANS_DROPPED.add(
+                u64::try_from(evicted_spans).unwrap_or(u64::MAX),
+                metric_attributes!(("reason", "expired")),
+            );
+        }
+    }
+}
+
+/// Span processor which buffers the spans of each trace until its local root span ends, and
+/// forwards them to the wrapped processor if the trace is sampled.
+#[derive(Debug)]
+pub(super) struct TailSamplingProcessor<P> {
+    inner: P,
+    sampler: Sampler,
+    latency_threshold: Duration,
+    max_buffered_spans: usize,
+    max_trace_duration: Duration,
+    state: Arc<Mutex<TailSamplingState>>,
+}
+
+impl<P: SpanProcessor> TailSamplingProcessor<P> {
+    pub(super) fn new(inner: P, config: &LogTailSampling, sampling_rate: f64) -> Self {
+        let max_trace_duration = Duration::from_secs(config.max_trace_duration_in_secs);
+        let state = Arc::new(Mutex::new(TailSamplingState::default()));
+        spawn_eviction_thread(Arc::downgrade(&state), max_trace_duration);
+
+        Self {
+            inner,
+            sampler: Sampler::TraceIdRatioBased(sampling_rate),
+            latency_threshold: Duration::from_millis(config.latency_threshold_in_ms),
+            max_buffered_spans: config.max_buffered_spans,
+            max_trace_duration,
+            state,
+        }
+    }
+
+    /// Returns the reason for which the trace of the root span must be kept, if any.
+    fn keep_reason(&self, root: &SpanData) -> Option<&'static str> {
+        if root
+            .end_time
+            .duration_since(root.start_time)
+            .is_ok_and(|latency| latency > self.latency_threshold)
+        {
+            return Some("latency");
+        }
+
+        let sampling_result = self.sampler.should_sample(
+            None,
+            root.span_context.trace_id(),
+            &root.name,
+            &root.span_kind,
+            &[],
+            &[],
+        );
+        matches!(
+            sampling_result.decision,
+            opentelemetry::trace::SamplingDecision::RecordAndSample
+        )
+        .then_some("sampled")
+    }
+}
+
+/// Periodically drops the spans of expired traces, so that they are not retained until the buffer
+/// is full. The thread exits once the processor is dropped.
+fn spawn_eviction_thread(state: Weak<Mutex<TailSamplingState>>, max_trace_duration: Duration) {
+    let spawn_result = std::thread::Builder::new()
+        .name("tail-sampling-eviction".into())
+        .spawn(move || loop {
+            std::thread::sleep(EVICTION_INTERVAL);
+            let Some(state) = state.upgrade() else {
+                break;
+            };
+            state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .evict_expired_traces(max_trace_duration);
+        });
+
+    if let Err(error) = spawn_result {
+        #[allow(clippy::print_stderr)] // The logger hasn't been initialized yet
+        eprintln!("Failed to spawn tail sampling eviction thread: {error}");
+    }
+}
+
+/// Returns the reason for which the trace of a span must be kept irrespective of the sampling
+/// rate, if any.
+fn span_keep_reason(span: &SpanData) -> Option<&'static str> {
+    let attribute = |key: &str| {
+        span.attributes
+            .iter()
+            .find(|attribute| attribute.key.as_str() == key)
+            .map(|attribute| &attribute.value)
+    };
+
+    if HTTP_STATUS_CODE_ATTRIBUTES
+        .iter()
+        .any(|key| matches!(attribute(key), Some(Value::I64(status_code)) if *status_code >= 500))
+    {
+        Some("server_error")
+    } else if matches!(
+        attribute(CONNECTOR_ERROR_ATTRIBUTE),
+        Some(Value::Bool(true))
+    ) {
+        Some("connector_error")
+    } else if matches!(span.status, Status::Error { .. }) {
+        Some("error")
+    } else if control::should_force_sample(&span.attributes) {
+        Some("debug_override")
+    } else {
+        None
+    }
+}
+
+impl<P: SpanProcessor> SpanProcessor for TailSamplingProcessor<P> {
+    fn on_start(&self, span: &mut Span, cx: &Context) {
+        use opentelemetry::trace::{Span as _, TraceContextExt};
+
+        let parent_span_context = cx.span().span_context().clone();
+        if !parent_span_context.is_valid() || parent_span_context.is_remote() {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.local_roots.len() >= self.max_buffered_spans {
+                state.evict_expired_traces(self.max_trace_duration);
+            }
+            // Once the limit is reached, root spans with a remote parent are buffered as any other
+            // span when they end, and their traces are dropped once they expire
+            if state.local_roots.len() < self.max_buffered_spans {
+                state
+                    .local_roots
+                    .insert(span.span_context().span_id(), Instant::now());
+            }
+        }
+
+        self.inner.on_start(span, cx);
+    }
+
+    fn on_end(&self, span: SpanData) {
+        let trace_id = span.span_context.trace_id();
+        let span_keep_reason = span_keep_reason(&span);
+
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let is_local_root = state
+            .local_roots
+            .remove(&span.span_context.span_id())
+            .is_some()
+            || span.parent_span_id == SpanId::INVALID;
+
+        if !is_local_root {
+            if state.buffered_spans >= self.max_buffered_spans {
+                state.evict_expired_traces(self.max_trace_duration);
+            }
+            if state.buffered_spans >= self.max_buffered_spans {
+                drop(state);
+                TAIL_SAMPLING_SPANS_DROPPED.add(1, metric_attributes!(("reason", "buffer_full")));
+                return;
+            }
+
+            state.buffered_spans = state.buffered_spans.saturating_add(1);
+            let trace = state
+                .traces
+                .entry(trace_id)
+                .or_insert_with(|| BufferedTrace {
+                    spans: Vec::new(),
+                    started_at: Instant::now(),
+                    keep_reason: None,
+                });
+            trace.keep_reason = trace.keep_reason.or(span_keep_reason);
+            trace.spans.push(span);
+            return;
+        }
+
+        let trace = state.traces.remove(&trace_id);
+        if let Some(trace) = &trace {
+            state.buffered_spans = state.buffered_spans.saturating_sub(trace.spans.len());
+        }
+        drop(state);
+
+        let (buffered_spans, buffered_keep_reason) = trace
+            .map(|trace| (trace.spans, trace.keep_reason))
+            .unwrap_or_default();
+        let keep_reason = span_keep_reason
+            .or(buffered_keep_reason)
+            .or_else(|| self.keep_reason(&span));
+
+        match keep_reason {
+            Some(reason) => {
+                TAIL_SAMPLING_TRACES_KEPT.add(1, metric_attributes!(("reason", reason)));
+                for buffered_span in buffered_spans {
+                    self.inner.on_end(buffered_span);
+                }
+                self.inner.on_end(span);
+            }
+            None => TAIL_SAMPLING_TRACES_DROPPED.add(1, &[]),
+        }
+    }
+
+    fn force_flush(&self) -> TraceResult<()> {
+        self.inner.force_flush()
+    }
+
+    fn shutdown(&self) -> TraceResult<()> {
+        self.inner.shutdown()
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.inner.set_resource(resource);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::time::SystemTime;
+
+    use opentelemetry::{
+        trace::{SpanContext, SpanKind, TraceFlags, TraceState},
+        InstrumentationScope, KeyValue,
+    };
+
+    use super::*;
+
+    const ROOT_SPAN_ID: u64 = 1;
+
+    /// Span processor which collects the spans forwarded to it.
+    #[derive(Debug, Default, Clone)]
+    struct CollectingProcessor {
+        spans: Arc<Mutex<Vec<SpanData>>>,
+    }
+
+    impl CollectingProcessor {
+        fn span_ids(&self) -> Vec<u64> {
+            self.spans
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|span| u64::from_be_bytes(span.span_context.span_id().to_bytes()))
+                .collect()
+        }
+    }
+
+    impl SpanProcessor for CollectingProcessor {
+        fn on_start(&self, _span: &mut Span, _cx: &Context) {}
+
+        fn on_end(&self, span: SpanData) {
+            self.spans.lock().unwrap().push(span);
+        }
+
+        fn force_flush(&self) -> TraceResult<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> TraceResult<()> {
+            Ok(())
+        }
+    }
+
+    fn tail_sampling_processor(
+        sampling_rate: f64,
+        max_buffered_spans: usize,
+    ) -> (
+        TailSamplingProcessor<CollectingProcessor>,
+        CollectingProcessor,
+    ) {
+        let collector = CollectingProcessor::default();
+        let config = LogTailSampling {
+            enabled: true,
+            latency_threshold_in_ms: 1000,
+            max_buffered_spans,
+            max_trace_duration_in_secs: 300,
+        };
+
+        (
+            TailSamplingProcessor::new(collector.clone(), &config, sampling_rate),
+            collector,
+        )
+    }
+
+    /// Builds a span of the trace, which is the root span if it has no parent.
+    fn span(span_id: u64, parent_span_id: Option<u64>, latency: Duration) -> SpanData {
+        let start_time = SystemTime::UNIX_EPOCH;
+
+        SpanData {
+            span_context: SpanContext::new(
+                TraceId::from(1_u128),
+                SpanId::from(span_id),
+                TraceFlags::SAMPLED,
+                false,
+                TraceState::default(),
+            ),
+            parent_span_id: parent_span_id.map_or(SpanId::INVALID, SpanId::from),
+            span_kind: SpanKind::Internal,
+            name: "span".into(),
+            start_time,
+            end_time: start_time + latency,
+            attributes: Vec::new(),
+            dropped_attributes_count: 0,
+            events: Default::default(),
+            links: Default::default(),
+            status: Status::Unset,
+            instrumentation_scope: InstrumentationScope::builder("test").build(),
+        }
+    }
+
+    fn child_span(span_id: u64) -> SpanData {
+        span(span_id, Some(ROOT_SPAN_ID), Duration::from_millis(10))
+    }
+
+    fn root_span(latency: Duration) -> SpanData {
+        span(ROOT_SPAN_ID, None, latency)
+    }
+
+    #[test]
+    fn test_unsampled_trace_is_dropped() {
+        let (processor, collector) = tail_sampling_processor(0.0, 100);
+
+        processor.on_end(child_span(2));
+        processor.on_end(root_span(Duration::from_millis(10)));
+
+        assert!(collector.span_ids().is_empty());
+        let state = processor.state.lock().unwrap();
+        assert!(state.traces.is_empty());
+        assert_eq!(state.buffered_spans, 0);
+    }
+
+    #[test]
+    fn test_sampled_trace_is_kept() {
+        let (processor, collector) = tail_sampling_processor(1.0, 100);
+
+        processor.on_end(child_span(2));
+        processor.on_end(root_span(Duration::from_millis(10)));
+
+        assert_eq!(collector.span_ids(), [2, ROOT_SPAN_ID]);
+    }
+
+    #[test]
+    fn test_trace_with_failed_span_is_kept() {
+        let (processor, collector) = tail_sampling_processor(0.0, 100);
+
+        let mut failed_span = child_span(3);
+        failed_span.status = Status::error("connector timed out");
+        processor.on_end(child_span(2));
+        processor.on_end(failed_span);
+        processor.on_end(root_span(Duration::from_millis(10)));
+
+        assert_eq!(collector.span_ids(), [2, 3, ROOT_SPAN_ID]);
+    }
+
+    #[test]
+    fn test_trace_of_server_error_is_kept() {
+        let (processor, collector) = tail_sampling_processor(0.0, 100);
+
+        let mut root_span = root_span(Duration::from_millis(10));
+        root_span
+            .attributes
+            .push(KeyValue::new("http.status_code", 502_i64));
+        processor.on_end(root_span);
+
+        assert_eq!(collector.span_ids(), [ROOT_SPAN_ID]);
+    }
+
+    #[test]
+    fn test_trace_of_client_error_is_not_kept() {
+        let (processor, collector) = tail_sampling_processor(0.0, 100);
+
+        let mut root_span = root_span(Duration::from_millis(10));
+        root_span
+            .attributes
+            .push(KeyValue::new("http.status_code", 404_i64));
+        processor.on_end(root_span);
+
+        assert!(collector.span_ids().is_empty());
+    }
+
+    #[test]
+    fn test_slow_trace_is_kept() {
+        let (processor, collector) = tail_sampling_processor(0.0, 100);
+
+        processor.on_end(child_span(2));
+        processor.on_end(root_span(Duration::from_secs(2)));
+
+        assert_eq!(collector.span_ids(), [2, ROOT_SPAN_ID]);
+    }
+
+    #[test]
+    fn test_spans_are_dropped_once_buffer_is_full() {
+        let (processor, collector) = tail_sampling_processor(1.0, 1);
+
+        processor.on_end(child_span(2));
+        processor.on_end(child_span(3));
+        processor.on_end(root_span(Duration::from_millis(10)));
+
+        assert_eq!(collector.span_ids(), [2, ROOT_SPAN_ID]);
+    }
+
+    #[test]
+    fn test_expired_traces_and_local_roots_are_evicted() {
+        let (processor, collector) = tail_sampling_processor(1.0, 100);
+
+        processor.on_end(child_span(2));
+        processor
+            .state
+            .lock()
+            .unwrap()
+            .local_roots
+            .insert(SpanId::from(ROOT_SPAN_ID), Instant::now());
+        std::thread::sleep(Duration::from_millis(5));
+
+        let mut state = processor.state.lock().unwrap();
+        state.evict_expired_traces(Duration::from_secs(60));
+        assert_eq!(state.traces.len(), 1);
+        assert_eq!(state.local_roots.len(), 1);
+
+        state.evict_expired_traces(Duration::from_millis(1));
+        assert!(state.traces.is_empty());
+        assert!(state.local_roots.is_empty());
+        assert_eq!(state.buffered_spans, 0);
+        drop(state);
+
+        // The spans of the evicted trace are not exported once its root span ends
+        processor.on_end(root_span(Duration::from_millis(10)));
+        assert_eq!(collector.span_ids(), [ROOT_SPAN_ID]);
+    }
+}
@@ -213,6 +213,21 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts filter flow.
     PayoutsFilter,
+    #[cfg(feature = "payouts")]
+    /// Payout batch create flow.
+    PayoutBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payout batch retrieve flow.
+    PayoutBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payout batch items list flow.
+    PayoutBatchItemsList,
+    #[cfg(feature = "payouts")]
+    /// Payout batch cancel flow.
+    PayoutBatchCancel,
+    #[cfg(feature = "payouts")]
+    /// Payout batch list flow.
+    PayoutBatchList,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payout link initiate flow
@@ -245,6 +260,16 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Settlement report ingestion flow
+    SettlementReportIngest,
+    /// Settlement report retrieve flow
+    SettlementReportRetrieve,
+    /// Settlement report list flow
+    SettlementReportList,
+    /// Recon results list flow
+    ReconResultsList,
+    /// Recon results export flow
+    ReconResultsExport,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -271,6 +296,8 @@ pub enum Flow {
     CreateSubscription,
     /// Subscription get plans flow,
     GetPlansForSubscription,
+    /// Subscription create plan flow,
+    CreateSubscriptionPlan,
     /// Subscription confirm flow,
     ConfirmSubscription,
     /// Subscription create and confirm flow,
@@ -343,6 +370,12 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Dispute Evidence bundle generation flow
+    DisputesEvidenceBundle,
+    /// Dispute automation config retrieve flow
+    DisputeAutomationConfigRetrieve,
+    /// Dispute automation config update flow
+    DisputeAutomationConfigUpdate,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -379,6 +412,28 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Record a bounce or complaint reported for an email sent through the email outbox
+    EmailDeliveryEventRecord,
+    /// Publish the analytics events of the payments of a merchant to Kafka again
+    KafkaEventsBackfill,
+    /// Retrieve the runtime logging state
+    LogControlRetrieve,
+    /// Replace the logging filtering directive at runtime
+    LogFilterUpdate,
+    /// Restore the configured logging filtering directives
+    LogFilterReset,
+    /// Enable verbose logging for the requests of a merchant, profile or connector
+    DebugLoggingCreate,
+    /// Disable verbose logging enabled earlier
+    DebugLoggingDelete,
+    /// List process tracker tasks matching a filter
+    ProcessTrackerTaskList,
+    /// Retrieve a process tracker task along with its tracking data
+    ProcessTrackerTaskRetrieve,
+    /// Reschedule, retry, pause or cancel a process tracker task
+    ProcessTrackerTaskUpdate,
+    /// Reschedule, retry, pause or cancel all process tracker tasks matching a filter
+    ProcessTrackerTaskBulkUpdate,
     /// Get data from embedded flow
     GetDataFromHyperswitchAiFlow,
     // List all chat interactions
@@ -401,6 +456,10 @@ pub enum Flow {
     DecisionManagerRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// Native fraud engine config retrieve flow
+    NativeFrmConfigRetrieve,
+    /// Native fraud engine config update flow
+    NativeFrmConfigUpdate,
     /// Get connectors feature matrix
     FeatureMatrix,
     /// Change password flow
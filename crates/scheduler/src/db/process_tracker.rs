@@ -62,6 +62,20 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: &storage::ProcessTrackerFilterConstraints,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn update_processes_by_ids_and_statuses(
+        &self,
+        task_ids: Vec<String>,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        task_update: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -192,6 +206,35 @@ impl ProcessTrackerInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: &storage::ProcessTrackerFilterConstraints,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::filter_processes_by_constraints(&conn, constraints, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn update_processes_by_ids_and_statuses(
+        &self,
+        task_ids: Vec<String>,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        task_update: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::update_processes_by_ids_and_statuses(
+            &conn,
+            task_ids,
+            statuses,
+            task_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -299,4 +342,73 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_processes_by_constraints(
+        &self,
+        constraints: &storage::ProcessTrackerFilterConstraints,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+
+        let mut processes: Vec<_> = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                constraints.runner.is_none_or(|runner| {
+                    process.runner.as_deref() == Some(runner.to_string().as_str())
+                }) && constraints
+                    .statuses
+                    .as_ref()
+                    .is_none_or(|statuses| statuses.contains(&process.status))
+                    && constraints.merchant_id.as_ref().is_none_or(|merchant_id| {
+                        process
+                            .tracking_data
+                            .get("merchant_id")
+                            .and_then(|value| value.as_str())
+                            == Some(merchant_id.get_string_repr())
+                    })
+                    && constraints.schedule_time_range.is_none_or(|time_range| {
+                        process.schedule_time.is_some_and(|schedule_time| {
+                            schedule_time >= time_range.start_time
+                                && time_range
+                                    .end_time
+                                    .is_none_or(|end_time| schedule_time <= end_time)
+                        })
+                    })
+            })
+            .cloned()
+            .collect();
+        processes.sort_by(|a, b| b.schedule_time.cmp(&a.schedule_time));
+
+        Ok(processes.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn update_processes_by_ids_and_statuses(
+        &self,
+        task_ids: Vec<String>,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        task_update: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let task_update = storage::ProcessTrackerUpdateInternal::from(task_update);
+
+        Ok(self
+            .processes
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id) && statuses.contains(&process.status))
+            .map(|process| {
+                *process = task_update.clone().apply_changeset(process.clone());
+                process.clone()
+            })
+            .collect())
+    }
 }
@@ -36,6 +36,7 @@ pub mod redis;
 pub mod refund;
 mod reverse_lookup;
 pub mod subscription;
+pub mod subscription_plan;
 pub mod utils;
 
 use common_utils::{errors::CustomResult, types::keymanager::KeyManagerState};
@@ -158,6 +159,15 @@ impl<T: DatabaseStore> RouterStore<T> {
             .await
             .change_context(StorageError::InitializationError)
             .attach_printable("Failed to subscribe to inmemory cache stream")?;
+        cache_store
+            .redis_conn
+            .subscribe(redis::pub_sub::LOG_CONTROL_CHANNEL)
+            .await
+            .change_context(StorageError::InitializationError)
+            .attach_printable("Failed to subscribe to log control channel")?;
+        if let Err(err) = cache_store.redis_conn.restore_log_control_state().await {
+            router_env::logger::error!(log_control_err=?err, "Failed to restore log control state");
+        }
 
         Ok(Self {
             db_store,
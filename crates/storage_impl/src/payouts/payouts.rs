@@ -304,6 +304,20 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         .map(|payout| payout.map(Payouts::from_storage_model))
     }
 
+    #[instrument(skip_all)]
+    async fn filter_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_ids: &[common_utils::id_type::PayoutId],
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        // Payouts which are yet to be drained from redis are not found by this lookup, callers
+        // must not rely on it as the only check for the uniqueness of payout IDs
+        self.router_store
+            .filter_payouts_by_merchant_id_payout_ids(merchant_id, payout_ids, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -459,6 +473,28 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn filter_payouts_by_merchant_id_payout_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_ids: &[common_utils::id_type::PayoutId],
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<Payouts>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPayouts::filter_by_merchant_id_payout_ids(&conn, merchant_id, payout_ids)
+            .await
+            .map(|payouts| {
+                payouts
+                    .into_iter()
+                    .map(Payouts::from_storage_model)
+                    .collect()
+            })
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
     #[cfg(feature = "olap")]
     #[instrument(skip_all)]
     async fn filter_payouts_by_constraints(
@@ -1,8 +1,14 @@
-use std::sync::atomic;
+use std::{collections::HashMap, sync::atomic};
 
 use error_stack::ResultExt;
-use redis_interface::{errors as redis_errors, PubsubInterface, RedisValue};
-use router_env::{logger, tracing::Instrument};
+use redis_interface::{errors as redis_errors, PubsubInterface, RedisConnectionPool, RedisValue};
+use router_env::{
+    logger::{
+        self,
+        control::{DebugOverride, LogControlStatus, LogControlUpdate},
+    },
+    tracing::Instrument,
+};
 
 use crate::redis::cache::{
     CacheKey, CacheKind, CacheRedact, ACCOUNTS_CACHE, CGRAPH_CACHE, CONFIG_CACHE,
@@ -11,6 +17,16 @@ use crate::redis::cache::{
     SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE, SURCHARGE_CACHE,
 };
 
+/// Redis channel name used for propagating runtime logging updates across instances
+pub const LOG_CONTROL_CHANNEL: &str = "hyperswitch_log_control";
+
+/// Redis hash in which the runtime logging state is persisted, so that it is restored by instances
+/// that start, or reconnect, after an update is published. Like the logging state, the hash is
+/// shared by all tenants.
+const LOG_CONTROL_STATE_KEY: &str = "hyperswitch_log_control_state";
+const LOG_CONTROL_FILTER_DIRECTIVE_FIELD: &str = "filter_directive";
+const LOG_CONTROL_DEBUG_OVERRIDE_FIELD_PREFIX: &str = "debug_override_";
+
 #[async_trait::async_trait]
 pub trait PubSubInterface {
     async fn subscribe(&self, channel: &str) -> error_stack::Result<(), redis_errors::RedisError>;
@@ -21,6 +37,18 @@ pub trait PubSubInterface {
         key: CacheKind<'a>,
     ) -> error_stack::Result<usize, redis_errors::RedisError>;
 
+    async fn publish_log_control_update(
+        &self,
+        update: &LogControlUpdate,
+    ) -> error_stack::Result<usize, redis_errors::RedisError>;
+
+    async fn persist_log_control_update(
+        &self,
+        update: &LogControlUpdate,
+    ) -> error_stack::Result<(), redis_errors::RedisError>;
+
+    async fn restore_log_control_state(&self) -> error_stack::Result<(), redis_errors::RedisError>;
+
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError>;
 }
 
@@ -57,6 +85,23 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                 }
                 .in_current_span(),
             );
+
+            // Log control updates published while the subscriber was disconnected are missed
+            let redis_clone = self.clone();
+            let _task_handle = tokio::spawn(
+                async move {
+                    redis_clone
+                        .on_subscriber_reconnect(|| async {
+                            if let Err(log_control_err) =
+                                redis_clone.restore_log_control_state().await
+                            {
+                                logger::error!(?log_control_err);
+                            }
+                        })
+                        .await;
+                }
+                .in_current_span(),
+            );
         }
 
         Ok(())
@@ -82,6 +127,94 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
             .change_context(redis_errors::RedisError::SubscribeError)
     }
 
+    #[inline]
+    async fn publish_log_control_update(
+        &self,
+        update: &LogControlUpdate,
+    ) -> error_stack::Result<usize, redis_errors::RedisError> {
+        let value = serde_json::to_vec(update)
+            .change_context(redis_errors::RedisError::JsonSerializationFailed)?;
+
+        self.publisher
+            .publish(LOG_CONTROL_CHANNEL, RedisValue::from_bytes(value))
+            .await
+            .change_context(redis_errors::RedisError::PublishError)
+    }
+
+    #[inline]
+    async fn persist_log_control_update(
+        &self,
+        update: &LogControlUpdate,
+    ) -> error_stack::Result<(), redis_errors::RedisError> {
+        let redis_conn = RedisConnectionPool::clone(self, "");
+        let key = LOG_CONTROL_STATE_KEY.into();
+
+        match update {
+            LogControlUpdate::SetFilterDirective {
+                directive: Some(directive),
+            } => {
+                redis_conn
+                    .set_hash_fields_without_modifying_ttl(
+                        &key,
+                        (LOG_CONTROL_FILTER_DIRECTIVE_FIELD, directive.as_str()),
+                    )
+                    .await
+            }
+            LogControlUpdate::SetFilterDirective { directive: None } => redis_conn
+                .delete_hash_fields(&key, vec![LOG_CONTROL_FILTER_DIRECTIVE_FIELD.to_owned()])
+                .await
+                .map(|_| ()),
+            LogControlUpdate::AddDebugOverride(debug_override) => {
+                let value = serde_json::to_string(debug_override)
+                    .change_context(redis_errors::RedisError::JsonSerializationFailed)?;
+
+                redis_conn
+                    .set_hash_fields_without_modifying_ttl(
+                        &key,
+                        (debug_override_field(&debug_override.id), value),
+                    )
+                    .await
+            }
+            LogControlUpdate::RemoveDebugOverride { id } => redis_conn
+                .delete_hash_fields(&key, vec![debug_override_field(id)])
+                .await
+                .map(|_| ()),
+        }
+    }
+
+    #[inline]
+    async fn restore_log_control_state(&self) -> error_stack::Result<(), redis_errors::RedisError> {
+        let redis_conn = RedisConnectionPool::clone(self, "");
+        let key = LOG_CONTROL_STATE_KEY.into();
+
+        let fields = redis_conn
+            .get_hash_fields::<HashMap<String, String>>(&key)
+            .await?;
+        let status = parse_log_control_state(fields);
+
+        // Expired debug overrides are ignored when the state is restored, and are deleted so that
+        // the hash does not keep growing
+        let now = common_utils::date_time::now_unix_timestamp();
+        let expired_fields = status
+            .debug_overrides
+            .iter()
+            .filter(|debug_override| debug_override.expires_at <= now)
+            .map(|debug_override| debug_override_field(&debug_override.id))
+            .collect::<Vec<_>>();
+        if !expired_fields.is_empty() {
+            if let Err(err) = redis_conn.delete_hash_fields(&key, expired_fields).await {
+                logger::error!(log_control_err=?err, "Failed to delete expired debug overrides");
+            }
+        }
+
+        // Updates are validated before they are persisted
+        if let Err(err) = logger::control::restore(status) {
+            logger::error!(log_control_err=?err, "Failed to restore log control state");
+        }
+
+        Ok(())
+    }
+
     #[inline]
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError> {
         logger::debug!("Started on message");
@@ -265,6 +398,27 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                         "Done invalidating {key}"
                     );
                 }
+                LOG_CONTROL_CHANNEL => {
+                    let update = match RedisValue::new(message.value)
+                        .as_bytes()
+                        .map(serde_json::from_slice::<LogControlUpdate>)
+                    {
+                        Some(Ok(update)) => update,
+                        Some(Err(err)) => {
+                            logger::error!(value_conversion_err=?err);
+                            continue;
+                        }
+                        None => {
+                            logger::error!("Invalid value received on log control channel");
+                            continue;
+                        }
+                    };
+
+                    // Updates are validated before being published, and are idempotent
+                    if let Err(err) = logger::control::apply(update) {
+                        logger::error!(log_control_err=?err);
+                    }
+                }
                 _ => {
                     logger::debug!("Received message from unknown channel: {channel_name}");
                 }
@@ -273,3 +427,86 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
         Ok(())
     }
 }
+
+fn debug_override_field(id: &str) -> String {
+    format!("{LOG_CONTROL_DEBUG_OVERRIDE_FIELD_PREFIX}{id}")
+}
+
+/// Builds the logging state from the fields of the hash in which it is persisted, skipping any
+/// fields that cannot be parsed.
+fn parse_log_control_state(fields: HashMap<String, String>) -> LogControlStatus {
+    let mut status = LogControlStatus::default();
+
+    for (field, value) in fields {
+        if field == LOG_CONTROL_FILTER_DIRECTIVE_FIELD {
+            status.filter_directive = Some(value);
+        } else if field.starts_with(LOG_CONTROL_DEBUG_OVERRIDE_FIELD_PREFIX) {
+            match serde_json::from_str::<DebugOverride>(&value) {
+                Ok(debug_override) => status.debug_overrides.push(debug_override),
+                Err(err) => logger::error!(%field, value_conversion_err=?err),
+            }
+        }
+    }
+    status
+        .debug_overrides
+        .sort_by(|left, right| left.id.cmp(&right.id));
+
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use router_env::logger::control::VerboseLevel;
+
+    use super::*;
+
+    fn debug_override(id: &str) -> DebugOverride {
+        DebugOverride {
+            id: id.to_owned(),
+            merchant_id: Some("merchant_1".to_owned()),
+            profile_id: None,
+            connector: None,
+            level: VerboseLevel::Trace,
+            sample_traces: false,
+            expires_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_parse_log_control_state() {
+        let fields = HashMap::from([
+            (
+                LOG_CONTROL_FILTER_DIRECTIVE_FIELD.to_owned(),
+                "router=debug".to_owned(),
+            ),
+            (
+                debug_override_field("dbg_2"),
+                serde_json::to_string(&debug_override("dbg_2")).unwrap(),
+            ),
+            (
+                debug_override_field("dbg_1"),
+                serde_json::to_string(&debug_override("dbg_1")).unwrap(),
+            ),
+            (debug_override_field("dbg_invalid"), "{".to_owned()),
+            ("unknown_field".to_owned(), "value".to_owned()),
+        ]);
+
+        assert_eq!(
+            parse_log_control_state(fields),
+            LogControlStatus {
+                filter_directive: Some("router=debug".to_owned()),
+                debug_overrides: vec![debug_override("dbg_1"), debug_override("dbg_2")],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_empty_log_control_state() {
+        assert_eq!(
+            parse_log_control_state(HashMap::new()),
+            LogControlStatus::default()
+        );
+    }
+}
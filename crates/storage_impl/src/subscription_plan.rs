@@ -0,0 +1,222 @@
+use diesel_models::subscription_plan as storage;
+use error_stack::report;
+use hyperswitch_domain_models::subscription_plan::SubscriptionPlanInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    connection, errors::StorageError, kv_router_store::KVRouterStore, CustomResult, DatabaseStore,
+    MockDb, RouterStore,
+};
+
+#[async_trait::async_trait]
+impl<T: DatabaseStore> SubscriptionPlanInterface for RouterStore<T> {
+    type Error = StorageError;
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        plan.insert(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan_price(
+        &self,
+        price: storage::SubscriptionPlanPriceNew,
+    ) -> CustomResult<storage::SubscriptionPlanPrice, StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        price
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_profile_id_plan_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::find_by_profile_id_plan_id(&conn, profile_id, plan_id)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlan::list_by_merchant_id_profile_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plan_prices_by_profile_id_plan_ids(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlanPrice>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlanPrice::list_by_profile_id_plan_ids(&conn, profile_id, plan_ids)
+            .await
+            .map_err(|error| report!(StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_price_by_profile_id_price_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        price_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionPlanPrice>, StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SubscriptionPlanPrice::find_optional_by_profile_id_price_id(
+            &conn, profile_id, price_id,
+        )
+        .await
+        .map_err(|error| report!(StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: DatabaseStore> SubscriptionPlanInterface for KVRouterStore<T> {
+    type Error = StorageError;
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan(
+        &self,
+        plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, StorageError> {
+        self.router_store.insert_subscription_plan(plan).await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_subscription_plan_price(
+        &self,
+        price: storage::SubscriptionPlanPriceNew,
+    ) -> CustomResult<storage::SubscriptionPlanPrice, StorageError> {
+        self.router_store
+            .insert_subscription_plan_price(price)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_by_profile_id_plan_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, StorageError> {
+        self.router_store
+            .find_subscription_plan_by_profile_id_plan_id(profile_id, plan_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, StorageError> {
+        self.router_store
+            .list_subscription_plans_by_merchant_id_profile_id(
+                merchant_id,
+                profile_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_subscription_plan_prices_by_profile_id_plan_ids(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        plan_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlanPrice>, StorageError> {
+        self.router_store
+            .list_subscription_plan_prices_by_profile_id_plan_ids(profile_id, plan_ids)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_subscription_plan_price_by_profile_id_price_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        price_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionPlanPrice>, StorageError> {
+        self.router_store
+            .find_subscription_plan_price_by_profile_id_price_id(profile_id, price_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SubscriptionPlanInterface for MockDb {
+    type Error = StorageError;
+
+    async fn insert_subscription_plan(
+        &self,
+        _plan: storage::SubscriptionPlanNew,
+    ) -> CustomResult<storage::SubscriptionPlan, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn insert_subscription_plan_price(
+        &self,
+        _price: storage::SubscriptionPlanPriceNew,
+    ) -> CustomResult<storage::SubscriptionPlanPrice, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_plan_by_profile_id_plan_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _plan_id: &str,
+    ) -> CustomResult<storage::SubscriptionPlan, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_plans_by_merchant_id_profile_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlan>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn list_subscription_plan_prices_by_profile_id_plan_ids(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _plan_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::SubscriptionPlanPrice>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn find_subscription_plan_price_by_profile_id_price_id(
+        &self,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _price_id: &str,
+    ) -> CustomResult<Option<storage::SubscriptionPlanPrice>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+}
@@ -4,8 +4,10 @@ use common_utils::id_type::GenerateId;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     api::ApplicationResponse, invoice::InvoiceUpdateRequest, platform::Platform,
+    router_response_types::subscriptions as subscription_response_types,
     subscription::SubscriptionUpdate,
 };
+use router_env::logger;
 
 pub type RouterResponse<T> =
     Result<ApplicationResponse<T>, error_stack::Report<errors::ApiErrorResponse>>;
@@ -13,16 +15,19 @@ use api_models::enums::SubscriptionStatus;
 
 use crate::{
     core::{
-        billing_processor_handler::BillingHandler,
         invoice_handler::InvoiceHandler,
+        native_billing_handler::{BillingEngine, NativeBillingCycle},
         subscription_handler::{SubscriptionHandler, SubscriptionWithHandler},
     },
     state::SubscriptionState as SessionState,
+    types::storage::{invoice_sync::InvoiceSyncPaymentStatus, native_billing},
+    workflows::subscription_billing,
 };
 
 pub mod billing_processor_handler;
 pub mod errors;
 pub mod invoice_handler;
+pub mod native_billing_handler;
 pub mod payments_api_client;
 pub mod subscription_handler;
 
@@ -42,7 +47,7 @@ pub async fn create_subscription(
     let _customer = SubscriptionHandler::find_customer(&state, &platform, &request.customer_id)
         .await
         .attach_printable("subscriptions: failed to find customer")?;
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
@@ -55,8 +60,8 @@ pub async fn create_subscription(
         .create_subscription_entry(
             subscription_id,
             &request.customer_id,
-            billing_handler.connector_name,
-            billing_handler.merchant_connector_id.clone(),
+            billing_engine.connector_name(),
+            billing_engine.merchant_connector_id(),
             request.merchant_reference_id.clone(),
             &profile.clone(),
             request.plan_id.clone(),
@@ -71,7 +76,7 @@ pub async fn create_subscription(
         coupon_code: None,
     };
 
-    let estimate = billing_handler
+    let estimate = billing_engine
         .get_subscription_estimate(&state, estimate_request)
         .await?;
 
@@ -89,12 +94,12 @@ pub async fn create_subscription(
     let invoice = invoice_handler
         .create_invoice_entry(
             &state,
-            billing_handler.merchant_connector_id,
+            billing_engine.merchant_connector_id(),
             Some(payment.payment_id.clone()),
             estimate.total,
             estimate.currency,
             connector_enums::InvoiceStatus::InvoiceCreated,
-            billing_handler.connector_name,
+            billing_engine.connector_name(),
             None,
             None,
         )
@@ -139,7 +144,7 @@ pub async fn get_subscription_plans(
             .await?
     };
 
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
@@ -147,30 +152,44 @@ pub async fn get_subscription_plans(
     )
     .await?;
 
-    let get_plans_response = billing_handler
+    let response = billing_engine
         .get_subscription_plans(&state, query.limit, query.offset)
         .await?;
 
-    let mut response = Vec::new();
+    Ok(ApplicationResponse::Json(response))
+}
+/// Adds a plan to the catalog of a profile billed by the native billing engine.
+pub async fn create_subscription_plan(
+    state: SessionState,
+    platform: Platform,
+    profile_id: common_utils::id_type::ProfileId,
+    request: subscription_types::CreateSubscriptionPlanRequest,
+) -> RouterResponse<subscription_types::GetPlansResponse> {
+    request
+        .validate()
+        .map_err(|message| errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        })?;
 
-    for plan in &get_plans_response.list {
-        let plan_price_response = billing_handler
-            .get_subscription_plan_prices(&state, plan.subscription_provider_plan_id.clone())
-            .await?;
+    let profile = SubscriptionHandler::find_business_profile(&state, &platform, &profile_id)
+        .await
+        .attach_printable("subscriptions: failed to find business profile")?;
+
+    let billing_engine = BillingEngine::create(
+        &state,
+        platform.get_processor().get_account(),
+        platform.get_processor().get_key_store(),
+        profile,
+    )
+    .await?;
+
+    let response = billing_engine
+        .create_subscription_plan(&state, request)
+        .await?;
 
-        response.push(subscription_types::GetPlansResponse {
-            plan_id: plan.subscription_provider_plan_id.clone(),
-            name: plan.name.clone(),
-            description: plan.description.clone(),
-            price_id: plan_price_response
-                .list
-                .into_iter()
-                .map(subscription_types::SubscriptionPlanPrices::from)
-                .collect::<Vec<_>>(),
-        });
-    }
     Ok(ApplicationResponse::Json(response))
 }
+
 /// Creates and confirms a subscription in one operation.
 pub async fn create_and_confirm_subscription(
     state: SessionState,
@@ -192,7 +211,7 @@ pub async fn create_and_confirm_subscription(
         .await
         .attach_printable("subscriptions: failed to find customer")?;
 
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
@@ -204,8 +223,8 @@ pub async fn create_and_confirm_subscription(
         .create_subscription_entry(
             subscription_id.clone(),
             &request.customer_id,
-            billing_handler.connector_name,
-            billing_handler.merchant_connector_id.clone(),
+            billing_engine.connector_name(),
+            billing_engine.merchant_connector_id(),
             request.merchant_reference_id.clone(),
             &profile.clone(),
             request.plan_id.clone(),
@@ -215,6 +234,54 @@ pub async fn create_and_confirm_subscription(
         .attach_printable("subscriptions: failed to create subscription entry")?;
     let invoice_handler = subs_handler.get_invoice_handler(profile.clone());
 
+    let billing_handler = match &billing_engine {
+        BillingEngine::Connector(billing_handler) => billing_handler,
+        BillingEngine::Native(native_handler) => {
+            let billing_cycle = native_handler
+                .start_billing_cycle(&state, request.plan_id.as_deref(), &request.item_price_id)
+                .await?;
+            let payment_response = invoice_handler
+                .create_and_confirm_payment(
+                    &state,
+                    &request,
+                    billing_cycle.amount_due,
+                    billing_cycle.currency,
+                )
+                .await?;
+
+            let invoice_entry = invoice_handler
+                .create_invoice_entry(
+                    &state,
+                    None,
+                    Some(payment_response.payment_id.clone()),
+                    billing_cycle.amount_due,
+                    billing_cycle.currency,
+                    connector_enums::InvoiceStatus::InvoiceCreated,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            invoice_handler
+                .create_invoice_sync_job(&state, &invoice_entry, None, None)
+                .await?;
+
+            let status = start_native_billing(
+                &state,
+                &mut subs_handler,
+                &billing_cycle,
+                &payment_response,
+                request.plan_id,
+                Some(request.item_price_id),
+            )
+            .await?;
+
+            let response =
+                subs_handler.generate_response(&invoice_entry, &payment_response, status)?;
+            return Ok(ApplicationResponse::Json(response));
+        }
+    };
+
     let customer_create_response = billing_handler
         .create_customer_on_connector(
             &state,
@@ -258,7 +325,7 @@ pub async fn create_and_confirm_subscription(
     let invoice_entry = invoice_handler
         .create_invoice_entry(
             &state,
-            profile.get_billing_processor_id()?,
+            Some(profile.get_billing_processor_id()?),
             Some(payment_response.payment_id.clone()),
             amount,
             currency,
@@ -266,7 +333,7 @@ pub async fn create_and_confirm_subscription(
                 .clone()
                 .and_then(|invoice| invoice.status)
                 .unwrap_or(connector_enums::InvoiceStatus::InvoiceCreated),
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
             None,
             invoice_details.clone().map(|invoice| invoice.id),
         )
@@ -277,7 +344,7 @@ pub async fn create_and_confirm_subscription(
             &state,
             &invoice_entry,
             invoice_details.clone().map(|details| details.id),
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
         )
         .await?;
 
@@ -348,13 +415,54 @@ pub async fn confirm_subscription(
         )
         .await?;
 
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
         profile.clone(),
     )
     .await?;
+    let billing_handler = match &billing_engine {
+        BillingEngine::Connector(billing_handler) => billing_handler,
+        BillingEngine::Native(native_handler) => {
+            let subscription = subscription_entry.subscription.clone();
+            let item_price_id = subscription.item_price_id.clone().ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "item_price_id",
+                },
+            )?;
+            let billing_cycle = native_handler
+                .start_billing_cycle(&state, subscription.plan_id.as_deref(), &item_price_id)
+                .await?;
+
+            let update_request = InvoiceUpdateRequest::update_payment_and_status(
+                payment_response.payment_method_id.clone(),
+                Some(payment_response.payment_id.clone()),
+                connector_enums::InvoiceStatus::InvoiceCreated,
+                None,
+            );
+            let invoice_entry = invoice_handler
+                .update_invoice(&state, invoice.id, update_request)
+                .await?;
+            invoice_handler
+                .create_invoice_sync_job(&state, &invoice_entry, None, None)
+                .await?;
+
+            let status = start_native_billing(
+                &state,
+                &mut subscription_entry,
+                &billing_cycle,
+                &payment_response,
+                subscription.plan_id,
+                Some(item_price_id),
+            )
+            .await?;
+
+            let response =
+                subscription_entry.generate_response(&invoice_entry, &payment_response, status)?;
+            return Ok(ApplicationResponse::Json(response));
+        }
+    };
     let customer = SubscriptionHandler::find_customer(
         &state,
         &platform,
@@ -416,7 +524,7 @@ pub async fn confirm_subscription(
             &state,
             &invoice_entry,
             invoice_details.map(|invoice| invoice.id),
-            billing_handler.connector_name,
+            Some(billing_handler.connector_name),
         )
         .await?;
 
@@ -474,14 +582,14 @@ pub async fn get_estimate(
     let profile = SubscriptionHandler::find_business_profile(&state, &platform, &profile_id)
         .await
         .attach_printable("subscriptions: failed to find business profile in get_estimate")?;
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
         profile,
     )
     .await?;
-    let estimate = billing_handler
+    let estimate = billing_engine
         .get_subscription_estimate(&state, query)
         .await?;
     Ok(ApplicationResponse::Json(estimate.into()))
@@ -501,7 +609,7 @@ pub async fn pause_subscription(
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
@@ -510,8 +618,8 @@ pub async fn pause_subscription(
     .await?;
 
     // Call the billing processor to pause the subscription
-    let pause_response = billing_handler
-        .pause_subscription_on_connector(&state, &subscription_entry.subscription, &request)
+    let pause_response = billing_engine
+        .pause_subscription(&state, &subscription_entry.subscription, &request)
         .await?;
     let status = SubscriptionStatus::from(pause_response.status);
     // Update the subscription status in our database
@@ -551,7 +659,7 @@ pub async fn resume_subscription(
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
@@ -560,8 +668,8 @@ pub async fn resume_subscription(
     .await?;
 
     // Call the billing processor to resume the subscription
-    let resume_response = billing_handler
-        .resume_subscription_on_connector(&state, &subscription_entry.subscription, &request)
+    let resume_response = billing_engine
+        .resume_subscription(&state, &subscription_entry.subscription, &request)
         .await?;
 
     let status = SubscriptionStatus::from(resume_response.status);
@@ -570,6 +678,10 @@ pub async fn resume_subscription(
         .update_subscription(SubscriptionUpdate::update_status(status.to_string()))
         .await?;
 
+    if let BillingEngine::Native(_) = &billing_engine {
+        reschedule_native_renewal(&state, &mut subscription_entry, status).await?;
+    }
+
     let response = subscription_types::ResumeSubscriptionResponse {
         id: subscription_entry.subscription.id.clone(),
         status,
@@ -602,7 +714,7 @@ pub async fn cancel_subscription(
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
-    let billing_handler = BillingHandler::create(
+    let billing_engine = BillingEngine::create(
         &state,
         platform.get_processor().get_account(),
         platform.get_processor().get_key_store(),
@@ -611,8 +723,8 @@ pub async fn cancel_subscription(
     .await?;
 
     // Call the billing processor to cancel the subscription
-    let cancel_response = billing_handler
-        .cancel_subscription_on_connector(&state, &subscription_entry.subscription, &request)
+    let cancel_response = billing_engine
+        .cancel_subscription(&state, &subscription_entry.subscription, &request)
         .await?;
 
     let status = SubscriptionStatus::from(cancel_response.status);
@@ -651,14 +763,94 @@ pub async fn update_subscription(
     let handler = SubscriptionHandler::new(&state, &platform);
     let mut subscription_entry = handler.find_subscription(subscription_id).await?;
 
+    let billing_engine = BillingEngine::create(
+        &state,
+        platform.get_processor().get_account(),
+        platform.get_processor().get_key_store(),
+        profile.clone(),
+    )
+    .await?;
+
     let invoice_handler = subscription_entry.get_invoice_handler(profile.clone());
+    let subscription = subscription_entry.subscription.clone();
+
+    // A natively billed subscription that is already running switches price mid-cycle, so the
+    // difference for the rest of the period is charged right away instead of re-pricing the
+    // pending first invoice.
+    if let (BillingEngine::Native(native_handler), Some(payment_method_id)) = (
+        &billing_engine,
+        subscription
+            .payment_method_id
+            .clone()
+            .filter(|_| subscription.current_period_end.is_some()),
+    ) {
+        let proration = native_handler
+            .calculate_proration(
+                &state,
+                &subscription,
+                Some(&request.plan_id),
+                &request.item_price_id,
+            )
+            .await?;
+
+        subscription_entry
+            .update_subscription(SubscriptionUpdate::new(
+                None,
+                None,
+                None,
+                Some(request.plan_id.clone()),
+                Some(request.item_price_id.clone()),
+            ))
+            .await?;
+
+        if proration.amount_due.is_greater_than(0) {
+            logger::info!(
+                subscription_id = ?subscription.id,
+                credit = ?proration.credit,
+                charge = ?proration.charge,
+                "subscriptions: charging proration for plan change"
+            );
+            let payment_response = invoice_handler
+                .create_mit_payment(
+                    &state,
+                    proration.amount_due,
+                    proration.currency,
+                    &payment_method_id,
+                    None,
+                )
+                .await?;
+            let invoice_entry = invoice_handler
+                .create_invoice_entry(
+                    &state,
+                    None,
+                    Some(payment_response.payment_id.clone()),
+                    proration.amount_due,
+                    proration.currency,
+                    connector_enums::InvoiceStatus::PaymentPending,
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            invoice_handler
+                .create_invoice_sync_job(&state, &invoice_entry, None, None)
+                .await?;
+        }
+
+        return Box::pin(get_subscription(
+            state,
+            platform,
+            profile_id,
+            subscription.id,
+        ))
+        .await;
+    }
+
     let invoice = invoice_handler
         .get_latest_invoice(&state)
         .await
         .attach_printable("subscriptions: failed to get latest invoice")?;
 
-    let subscription = subscription_entry.subscription.clone();
-
     subscription_entry
         .update_subscription(SubscriptionUpdate::new(
             None,
@@ -669,21 +861,13 @@ pub async fn update_subscription(
         ))
         .await?;
 
-    let billing_handler = BillingHandler::create(
-        &state,
-        platform.get_processor().get_account(),
-        platform.get_processor().get_key_store(),
-        profile.clone(),
-    )
-    .await?;
-
     let estimate_request = subscription_types::EstimateSubscriptionQuery {
         plan_id: Some(request.plan_id.clone()),
         item_price_id: request.item_price_id.clone(),
         coupon_code: None,
     };
 
-    let estimate = billing_handler
+    let estimate = billing_engine
         .get_subscription_estimate(&state, estimate_request)
         .await?;
 
@@ -748,3 +932,95 @@ pub async fn list_subscriptions(
 
     Ok(ApplicationResponse::Json(subscriptions_resonse))
 }
+
+/// Starts the first billing period of a natively billed subscription once its first payment has
+/// been attempted, and schedules the renewal at the end of that period. A failed payment leaves
+/// the subscription without a period so that it can be confirmed again.
+async fn start_native_billing(
+    state: &SessionState,
+    subscription_entry: &mut SubscriptionWithHandler<'_>,
+    billing_cycle: &NativeBillingCycle,
+    payment_response: &subscription_types::PaymentResponseData,
+    plan_id: Option<String>,
+    item_price_id: Option<String>,
+) -> errors::SubscriptionResult<subscription_response_types::SubscriptionStatus> {
+    let subscription_update = SubscriptionUpdate::new(
+        None,
+        payment_response.payment_method_id.clone(),
+        None,
+        plan_id,
+        item_price_id,
+    );
+
+    if let InvoiceSyncPaymentStatus::PaymentFailed =
+        InvoiceSyncPaymentStatus::from(payment_response.status)
+    {
+        let status = subscription_response_types::SubscriptionStatus::Failed;
+        subscription_entry
+            .update_subscription(SubscriptionUpdate {
+                status: Some(SubscriptionStatus::from(status).to_string()),
+                ..subscription_update
+            })
+            .await?;
+        return Ok(status);
+    }
+
+    let billing_cycle_update = billing_cycle.to_subscription_update();
+    subscription_entry
+        .update_subscription(SubscriptionUpdate {
+            status: billing_cycle_update.status,
+            current_period_start: billing_cycle_update.current_period_start,
+            current_period_end: billing_cycle_update.current_period_end,
+            trial_end: billing_cycle_update.trial_end,
+            ..subscription_update
+        })
+        .await?;
+
+    subscription_billing::create_subscription_billing_job(
+        state,
+        native_billing::SubscriptionBillingTrackingData::new(
+            &subscription_entry.subscription,
+            billing_cycle.current_period_end,
+        ),
+    )
+    .await
+    .attach_printable("subscriptions: failed to schedule subscription renewal")?;
+
+    Ok(billing_cycle.status)
+}
+
+/// Schedules the renewal of a resumed natively billed subscription. A renewal that fell due while
+/// the subscription was paused has been dropped by its task, so the current period is cut short
+/// and renewed right away; otherwise the pending renewal task is still in place.
+async fn reschedule_native_renewal(
+    state: &SessionState,
+    subscription_entry: &mut SubscriptionWithHandler<'_>,
+    status: SubscriptionStatus,
+) -> errors::SubscriptionResult<()> {
+    let now = common_utils::date_time::now();
+    let subscription = subscription_entry.subscription.clone();
+    let Some(current_period_end) = subscription
+        .current_period_end
+        .filter(|period_end| *period_end <= now)
+    else {
+        return Ok(());
+    };
+
+    subscription_entry
+        .update_subscription(SubscriptionUpdate::update_billing_cycle(
+            status.to_string(),
+            subscription
+                .current_period_start
+                .unwrap_or(current_period_end),
+            now,
+            subscription.trial_end,
+        ))
+        .await?;
+
+    subscription_billing::create_subscription_billing_job(
+        state,
+        native_billing::SubscriptionBillingTrackingData::new(&subscription_entry.subscription, now),
+    )
+    .await
+    .attach_printable("subscriptions: failed to schedule subscription renewal")
+}
@@ -41,12 +41,12 @@ impl InvoiceHandler {
     pub async fn create_invoice_entry(
         &self,
         state: &SessionState,
-        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
         payment_intent_id: Option<common_utils::id_type::PaymentId>,
         amount: MinorUnit,
         currency: common_enums::Currency,
         status: connector_enums::InvoiceStatus,
-        provider_name: connector_enums::Connector,
+        provider_name: Option<connector_enums::Connector>,
         metadata: Option<pii::SecretSerdeValue>,
         connector_invoice_id: Option<common_utils::id_type::InvoiceId>,
     ) -> errors::SubscriptionResult<hyperswitch_domain_models::invoice::Invoice> {
@@ -288,7 +288,7 @@ impl InvoiceHandler {
         state: &SessionState,
         invoice: &hyperswitch_domain_models::invoice::Invoice,
         connector_invoice_id: Option<common_utils::id_type::InvoiceId>,
-        connector_name: connector_enums::Connector,
+        connector_name: Option<connector_enums::Connector>,
     ) -> errors::SubscriptionResult<()> {
         let request = storage_types::invoice_sync::InvoiceSyncRequest::new(
             self.subscription.id.to_owned(),
@@ -312,8 +312,10 @@ impl InvoiceHandler {
         amount: MinorUnit,
         currency: common_enums::Currency,
         payment_method_id: &str,
+        payment_id: Option<common_utils::id_type::PaymentId>,
     ) -> errors::SubscriptionResult<subscription_types::PaymentResponseData> {
         let mit_payment_request = subscription_types::CreateMitPaymentRequestData {
+            payment_id,
             amount,
             currency,
             confirm: true,
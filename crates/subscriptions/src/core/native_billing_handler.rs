@@ -0,0 +1,803 @@
+use std::collections::HashMap;
+
+use api_models::subscription as subscription_types;
+use common_enums::connector_enums;
+use common_utils::{ext_traits::StringExt, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    errors::api_error_response as errors,
+    router_response_types::subscriptions as subscription_response_types,
+    subscription::{Subscription, SubscriptionUpdate},
+    subscription_plan::{SubscriptionPlan, SubscriptionPlanNew, SubscriptionPlanPriceNew},
+};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::billing_processor_handler::BillingHandler,
+    errors::SubscriptionResult,
+    helpers::StorageErrorExt,
+    state::SubscriptionState as SessionState,
+    types::storage::native_billing::{NativeDunningConfig, NativePlanPrice},
+};
+
+/// Name recorded against subscriptions and retry configs handled by the native billing engine
+pub const NATIVE_BILLING_ENGINE: &str = "hyperswitch_billing";
+
+/// Billing backend of a profile: the configured billing processor, or the native billing engine
+/// when the profile has no billing processor.
+pub enum BillingEngine {
+    Connector(Box<BillingHandler>),
+    Native(NativeBillingHandler),
+}
+
+impl BillingEngine {
+    pub async fn create(
+        state: &SessionState,
+        merchant_account: &hyperswitch_domain_models::merchant_account::MerchantAccount,
+        key_store: &hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
+        profile: hyperswitch_domain_models::business_profile::Profile,
+    ) -> SubscriptionResult<Self> {
+        match profile.billing_processor_id {
+            Some(_) => Ok(Self::Connector(Box::new(
+                BillingHandler::create(state, merchant_account, key_store, profile).await?,
+            ))),
+            None => Ok(Self::Native(
+                NativeBillingHandler::create(state, merchant_account.get_id(), profile.get_id())
+                    .await?,
+            )),
+        }
+    }
+
+    pub fn connector_name(&self) -> Option<connector_enums::Connector> {
+        match self {
+            Self::Connector(handler) => Some(handler.connector_name),
+            Self::Native(_) => None,
+        }
+    }
+
+    pub fn merchant_connector_id(
+        &self,
+    ) -> Option<common_utils::id_type::MerchantConnectorAccountId> {
+        match self {
+            Self::Connector(handler) => Some(handler.merchant_connector_id.clone()),
+            Self::Native(_) => None,
+        }
+    }
+
+    pub async fn get_subscription_plans(
+        &self,
+        state: &SessionState,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> SubscriptionResult<Vec<subscription_types::GetPlansResponse>> {
+        match self {
+            Self::Connector(handler) => {
+                let get_plans_response =
+                    handler.get_subscription_plans(state, limit, offset).await?;
+
+                let mut response = Vec::new();
+                for plan in &get_plans_response.list {
+                    let plan_price_response = handler
+                        .get_subscription_plan_prices(
+                            state,
+                            plan.subscription_provider_plan_id.clone(),
+                        )
+                        .await?;
+
+                    response.push(subscription_types::GetPlansResponse {
+                        plan_id: plan.subscription_provider_plan_id.clone(),
+                        name: plan.name.clone(),
+                        description: plan.description.clone(),
+                        price_id: plan_price_response
+                            .list
+                            .into_iter()
+                            .map(subscription_types::SubscriptionPlanPrices::from)
+                            .collect::<Vec<_>>(),
+                    });
+                }
+                Ok(response)
+            }
+            Self::Native(handler) => handler.get_subscription_plans(state, limit, offset).await,
+        }
+    }
+
+    pub async fn create_subscription_plan(
+        &self,
+        state: &SessionState,
+        request: subscription_types::CreateSubscriptionPlanRequest,
+    ) -> SubscriptionResult<subscription_types::GetPlansResponse> {
+        match self {
+            Self::Connector(_) => Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "plans of profiles with a billing processor are managed on the billing processor"
+                    .to_string(),
+            }
+            .into()),
+            Self::Native(handler) => handler.create_subscription_plan(state, request).await,
+        }
+    }
+
+    pub async fn get_subscription_estimate(
+        &self,
+        state: &SessionState,
+        estimate_request: subscription_types::EstimateSubscriptionQuery,
+    ) -> SubscriptionResult<subscription_response_types::GetSubscriptionEstimateResponse> {
+        match self {
+            Self::Connector(handler) => {
+                handler
+                    .get_subscription_estimate(state, estimate_request)
+                    .await
+            }
+            Self::Native(handler) => {
+                handler
+                    .get_subscription_estimate(state, &estimate_request)
+                    .await
+            }
+        }
+    }
+
+    pub async fn pause_subscription(
+        &self,
+        state: &SessionState,
+        subscription: &Subscription,
+        request: &subscription_types::PauseSubscriptionRequest,
+    ) -> SubscriptionResult<subscription_response_types::SubscriptionPauseResponse> {
+        match self {
+            Self::Connector(handler) => {
+                handler
+                    .pause_subscription_on_connector(state, subscription, request)
+                    .await
+            }
+            Self::Native(handler) => handler.pause_subscription(subscription, request),
+        }
+    }
+
+    pub async fn resume_subscription(
+        &self,
+        state: &SessionState,
+        subscription: &Subscription,
+        request: &subscription_types::ResumeSubscriptionRequest,
+    ) -> SubscriptionResult<subscription_response_types::SubscriptionResumeResponse> {
+        match self {
+            Self::Connector(handler) => {
+                handler
+                    .resume_subscription_on_connector(state, subscription, request)
+                    .await
+            }
+            Self::Native(handler) => handler.resume_subscription(subscription, request),
+        }
+    }
+
+    pub async fn cancel_subscription(
+        &self,
+        state: &SessionState,
+        subscription: &Subscription,
+        request: &subscription_types::CancelSubscriptionRequest,
+    ) -> SubscriptionResult<subscription_response_types::SubscriptionCancelResponse> {
+        match self {
+            Self::Connector(handler) => {
+                handler
+                    .cancel_subscription_on_connector(state, subscription, request)
+                    .await
+            }
+            Self::Native(handler) => handler.cancel_subscription(subscription, request),
+        }
+    }
+}
+
+/// Billing period computed by the native billing engine, along with the amount to be collected
+/// when it starts.
+#[derive(Debug, Clone)]
+pub struct NativeBillingCycle {
+    pub status: subscription_response_types::SubscriptionStatus,
+    pub current_period_start: PrimitiveDateTime,
+    pub current_period_end: PrimitiveDateTime,
+    pub trial_end: Option<PrimitiveDateTime>,
+    pub amount_due: MinorUnit,
+    pub currency: common_enums::Currency,
+}
+
+impl NativeBillingCycle {
+    pub fn to_subscription_update(&self) -> SubscriptionUpdate {
+        SubscriptionUpdate::update_billing_cycle(
+            common_enums::SubscriptionStatus::from(self.status).to_string(),
+            self.current_period_start,
+            self.current_period_end,
+            self.trial_end,
+        )
+    }
+}
+
+/// Amounts owed for the rest of the current period when switching prices mid-cycle.
+#[derive(Debug, Clone)]
+pub struct NativeProration {
+    pub credit: MinorUnit,
+    pub charge: MinorUnit,
+    pub amount_due: MinorUnit,
+    pub currency: common_enums::Currency,
+}
+
+pub struct NativeBillingHandler {
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub dunning: NativeDunningConfig,
+}
+
+impl NativeBillingHandler {
+    pub async fn create(
+        state: &SessionState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+    ) -> SubscriptionResult<Self> {
+        let dunning = match state
+            .store
+            .find_config_by_key(&profile_id.get_native_billing_dunning_config_key())
+            .await
+        {
+            Ok(config) => config
+                .config
+                .parse_struct::<NativeDunningConfig>("NativeDunningConfig")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("subscriptions: unable to parse native dunning config")?,
+            Err(error) if error.current_context().is_db_not_found() => {
+                NativeDunningConfig::default()
+            }
+            Err(error) => Err(error.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("subscriptions: unable to fetch native dunning config")?,
+        };
+
+        Ok(Self {
+            merchant_id: merchant_id.to_owned(),
+            profile_id: profile_id.to_owned(),
+            dunning,
+        })
+    }
+
+    /// Looks a price of the catalog up by its id, restricted to the given plan when one is
+    /// provided.
+    pub async fn get_price(
+        &self,
+        state: &SessionState,
+        plan_id: Option<&str>,
+        price_id: &str,
+    ) -> SubscriptionResult<NativePlanPrice> {
+        let price = state
+            .store
+            .find_subscription_plan_price_by_profile_id_price_id(&self.profile_id, price_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("subscriptions: unable to fetch subscription plan price")?
+            .filter(|price| plan_id.is_none_or(|plan_id| price.plan_id == plan_id))
+            .ok_or_else(|| {
+                error_stack::report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("item_price_id {price_id} not found in the billing catalog"),
+                })
+            })?;
+
+        NativePlanPrice::try_from(price)
+    }
+
+    pub async fn get_subscription_price(
+        &self,
+        state: &SessionState,
+        subscription: &Subscription,
+    ) -> SubscriptionResult<NativePlanPrice> {
+        let price_id = subscription.item_price_id.as_deref().ok_or(
+            errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "item_price_id",
+            },
+        )?;
+
+        self.get_price(state, subscription.plan_id.as_deref(), price_id)
+            .await
+    }
+
+    pub async fn get_subscription_plans(
+        &self,
+        state: &SessionState,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> SubscriptionResult<Vec<subscription_types::GetPlansResponse>> {
+        let plans = state
+            .store
+            .list_subscription_plans_by_merchant_id_profile_id(
+                &self.merchant_id,
+                &self.profile_id,
+                limit.map(i64::from),
+                offset.map(i64::from),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("subscriptions: unable to list subscription plans")?;
+
+        let mut prices_by_plan = state
+            .store
+            .list_subscription_plan_prices_by_profile_id_plan_ids(
+                &self.profile_id,
+                plans.iter().map(|plan| plan.id.clone()).collect(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("subscriptions: unable to list subscription plan prices")?
+            .into_iter()
+            .map(NativePlanPrice::try_from)
+            .try_fold(
+                HashMap::<String, Vec<subscription_types::SubscriptionPlanPrices>>::new(),
+                |mut prices_by_plan, price| {
+                    let price = price?;
+                    prices_by_plan
+                        .entry(price.plan_id.clone())
+                        .or_default()
+                        .push(price.into());
+                    SubscriptionResult::Ok(prices_by_plan)
+                },
+            )?;
+
+        Ok(plans
+            .into_iter()
+            .map(|plan| subscription_types::GetPlansResponse {
+                price_id: prices_by_plan.remove(&plan.id).unwrap_or_default(),
+                plan_id: plan.id,
+                name: plan.name,
+                description: plan.description,
+            })
+            .collect())
+    }
+
+    /// Adds a plan along with its prices to the catalog of the profile.
+    pub async fn create_subscription_plan(
+        &self,
+        state: &SessionState,
+        request: subscription_types::CreateSubscriptionPlanRequest,
+    ) -> SubscriptionResult<subscription_types::GetPlansResponse> {
+        let now = common_utils::date_time::now();
+        let plan = state
+            .store
+            .insert_subscription_plan(SubscriptionPlanNew {
+                id: common_utils::generate_id(common_utils::consts::ID_LENGTH, "plan"),
+                merchant_id: self.merchant_id.clone(),
+                profile_id: self.profile_id.clone(),
+                name: request.name,
+                description: request.description,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("subscriptions: unable to insert subscription plan")?;
+
+        let mut prices = Vec::with_capacity(request.prices.len());
+        for price in request.prices {
+            let price = state
+                .store
+                .insert_subscription_plan_price(SubscriptionPlanPriceNew {
+                    id: common_utils::generate_id(common_utils::consts::ID_LENGTH, "price"),
+                    plan_id: plan.id.clone(),
+                    merchant_id: self.merchant_id.clone(),
+                    profile_id: self.profile_id.clone(),
+                    amount: price.amount,
+                    currency: price.currency,
+                    interval: price.interval.to_string(),
+                    interval_count: price.interval_count,
+                    trial_period: price.trial_period,
+                    trial_period_unit: price
+                        .trial_period_unit
+                        .map(|trial_period_unit| trial_period_unit.to_string()),
+                    created_at: now,
+                    modified_at: now,
+                })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("subscriptions: unable to insert subscription plan price")?;
+            prices.push(NativePlanPrice::try_from(price)?.into());
+        }
+
+        Ok(subscription_types::GetPlansResponse {
+            plan_id: plan.id,
+            name: plan.name,
+            description: plan.description,
+            price_id: prices,
+        })
+    }
+
+    async fn get_plan(
+        &self,
+        state: &SessionState,
+        plan_id: &str,
+    ) -> SubscriptionResult<SubscriptionPlan> {
+        state
+            .store
+            .find_subscription_plan_by_profile_id_plan_id(&self.profile_id, plan_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("plan_id {plan_id} not found in the billing catalog"),
+            })
+    }
+
+    pub async fn get_subscription_estimate(
+        &self,
+        state: &SessionState,
+        estimate_request: &subscription_types::EstimateSubscriptionQuery,
+    ) -> SubscriptionResult<subscription_response_types::GetSubscriptionEstimateResponse> {
+        let price = self
+            .get_price(
+                state,
+                estimate_request.plan_id.as_deref(),
+                &estimate_request.item_price_id,
+            )
+            .await?;
+        let plan = self.get_plan(state, &price.plan_id).await?;
+        let billing_cycle = Self::get_first_billing_cycle(&price, common_utils::date_time::now())?;
+
+        Ok(
+            subscription_response_types::GetSubscriptionEstimateResponse {
+                sub_total: billing_cycle.amount_due,
+                total: billing_cycle.amount_due,
+                credits_applied: None,
+                amount_paid: None,
+                amount_due: Some(billing_cycle.amount_due),
+                currency: billing_cycle.currency,
+                next_billing_at: Some(billing_cycle.current_period_end),
+                line_items: vec![subscription_response_types::SubscriptionLineItem {
+                    item_id: price.price_id.clone(),
+                    item_type: "plan_item_price".to_string(),
+                    description: plan.name,
+                    amount: billing_cycle.amount_due,
+                    currency: billing_cycle.currency,
+                    unit_amount: Some(price.amount),
+                    quantity: 1,
+                    pricing_model: Some("flat_fee".to_string()),
+                }],
+                customer_id: None,
+            },
+        )
+    }
+
+    /// Starts the first billing period of a subscription to the given price.
+    pub async fn start_billing_cycle(
+        &self,
+        state: &SessionState,
+        plan_id: Option<&str>,
+        price_id: &str,
+    ) -> SubscriptionResult<NativeBillingCycle> {
+        let price = self.get_price(state, plan_id, price_id).await?;
+        Self::get_first_billing_cycle(&price, common_utils::date_time::now())
+    }
+
+    /// Computes the first billing period of `price` starting at `now`. Prices with a trial start
+    /// with a free trial period, the rest are charged the full price upfront.
+    pub fn get_first_billing_cycle(
+        price: &NativePlanPrice,
+        now: PrimitiveDateTime,
+    ) -> SubscriptionResult<NativeBillingCycle> {
+        match price.get_trial_end(now) {
+            Some(trial_end) => Ok(NativeBillingCycle {
+                status: subscription_response_types::SubscriptionStatus::Trial,
+                current_period_start: now,
+                current_period_end: trial_end,
+                trial_end: Some(trial_end),
+                amount_due: MinorUnit::new(0),
+                currency: price.currency,
+            }),
+            None => Self::get_billing_cycle(price, now),
+        }
+    }
+
+    /// Computes the paid billing period of `price` starting at `period_start`.
+    pub fn get_billing_cycle(
+        price: &NativePlanPrice,
+        period_start: PrimitiveDateTime,
+    ) -> SubscriptionResult<NativeBillingCycle> {
+        let current_period_end = price.get_period_end(period_start).ok_or(
+            errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "interval_count",
+            },
+        )?;
+
+        Ok(NativeBillingCycle {
+            status: subscription_response_types::SubscriptionStatus::Active,
+            current_period_start: period_start,
+            current_period_end,
+            trial_end: None,
+            amount_due: price.amount,
+            currency: price.currency,
+        })
+    }
+
+    /// Prorates switching the subscription to a new price over the unused part of its current
+    /// billing period.
+    pub async fn calculate_proration(
+        &self,
+        state: &SessionState,
+        subscription: &Subscription,
+        new_plan_id: Option<&str>,
+        new_price_id: &str,
+    ) -> SubscriptionResult<NativeProration> {
+        let old_price = self.get_subscription_price(state, subscription).await?;
+        let new_price = self.get_price(state, new_plan_id, new_price_id).await?;
+
+        prorate_price_change(
+            &old_price,
+            &new_price,
+            subscription.current_period_start,
+            subscription.current_period_end,
+            subscription.trial_end,
+            common_utils::date_time::now(),
+        )
+    }
+
+    pub fn pause_subscription(
+        &self,
+        subscription: &Subscription,
+        request: &subscription_types::PauseSubscriptionRequest,
+    ) -> SubscriptionResult<subscription_response_types::SubscriptionPauseResponse> {
+        if request.pause_at.is_some()
+            || !matches!(
+                request.pause_option,
+                None | Some(subscription_types::PauseOption::Immediately)
+            )
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "native billing only supports pausing immediately".to_string(),
+            }
+            .into());
+        }
+
+        Ok(subscription_response_types::SubscriptionPauseResponse {
+            subscription_id: subscription.id.clone(),
+            status: subscription_response_types::SubscriptionStatus::Paused,
+            paused_at: Some(common_utils::date_time::now()),
+        })
+    }
+
+    pub fn resume_subscription(
+        &self,
+        subscription: &Subscription,
+        request: &subscription_types::ResumeSubscriptionRequest,
+    ) -> SubscriptionResult<subscription_response_types::SubscriptionResumeResponse> {
+        if request.resume_date.is_some()
+            || !matches!(
+                request.resume_option,
+                None | Some(subscription_types::ResumeOption::Immediately)
+            )
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "native billing only supports resuming immediately".to_string(),
+            }
+            .into());
+        }
+
+        let now = common_utils::date_time::now();
+        let in_trial = subscription
+            .trial_end
+            .is_some_and(|trial_end| trial_end > now);
+
+        Ok(subscription_response_types::SubscriptionResumeResponse {
+            subscription_id: subscription.id.clone(),
+            status: if in_trial {
+                subscription_response_types::SubscriptionStatus::Trial
+            } else {
+                subscription_response_types::SubscriptionStatus::Active
+            },
+            next_billing_at: Some(
+                subscription
+                    .current_period_end
+                    .map_or(now, |period_end| period_end.max(now)),
+            ),
+        })
+    }
+
+    pub fn cancel_subscription(
+        &self,
+        subscription: &Subscription,
+        request: &subscription_types::CancelSubscriptionRequest,
+    ) -> SubscriptionResult<subscription_response_types::SubscriptionCancelResponse> {
+        if request.cancel_at.is_some()
+            || !matches!(
+                request.cancel_option,
+                None | Some(subscription_types::CancelOption::Immediately)
+            )
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "native billing only supports cancelling immediately".to_string(),
+            }
+            .into());
+        }
+
+        Ok(subscription_response_types::SubscriptionCancelResponse {
+            subscription_id: subscription.id.clone(),
+            status: subscription_response_types::SubscriptionStatus::Cancelled,
+            cancelled_at: Some(common_utils::date_time::now()),
+        })
+    }
+}
+
+/// Prorates a price change at `now` over the unused part of the current billing period: the
+/// unused portion of the old price is credited and the same portion of the new price is charged.
+/// Changes during a trial, or outside of a billing period, are not prorated.
+pub fn prorate_price_change(
+    old_price: &NativePlanPrice,
+    new_price: &NativePlanPrice,
+    current_period_start: Option<PrimitiveDateTime>,
+    current_period_end: Option<PrimitiveDateTime>,
+    trial_end: Option<PrimitiveDateTime>,
+    now: PrimitiveDateTime,
+) -> SubscriptionResult<NativeProration> {
+    if old_price.currency != new_price.currency {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "plan change across currencies is not supported".to_string(),
+        }
+        .into());
+    }
+
+    let zero_proration = NativeProration {
+        credit: MinorUnit::new(0),
+        charge: MinorUnit::new(0),
+        amount_due: MinorUnit::new(0),
+        currency: new_price.currency,
+    };
+    let in_trial = trial_end.is_some_and(|trial_end| trial_end > now);
+
+    let (period_start, period_end) = match (current_period_start, current_period_end) {
+        (Some(start), Some(end)) if !in_trial && end > now && end > start => (start, end),
+        _ => return Ok(zero_proration),
+    };
+
+    let period_seconds = (period_end - period_start).whole_seconds();
+    let remaining_seconds = (period_end - now).whole_seconds().min(period_seconds);
+    let prorate = |amount: MinorUnit| {
+        let prorated = i128::from(amount.get_amount_as_i64())
+            .saturating_mul(i128::from(remaining_seconds))
+            / i128::from(period_seconds);
+        MinorUnit::new(i64::try_from(prorated).unwrap_or_default())
+    };
+
+    let credit = prorate(old_price.amount);
+    let charge = prorate(new_price.amount);
+    let amount_due = MinorUnit::new(
+        charge
+            .get_amount_as_i64()
+            .saturating_sub(credit.get_amount_as_i64())
+            .max(0),
+    );
+
+    Ok(NativeProration {
+        credit,
+        charge,
+        amount_due,
+        currency: new_price.currency,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::subscription::PeriodUnit;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn price(amount: i64, currency: common_enums::Currency) -> NativePlanPrice {
+        NativePlanPrice {
+            price_id: "price_1".to_string(),
+            plan_id: "plan_1".to_string(),
+            amount: MinorUnit::new(amount),
+            currency,
+            interval: PeriodUnit::Month,
+            interval_count: 1,
+            trial_period: None,
+            trial_period_unit: None,
+        }
+    }
+
+    #[test]
+    fn test_proration_of_upgrade_halfway_through_the_period() {
+        let proration = prorate_price_change(
+            &price(1000, common_enums::Currency::USD),
+            &price(3000, common_enums::Currency::USD),
+            Some(datetime!(2025-04-01 00:00)),
+            Some(datetime!(2025-05-01 00:00)),
+            None,
+            datetime!(2025-04-16 00:00),
+        )
+        .unwrap();
+
+        assert_eq!(proration.credit, MinorUnit::new(500));
+        assert_eq!(proration.charge, MinorUnit::new(1500));
+        assert_eq!(proration.amount_due, MinorUnit::new(1000));
+    }
+
+    #[test]
+    fn test_proration_of_downgrade_is_not_refunded() {
+        let proration = prorate_price_change(
+            &price(3000, common_enums::Currency::USD),
+            &price(1000, common_enums::Currency::USD),
+            Some(datetime!(2025-04-01 00:00)),
+            Some(datetime!(2025-05-01 00:00)),
+            None,
+            datetime!(2025-04-16 00:00),
+        )
+        .unwrap();
+
+        assert_eq!(proration.credit, MinorUnit::new(1500));
+        assert_eq!(proration.charge, MinorUnit::new(500));
+        assert_eq!(proration.amount_due, MinorUnit::new(0));
+    }
+
+    #[test]
+    fn test_no_proration_during_trial_or_outside_of_a_period() {
+        let old_price = price(1000, common_enums::Currency::USD);
+        let new_price = price(3000, common_enums::Currency::USD);
+        let period_start = Some(datetime!(2025-04-01 00:00));
+        let period_end = Some(datetime!(2025-05-01 00:00));
+
+        let in_trial = prorate_price_change(
+            &old_price,
+            &new_price,
+            period_start,
+            period_end,
+            Some(datetime!(2025-04-20 00:00)),
+            datetime!(2025-04-16 00:00),
+        )
+        .unwrap();
+        let after_period = prorate_price_change(
+            &old_price,
+            &new_price,
+            period_start,
+            period_end,
+            None,
+            datetime!(2025-05-02 00:00),
+        )
+        .unwrap();
+        let without_period = prorate_price_change(
+            &old_price,
+            &new_price,
+            None,
+            None,
+            None,
+            datetime!(2025-04-16 00:00),
+        )
+        .unwrap();
+
+        assert_eq!(in_trial.amount_due, MinorUnit::new(0));
+        assert_eq!(after_period.amount_due, MinorUnit::new(0));
+        assert_eq!(without_period.amount_due, MinorUnit::new(0));
+    }
+
+    #[test]
+    fn test_proration_across_currencies_is_rejected() {
+        let proration = prorate_price_change(
+            &price(1000, common_enums::Currency::USD),
+            &price(1000, common_enums::Currency::EUR),
+            Some(datetime!(2025-04-01 00:00)),
+            Some(datetime!(2025-05-01 00:00)),
+            None,
+            datetime!(2025-04-16 00:00),
+        );
+
+        assert!(proration.is_err());
+    }
+
+    #[test]
+    fn test_first_billing_cycle_starts_with_trial() {
+        let now = datetime!(2025-01-31 00:00);
+        let paid_cycle = NativeBillingHandler::get_first_billing_cycle(
+            &price(1000, common_enums::Currency::USD),
+            now,
+        )
+        .unwrap();
+        let trial_cycle = NativeBillingHandler::get_first_billing_cycle(
+            &NativePlanPrice {
+                trial_period: Some(2),
+                trial_period_unit: Some(PeriodUnit::Week),
+                ..price(1000, common_enums::Currency::USD)
+            },
+            now,
+        )
+        .unwrap();
+
+        assert_eq!(paid_cycle.amount_due, MinorUnit::new(1000));
+        assert_eq!(paid_cycle.current_period_end, datetime!(2025-02-28 00:00));
+        assert_eq!(trial_cycle.amount_due, MinorUnit::new(0));
+        assert_eq!(trial_cycle.trial_end, Some(datetime!(2025-02-14 00:00)));
+    }
+}
@@ -2,7 +2,7 @@ use common_utils::types::keymanager;
 use hyperswitch_domain_models::{
     business_profile, configs as domain_configs, customer, invoice as invoice_domain, master_key,
     merchant_account, merchant_connector_account, merchant_key_store,
-    subscription as subscription_domain,
+    subscription as subscription_domain, subscription_plan,
 };
 use hyperswitch_interfaces::configs;
 use router_env::RequestId;
@@ -18,6 +18,7 @@ pub trait SubscriptionStorageInterface:
     + scheduler::SchedulerInterface
     + subscription_domain::SubscriptionInterface<Error = errors::StorageError>
     + invoice_domain::InvoiceInterface<Error = errors::StorageError>
+    + subscription_plan::SubscriptionPlanInterface<Error = errors::StorageError>
     + business_profile::ProfileInterface<Error = errors::StorageError>
     + domain_configs::ConfigInterface<Error = errors::StorageError>
     + customer::CustomerInterface<Error = errors::StorageError>
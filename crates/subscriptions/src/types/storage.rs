@@ -1 +1,2 @@
 pub mod invoice_sync;
+pub mod native_billing;
@@ -0,0 +1,308 @@
+use std::str::FromStr;
+
+use api_models::{
+    enums as api_enums,
+    subscription::{self as subscription_types, PeriodUnit},
+};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::errors::api_error_response as errors;
+use time::PrimitiveDateTime;
+
+/// Price of the native billing catalog of a profile, as stored in the `subscription_plan_price`
+/// table.
+#[derive(Debug, Clone)]
+pub struct NativePlanPrice {
+    pub price_id: String,
+    pub plan_id: String,
+    pub amount: MinorUnit,
+    pub currency: api_enums::Currency,
+    pub interval: PeriodUnit,
+    pub interval_count: i64,
+    pub trial_period: Option<i64>,
+    pub trial_period_unit: Option<PeriodUnit>,
+}
+
+impl TryFrom<hyperswitch_domain_models::subscription_plan::SubscriptionPlanPrice>
+    for NativePlanPrice
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(
+        price: hyperswitch_domain_models::subscription_plan::SubscriptionPlanPrice,
+    ) -> Result<Self, Self::Error> {
+        let parse_period_unit = |period_unit: &str| {
+            PeriodUnit::from_str(period_unit)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("invalid period unit {period_unit} for price {}", price.id)
+                })
+        };
+
+        Ok(Self {
+            interval: parse_period_unit(&price.interval)?,
+            trial_period_unit: price
+                .trial_period_unit
+                .as_deref()
+                .map(parse_period_unit)
+                .transpose()?,
+            price_id: price.id,
+            plan_id: price.plan_id,
+            amount: price.amount,
+            currency: price.currency,
+            interval_count: price.interval_count,
+            trial_period: price.trial_period,
+        })
+    }
+}
+
+impl From<NativePlanPrice> for subscription_types::SubscriptionPlanPrices {
+    fn from(price: NativePlanPrice) -> Self {
+        Self {
+            price_id: price.price_id,
+            plan_id: Some(price.plan_id),
+            amount: price.amount,
+            currency: price.currency,
+            interval: price.interval,
+            interval_count: price.interval_count,
+            trial_period: price.trial_period,
+            trial_period_unit: price.trial_period_unit,
+        }
+    }
+}
+
+/// Delays between recurring charge retries after a renewal payment fails. Once all of them are
+/// used up the subscription is cancelled. Stored in the configs table under
+/// `ProfileId::get_native_billing_dunning_config_key`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NativeDunningConfig {
+    pub retry_intervals_in_hours: Vec<u32>,
+}
+
+impl Default for NativeDunningConfig {
+    fn default() -> Self {
+        Self {
+            retry_intervals_in_hours: vec![24, 72, 168],
+        }
+    }
+}
+
+/// Next step of dunning after a renewal payment failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DunningAction {
+    Retry { after: time::Duration },
+    Cancel,
+}
+
+impl NativeDunningConfig {
+    /// Decides how to follow up on a failed renewal payment, given the number of attempts that
+    /// had already failed before it.
+    pub fn get_dunning_action(&self, failed_attempts: u32) -> DunningAction {
+        usize::try_from(failed_attempts)
+            .ok()
+            .and_then(|failed_attempts| self.retry_intervals_in_hours.get(failed_attempts))
+            .map_or(DunningAction::Cancel, |retry_interval| {
+                DunningAction::Retry {
+                    after: time::Duration::hours(i64::from(*retry_interval)),
+                }
+            })
+    }
+}
+
+impl NativePlanPrice {
+    pub fn get_period_end(&self, period_start: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        add_period(period_start, self.interval, self.interval_count)
+    }
+
+    pub fn get_trial_end(&self, start: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        self.trial_period
+            .filter(|trial_period| *trial_period > 0)
+            .and_then(|trial_period| {
+                add_period(
+                    start,
+                    self.trial_period_unit.unwrap_or(PeriodUnit::Day),
+                    trial_period,
+                )
+            })
+    }
+}
+
+/// Adds `count` periods to `start`. Month and year periods keep the day of month, clamped to the
+/// length of the target month.
+pub fn add_period(
+    start: PrimitiveDateTime,
+    unit: PeriodUnit,
+    count: i64,
+) -> Option<PrimitiveDateTime> {
+    match unit {
+        PeriodUnit::Day => start.checked_add(time::Duration::days(count)),
+        PeriodUnit::Week => start.checked_add(time::Duration::weeks(count)),
+        PeriodUnit::Month => add_months(start, count),
+        PeriodUnit::Year => add_months(start, count.checked_mul(12)?),
+    }
+}
+
+fn add_months(start: PrimitiveDateTime, months: i64) -> Option<PrimitiveDateTime> {
+    let date = start.date();
+    let month_index = i64::from(date.year())
+        .checked_mul(12)?
+        .checked_add(i64::from(u8::from(date.month())) - 1)?
+        .checked_add(months)?;
+    let year = i32::try_from(month_index.div_euclid(12)).ok()?;
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| PrimitiveDateTime::new(date, start.time()))
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SubscriptionBillingTrackingData {
+    pub subscription_id: id_type::SubscriptionId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: id_type::CustomerId,
+    /// End of the billing period this task renews; a mismatch with the subscription means the
+    /// task has been superseded by a reschedule.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    /// Renewal invoice of this cycle, set once the invoice has been generated.
+    pub invoice_id: Option<id_type::InvoiceId>,
+    #[serde(default)]
+    pub failed_attempts: u32,
+}
+
+impl SubscriptionBillingTrackingData {
+    pub fn new(
+        subscription: &hyperswitch_domain_models::subscription::Subscription,
+        period_end: PrimitiveDateTime,
+    ) -> Self {
+        Self {
+            subscription_id: subscription.id.to_owned(),
+            merchant_id: subscription.merchant_id.to_owned(),
+            profile_id: subscription.profile_id.to_owned(),
+            customer_id: subscription.customer_id.to_owned(),
+            period_end,
+            invoice_id: None,
+            failed_attempts: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::id_type::GenerateId;
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_add_months_keeps_day_of_month() {
+        assert_eq!(
+            add_months(datetime!(2025-01-15 10:30), 1),
+            Some(datetime!(2025-02-15 10:30))
+        );
+        assert_eq!(
+            add_months(datetime!(2025-11-15 00:00), 3),
+            Some(datetime!(2026-02-15 00:00))
+        );
+    }
+
+    #[test]
+    fn test_add_months_clamps_to_end_of_month() {
+        assert_eq!(
+            add_months(datetime!(2025-01-31 00:00), 1),
+            Some(datetime!(2025-02-28 00:00))
+        );
+        assert_eq!(
+            add_months(datetime!(2024-01-31 00:00), 1),
+            Some(datetime!(2024-02-29 00:00))
+        );
+        assert_eq!(
+            add_months(datetime!(2025-03-31 00:00), -1),
+            Some(datetime!(2025-02-28 00:00))
+        );
+    }
+
+    #[test]
+    fn test_add_period() {
+        let start = datetime!(2024-02-29 12:00);
+
+        assert_eq!(
+            add_period(start, PeriodUnit::Day, 1),
+            Some(datetime!(2024-03-01 12:00))
+        );
+        assert_eq!(
+            add_period(start, PeriodUnit::Week, 2),
+            Some(datetime!(2024-03-14 12:00))
+        );
+        assert_eq!(
+            add_period(start, PeriodUnit::Year, 1),
+            Some(datetime!(2025-02-28 12:00))
+        );
+        assert_eq!(add_period(start, PeriodUnit::Month, i64::MAX), None);
+    }
+
+    #[test]
+    fn test_dunning_retries_until_intervals_are_used_up() {
+        let dunning = NativeDunningConfig::default();
+
+        assert_eq!(
+            dunning.get_dunning_action(0),
+            DunningAction::Retry {
+                after: time::Duration::hours(24)
+            }
+        );
+        assert_eq!(
+            dunning.get_dunning_action(2),
+            DunningAction::Retry {
+                after: time::Duration::hours(168)
+            }
+        );
+        assert_eq!(dunning.get_dunning_action(3), DunningAction::Cancel);
+    }
+
+    #[test]
+    fn test_dunning_without_retries_cancels_right_away() {
+        let dunning = NativeDunningConfig {
+            retry_intervals_in_hours: Vec::new(),
+        };
+
+        assert_eq!(dunning.get_dunning_action(0), DunningAction::Cancel);
+    }
+
+    #[test]
+    fn test_price_from_storage() {
+        let now = datetime!(2025-01-01 00:00);
+        let price = hyperswitch_domain_models::subscription_plan::SubscriptionPlanPrice {
+            id: "price_1".to_string(),
+            plan_id: "plan_1".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::generate(),
+            amount: MinorUnit::new(1000),
+            currency: api_enums::Currency::USD,
+            interval: "Month".to_string(),
+            interval_count: 1,
+            trial_period: Some(7),
+            trial_period_unit: Some("Day".to_string()),
+            created_at: now,
+            modified_at: now,
+        };
+
+        let native_price = NativePlanPrice::try_from(price.clone()).unwrap();
+        assert_eq!(native_price.interval, PeriodUnit::Month);
+        assert_eq!(native_price.trial_period_unit, Some(PeriodUnit::Day));
+        assert_eq!(
+            native_price.get_trial_end(now),
+            Some(datetime!(2025-01-08 00:00))
+        );
+
+        let invalid_price = hyperswitch_domain_models::subscription_plan::SubscriptionPlanPrice {
+            interval: "Fortnight".to_string(),
+            ..price
+        };
+        assert!(NativePlanPrice::try_from(invalid_price).is_err());
+    }
+}
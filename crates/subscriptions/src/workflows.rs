@@ -3,6 +3,7 @@
 //! This module contains workflow definitions for subscription-related operations
 
 pub mod invoice_sync;
+pub mod subscription_billing;
 
 // Re-export workflow types for easier access
 pub use invoice_sync::*;
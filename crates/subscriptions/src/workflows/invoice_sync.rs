@@ -17,7 +17,7 @@ use storage_impl::StorageError;
 use crate::{
     core::{
         billing_processor_handler as billing, errors as router_errors, invoice_handler,
-        payments_api_client,
+        native_billing_handler::NATIVE_BILLING_ENGINE, payments_api_client,
     },
     helpers::ForeignTryFrom,
     state::{SubscriptionState as SessionState, SubscriptionStorageInterface as StorageInterface},
@@ -379,7 +379,11 @@ pub async fn perform_subscription_invoice_sync(
             logger::error!(?e, "Error in transitioning workflow state");
             retry_subscription_invoice_sync_task(
                 &*handler.state.store,
-                handler.tracking_data.connector_name.to_string().clone(),
+                handler
+                    .tracking_data
+                    .connector_name
+                    .map(|connector| connector.to_string())
+                    .unwrap_or_else(|| NATIVE_BILLING_ENGINE.to_string()),
                 handler.merchant_account.get_id().to_owned(),
                 process,
             )
@@ -0,0 +1,497 @@
+use std::str::FromStr;
+
+use api_models::subscription as subscription_types;
+use common_enums::connector_enums::InvoiceStatus;
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::invoice::InvoiceUpdateRequest;
+use router_env::logger;
+use scheduler::{
+    errors,
+    workflows::storage::{
+        business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate,
+    },
+};
+
+use crate::{
+    core::{
+        errors as router_errors, invoice_handler::InvoiceHandler,
+        native_billing_handler::NativeBillingHandler,
+    },
+    state::SubscriptionState as SessionState,
+    types::storage::{
+        self,
+        native_billing::{DunningAction, SubscriptionBillingTrackingData},
+    },
+};
+
+const SUBSCRIPTION_BILLING_WORKFLOW: &str = "SUBSCRIPTION_BILLING";
+const SUBSCRIPTION_BILLING_WORKFLOW_TAG: &str = "SUBSCRIPTION";
+/// Delay before checking again on a renewal payment that is still being processed
+const PROCESSING_PAYMENT_RECHECK_DELAY_IN_SECS: i64 = 900;
+
+pub struct SubscriptionBillingHandler<'a> {
+    pub state: &'a SessionState,
+    pub tracking_data: SubscriptionBillingTrackingData,
+    pub key_store: hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
+    pub merchant_account: hyperswitch_domain_models::merchant_account::MerchantAccount,
+    pub profile: hyperswitch_domain_models::business_profile::Profile,
+    pub subscription: hyperswitch_domain_models::subscription::Subscription,
+}
+
+impl<'a> SubscriptionBillingHandler<'a> {
+    pub async fn create(
+        state: &'a SessionState,
+        tracking_data: SubscriptionBillingTrackingData,
+    ) -> Result<Self, errors::ProcessTrackerError> {
+        let key_store = state
+            .store
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &state.store.get_master_key().to_vec().into(),
+            )
+            .await
+            .attach_printable("Failed to fetch Merchant key store from DB")?;
+
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await
+            .attach_printable("Subscriptions: Failed to fetch Merchant Account from DB")?;
+
+        let profile = state
+            .store
+            .find_business_profile_by_profile_id(&key_store, &tracking_data.profile_id)
+            .await
+            .attach_printable("Subscriptions: Failed to fetch Business Profile from DB")?;
+
+        let subscription = state
+            .store
+            .find_by_merchant_id_subscription_id(
+                &key_store,
+                merchant_account.get_id(),
+                tracking_data.subscription_id.get_string_repr().to_string(),
+            )
+            .await
+            .attach_printable("Subscriptions: Failed to fetch subscription from DB")?;
+
+        Ok(Self {
+            state,
+            tracking_data,
+            key_store,
+            merchant_account,
+            profile,
+            subscription,
+        })
+    }
+
+    /// A renewal only goes ahead for live, natively billed subscriptions whose current period
+    /// is still the one this task was scheduled for.
+    fn is_renewal_due(&self) -> bool {
+        let is_live = common_enums::SubscriptionStatus::from_str(&self.subscription.status)
+            .is_ok_and(|status| {
+                matches!(
+                    status,
+                    common_enums::SubscriptionStatus::Active
+                        | common_enums::SubscriptionStatus::Trial
+                        | common_enums::SubscriptionStatus::Unpaid
+                )
+            });
+
+        is_live
+            && self.subscription.billing_processor.is_none()
+            && self.subscription.current_period_end == Some(self.tracking_data.period_end)
+    }
+
+    fn get_invoice_handler(&self) -> InvoiceHandler {
+        InvoiceHandler::new(
+            self.subscription.clone(),
+            self.merchant_account.clone(),
+            self.profile.clone(),
+            self.key_store.clone(),
+        )
+    }
+
+    async fn update_subscription_status(
+        &mut self,
+        status: common_enums::SubscriptionStatus,
+    ) -> CustomResult<(), router_errors::ApiErrorResponse> {
+        self.update_subscription(
+            hyperswitch_domain_models::subscription::SubscriptionUpdate::update_status(
+                status.to_string(),
+            ),
+        )
+        .await
+    }
+
+    async fn update_subscription(
+        &mut self,
+        subscription_update: hyperswitch_domain_models::subscription::SubscriptionUpdate,
+    ) -> CustomResult<(), router_errors::ApiErrorResponse> {
+        self.subscription = self
+            .state
+            .store
+            .update_subscription_entry(
+                &self.key_store,
+                self.merchant_account.get_id(),
+                self.subscription.id.get_string_repr().to_string(),
+                subscription_update,
+            )
+            .await
+            .change_context(router_errors::ApiErrorResponse::SubscriptionError {
+                operation: "Subscription billing: subscription update".to_string(),
+            })
+            .attach_printable("subscriptions: unable to update subscription entry in database")?;
+        Ok(())
+    }
+
+    /// Persists the tracking data on the task, rescheduling it when a `schedule_time` is given.
+    async fn update_process(
+        &self,
+        process: ProcessTracker,
+        schedule_time: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<ProcessTracker, router_errors::ApiErrorResponse> {
+        let tracking_data = serde_json::to_value(&self.tracking_data)
+            .change_context(router_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize subscription billing tracking data")?;
+        let retry_count = process.retry_count + 1;
+
+        self.state
+            .store
+            .as_scheduler()
+            .update_process(
+                process,
+                ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: schedule_time.map(|_| retry_count),
+                    schedule_time,
+                    tracking_data: Some(tracking_data),
+                    business_status: schedule_time.map(|_| String::from(business_status::PENDING)),
+                    status: schedule_time.map(|_| common_enums::ProcessTrackerStatus::Pending),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await
+            .change_context(router_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update subscription billing task")
+    }
+
+    async fn finish_process_with_business_status(
+        &self,
+        process: ProcessTracker,
+        business_status: &'static str,
+    ) -> CustomResult<(), router_errors::ApiErrorResponse> {
+        self.state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await
+            .change_context(router_errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update process tracker status")
+    }
+
+    /// Fetches the renewal invoice of this cycle, generating it on the first run. The invoice id
+    /// is stored on the task right away so that a retried run reuses the same invoice.
+    async fn get_or_create_renewal_invoice(
+        &mut self,
+        process: ProcessTracker,
+        native_billing: &NativeBillingHandler,
+    ) -> CustomResult<
+        (ProcessTracker, hyperswitch_domain_models::invoice::Invoice),
+        router_errors::ApiErrorResponse,
+    > {
+        let invoice_handler = self.get_invoice_handler();
+
+        if let Some(invoice_id) = self.tracking_data.invoice_id.clone() {
+            let invoice = invoice_handler
+                .get_invoice_by_id(self.state, invoice_id)
+                .await?;
+            return Ok((process, invoice));
+        }
+
+        let price = native_billing
+            .get_subscription_price(self.state, &self.subscription)
+            .await?;
+        let invoice = invoice_handler
+            .create_invoice_entry(
+                self.state,
+                None,
+                None,
+                price.amount,
+                price.currency,
+                InvoiceStatus::InvoiceCreated,
+                None,
+                None,
+                None,
+            )
+            .await?;
+
+        self.tracking_data.invoice_id = Some(invoice.id.clone());
+        let process = self.update_process(process, None).await?;
+        Ok((process, invoice))
+    }
+
+    /// Payment id of the renewal charge for the current dunning attempt. Retried runs of the
+    /// same attempt reuse it, so that the payment is never created twice.
+    fn get_renewal_payment_id(
+        &self,
+        invoice: &hyperswitch_domain_models::invoice::Invoice,
+    ) -> CustomResult<common_utils::id_type::PaymentId, router_errors::ApiErrorResponse> {
+        common_utils::id_type::PaymentId::wrap(format!(
+            "{}_{}",
+            invoice.id.get_string_repr(),
+            self.tracking_data.failed_attempts
+        ))
+        .change_context(router_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate renewal payment id")
+    }
+
+    /// Charges the saved payment method for the invoice, or syncs the payment already made for it
+    /// in this dunning attempt. The payment id is stored on the invoice before the payment is
+    /// created, and the payment status right after, so that a retried run never charges twice.
+    async fn collect_invoice_payment(
+        &self,
+        invoice: hyperswitch_domain_models::invoice::Invoice,
+    ) -> CustomResult<
+        (
+            hyperswitch_domain_models::invoice::Invoice,
+            subscription_types::PaymentResponseData,
+        ),
+        router_errors::ApiErrorResponse,
+    > {
+        let invoice_handler = self.get_invoice_handler();
+        let payment_id = self.get_renewal_payment_id(&invoice)?;
+
+        if invoice.payment_intent_id.as_ref() == Some(&payment_id) {
+            if let InvoiceStatus::PaymentPending | InvoiceStatus::PaymentSucceeded = invoice.status
+            {
+                let payment_response = invoice_handler
+                    .get_payment_details(self.state, payment_id)
+                    .await?;
+                return Ok((invoice, payment_response));
+            }
+            // The payment may not have been created before the previous run stopped, in which
+            // case it is created below with the same payment id
+            if let Ok(payment_response) = invoice_handler
+                .get_payment_details(self.state, payment_id.clone())
+                .await
+            {
+                return Ok((invoice, payment_response));
+            }
+        }
+
+        let payment_method_id = self.subscription.payment_method_id.as_deref().ok_or(
+            router_errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "payment_method_id",
+            },
+        )?;
+        let currency = common_enums::Currency::from_str(invoice.currency.as_str())
+            .change_context(router_errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "currency",
+            })
+            .attach_printable("Failed to parse invoice currency")?;
+
+        let invoice = invoice_handler
+            .update_invoice(
+                self.state,
+                invoice.id.clone(),
+                InvoiceUpdateRequest::update_payment_and_status(
+                    None,
+                    Some(payment_id.clone()),
+                    InvoiceStatus::InvoiceCreated,
+                    None,
+                ),
+            )
+            .await?;
+
+        let payment_response = invoice_handler
+            .create_mit_payment(
+                self.state,
+                invoice.amount,
+                currency,
+                payment_method_id,
+                Some(payment_id),
+            )
+            .await?;
+
+        let invoice_sync_status =
+            storage::invoice_sync::InvoiceSyncPaymentStatus::from(payment_response.status);
+        let invoice = invoice_handler
+            .update_invoice(
+                self.state,
+                invoice.id.clone(),
+                InvoiceUpdateRequest::update_payment_and_status(
+                    payment_response.payment_method_id.clone(),
+                    Some(payment_response.payment_id.clone()),
+                    InvoiceStatus::from(invoice_sync_status),
+                    None,
+                ),
+            )
+            .await?;
+
+        Ok((invoice, payment_response))
+    }
+}
+
+/// Renews a natively billed subscription: generates the invoice of the next billing period and
+/// charges it off-session against the saved payment method. Failed charges are retried on the
+/// profile's dunning schedule, after which the subscription is cancelled.
+///
+/// Returns the invoice along with its payment once the payment has reached a final state.
+pub async fn perform_subscription_billing(
+    state: &SessionState,
+    process: ProcessTracker,
+    tracking_data: SubscriptionBillingTrackingData,
+) -> Result<
+    (
+        SubscriptionBillingHandler<'_>,
+        Option<(
+            hyperswitch_domain_models::invoice::Invoice,
+            subscription_types::PaymentResponseData,
+        )>,
+    ),
+    errors::ProcessTrackerError,
+> {
+    let mut handler = SubscriptionBillingHandler::create(state, tracking_data).await?;
+
+    if !handler.is_renewal_due() {
+        logger::info!(
+            subscription_id = ?handler.subscription.id,
+            "Subscription is no longer due for this renewal, skipping"
+        );
+        handler
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+        return Ok((handler, None));
+    }
+
+    let native_billing = NativeBillingHandler::create(
+        state,
+        handler.merchant_account.get_id(),
+        handler.profile.get_id(),
+    )
+    .await?;
+    let (process, invoice) = handler
+        .get_or_create_renewal_invoice(process, &native_billing)
+        .await?;
+    let (mut invoice, payment_response) = handler.collect_invoice_payment(invoice).await?;
+
+    let invoice_sync_status =
+        storage::invoice_sync::InvoiceSyncPaymentStatus::from(payment_response.status);
+    let invoice_status = InvoiceStatus::from(invoice_sync_status.clone());
+    if invoice.status != invoice_status {
+        invoice = handler
+            .get_invoice_handler()
+            .update_invoice(
+                state,
+                invoice.id.clone(),
+                InvoiceUpdateRequest::update_payment_and_status(
+                    payment_response.payment_method_id.clone(),
+                    Some(payment_response.payment_id.clone()),
+                    invoice_status,
+                    None,
+                ),
+            )
+            .await?;
+    }
+
+    match invoice_sync_status {
+        storage::invoice_sync::InvoiceSyncPaymentStatus::PaymentSucceeded => {
+            let price = native_billing
+                .get_subscription_price(state, &handler.subscription)
+                .await?;
+            let billing_cycle =
+                NativeBillingHandler::get_billing_cycle(&price, handler.tracking_data.period_end)?;
+            handler
+                .update_subscription(billing_cycle.to_subscription_update())
+                .await?;
+
+            create_subscription_billing_job(
+                state,
+                SubscriptionBillingTrackingData::new(
+                    &handler.subscription,
+                    billing_cycle.current_period_end,
+                ),
+            )
+            .await?;
+            handler
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            Ok((handler, Some((invoice, payment_response))))
+        }
+        storage::invoice_sync::InvoiceSyncPaymentStatus::PaymentProcessing => {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(PROCESSING_PAYMENT_RECHECK_DELAY_IN_SECS),
+            );
+            handler.update_process(process, Some(schedule_time)).await?;
+            Ok((handler, None))
+        }
+        storage::invoice_sync::InvoiceSyncPaymentStatus::PaymentFailed => {
+            let dunning_action = native_billing
+                .dunning
+                .get_dunning_action(handler.tracking_data.failed_attempts);
+            handler.tracking_data.failed_attempts =
+                handler.tracking_data.failed_attempts.saturating_add(1);
+
+            match dunning_action {
+                DunningAction::Retry { after } => {
+                    logger::info!(
+                        subscription_id = ?handler.subscription.id,
+                        failed_attempts = handler.tracking_data.failed_attempts,
+                        "Renewal payment failed, scheduling a dunning retry"
+                    );
+                    handler
+                        .update_subscription_status(common_enums::SubscriptionStatus::Unpaid)
+                        .await?;
+                    let schedule_time = common_utils::date_time::now().saturating_add(after);
+                    handler.update_process(process, Some(schedule_time)).await?;
+                }
+                DunningAction::Cancel => {
+                    logger::info!(
+                        subscription_id = ?handler.subscription.id,
+                        "Dunning retries exhausted, cancelling subscription"
+                    );
+                    handler
+                        .update_subscription_status(common_enums::SubscriptionStatus::Cancelled)
+                        .await?;
+                    handler
+                        .finish_process_with_business_status(
+                            process,
+                            business_status::RETRIES_EXCEEDED,
+                        )
+                        .await?;
+                }
+            }
+            Ok((handler, Some((invoice, payment_response))))
+        }
+    }
+}
+
+/// Schedules the renewal of a natively billed subscription at the end of its current period.
+pub async fn create_subscription_billing_job(
+    state: &SessionState,
+    tracking_data: SubscriptionBillingTrackingData,
+) -> CustomResult<(), router_errors::ApiErrorResponse> {
+    let schedule_time = tracking_data.period_end;
+    let process_tracker_entry = ProcessTrackerNew::new(
+        common_utils::generate_id(common_utils::consts::ID_LENGTH, "proc"),
+        SUBSCRIPTION_BILLING_WORKFLOW.to_string(),
+        common_enums::ProcessTrackerRunner::SubscriptionBillingWorkflow,
+        vec![SUBSCRIPTION_BILLING_WORKFLOW_TAG.to_string()],
+        tracking_data,
+        Some(0),
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(router_errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("subscriptions: unable to form process_tracker type")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(router_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("subscriptions: unable to insert process_tracker entry in DB")?;
+
+    Ok(())
+}
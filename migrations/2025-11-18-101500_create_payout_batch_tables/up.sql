@@ -0,0 +1,39 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    total_count INTEGER NOT NULL,
+    processed_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    cancelled_count INTEGER NOT NULL DEFAULT 0,
+    metadata JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_merchant_id_created_at_index ON payout_batch (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    id VARCHAR(64) PRIMARY KEY,
+    batch_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    payout_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payout_status "PayoutStatus",
+    payout_request BYTEA,
+    retry_count INTEGER NOT NULL DEFAULT 0,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS payout_batch_item_batch_id_status_index ON payout_batch_item (batch_id, status);
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_batch_completed';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payout_batch_details';
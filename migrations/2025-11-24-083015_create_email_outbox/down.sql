@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS email_outbox_recipient_status_index;
+
+DROP TABLE IF EXISTS email_outbox;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS email_outbox (
+    id VARCHAR(64) PRIMARY KEY,
+    recipient VARCHAR(255) NOT NULL,
+    subject TEXT NOT NULL,
+    body BYTEA,
+    email_type VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    theme_id VARCHAR(64),
+    locale VARCHAR(32),
+    retry_count INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS email_outbox_recipient_status_index ON email_outbox (recipient, status);
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_report (
+    id VARCHAR(64) PRIMARY KEY,
+    organization_id VARCHAR(32) NOT NULL,
+    report_type VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    auth_info JSONB NOT NULL,
+    start_time TIMESTAMP NOT NULL,
+    end_time TIMESTAMP,
+    requested_by VARCHAR(64) NOT NULL,
+    notification_emails JSONB,
+    webhook_url TEXT,
+    file_key TEXT,
+    file_size BIGINT,
+    row_count BIGINT,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMP
+);
@@ -0,0 +1,12 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS dispute_dispute_id_trgm_index;
+
+DROP INDEX IF EXISTS dispute_payment_id_trgm_index;
+
+DROP INDEX IF EXISTS dispute_connector_dispute_id_trgm_index;
+
+DROP INDEX IF EXISTS payouts_payout_id_trgm_index;
+
+DROP INDEX IF EXISTS payouts_customer_id_trgm_index;
+
+DROP INDEX IF EXISTS payout_attempt_connector_payout_id_trgm_index;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS dispute_dispute_id_trgm_index ON dispute USING GIN (dispute_id gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS dispute_payment_id_trgm_index ON dispute USING GIN (payment_id gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS dispute_connector_dispute_id_trgm_index ON dispute USING GIN (connector_dispute_id gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS payouts_payout_id_trgm_index ON payouts USING GIN (payout_id gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS payouts_customer_id_trgm_index ON payouts USING GIN (customer_id gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS payout_attempt_connector_payout_id_trgm_index ON payout_attempt USING GIN (connector_payout_id gin_trgm_ops);
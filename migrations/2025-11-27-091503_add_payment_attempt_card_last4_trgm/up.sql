@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_attempt_card_last4_trgm_index ON payment_attempt USING GIN (
+    (payment_method_data -> 'card' ->> 'last4') gin_trgm_ops
+);
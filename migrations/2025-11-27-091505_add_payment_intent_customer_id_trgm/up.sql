@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_customer_id_trgm_index ON payment_intent USING GIN (customer_id gin_trgm_ops);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_intent_merchant_order_reference_id_trgm_index ON payment_intent USING GIN (merchant_order_reference_id gin_trgm_ops);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX CONCURRENTLY IF NOT EXISTS refund_payment_id_trgm_index ON refund USING GIN (payment_id gin_trgm_ops);
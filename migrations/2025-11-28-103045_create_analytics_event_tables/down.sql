@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS api_events;
+DROP TABLE IF EXISTS connector_events;
+DROP TABLE IF EXISTS outgoing_webhook_events;
+DROP TABLE IF EXISTS routing_events;
@@ -0,0 +1,36 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS api_events (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL,
+    event JSONB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS api_events_merchant_id_created_at_index ON api_events (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS connector_events (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL,
+    event JSONB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS connector_events_merchant_id_created_at_index ON connector_events (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS outgoing_webhook_events (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL,
+    event JSONB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS outgoing_webhook_events_merchant_id_created_at_index ON outgoing_webhook_events (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS routing_events (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL,
+    event JSONB NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS routing_events_merchant_id_created_at_index ON routing_events (merchant_id, created_at);
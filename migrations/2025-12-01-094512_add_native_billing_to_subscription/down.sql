@@ -0,0 +1,17 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS subscription_plan_price_profile_id_plan_id_index;
+
+DROP TABLE IF EXISTS subscription_plan_price;
+
+DROP INDEX IF EXISTS subscription_plan_profile_id_created_at_index;
+
+DROP TABLE IF EXISTS subscription_plan;
+
+ALTER TABLE invoice
+ALTER COLUMN merchant_connector_id SET NOT NULL,
+ALTER COLUMN provider_name SET NOT NULL;
+
+ALTER TABLE subscription
+DROP COLUMN IF EXISTS current_period_start,
+DROP COLUMN IF EXISTS current_period_end,
+DROP COLUMN IF EXISTS trial_end;
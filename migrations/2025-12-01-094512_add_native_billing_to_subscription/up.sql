@@ -0,0 +1,38 @@
+-- Your SQL goes here
+ALTER TABLE subscription
+ADD COLUMN IF NOT EXISTS current_period_start TIMESTAMP,
+ADD COLUMN IF NOT EXISTS current_period_end TIMESTAMP,
+ADD COLUMN IF NOT EXISTS trial_end TIMESTAMP;
+
+ALTER TABLE invoice
+ALTER COLUMN merchant_connector_id DROP NOT NULL,
+ALTER COLUMN provider_name DROP NOT NULL;
+
+CREATE TABLE IF NOT EXISTS subscription_plan (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_profile_id_created_at_index ON subscription_plan (profile_id, created_at);
+
+CREATE TABLE IF NOT EXISTS subscription_plan_price (
+    id VARCHAR(64) PRIMARY KEY,
+    plan_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    interval VARCHAR(16) NOT NULL,
+    interval_count BIGINT NOT NULL,
+    trial_period BIGINT,
+    trial_period_unit VARCHAR(16),
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS subscription_plan_price_profile_id_plan_id_index ON subscription_plan_price (profile_id, plan_id);
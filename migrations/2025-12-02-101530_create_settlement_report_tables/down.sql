@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS recon_result_merchant_id_status_index;
+
+DROP INDEX IF EXISTS recon_result_report_id_status_index;
+
+DROP TABLE IF EXISTS recon_result;
+
+DROP INDEX IF EXISTS settlement_report_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS settlement_report;
@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS settlement_report (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64),
+    file_key VARCHAR(255),
+    total_count INTEGER NOT NULL,
+    matched_count INTEGER NOT NULL,
+    mismatched_count INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS settlement_report_merchant_id_created_at_index ON settlement_report (merchant_id, created_at);
+
+CREATE TABLE IF NOT EXISTS recon_result (
+    id VARCHAR(64) PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    transaction_type VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    expected_amount BIGINT,
+    expected_currency "Currency",
+    settled_amount BIGINT NOT NULL,
+    settled_currency "Currency" NOT NULL,
+    fee_amount BIGINT,
+    settled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS recon_result_report_id_status_index ON recon_result (report_id, status);
+
+CREATE INDEX IF NOT EXISTS recon_result_merchant_id_status_index ON recon_result (merchant_id, status);
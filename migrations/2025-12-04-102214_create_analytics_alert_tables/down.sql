@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS analytics_alert_history_rule_id_created_at_index;
+
+DROP INDEX IF EXISTS analytics_alert_history_merchant_id_created_at_index;
+
+DROP TABLE IF EXISTS analytics_alert_history;
+
+DROP INDEX IF EXISTS analytics_alert_rule_merchant_id_index;
+
+DROP TABLE IF EXISTS analytics_alert_rule;
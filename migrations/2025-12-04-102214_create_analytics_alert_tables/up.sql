@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_alert_rule (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    metric VARCHAR(32) NOT NULL,
+    filters JSONB NOT NULL,
+    baseline VARCHAR(32) NOT NULL,
+    evaluation_window_in_mins INTEGER NOT NULL,
+    baseline_periods INTEGER NOT NULL,
+    deviation_threshold DOUBLE PRECISION NOT NULL,
+    direction VARCHAR(32) NOT NULL,
+    min_volume INTEGER NOT NULL,
+    channels JSONB NOT NULL,
+    mute_windows JSONB NOT NULL DEFAULT '[]'::JSONB,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    last_evaluated_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS analytics_alert_rule_merchant_id_index ON analytics_alert_rule (merchant_id);
+
+CREATE TABLE IF NOT EXISTS analytics_alert_history (
+    id VARCHAR(64) PRIMARY KEY,
+    rule_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    metric VARCHAR(32) NOT NULL,
+    profile_id VARCHAR(64),
+    connector VARCHAR(64),
+    payment_method VARCHAR(64),
+    error_code VARCHAR(255),
+    observed_value DOUBLE PRECISION NOT NULL,
+    baseline_mean DOUBLE PRECISION NOT NULL,
+    baseline_stddev DOUBLE PRECISION NOT NULL,
+    sample_size BIGINT NOT NULL,
+    window_start TIMESTAMP NOT NULL,
+    window_end TIMESTAMP NOT NULL,
+    delivery_status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS analytics_alert_history_merchant_id_created_at_index ON analytics_alert_history (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS analytics_alert_history_rule_id_created_at_index ON analytics_alert_history (rule_id, created_at);
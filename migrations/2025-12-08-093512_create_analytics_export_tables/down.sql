@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS analytics_export_run_export_id_period_start_index;
+
+DROP TABLE IF EXISTS analytics_export_run;
+
+DROP INDEX IF EXISTS analytics_export_merchant_id_index;
+
+DROP TABLE IF EXISTS analytics_export;
@@ -0,0 +1,39 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_export (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    organization_id VARCHAR(32) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    dataset VARCHAR(32) NOT NULL,
+    columns JSONB NOT NULL,
+    filters JSONB NOT NULL,
+    format VARCHAR(32) NOT NULL,
+    frequency VARCHAR(32) NOT NULL,
+    schedule_hour INTEGER NOT NULL,
+    destination BYTEA NOT NULL,
+    is_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS analytics_export_merchant_id_index ON analytics_export (merchant_id);
+
+CREATE TABLE IF NOT EXISTS analytics_export_run (
+    id VARCHAR(64) PRIMARY KEY,
+    export_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    file_name VARCHAR(255),
+    file_size BIGINT,
+    row_count BIGINT,
+    checksum VARCHAR(64),
+    attempt_count INTEGER NOT NULL DEFAULT 0,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    modified_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS analytics_export_run_export_id_period_start_index ON analytics_export_run (export_id, period_start);